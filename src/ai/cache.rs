@@ -0,0 +1,144 @@
+//! On-disk response cache and request rate limiting for AI providers
+//!
+//! Responses are stored under the user cache directory (`~/.cache/rask/ai`
+//! on Linux), one JSON file per request keyed by a SHA-256 hash of the provider,
+//! model, operation and prompt, so running `rask ai analyze` twice on an unchanged
+//! roadmap only calls the API once. The rate limiter keeps the timestamps of
+//! recent requests in the same directory, which makes the limit hold across
+//! separate `rask` invocations.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use hmac_sha256::Hash;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const REQUEST_LOG: &str = "requests.json";
+
+/// A cached response with the time it was stored
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<T> {
+    created_at: DateTime<Utc>,
+    provider: String,
+    model: String,
+    operation: String,
+    value: T,
+}
+
+/// Directory holding cached responses and the request log
+pub fn cache_dir() -> Result<PathBuf> {
+    let base = dirs::cache_dir().context("Could not determine the user cache directory")?;
+    Ok(base.join("rask").join("ai"))
+}
+
+/// Disk cache for AI responses with a time-to-live
+pub struct ResponseCache {
+    dir: Option<PathBuf>,
+    ttl: Duration,
+}
+
+impl ResponseCache {
+    /// Create a cache; a TTL of zero disables caching
+    pub fn new(ttl_minutes: u64) -> Self {
+        let dir = if ttl_minutes == 0 { None } else { cache_dir().ok() };
+        Self { dir, ttl: Duration::minutes(ttl_minutes as i64) }
+    }
+
+    fn path(&self, provider: &str, model: &str, operation: &str, input: &str) -> Option<PathBuf> {
+        let material = format!("{}\0{}\0{}\0{}", provider, model, operation, input);
+        let key: String = Hash::hash(material.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect();
+        self.dir.as_ref().map(|dir| dir.join(format!("{}.json", key)))
+    }
+
+    /// Look up a fresh response, removing it when it has expired
+    pub fn get<T: DeserializeOwned>(&self, provider: &str, model: &str, operation: &str, input: &str) -> Option<T> {
+        let path = self.path(provider, model, operation, input)?;
+        let entry: CacheEntry<T> = serde_json::from_str(&fs::read_to_string(&path).ok()?).ok()?;
+        if Utc::now() - entry.created_at > self.ttl {
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+        Some(entry.value)
+    }
+
+    /// Store a response. Failures are ignored: the cache is only an optimisation.
+    pub fn put<T: Serialize>(&self, provider: &str, model: &str, operation: &str, input: &str, value: &T) {
+        let Some(path) = self.path(provider, model, operation, input) else { return };
+        let entry = CacheEntry {
+            created_at: Utc::now(),
+            provider: provider.to_string(),
+            model: model.to_string(),
+            operation: operation.to_string(),
+            value,
+        };
+        if let (Some(dir), Ok(data)) = (path.parent(), serde_json::to_string(&entry)) {
+            let _ = fs::create_dir_all(dir).and_then(|_| fs::write(&path, data));
+        }
+    }
+}
+
+/// Remove every cached response, returning how many were deleted
+pub fn clear_cache() -> Result<usize> {
+    let dir = cache_dir()?;
+    if !dir.exists() {
+        return Ok(0);
+    }
+    let mut removed = 0;
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        let is_response = path.extension().is_some_and(|e| e == "json") && !path.ends_with(REQUEST_LOG);
+        if is_response {
+            fs::remove_file(&path)?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Limits outgoing requests to a number per rolling minute
+pub struct RateLimiter {
+    requests_per_minute: u32,
+}
+
+impl RateLimiter {
+    /// Create a limiter; zero means unlimited
+    pub fn new(requests_per_minute: u32) -> Self {
+        Self { requests_per_minute }
+    }
+
+    /// Wait until another request is allowed, then record it
+    pub async fn acquire(&self) -> Result<()> {
+        if self.requests_per_minute == 0 {
+            return Ok(());
+        }
+        let log_path = cache_dir()?.join(REQUEST_LOG);
+        let window = Duration::minutes(1);
+
+        let mut recent: Vec<DateTime<Utc>> = fs::read_to_string(&log_path).ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default();
+        recent.retain(|t| Utc::now() - *t < window);
+        recent.sort();
+
+        if recent.len() >= self.requests_per_minute as usize {
+            let oldest_allowed = recent[recent.len() - self.requests_per_minute as usize];
+            let wait = (oldest_allowed + window - Utc::now()).to_std().unwrap_or_default();
+            if !wait.is_zero() {
+                tracing::info!(wait_secs = wait.as_secs(), "AI rate limit reached");
+                crate::ui::display_info(&format!(
+                    "⏳ AI rate limit of {} requests/minute reached, waiting {}s",
+                    self.requests_per_minute,
+                    wait.as_secs() + 1
+                ));
+                tokio::time::sleep(wait).await;
+            }
+        }
+
+        recent.push(Utc::now());
+        recent.retain(|t| Utc::now() - *t < window);
+        fs::create_dir_all(log_path.parent().unwrap_or(&log_path))?;
+        fs::write(&log_path, serde_json::to_string(&recent)?)?;
+        Ok(())
+    }
+}
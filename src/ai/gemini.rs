@@ -2,13 +2,14 @@
 
 use anyhow::{Context, Result};
 use async_trait::async_trait;
+use futures::StreamExt;
 use reqwest::{Client, header};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 use crate::config::AiConfig;
 use crate::model::{Task, Roadmap, Priority, Phase};
-use super::{AiProvider, AiTaskAnalysis, AiTaskSuggestion, AiProjectInsights, AiRisk, AiMessageMetadata};
+use super::{AiProvider, ChatStream, AiTaskAnalysis, AiTaskSuggestion, AiProjectInsights, AiRisk, AiMessageMetadata};
 
 /// Google Gemini API client
 pub struct GeminiProvider {
@@ -75,6 +76,33 @@ struct GeminiUsageMetadata {
     total_token_count: Option<u32>,
 }
 
+/// Model used for embeddings; the chat model cannot produce them
+const EMBEDDING_MODEL: &str = "text-embedding-004";
+
+/// Most texts the batch embedding endpoint accepts in one request
+const EMBEDDING_BATCH: usize = 100;
+
+#[derive(Debug, Serialize)]
+struct GeminiEmbedRequest {
+    requests: Vec<GeminiEmbedContent>,
+}
+
+#[derive(Debug, Serialize)]
+struct GeminiEmbedContent {
+    model: String,
+    content: GeminiContent,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiEmbedResponse {
+    embeddings: Vec<GeminiEmbedding>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiEmbedding {
+    values: Vec<f32>,
+}
+
 impl GeminiProvider {
     /// Create a new Gemini provider
     pub fn new(config: &AiConfig) -> Result<Self> {
@@ -100,9 +128,9 @@ impl GeminiProvider {
         })
     }
 
-    /// Make a request to the Gemini API
-    async fn make_request(&self, prompt: &str) -> Result<(String, Option<AiMessageMetadata>)> {
-        let request = GeminiRequest {
+    /// Build the request body for a single-turn prompt
+    fn build_request(&self, prompt: &str) -> GeminiRequest {
+        GeminiRequest {
             contents: vec![GeminiContent {
                 parts: vec![GeminiPart {
                     text: prompt.to_string(),
@@ -112,7 +140,77 @@ impl GeminiProvider {
                 temperature: self.config.temperature,
                 max_output_tokens: self.config.max_tokens,
             },
-        };
+        }
+    }
+
+    /// Make a streaming request, yielding text as server-sent events arrive
+    #[tracing::instrument(skip_all, fields(model = %self.config.default_model))]
+    async fn make_stream_request(&self, prompt: &str) -> Result<ChatStream> {
+        let url = format!(
+            "{}/models/{}:streamGenerateContent?alt=sse&key={}",
+            self.config.gemini.endpoint,
+            self.config.default_model,
+            self.api_key
+        );
+
+        tracing::info!(prompt_chars = prompt.len(), "sending streaming request to Gemini");
+        let response = self
+            .client
+            .post(&url)
+            .json(&self.build_request(prompt))
+            .send()
+            .await
+            .context("Failed to send request to Gemini API")?;
+        tracing::debug!(status = %response.status(), "Gemini stream opened");
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            anyhow::bail!("Gemini API error ({}): {}", status, error_text);
+        }
+
+        // Bytes are buffered until a full line arrives, so multi-byte
+        // characters split across network chunks are decoded intact
+        let events = futures::stream::unfold(
+            (response.bytes_stream(), Vec::new(), false),
+            |(mut bytes, mut buffer, mut finished)| async move {
+                loop {
+                    if let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+                        let line: Vec<u8> = buffer.drain(..=pos).collect();
+                        let line = String::from_utf8_lossy(&line);
+                        let Some(data) = line.trim().strip_prefix("data:") else { continue };
+                        match parse_stream_event(data.trim()) {
+                            Ok(text) if text.is_empty() => continue,
+                            Ok(text) => return Some((Ok(text), (bytes, buffer, finished))),
+                            Err(e) => return Some((Err(e), (bytes, Vec::new(), true))),
+                        }
+                    }
+                    if finished {
+                        return None;
+                    }
+                    match bytes.next().await {
+                        Some(Ok(chunk)) => buffer.extend_from_slice(&chunk),
+                        Some(Err(e)) => {
+                            let error = anyhow::Error::new(e).context("Gemini response stream failed");
+                            return Some((Err(error), (bytes, Vec::new(), true)));
+                        }
+                        None => {
+                            // Flush a final event without a trailing newline
+                            buffer.push(b'\n');
+                            finished = true;
+                        }
+                    }
+                }
+            },
+        );
+
+        Ok(Box::pin(events))
+    }
+
+    /// Make a request to the Gemini API
+    #[tracing::instrument(skip_all, fields(model = %self.config.default_model))]
+    async fn make_request(&self, prompt: &str) -> Result<(String, Option<AiMessageMetadata>)> {
+        let request = self.build_request(prompt);
 
         let url = format!(
             "{}/models/{}:generateContent?key={}",
@@ -122,6 +220,7 @@ impl GeminiProvider {
         );
 
         let start_time = std::time::Instant::now();
+        tracing::info!(prompt_chars = prompt.len(), "sending request to Gemini");
         
         let response = self
             .client
@@ -132,6 +231,7 @@ impl GeminiProvider {
             .context("Failed to send request to Gemini API")?;
 
         let processing_time = start_time.elapsed().as_millis() as u64;
+        tracing::debug!(status = %response.status(), elapsed_ms = processing_time, "Gemini responded");
 
         if !response.status().is_success() {
             let status = response.status();
@@ -158,6 +258,10 @@ impl GeminiProvider {
             processing_time: Some(processing_time),
         });
 
+        if let Some(meta) = &metadata {
+            tracing::debug!(input_tokens = ?meta.input_tokens, output_tokens = ?meta.output_tokens, "Gemini token usage");
+        }
+
         Ok((text, metadata))
     }
 
@@ -232,22 +336,59 @@ impl GeminiProvider {
 #[async_trait]
 impl AiProvider for GeminiProvider {
     async fn chat(&self, message: &str, context: Option<&str>) -> Result<String> {
-        let prompt = if let Some(ctx) = context {
-            format!(
-                "You are an AI assistant helping with project management in Rask, a CLI task management tool.\n\nProject Context:\n{}\n\nUser Question: {}\n\nPlease provide a helpful, concise response focused on project management, task organization, and productivity.",
-                ctx, message
-            )
-        } else {
-            format!(
-                "You are an AI assistant for Rask, a CLI project management tool. Please help the user with their question:\n\n{}",
-                message
-            )
-        };
-
-        let (response, _) = self.make_request(&prompt).await?;
+        let (response, _) = self.make_request(&chat_prompt(message, context)).await?;
         Ok(response)
     }
 
+    async fn chat_stream(&self, message: &str, context: Option<&str>) -> Result<ChatStream> {
+        self.make_stream_request(&chat_prompt(message, context)).await
+    }
+
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let url = format!(
+            "{}/models/{}:batchEmbedContents?key={}",
+            self.config.gemini.endpoint,
+            EMBEDDING_MODEL,
+            self.api_key
+        );
+
+        let mut vectors = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(EMBEDDING_BATCH) {
+            let request = GeminiEmbedRequest {
+                requests: batch.iter().map(|text| GeminiEmbedContent {
+                    model: format!("models/{}", EMBEDDING_MODEL),
+                    content: GeminiContent { parts: vec![GeminiPart { text: text.clone() }] },
+                }).collect(),
+            };
+            tracing::info!(texts = batch.len(), "sending embedding request to Gemini");
+
+            let response = self
+                .client
+                .post(&url)
+                .json(&request)
+                .send()
+                .await
+                .context("Failed to send request to Gemini API")?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                anyhow::bail!("Gemini API error ({}): {}", status, error_text);
+            }
+
+            let embed_response: GeminiEmbedResponse = response
+                .json()
+                .await
+                .context("Failed to parse Gemini embedding response")?;
+            vectors.extend(embed_response.embeddings.into_iter().map(|embedding| embedding.values));
+        }
+
+        if vectors.len() != texts.len() {
+            anyhow::bail!("Gemini returned {} embeddings for {} texts", vectors.len(), texts.len());
+        }
+        Ok(vectors)
+    }
+
     async fn analyze_tasks(&self, tasks: &[Task]) -> Result<AiTaskAnalysis> {
         let task_context = self.build_task_context(tasks);
         
@@ -411,4 +552,33 @@ Provide strategic insights focusing on project health, bottlenecks, and optimiza
     fn provider_name(&self) -> &str {
         "Google Gemini"
     }
-}
\ No newline at end of file
+}
+
+/// Prompt for a chat message, with optional project context
+fn chat_prompt(message: &str, context: Option<&str>) -> String {
+    if let Some(ctx) = context {
+        format!(
+            "You are an AI assistant helping with project management in Rask, a CLI task management tool.\n\nProject Context:\n{}\n\nUser Question: {}\n\nPlease provide a helpful, concise response focused on project management, task organization, and productivity.",
+            ctx, message
+        )
+    } else {
+        format!(
+            "You are an AI assistant for Rask, a CLI project management tool. Please help the user with their question:\n\n{}",
+            message
+        )
+    }
+}
+
+/// Extract the text from one server-sent event of a streaming response
+fn parse_stream_event(data: &str) -> Result<String> {
+    let event: serde_json::Value = serde_json::from_str(data)
+        .context("Failed to parse Gemini stream event")?;
+    if let Some(error) = event.get("error") {
+        anyhow::bail!("Gemini API error: {}", error.get("message").and_then(|m| m.as_str()).unwrap_or("unknown error"));
+    }
+
+    Ok(event["candidates"][0]["content"]["parts"]
+        .as_array()
+        .map(|parts| parts.iter().filter_map(|p| p["text"].as_str()).collect())
+        .unwrap_or_default())
+}
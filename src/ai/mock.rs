@@ -0,0 +1,448 @@
+//! Offline mock AI provider
+//!
+//! Produces deterministic, template-based responses built from the project
+//! data it is given, so `rask ai` commands can run in CI and demos without
+//! credentials or network access. Select it with `rask config set ai.provider mock`.
+//!
+//! Prompt-based features (templates, estimates, risks, standups, roadmaps) go through
+//! [`AiProvider::chat`]; the mock recognises them by the response format each
+//! prompt asks for and answers in that format.
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::model::{Phase, Priority, Roadmap, Task, TaskStatus};
+use super::{AiProjectInsights, AiProvider, AiRisk, AiTaskAnalysis, AiTaskSuggestion};
+
+/// Provider that answers locally with canned responses
+pub struct MockProvider;
+
+#[async_trait]
+impl AiProvider for MockProvider {
+    async fn chat(&self, message: &str, context: Option<&str>) -> Result<String> {
+        let response = if message.contains("\"estimated_hours\": 4.5") {
+            estimate_response(message)
+        } else if message.contains("Plan a phased roadmap") {
+            brief_roadmap_response(message)
+        } else if message.contains("Summarize the notes on this task") {
+            notes_summary_response(message)
+        } else if message.contains("\"action\": \"set_phase\"") {
+            operations_response(message)
+        } else if message.contains("\"task_ids\"") {
+            risks_response(message)
+        } else if message.contains("JSON array of template objects") {
+            templates_response(message)
+        } else if message.contains("\"usefulness_score\"") {
+            template_suggestions_response()
+        } else if message.contains("\"enhanced_description\"") {
+            template_enhancement_response(message)
+        } else if message.contains("daily standup") {
+            standup_response(message)
+        } else if message.contains("Generate a new comprehensive project plan") {
+            plan_response()
+        } else if message.contains("Analyze this project roadmap") {
+            roadmap_analysis_response(message)
+        } else {
+            let question = message.lines().last().unwrap_or(message).trim();
+            match context {
+                Some(ctx) => format!("[mock] {}\n\nYou asked: \"{}\". Break it into small tasks, tackle the highest priority ready task first, and track time to improve future estimates.", ctx.lines().next().unwrap_or(ctx), question),
+                None => format!("[mock] You asked: \"{}\". Break it into small tasks and tackle the highest priority ready task first.", question),
+            }
+        };
+        Ok(response)
+    }
+
+    /// Bag-of-words vectors: each word is hashed into one of a fixed number
+    /// of dimensions, so texts sharing words point the same way
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        const DIMENSIONS: usize = 64;
+        Ok(texts.iter().map(|text| {
+            let mut vector = vec![0.0; DIMENSIONS];
+            for word in text.split(|c: char| !c.is_alphanumeric()).filter(|w| w.len() > 2) {
+                let word = word.to_lowercase();
+                let hash = word.trim_end_matches('s').bytes().fold(0usize, |hash, b| hash.wrapping_mul(31).wrapping_add(b as usize));
+                vector[hash % DIMENSIONS] += 1.0;
+            }
+            vector
+        }).collect())
+    }
+
+    async fn analyze_tasks(&self, tasks: &[Task]) -> Result<AiTaskAnalysis> {
+        let completed = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+        let pending: Vec<&Task> = tasks.iter().filter(|t| t.status == TaskStatus::Pending).collect();
+        let unestimated = pending.iter().filter(|t| t.estimated_hours.is_none()).count();
+        let urgent = pending.iter().filter(|t| matches!(t.priority, Priority::High | Priority::Critical)).count();
+
+        let mut potential_issues = Vec::new();
+        if urgent > 3 {
+            potential_issues.push(format!("{} high or critical tasks are open at once; consider lowering some priorities", urgent));
+        }
+        if unestimated > 0 {
+            potential_issues.push(format!("{} open task(s) have no estimate", unestimated));
+        }
+
+        let task_suggestions = pending.iter()
+            .filter(|t| !t.tags.contains("testing"))
+            .take(3)
+            .map(|t| AiTaskSuggestion {
+                description: format!("Write tests for: {}", t.description),
+                priority: t.priority.clone(),
+                phase: t.phase.clone(),
+                tags: vec!["testing".to_string()],
+                estimated_hours: Some(1.0),
+                dependencies: vec![t.description.clone()],
+                notes: None,
+                reasoning: format!("Task #{} has no matching test task", t.id),
+            })
+            .collect();
+
+        Ok(AiTaskAnalysis {
+            health_score: percentage(completed, tasks.len()).clamp(40, 100) as u8,
+            insights: vec![
+                format!("{} of {} tasks are completed", completed, tasks.len()),
+                format!("{} open task(s), {} of them high or critical priority", pending.len(), urgent),
+            ],
+            task_suggestions,
+            workflow_recommendations: vec![
+                "Finish in-progress work before starting new tasks".to_string(),
+                "Add estimates to open tasks so progress can be forecast".to_string(),
+            ],
+            potential_issues,
+        })
+    }
+
+    async fn generate_task_breakdown(&self, description: &str) -> Result<Vec<AiTaskSuggestion>> {
+        let steps = [
+            ("Design", 2.0, "design", Priority::High),
+            ("Implement", 4.0, "development", Priority::High),
+            ("Test", 2.0, "testing", Priority::Medium),
+            ("Document", 1.0, "documentation", Priority::Low),
+        ];
+        Ok(steps.iter().enumerate().map(|(i, (step, hours, tag, priority))| AiTaskSuggestion {
+            description: format!("{}: {}", step, description),
+            priority: priority.clone(),
+            phase: Phase::mvp(),
+            tags: vec![tag.to_string()],
+            estimated_hours: Some(*hours),
+            dependencies: if i == 0 { vec![] } else { vec![format!("{}: {}", steps[i - 1].0, description)] },
+            notes: None,
+            reasoning: format!("{} step of the standard design → implement → test → document breakdown", step),
+        }).collect())
+    }
+
+    async fn get_project_insights(&self, roadmap: &Roadmap) -> Result<AiProjectInsights> {
+        let completed = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+        let ready = roadmap.get_ready_tasks();
+        let blocked = roadmap.tasks.iter()
+            .filter(|t| t.status == TaskStatus::Pending && !ready.iter().any(|r| r.id == t.id))
+            .count();
+
+        let mut risks = Vec::new();
+        if blocked > 0 {
+            risks.push(AiRisk {
+                description: format!("{} task(s) are waiting on unfinished dependencies", blocked),
+                severity: if blocked > 5 { "High" } else { "Medium" }.to_string(),
+                mitigation: vec!["Prioritise the tasks that unblock the most work".to_string()],
+                affected_areas: vec!["Schedule".to_string()],
+            });
+        }
+
+        Ok(AiProjectInsights {
+            completion_assessment: format!(
+                "{} of {} tasks completed ({}%)",
+                completed,
+                roadmap.tasks.len(),
+                percentage(completed, roadmap.tasks.len())
+            ),
+            critical_path: ready.iter().take(3).map(|t| format!("#{} {}", t.id, t.description)).collect(),
+            resource_suggestions: vec!["Keep work in progress small and finish one phase at a time".to_string()],
+            risks,
+            next_actions: ready.iter().take(3).map(|t| format!("Start #{} {}", t.id, t.description)).collect(),
+            performance_insights: None,
+        })
+    }
+
+    fn is_ready(&self) -> bool {
+        true
+    }
+
+    fn provider_name(&self) -> &str {
+        "Mock (offline)"
+    }
+}
+
+fn percentage(part: usize, total: usize) -> usize {
+    (part * 100).checked_div(total).unwrap_or(0)
+}
+
+/// Text after `label` up to the next blank line
+fn section<'a>(text: &'a str, label: &str) -> Vec<&'a str> {
+    text.split(label).nth(1)
+        .map(|rest| rest.lines()
+            .skip(1)
+            .take_while(|l| !l.trim().is_empty())
+            .map(|l| l.trim().trim_start_matches("- "))
+            .filter(|l| *l != "none")
+            .collect())
+        .unwrap_or_default()
+}
+
+fn estimate_response(prompt: &str) -> String {
+    let description = prompt.lines()
+        .find(|l| l.starts_with("Task #"))
+        .and_then(|l| l.split_once(": "))
+        .map(|(_, d)| d)
+        .unwrap_or_default();
+    let hours = 1.0 + (description.split_whitespace().count() as f64 / 2.0).round();
+    serde_json::json!({
+        "estimated_hours": hours,
+        "confidence": 50,
+        "reasoning": "Mock estimate: one hour plus half an hour per word of the description",
+    }).to_string()
+}
+
+/// Task numbers mentioned in a line, in order
+fn task_ids(line: &str) -> Vec<usize> {
+    line.split_whitespace()
+        .filter_map(|word| word.strip_prefix('#')?.parse().ok())
+        .collect()
+}
+
+fn risks_response(prompt: &str) -> String {
+    let mut risks = Vec::new();
+    for chain in section(prompt, "Longest open dependency chain:") {
+        let ids = task_ids(chain);
+        if ids.len() >= 3 {
+            risks.push(serde_json::json!({
+                "kind": "dependency_chain",
+                "severity": if ids.len() >= 4 { "High" } else { "Medium" },
+                "description": format!("{} tasks must be finished one after another", ids.len()),
+                "task_ids": ids,
+                "mitigation": "Look for steps in the chain that can start in parallel",
+            }));
+        }
+    }
+    for blocker in section(prompt, "Open tasks blocking two or more others:") {
+        let ids = task_ids(blocker);
+        risks.push(serde_json::json!({
+            "kind": "blocker",
+            "severity": if ids.len() > 3 { "High" } else { "Medium" },
+            "description": format!("{} holds up {} other task(s)", blocker.split_whitespace().next().unwrap_or("A task"), ids.len() - 1),
+            "task_ids": ids.first().map(|id| vec![*id]).unwrap_or_default(),
+            "mitigation": "Start it early and keep it small",
+        }));
+    }
+    for concern in section(prompt, "Critical or high priority estimate concerns:") {
+        risks.push(serde_json::json!({
+            "kind": "estimate",
+            "severity": "High",
+            "description": concern.split_once(' ').map(|(_, rest)| rest).unwrap_or(concern),
+            "task_ids": task_ids(concern).into_iter().take(1).collect::<Vec<_>>(),
+            "mitigation": "Estimate it with 'rask estimate' or split it into smaller tasks",
+        }));
+    }
+    serde_json::Value::Array(risks).to_string()
+}
+
+fn notes_summary_response(prompt: &str) -> String {
+    let entries: Vec<&str> = prompt.lines().filter(|line| line.starts_with("- [")).collect();
+    let mut summary = vec![format!("- [mock] {} note(s) and comment(s) on this task", entries.len())];
+    if let Some(latest) = entries.last() {
+        let text = latest.split_once("] ").map_or(*latest, |(_, text)| text);
+        summary.push(format!("- Latest: {}", text));
+    }
+    if let Some(blocker) = entries.iter().find(|entry| entry.starts_with("- [Blocker")) {
+        summary.push(format!("- Blocked by: {}", blocker.split_once("] ").map_or(*blocker, |(_, text)| text)));
+    }
+    summary.join("\n")
+}
+
+/// Understands "mark #12 done", "reopen #3", "move #4 to Beta" and
+/// "move all auth tasks to Beta", joined with "and" or commas
+fn operations_response(prompt: &str) -> String {
+    let instruction = prompt.lines().nth(2).unwrap_or("").trim().to_lowercase();
+    let tasks: Vec<&str> = prompt.lines().filter(|line| line.starts_with("- #")).collect();
+    let mut operations = Vec::new();
+    for step in instruction.split([',', ';']).flat_map(|part| part.split(" and ")) {
+        let step = step.trim();
+        if let Some(rest) = step.strip_prefix("move ") {
+            let Some((what, phase)) = rest.rsplit_once(" to ") else { continue };
+            let mut ids = task_ids(what);
+            if ids.is_empty() {
+                let topic = what.trim_start_matches("all ").trim_end_matches(" tasks").trim_end_matches(" task").trim();
+                ids = tasks.iter()
+                    .filter(|task| task.contains("[pending]") && task.to_lowercase().contains(topic))
+                    .flat_map(|task| task_ids(task).into_iter().take(1))
+                    .collect();
+            }
+            let phase = phase.trim();
+            let phase: String = phase.chars().take(1).flat_map(char::to_uppercase).chain(phase.chars().skip(1)).collect();
+            operations.push(serde_json::json!({ "action": "set_phase", "task_ids": ids, "value": phase }));
+        } else if step.starts_with("reopen ") {
+            operations.push(serde_json::json!({ "action": "reopen", "task_ids": task_ids(step) }));
+        } else if step.ends_with(" done") || step.starts_with("complete ") || step.starts_with("finish ") {
+            operations.push(serde_json::json!({ "action": "complete", "task_ids": task_ids(step) }));
+        }
+    }
+    serde_json::Value::Array(operations).to_string()
+}
+
+fn brief_roadmap_response(prompt: &str) -> String {
+    let brief = prompt.lines().nth(2).unwrap_or("the project").trim();
+    let task = |key: &str, description: &str, priority: &str, hours: f64, tag: &str, depends_on: &[&str]| serde_json::json!({
+        "key": key,
+        "description": description,
+        "priority": priority,
+        "estimated_hours": hours,
+        "tags": [tag],
+        "depends_on": depends_on,
+    });
+    serde_json::json!({
+        "title": "Project Plan (mock)",
+        "description": format!("Roadmap for: {}", brief),
+        "phases": [
+            {
+                "name": "MVP",
+                "description": "The smallest version that works end to end",
+                "tasks": [
+                    task("setup", "Set up the repository and CI", "High", 3.0, "setup", &[]),
+                    task("core", "Implement the core workflow", "High", 8.0, "feature", &["setup"]),
+                    task("core-tests", "Add tests for the core workflow", "Medium", 4.0, "testing", &["core"]),
+                ],
+            },
+            {
+                "name": "Beta",
+                "description": "Early users and their feedback",
+                "tasks": [
+                    task("feedback", "Gather feedback from early users", "Medium", 2.0, "feedback", &["core-tests"]),
+                    task("fixes", "Fix the top reported issues", "High", 6.0, "bug", &["feedback", "unknown"]),
+                ],
+            },
+            {
+                "name": "Release",
+                "description": "A first public release",
+                "tasks": [
+                    task("docs", "Write user documentation", "Medium", 4.0, "docs", &["core"]),
+                    task("release", "Publish the first release", "Critical", 2.0, "release", &["fixes", "docs"]),
+                ],
+            },
+        ],
+    }).to_string()
+}
+
+fn templates_response(prompt: &str) -> String {
+    let subject = prompt.lines()
+        .find(|l| !l.trim().is_empty())
+        .unwrap_or("the project")
+        .trim();
+    serde_json::json!([
+        {
+            "name": "Feature Slice",
+            "description": "Implement [FEATURE] end to end",
+            "tags": ["feature", "mock"],
+            "priority": "High",
+            "phase": "MVP",
+            "category": "Feature",
+            "implementation_notes": ["Define the acceptance criteria", "Implement", "Add tests"],
+            "usage_examples": ["Implement user login end to end"],
+            "reasoning": format!("Generated offline for: {}", subject),
+        },
+        {
+            "name": "Bug Fix",
+            "description": "Fix [BUG] and add a regression test",
+            "tags": ["bug", "mock"],
+            "priority": "Medium",
+            "phase": "Beta",
+            "category": "Bug",
+            "implementation_notes": ["Reproduce", "Fix", "Add a regression test"],
+            "usage_examples": ["Fix crash when the config file is missing"],
+            "reasoning": "Every project needs a repeatable bug-fix workflow",
+        }
+    ]).to_string()
+}
+
+fn template_suggestions_response() -> String {
+    serde_json::json!([
+        {
+            "name": "Release Checklist",
+            "description": "Steps to cut and verify a release",
+            "category": "DevOps",
+            "priority": "High",
+            "reasoning": "Releases repeat every phase and benefit from a fixed checklist",
+            "usefulness_score": 80,
+        },
+        {
+            "name": "Spike",
+            "description": "Time-boxed research before committing to an approach",
+            "category": "Research",
+            "priority": "Medium",
+            "reasoning": "Reduces estimate risk on unfamiliar work",
+            "usefulness_score": 65,
+        }
+    ]).to_string()
+}
+
+fn template_enhancement_response(prompt: &str) -> String {
+    let description = prompt.lines()
+        .find_map(|l| l.trim().strip_prefix("- Description: "))
+        .unwrap_or("the task");
+    serde_json::json!({
+        "enhanced_description": format!("{} (with acceptance criteria and tests)", description),
+        "additional_tags": ["mock"],
+        "enhanced_implementation_notes": ["Agree on the acceptance criteria", "Implement in small commits", "Add tests before closing"],
+        "usage_examples": [description],
+        "common_pitfalls": ["Skipping tests", "Leaving the scope open-ended"],
+        "acceptance_criteria": ["Tests pass", "Documentation updated"],
+        "improvements_summary": "Added acceptance criteria, testing steps and pitfalls",
+    }).to_string()
+}
+
+fn standup_response(prompt: &str) -> String {
+    let bullets = |items: &[&str]| if items.is_empty() {
+        "- None".to_string()
+    } else {
+        items.iter().map(|i| format!("- {}", i)).collect::<Vec<_>>().join("\n")
+    };
+    let mut yesterday = section(prompt, "Completed:");
+    let worked = section(prompt, "Worked on, not finished:");
+    yesterday.extend(worked.iter());
+    let mut today = worked.clone();
+    today.extend(section(prompt, "Ready to start next:").into_iter().take(1));
+
+    format!(
+        "Yesterday\n{}\n\nToday\n{}\n\nBlockers\n{}",
+        bullets(&yesterday),
+        bullets(&today),
+        bullets(&section(prompt, "Blocked:"))
+    )
+}
+
+fn plan_response() -> String {
+    "# Project Plan (mock)\n\n\
+     ## MVP Phase\n\
+     - [ ] Set up the repository and CI #setup !high\n\
+     - [ ] Implement the core workflow #feature !high\n\
+     - [ ] Add tests for the core workflow #testing\n\n\
+     ## Beta Phase\n\
+     - [ ] Gather feedback from early users #feedback\n\
+     - [ ] Fix the top reported issues #bug\n\n\
+     ## Release Phase\n\
+     - [ ] Write user documentation #docs\n\
+     - [ ] Publish the first release #release !high\n"
+        .to_string()
+}
+
+fn roadmap_analysis_response(prompt: &str) -> String {
+    let tasks = prompt.lines().filter(|l| l.trim_start().starts_with("- [")).count();
+    format!(
+        "## Overall Assessment (mock)\n\
+         The roadmap lists {} task(s).\n\n\
+         ## Suggestions\n\
+         1. Give every phase a clear exit criterion\n\
+         2. Add estimates to open tasks\n\
+         3. Record dependencies between tasks that must happen in order\n\n\
+         ## Risks\n\
+         - Unestimated work makes the timeline hard to forecast",
+        tasks
+    )
+}
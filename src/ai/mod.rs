@@ -8,12 +8,19 @@
 
 pub mod models;
 pub mod gemini;
+pub mod mock;
+pub mod cache;
 pub mod service;
 
 pub use models::*;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::Stream;
+use std::pin::Pin;
+
+/// Response text delivered in chunks as the provider generates it
+pub type ChatStream = Pin<Box<dyn Stream<Item = Result<String>> + Send>>;
 
 /// Trait defining the AI service interface for different providers
 #[async_trait]
@@ -21,6 +28,20 @@ pub trait AiProvider {
     /// Send a chat message and get a response
     async fn chat(&self, message: &str, context: Option<&str>) -> Result<String>;
     
+    /// Send a chat message and stream the response as it is generated.
+    /// Providers without streaming support deliver the whole response as one chunk.
+    async fn chat_stream(&self, message: &str, context: Option<&str>) -> Result<ChatStream> {
+        let response = self.chat(message, context).await?;
+        Ok(Box::pin(futures::stream::once(async move { Ok(response) })))
+    }
+    
+    /// Turn each text into an embedding vector, for similarity comparisons.
+    /// Providers without an embedding model report that it is unsupported.
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let _ = texts;
+        anyhow::bail!("The {} provider does not support embeddings", self.provider_name())
+    }
+    
     /// Analyze tasks and provide suggestions
     async fn analyze_tasks(&self, tasks: &[crate::model::Task]) -> Result<AiTaskAnalysis>;
     
@@ -44,6 +65,7 @@ pub fn create_ai_provider(config: &crate::config::AiConfig) -> Result<Box<dyn Ai
             let provider = gemini::GeminiProvider::new(config)?;
             Ok(Box::new(provider))
         }
+        "mock" => Ok(Box::new(mock::MockProvider)),
         _ => anyhow::bail!("Unsupported AI provider: {}", config.provider),
     }
 }
\ No newline at end of file
@@ -88,6 +88,104 @@ pub struct AiRisk {
     pub affected_areas: Vec<String>,
 }
 
+/// Risk found by `rask ai risks`, tied to the tasks it affects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiTaskRisk {
+    /// Short label such as dependency_chain, blocker, estimate or deadline
+    pub kind: String,
+    
+    /// Severity level (Low, Medium, High, Critical)
+    pub severity: String,
+    
+    /// What could go wrong
+    pub description: String,
+    
+    /// Tasks the risk applies to
+    #[serde(default)]
+    pub task_ids: Vec<usize>,
+    
+    /// Suggested way to reduce the risk
+    #[serde(default)]
+    pub mitigation: String,
+}
+
+impl AiTaskRisk {
+    /// Severity as a number for sorting, Critical highest
+    pub fn severity_rank(&self) -> u8 {
+        match self.severity.to_lowercase().as_str() {
+            "critical" => 3,
+            "high" => 2,
+            "medium" => 1,
+            _ => 0,
+        }
+    }
+}
+
+/// One step of the plan `rask ai do` makes from an instruction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiOperation {
+    /// complete, reopen, set_phase, set_priority, add_tags, remove_tags,
+    /// assign, unassign, edit or add_task
+    pub action: String,
+
+    /// Tasks the step applies to
+    #[serde(default)]
+    pub task_ids: Vec<usize>,
+
+    /// Phase, priority, comma-separated tags or owners, or a description
+    #[serde(default)]
+    pub value: String,
+}
+
+/// Roadmap generated by `rask ai roadmap` from a project brief
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiRoadmapPlan {
+    /// Project title, used as the `# heading`
+    pub title: String,
+
+    /// One or two sentences on what the project is
+    #[serde(default)]
+    pub description: String,
+
+    /// Phases in the order they should be worked on
+    pub phases: Vec<AiRoadmapPhase>,
+}
+
+/// One phase of a generated roadmap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiRoadmapPhase {
+    pub name: String,
+
+    /// What the phase delivers
+    #[serde(default)]
+    pub description: String,
+
+    pub tasks: Vec<AiRoadmapTask>,
+}
+
+/// One task of a generated roadmap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiRoadmapTask {
+    /// Short unique key that other tasks refer to in `depends_on`
+    pub key: String,
+
+    pub description: String,
+
+    /// Priority level (Low, Medium, High, Critical)
+    #[serde(default)]
+    pub priority: String,
+
+    #[serde(default)]
+    pub estimated_hours: Option<f64>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    /// Keys of the tasks that must be done first
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
 /// Performance insights from AI analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AiPerformanceInsights {
@@ -296,4 +394,17 @@ pub struct AiTemplateEnhancement {
     
     /// Summary of improvements made
     pub improvements_summary: String,
-}
\ No newline at end of file
+}
+
+/// AI effort estimate for a single task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AiEstimateSuggestion {
+    /// Suggested estimate in hours
+    pub estimated_hours: f64,
+    
+    /// Confidence in the estimate (0-100)
+    pub confidence: u8,
+    
+    /// Reasoning, including how past estimation accuracy was taken into account
+    pub reasoning: String,
+}
@@ -1,19 +1,25 @@
 //! AI service orchestration and management
 
 use anyhow::Result;
+use futures::StreamExt;
+use serde::{de::DeserializeOwned, Serialize};
+use std::future::Future;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::config::RaskConfig;
 use crate::model::{Task, Roadmap};
 use super::{AiProvider, AiChatContext, AiTaskAnalysis, AiTaskSuggestion, AiProjectInsights, create_ai_provider};
-use super::models::{AiTemplateGeneration, AiTemplateSuggestion, AiTemplateEnhancement};
+use super::cache::{RateLimiter, ResponseCache};
+use super::models::{AiTemplateGeneration, AiTemplateSuggestion, AiTemplateEnhancement, AiEstimateSuggestion, AiTaskRisk, AiRoadmapPlan, AiOperation};
 
 /// High-level AI service that manages providers and conversations
 pub struct AiService {
     provider: Arc<dyn AiProvider + Send + Sync>,
     config: RaskConfig,
     current_context: Arc<RwLock<Option<AiChatContext>>>,
+    cache: ResponseCache,
+    limiter: RateLimiter,
 }
 
 impl AiService {
@@ -27,11 +33,38 @@ impl AiService {
         
         Ok(Self {
             provider: Arc::from(provider),
+            cache: ResponseCache::new(config.ai.cache_ttl_minutes),
+            limiter: RateLimiter::new(config.ai.requests_per_minute),
             config,
             current_context: Arc::new(RwLock::new(None)),
         })
     }
 
+    /// Return a cached result for this operation and input, or fetch and cache it.
+    /// Requests that reach the provider go through the rate limiter.
+    async fn cached<T, F, Fut>(&self, operation: &str, input: &str, fetch: F) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let provider = self.provider.provider_name();
+        let model = &self.config.ai.default_model;
+        if let Some(value) = self.cache.get(provider, model, operation, input) {
+            tracing::debug!(operation, "AI response served from cache");
+            return Ok(value);
+        }
+        self.limiter.acquire().await?;
+        let value = fetch().await?;
+        self.cache.put(provider, model, operation, input, &value);
+        Ok(value)
+    }
+
+    /// Send a one-off prompt, using the response cache
+    async fn complete(&self, operation: &str, prompt: &str) -> Result<String> {
+        self.cached(operation, prompt, || self.provider.chat(prompt, None)).await
+    }
+
     /// Check if the AI service is ready to use
     pub fn is_ready(&self) -> bool {
         self.config.ai.is_ready() && self.provider.is_ready()
@@ -65,7 +98,8 @@ impl AiService {
             current_context.as_ref().and_then(|ctx| ctx.project_context.clone())
         };
 
-        // Get AI response
+        // Get AI response; conversations are never cached
+        self.limiter.acquire().await?;
         let response = self.provider.chat(&message, context_for_ai.as_deref()).await?;
 
         // Update conversation history
@@ -80,6 +114,34 @@ impl AiService {
         Ok(response)
     }
 
+    /// Send a chat message, calling `on_chunk` with each piece of the response
+    /// as it arrives. Returns the full response once the stream ends.
+    pub async fn chat_stream(&self, message: String, mut on_chunk: impl FnMut(&str)) -> Result<String> {
+        let context_for_ai = {
+            let current_context = self.current_context.read().await;
+            current_context.as_ref().and_then(|ctx| ctx.project_context.clone())
+        };
+
+        self.limiter.acquire().await?;
+        let mut stream = self.provider.chat_stream(&message, context_for_ai.as_deref()).await?;
+        let mut response = String::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            on_chunk(&chunk);
+            response.push_str(&chunk);
+        }
+
+        {
+            let mut current_context = self.current_context.write().await;
+            if let Some(ref mut ctx) = *current_context {
+                ctx.add_user_message(message);
+                ctx.add_ai_response(response.clone(), None);
+            }
+        }
+
+        Ok(response)
+    }
+
     /// Get the current chat context
     pub async fn get_chat_context(&self) -> Option<AiChatContext> {
         let current_context = self.current_context.read().await;
@@ -94,17 +156,25 @@ impl AiService {
 
     /// Analyze tasks and get AI insights
     pub async fn analyze_tasks(&self, tasks: &[Task]) -> Result<AiTaskAnalysis> {
-        self.provider.analyze_tasks(tasks).await
+        let input = serde_json::to_string(tasks)?;
+        self.cached("analyze_tasks", &input, || self.provider.analyze_tasks(tasks)).await
     }
 
     /// Generate task breakdown from a description
     pub async fn generate_task_breakdown(&self, description: &str) -> Result<Vec<AiTaskSuggestion>> {
-        self.provider.generate_task_breakdown(description).await
+        self.cached("task_breakdown", description, || self.provider.generate_task_breakdown(description)).await
+    }
+
+    /// Embedding vector for each text, in the same order
+    pub async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let input = serde_json::to_string(texts)?;
+        self.cached("embed", &input, || self.provider.embed(texts)).await
     }
 
     /// Get project insights
     pub async fn get_project_insights(&self, roadmap: &Roadmap) -> Result<AiProjectInsights> {
-        self.provider.get_project_insights(roadmap).await
+        let input = serde_json::to_string(roadmap)?;
+        self.cached("project_insights", &input, || self.provider.get_project_insights(roadmap)).await
     }
 
     /// Quick task suggestion based on current project state
@@ -177,7 +247,7 @@ impl AiService {
             context.unwrap_or_else(|| "No project context available".to_string())
         );
 
-        let response = self.provider.chat(&prompt, None).await?;
+        let response = self.complete("generate_templates", &prompt).await?;
         
         // Parse JSON response
         let templates: Vec<AiTemplateGeneration> = serde_json::from_str(&response)
@@ -214,7 +284,7 @@ impl AiService {
             limit
         );
 
-        let response = self.provider.chat(&prompt, None).await?;
+        let response = self.complete("suggest_templates", &prompt).await?;
         
         let suggestions: Vec<AiTemplateSuggestion> = serde_json::from_str(&response)
             .map_err(|e| anyhow::anyhow!("Failed to parse AI template suggestions: {}", e))?;
@@ -267,7 +337,7 @@ impl AiService {
             context.unwrap_or_else(|| "No project context available".to_string())
         );
 
-        let response = self.provider.chat(&prompt, None).await?;
+        let response = self.complete("enhance_template", &prompt).await?;
         
         let enhancement: AiTemplateEnhancement = serde_json::from_str(&response)
             .map_err(|e| anyhow::anyhow!("Failed to parse AI template enhancement: {}", e))?;
@@ -275,6 +345,215 @@ impl AiService {
         Ok(enhancement)
     }
     
+    /// Suggest an effort estimate for a task, calibrated by the project's past estimation accuracy
+    pub async fn suggest_estimate(&self, task: &Task, roadmap: &Roadmap) -> Result<AiEstimateSuggestion> {
+        let project_context = utils::create_project_context(roadmap);
+        let history = utils::create_estimate_history(roadmap, task.id);
+        
+        let prompt = format!(
+            "Estimate the effort for this task in hours.\n\n\
+            Task #{}: {}\n\
+            - Phase: {}\n\
+            - Priority: {}\n\
+            - Tags: {}\n\
+            - Notes: {}\n\
+            - Current estimate: {}\n\n\
+            Project: {}\n\n\
+            Past estimates compared with tracked time (variance = actual vs. estimate):\n{}\n\n\
+            Use the variance history to correct for this team's usual bias: if similar tasks \
+            ran over their estimates, estimate higher, and lower your confidence when the \
+            history is short or inconsistent.\n\n\
+            Respond with JSON only:\n\
+            {{\n\
+              \"estimated_hours\": 4.5,\n\
+              \"confidence\": 70,\n\
+              \"reasoning\": \"Why this estimate, mentioning the historical variance used\"\n\
+            }}",
+            task.id,
+            task.description,
+            task.phase.name,
+            task.priority,
+            if task.tags.is_empty() { "None".to_string() } else { task.tags.iter().cloned().collect::<Vec<_>>().join(", ") },
+            task.notes.as_deref().unwrap_or("None"),
+            task.estimated_hours.map(|h| format!("{}h", h)).unwrap_or_else(|| "None".to_string()),
+            project_context,
+            history
+        );
+
+        let response = self.complete("suggest_estimate", &prompt).await?;
+        let json = response.trim().trim_start_matches("```json").trim_start_matches("```").trim_end_matches("```").trim();
+        
+        let mut suggestion: AiEstimateSuggestion = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse AI estimate: {}", e))?;
+        if !(suggestion.estimated_hours.is_finite() && suggestion.estimated_hours > 0.0) {
+            anyhow::bail!("AI returned an invalid estimate: {}h", suggestion.estimated_hours);
+        }
+        suggestion.confidence = suggestion.confidence.min(100);
+        
+        Ok(suggestion)
+    }
+    
+    /// Identify schedule risks from the dependency graph, estimates and due dates,
+    /// most severe first
+    pub async fn identify_risks(&self, roadmap: &Roadmap) -> Result<Vec<AiTaskRisk>> {
+        let prompt = format!(
+            "Identify the main delivery risks in this project plan.\n\n\
+            {}\n\n\
+            {}\n\n\
+            Look for long dependency chains, single tasks that block many others, critical or \
+            high priority work that is unestimated or likely under-estimated given the estimate \
+            history, and deadlines that the remaining work does not fit. Only report risks \
+            supported by the data, each tied to the task numbers it affects.\n\n\
+            Respond with a JSON array only:\n\
+            [\n\
+              {{\n\
+                \"kind\": \"dependency_chain | blocker | estimate | deadline\",\n\
+                \"severity\": \"Low | Medium | High | Critical\",\n\
+                \"description\": \"What could go wrong\",\n\
+                \"task_ids\": [3, 7],\n\
+                \"mitigation\": \"How to reduce the risk\"\n\
+              }}\n\
+            ]",
+            utils::create_project_context(roadmap),
+            utils::create_risk_context(roadmap)
+        );
+
+        let response = self.complete("identify_risks", &prompt).await?;
+        let json = response.trim().trim_start_matches("```json").trim_start_matches("```").trim_end_matches("```").trim();
+        
+        let mut risks: Vec<AiTaskRisk> = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse AI risks: {}", e))?;
+        // Task numbers the model made up are dropped
+        for risk in &mut risks {
+            risk.task_ids.retain(|id| roadmap.find_task_by_id(*id).is_some());
+        }
+        risks.sort_by_key(|risk| std::cmp::Reverse(risk.severity_rank()));
+        
+        Ok(risks)
+    }
+    
+    /// Write a short daily-standup update from a structured activity summary
+    pub async fn generate_standup(&self, activity: &str) -> Result<String> {
+        let prompt = format!(
+            "Write a short daily standup update for a developer, based only on the activity below.\n\
+            Use exactly three sections with these headings: Yesterday, Today, Blockers.\n\
+            Keep it under 120 words, use short bullet points starting with '- ', mention task \
+            numbers like #12, and write \"None\" under a heading with nothing to report. \
+            Do not invent work that is not listed.\n\n{}",
+            activity
+        );
+
+        let response = self.complete("generate_standup", &prompt).await?;
+        Ok(response.trim().to_string())
+    }
+    
+    /// Condense a task's notes, implementation notes and comments into a short summary
+    pub async fn summarize_task_notes(&self, task: &Task) -> Result<String> {
+        let prompt = format!(
+            "Summarize the notes on this task for someone picking it up.\n\
+            Write at most five short bullet points starting with '- ', covering the current \
+            state, decisions made, open blockers and what is left. Mention names and dates only \
+            when they matter, and do not invent anything that is not in the notes.\n\n{}",
+            utils::create_notes_context(task)
+        );
+
+        let response = self.complete("summarize_task_notes", &prompt).await?;
+        Ok(response.trim().to_string())
+    }
+
+    /// Turn a natural-language instruction into task operations
+    pub async fn plan_operations(&self, roadmap: &Roadmap, instruction: &str) -> Result<Vec<AiOperation>> {
+        let prompt = format!(
+            "Turn this instruction into operations on the project's tasks:\n\n{}\n\n\
+            {}\n\n\
+            {}\n\n\
+            Available actions, each on the task numbers in task_ids:\n\
+            - complete: mark the tasks done\n\
+            - reopen: mark completed tasks pending again\n\
+            - set_phase: move the tasks to the phase in value\n\
+            - set_priority: set the priority in value (low, medium, high or critical)\n\
+            - add_tags / remove_tags: comma-separated tags in value\n\
+            - assign / unassign: comma-separated owners in value\n\
+            - edit: set the description of a single task to value\n\
+            - add_task: create a task described by value, with no task_ids\n\n\
+            Only use task numbers from the list, and only do what the instruction asks. \
+            Respond with a JSON array only, in the order the steps should run:\n\
+            [\n\
+              {{\n\
+                \"action\": \"set_phase\",\n\
+                \"task_ids\": [3, 7],\n\
+                \"value\": \"Beta\"\n\
+              }}\n\
+            ]",
+            instruction.trim(),
+            utils::create_project_context(roadmap),
+            utils::create_task_list_context(roadmap)
+        );
+
+        let response = self.complete("plan_operations", &prompt).await?;
+        let json = response.trim().trim_start_matches("```json").trim_start_matches("```").trim_end_matches("```").trim();
+
+        let mut operations: Vec<AiOperation> = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse AI plan: {}", e))?;
+        // Task numbers the model made up are dropped
+        for operation in &mut operations {
+            operation.task_ids.retain(|id| roadmap.find_task_by_id(*id).is_some());
+            operation.task_ids.dedup();
+        }
+
+        Ok(operations)
+    }
+
+    /// Plan a phased roadmap for a new project from a short brief
+    pub async fn generate_roadmap_from_brief(&self, brief: &str) -> Result<AiRoadmapPlan> {
+        let prompt = format!(
+            "Plan a phased roadmap for a new software project from this brief:\n\n{}\n\n\
+            Split the work into 3 to 6 phases in the order they should happen, each with \
+            specific, actionable tasks of at most a day or two. Give every task a short unique \
+            key, and list in depends_on the keys of the tasks that must be finished before it \
+            can start. A task may only depend on tasks listed before it.\n\n\
+            Respond with a JSON object only:\n\
+            {{\n\
+              \"title\": \"Project title\",\n\
+              \"description\": \"One or two sentences on the project\",\n\
+              \"phases\": [\n\
+                {{\n\
+                  \"name\": \"MVP\",\n\
+                  \"description\": \"What the phase delivers\",\n\
+                  \"tasks\": [\n\
+                    {{\n\
+                      \"key\": \"setup-repo\",\n\
+                      \"description\": \"Task description\",\n\
+                      \"priority\": \"Low | Medium | High | Critical\",\n\
+                      \"estimated_hours\": 4,\n\
+                      \"tags\": [\"setup\"],\n\
+                      \"depends_on\": []\n\
+                    }}\n\
+                  ]\n\
+                }}\n\
+              ]\n\
+            }}",
+            brief.trim()
+        );
+
+        let response = self.complete("generate_roadmap_from_brief", &prompt).await?;
+        let json = response.trim().trim_start_matches("```json").trim_start_matches("```").trim_end_matches("```").trim();
+
+        let mut plan: AiRoadmapPlan = serde_json::from_str(json)
+            .map_err(|e| anyhow::anyhow!("Failed to parse AI roadmap: {}", e))?;
+        plan.phases.retain(|phase| !phase.tasks.is_empty());
+        // Dependencies may only point back at earlier tasks, which keeps the
+        // generated graph free of cycles and of keys the model made up
+        let mut seen = std::collections::HashSet::new();
+        for task in plan.phases.iter_mut().flat_map(|phase| phase.tasks.iter_mut()) {
+            task.depends_on.retain(|key| seen.contains(key));
+            task.depends_on.dedup();
+            seen.insert(task.key.clone());
+        }
+
+        Ok(plan)
+    }
+
     /// Generate or analyze a project roadmap with AI suggestions
     pub async fn generate_project_roadmap(&self, roadmap: &Roadmap, file: Option<&str>, focus: Option<&str>, generate_plan: bool) -> Result<String> {
         let project_context = utils::create_project_context(roadmap);
@@ -348,7 +627,7 @@ impl AiService {
             )
         };
 
-        let response = self.provider.chat(&prompt, None).await?;
+        let response = self.complete("generate_project_roadmap", &prompt).await?;
         Ok(response)
     }
 }
@@ -437,4 +716,180 @@ pub mod utils {
             roadmap.get_all_phases().iter().map(|p| p.name.as_str()).collect::<Vec<_>>().join(", ")
         )
     }
-}
\ No newline at end of file
+
+    /// A task's description, notes, implementation notes and comments,
+    /// oldest first within each kind
+    pub fn create_notes_context(task: &Task) -> String {
+        let date = |at: Option<&str>| at.and_then(|at| at.get(..10)).unwrap_or("undated").to_string();
+        let mut sections = vec![format!("Task #{}: {}", task.id, task.description)];
+        if let Some(notes) = task.notes.as_deref().filter(|notes| !notes.trim().is_empty()) {
+            sections.push(format!("Notes:\n{}", notes.trim()));
+        }
+        if !task.implementation_notes.is_empty() {
+            let notes: Vec<String> = task.implementation_notes.iter()
+                .map(|note| format!("- [{}, {}] {}", note.kind, date(note.created_at.as_deref()), note.text.trim()))
+                .collect();
+            sections.push(format!("Implementation notes:\n{}", notes.join("\n")));
+        }
+        if !task.comments.is_empty() {
+            let comments: Vec<String> = task.comments.iter()
+                .map(|comment| format!("- [{}, {}] {}", comment.author, date(Some(&comment.created_at)), comment.text.trim()))
+                .collect();
+            sections.push(format!("Comments:\n{}", comments.join("\n")));
+        }
+        sections.join("\n\n")
+    }
+
+    /// Every task with its status, phase, priority, tags and owners, so
+    /// instructions can refer to tasks by any of them
+    pub fn create_task_list_context(roadmap: &crate::model::Roadmap) -> String {
+        let tasks: Vec<String> = roadmap.tasks.iter().take(200).map(|t| {
+            let mut facts = vec![format!("phase: {}", t.phase.name), format!("priority: {}", t.priority)];
+            if !t.tags.is_empty() {
+                let mut tags: Vec<&str> = t.tags.iter().map(String::as_str).collect();
+                tags.sort_unstable();
+                facts.push(format!("tags: {}", tags.join(", ")));
+            }
+            if !t.owners.is_empty() {
+                facts.push(format!("owners: {}", t.owners.join(", ")));
+            }
+            let status = if t.status == crate::model::TaskStatus::Completed { "done" } else { "pending" };
+            format!("- #{} [{}] {} ({})", t.id, status, t.description, facts.join("; "))
+        }).collect();
+        format!("Tasks:\n{}", if tasks.is_empty() { "- none".to_string() } else { tasks.join("\n") })
+    }
+
+    /// Longest chain of open tasks where each depends on the one before,
+    /// as task IDs from first to last
+    fn longest_open_chain(roadmap: &crate::model::Roadmap) -> Vec<usize> {
+        use std::collections::HashMap;
+        fn chain_to(roadmap: &crate::model::Roadmap, id: usize, memo: &mut HashMap<usize, Vec<usize>>, visiting: &mut Vec<usize>) -> Vec<usize> {
+            if let Some(chain) = memo.get(&id) {
+                return chain.clone();
+            }
+            // Cycles are reported by `rask dependencies`; here they just end the chain
+            if visiting.contains(&id) {
+                return Vec::new();
+            }
+            visiting.push(id);
+            let mut longest = Vec::new();
+            if let Some(task) = roadmap.find_task_by_id(id) {
+                for dep in &task.dependencies {
+                    let open = roadmap.find_task_by_id(*dep).is_some_and(|t| t.status == crate::model::TaskStatus::Pending);
+                    if open {
+                        let chain = chain_to(roadmap, *dep, memo, visiting);
+                        if chain.len() > longest.len() {
+                            longest = chain;
+                        }
+                    }
+                }
+            }
+            visiting.pop();
+            longest.push(id);
+            memo.insert(id, longest.clone());
+            longest
+        }
+
+        let mut memo = HashMap::new();
+        roadmap.tasks.iter()
+            .filter(|t| t.status == crate::model::TaskStatus::Pending)
+            .map(|t| chain_to(roadmap, t.id, &mut memo, &mut Vec::new()))
+            .max_by_key(|chain| chain.len())
+            .unwrap_or_default()
+    }
+
+    /// Describe open tasks, dependencies, estimates and deadlines for risk prompts
+    pub fn create_risk_context(roadmap: &crate::model::Roadmap) -> String {
+        use crate::model::{Priority, TaskStatus};
+        let pending: Vec<&Task> = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Pending).collect();
+        let list = |lines: Vec<String>| if lines.is_empty() { "- none".to_string() } else { lines.join("\n") };
+        let mut sections = Vec::new();
+
+        let tasks: Vec<String> = pending.iter().take(60).map(|t| {
+            let mut facts = vec![t.phase.name.clone(), t.priority.to_string()];
+            facts.push(t.estimated_hours.map_or("no estimate".to_string(), |h| format!("estimate {:.1}h", h)));
+            let tracked = t.get_total_tracked_hours();
+            if tracked > 0.0 {
+                facts.push(format!("tracked {:.1}h", tracked));
+            }
+            if let Some(due) = &t.due_date {
+                facts.push(format!("due {}", due));
+            }
+            let deps = if t.dependencies.is_empty() {
+                String::new()
+            } else {
+                format!(" depends on {}", t.dependencies.iter().map(|d| format!("#{}", d)).collect::<Vec<_>>().join(" "))
+            };
+            format!("- #{} {} [{}]{}", t.id, t.description, facts.join(", "), deps)
+        }).collect();
+        sections.push(format!("Open tasks:\n{}", list(tasks)));
+
+        let chain = longest_open_chain(roadmap);
+        let chain = if chain.len() > 1 {
+            vec![format!("- {}", chain.iter().map(|id| format!("#{}", id)).collect::<Vec<_>>().join(" -> "))]
+        } else {
+            Vec::new()
+        };
+        sections.push(format!("Longest open dependency chain:\n{}", list(chain)));
+
+        let mut blockers: Vec<(usize, Vec<usize>)> = pending.iter()
+            .map(|t| (t.id, roadmap.get_dependents(t.id).into_iter()
+                .filter(|id| roadmap.find_task_by_id(*id).is_some_and(|d| d.status == TaskStatus::Pending))
+                .collect::<Vec<_>>()))
+            .filter(|(_, dependents)| dependents.len() >= 2)
+            .collect();
+        blockers.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then(a.0.cmp(&b.0)));
+        let blockers: Vec<String> = blockers.iter().take(5)
+            .map(|(id, dependents)| format!("- #{} blocks {}", id, dependents.iter().map(|d| format!("#{}", d)).collect::<Vec<_>>().join(" ")))
+            .collect();
+        sections.push(format!("Open tasks blocking two or more others:\n{}", list(blockers)));
+
+        let estimates: Vec<String> = pending.iter()
+            .filter(|t| matches!(t.priority, Priority::Critical | Priority::High))
+            .filter_map(|t| match t.estimated_hours {
+                None => Some(format!("- #{} {} has no estimate", t.id, t.description)),
+                Some(estimate) if t.get_total_tracked_hours() > estimate => Some(format!(
+                    "- #{} {} already took {:.1}h of a {:.1}h estimate", t.id, t.description, t.get_total_tracked_hours(), estimate)),
+                Some(_) => None,
+            })
+            .collect();
+        sections.push(format!("Critical or high priority estimate concerns:\n{}", list(estimates)));
+
+        let history = create_estimate_history(roadmap, 0);
+        let summary = history.lines().last().unwrap_or(&history).to_string();
+        sections.push(format!("Estimate history: {}", summary));
+
+        sections.join("\n\n")
+    }
+
+    /// Summarize estimated-vs-actual variance of finished tasks for estimate prompts
+    pub fn create_estimate_history(roadmap: &crate::model::Roadmap, exclude_id: usize) -> String {
+        let mut finished: Vec<&Task> = roadmap.tasks.iter()
+            .filter(|t| t.id != exclude_id && matches!(t.status, crate::model::TaskStatus::Completed))
+            .filter(|t| t.get_time_variance_percentage().is_some())
+            .collect();
+        if finished.is_empty() {
+            return "No finished tasks have both an estimate and tracked time yet.".to_string();
+        }
+        finished.sort_by(|a, b| b.completed_at.cmp(&a.completed_at));
+
+        let variances: Vec<f64> = finished.iter().filter_map(|t| t.get_time_variance_percentage()).collect();
+        let average = variances.iter().sum::<f64>() / variances.len() as f64;
+        let lines = finished.iter()
+            .take(20)
+            .map(|t| format!(
+                "- #{} {} [{}{}]: estimated {:.1}h, actual {:.1}h, variance {:+.0}%",
+                t.id,
+                t.description,
+                t.phase.name,
+                if t.tags.is_empty() { String::new() } else { format!(" #{}", t.tags.iter().cloned().collect::<Vec<_>>().join(" #")) },
+                t.estimated_hours.unwrap_or_default(),
+                t.actual_hours.unwrap_or_default(),
+                t.get_time_variance_percentage().unwrap_or_default()
+            ))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!("{}\nAverage variance across {} task(s): {:+.0}%", lines, variances.len(), average)
+    }
+}
@@ -1,5 +1,7 @@
-use clap::{Parser as ClapParser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser as ClapParser, Subcommand};
 use std::path::PathBuf;
+use crate::sorting::SortKey;
+use crate::commands::timeline::EventKind;
 
 // Import all the modularized CLI components
 pub mod ai;
@@ -9,15 +11,37 @@ pub mod config;
 pub mod notes;
 pub mod bulk;
 pub mod template;
+pub mod import;
+pub mod git;
+pub mod github;
+pub mod time;
+pub mod dependency;
+pub mod report;
+pub mod rules;
+pub mod workspace;
+pub mod milestone;
+pub mod notify;
+pub mod sync;
 
 // Re-export the types for easier access
 pub use ai::AiCommands;
-pub use types::{CliPriority, ExportFormat};
+pub use types::{CliEnergy, CliPriority, ExportFormat, RuleTestEvent, TemplateConflict, TimeTracker, TimesheetGroup};
 pub use phase::PhaseCommands;
 pub use config::ConfigCommands;
 pub use notes::NotesCommands;
-pub use bulk::BulkCommands;
-pub use template::TemplateCommands;
+pub use bulk::{BulkCommands, TaskSelection};
+pub use template::{TemplateCommands, TemplatePackCommands};
+pub use import::ImportCommands;
+pub use git::GitCommands;
+pub use github::GithubCommands;
+pub use time::TimeCommands;
+pub use dependency::DependencyCommands;
+pub use report::ReportCommands;
+pub use rules::RulesCommands;
+pub use workspace::WorkspaceCommands;
+pub use milestone::MilestoneCommands;
+pub use notify::NotifyCommands;
+pub use sync::SyncCommands;
 
 /// Main CLI structure for the Rask application
 #[derive(ClapParser)]
@@ -31,6 +55,50 @@ pub use template::TemplateCommands;
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
+
+    /// Never prompt; commands that need input fail instead of waiting on stdin
+    #[arg(long, global = true, help = "Fail instead of prompting (also enabled by RASK_NON_INTERACTIVE=1)")]
+    pub non_interactive: bool,
+
+    /// Answer yes to every confirmation prompt
+    #[arg(short = 'y', long, global = true, help = "Automatically confirm all prompts")]
+    pub yes: bool,
+
+    /// Print read commands as JSON for scripts and editor integrations
+    #[arg(long, global = true, help = "Print JSON instead of formatted text (show, list, view, dependencies, time, analytics, phase overview)")]
+    pub json: bool,
+
+    /// Only print errors and the output a script asked for
+    #[arg(short = 'q', long, global = true, help = "Suppress informational output; errors still go to stderr")]
+    pub quiet: bool,
+
+    /// Stable, uncolored output for scripts
+    #[arg(long, global = true, help = "Print task lists as tab-separated lines (id, status, priority, phase, tags, description) and only the new ID from add; implies --quiet")]
+    pub porcelain: bool,
+
+    /// Print without colors
+    #[arg(long, global = true, help = "Disable colored output (also enabled by the NO_COLOR environment variable)")]
+    pub no_color: bool,
+
+    /// Screen-reader friendly output
+    #[arg(long, global = true, help = "Plain text output for screen readers: labels like [DONE] and [HIGH PRIORITY] instead of emoji, color and box drawing (also ui.accessible)")]
+    pub accessible: bool,
+
+    /// Run this one command in another project without switching to it
+    #[arg(long, value_name = "PROJECT", help = "Project to run this command in, given before the command: a name registered with 'rask config set projects.<name> <dir>', or a directory containing .rask")]
+    pub project: Option<String>,
+
+    /// Overlay a named profile from the user config
+    #[arg(long, global = true, value_name = "NAME", help = "Use the [profiles.<name>] settings from config.toml for this command (also RASK_PROFILE)")]
+    pub profile: Option<String>,
+
+    /// Show diagnostics on stderr; repeat for more detail
+    #[arg(short = 'v', long, global = true, action = clap::ArgAction::Count, help = "Log what rask is doing to stderr (-v for info, -vv for debug, -vvv for trace)")]
+    pub verbose: u8,
+
+    /// Name of the subcommand that was run, for logging
+    #[arg(skip)]
+    pub command_name: String,
 }
 
 /// Available commands for the Rask CLI
@@ -61,6 +129,18 @@ pub enum Commands {
         /// Collapse completed phases to focus on active work
         #[arg(long, help = "Collapse completed phases to reduce visual clutter")]
         collapse_completed: bool,
+
+        /// Order of the tasks
+        #[arg(long, value_enum, value_name = "ORDER", help = "Sort tasks by this key (default: ui.default_sort from config)")]
+        sort: Option<SortKey>,
+
+        /// Reverse the sort order
+        #[arg(long, help = "Reverse the sort order")]
+        reverse: bool,
+
+        /// Include tasks that are snoozed
+        #[arg(long, help = "Also show snoozed tasks")]
+        include_snoozed: bool,
     },
     
     /// Mark a task as completed
@@ -68,7 +148,11 @@ pub enum Commands {
     Complete { 
         /// ID of the task to mark as complete
         #[arg(value_name = "TASK_ID", help = "The ID number of the task to complete")]
-        id: usize 
+        id: usize,
+
+        /// Skip the acceptance criteria checklist
+        #[arg(long, help = "Complete without confirming each acceptance criterion")]
+        force: bool,
     },
 
     /// Add a new task to the project with optional metadata
@@ -100,6 +184,30 @@ pub enum Commands {
         /// Estimated time to complete the task in hours
         #[arg(long, value_name = "HOURS", help = "Estimated time to complete the task in hours (e.g., 2.5)")]
         estimated_hours: Option<f64>,
+
+        /// Due date for the task
+        #[arg(long, value_name = "DATE", help = "Due date (YYYY-MM-DD, today, or tomorrow)")]
+        due: Option<String>,
+
+        /// Acceptance criteria that must be confirmed before completion
+        #[arg(long = "criteria", value_name = "CRITERION", help = "Acceptance criterion (repeat the flag for several)")]
+        criteria: Vec<String>,
+
+        /// People responsible for the task (comma-separated)
+        #[arg(long = "owner", value_name = "NAMES", help = "Comma-separated task owners (e.g., alice,bob)")]
+        owner: Option<String>,
+
+        /// Energy the task needs
+        #[arg(long, value_enum, value_name = "ENERGY", help = "Energy the task needs: low, medium, high")]
+        energy: Option<CliEnergy>,
+
+        /// Contexts where the task can be done (comma-separated)
+        #[arg(long = "context", value_name = "CONTEXTS", help = "Comma-separated contexts (e.g., @home,@errand)")]
+        context: Option<String>,
+
+        /// Keep the task out of the markdown roadmap file
+        #[arg(long, help = "Track the task in Rask only, without writing it to the roadmap markdown")]
+        no_markdown: bool,
     },
 
     /// 🚀 Quick task creation with natural language parsing
@@ -108,6 +216,10 @@ pub enum Commands {
         /// Natural language task description with embedded metadata
         #[arg(value_name = "TEXT", help = "Natural language task (e.g., 'Fix login bug high priority backend tomorrow')")]
         text: String,
+        
+        /// Show what would be created without adding the task
+        #[arg(long, help = "Preview the parsed task without adding it")]
+        dry_run: bool,
     },
 
     /// Remove a task from the project
@@ -117,6 +229,17 @@ pub enum Commands {
         id: usize
     },
 
+    /// Fold one task into another and remove it
+    Merge {
+        /// ID of the task to keep
+        #[arg(value_name = "KEEP_ID", help = "The ID number of the task to keep")]
+        keep_id: usize,
+
+        /// ID of the task folded into it and removed
+        #[arg(value_name = "MERGE_ID", help = "The ID number of the task to merge in and remove")]
+        merge_id: usize,
+    },
+
     /// Edit the description of an existing task
     Edit {
         /// ID of the task to edit
@@ -124,7 +247,23 @@ pub enum Commands {
         id: usize,
         /// New description for the task
         #[arg(value_name = "DESCRIPTION", help = "The new description for the task")]
-        description: String
+        description: Option<String>,
+
+        /// Owners to add to the task (comma-separated)
+        #[arg(long = "owner", value_name = "NAMES", help = "Comma-separated owners to add to the task")]
+        owner: Option<String>,
+
+        /// Remove all owners before adding any given with --owner
+        #[arg(long, help = "Clear the task's owners")]
+        clear_owners: bool,
+
+        /// Energy the task needs
+        #[arg(long, value_enum, value_name = "ENERGY", help = "Set the energy the task needs: low, medium, high")]
+        energy: Option<CliEnergy>,
+
+        /// Contexts where the task can be done (comma-separated, replaces existing)
+        #[arg(long = "context", value_name = "CONTEXTS", help = "Replace the task's contexts (e.g., @home,@office)")]
+        context: Option<String>,
     },
 
     /// Reset task(s) to pending status
@@ -157,13 +296,46 @@ pub enum Commands {
         #[arg(long, value_name = "QUERY", help = "Search for text in task descriptions and notes")]
         search: Option<String>,
         
+        /// Filter by owner
+        #[arg(long, value_name = "NAME", conflicts_with = "mine", help = "Show only tasks owned by this person")]
+        owner: Option<String>,
+
+        /// Show only tasks owned by the configured user
+        #[arg(long, help = "Show only your tasks (uses user.name from config, then git)")]
+        mine: bool,
+
+        /// Show only pending tasks idle for at least this many days
+        #[arg(long, value_name = "DAYS", help = "Show only pending tasks with no activity for at least DAYS days")]
+        stale: Option<u32>,
+        
         /// Show detailed information including notes
         #[arg(long, help = "Show detailed task information including notes and dependencies")]
         detailed: bool,
+
+        /// List tasks from every project in the workspace
+        #[arg(long, help = "Query every project in the current workspace (see 'rask workspace')")]
+        all_projects: bool,
+
+        /// Order of the tasks
+        #[arg(long, value_enum, value_name = "ORDER", help = "Sort tasks by this key (default: ui.default_sort from config)")]
+        sort: Option<SortKey>,
+
+        /// Reverse the sort order
+        #[arg(long, help = "Reverse the sort order")]
+        reverse: bool,
+
+        /// Include tasks that are snoozed
+        #[arg(long, help = "Also show snoozed tasks")]
+        include_snoozed: bool,
+
+        /// Group tasks by tag namespace
+        #[arg(long, value_name = "NAMESPACE", num_args = 0..=1, default_missing_value = "", conflicts_with = "all_projects", help = "Group tasks by top-level tag namespace, or by the tags nested under NAMESPACE (e.g. --group-tags area)")]
+        group_tags: Option<String>,
     },
 
 
     /// Analyze and visualize task dependencies
+    #[command(alias = "dep")]
     Dependencies {
         /// Show dependency tree for a specific task
         #[arg(long, value_name = "TASK_ID", help = "Show dependency tree for a specific task")]
@@ -180,11 +352,72 @@ pub enum Commands {
         /// Show tasks blocked by dependencies
         #[arg(long, help = "Show tasks blocked by incomplete dependencies")]
         show_blocked: bool,
+        
+        /// Suggest an execution order for pending tasks
+        #[arg(long, help = "Show a dependency-respecting execution order for pending tasks")]
+        order: bool,
+        
+        /// Renumber tasks to match the suggested order
+        #[arg(long, requires = "order", help = "Renumber tasks to follow the suggested order")]
+        apply: bool,
+        
+        /// Annotate dependency edges
+        #[command(subcommand)]
+        action: Option<DependencyCommands>,
     },
 
     /// 🎯 Show tasks ready to start (no blockers)
     #[command(alias = "r")]
-    Ready,
+    Ready {
+        /// Include tasks that are snoozed
+        #[arg(long, help = "Also show snoozed tasks")]
+        include_snoozed: bool,
+    },
+
+    /// 💤 Hide a task from show, list and ready until a later date
+    Snooze {
+        /// ID of the task to snooze
+        #[arg(value_name = "TASK_ID", help = "The ID number of the task to snooze")]
+        id: usize,
+
+        /// Date the task shows up again
+        #[arg(long, value_name = "DATE", required_unless_present_any = ["for_span", "clear"], conflicts_with_all = ["for_span", "clear"], help = "Snooze until this date: YYYY-MM-DD, today, or tomorrow")]
+        until: Option<String>,
+
+        /// How long to snooze the task for
+        #[arg(long = "for", value_name = "SPAN", conflicts_with = "clear", help = "Snooze for this long from today (e.g. 3d, 2w)")]
+        for_span: Option<String>,
+
+        /// Wake the task up now
+        #[arg(long, help = "Remove the snooze so the task shows up again")]
+        clear: bool,
+    },
+
+    /// ⚡ Show ready tasks that fit your current energy and context
+    Now {
+        /// Energy you have available right now
+        #[arg(long, value_enum, value_name = "ENERGY", help = "Only tasks needing at most this energy: low, medium, high")]
+        energy: Option<CliEnergy>,
+
+        /// Where you are right now
+        #[arg(long, value_name = "CONTEXT", help = "Only tasks doable in this context (e.g., @home)")]
+        context: Option<String>,
+
+        /// Maximum number of tasks to show
+        #[arg(long, value_name = "N", default_value_t = 5, help = "Maximum number of tasks to show")]
+        limit: usize,
+    },
+
+    /// 🧭 Suggest what to work on next, ranked by priority, due date and impact
+    Next {
+        /// Time available right now
+        #[arg(long, value_name = "DURATION", help = "Only suggest tasks whose remaining estimate fits this window (e.g. 45m, 2h)")]
+        time: Option<String>,
+
+        /// Maximum number of tasks to suggest
+        #[arg(long, value_name = "N", default_value_t = 5, help = "Maximum number of tasks to suggest")]
+        limit: usize,
+    },
 
     /// 🔥 Show urgent tasks (high/critical priority)
     #[command(alias = "u")]
@@ -194,6 +427,9 @@ pub enum Commands {
     #[command(alias = "b")]
     Blocked,
 
+    /// 📟 Print a one-line project summary for shell prompts and status bars
+    Statusline,
+
     /// 🔍 Fuzzy search tasks by description
     #[command(alias = "f")]
     Find {
@@ -206,6 +442,10 @@ pub enum Commands {
     #[command(subcommand)]
     Phase(PhaseCommands),
 
+    /// 🏁 Track milestones with target dates and progress
+    #[command(subcommand)]
+    Milestone(MilestoneCommands),
+
     /// Manage configuration settings
     #[command(subcommand)]
     Config(ConfigCommands),
@@ -215,6 +455,10 @@ pub enum Commands {
         /// ID of the task to view in detail
         #[arg(value_name = "TASK_ID", help = "The ID number of the task to view")]
         id: usize,
+
+        /// Show only the task's activity log
+        #[arg(long, help = "Show the full activity log of the task: status, edits, tags, phase and priority changes")]
+        history: bool,
     },
 
     /// Perform bulk operations on multiple tasks
@@ -225,10 +469,35 @@ pub enum Commands {
     #[command(subcommand)]
     Notes(NotesCommands),
 
+    /// Comment on a task, or show its discussion thread
+    Comment {
+        /// ID of the task to comment on
+        #[arg(value_name = "TASK_ID", help = "The ID number of the task")]
+        id: usize,
+
+        /// Comment text (omit to show the task's comments)
+        #[arg(value_name = "TEXT", help = "The comment to add (omit to list comments)")]
+        text: Option<String>,
+
+        /// Name to record as the comment author
+        #[arg(long, value_name = "NAME", help = "Comment author (defaults to user.name from config, then git)")]
+        author: Option<String>,
+    },
+
+    /// ⚖️ List decision notes from every task, oldest first
+    Decisions,
+
+    /// ↕️ Reorder, reprioritize, or drop tasks in your editor, like `git rebase -i`
+    Reorder {
+        /// Only reorder tasks in this phase
+        #[arg(long, value_name = "PHASE", help = "Only list tasks in this phase")]
+        phase: Option<String>,
+    },
+
     /// Export roadmap to different formats with advanced time-based filtering
     Export {
         /// Output format
-        #[arg(value_enum, help = "Export format: json, csv, or html")]
+        #[arg(value_enum, help = "Export format: json, csv, html, markdown, mermaid-gantt, mermaid-board, or ics")]
         format: ExportFormat,
         
         /// Output file path (optional, defaults to stdout)
@@ -295,12 +564,44 @@ pub enum Commands {
         /// Include only under-estimated tasks
         #[arg(long, help = "Include only tasks that took less time than estimated")]
         under_estimated_only: bool,
+        
+        /// Split large HTML exports into per-phase pages
+        #[arg(long, value_name = "N", default_value_t = 1000, help = "HTML exports with more than N tasks are split into per-phase pages of at most N rows, linked from an index (0 = never split)")]
+        max_rows: usize,
+        
+        /// Upload the export to configured destinations
+        #[arg(long = "to", value_name = "DESTINATION", help = "Also upload the export to this destination from [export.destinations] (repeatable)")]
+        to: Vec<String>,
     },
 
+    /// Import tasks from JSON, CSV, or GitHub Issues
+    #[command(subcommand)]
+    Import(ImportCommands),
+
+    /// Link git branches and commits to tasks
+    #[command(subcommand)]
+    Git(GitCommands),
+
+    /// Mirror tagged tasks as GitHub issues
+    #[command(subcommand)]
+    Github(GithubCommands),
+
+    /// 🔔 Check the Slack/Discord webhooks that announce completed tasks and milestones
+    #[command(subcommand)]
+    Notify(NotifyCommands),
+
     /// Manage task templates for quick task creation
     #[command(subcommand)]
     Template(TemplateCommands),
 
+    /// 🗂️ Link child projects into a workspace and track them together
+    #[command(subcommand)]
+    Workspace(WorkspaceCommands),
+
+    /// ⚙️ List, test, and audit automation rules
+    #[command(subcommand)]
+    Rules(RulesCommands),
+
     /// Start time tracking for a task
     Start {
         /// ID of the task to start tracking time for
@@ -315,6 +616,39 @@ pub enum Commands {
     /// Stop time tracking for the currently active task
     Stop,
 
+    /// 📐 Check milestones against the remaining estimates and recent velocity
+    Plan {
+        /// Weeks of recent work the velocity is taken from
+        #[arg(long, value_name = "N", default_value_t = 4, help = "Measure velocity over the last N weeks")]
+        weeks: u32,
+    },
+
+    /// 📅 Show a month of due tasks and logged hours, flagging overloaded days
+    Calendar {
+        /// Month to show
+        #[arg(long, value_name = "YYYY-MM", help = "Month to show (default: the current month)")]
+        month: Option<String>,
+    },
+
+    /// 🍅 Run pomodoro work/break cycles, tracking each work interval
+    Pomodoro {
+        /// ID of the task to work on
+        #[arg(value_name = "TASK_ID", help = "The ID number of the task to work on")]
+        task_id: usize,
+
+        /// Length of each work interval in minutes
+        #[arg(long, value_name = "MINUTES", default_value_t = 25, help = "Work interval length in minutes")]
+        work: u64,
+
+        /// Length of each break in minutes
+        #[arg(long = "break", value_name = "MINUTES", default_value_t = 5, help = "Break length in minutes")]
+        break_minutes: u64,
+
+        /// Number of work intervals to run
+        #[arg(long, value_name = "N", default_value_t = 4, help = "Number of work intervals")]
+        cycles: u32,
+    },
+
     /// View time tracking information for tasks
     Time {
         /// Show time information for a specific task
@@ -328,6 +662,10 @@ pub enum Commands {
         /// Show detailed time session history
         #[arg(long, help = "Show detailed time session history")]
         detailed: bool,
+        
+        /// Log or correct sessions, or exchange them with Toggl or Clockify
+        #[command(subcommand)]
+        action: Option<TimeCommands>,
     },
 
     /// View comprehensive project analytics and progress reports
@@ -349,12 +687,20 @@ pub enum Commands {
         #[arg(long, help = "Show analytics broken down by priorities")]
         priorities: bool,
         
+        /// Show tag namespace analytics
+        #[arg(long, help = "Show analytics broken down by top-level tag namespace (area for area/backend/auth)")]
+        tags: bool,
+        
         /// Show trend analytics and velocity metrics
         #[arg(long, help = "Show trend analytics and project velocity")]
         trends: bool,
         
+        /// Show how long pending tasks have been idle
+        #[arg(long, help = "Show staleness analytics: idle time of pending tasks")]
+        stale: bool,
+        
         /// Export analytics to file
-        #[arg(long, value_name = "FILE", help = "Export analytics summary to file")]
+        #[arg(long, value_name = "FILE", help = "Write an interactive HTML dashboard (.html) or the raw figures (.json) to a file; 'json' or 'summary' print them")]
         export: Option<PathBuf>,
         
         /// Show all analytics sections
@@ -362,10 +708,133 @@ pub enum Commands {
         all: bool,
     },
 
-    /// Show project timeline with phase-based horizontal layout
+    /// 🩹 Inspect or undo an interrupted bulk/AI operation
+    Recover {
+        /// Restore the state from before the interrupted operation
+        #[arg(long, conflicts_with = "discard", help = "Roll back to the snapshot taken before the interrupted operation")]
+        rollback: bool,
+
+        /// Keep the current state and forget the interrupted operation
+        #[arg(long, help = "Keep the current (possibly partial) state and remove the checkpoint")]
+        discard: bool,
+    },
+
+    /// 🧬 Upgrade the project state to the current schema version
+    Migrate {
+        /// Only report whether a migration is needed
+        #[arg(long, help = "Report pending migrations without changing anything; fails if any are pending")]
+        check: bool,
+    },
+
+    /// 🗄️ Show or change how the project state is stored
+    Storage {
+        /// Backend to move the state to
+        #[arg(value_parser = ["json", "sqlite"], help = "Convert the state to this backend (json or sqlite); omit to show the current one")]
+        backend: Option<String>,
+    },
+
+    /// ⏪ List state backups or restore one
+    Restore {
+        /// Backup to restore, by name or by its number in the list (1 = newest)
+        #[arg(help = "Backup name or list number to restore (see --list)")]
+        backup: Option<String>,
+
+        /// List the available backups
+        #[arg(long, conflicts_with = "backup", help = "List the backups in .rask/backups")]
+        list: bool,
+    },
+
+    /// 🧾 Generate reports such as timesheets from tracked time
+    #[command(subcommand)]
+    Report(ReportCommands),
+
+    /// 🧹 Walk through stale or never-reviewed tasks and triage each one
+    Review {
+        /// Tasks idle for longer than this are up for review
+        #[arg(long, value_name = "AGE", default_value = "7d", help = "Review tasks with no activity for this long (e.g. 30d, 2w)")]
+        older_than: String,
+    },
+
+    /// 🔮 Predict when the pending tasks will be done at the recent pace
+    Forecast {
+        /// Forecast one phase instead of the whole project
+        #[arg(long, help = "Forecast the pending tasks of this phase only")]
+        phase: Option<String>,
+
+        /// Weeks of completed tasks the pace is measured over
+        #[arg(long, value_name = "N", default_value_t = 4, help = "Measure the pace over the last N weeks")]
+        weeks: u32,
+    },
+
+    /// 🃏 Estimate the pending tasks that have no estimate, one by one
+    Estimate {
+        /// Only tasks in this phase
+        #[arg(long, help = "Only estimate tasks in this phase")]
+        phase: Option<String>,
+
+        /// Only tasks with this tag or one nested under it
+        #[arg(long, help = "Only estimate tasks with this tag (or one nested under it)")]
+        tag: Option<String>,
+    },
+
+    /// 👯 Find near-duplicate tasks and merge or dismiss them
+    Dedupe {
+        /// How alike two descriptions must be to be reported, from 0 to 1
+        #[arg(long, value_name = "SCORE", default_value_t = 0.6, help = "Minimum text similarity to report a pair, from 0 to 1")]
+        threshold: f64,
+
+        /// Also compare completed tasks
+        #[arg(long, help = "Include completed tasks")]
+        all: bool,
+
+        /// Also compare AI embeddings of the descriptions
+        #[arg(long, help = "Also report pairs the AI provider's embeddings find alike (needs 'rask ai configure')")]
+        ai: bool,
+    },
+
+    /// 🔁 Generate a retrospective for a phase
+    Retro {
+        /// Phase to look back on
+        #[arg(long, value_name = "PHASE", help = "Phase to summarize (live or archived)")]
+        phase: String,
+
+        /// Ask the configured AI provider to write the lessons learned
+        #[arg(long, help = "Use AI to write the lessons-learned bullets")]
+        ai: bool,
+
+        /// Write the retrospective to a markdown file
+        #[arg(long, value_name = "FILE", help = "Write the retrospective as markdown to this file")]
+        output: Option<PathBuf>,
+    },
+
+    /// Show project activity in order: edits, phase moves, sessions, AI operations
     Timeline {
+        /// Only show these event types
+        #[arg(long = "type", value_enum, value_delimiter = ',', value_name = "TYPE", conflicts_with_all = ["phases", "detailed", "active_only", "compact", "page", "page_size"], help = "Only show these event types (comma-separated)")]
+        kinds: Vec<EventKind>,
+
+        /// Only show events on or after this date
+        #[arg(long, value_name = "WHEN", conflicts_with_all = ["phases", "detailed", "active_only", "compact", "page", "page_size"], help = "Show events since: yesterday, today, a weekday, Nd (e.g. 7d), or YYYY-MM-DD")]
+        since: Option<String>,
+
+        /// Only show events on or before this date
+        #[arg(long, value_name = "WHEN", conflicts_with_all = ["phases", "detailed", "active_only", "compact", "page", "page_size"], help = "Show events until (inclusive): yesterday, today, a weekday, Nd, or YYYY-MM-DD")]
+        until: Option<String>,
+
+        /// Only show events of one task
+        #[arg(long, value_name = "ID", conflicts_with_all = ["phases", "detailed", "active_only", "compact", "page", "page_size"], help = "Only show the events of this task")]
+        task: Option<usize>,
+
+        /// Number of most recent events to show
+        #[arg(long, value_name = "N", default_value_t = 50, help = "Show at most this many of the latest events")]
+        limit: usize,
+
+        /// Show the phase-based horizontal layout instead
+        #[arg(long, help = "Show the phase-by-phase horizontal layout instead of the activity feed")]
+        phases: bool,
+
         /// Show detailed task information in timeline
-        #[arg(long, help = "Show detailed task information in timeline view")]
+        #[arg(long, help = "Show detailed task information in timeline view (implies --phases)")]
         detailed: bool,
         
         /// Show only active phases (hide empty phases)
@@ -401,8 +870,12 @@ pub enum Commands {
         no_welcome: bool,
     },
 
-    /// Synchronize changes between roadmap files and Rask state
+    /// Synchronize changes between roadmap files and Rask state, or with Jira
+    #[command(args_conflicts_with_subcommands = true)]
     Sync {
+        #[command(subcommand)]
+        target: Option<SyncCommands>,
+
         /// Sync from the original roadmap file to Rask state
         #[arg(long, help = "Update Rask state from changes in the original roadmap file")]
         from_roadmap: bool,
@@ -427,9 +900,81 @@ pub enum Commands {
         #[arg(long, help = "Show what would be synced without making changes")]
         dry_run: bool,
     },
+
+    /// Print a shell completion script
+    Completions {
+        /// Shell to generate the script for
+        #[arg(value_enum, value_name = "SHELL", help = "Shell to generate completions for: bash, zsh, fish, powershell, elvish")]
+        shell: clap_complete::Shell,
+    },
+
+    /// List completion candidates for a partial command line (used by the completion scripts)
+    #[command(name = "__complete", hide = true)]
+    CompleteValues {
+        /// Words after `rask`, ending with the word being completed
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        words: Vec<String>,
+    },
+}
+
+impl Commands {
+    /// Make relative file arguments absolute against the current directory.
+    ///
+    /// Called before `--project` switches into another project's directory, so
+    /// `-o out.md` or an import file still means the file where the user ran
+    /// the command. Arguments that may also be a name or URL are only
+    /// rewritten when they name an existing file.
+    pub fn absolutize_paths(&mut self) {
+        match self {
+            Commands::Init { filepath } => absolutize(filepath),
+            Commands::Export { output, .. } | Commands::Retro { output, .. } => output.iter_mut().for_each(absolutize),
+            Commands::Analytics { export, .. } => export.iter_mut()
+                .filter(|path| !matches!(path.to_str(), Some("json" | "summary")))
+                .for_each(absolutize),
+            Commands::Report(ReportCommands::Timesheet { csv, .. }) => csv.iter_mut().for_each(absolutize),
+            Commands::Template(TemplateCommands::Export { output: path, .. })
+            | Commands::Template(TemplateCommands::Import { input: path, .. })
+            | Commands::Time { action: Some(TimeCommands::Import { file: path, .. }), .. }
+            | Commands::Import(ImportCommands::Json { file: path, .. })
+            | Commands::Import(ImportCommands::Csv { file: path, .. }) => absolutize(path),
+            Commands::Time { action: Some(TimeCommands::Export { output, .. }), .. } => output.iter_mut().for_each(absolutize),
+            Commands::Template(TemplateCommands::Fetch { url: Some(path), .. })
+            | Commands::Template(TemplateCommands::Publish { target: path })
+            | Commands::Workspace(WorkspaceCommands::Add { project: path, .. }) => absolutize_existing(path),
+            Commands::Ai(AiCommands::Analyze { output, .. } | AiCommands::Insights { output, .. }) => {
+                output.iter_mut().for_each(absolutize_str);
+            }
+            Commands::Ai(AiCommands::Roadmap { file, output, .. }) => {
+                file.iter_mut().for_each(absolutize_str);
+                output.iter_mut().for_each(absolutize_str);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn absolutize(path: &mut PathBuf) {
+    if let Ok(absolute) = std::path::absolute(&*path) {
+        *path = absolute;
+    }
+}
+
+fn absolutize_str(path: &mut String) {
+    let mut buf = PathBuf::from(&*path);
+    absolutize(&mut buf);
+    *path = buf.to_string_lossy().into_owned();
+}
+
+fn absolutize_existing(path: &mut String) {
+    if std::path::Path::new(path.as_str()).exists() {
+        absolutize_str(path);
+    }
 }
 
 /// Parse command line arguments and return the CLI structure
 pub fn parse_args() -> Cli {
-    Cli::parse()
+    let matches = Cli::command().get_matches();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    cli.command_name = matches.subcommand_name().unwrap_or_default().to_string();
+    cli
 } 
\ No newline at end of file
@@ -59,8 +59,8 @@ pub enum AiCommands {
     
     /// Configure AI settings and API keys
     Configure {
-        /// Set AI provider (gemini, openai, claude - future)
-        #[arg(long, value_name = "PROVIDER", help = "Set AI provider: gemini")]
+        /// Set AI provider (gemini, or mock for offline canned responses)
+        #[arg(long, value_name = "PROVIDER", help = "Set AI provider: gemini or mock")]
         provider: Option<String>,
         
         /// Set API key for the current provider
@@ -114,12 +114,59 @@ pub enum AiCommands {
         phase: Option<String>,
     },
     
-    /// Analyze roadmap file and suggest improvements or create a plan
+    /// Summarize recent work, blockers and next tasks as a daily standup
+    Standup {
+        /// Start of the period to report on
+        #[arg(long, value_name = "WHEN", default_value = "yesterday", help = "Report activity since: yesterday, today, a weekday, Nd (e.g. 3d), or YYYY-MM-DD")]
+        since: String,
+    },
+    
+    /// Suggest an effort estimate for a task using past estimate accuracy
+    Estimate {
+        /// Task to estimate
+        #[arg(value_name = "TASK_ID", help = "ID of the task to estimate")]
+        task_id: usize,
+        
+        /// Save the suggested estimate on the task
+        #[arg(long, help = "Set the task's estimated hours to the suggestion")]
+        apply: bool,
+    },
+    
+    /// Condense a task's notes, implementation notes and comments into a short summary
+    Summarize {
+        /// Task ID to summarize
+        #[arg(value_name = "TASK_ID", help = "ID of the task whose notes to summarize")]
+        task_id: usize,
+    },
+    
+    /// Find delivery risks in the dependency graph, estimates and deadlines
+    Risks {
+        /// Tag the affected tasks with risk/<kind>
+        #[arg(long, help = "Tag affected pending tasks with risk/<kind>, replacing earlier risk tags")]
+        apply: bool,
+    },
+    
+    /// Change tasks from a plain-language instruction, after confirming the plan
+    Do {
+        /// What to change, e.g. "move all auth tasks to Beta and mark #12 done"
+        #[arg(value_name = "INSTRUCTION", help = "What to change, in plain language")]
+        instruction: String,
+        
+        /// Show the plan without running it
+        #[arg(long, help = "Show the planned commands without running them")]
+        dry_run: bool,
+    },
+    
+    /// Analyze roadmap file and suggest improvements, or generate a roadmap from a project brief
     Roadmap {
         /// Roadmap file to analyze (defaults to current project's roadmap)
-        #[arg(value_name = "FILE", help = "Roadmap markdown file to analyze")]
+        #[arg(value_name = "FILE", help = "Roadmap markdown file to analyze (defaults to the current project's roadmap)")]
         file: Option<String>,
         
+        /// Project brief to generate a new roadmap from
+        #[arg(long, value_name = "TEXT", conflicts_with_all = ["file", "apply", "focus", "generate_plan"], help = "Generate a phased roadmap for a new project from this brief")]
+        brief: Option<String>,
+        
         /// Apply suggested improvements/tasks to the project
         #[arg(long, help = "Apply AI suggestions directly to the project")]
         apply: bool,
@@ -128,12 +175,15 @@ pub enum AiCommands {
         #[arg(long, value_name = "FOCUS", help = "Focus analysis on: structure, priorities, phases, timeline, dependencies")]
         focus: Option<String>,
         
-        /// Export detailed analysis to file
-        #[arg(long, short, value_name = "FILE", help = "Export detailed analysis to file")]
+        /// Export detailed analysis to file; a roadmap generated from a brief goes to roadmap.md by default
+        #[arg(long, short, value_name = "FILE", help = "Export detailed analysis (or the generated roadmap, default roadmap.md) to file")]
         output: Option<String>,
         
         /// Generate a new project plan instead of analyzing existing roadmap
         #[arg(long, help = "Generate a new project plan based on requirements")]
         generate_plan: bool,
     },
-}
\ No newline at end of file
+    
+    /// Remove cached AI responses so the next request goes to the provider
+    ClearCache,
+}
@@ -1,21 +1,40 @@
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 use super::types::CliPriority;
 
+/// The tasks a bulk operation applies to: explicit IDs or a filter expression
+#[derive(Args, Debug, Clone)]
+pub struct TaskSelection {
+    /// Comma-separated list of task IDs
+    #[arg(value_name = "IDS", required_unless_present = "filter", help = "Task IDs separated by commas (e.g., 1,2,3)")]
+    pub ids: Option<String>,
+
+    /// Select the tasks with a filter instead of IDs
+    #[arg(long, value_name = "QUERY", conflicts_with = "ids",
+          help = "Select tasks matching a filter, e.g. \"tag:backend phase:MVP status:pending\" (keys: tag, priority, phase, status, owner; other words search the text)")]
+    pub filter: Option<String>,
+}
+
 /// Bulk operations on multiple tasks
 #[derive(Subcommand)]
 pub enum BulkCommands {
+    /// Pick tasks from a checklist, then choose what to do with them
+    Select {
+        /// Only list the tasks matching this filter
+        #[arg(long, value_name = "QUERY", help = "Only list tasks matching a filter, e.g. \"status:pending tag:backend\"")]
+        filter: Option<String>,
+    },
+    
     /// Complete multiple tasks at once
     Complete {
-        /// Comma-separated list of task IDs to complete
-        #[arg(value_name = "IDS", help = "Task IDs separated by commas (e.g., 1,2,3)")]
-        ids: String,
+        #[command(flatten)]
+        tasks: TaskSelection,
     },
     
     /// Add tags to multiple tasks
+    #[command(allow_missing_positional = true)]
     AddTags {
-        /// Comma-separated list of task IDs
-        #[arg(value_name = "IDS", help = "Task IDs separated by commas")]
-        ids: String,
+        #[command(flatten)]
+        tasks: TaskSelection,
         
         /// Comma-separated list of tags to add
         #[arg(value_name = "TAGS", help = "Tags separated by commas")]
@@ -23,21 +42,43 @@ pub enum BulkCommands {
     },
     
     /// Remove tags from multiple tasks
+    #[command(allow_missing_positional = true)]
     RemoveTags {
-        /// Comma-separated list of task IDs
-        #[arg(value_name = "IDS", help = "Task IDs separated by commas")]
-        ids: String,
+        #[command(flatten)]
+        tasks: TaskSelection,
         
         /// Comma-separated list of tags to remove
         #[arg(value_name = "TAGS", help = "Tags separated by commas")]
         tags: String,
     },
     
+    /// Add owners to multiple tasks
+    #[command(allow_missing_positional = true)]
+    Assign {
+        #[command(flatten)]
+        tasks: TaskSelection,
+        
+        /// Comma-separated list of owners to add
+        #[arg(value_name = "OWNERS", help = "Owners separated by commas")]
+        owners: String,
+    },
+    
+    /// Remove owners from multiple tasks
+    #[command(allow_missing_positional = true)]
+    Unassign {
+        #[command(flatten)]
+        tasks: TaskSelection,
+        
+        /// Comma-separated list of owners to remove
+        #[arg(value_name = "OWNERS", help = "Owners separated by commas")]
+        owners: String,
+    },
+    
     /// Set priority for multiple tasks
+    #[command(allow_missing_positional = true)]
     SetPriority {
-        /// Comma-separated list of task IDs
-        #[arg(value_name = "IDS", help = "Task IDs separated by commas")]
-        ids: String,
+        #[command(flatten)]
+        tasks: TaskSelection,
         
         /// Priority level to set
         #[arg(value_enum, help = "Priority level")]
@@ -45,28 +86,37 @@ pub enum BulkCommands {
     },
     
     /// Set phase for multiple tasks
+    #[command(allow_missing_positional = true)]
     SetPhase {
-        /// Comma-separated list of task IDs
-        #[arg(value_name = "IDS", help = "Task IDs separated by commas")]
-        ids: String,
+        #[command(flatten)]
+        tasks: TaskSelection,
         
         /// Phase to set
         #[arg(help = "Phase name")]
         phase: String,
     },
     
+    /// Include or exclude multiple tasks from the markdown roadmap file
+    #[command(allow_missing_positional = true)]
+    SetMarkdown {
+        #[command(flatten)]
+        tasks: TaskSelection,
+        
+        /// Whether the tasks are written to the markdown file
+        #[arg(value_name = "SYNC", action = clap::ArgAction::Set, help = "true to write the tasks to the roadmap markdown, false to keep them local")]
+        sync: bool,
+    },
+    
     /// Reset multiple tasks to pending status
     Reset {
-        /// Comma-separated list of task IDs to reset
-        #[arg(value_name = "IDS", help = "Task IDs separated by commas")]
-        ids: String,
+        #[command(flatten)]
+        tasks: TaskSelection,
     },
     
     /// Remove multiple tasks (with dependency validation)
     Remove {
-        /// Comma-separated list of task IDs to remove
-        #[arg(value_name = "IDS", help = "Task IDs separated by commas")]
-        ids: String,
+        #[command(flatten)]
+        tasks: TaskSelection,
         
         /// Force removal even if other tasks depend on these
         #[arg(long, help = "Force removal even with dependencies")]
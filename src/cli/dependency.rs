@@ -0,0 +1,20 @@
+use clap::Subcommand;
+
+/// Dependency edge commands
+#[derive(Subcommand)]
+pub enum DependencyCommands {
+    /// Explain why a task depends on another, or show the current note
+    Note {
+        /// Dependency edge as TASK→DEPENDENCY
+        #[arg(value_name = "EDGE", help = "Dependency edge, e.g. 12→5 or 12->5 (task 12 depends on task 5)")]
+        edge: String,
+
+        /// Why the dependency exists (omit to show the current note)
+        #[arg(value_name = "TEXT", help = "Reason for the dependency (e.g., \"waiting on schema migration\")")]
+        text: Option<String>,
+
+        /// Remove the note from the edge
+        #[arg(long, conflicts_with = "text", help = "Remove the note from this dependency")]
+        clear: bool,
+    },
+}
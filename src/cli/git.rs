@@ -0,0 +1,53 @@
+use clap::Subcommand;
+
+/// Git integration commands
+#[derive(Subcommand)]
+pub enum GitCommands {
+    /// Create a git branch named after a task
+    Branch {
+        /// Task ID to create the branch for
+        #[arg(value_name = "TASK_ID", help = "ID of the task to create a branch for")]
+        task_id: usize,
+
+        /// Prefix for the branch name
+        #[arg(long, value_name = "PREFIX", default_value = "task", help = "Branch name prefix (e.g., feature, fix)")]
+        prefix: String,
+
+        /// Create the branch without switching to it
+        #[arg(long, help = "Create the branch without checking it out")]
+        no_checkout: bool,
+    },
+
+    /// Scan commit messages for rask:#ID references and link them to tasks
+    Scan {
+        /// Revision range to scan
+        #[arg(long, value_name = "RANGE", default_value = "HEAD", help = "Revision or range to scan (e.g., main..HEAD)")]
+        range: String,
+
+        /// Maximum number of commits to scan
+        #[arg(long, value_name = "N", help = "Only scan the most recent N commits")]
+        limit: Option<usize>,
+
+        /// Preview the links without writing state
+        #[arg(long, help = "Show which commits would be linked without saving")]
+        dry_run: bool,
+    },
+
+    /// Install git hooks that complete tasks from `closes rask:#ID` commit messages
+    InstallHooks {
+        /// Overwrite existing hooks that were not installed by Rask
+        #[arg(long, help = "Replace existing commit-msg/post-commit hooks (backups are kept)")]
+        force: bool,
+    },
+
+    /// Check a commit message before committing (used by the commit-msg hook)
+    #[command(hide = true)]
+    HookCommitMsg {
+        /// Path to the commit message file
+        file: std::path::PathBuf,
+    },
+
+    /// Complete tasks closed by the last commit (used by the post-commit hook)
+    #[command(hide = true)]
+    HookPostCommit,
+}
@@ -0,0 +1,20 @@
+use clap::Subcommand;
+
+/// GitHub Issues integration commands
+#[derive(Subcommand)]
+pub enum GithubCommands {
+    /// Create or update GitHub issues for tagged tasks and sync their open/closed state
+    Push {
+        /// Repository in owner/name form
+        #[arg(long, value_name = "OWNER/REPO", help = "Repository to push to (defaults to github.repo from the config)")]
+        repo: Option<String>,
+
+        /// Tag that marks the tasks to push
+        #[arg(long, value_name = "TAG", default_value = "github", help = "Push the tasks with this tag")]
+        tag: String,
+
+        /// Show what would change without calling GitHub or saving
+        #[arg(long, help = "Show what would be pushed without creating or updating issues")]
+        dry_run: bool,
+    },
+}
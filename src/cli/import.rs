@@ -0,0 +1,51 @@
+use clap::Subcommand;
+use std::path::PathBuf;
+
+/// Import tasks from external sources
+#[derive(Subcommand)]
+pub enum ImportCommands {
+    /// Import tasks from a JSON file (array of items or a Rask JSON export)
+    Json {
+        /// Path to the JSON file
+        #[arg(value_name = "FILE", help = "JSON file containing the items to import")]
+        file: PathBuf,
+
+        /// Preview the changes without writing state
+        #[arg(long, help = "Show what would be imported without saving")]
+        dry_run: bool,
+    },
+
+    /// Import tasks from a CSV file with a header row
+    Csv {
+        /// Path to the CSV file
+        #[arg(value_name = "FILE", help = "CSV file with columns such as Description, Tags, Priority, Phase")]
+        file: PathBuf,
+
+        /// Columns to read each field from
+        #[arg(long, value_name = "FIELD=COLUMN,...", help = "Map fields to column headers, e.g. description=Title,tags=Labels (fields: description, tags, priority, phase, notes, status, estimate, uuid, id, dependencies)")]
+        map: Option<String>,
+
+        /// Preview the changes without writing state
+        #[arg(long, help = "Show what would be imported without saving")]
+        dry_run: bool,
+    },
+
+    /// Import issues from a GitHub repository
+    Github {
+        /// Repository in owner/name form
+        #[arg(value_name = "OWNER/REPO", help = "GitHub repository (e.g., titobsala/Raska)")]
+        repo: String,
+
+        /// Issue state to import
+        #[arg(long, value_name = "STATE", default_value = "open", help = "Issue state to import: open, closed, all")]
+        state: String,
+
+        /// GitHub API token (falls back to GITHUB_TOKEN)
+        #[arg(long, value_name = "TOKEN", help = "Personal access token (defaults to the GITHUB_TOKEN environment variable)")]
+        token: Option<String>,
+
+        /// Preview the changes without writing state
+        #[arg(long, help = "Show what would be imported without saving")]
+        dry_run: bool,
+    },
+}
@@ -0,0 +1,45 @@
+use clap::Subcommand;
+
+/// Milestone commands: dated goals tracked by a phase or a set of tags
+#[derive(Subcommand)]
+pub enum MilestoneCommands {
+    /// Add a milestone with a target date
+    Add {
+        /// Name of the milestone
+        #[arg(value_name = "NAME", help = "Milestone name (e.g., \"v1.0\")")]
+        name: String,
+
+        /// Target date
+        #[arg(long, value_name = "DATE", help = "Target date: YYYY-MM-DD, today, or tomorrow")]
+        target: String,
+
+        /// Phase whose tasks make up the milestone
+        #[arg(long, value_name = "PHASE", help = "Track the tasks of this phase")]
+        phase: Option<String>,
+
+        /// Tags whose tasks make up the milestone (comma-separated)
+        #[arg(long, value_name = "TAGS", help = "Track tasks with any of these tags (e.g., release,docs)")]
+        tag: Option<String>,
+
+        /// Description of the milestone
+        #[arg(long, help = "Description of the milestone")]
+        description: Option<String>,
+    },
+
+    /// List milestones with their progress and countdown
+    List,
+
+    /// Show a milestone's progress and tasks
+    Show {
+        /// Name of the milestone
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+
+    /// Remove a milestone (its tasks are left untouched)
+    Remove {
+        /// Name of the milestone
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+}
@@ -1,4 +1,5 @@
 use clap::Subcommand;
+use super::types::CliNoteKind;
 
 /// Implementation notes management commands
 #[derive(Subcommand)]
@@ -12,6 +13,10 @@ pub enum NotesCommands {
         /// Implementation note content
         #[arg(value_name = "NOTE", help = "Implementation note content (code snippets, technical details, etc.)")]
         note: String,
+
+        /// Kind of note
+        #[arg(long = "type", value_enum, value_name = "TYPE", default_value = "general", help = "Note type: general, decision, blocker, code, link")]
+        kind: CliNoteKind,
     },
     
     /// List all implementation notes for a task
@@ -19,6 +24,10 @@ pub enum NotesCommands {
         /// Task ID to show notes for
         #[arg(value_name = "TASK_ID", help = "ID of the task to show implementation notes for")]
         task_id: usize,
+
+        /// Only show notes of this kind
+        #[arg(long = "type", value_enum, value_name = "TYPE", help = "Only show notes of this type")]
+        kind: Option<CliNoteKind>,
     },
     
     /// Remove an implementation note from a task
@@ -39,6 +48,17 @@ pub enum NotesCommands {
         task_id: usize,
     },
     
+    /// Open the pages a note links to with [[Note Name]] in your editor
+    Open {
+        /// Task ID containing the note
+        #[arg(value_name = "TASK_ID", help = "ID of the task containing the note")]
+        task_id: usize,
+        
+        /// Index of the implementation note (0-based); the task's own notes when omitted
+        #[arg(value_name = "INDEX", help = "Index of the implementation note with the links (0-based); omit to use the task's notes")]
+        index: Option<usize>,
+    },
+    
     /// Edit an implementation note
     Edit {
         /// Task ID containing the note
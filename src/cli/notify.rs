@@ -0,0 +1,8 @@
+use clap::Subcommand;
+
+/// Webhook notification commands
+#[derive(Subcommand)]
+pub enum NotifyCommands {
+    /// Send a test message to every configured webhook
+    Test,
+}
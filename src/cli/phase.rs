@@ -68,4 +68,64 @@ pub enum PhaseCommands {
         #[arg(long, help = "Keep original tasks in their current phase (copy instead of move)")]
         copy: bool,
     },
-} 
\ No newline at end of file
+    
+    /// Set, show or clear a phase's WIP limit (maximum open tasks)
+    Limit {
+        /// Phase to limit
+        #[arg(help = "Phase name")]
+        phase: String,
+        
+        /// Maximum number of open tasks
+        #[arg(value_name = "MAX_OPEN", help = "Maximum number of open (pending) tasks in the phase; omit to show the current limit")]
+        limit: Option<usize>,
+        
+        /// Remove the limit
+        #[arg(long, conflicts_with = "limit", help = "Remove the phase's WIP limit")]
+        clear: bool,
+    },
+    
+    /// Rename a phase on all of its tasks
+    Rename {
+        /// Phase to rename
+        #[arg(value_name = "PHASE", help = "Current phase name")]
+        phase: String,
+        
+        /// New name for the phase
+        #[arg(help = "New phase name")]
+        new_name: String,
+    },
+    
+    /// Move a phase in the display order used by show, overview and export
+    #[command(group(clap::ArgGroup::new("target").required(true).args(["before", "after", "position"])))]
+    Reorder {
+        /// Phase to move
+        #[arg(value_name = "PHASE", help = "Phase name to move")]
+        phase: String,
+        
+        /// Place the phase before another phase
+        #[arg(long, value_name = "PHASE", help = "Move the phase before this phase")]
+        before: Option<String>,
+        
+        /// Place the phase after another phase
+        #[arg(long, value_name = "PHASE", help = "Move the phase after this phase")]
+        after: Option<String>,
+        
+        /// Place the phase at a position (1 = first)
+        #[arg(long, help = "Move the phase to this position, starting at 1")]
+        position: Option<usize>,
+    },
+    
+    /// Archive a completed phase into .rask/archive/ to keep state small
+    Archive {
+        /// Phase to archive
+        #[arg(help = "Name of the completed phase to archive")]
+        phase: String,
+    },
+    
+    /// List archived phases, or show the tasks of one archived phase
+    Archived {
+        /// Archived phase to load and display
+        #[arg(help = "Name of an archived phase to display (lists all archives if omitted)")]
+        phase: Option<String>,
+    },
+}
@@ -0,0 +1,31 @@
+use clap::Subcommand;
+use std::path::PathBuf;
+
+use super::types::TimesheetGroup;
+
+/// Reports built from tracked data
+#[derive(Subcommand)]
+pub enum ReportCommands {
+    /// Hours tracked per day (week) or per week (month), grouped by task, tag or phase
+    Timesheet {
+        /// Report on a calendar week (the default)
+        #[arg(long, conflicts_with = "month", help = "Report on the week (Monday to Sunday) containing --date")]
+        week: bool,
+
+        /// Report on a calendar month
+        #[arg(long, help = "Report on the month containing --date")]
+        month: bool,
+
+        /// Any day inside the period to report on
+        #[arg(long, value_name = "DATE", help = "A day in the period to report on (YYYY-MM-DD, defaults to today)")]
+        date: Option<String>,
+
+        /// How to group the rows
+        #[arg(long, value_enum, default_value = "task", help = "Group rows by task, tag or phase")]
+        group_by: TimesheetGroup,
+
+        /// Also write the table as CSV
+        #[arg(long, value_name = "FILE", help = "Write the timesheet as CSV to this file")]
+        csv: Option<PathBuf>,
+    },
+}
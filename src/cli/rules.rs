@@ -0,0 +1,39 @@
+use clap::Subcommand;
+
+use super::types::RuleTestEvent;
+
+/// Automation rule commands
+#[derive(Subcommand)]
+pub enum RulesCommands {
+    /// List the automation rules ([[rules]]) and escalation rules ([[escalations]])
+    List,
+
+    /// Show which rules would run for an event, without changing anything
+    Test {
+        /// Simulate an event for this task
+        #[arg(long, value_name = "TASK_ID", conflicts_with = "phase", required_unless_present = "phase", help = "Task the simulated event is about")]
+        task: Option<usize>,
+
+        /// Event to simulate for the task
+        #[arg(long, value_enum, default_value = "completed", requires = "task", help = "Simulated task event")]
+        event: RuleTestEvent,
+
+        /// Simulate the completion of this phase
+        #[arg(long, value_name = "PHASE", help = "Simulate every task in this phase being completed")]
+        phase: Option<String>,
+    },
+
+    /// Apply the escalation rules now and save the result
+    Run {
+        /// Only show what would change
+        #[arg(long, help = "Preview the escalations without saving anything")]
+        dry_run: bool,
+    },
+
+    /// Show recent rule runs from the audit log
+    Log {
+        /// Number of entries to show
+        #[arg(long, value_name = "N", default_value = "20", help = "Show the most recent N entries")]
+        limit: usize,
+    },
+}
@@ -0,0 +1,16 @@
+use clap::Subcommand;
+
+/// Two-way sync with external issue trackers
+#[derive(Subcommand)]
+pub enum SyncCommands {
+    /// Sync tasks with the Jira project set up under [jira] in the config
+    Jira {
+        /// Show what would change without saving or updating Jira
+        #[arg(long, help = "Show what would be synced without saving or updating Jira")]
+        dry_run: bool,
+
+        /// Also create tasks for issues that are already done
+        #[arg(long, help = "Import issues that are already done as completed tasks")]
+        include_done: bool,
+    },
+}
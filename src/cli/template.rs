@@ -1,6 +1,6 @@
 use clap::Subcommand;
 use std::path::PathBuf;
-use super::types::CliPriority;
+use super::types::{CliPriority, TemplateConflict};
 
 /// Template management commands
 #[derive(Subcommand, Clone)]
@@ -44,6 +44,10 @@ pub enum TemplateCommands {
         /// Override template phase
         #[arg(long, help = "Override template phase")]
         phase: Option<String>,
+        
+        /// Values for the template's [PLACEHOLDER] variables
+        #[arg(long = "var", value_name = "NAME=VALUE", help = "Fill a [PLACEHOLDER] variable, e.g. --var BUG_DESCRIPTION=\"login 500\" (repeatable); unfilled ones are prompted for")]
+        vars: Vec<String>,
     },
     
     /// Create a new custom template
@@ -110,9 +114,37 @@ pub enum TemplateCommands {
         merge: bool,
     },
     
+    /// Fetch a shared template collection and merge it into yours
+    Fetch {
+        /// URL or path of a published templates.json; defaults to advanced.template_registry
+        #[arg(value_name = "URL", help = "URL or file path of the shared templates.json (default: advanced.template_registry)")]
+        url: Option<String>,
+        
+        /// Expected SHA-256 of the file, instead of the published .sha256
+        #[arg(long, value_name = "HEX", help = "Expected SHA-256 hash of the file")]
+        sha256: Option<String>,
+        
+        /// What to do with templates that differ from yours
+        #[arg(long, value_enum, default_value = "ask", help = "How to handle templates that differ from local ones")]
+        on_conflict: TemplateConflict,
+    },
+    
+    /// Publish your template collection for the team
+    Publish {
+        /// Export destination name, http(s) URL of a WebDAV/PUT endpoint, or path of a git repository
+        #[arg(value_name = "TARGET", help = "Export destination name, WebDAV/PUT URL or git repository path")]
+        target: String,
+    },
+    
     /// Show template help and examples
     Examples,
     
+    /// Template packs: sets of linked tasks created in one go
+    Pack {
+        #[command(subcommand)]
+        command: TemplatePackCommands,
+    },
+    
     /// Generate templates using AI based on project context
     Generate {
         /// Description of the templates to generate
@@ -172,4 +204,33 @@ pub enum TemplateCommands {
         #[arg(value_name = "PROJECT_NAME", help = "Name of the new project")]
         project_name: String,
     },
-} 
\ No newline at end of file
+}
+
+/// Template pack commands
+#[derive(Subcommand, Clone)]
+pub enum TemplatePackCommands {
+    /// List available template packs
+    List,
+    
+    /// Show the tasks of a pack and how they depend on each other
+    Show {
+        /// Name of the pack
+        #[arg(value_name = "PACK", help = "Name of the template pack")]
+        name: String,
+    },
+    
+    /// Create every task of a pack, with dependencies between them
+    Use {
+        /// Name of the pack
+        #[arg(value_name = "PACK", help = "Name of the template pack")]
+        name: String,
+        
+        /// Values for the pack's [PLACEHOLDER] variables
+        #[arg(long = "var", value_name = "NAME=VALUE", help = "Fill a [PLACEHOLDER] variable, e.g. --var PROJECT_NAME=Shop (repeatable); unfilled ones are prompted for")]
+        vars: Vec<String>,
+        
+        /// Additional tags for every created task (comma-separated)
+        #[arg(long, value_name = "TAGS", help = "Additional tags to add to every task")]
+        add_tags: Option<String>,
+    },
+}
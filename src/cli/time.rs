@@ -0,0 +1,92 @@
+use clap::Subcommand;
+use std::path::PathBuf;
+
+use super::types::TimeTracker;
+
+/// Manual time entries and exchange with external time trackers
+#[derive(Subcommand)]
+pub enum TimeCommands {
+    /// Export completed time sessions as a Toggl or Clockify CSV
+    Export {
+        /// Target time tracker format
+        #[arg(long, value_enum, help = "Time tracker to export for: toggl or clockify")]
+        to: TimeTracker,
+
+        /// Output file (prints to stdout if omitted)
+        #[arg(short, long, value_name = "FILE", help = "Output file path")]
+        output: Option<PathBuf>,
+
+        /// Email to put on each entry
+        #[arg(long, value_name = "EMAIL", help = "User email for the entries (defaults to git user.email)")]
+        email: Option<String>,
+    },
+
+    /// Import time entries from a Toggl or Clockify CSV export
+    Import {
+        /// CSV file exported from the time tracker
+        #[arg(value_name = "FILE", help = "Toggl or Clockify detailed report CSV")]
+        file: PathBuf,
+
+        /// Source format (detected from the header if omitted)
+        #[arg(long, value_enum, help = "Time tracker the file came from: toggl or clockify")]
+        from: Option<TimeTracker>,
+
+        /// Preview the matches without writing state
+        #[arg(long, help = "Show how entries map to tasks without saving")]
+        dry_run: bool,
+    },
+
+    /// Record time worked without a live start/stop session
+    Add {
+        /// Task the time was spent on
+        #[arg(value_name = "TASK_ID", help = "ID of the task to log time against")]
+        task_id: usize,
+
+        /// Hours worked
+        #[arg(long, value_name = "HOURS", help = "Hours worked (e.g., 1.5)")]
+        hours: f64,
+
+        /// Day the work happened
+        #[arg(long, value_name = "DATE", help = "Date of the work (YYYY-MM-DD, defaults to today)")]
+        date: Option<String>,
+
+        /// Local start time
+        #[arg(long, value_name = "HH:MM", help = "Start time of the work (defaults to 09:00, or now minus the hours for today)")]
+        start: Option<String>,
+
+        /// What was worked on
+        #[arg(long, value_name = "TEXT", help = "Session note (e.g., \"offline work\")")]
+        note: Option<String>,
+    },
+
+    /// Correct a recorded time session (indices are shown by `rask time <id> --detailed`)
+    Edit {
+        /// Task the session belongs to
+        #[arg(value_name = "TASK_ID", help = "ID of the task the session belongs to")]
+        task_id: usize,
+
+        /// Index of the session to edit (0-based)
+        #[arg(value_name = "SESSION_INDEX", help = "Index of the session to edit (0-based)")]
+        session_index: usize,
+
+        /// New duration
+        #[arg(long, value_name = "HOURS", help = "New duration in hours")]
+        hours: Option<f64>,
+
+        /// New date, keeping the start time
+        #[arg(long, value_name = "DATE", help = "Move the session to this date (YYYY-MM-DD)")]
+        date: Option<String>,
+
+        /// New local start time, keeping the date
+        #[arg(long, value_name = "HH:MM", help = "New start time of the session")]
+        start: Option<String>,
+
+        /// New session note
+        #[arg(long, value_name = "TEXT", help = "Replace the session note")]
+        note: Option<String>,
+
+        /// Remove the session note
+        #[arg(long, conflicts_with = "note", help = "Remove the session note")]
+        clear_note: bool,
+    },
+}
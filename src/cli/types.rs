@@ -31,6 +31,46 @@ impl From<crate::model::Priority> for CliPriority {
     }
 }
 
+/// Energy level options
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum CliEnergy {
+    Low,
+    Medium,
+    High,
+}
+
+impl From<CliEnergy> for crate::model::Energy {
+    fn from(cli_energy: CliEnergy) -> Self {
+        match cli_energy {
+            CliEnergy::Low => crate::model::Energy::Low,
+            CliEnergy::Medium => crate::model::Energy::Medium,
+            CliEnergy::High => crate::model::Energy::High,
+        }
+    }
+}
+
+/// Implementation note type options
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum CliNoteKind {
+    General,
+    Decision,
+    Blocker,
+    Code,
+    Link,
+}
+
+impl From<CliNoteKind> for crate::model::NoteKind {
+    fn from(cli_kind: CliNoteKind) -> Self {
+        match cli_kind {
+            CliNoteKind::General => crate::model::NoteKind::General,
+            CliNoteKind::Decision => crate::model::NoteKind::Decision,
+            CliNoteKind::Blocker => crate::model::NoteKind::Blocker,
+            CliNoteKind::Code => crate::model::NoteKind::Code,
+            CliNoteKind::Link => crate::model::NoteKind::Link,
+        }
+    }
+}
+
 /// Export format options
 #[derive(ValueEnum, Clone)]
 pub enum ExportFormat {
@@ -40,4 +80,66 @@ pub enum ExportFormat {
     Csv,
     /// HTML format
     Html,
-} 
\ No newline at end of file
+    /// Markdown checklist (re-importable with `rask init`)
+    Markdown,
+    /// Mermaid Gantt chart
+    MermaidGantt,
+    /// Mermaid flowchart board with a column per phase and dependency arrows
+    MermaidBoard,
+    /// iCalendar file with due dates and time sessions
+    Ics,
+} 
+
+impl ExportFormat {
+    /// File extension used when an export file name has to be chosen
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Json => "json",
+            ExportFormat::Csv => "csv",
+            ExportFormat::Html => "html",
+            ExportFormat::Markdown => "md",
+            ExportFormat::MermaidGantt | ExportFormat::MermaidBoard => "mmd",
+            ExportFormat::Ics => "ics",
+        }
+    }
+}
+
+/// External time trackers for session import/export
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum TimeTracker {
+    /// Toggl Track CSV
+    Toggl,
+    /// Clockify CSV
+    Clockify,
+}
+
+/// Row grouping for timesheet reports
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum TimesheetGroup {
+    /// One row per task
+    Task,
+    /// One row per tag (tasks with several tags count under each)
+    Tag,
+    /// One row per phase
+    Phase,
+}
+
+/// Task events that `rask rules test` can simulate
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum RuleTestEvent {
+    /// The task was just added
+    Added,
+    /// The task was just completed
+    Completed,
+}
+
+/// What `rask template fetch` does with a template that differs from the local one
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum TemplateConflict {
+    /// Ask for each conflicting template
+    Ask,
+    /// Keep the local version
+    Keep,
+    /// Take the fetched version
+    Replace,
+}
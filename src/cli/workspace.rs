@@ -0,0 +1,29 @@
+use clap::Subcommand;
+
+/// Workspace commands: link child projects to this one and track them together
+#[derive(Subcommand)]
+pub enum WorkspaceCommands {
+    /// Link a child project to the workspace in the current directory
+    Add {
+        /// Child project directory, or a name registered under [projects]
+        #[arg(value_name = "PROJECT", help = "Directory containing the child's .rask, or a registered project name")]
+        project: String,
+
+        /// Name to show for the child
+        #[arg(long, value_name = "NAME", help = "Name for the child project (defaults to the registered name or directory name)")]
+        name: Option<String>,
+    },
+
+    /// Unlink a child project (its files are left untouched)
+    Remove {
+        /// Name of the linked project
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+
+    /// List the linked projects
+    List,
+
+    /// Show progress across every project in the workspace
+    Status,
+}
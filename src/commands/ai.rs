@@ -1,12 +1,15 @@
 //! AI command implementations for intelligent task management
 
+use std::collections::HashMap;
 use std::fs;
 use tokio::runtime::Runtime;
 
-use super::CommandResult;
+use super::{CommandError, CommandResult};
 use crate::ai::service::{utils, AiService};
-use crate::cli::AiCommands;
+use crate::ai::{AiOperation, AiRoadmapPhase, AiRoadmapPlan};
+use crate::cli::{AiCommands, CliPriority, TaskSelection};
 use crate::config::RaskConfig;
+use crate::model::Priority;
 use crate::state::load_state;
 use crate::ui::{display_error, display_info, display_success, display_warning};
 
@@ -62,12 +65,22 @@ pub fn handle_ai_command(ai_command: &AiCommands) -> CommandResult {
                 priority,
                 phase,
             } => handle_ai_suggest(*count, *apply, priority.as_deref(), phase.as_deref()).await,
+            AiCommands::Standup { since } => handle_ai_standup(since).await,
+            AiCommands::Estimate { task_id, apply } => handle_ai_estimate(*task_id, *apply).await,
+            AiCommands::Summarize { task_id } => handle_ai_summarize(*task_id).await,
+            AiCommands::Risks { apply } => handle_ai_risks(*apply).await,
+            AiCommands::Do { instruction, dry_run } => handle_ai_do(instruction, *dry_run).await,
+            AiCommands::ClearCache => handle_ai_clear_cache(),
+            AiCommands::Roadmap { brief: Some(brief), output, .. } => {
+                handle_ai_roadmap_from_brief(brief, output.as_deref()).await
+            }
             AiCommands::Roadmap {
                 file,
                 apply,
                 focus,
                 output,
                 generate_plan,
+                brief: None,
             } => {
                 handle_ai_roadmap(
                     file.as_deref(),
@@ -126,16 +139,19 @@ async fn handle_ai_chat(initial_message: Option<&str>, with_context: bool) -> Co
     // Send initial message if provided
     if let Some(msg) = initial_message {
         display_info(&format!("You: {}", msg));
-        match ai_service.chat(msg.to_string()).await {
-            Ok(response) => {
-                println!("🤖 AI: {}", response);
-                println!();
-            }
-            Err(e) => {
-                display_error(&format!("AI Error: {}", e));
-                return Ok(());
-            }
+        if let Err(e) = stream_chat_reply(&ai_service, msg).await {
+            display_error(&format!("AI Error: {}", e));
+            return Ok(());
+        }
+    }
+
+    // Without a terminal to talk to, answer the initial message and stop
+    if super::utils::is_non_interactive() {
+        if initial_message.is_none() {
+            return Err("AI chat needs a message when running non-interactively: rask ai chat \"<message>\"".into());
         }
+        ai_service.clear_chat_session().await;
+        return Ok(());
     }
 
     // Interactive chat loop
@@ -157,15 +173,9 @@ async fn handle_ai_chat(initial_message: Option<&str>, with_context: bool) -> Co
             break;
         }
 
-        match ai_service.chat(input.to_string()).await {
-            Ok(response) => {
-                println!("🤖 AI: {}", response);
-                println!();
-            }
-            Err(e) => {
-                display_error(&format!("AI Error: {}", e));
-                break;
-            }
+        if let Err(e) = stream_chat_reply(&ai_service, input).await {
+            display_error(&format!("AI Error: {}", e));
+            break;
         }
     }
 
@@ -174,6 +184,25 @@ async fn handle_ai_chat(initial_message: Option<&str>, with_context: bool) -> Co
     Ok(())
 }
 
+/// Print the AI's reply to a chat message as it streams in
+async fn stream_chat_reply(ai_service: &AiService, message: &str) -> anyhow::Result<()> {
+    print!("🤖 AI: ");
+    std::io::Write::flush(&mut std::io::stdout())?;
+    let result = ai_service.chat_stream(message.to_string(), |chunk| {
+        print!("{}", chunk);
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+    }).await;
+    println!("\n");
+    result.map(|_| ())
+}
+
+/// Handle AI clear-cache command
+fn handle_ai_clear_cache() -> CommandResult {
+    let removed = crate::ai::cache::clear_cache().map_err(|e| format!("Failed to clear AI cache: {}", e))?;
+    display_success(&format!("Removed {} cached AI response(s)", removed));
+    Ok(())
+}
+
 /// Handle AI analyze command
 async fn handle_ai_analyze(
     limit: usize,
@@ -303,45 +332,49 @@ async fn handle_ai_breakdown(
             println!("{}", formatted);
 
             if apply {
-                let mut roadmap = load_state()?;
-                let mut added_count = 0;
+                super::utils::run_with_checkpoint("ai breakdown --apply", || {
+                    let mut roadmap = load_state()?;
+                    let mut added_count = 0;
 
-                for suggestion in suggestions {
-                    let mut suggestion = suggestion;
+                    for suggestion in suggestions {
+                        let mut suggestion = suggestion;
 
-                    // Override phase if specified
-                    if let Some(phase_name) = default_phase {
-                        suggestion.phase = crate::model::Phase::from_string(phase_name);
-                    }
+                        // Override phase if specified
+                        if let Some(phase_name) = default_phase {
+                            suggestion.phase = crate::model::Phase::from_string(phase_name);
+                        }
 
-                    let new_id = roadmap.get_next_task_id();
-                    let mut task = utils::ai_suggestion_to_task(suggestion, new_id);
+                        let new_id = roadmap.get_next_task_id();
+                        let mut task = utils::ai_suggestion_to_task(suggestion, new_id);
 
-                    // Update AI info with correct operation and model
-                    task.mark_as_ai_generated(
-                        "breakdown",
-                        task.get_ai_reasoning().map(|s| s.clone()),
-                        Some(model_name.clone()),
-                    );
+                        // Update AI info with correct operation and model
+                        task.mark_as_ai_generated(
+                            "breakdown",
+                            task.get_ai_reasoning().map(|s| s.clone()),
+                            Some(model_name.clone()),
+                        );
+                        task.record_history("created", Some(format!("by AI breakdown ({})", model_name)));
 
-                    roadmap.add_task(task);
-                    added_count += 1;
-                }
+                        roadmap.add_task(task);
+                        added_count += 1;
+                    }
 
-                // Save the updated roadmap
-                if let Err(e) = crate::state::save_state(&roadmap) {
-                    display_error(&format!("Failed to save roadmap: {}", e));
-                    return Ok(());
-                }
+                    // Save the updated roadmap
+                    if let Err(e) = crate::state::save_state(&roadmap) {
+                        display_error(&format!("Failed to save roadmap: {}", e));
+                        return Ok(());
+                    }
 
-                // Update markdown file if available
-                if let Some(ref _source_file) = roadmap.source_file {
-                    if let Err(e) = crate::markdown_writer::sync_to_source_file(&roadmap) {
-                        display_warning(&format!("Failed to update markdown file: {}", e));
+                    // Update markdown file if available
+                    if let Some(ref _source_file) = roadmap.source_file {
+                        if let Err(e) = crate::markdown_writer::sync_to_source_file(&roadmap) {
+                            display_warning(&format!("Failed to update markdown file: {}", e));
+                        }
                     }
-                }
 
-                display_success(&format!("Applied {} tasks to the project!", added_count));
+                    display_success(&format!("Applied {} tasks to the project!", added_count));
+                    Ok(())
+                })?;
             } else {
                 println!();
                 display_info("Use --apply to add these tasks to your project");
@@ -463,10 +496,8 @@ async fn handle_ai_configure(
     temperature: Option<f32>,
     show: bool,
 ) -> CommandResult {
-    let mut config =
-        RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
-
     if show {
+        let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
         println!("🤖 AI Configuration:");
         println!("  Enabled: {}", config.ai.enabled);
         println!("  Provider: {}", config.ai.provider);
@@ -475,11 +506,21 @@ async fn handle_ai_configure(
         println!("  Max Tokens: {}", config.ai.max_tokens);
         println!("  Context Window: {}", config.ai.context_window);
         println!("  Auto Suggestions: {}", config.ai.auto_suggestions);
+        println!(
+            "  Response Cache: {}",
+            if config.ai.cache_ttl_minutes == 0 { "off".to_string() } else { format!("{} min", config.ai.cache_ttl_minutes) }
+        );
+        println!(
+            "  Rate Limit: {}",
+            if config.ai.requests_per_minute == 0 { "unlimited".to_string() } else { format!("{} requests/min", config.ai.requests_per_minute) }
+        );
         println!();
         println!(
             "  API Key Status: {}",
             if config.ai.get_api_key().is_some() {
                 "✅ Configured"
+            } else if config.ai.provider == "mock" {
+                "Not needed (offline mock provider)"
             } else {
                 "❌ Not set"
             }
@@ -489,16 +530,19 @@ async fn handle_ai_configure(
         return Ok(());
     }
 
+    // Profile and environment overrides must not be written back to the user config
+    let mut config =
+        RaskConfig::load_files().map_err(|e| format!("Failed to load configuration: {}", e))?;
     let mut updated = false;
 
     if let Some(p) = provider {
-        if p == "gemini" {
+        if p == "gemini" || p == "mock" {
             config.ai.provider = p.to_string();
             updated = true;
             display_success(&format!("Set AI provider to: {}", p));
         } else {
             display_error(&format!(
-                "Unsupported provider: {}. Only 'gemini' is currently supported.",
+                "Unsupported provider: {}. Supported providers: gemini, mock (offline).",
                 p
             ));
             return Ok(());
@@ -672,39 +716,43 @@ async fn handle_ai_suggest(
             println!("{}", formatted);
 
             if apply {
-                let mut roadmap = load_state()?;
-                let mut added_count = 0;
-
-                for suggestion in suggestions {
-                    let new_id = roadmap.get_next_task_id();
-                    let mut task = utils::ai_suggestion_to_task(suggestion, new_id);
-
-                    // Update AI info with correct operation and model
-                    task.mark_as_ai_generated(
-                        "suggest",
-                        task.get_ai_reasoning().map(|s| s.clone()),
-                        Some(model_name.clone()),
-                    );
-
-                    roadmap.add_task(task);
-                    added_count += 1;
-                }
+                super::utils::run_with_checkpoint("ai suggest --apply", || {
+                    let mut roadmap = load_state()?;
+                    let mut added_count = 0;
+
+                    for suggestion in suggestions {
+                        let new_id = roadmap.get_next_task_id();
+                        let mut task = utils::ai_suggestion_to_task(suggestion, new_id);
+
+                        // Update AI info with correct operation and model
+                        task.mark_as_ai_generated(
+                            "suggest",
+                            task.get_ai_reasoning().map(|s| s.clone()),
+                            Some(model_name.clone()),
+                        );
+                        task.record_history("created", Some(format!("by AI suggest ({})", model_name)));
 
-                if let Err(e) = crate::state::save_state(&roadmap) {
-                    display_error(&format!("Failed to save roadmap: {}", e));
-                    return Ok(());
-                }
+                        roadmap.add_task(task);
+                        added_count += 1;
+                    }
 
-                if let Some(ref _source_file) = roadmap.source_file {
-                    if let Err(e) = crate::markdown_writer::sync_to_source_file(&roadmap) {
-                        display_warning(&format!("Failed to update markdown file: {}", e));
+                    if let Err(e) = crate::state::save_state(&roadmap) {
+                        display_error(&format!("Failed to save roadmap: {}", e));
+                        return Ok(());
+                    }
+
+                    if let Some(ref _source_file) = roadmap.source_file {
+                        if let Err(e) = crate::markdown_writer::sync_to_source_file(&roadmap) {
+                            display_warning(&format!("Failed to update markdown file: {}", e));
+                        }
                     }
-                }
 
-                display_success(&format!(
-                    "Applied {} suggested tasks to the project!",
-                    added_count
-                ));
+                    display_success(&format!(
+                        "Applied {} suggested tasks to the project!",
+                        added_count
+                    ));
+                    Ok(())
+                })?;
             } else {
                 println!();
                 display_info("Use --apply to add these suggestions to your project");
@@ -718,6 +766,591 @@ async fn handle_ai_suggest(
     Ok(())
 }
 
+/// Handle AI standup command
+async fn handle_ai_standup(since: &str) -> CommandResult {
+    let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+
+    if !config.ai.is_ready() {
+        display_error("AI is not configured. Please run 'rask ai configure' first.");
+        return Ok(());
+    }
+
+    let since_date = super::utils::parse_since(since)?;
+    let roadmap = load_state()?;
+    let activity = standup_activity(&roadmap, since_date);
+    let ai_service = AiService::new(config)
+        .await
+        .map_err(|e| format!("Failed to initialize AI service: {}", e))?;
+
+    display_info(&format!("🗣️  Writing standup for activity since {}...", since_date.format("%a %Y-%m-%d")));
+
+    let standup = ai_service.generate_standup(&activity)
+        .await
+        .map_err(|e| format!("Failed to generate standup: {}", e))?;
+    println!("\n{}", standup);
+
+    Ok(())
+}
+
+/// Structured summary of completed, in-progress, blocked and ready work for the standup prompt
+fn standup_activity(roadmap: &crate::model::Roadmap, since: chrono::NaiveDate) -> String {
+    use crate::model::{parse_timestamp, TaskStatus};
+
+    let since_utc = since.and_hms_opt(0, 0, 0)
+        .and_then(|start| start.and_local_timezone(chrono::Local).earliest())
+        .map(|start| start.with_timezone(&chrono::Utc))
+        .unwrap_or_else(chrono::Utc::now);
+    let is_recent = |timestamp: &str| parse_timestamp(Some(timestamp)).is_some_and(|t| t >= since_utc);
+    let hours_since = |task: &crate::model::Task| -> f64 {
+        task.time_sessions.iter()
+            .filter(|s| is_recent(&s.start_time))
+            .map(|s| s.elapsed_hours())
+            .sum()
+    };
+    let line = |task: &crate::model::Task| format!("#{} {} [{}, {}]", task.id, task.description, task.priority, task.phase.name);
+
+    let mut sections = vec![format!("Project: {}\nPeriod: since {}", roadmap.title, since.format("%A %Y-%m-%d"))];
+
+    let completed: Vec<String> = roadmap.tasks.iter()
+        .filter(|t| t.status == TaskStatus::Completed && t.completed_at.as_deref().is_some_and(&is_recent))
+        .map(|t| match hours_since(t) {
+            hours if hours > 0.0 => format!("- {} ({:.1}h tracked)", line(t), hours),
+            _ => format!("- {}", line(t)),
+        })
+        .collect();
+    sections.push(format!("Completed:\n{}", if completed.is_empty() { "- none".to_string() } else { completed.join("\n") }));
+
+    let worked: Vec<String> = roadmap.tasks.iter()
+        .filter(|t| t.status == TaskStatus::Pending)
+        .filter_map(|t| {
+            let hours = hours_since(t);
+            (hours > 0.0 || t.has_active_time_session()).then(|| format!(
+                "- {} ({:.1}h tracked{})",
+                line(t),
+                hours,
+                if t.has_active_time_session() { ", timer running now" } else { "" }
+            ))
+        })
+        .collect();
+    sections.push(format!("Worked on, not finished:\n{}", if worked.is_empty() { "- none".to_string() } else { worked.join("\n") }));
+
+    let events: Vec<String> = roadmap.tasks.iter()
+        .flat_map(|t| t.history.iter().filter(|e| is_recent(&e.timestamp)).map(move |e| (t.id, e)))
+        .filter(|(_, e)| e.action != "completed")
+        .map(|(id, e)| format!("- #{} {}{}", id, e.action.replace('_', " "), e.details.as_ref().map(|d| format!(": {}", d)).unwrap_or_default()))
+        .collect();
+    if !events.is_empty() {
+        sections.push(format!("Other changes:\n{}", events.join("\n")));
+    }
+
+    let blocked: Vec<String> = roadmap.get_blocked_tasks().iter()
+        .take(10)
+        .map(|t| {
+            let waiting_on: Vec<String> = t.dependencies.iter()
+                .filter(|dep| roadmap.find_task_by_id(**dep).is_some_and(|d| d.status != TaskStatus::Completed))
+                .map(|dep| match t.dependency_notes.get(dep) {
+                    Some(note) => format!("#{} ({})", dep, note),
+                    None => format!("#{}", dep),
+                })
+                .collect();
+            format!("- {} waiting on {}", line(t), waiting_on.join(", "))
+        })
+        .collect();
+    sections.push(format!("Blocked:\n{}", if blocked.is_empty() { "- none".to_string() } else { blocked.join("\n") }));
+
+    let mut ready = roadmap.get_ready_tasks();
+    ready.sort_by(|a, b| b.priority.rank().cmp(&a.priority.rank()).then(a.id.cmp(&b.id)));
+    let next: Vec<String> = ready.iter().take(5).map(|t| format!("- {}", line(t))).collect();
+    sections.push(format!("Ready to start next:\n{}", if next.is_empty() { "- none".to_string() } else { next.join("\n") }));
+
+    sections.join("\n\n")
+}
+
+/// Handle AI estimate command
+async fn handle_ai_estimate(task_id: usize, apply: bool) -> CommandResult {
+    let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+
+    if !config.ai.is_ready() {
+        display_error("AI is not configured. Please run 'rask ai configure' first.");
+        return Ok(());
+    }
+
+    let model_name = config.ai.default_model.clone();
+    let roadmap = load_state()?;
+    let task = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    let ai_service = AiService::new(config)
+        .await
+        .map_err(|e| format!("Failed to initialize AI service: {}", e))?;
+
+    display_info(&format!("🤖 Estimating task #{}...", task_id));
+
+    let suggestion = ai_service.suggest_estimate(task, &roadmap)
+        .await
+        .map_err(|e| format!("Failed to generate estimate: {}", e))?;
+
+    println!("\n⏱️  Suggested estimate for #{}: {:.1}h ({}% confidence)", task_id, suggestion.estimated_hours, suggestion.confidence);
+    if let Some(current) = task.estimated_hours {
+        println!("   Current estimate: {:.1}h", current);
+    }
+    println!("   💭 {}", suggestion.reasoning);
+
+    if apply {
+        super::utils::run_with_checkpoint("ai estimate --apply", || {
+            let mut roadmap = load_state()?;
+            let task = roadmap.find_task_by_id_mut(task_id)
+                .ok_or_else(|| CommandError::task_not_found(task_id))?;
+            task.set_estimated_hours(suggestion.estimated_hours);
+            task.add_ai_suggestion(suggestion.reasoning.clone(), "estimate", Some(model_name.clone()));
+            task.record_history("estimated", Some(format!("{:.1}h (AI, {}% confidence)", suggestion.estimated_hours, suggestion.confidence)));
+            super::utils::save_and_sync(&roadmap)?;
+            display_success(&format!("Set estimate for task #{} to {:.1}h", task_id, suggestion.estimated_hours));
+            Ok(())
+        })?;
+    } else {
+        println!();
+        display_info("Use --apply to save this estimate on the task");
+    }
+
+    Ok(())
+}
+
+/// Handle AI summarize command: store a summary of the task's notes,
+/// shown at the top of `rask view`
+async fn handle_ai_summarize(task_id: usize) -> CommandResult {
+    let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+
+    if !config.ai.is_ready() {
+        display_error("AI is not configured. Please run 'rask ai configure' first.");
+        return Ok(());
+    }
+
+    let model_name = config.ai.default_model.clone();
+    let roadmap = load_state()?;
+    let task = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    let has_notes = task.notes.as_deref().is_some_and(|notes| !notes.trim().is_empty());
+    if !has_notes && task.implementation_notes.is_empty() && task.comments.is_empty() {
+        return Err(format!("Task #{} has no notes or comments to summarize", task_id).into());
+    }
+    let ai_service = AiService::new(config)
+        .await
+        .map_err(|e| format!("Failed to initialize AI service: {}", e))?;
+
+    if !super::utils::json_output() {
+        display_info(&format!("🤖 Summarizing the notes on task #{}...", task_id));
+    }
+    let text = ai_service.summarize_task_notes(task)
+        .await
+        .map_err(|e| format!("Failed to summarize notes: {}", e))?;
+
+    let mut roadmap = load_state()?;
+    let task = roadmap.find_task_by_id_mut(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    task.summary = Some(crate::model::NotesSummary::new(text, Some(model_name)));
+    let summary = task.summary.clone();
+    super::utils::save_and_sync(&roadmap)?;
+
+    if super::utils::json_output() {
+        return super::utils::print_json(&summary);
+    }
+    if let Some(summary) = summary {
+        println!("\n📝 Summary of task #{}:\n{}", task_id, summary.text);
+    }
+    display_success(&format!("Saved; 'rask view {}' shows it at the top", task_id));
+    Ok(())
+}
+
+/// Tag namespace `rask ai risks --apply` writes to
+const RISK_TAG: &str = "risk";
+
+/// Tag for a kind of risk, e.g. `risk/dependency-chain`
+fn risk_tag(kind: &str) -> String {
+    let kind: String = kind.trim().to_lowercase().chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' { c } else { '-' })
+        .collect();
+    let kind = kind.trim_matches('-');
+    format!("{}/{}", RISK_TAG, if kind.is_empty() { "general" } else { kind })
+}
+
+/// Handle AI risks command
+async fn handle_ai_risks(apply: bool) -> CommandResult {
+    use colored::Colorize;
+    let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+
+    if !config.ai.is_ready() {
+        display_error("AI is not configured. Please run 'rask ai configure' first.");
+        return Ok(());
+    }
+
+    let model_name = config.ai.default_model.clone();
+    let roadmap = load_state()?;
+    let ai_service = AiService::new(config)
+        .await
+        .map_err(|e| format!("Failed to initialize AI service: {}", e))?;
+
+    if !super::utils::json_output() {
+        display_info("🤖 Looking for risks in the roadmap...");
+    }
+    let risks = ai_service.identify_risks(&roadmap)
+        .await
+        .map_err(|e| format!("Failed to identify risks: {}", e))?;
+
+    if super::utils::json_output() {
+        super::utils::print_json(&risks)?;
+    } else if risks.is_empty() {
+        display_success("No risks found in the dependencies, estimates or deadlines");
+    } else {
+        println!("\n⚠️  {} risk(s) found:", risks.len());
+        for risk in &risks {
+            let severity = format!("[{}]", risk.severity.to_uppercase());
+            let severity = match risk.severity_rank() {
+                3 => severity.bright_red().bold(),
+                2 => severity.red(),
+                1 => severity.yellow(),
+                _ => severity.bright_black(),
+            };
+            println!("\n  {} {} {}", severity, risk.description.bold(), format!("({})", risk.kind).bright_black());
+            for id in &risk.task_ids {
+                if let Some(task) = roadmap.find_task_by_id(*id) {
+                    println!("     #{} {}", id, task.description);
+                }
+            }
+            if !risk.mitigation.is_empty() {
+                println!("     💡 {}", risk.mitigation.bright_blue());
+            }
+        }
+    }
+
+    if apply {
+        super::utils::run_with_checkpoint("ai risks --apply", || {
+            let mut roadmap = load_state()?;
+            let mut tagged = 0;
+            for task in roadmap.tasks.iter_mut().filter(|task| task.status == crate::model::TaskStatus::Pending) {
+                let before = task.tags.clone();
+                // Tags from an earlier run are replaced so they follow the current risks
+                task.tags.retain(|tag| !crate::model::tag_within(tag, RISK_TAG));
+                let id = task.id;
+                for risk in risks.iter().filter(|risk| risk.task_ids.contains(&id)) {
+                    task.tags.insert(risk_tag(&risk.kind));
+                    task.add_ai_suggestion(risk.description.clone(), "risks", Some(model_name.clone()));
+                }
+                if task.tags != before {
+                    tagged += 1;
+                }
+            }
+            super::utils::save_and_sync(&roadmap)?;
+            display_success(&format!("Updated risk tags on {} task(s); list them with 'rask list --tag {}'", tagged, RISK_TAG));
+            Ok(())
+        })?;
+    } else if !risks.is_empty() && !super::utils::json_output() {
+        println!();
+        display_info(&format!("Use --apply to tag the affected tasks with {}/<kind>", RISK_TAG));
+    }
+
+    Ok(())
+}
+
+/// A step of an `rask ai do` plan, checked and ready to run through the
+/// command that does the same thing from the command line
+enum DoStep {
+    Complete(Vec<usize>),
+    Reopen(Vec<usize>),
+    SetPhase(Vec<usize>, String),
+    SetPriority(Vec<usize>, CliPriority),
+    AddTags(Vec<usize>, String),
+    RemoveTags(Vec<usize>, String),
+    Assign(Vec<usize>, String),
+    Unassign(Vec<usize>, String),
+    Edit(usize, String),
+    AddTask(String),
+}
+
+impl DoStep {
+    fn from_operation(operation: &AiOperation) -> Result<Self, String> {
+        let ids = operation.task_ids.clone();
+        let value = operation.value.trim().to_string();
+        let action = operation.action.trim().to_lowercase().replace('-', "_");
+        let needs_tasks = !matches!(action.as_str(), "add_task");
+        if needs_tasks && ids.is_empty() {
+            return Err(format!("'{}' does not name any existing task", action));
+        }
+        let needs_value = !matches!(action.as_str(), "complete" | "reopen");
+        if needs_value && value.is_empty() {
+            return Err(format!("'{}' has no value", action));
+        }
+        Ok(match action.as_str() {
+            "complete" => DoStep::Complete(ids),
+            "reopen" => DoStep::Reopen(ids),
+            "set_phase" => DoStep::SetPhase(ids, value),
+            "set_priority" => match clap::ValueEnum::from_str(&value, true) {
+                Ok(priority) => DoStep::SetPriority(ids, priority),
+                Err(_) => return Err(format!("unknown priority '{}'", value)),
+            },
+            "add_tags" => DoStep::AddTags(ids, value),
+            "remove_tags" => DoStep::RemoveTags(ids, value),
+            "assign" => DoStep::Assign(ids, value),
+            "unassign" => DoStep::Unassign(ids, value),
+            "edit" if ids.len() == 1 => DoStep::Edit(ids[0], value),
+            "edit" => return Err("'edit' applies to one task at a time".to_string()),
+            "add_task" => DoStep::AddTask(value),
+            other => return Err(format!("unsupported action '{}'", other)),
+        })
+    }
+
+    /// Tasks the step changes
+    fn task_ids(&self) -> &[usize] {
+        match self {
+            DoStep::Complete(ids) | DoStep::Reopen(ids) | DoStep::SetPhase(ids, _) | DoStep::SetPriority(ids, _)
+            | DoStep::AddTags(ids, _) | DoStep::RemoveTags(ids, _) | DoStep::Assign(ids, _) | DoStep::Unassign(ids, _) => ids,
+            DoStep::Edit(id, _) => std::slice::from_ref(id),
+            DoStep::AddTask(_) => &[],
+        }
+    }
+
+    /// The equivalent command line
+    fn command(&self) -> String {
+        let ids = |ids: &[usize]| ids.iter().map(usize::to_string).collect::<Vec<_>>().join(",");
+        match self {
+            DoStep::Complete(t) => format!("rask bulk complete {}", ids(t)),
+            DoStep::Reopen(t) => format!("rask bulk reset {}", ids(t)),
+            DoStep::SetPhase(t, phase) => format!("rask bulk set-phase {} {:?}", ids(t), phase),
+            DoStep::SetPriority(t, priority) => format!("rask bulk set-priority {} {}", ids(t), format!("{:?}", priority).to_lowercase()),
+            DoStep::AddTags(t, tags) => format!("rask bulk add-tags {} {:?}", ids(t), tags),
+            DoStep::RemoveTags(t, tags) => format!("rask bulk remove-tags {} {:?}", ids(t), tags),
+            DoStep::Assign(t, owners) => format!("rask bulk assign {} {:?}", ids(t), owners),
+            DoStep::Unassign(t, owners) => format!("rask bulk unassign {} {:?}", ids(t), owners),
+            DoStep::Edit(id, description) => format!("rask edit {} {:?}", id, description),
+            DoStep::AddTask(description) => format!("rask add {:?}", description),
+        }
+    }
+
+    fn run(&self) -> CommandResult {
+        let select = |ids: &[usize]| TaskSelection {
+            ids: Some(ids.iter().map(usize::to_string).collect::<Vec<_>>().join(",")),
+            filter: None,
+        };
+        match self {
+            DoStep::Complete(ids) => super::bulk_complete_tasks(&select(ids)),
+            DoStep::Reopen(ids) => super::bulk_reset_tasks(&select(ids)),
+            DoStep::SetPhase(ids, phase) => super::bulk_set_phase(&select(ids), phase),
+            DoStep::SetPriority(ids, priority) => super::bulk_set_priority(&select(ids), priority),
+            DoStep::AddTags(ids, tags) => super::bulk_add_tags(&select(ids), tags),
+            DoStep::RemoveTags(ids, tags) => super::bulk_remove_tags(&select(ids), tags),
+            DoStep::Assign(ids, owners) => super::bulk_assign_owners(&select(ids), owners),
+            DoStep::Unassign(ids, owners) => super::bulk_unassign_owners(&select(ids), owners),
+            DoStep::Edit(id, description) => super::edit_task(*id, Some(description), None, false, None, None),
+            DoStep::AddTask(description) => super::add_task_enhanced(
+                description, &None, &None, &None, &None, &None, &None, &None, &[], &None, None, &None, false,
+            ),
+        }
+    }
+}
+
+/// Handle `rask ai do`: plan CLI operations from an instruction, show the
+/// plan, and run it once confirmed
+async fn handle_ai_do(instruction: &str, dry_run: bool) -> CommandResult {
+    use colored::Colorize;
+    let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+
+    if !config.ai.is_ready() {
+        display_error("AI is not configured. Please run 'rask ai configure' first.");
+        return Ok(());
+    }
+    if instruction.trim().is_empty() {
+        return Err("The instruction is empty".into());
+    }
+
+    let roadmap = load_state()?;
+    let ai_service = AiService::new(config)
+        .await
+        .map_err(|e| format!("Failed to initialize AI service: {}", e))?;
+
+    display_info("🤖 Planning the changes...");
+    let operations = ai_service.plan_operations(&roadmap, instruction)
+        .await
+        .map_err(|e| format!("Failed to plan the changes: {}", e))?;
+
+    let mut steps = Vec::new();
+    for operation in &operations {
+        match DoStep::from_operation(operation) {
+            Ok(step) => steps.push(step),
+            Err(reason) => display_warning(&format!("Leaving out a step: {}", reason)),
+        }
+    }
+    if steps.is_empty() {
+        display_warning("Nothing in the instruction maps to a change rask can make");
+        return Ok(());
+    }
+
+    println!("\n📋 Plan ({} step(s)):", steps.len());
+    for (index, step) in steps.iter().enumerate() {
+        println!("\n  {}. {}", index + 1, step.command().bright_cyan());
+        for id in step.task_ids() {
+            if let Some(task) = roadmap.find_task_by_id(*id) {
+                println!("     #{} {}", id, task.description.bright_black());
+            }
+        }
+    }
+    println!();
+
+    if dry_run {
+        display_info("Dry run: nothing was changed");
+        return Ok(());
+    }
+    if !super::utils::confirm("Run this plan?")? {
+        display_info("Plan cancelled, nothing was changed");
+        return Ok(());
+    }
+
+    super::utils::run_with_checkpoint("ai do", || {
+        for step in &steps {
+            step.run()?;
+        }
+        Ok(())
+    })?;
+    display_success(&format!("Ran all {} step(s) of the plan", steps.len()));
+    Ok(())
+}
+
+/// File a roadmap generated from a brief is written to by default
+const BRIEF_ROADMAP_FILE: &str = "roadmap.md";
+
+/// Markdown for the accepted phases of a generated roadmap, in the layout
+/// `rask init` reads. Each task carries its plan position as an `id` in a
+/// `<!-- rask: -->` comment so its `deps` are wired up on import.
+fn brief_roadmap_markdown(plan: &AiRoadmapPlan, phases: &[&AiRoadmapPhase]) -> String {
+    let mut markdown = format!("# {}\n\n", plan.title.trim());
+    if !plan.description.trim().is_empty() {
+        markdown.push_str(&format!("{}\n\n", plan.description.trim()));
+    }
+
+    let ids: HashMap<&str, usize> = phases.iter()
+        .flat_map(|phase| phase.tasks.iter())
+        .enumerate()
+        .map(|(index, task)| (task.key.as_str(), index + 1))
+        .collect();
+
+    for phase in phases {
+        markdown.push_str(&format!("## {}\n\n", phase.name.trim()));
+        if !phase.description.trim().is_empty() {
+            markdown.push_str(&format!("{}\n\n", phase.description.trim()));
+        }
+        for task in &phase.tasks {
+            let mut metadata = Vec::new();
+            if let Some(hours) = task.estimated_hours.filter(|hours| *hours > 0.0) {
+                metadata.push(format!("est: {}h", (hours * 100.0).round() / 100.0));
+            }
+            match crate::importers::parse_priority(&task.priority) {
+                Some(Priority::Medium) | None => {}
+                Some(priority) => metadata.push(format!("priority: {}", priority.to_string().to_lowercase())),
+            }
+            let tags: Vec<String> = task.tags.iter()
+                .map(|tag| tag.trim().trim_start_matches('#').replace([',', ' '], "-"))
+                .filter(|tag| !tag.is_empty())
+                .collect();
+            if !tags.is_empty() {
+                metadata.push(format!("tags: {}", tags.join(", ")));
+            }
+            let metadata = if metadata.is_empty() { String::new() } else { format!(" ({})", metadata.join(", ")) };
+
+            // Dependencies on tasks in skipped phases are dropped
+            let deps: Vec<String> = task.depends_on.iter()
+                .filter_map(|key| ids.get(key.as_str()))
+                .map(usize::to_string)
+                .collect();
+            let mut comment = format!("id={}", ids[task.key.as_str()]);
+            if !deps.is_empty() {
+                comment.push_str(&format!(" deps={}", deps.join(",")));
+            }
+
+            let description = task.description.lines().collect::<Vec<_>>().join(" ");
+            markdown.push_str(&format!("- [ ] {}{} <!-- rask: {} -->\n", description.trim(), metadata, comment));
+        }
+        markdown.push('\n');
+    }
+
+    markdown
+}
+
+/// Handle `rask ai roadmap --brief "<brief>"`: generate a phased roadmap, let the
+/// user accept or skip each phase, and write the accepted ones to markdown
+async fn handle_ai_roadmap_from_brief(brief: &str, output: Option<&str>) -> CommandResult {
+    use colored::Colorize;
+    let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+
+    if !config.ai.is_ready() {
+        display_error("AI is not configured. Please run 'rask ai configure' first.");
+        return Ok(());
+    }
+    if brief.trim().is_empty() {
+        return Err("The project brief is empty".into());
+    }
+
+    let output = output.unwrap_or(BRIEF_ROADMAP_FILE);
+    if std::path::Path::new(output).exists()
+        && !super::utils::confirm(&format!("{} already exists. Overwrite it?", output))?
+    {
+        display_info("Roadmap generation cancelled");
+        return Ok(());
+    }
+
+    let ai_service = AiService::new(config)
+        .await
+        .map_err(|e| format!("Failed to initialize AI service: {}", e))?;
+
+    display_info("🗓 Generating a roadmap from the project brief...");
+    let plan = ai_service.generate_roadmap_from_brief(brief)
+        .await
+        .map_err(|e| format!("Failed to generate roadmap: {}", e))?;
+    if plan.phases.is_empty() {
+        display_warning("The generated roadmap has no tasks");
+        return Ok(());
+    }
+
+    println!("\n📋 {}", plan.title.bold());
+    if !plan.description.is_empty() {
+        println!("   {}", plan.description.bright_black());
+    }
+
+    let keys: HashMap<&str, &str> = plan.phases.iter()
+        .flat_map(|phase| phase.tasks.iter())
+        .map(|task| (task.key.as_str(), task.description.as_str()))
+        .collect();
+    let mut accepted = Vec::new();
+    for (index, phase) in plan.phases.iter().enumerate() {
+        println!("\n  {} {}", format!("Phase {}/{}:", index + 1, plan.phases.len()).bright_black(), phase.name.bold().bright_cyan());
+        if !phase.description.is_empty() {
+            println!("  {}", phase.description.bright_black());
+        }
+        for task in &phase.tasks {
+            let estimate = task.estimated_hours.map(|hours| format!(", {:.1}h", hours)).unwrap_or_default();
+            println!("    • {} {}", task.description, format!("({}{})", task.priority.to_lowercase(), estimate).bright_black());
+            let after: Vec<&str> = task.depends_on.iter().filter_map(|key| keys.get(key.as_str()).copied()).collect();
+            if !after.is_empty() {
+                println!("      {} {}", "after:".bright_black(), after.join("; ").bright_black());
+            }
+        }
+        if super::utils::confirm(&format!("  Include the {} phase?", phase.name))? {
+            accepted.push(phase);
+        } else {
+            display_info(&format!("Skipped the {} phase", phase.name));
+        }
+    }
+
+    if accepted.is_empty() {
+        display_warning("No phases accepted, nothing was written");
+        return Ok(());
+    }
+
+    fs::write(output, brief_roadmap_markdown(&plan, &accepted))
+        .map_err(|e| format!("Failed to write to file: {}", e))?;
+    let tasks: usize = accepted.iter().map(|phase| phase.tasks.len()).sum();
+    display_success(&format!("Roadmap with {} phase(s) and {} task(s) written to {}", accepted.len(), tasks, output));
+    display_info(&format!("Run 'rask init {}' to start tracking it", output));
+    Ok(())
+}
+
 /// Handle AI roadmap command
 pub async fn handle_ai_roadmap(
     file: Option<&str>,
@@ -726,6 +1359,9 @@ pub async fn handle_ai_roadmap(
     output: Option<&str>,
     generate_plan: bool,
 ) -> CommandResult {
+    if let Some(file) = file.filter(|file| !std::path::Path::new(file).exists()) {
+        return Err(CommandError::NotFound(format!("Roadmap file '{}' not found", file)).into());
+    }
     let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
 
     if !config.ai.is_ready() {
@@ -1,6 +1,6 @@
-use crate::model::{Roadmap, Task, TaskStatus, Priority, Phase};
+use crate::model::{MilestoneProgress, Roadmap, Task, TaskStatus, Priority, Phase};
 use crate::{state, ui};
-use super::CommandResult;
+use super::{CommandResult, utils};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use serde::Serialize;
@@ -18,7 +18,10 @@ pub struct ProgressAnalytics {
     pub estimation_accuracy: f64,
     pub phase_analytics: Vec<PhaseAnalytics>,
     pub priority_analytics: Vec<PriorityAnalytics>,
+    pub tag_analytics: Vec<TagAnalytics>,
     pub time_analytics: TimeAnalytics,
+    pub staleness: StalenessAnalytics,
+    pub milestones: Vec<MilestoneProgress>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -43,6 +46,19 @@ pub struct PriorityAnalytics {
     pub average_completion_time: f64,
 }
 
+/// Tasks under one top-level tag namespace (`area` for `area/backend/auth`)
+#[derive(Debug, Clone, Serialize)]
+pub struct TagAnalytics {
+    pub namespace: String,
+    pub total_tasks: usize,
+    pub completed_tasks: usize,
+    pub completion_rate: f64,
+    pub estimated_hours: f64,
+    pub actual_hours: f64,
+    /// Distinct tags in the namespace, including the bare namespace tag
+    pub tags: usize,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct TimeAnalytics {
     pub total_estimated_hours: f64,
@@ -60,19 +76,46 @@ pub struct TimeAnalytics {
     pub average_session_duration: f64,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct StalenessAnalytics {
+    pub stale_after_days: u32,
+    pub stale_tasks: usize,
+    pub average_idle_days: f64,
+    /// Pending tasks idle for under a week, a week to a month, one to three months, and longer
+    pub idle_buckets: [usize; 4],
+    pub oldest: Vec<StaleTask>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StaleTask {
+    pub id: usize,
+    pub description: String,
+    pub idle_days: i64,
+}
+
+/// Number of tasks idle for a week or more listed in the staleness section
+const MAX_OLDEST_TASKS: usize = 5;
+
 /// Main analytics command handler
+#[allow(clippy::too_many_arguments)]
 pub fn show_analytics(
     overview: bool,
     time_focus: bool,
     phases: bool,
     priorities: bool,
+    tags: bool,
     trends: bool,
+    stale: bool,
     export_format: Option<String>,
 ) -> CommandResult {
     let roadmap = state::load_state()?;
     let analytics = calculate_analytics(&roadmap)?;
     
-    if overview || (!time_focus && !phases && !priorities && !trends) {
+    if utils::json_output() {
+        return utils::print_json(&analytics);
+    }
+    
+    if overview || (!time_focus && !phases && !priorities && !tags && !trends && !stale) {
         ui::display_analytics_overview(&analytics);
     }
     
@@ -88,12 +131,20 @@ pub fn show_analytics(
         ui::display_priority_analytics(&analytics.priority_analytics);
     }
     
+    if tags {
+        ui::display_tag_analytics(&analytics.tag_analytics);
+    }
+    
     if trends {
         ui::display_trend_analytics(&roadmap, &analytics)?;
     }
     
-    if let Some(format) = export_format {
-        export_analytics_report(&analytics, &format)?;
+    if stale {
+        ui::display_staleness_analytics(&analytics.staleness);
+    }
+    
+    if let Some(target) = export_format {
+        export_analytics_report(&roadmap, &analytics, &target)?;
     }
     
     Ok(())
@@ -101,8 +152,10 @@ pub fn show_analytics(
 
 /// Calculate comprehensive analytics from roadmap data
 fn calculate_analytics(roadmap: &Roadmap) -> Result<ProgressAnalytics, Box<dyn std::error::Error>> {
-    let total_tasks = roadmap.tasks.len();
-    let completed_tasks = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+    // Archived phases only contain completed tasks, so they count towards both totals
+    let archived_tasks: usize = roadmap.archived_phases.iter().map(|a| a.total_tasks).sum();
+    let total_tasks = roadmap.tasks.len() + archived_tasks;
+    let completed_tasks = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count() + archived_tasks;
     let pending_tasks = total_tasks - completed_tasks;
     let completion_rate = if total_tasks > 0 { completed_tasks as f64 / total_tasks as f64 * 100.0 } else { 0.0 };
     
@@ -122,9 +175,14 @@ fn calculate_analytics(roadmap: &Roadmap) -> Result<ProgressAnalytics, Box<dyn s
     // Calculate priority analytics
     let priority_analytics = calculate_priority_analytics(roadmap);
     
+    // Aggregate hierarchical tags by their top-level namespace
+    let tag_analytics = calculate_tag_analytics(roadmap);
+    
     // Calculate time analytics
     let time_analytics = calculate_time_analytics(roadmap);
     
+    let staleness = calculate_staleness_analytics(roadmap);
+    
     Ok(ProgressAnalytics {
         total_tasks,
         completed_tasks,
@@ -136,7 +194,10 @@ fn calculate_analytics(roadmap: &Roadmap) -> Result<ProgressAnalytics, Box<dyn s
         estimation_accuracy,
         phase_analytics,
         priority_analytics,
+        tag_analytics,
         time_analytics,
+        staleness,
+        milestones: roadmap.milestone_progress(chrono::Local::now().date_naive()),
     })
 }
 
@@ -155,17 +216,13 @@ fn calculate_task_velocity(roadmap: &Roadmap) -> f64 {
     let mut latest_date: Option<DateTime<Utc>> = None;
     
     for task in &completed_tasks {
-        if let Some(completed_at) = &task.completed_at {
-            if let Ok(date) = DateTime::parse_from_rfc3339(completed_at) {
-                let utc_date = date.with_timezone(&Utc);
-                
-                if earliest_date.is_none() || utc_date < earliest_date.unwrap() {
-                    earliest_date = Some(utc_date);
-                }
-                
-                if latest_date.is_none() || utc_date > latest_date.unwrap() {
-                    latest_date = Some(utc_date);
-                }
+        if let Some(utc_date) = task.completed_datetime() {
+            if earliest_date.is_none() || utc_date < earliest_date.unwrap() {
+                earliest_date = Some(utc_date);
+            }
+            
+            if latest_date.is_none() || utc_date > latest_date.unwrap() {
+                latest_date = Some(utc_date);
             }
         }
     }
@@ -199,19 +256,8 @@ fn calculate_average_completion_time(roadmap: &Roadmap) -> f64 {
     let completion_times: Vec<f64> = roadmap.tasks.iter()
         .filter(|t| t.status == TaskStatus::Completed)
         .filter_map(|t| {
-            if let (Some(created), Some(completed)) = (&t.created_at, &t.completed_at) {
-                if let (Ok(created_date), Ok(completed_date)) = (
-                    DateTime::parse_from_rfc3339(created),
-                    DateTime::parse_from_rfc3339(completed)
-                ) {
-                    let duration = completed_date - created_date;
-                    Some(duration.num_days() as f64)
-                } else {
-                    None
-                }
-            } else {
-                None
-            }
+            let (created_date, completed_date) = (t.created_datetime()?, t.completed_datetime()?);
+            Some((completed_date - created_date).num_days() as f64)
         })
         .collect();
     
@@ -286,6 +332,37 @@ fn calculate_phase_analytics(roadmap: &Roadmap) -> Vec<PhaseAnalytics> {
         });
     }
     
+    // Fold in archived phases from their stored summaries
+    for archived in &roadmap.archived_phases {
+        let existing = phase_analytics.iter_mut()
+            .find(|p| p.phase.name.eq_ignore_ascii_case(&archived.phase));
+        
+        match existing {
+            Some(analytics) => {
+                analytics.total_tasks += archived.total_tasks;
+                analytics.completed_tasks += archived.total_tasks;
+                analytics.completion_rate = analytics.completed_tasks as f64 / analytics.total_tasks as f64 * 100.0;
+                analytics.estimated_hours += archived.estimated_hours;
+                analytics.actual_hours += archived.actual_hours;
+                analytics.variance_hours = analytics.actual_hours - analytics.estimated_hours;
+            }
+            None if archived.total_tasks > 0 => {
+                phase_analytics.push(PhaseAnalytics {
+                    phase: Phase::from_string(&archived.phase),
+                    total_tasks: archived.total_tasks,
+                    completed_tasks: archived.total_tasks,
+                    completion_rate: 100.0,
+                    estimated_hours: archived.estimated_hours,
+                    actual_hours: archived.actual_hours,
+                    variance_hours: archived.actual_hours - archived.estimated_hours,
+                    ready_tasks: 0,
+                    blocked_tasks: 0,
+                });
+            }
+            None => {}
+        }
+    }
+    
     // Sort by completion rate (highest first)
     phase_analytics.sort_by(|a, b| b.completion_rate.partial_cmp(&a.completion_rate).unwrap_or(std::cmp::Ordering::Equal));
     
@@ -307,19 +384,8 @@ fn calculate_priority_analytics(roadmap: &Roadmap) -> Vec<PriorityAnalytics> {
         let completion_times: Vec<f64> = tasks.iter()
             .filter(|t| t.status == TaskStatus::Completed)
             .filter_map(|t| {
-                if let (Some(created), Some(completed)) = (&t.created_at, &t.completed_at) {
-                    if let (Ok(created_date), Ok(completed_date)) = (
-                        DateTime::parse_from_rfc3339(created),
-                        DateTime::parse_from_rfc3339(completed)
-                    ) {
-                        let duration = completed_date - created_date;
-                        Some(duration.num_days() as f64)
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                }
+                let (created_date, completed_date) = (t.created_datetime()?, t.completed_datetime()?);
+                Some((completed_date - created_date).num_days() as f64)
             })
             .collect();
         
@@ -343,6 +409,41 @@ fn calculate_priority_analytics(roadmap: &Roadmap) -> Vec<PriorityAnalytics> {
     priority_analytics
 }
 
+/// Calculate analytics per top-level tag namespace, largest first. A task
+/// with several tags in one namespace counts once for it.
+fn calculate_tag_analytics(roadmap: &Roadmap) -> Vec<TagAnalytics> {
+    let mut namespaces: HashMap<&str, (Vec<&Task>, std::collections::HashSet<&str>)> = HashMap::new();
+    for task in &roadmap.tasks {
+        let mut seen = std::collections::HashSet::new();
+        for tag in &task.tags {
+            let namespace = crate::model::tag_root(tag);
+            let entry = namespaces.entry(namespace).or_default();
+            entry.1.insert(tag.as_str());
+            if seen.insert(namespace) {
+                entry.0.push(task);
+            }
+        }
+    }
+    
+    let mut tag_analytics: Vec<TagAnalytics> = namespaces.into_iter()
+        .map(|(namespace, (tasks, tags))| {
+            let total_tasks = tasks.len();
+            let completed_tasks = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+            TagAnalytics {
+                namespace: namespace.to_string(),
+                total_tasks,
+                completed_tasks,
+                completion_rate: completed_tasks as f64 / total_tasks as f64 * 100.0,
+                estimated_hours: tasks.iter().filter_map(|t| t.estimated_hours).fold(0.0, |sum, hours| sum + hours),
+                actual_hours: tasks.iter().filter_map(|t| t.actual_hours).fold(0.0, |sum, hours| sum + hours),
+                tags: tags.len(),
+            }
+        })
+        .collect();
+    tag_analytics.sort_by(|a, b| b.total_tasks.cmp(&a.total_tasks).then_with(|| a.namespace.cmp(&b.namespace)));
+    tag_analytics
+}
+
 /// Calculate comprehensive time tracking analytics
 fn calculate_time_analytics(roadmap: &Roadmap) -> TimeAnalytics {
     let total_estimated_hours: f64 = roadmap.tasks.iter().filter_map(|t| t.estimated_hours).sum();
@@ -407,16 +508,55 @@ fn calculate_time_analytics(roadmap: &Roadmap) -> TimeAnalytics {
     }
 }
 
+/// How long pending tasks have gone without activity
+fn calculate_staleness_analytics(roadmap: &Roadmap) -> StalenessAnalytics {
+    let stale_after_days = crate::config::RaskConfig::load().map(|c| c.ui.stale_days).unwrap_or(30);
+    let now = Utc::now();
+    let mut idle: Vec<(&Task, i64)> = roadmap.tasks.iter()
+        .filter(|t| t.status == TaskStatus::Pending)
+        .filter_map(|t| t.idle_days(now).map(|days| (t, days)))
+        .collect();
+    idle.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.id.cmp(&b.0.id)));
+    
+    let mut idle_buckets = [0; 4];
+    for (_, days) in &idle {
+        let bucket = match days {
+            0..=6 => 0,
+            7..=29 => 1,
+            30..=89 => 2,
+            _ => 3,
+        };
+        idle_buckets[bucket] += 1;
+    }
+    
+    let stale_tasks = if stale_after_days == 0 {
+        0
+    } else {
+        idle.iter().filter(|(_, days)| *days >= i64::from(stale_after_days)).count()
+    };
+    let average_idle_days = if idle.is_empty() {
+        0.0
+    } else {
+        idle.iter().map(|(_, days)| *days as f64).sum::<f64>() / idle.len() as f64
+    };
+    
+    StalenessAnalytics {
+        stale_after_days,
+        stale_tasks,
+        average_idle_days,
+        idle_buckets,
+        oldest: idle.iter()
+            .filter(|(_, days)| *days >= 7)
+            .take(MAX_OLDEST_TASKS)
+            .map(|(task, days)| StaleTask { id: task.id, description: task.description.clone(), idle_days: *days })
+            .collect(),
+    }
+}
+
 /// Calculate project duration in days
 fn calculate_project_duration_days(roadmap: &Roadmap) -> f64 {
     let dates: Vec<DateTime<Utc>> = roadmap.tasks.iter()
-        .filter_map(|t| {
-            if let Some(created_at) = &t.created_at {
-                DateTime::parse_from_rfc3339(created_at).ok().map(|d| d.with_timezone(&Utc))
-            } else {
-                None
-            }
-        })
+        .filter_map(|t| t.created_datetime())
         .collect();
     
     if dates.len() < 2 {
@@ -429,19 +569,33 @@ fn calculate_project_duration_days(roadmap: &Roadmap) -> f64 {
     duration.num_days().max(1) as f64
 }
 
-/// Export analytics report in specified format
-fn export_analytics_report(analytics: &ProgressAnalytics, format: &str) -> CommandResult {
-    match format.to_lowercase().as_str() {
-        "json" => {
+/// Export the analytics: `json` or `summary` print them, a `.html` file gets
+/// the interactive dashboard, and a `.json` file the raw figures
+fn export_analytics_report(roadmap: &Roadmap, analytics: &ProgressAnalytics, target: &str) -> CommandResult {
+    let extension = std::path::Path::new(target).extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase());
+    match (target.to_lowercase().as_str(), extension.as_deref()) {
+        ("json", _) => {
             let json_report = serde_json::to_string_pretty(&analytics)
                 .map_err(|e| format!("Failed to serialize analytics: {}", e))?;
             println!("{}", json_report);
         },
-        "summary" => {
+        ("summary", _) => {
             ui::display_analytics_summary(analytics);
         },
+        (_, Some("html" | "htm")) => {
+            let html = super::dashboard::render_dashboard(roadmap, analytics)?;
+            std::fs::write(target, html).map_err(|e| format!("Failed to write {}: {}", target, e))?;
+            ui::display_success(&format!("📊 Analytics dashboard written to {}", target));
+        },
+        (_, Some("json")) => {
+            let json_report = serde_json::to_string_pretty(&analytics)
+                .map_err(|e| format!("Failed to serialize analytics: {}", e))?;
+            std::fs::write(target, json_report).map_err(|e| format!("Failed to write {}: {}", target, e))?;
+            ui::display_success(&format!("Analytics written to {}", target));
+        },
         _ => {
-            return Err(format!("Unsupported export format: {}. Use 'json' or 'summary'", format).into());
+            return Err(format!("Unsupported export target: {}. Use a .html or .json file, or 'json' or 'summary'", target).into());
         }
     }
     
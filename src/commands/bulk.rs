@@ -4,33 +4,87 @@
 //! completion, tag management, priority setting, and removal.
 
 use crate::{
-    cli::CliPriority,
-    model::{TaskStatus, Priority, Phase},
+    cli::{CliPriority, TaskSelection},
+    hooks,
+    model::{Task, TaskStatus, Priority},
     ui
 };
-use super::{CommandResult, utils, dependencies, BulkCommands};
+use super::{CommandResult, utils, dependencies, BulkAction, BulkChoice, BulkCommands};
 
-/// Handle bulk operations on multiple tasks
+/// Handle bulk operations on multiple tasks.
+///
+/// Every bulk operation runs under a checkpoint so an interrupted run can be
+/// resumed or rolled back the next time a bulk command is used.
 pub fn handle_bulk_command(bulk_command: &BulkCommands) -> CommandResult {
-    match bulk_command {
-        BulkCommands::Complete { ids } => bulk_complete_tasks(ids),
-        BulkCommands::AddTags { ids, tags } => bulk_add_tags(ids, tags),
-        BulkCommands::RemoveTags { ids, tags } => bulk_remove_tags(ids, tags),
-        BulkCommands::SetPriority { ids, priority } => bulk_set_priority(ids, priority),
-        BulkCommands::SetPhase { ids, phase } => bulk_set_phase(ids, phase),
-        BulkCommands::Reset { ids } => bulk_reset_tasks(ids),
-        BulkCommands::Remove { ids, force } => bulk_remove_tasks(ids, *force),
+    let operation = match bulk_command {
+        BulkCommands::Select { filter } => return bulk_select(filter.as_deref()),
+        BulkCommands::Complete { .. } => "bulk complete",
+        BulkCommands::AddTags { .. } => "bulk add-tags",
+        BulkCommands::RemoveTags { .. } => "bulk remove-tags",
+        BulkCommands::Assign { .. } => "bulk assign",
+        BulkCommands::Unassign { .. } => "bulk unassign",
+        BulkCommands::SetPriority { .. } => "bulk set-priority",
+        BulkCommands::SetPhase { .. } => "bulk set-phase",
+        BulkCommands::SetMarkdown { .. } => "bulk set-markdown",
+        BulkCommands::Reset { .. } => "bulk reset",
+        BulkCommands::Remove { .. } => "bulk remove",
+    };
+    
+    utils::run_with_checkpoint(operation, || match bulk_command {
+        BulkCommands::Complete { tasks } => bulk_complete_tasks(tasks),
+        BulkCommands::AddTags { tasks, tags } => bulk_add_tags(tasks, tags),
+        BulkCommands::RemoveTags { tasks, tags } => bulk_remove_tags(tasks, tags),
+        BulkCommands::Assign { tasks, owners } => bulk_assign_owners(tasks, owners),
+        BulkCommands::Unassign { tasks, owners } => bulk_unassign_owners(tasks, owners),
+        BulkCommands::SetPriority { tasks, priority } => bulk_set_priority(tasks, priority),
+        BulkCommands::SetPhase { tasks, phase } => bulk_set_phase(tasks, phase),
+        BulkCommands::SetMarkdown { tasks, sync } => bulk_set_markdown_sync(tasks, *sync),
+        BulkCommands::Reset { tasks } => bulk_reset_tasks(tasks),
+        BulkCommands::Remove { tasks, force } => bulk_remove_tasks(tasks, *force),
+        BulkCommands::Select { .. } => unreachable!("bulk select is dispatched before the checkpoint"),
+    })
+}
+
+/// Check off tasks in a TUI list and hand them to the chosen bulk operation
+pub fn bulk_select(filter: Option<&str>) -> CommandResult {
+    let roadmap = crate::state::load_state()?;
+    let filter = filter.map(utils::TaskFilter::parse).transpose()?;
+    let tasks: Vec<&Task> = roadmap.tasks.iter()
+        .filter(|task| filter.as_ref().is_none_or(|filter| filter.matches(task)))
+        .collect();
+    if tasks.is_empty() {
+        ui::display_info("No tasks to select from");
+        return Ok(());
     }
+    
+    let Some(BulkChoice { ids, action }) = super::run_bulk_select(&tasks)? else {
+        ui::display_info("Bulk selection cancelled");
+        return Ok(());
+    };
+    
+    let tasks = TaskSelection {
+        ids: Some(ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",")),
+        filter: None,
+    };
+    handle_bulk_command(&match action {
+        BulkAction::Complete => BulkCommands::Complete { tasks },
+        BulkAction::AddTags(tags) => BulkCommands::AddTags { tasks, tags },
+        BulkAction::SetPriority(priority) => BulkCommands::SetPriority { tasks, priority },
+        BulkAction::Remove => BulkCommands::Remove { tasks, force: false },
+    })
 }
 
 /// Complete multiple tasks at once
-pub fn bulk_complete_tasks(ids_str: &str) -> CommandResult {
+pub fn bulk_complete_tasks(tasks: &TaskSelection) -> CommandResult {
     let mut roadmap = crate::state::load_state()?;
-    let task_ids = utils::parse_and_validate_task_ids(ids_str, &roadmap)?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
     
     ui::display_info(&format!("🚀 Attempting to complete {} tasks...", task_ids.len()));
     
     let mut completed_count = 0;
+    let mut completed_ids = Vec::new();
     let mut failed_tasks = Vec::new();
     let mut newly_unblocked = Vec::new();
     
@@ -69,6 +123,22 @@ pub fn bulk_complete_tasks(ids_str: &str) -> CommandResult {
             }
         }
         
+        // Tasks with acceptance criteria need the interactive checklist
+        if let Some(task) = roadmap.find_task_by_id(task_id) {
+            if !task.acceptance_criteria.is_empty() {
+                failed_tasks.push((task_id, format!("Has {} acceptance criteria to confirm (use 'rask complete {}')",
+                    task.acceptance_criteria.len(), task_id)));
+                continue;
+            }
+        }
+        
+        if let Some(task) = roadmap.find_task_by_id(task_id) {
+            if let Err(reason) = hooks::run_pre_hook("pre-complete", task) {
+                failed_tasks.push((task_id, reason));
+                continue;
+            }
+        }
+        
         // Find newly unblocked tasks before completing this one
         let unblocked = dependencies::find_newly_unblocked_tasks(&roadmap, task_id);
         newly_unblocked.extend(unblocked);
@@ -77,6 +147,7 @@ pub fn bulk_complete_tasks(ids_str: &str) -> CommandResult {
         if let Some(task) = roadmap.tasks.iter_mut().find(|t| t.id == task_id) {
             task.mark_completed();
             completed_count += 1;
+            completed_ids.push(task_id);
             ui::display_success(&format!("✅ Completed task #{}: {}", task_id, task.description));
         }
     }
@@ -84,6 +155,9 @@ pub fn bulk_complete_tasks(ids_str: &str) -> CommandResult {
     // Save state if any tasks were completed
     if completed_count > 0 {
         utils::save_and_sync(&roadmap)?;
+        for task in roadmap.tasks.iter().filter(|t| completed_ids.contains(&t.id)) {
+            hooks::run_post_hook("post-complete", task);
+        }
         
         ui::display_success(&format!("🎉 Successfully completed {} out of {} tasks!", 
             completed_count, task_ids.len()));
@@ -113,9 +187,11 @@ pub fn bulk_complete_tasks(ids_str: &str) -> CommandResult {
 }
 
 /// Add tags to multiple tasks
-pub fn bulk_add_tags(ids_str: &str, tags_str: &str) -> CommandResult {
+pub fn bulk_add_tags(tasks: &TaskSelection, tags_str: &str) -> CommandResult {
     let mut roadmap = crate::state::load_state()?;
-    let task_ids = utils::parse_and_validate_task_ids(ids_str, &roadmap)?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
     
     // Parse and validate tags
     let tags = utils::validate_and_parse_tags(tags_str)?;
@@ -160,10 +236,80 @@ pub fn bulk_add_tags(ids_str: &str, tags_str: &str) -> CommandResult {
     Ok(())
 }
 
+/// Add owners to multiple tasks
+pub fn bulk_assign_owners(tasks: &TaskSelection, owners_str: &str) -> CommandResult {
+    let mut roadmap = crate::state::load_state()?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
+    let owners = utils::parse_owners(owners_str)?;
+    
+    ui::display_info(&format!("👥 Assigning {} to {} tasks...", owners.join(", "), task_ids.len()));
+    
+    let mut modified_count = 0;
+    
+    for &task_id in &task_ids {
+        if let Some(task) = roadmap.tasks.iter_mut().find(|t| t.id == task_id) {
+            if task.add_owners(&owners) > 0 {
+                modified_count += 1;
+                task.record_history("owners_changed", Some(task.owners.join(", ")));
+                ui::display_success(&format!("✅ Task #{} owners: {}", task_id, task.owners.join(", ")));
+            } else {
+                ui::display_info(&format!("ℹ️  Task #{} already has all specified owners", task_id));
+            }
+        }
+    }
+    
+    if modified_count > 0 {
+        utils::save_and_sync(&roadmap)?;
+        ui::display_success(&format!("🎉 Successfully modified {} tasks!", modified_count));
+    }
+    
+    Ok(())
+}
+
+/// Remove owners from multiple tasks
+pub fn bulk_unassign_owners(tasks: &TaskSelection, owners_str: &str) -> CommandResult {
+    let mut roadmap = crate::state::load_state()?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
+    let owners = utils::parse_owners(owners_str)?;
+    
+    ui::display_info(&format!("👥 Unassigning {} from {} tasks...", owners.join(", "), task_ids.len()));
+    
+    let mut modified_count = 0;
+    
+    for &task_id in &task_ids {
+        if let Some(task) = roadmap.tasks.iter_mut().find(|t| t.id == task_id) {
+            let before = task.owners.len();
+            task.owners.retain(|owner| !owners.iter().any(|o| o.eq_ignore_ascii_case(owner)));
+            
+            if task.owners.len() != before {
+                modified_count += 1;
+                let details = if task.owners.is_empty() { "none".to_string() } else { task.owners.join(", ") };
+                task.record_history("owners_changed", Some(details.clone()));
+                ui::display_success(&format!("✅ Task #{} owners: {}", task_id, details));
+            } else {
+                ui::display_info(&format!("ℹ️  Task #{} has none of the specified owners", task_id));
+            }
+        }
+    }
+    
+    if modified_count > 0 {
+        utils::save_and_sync(&roadmap)?;
+        ui::display_success(&format!("🎉 Successfully modified {} tasks!", modified_count));
+    }
+    
+    Ok(())
+}
+
 /// Remove tags from multiple tasks
-pub fn bulk_remove_tags(ids_str: &str, tags_str: &str) -> CommandResult {
+pub fn bulk_remove_tags(tasks: &TaskSelection, tags_str: &str) -> CommandResult {
     let mut roadmap = crate::state::load_state()?;
-    let task_ids = utils::parse_and_validate_task_ids(ids_str, &roadmap)?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
     
     let tags: Vec<String> = tags_str.split(',')
         .map(|s| s.trim().to_string())
@@ -210,9 +356,11 @@ pub fn bulk_remove_tags(ids_str: &str, tags_str: &str) -> CommandResult {
 }
 
 /// Set priority for multiple tasks
-pub fn bulk_set_priority(ids_str: &str, priority: &CliPriority) -> CommandResult {
+pub fn bulk_set_priority(tasks: &TaskSelection, priority: &CliPriority) -> CommandResult {
     let mut roadmap = crate::state::load_state()?;
-    let task_ids = utils::parse_and_validate_task_ids(ids_str, &roadmap)?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
     let new_priority: Priority = priority.clone().into();
     
     ui::display_info(&format!("⚡ Setting priority to {} for {} tasks...", 
@@ -243,10 +391,12 @@ pub fn bulk_set_priority(ids_str: &str, priority: &CliPriority) -> CommandResult
 }
 
 /// Set phase for multiple tasks
-pub fn bulk_set_phase(ids_str: &str, phase_name: &str) -> CommandResult {
+pub fn bulk_set_phase(tasks: &TaskSelection, phase_name: &str) -> CommandResult {
     let mut roadmap = crate::state::load_state()?;
-    let task_ids = utils::parse_and_validate_task_ids(ids_str, &roadmap)?;
-    let new_phase = Phase::from_string(phase_name);
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
+    let new_phase = roadmap.phase_named(phase_name);
     
     ui::display_info(&format!("{} Setting phase to {} for {} tasks...", 
         new_phase.emoji(), new_phase, task_ids.len()));
@@ -255,7 +405,7 @@ pub fn bulk_set_phase(ids_str: &str, phase_name: &str) -> CommandResult {
     
     for &task_id in &task_ids {
         if let Some(task) = roadmap.tasks.iter_mut().find(|t| t.id == task_id) {
-            if task.phase != new_phase {
+            if !task.phase.is_named(&new_phase.name) {
                 let old_phase = task.phase.clone();
                 task.phase = new_phase.clone();
                 modified_count += 1;
@@ -275,10 +425,44 @@ pub fn bulk_set_phase(ids_str: &str, phase_name: &str) -> CommandResult {
     Ok(())
 }
 
+/// Include or exclude multiple tasks from the markdown roadmap file
+pub fn bulk_set_markdown_sync(tasks: &TaskSelection, sync: bool) -> CommandResult {
+    let mut roadmap = crate::state::load_state()?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
+    let target = if sync { "the roadmap markdown" } else { "local-only tracking" };
+    
+    ui::display_info(&format!("📝 Moving {} tasks to {}...", task_ids.len(), target));
+    
+    let mut modified_count = 0;
+    
+    for &task_id in &task_ids {
+        if let Some(task) = roadmap.tasks.iter_mut().find(|t| t.id == task_id) {
+            if task.sync != sync {
+                task.sync = sync;
+                modified_count += 1;
+                ui::display_success(&format!("✅ Task #{} now uses {}: {}", task_id, target, task.description));
+            } else {
+                ui::display_info(&format!("ℹ️  Task #{} already uses {}", task_id, target));
+            }
+        }
+    }
+    
+    if modified_count > 0 {
+        utils::save_and_sync(&roadmap)?;
+        ui::display_success(&format!("🎉 Successfully modified {} tasks!", modified_count));
+    }
+    
+    Ok(())
+}
+
 /// Reset multiple tasks to pending status
-pub fn bulk_reset_tasks(ids_str: &str) -> CommandResult {
+pub fn bulk_reset_tasks(tasks: &TaskSelection) -> CommandResult {
     let mut roadmap = crate::state::load_state()?;
-    let task_ids = utils::parse_and_validate_task_ids(ids_str, &roadmap)?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
     
     ui::display_info(&format!("🔄 Resetting {} tasks to pending status...", task_ids.len()));
     
@@ -305,9 +489,11 @@ pub fn bulk_reset_tasks(ids_str: &str) -> CommandResult {
 }
 
 /// Remove multiple tasks
-pub fn bulk_remove_tasks(ids_str: &str, force: bool) -> CommandResult {
+pub fn bulk_remove_tasks(tasks: &TaskSelection, force: bool) -> CommandResult {
     let mut roadmap = crate::state::load_state()?;
-    let task_ids = utils::parse_and_validate_task_ids(ids_str, &roadmap)?;
+    let Some(task_ids) = utils::select_bulk_tasks(tasks, &roadmap)? else {
+        return Ok(());
+    };
     
     // Check for tasks that depend on the ones being removed
     let mut blocking_dependencies = Vec::new();
@@ -340,9 +526,14 @@ pub fn bulk_remove_tasks(ids_str: &str, force: bool) -> CommandResult {
         return Err("Cannot remove tasks with dependencies. Use --force to override.".into());
     }
     
+    for task in roadmap.tasks.iter().filter(|t| task_ids.contains(&t.id)) {
+        hooks::run_pre_hook("pre-remove", task)?;
+    }
+    
     ui::display_info(&format!("🗑️  Removing {} tasks...", task_ids.len()));
     
     let mut removed_count = 0;
+    let mut removed_tasks = Vec::new();
     let mut task_descriptions = Vec::new();
     
     // Collect task descriptions before removal
@@ -352,13 +543,13 @@ pub fn bulk_remove_tasks(ids_str: &str, force: bool) -> CommandResult {
         }
     }
     
-    // Remove tasks (in reverse order to maintain indices)
+    // Remove tasks from the highest ID down, so renumbering never shifts an ID still to be removed
     let mut sorted_ids = task_ids.clone();
     sorted_ids.sort_by(|a, b| b.cmp(a)); // Sort in descending order
     
     for &task_id in &sorted_ids {
-        if let Some(pos) = roadmap.tasks.iter().position(|t| t.id == task_id) {
-            roadmap.tasks.remove(pos);
+        if let Some(task) = roadmap.remove_task(task_id) {
+            removed_tasks.push(task);
             removed_count += 1;
         }
     }
@@ -370,6 +561,9 @@ pub fn bulk_remove_tasks(ids_str: &str, force: bool) -> CommandResult {
     
     if removed_count > 0 {
         utils::save_and_sync(&roadmap)?;
+        for task in removed_tasks.iter().rev() {
+            hooks::run_post_hook("post-remove", task);
+        }
         ui::display_success(&format!("🎉 Successfully removed {} tasks!", removed_count));
         
         if !blocking_dependencies.is_empty() {
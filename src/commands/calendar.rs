@@ -0,0 +1,185 @@
+//! Calendar workload view
+//!
+//! `rask calendar` draws one month as a Monday-first grid. Each day shows the
+//! hours logged in time sessions that started that day and the pending tasks
+//! due on it. A day's load is its logged hours plus the remaining estimates
+//! of the tasks due that day; days above `behavior.daily_capacity_hours` are
+//! flagged as overloaded.
+
+use crate::config::RaskConfig;
+use crate::model::{parse_timestamp, Roadmap, TaskStatus};
+use crate::{state, ui};
+use super::{utils, CommandResult};
+use chrono::{Datelike, Local, NaiveDate};
+use colored::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// Characters inside one day cell
+const CELL_WIDTH: usize = 10;
+
+/// Workload of one day
+#[derive(Serialize, Default)]
+struct CalendarDay {
+    date: NaiveDate,
+    logged_hours: f64,
+    /// Remaining estimated hours of the pending tasks due that day
+    planned_hours: f64,
+    due: Vec<usize>,
+    overloaded: bool,
+}
+
+impl CalendarDay {
+    fn load(&self) -> f64 {
+        self.logged_hours + self.planned_hours
+    }
+}
+
+/// Month given as YYYY-MM, or the current one
+fn parse_month(month: Option<&str>) -> Result<NaiveDate, String> {
+    match month {
+        None => {
+            let today = Local::now().date_naive();
+            Ok(today.with_day(1).unwrap_or(today))
+        }
+        Some(text) => NaiveDate::parse_from_str(&format!("{}-01", text.trim()), "%Y-%m-%d")
+            .map_err(|_| format!("Invalid month '{}'. Use YYYY-MM, e.g. 2024-07", text)),
+    }
+}
+
+/// The days of the month starting at `first`, with their workload
+fn month_days(roadmap: &Roadmap, first: NaiveDate, capacity: f64) -> Vec<CalendarDay> {
+    let mut days: BTreeMap<NaiveDate, CalendarDay> = first.iter_days()
+        .take_while(|day| day.month() == first.month())
+        .map(|date| (date, CalendarDay { date, ..Default::default() }))
+        .collect();
+
+    for task in &roadmap.tasks {
+        for session in &task.time_sessions {
+            let Some(start) = parse_timestamp(Some(&session.start_time)) else { continue };
+            if let Some(day) = days.get_mut(&start.with_timezone(&Local).date_naive()) {
+                day.logged_hours += session.duration_hours().unwrap_or_else(|| session.elapsed_hours());
+            }
+        }
+        if task.status != TaskStatus::Pending {
+            continue;
+        }
+        if let Some(day) = task.due_date_parsed().and_then(|due| days.get_mut(&due)) {
+            day.due.push(task.id);
+            if let Some(estimate) = task.estimated_hours {
+                day.planned_hours += (estimate - task.get_total_tracked_hours()).max(0.0);
+            }
+        }
+    }
+
+    days.into_values()
+        .map(|mut day| {
+            day.overloaded = day.load() > capacity;
+            day
+        })
+        .collect()
+}
+
+/// Pad text to the cell width, counting only visible characters
+fn cell(text: &str, visible: usize) -> String {
+    format!("{}{}", text, " ".repeat(CELL_WIDTH.saturating_sub(visible)))
+}
+
+/// Show a month of due tasks and logged hours
+pub fn show_calendar(month: Option<&str>) -> CommandResult {
+    let first = parse_month(month)?;
+    let capacity = RaskConfig::load().map(|config| config.behavior.daily_capacity_hours).unwrap_or(6.0);
+    let roadmap = state::load_state()?;
+    let days = month_days(&roadmap, first, capacity);
+
+    if utils::json_output() {
+        return utils::print_json(&days);
+    }
+
+    let today = Local::now().date_naive();
+    let border = |left: &str, middle: &str, right: &str| {
+        format!("  {}{}{}", left, vec!["─".repeat(CELL_WIDTH); 7].join(middle), right).bright_black().to_string()
+    };
+
+    println!("\n  {} {}", "📅".bright_cyan(), first.format("%B %Y").to_string().bold().bright_cyan());
+    println!("  {}", format!("Daily capacity {}h · load = logged hours + remaining estimates of tasks due", capacity).bright_black());
+    println!("{}", border("┌", "┬", "┐"));
+    let header: Vec<String> = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"].iter()
+        .map(|name| cell(&name.bold().to_string(), name.len()))
+        .collect();
+    println!("  {}{}{}", "│".bright_black(), header.join(&"│".bright_black().to_string()), "│".bright_black());
+    println!("{}", border("├", "┼", "┤"));
+
+    // Blank cells before the first day so weeks start on Monday
+    let offset = first.weekday().num_days_from_monday() as usize;
+    let mut cells: Vec<Option<&CalendarDay>> = vec![None; offset];
+    cells.extend(days.iter().map(Some));
+    cells.resize(cells.len().div_ceil(7) * 7, None);
+
+    let weeks: Vec<&[Option<&CalendarDay>]> = cells.chunks(7).collect();
+    for (index, week) in weeks.iter().enumerate() {
+        let lines: [Vec<String>; 3] = [0, 1, 2].map(|line| {
+            week.iter().map(|day| {
+                let Some(day) = day else { return cell("", 0) };
+                match line {
+                    0 => {
+                        let number = format!("{:>2}", day.date.day());
+                        let marker = if day.overloaded { " ⚠" } else { "" };
+                        let text = if day.date == today { number.black().on_bright_cyan().to_string() } else { number.bold().to_string() };
+                        let marker_text = marker.bright_red().bold().to_string();
+                        cell(&format!("{}{}", text, marker_text), 2 + marker.chars().count())
+                    }
+                    1 if day.logged_hours > 0.0 => {
+                        let text = format!("{:.1}h", day.logged_hours);
+                        let colored = if day.overloaded { text.bright_red() } else { text.bright_green() };
+                        cell(&colored.to_string(), text.len())
+                    }
+                    2 if !day.due.is_empty() => {
+                        let text = format!("{} due", day.due.len());
+                        let colored = if day.overloaded { text.bright_red() } else { text.bright_yellow() };
+                        cell(&colored.to_string(), text.len())
+                    }
+                    _ => cell("", 0),
+                }
+            }).collect()
+        });
+        for line in &lines {
+            println!("  {}{}{}", "│".bright_black(), line.join(&"│".bright_black().to_string()), "│".bright_black());
+        }
+        if index + 1 < weeks.len() {
+            println!("{}", border("├", "┼", "┤"));
+        }
+    }
+    println!("{}", border("└", "┴", "┘"));
+
+    let logged: f64 = days.iter().map(|day| day.logged_hours).sum();
+    let due: usize = days.iter().map(|day| day.due.len()).sum();
+    let overloaded: Vec<&CalendarDay> = days.iter().filter(|day| day.overloaded).collect();
+    println!("\n  {} logged · {} task(s) due · {} overloaded day(s)",
+        format!("{:.1}h", logged).bright_green().bold(),
+        due.to_string().bright_yellow().bold(),
+        overloaded.len().to_string().bright_red().bold()
+    );
+
+    for day in &days {
+        if day.due.is_empty() && !day.overloaded {
+            continue;
+        }
+        let load = format!("{:.1}h of {}h", day.load(), capacity);
+        let load = if day.overloaded { load.bright_red().bold() } else { load.bright_black() };
+        println!("\n  {} {}", day.date.format("%a %d").to_string().bold(), load);
+        for id in &day.due {
+            if let Some(task) = roadmap.find_task_by_id(*id) {
+                let estimate = task.estimated_hours.map(|hours| format!(" ({}h est.)", hours)).unwrap_or_default();
+                println!("    {} {}{}", format!("#{}", id).bright_white(), task.description, estimate.bright_black());
+            }
+        }
+    }
+
+    if !overloaded.is_empty() {
+        println!();
+        ui::display_info("💡 Reschedule tasks with 'rask review', or raise 'behavior.daily_capacity_hours'");
+    }
+    println!();
+    Ok(())
+}
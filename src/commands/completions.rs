@@ -0,0 +1,205 @@
+//! Shell completion scripts
+//!
+//! `rask completions <shell>` prints clap's generated script for the static
+//! parts of the CLI (subcommands, flags, value enums) followed by a small
+//! wrapper that asks `rask __complete` for values that depend on the current
+//! project: task IDs, phase names, tags and template names. The wrapper falls
+//! back to the static completions whenever `__complete` has nothing to offer.
+
+use crate::cli::Cli;
+use crate::model::Phase;
+use crate::state;
+use super::{template_names, CommandResult};
+use clap::{Arg, CommandFactory};
+use clap_complete::Shell;
+use std::collections::BTreeSet;
+use std::io::Write;
+
+/// Project values the completion scripts can ask for
+enum CompletionKind {
+    TaskIds,
+    Phases,
+    Tags,
+    Templates,
+}
+
+const BASH_DYNAMIC: &str = r#"
+_rask_dynamic() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" values
+    values="$(rask __complete "${COMP_WORDS[@]:1:COMP_CWORD}" 2>/dev/null)"
+    if [[ -n "$values" ]]; then
+        local IFS=$'\n'
+        COMPREPLY=($(compgen -W "$values" -- "$cur"))
+        return 0
+    fi
+    _rask "$@"
+}
+
+if [[ "${BASH_VERSINFO[0]}" -eq 4 && "${BASH_VERSINFO[1]}" -ge 4 || "${BASH_VERSINFO[0]}" -gt 4 ]]; then
+    complete -F _rask_dynamic -o nosort -o bashdefault -o default rask
+else
+    complete -F _rask_dynamic -o bashdefault -o default rask
+fi
+"#;
+
+const ZSH_DYNAMIC: &str = r#"_rask() {
+    local -a values
+    values=("${(@f)$(rask __complete "${(@)words[2,CURRENT]}" 2>/dev/null)}")
+    values=(${values:#})
+    if (( ${#values} )); then
+        compadd -a values
+    else
+        _rask_static "$@"
+    fi
+}
+
+"#;
+
+const FISH_DYNAMIC: &str = r#"
+complete -c rask -f -a '(rask __complete (commandline -opc)[2..-1] (commandline -ct) 2>/dev/null)'
+"#;
+
+const POWERSHELL_DYNAMIC: &str = r#"
+Register-ArgumentCompleter -Native -CommandName 'rask' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    $words = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object { $_.ToString() })
+    if ($wordToComplete -eq '') { $words += '' }
+    $values = @(rask __complete @words 2>$null)
+    if ($values.Count -gt 0) {
+        $values | Where-Object { $_ -like "$wordToComplete*" } | ForEach-Object {
+            [CompletionResult]::new($_, $_, [CompletionResultType]::ParameterValue, $_)
+        }
+    } else {
+        & $global:RaskStaticCompleter $wordToComplete $commandAst $cursorPosition
+    }
+}
+"#;
+
+/// Print the completion script for a shell
+pub fn generate_completions(shell: Shell) -> CommandResult {
+    let mut buffer = Vec::new();
+    clap_complete::generate(shell, &mut Cli::command(), "rask", &mut buffer);
+    let mut script = String::from_utf8(buffer)?;
+
+    match shell {
+        Shell::Bash => script.push_str(BASH_DYNAMIC),
+        Shell::Zsh => {
+            // Rename clap's function so the autoloaded `_rask` is the dynamic one
+            script = script.replacen("\n_rask() {", "\n_rask_static() {", 1);
+            let tail = script.find("if [ \"$funcstack[1]\" = \"_rask\" ]").unwrap_or(script.len());
+            script.insert_str(tail, ZSH_DYNAMIC);
+        }
+        Shell::Fish => script.push_str(FISH_DYNAMIC),
+        Shell::PowerShell => {
+            // Keep clap's completer as the fallback for the dynamic one
+            script = script.replacen(
+                "Register-ArgumentCompleter -Native -CommandName 'rask' -ScriptBlock {",
+                "$global:RaskStaticCompleter = {",
+                1,
+            );
+            script.push_str(POWERSHELL_DYNAMIC);
+        }
+        _ => {}
+    }
+
+    std::io::stdout().write_all(script.as_bytes())?;
+    Ok(())
+}
+
+/// Print the project values that fit the last word of a partial command line, one per line
+pub fn complete_values(words: &[String]) -> CommandResult {
+    let Some(kind) = completion_kind(words) else {
+        return Ok(());
+    };
+
+    let values: Vec<String> = match kind {
+        CompletionKind::Templates => template_names(),
+        _ => {
+            let Ok(roadmap) = state::load_state() else {
+                return Ok(());
+            };
+            match kind {
+                CompletionKind::TaskIds => roadmap.tasks.iter().map(|t| t.id.to_string()).collect(),
+                CompletionKind::Phases => {
+                    let mut phases: Vec<String> = roadmap.get_all_phases().into_iter().map(|p| p.name).collect();
+                    for entry in roadmap.phases.iter().filter(|e| !e.archived) {
+                        if !phases.contains(&entry.name) {
+                            phases.push(entry.name.clone());
+                        }
+                    }
+                    for phase in Phase::predefined_phases() {
+                        if !phases.contains(&phase.name) {
+                            phases.push(phase.name);
+                        }
+                    }
+                    phases
+                }
+                _ => roadmap.tasks.iter()
+                    .flat_map(|t| t.tags.iter().cloned())
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect(),
+            }
+        }
+    };
+
+    for value in values {
+        println!("{}", value);
+    }
+    Ok(())
+}
+
+/// Work out which argument the last word fills by walking the command tree
+fn completion_kind(words: &[String]) -> Option<CompletionKind> {
+    let (current, before) = words.split_last()?;
+    if current.starts_with('-') {
+        return None;
+    }
+
+    let mut root = Cli::command();
+    root.build();
+    let mut command = &root;
+    let mut path: Vec<&str> = Vec::new();
+    let mut positionals = 0;
+    let mut option: Option<&Arg> = None;
+
+    for word in before {
+        if option.take().is_some() {
+            continue;
+        }
+        if let Some(long) = word.strip_prefix("--") {
+            if !long.contains('=') {
+                option = command.get_arguments().find(|a| a.get_long() == Some(long));
+            }
+        } else if let Some(short) = word.strip_prefix('-').filter(|s| s.chars().count() == 1) {
+            option = command.get_arguments().find(|a| a.get_short().map(String::from).as_deref() == Some(short));
+        } else if let Some(subcommand) = command.find_subcommand(word).filter(|_| positionals == 0) {
+            command = subcommand;
+            path.push(subcommand.get_name());
+        } else {
+            positionals += 1;
+        }
+        option = option.filter(|a| a.get_action().takes_values());
+    }
+
+    let arg = match option {
+        Some(arg) => arg,
+        None => command.get_positionals().nth(positionals)?,
+    };
+    value_kind(&path, arg)
+}
+
+/// Which project values an argument takes, judged by its value name
+fn value_kind(path: &[&str], arg: &Arg) -> Option<CompletionKind> {
+    let value_name = arg.get_value_names()?.first()?.as_str();
+    match value_name {
+        "TASK_ID" | "TASK_IDS" | "IDS" => Some(CompletionKind::TaskIds),
+        "PHASE" | "FROM_PHASE" => Some(CompletionKind::Phases),
+        "TAGS" => Some(CompletionKind::Tags),
+        "NAME" | "TEMPLATE_NAME" if matches!(path, ["template", "show" | "use" | "delete" | "enhance"]) => {
+            Some(CompletionKind::Templates)
+        }
+        _ => None,
+    }
+}
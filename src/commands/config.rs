@@ -6,7 +6,6 @@
 use crate::{config::RaskConfig, ui};
 use super::{CommandResult, ConfigCommands};
 use std::path::PathBuf;
-use std::process::Command;
 
 /// Handle configuration-related commands
 pub fn handle_config_command(config_command: &ConfigCommands) -> CommandResult {
@@ -33,6 +32,8 @@ fn show_config(section: Option<&str>) -> CommandResult {
             println!("  Compact view: {}", config.ui.compact_view);
             println!("  Show task IDs: {}", config.ui.show_task_ids);
             println!("  Max width: {} (0 = auto)", config.ui.max_width);
+            println!("  Accessible: {} (plain text labels for screen readers)", config.ui.accessible);
+            println!("  Stale after: {} days (0 = never)", config.ui.stale_days);
         },
         Some("behavior") => {
             ui::display_info("⚙️  Behavior Configuration:");
@@ -43,6 +44,10 @@ fn show_config(section: Option<&str>) -> CommandResult {
             println!("  Warn on circular: {}", config.behavior.warn_on_circular);
             println!("  Confirm destructive: {}", config.behavior.confirm_destructive);
             println!("  Auto sync markdown: {}", config.behavior.auto_sync_markdown);
+            println!("  Max session hours: {} (0 = never warn)", config.behavior.max_session_hours);
+            println!("  Backup count: {} (0 = no automatic backups)", config.behavior.backup_count);
+            println!("  WIP limit mode: {} (warn or refuse)", config.behavior.wip_limit_mode);
+            println!("  Stable IDs: {} (true = never renumber tasks)", config.behavior.stable_ids);
         },
         Some("export") => {
             ui::display_info("📤 Export Configuration:");
@@ -50,23 +55,69 @@ fn show_config(section: Option<&str>) -> CommandResult {
             println!("  Default path: {:?}", config.export.default_path);
             println!("  Include completed: {}", config.export.include_completed);
             println!("  Include metadata: {}", config.export.include_metadata);
+            let mut destinations: Vec<_> = config.export.destinations.iter().collect();
+            destinations.sort_by(|a, b| a.0.cmp(b.0));
+            if destinations.is_empty() {
+                println!("  Destinations: none (add [export.destinations.<name>] with 'rask config edit')");
+            } else {
+                println!("  Destinations:");
+                for (name, destination) in destinations {
+                    println!("    {} ({})", name, destination.kind());
+                }
+            }
         },
         Some("advanced") => {
             ui::display_info("🔧 Advanced Configuration:");
             println!("  Aliases: {:?}", config.advanced.aliases);
             println!("  Editor: {:?}", config.advanced.editor);
+            println!("  Notes directory: {:?}", config.advanced.notes_dir);
+            println!("  Template registry: {:?}", config.advanced.template_registry);
             println!("  Templates: {:?}", config.advanced.templates);
             println!("  Debug: {}", config.advanced.debug);
         },
         Some("theme") => {
             ui::display_info("🎭 Theme Configuration:");
-            println!("  Name: {}", config.theme.name);
+            println!("  Name: {} (built-in: {})", config.theme.name, crate::ui::theme::BUILTIN_THEMES.join(", "));
             println!("  Priority colors: {:?}", config.theme.priority_colors);
             println!("  Status colors: {:?}", config.theme.status_colors);
+            println!("  Priority symbols: {:?}", config.theme.priority_symbols);
+            println!("  Tag colors: {:?}", config.theme.tag_colors);
+            println!("  Phase colors: {:?}", config.theme.phase_colors);
             println!("  Symbols: {:?}", config.theme.symbols);
         },
+        Some("user") => {
+            ui::display_info("👤 User Configuration:");
+            println!("  Name: {:?}", config.user.name);
+            println!("  Email: {:?}", config.user.email);
+        },
+        Some("projects") => {
+            ui::display_info("📂 Projects (for --project):");
+            if config.projects.is_empty() {
+                println!("  None. Register one with 'rask config set projects.<name> <path>'");
+            }
+            let mut projects: Vec<_> = config.projects.iter().collect();
+            projects.sort();
+            for (name, path) in projects {
+                println!("  {}: {}", name, path);
+            }
+        },
+        Some("profiles") => {
+            ui::display_info("🗂️  Profiles (for --profile):");
+            if config.profiles.is_empty() {
+                println!("  None. Add a [profiles.<name>] table with 'rask config edit'");
+            }
+            let active = crate::config::active_profile();
+            let mut profiles: Vec<_> = config.profiles.iter().collect();
+            profiles.sort_by(|a, b| a.0.cmp(b.0));
+            for (name, overlay) in profiles {
+                let marker = if active.as_deref() == Some(name.as_str()) { " (active)" } else { "" };
+                let mut sections: Vec<&str> = overlay.keys().map(String::as_str).collect();
+                sections.sort();
+                println!("  {}{}: {}", name, marker, sections.join(", "));
+            }
+        },
         Some(unknown) => {
-            return Err(format!("Unknown configuration section: {}. Available sections: ui, behavior, export, advanced, theme", unknown).into());
+            return Err(format!("Unknown configuration section: {}. Available sections: ui, behavior, export, advanced, theme, user, projects, profiles", unknown).into());
         },
         None => {
             // Show all configuration
@@ -80,6 +131,12 @@ fn show_config(section: Option<&str>) -> CommandResult {
             show_config(Some("advanced"))?;
             println!();
             show_config(Some("theme"))?;
+            println!();
+            show_config(Some("user"))?;
+            println!();
+            show_config(Some("projects"))?;
+            println!();
+            show_config(Some("profiles"))?;
             
             // Show config file locations
             println!();
@@ -96,7 +153,7 @@ fn show_config(section: Option<&str>) -> CommandResult {
 
 /// Set a configuration value
 fn set_config(key: &str, value: &str, project_config: bool) -> CommandResult {
-    let mut config = RaskConfig::load()?;
+    let mut config = RaskConfig::load_files()?;
     
     // Set the configuration value
     config.set(key, value)?;
@@ -128,14 +185,8 @@ fn get_config(key: &str) -> CommandResult {
 
 /// Edit configuration in the user's preferred editor
 fn edit_config(project_config: bool) -> CommandResult {
-    let config = RaskConfig::load()?;
-    
     // Determine the editor to use
-    let editor_env = std::env::var("EDITOR").ok();
-    let editor = config.advanced.editor
-        .as_ref()
-        .or_else(|| editor_env.as_ref())
-        .ok_or("No editor configured. Set EDITOR environment variable or use 'rask config set advanced.editor <editor>'")?;
+    let mut editor = super::utils::editor_command()?;
     
     // Determine the config file path
     let config_path = if project_config {
@@ -157,7 +208,7 @@ fn edit_config(project_config: bool) -> CommandResult {
     }
     
     // Launch the editor
-    let status = Command::new(editor)
+    let status = editor
         .arg(&config_path)
         .status()?;
     
@@ -4,16 +4,17 @@
 //! complete, add, remove, edit, reset, list, and view.
 
 use crate::{
-    cli::CliPriority,
+    cli::{CliEnergy, CliPriority},
+    hooks,
     model::{TaskStatus, Priority, Phase, Task}, 
     parser, 
+    sorting::{self, SortKey},
     state, 
     ui
 };
-use super::{CommandResult, utils, dependencies};
+use super::{CommandError, CommandResult, utils, dependencies};
 use std::fs;
 use std::path::{PathBuf, Path};
-use regex;
 
 /// Initialize a new project from a Markdown file
 pub fn init_project(filepath: &PathBuf) -> CommandResult {
@@ -27,6 +28,7 @@ pub fn init_project(filepath: &PathBuf) -> CommandResult {
     
     // Save the state
     state::save_state(&roadmap)?;
+    crate::markdown_writer::record_synced_source(filepath)?;
     
     // Display enhanced success message with project structure info
     ui::display_init_success(&roadmap);
@@ -76,7 +78,7 @@ fn setup_local_project_directory(roadmap: &mut crate::model::Roadmap, source_fil
 fn create_project_overview(roadmap: &crate::model::Roadmap, rask_dir: &Path) -> CommandResult {
     let overview_content = format!(r#"# Project Overview: {}
 
-## 📊 Quick Stats
+{}## 📊 Quick Stats
 - Total Tasks: {}
 - Completed: {}
 - Pending: {}
@@ -96,6 +98,7 @@ fn create_project_overview(roadmap: &crate::model::Roadmap, rask_dir: &Path) ->
 *Edit your main roadmap.md or use Rask commands to make changes.*
 "#, 
         roadmap.title,
+        generate_project_summary(roadmap),
         roadmap.tasks.len(),
         roadmap.tasks.iter().filter(|t| t.status == crate::model::TaskStatus::Completed).count(),
         roadmap.tasks.iter().filter(|t| t.status == crate::model::TaskStatus::Pending).count(),
@@ -111,6 +114,29 @@ fn create_project_overview(roadmap: &crate::model::Roadmap, rask_dir: &Path) ->
     Ok(())
 }
 
+/// Description, tags and estimate from the roadmap frontmatter, if any
+fn generate_project_summary(roadmap: &crate::model::Roadmap) -> String {
+    let metadata = &roadmap.metadata;
+    let mut summary = String::new();
+    if let Some(description) = &metadata.description {
+        summary.push_str(&format!("{}\n\n", description));
+    }
+    if !metadata.tags.is_empty() {
+        summary.push_str(&format!("- Tags: {}\n", metadata.tags.iter().map(|t| format!("#{}", t)).collect::<Vec<_>>().join(" ")));
+    }
+    if let Some(hours) = metadata.estimated_hours {
+        let estimated: f64 = roadmap.tasks.iter().filter_map(|t| t.estimated_hours).sum();
+        summary.push_str(&format!("- Estimate: {:.1}h ({:.1}h estimated across tasks)\n", hours, estimated));
+    }
+    if let Some(phase) = &metadata.default_phase {
+        summary.push_str(&format!("- Default phase: {}\n", phase));
+    }
+    if !summary.ends_with("\n\n") && !summary.is_empty() {
+        summary.push('\n');
+    }
+    summary
+}
+
 /// Create an editable task details file with metadata
 fn create_task_details_file(roadmap: &crate::model::Roadmap, rask_dir: &Path) -> CommandResult {
     let mut details_content = String::from(r#"# Task Details & Metadata
@@ -427,8 +453,28 @@ pub fn show_project_enhanced(
     phase_filter: Option<&str>,
     detailed: bool,
     collapse_completed: bool,
+    sort: Option<SortKey>,
+    reverse: bool,
+    include_snoozed: bool,
 ) -> CommandResult {
-    let roadmap = state::load_state()?;
+    let mut roadmap = state::load_state()?;
+    let mut tasks = std::mem::take(&mut roadmap.tasks);
+    utils::hide_snoozed(&mut tasks, include_snoozed);
+    sorting::sort_tasks(&mut tasks, sort.unwrap_or_else(SortKey::from_config), reverse, &roadmap);
+    roadmap.tasks = tasks;
+    
+    if utils::json_output() {
+        let tasks: Vec<&Task> = roadmap.tasks.iter()
+            .filter(|t| phase_filter.is_none_or(|phase| t.phase.name.eq_ignore_ascii_case(phase)))
+            .collect();
+        return utils::print_json(&serde_json::json!({
+            "title": roadmap.title,
+            "source_file": roadmap.source_file,
+            "total_tasks": tasks.len(),
+            "completed_tasks": tasks.iter().filter(|t| t.status == TaskStatus::Completed).count(),
+            "tasks": tasks,
+        }));
+    }
     
     if group_by_phase {
         ui::display_roadmap_grouped_by_phase(&roadmap, detailed, collapse_completed);
@@ -449,9 +495,10 @@ pub fn show_timeline(detailed: bool, active_only: bool, compact: bool, page: Opt
 }
 
 /// Mark a task as completed
-pub fn complete_task(task_id: usize) -> CommandResult {
+pub fn complete_task(task_id: usize, force: bool) -> CommandResult {
     // Load current state
     let mut roadmap = state::load_state()?;
+    roadmap.find_task_by_id(task_id).ok_or_else(|| CommandError::task_not_found(task_id))?;
     
     // Validate dependencies first
     if let Err(errors) = roadmap.validate_task_dependencies(task_id) {
@@ -472,13 +519,19 @@ pub fn complete_task(task_id: usize) -> CommandResult {
             
             // Show detailed dependency information
             ui::display_dependency_error(task_id, &incomplete_deps, &roadmap);
-            return Err(format!(
+            return Err(CommandError::Blocked(format!(
                 "Cannot complete task {}. Missing dependencies: {:?}", 
                 task_id, incomplete_deps
-            ).into());
+            )).into());
         }
     }
     
+    // Walk through the acceptance criteria checklist
+    let criteria_record = match roadmap.find_task_by_id(task_id) {
+        Some(task) => confirm_acceptance_criteria(task, force)?,
+        None => None,
+    };
+    
     // Find tasks that will be unblocked (before completing this task)
     let newly_unblocked = dependencies::find_newly_unblocked_tasks(&roadmap, task_id);
     
@@ -487,11 +540,15 @@ pub fn complete_task(task_id: usize) -> CommandResult {
     
     match task {
         Some(task) => {
+            hooks::run_pre_hook("pre-complete", task)?;
             let task_description = task.description.clone();
             task.mark_completed();
+            task.record_history("completed", criteria_record);
+            let completed_task = task.clone();
             
             // Save to both JSON state and original markdown file
             utils::save_and_sync(&roadmap)?;
+            hooks::run_post_hook("post-complete", &completed_task);
             
             // Display enhanced completion success with dependency unlocking
             ui::display_completion_success_enhanced(task_id, &task_description, &newly_unblocked, &roadmap);
@@ -499,11 +556,52 @@ pub fn complete_task(task_id: usize) -> CommandResult {
             
             Ok(())
         }
-        None => Err(format!("Task with ID {} not found.", task_id).into()),
+        None => Err(CommandError::task_not_found(task_id).into()),
+    }
+}
+
+/// Display a task's acceptance criteria and ask the user to confirm each one.
+///
+/// Returns a summary suitable for the task history, or `None` when the task
+/// has no criteria. With `force`, the checklist is skipped and the summary
+/// notes that the criteria were not verified.
+fn confirm_acceptance_criteria(task: &Task, force: bool) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let criteria = &task.acceptance_criteria;
+    if criteria.is_empty() {
+        return Ok(None);
+    }
+    
+    if force {
+        ui::display_warning(&format!(
+            "Skipping verification of {} acceptance criteria (--force)", criteria.len()
+        ));
+        return Ok(Some(format!("forced; {} acceptance criteria not verified", criteria.len())));
+    }
+    
+    ui::display_info(&format!("🎯 Task #{} has {} acceptance criteria:", task.id, criteria.len()));
+    for (index, criterion) in criteria.iter().enumerate() {
+        let prompt = format!("   {}. {} — met?", index + 1, criterion);
+        if utils::assume_yes() {
+            println!("{} yes (--yes)", prompt);
+            continue;
+        }
+        
+        if !utils::confirm(&prompt)? {
+            return Err(format!(
+                "Task {} not completed: acceptance criterion {} not confirmed. Use --force to override.",
+                task.id, index + 1
+            ).into());
+        }
     }
+    
+    if utils::assume_yes() {
+        return Ok(Some(format!("all {} acceptance criteria confirmed via --yes", criteria.len())));
+    }
+    Ok(Some(format!("all {} acceptance criteria confirmed", criteria.len())))
 }
 
 /// Add a new task with enhanced metadata support
+#[allow(clippy::too_many_arguments)]
 pub fn add_task_enhanced(
     description: &str,
     tags: &Option<String>,
@@ -512,6 +610,12 @@ pub fn add_task_enhanced(
     notes: &Option<String>,
     dependencies: &Option<String>,
     estimated_hours: &Option<f64>,
+    due: &Option<String>,
+    criteria: &[String],
+    owners: &Option<String>,
+    energy: Option<CliEnergy>,
+    contexts: &Option<String>,
+    no_markdown: bool,
 ) -> CommandResult {
     // Enhanced input validation
     if let Err(validation_error) = utils::validate_task_description(description) {
@@ -566,7 +670,7 @@ pub fn add_task_enhanced(
     }
 
     if let Some(ref phase_str) = phase {
-        let phase_model = Phase::from_string(phase_str);
+        let phase_model = roadmap.phase_named(phase_str);
         new_task = new_task.with_phase(phase_model);
     }
 
@@ -595,14 +699,44 @@ pub fn add_task_enhanced(
         new_task.set_estimated_hours(*hours);
     }
     
+    if let Some(due_str) = due {
+        new_task = new_task.with_due_date(utils::parse_due_date(due_str)?);
+    }
+    
+    // Attach acceptance criteria, ignoring blank entries
+    let criteria: Vec<String> = criteria.iter()
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect();
+    if !criteria.is_empty() {
+        new_task = new_task.with_acceptance_criteria(criteria);
+    }
+    
+    if let Some(owners_str) = owners {
+        new_task.owners = utils::parse_owners(owners_str)?;
+    }
+    
+    new_task.energy = energy.map(Into::into);
+    if let Some(contexts_str) = contexts {
+        new_task.contexts = utils::parse_contexts(contexts_str)?;
+    }
+    
+    new_task.sync = !no_markdown;
+    
+    utils::check_wip_limit(&roadmap, &new_task.phase, roadmap.open_tasks_in_phase(&new_task.phase) + 1)?;
+    hooks::run_pre_hook("pre-add", &new_task)?;
+    
     // Add task to roadmap
     roadmap.add_task(new_task.clone());
     
     // Save to both JSON state and original markdown file
-    utils::save_and_sync(&roadmap)?;
+    let markdown_synced = utils::save_and_sync_source(&roadmap)? && new_task.sync;
+    if let Some(added) = roadmap.find_task_by_id(new_task.id) {
+        hooks::run_post_hook("post-add", added);
+    }
     
     // Display success and updated roadmap
-    ui::display_add_success_enhanced(&new_task);
+    ui::display_add_success_enhanced(&new_task, markdown_synced);
     ui::display_roadmap(&roadmap);
     
     Ok(())
@@ -626,45 +760,186 @@ pub fn remove_task(task_id: usize) -> CommandResult {
         ).into());
     }
     
+    if let Some(task) = roadmap.find_task_by_id(task_id) {
+        hooks::run_pre_hook("pre-remove", task)?;
+    }
+    
     // Remove the task
     if let Some(removed_task) = roadmap.remove_task(task_id) {
         // Save to both JSON state and original markdown file
-        utils::save_and_sync(&roadmap)?;
+        let markdown_synced = utils::save_and_sync_source(&roadmap)?;
+        hooks::run_post_hook("post-remove", &removed_task);
         
         // Display success and updated roadmap
-        ui::display_remove_success(&removed_task.description);
+        ui::display_remove_success(&removed_task.description, markdown_synced);
         ui::display_roadmap(&roadmap);
         
         Ok(())
     } else {
-        Err(format!("Task with ID {} not found.", task_id).into())
+        Err(CommandError::task_not_found(task_id).into())
     }
 }
 
-/// Edit the description of an existing task
-pub fn edit_task(task_id: usize, new_description: &str) -> CommandResult {
+/// Fold task `merge_id` into task `keep_id` and remove it
+pub fn merge_task(keep_id: usize, merge_id: usize) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    
+    let keep_uuid = roadmap.find_task_by_id(keep_id)
+        .ok_or_else(|| CommandError::task_not_found(keep_id))?
+        .uuid.clone();
+    let dependents = roadmap.get_dependents(merge_id);
+    if let Some(task) = roadmap.find_task_by_id(merge_id) {
+        hooks::run_pre_hook("pre-remove", task)?;
+    }
+    
+    let merged = roadmap.merge_tasks(keep_id, merge_id)?;
+    utils::save_and_sync(&roadmap)?;
+    hooks::run_post_hook("post-remove", &merged);
+    
+    // The kept task moves down one when the merged task came before it
+    let kept = roadmap.tasks.iter().find(|t| t.uuid == keep_uuid)
+        .ok_or_else(|| CommandError::task_not_found(keep_id))?;
+    ui::display_success(&format!("Merged task #{} \"{}\" into #{} \"{}\"", merge_id, merged.description, kept.id, kept.description));
+    let mut carried = Vec::new();
+    if !merged.implementation_notes.is_empty() {
+        carried.push(format!("{} implementation note(s)", merged.implementation_notes.len()));
+    }
+    if !merged.comments.is_empty() {
+        carried.push(format!("{} comment(s)", merged.comments.len()));
+    }
+    if !merged.time_sessions.is_empty() {
+        carried.push(format!("{:.1}h over {} time session(s)", merged.get_total_tracked_hours(), merged.time_sessions.len()));
+    }
+    if !dependents.is_empty() {
+        carried.push(format!("{} dependent task(s)", dependents.len()));
+    }
+    if !carried.is_empty() {
+        ui::display_info(&format!("Carried over {}", carried.join(", ")));
+    }
+    if kept.id != keep_id {
+        ui::display_info(&format!("Task #{} is now #{}", keep_id, kept.id));
+    }
+    
+    Ok(())
+}
+
+/// Hide a task from show, list and ready until a date, or wake it up again
+pub fn snooze_task(task_id: usize, until: Option<&str>, for_span: Option<&str>, clear: bool) -> CommandResult {
+    let today = chrono::Local::now().date_naive();
+    let until = match (until, for_span) {
+        _ if clear => None,
+        (Some(date), _) => Some(utils::parse_due_date(date)?),
+        (None, Some(span)) => Some(today + utils::parse_day_span(span)?),
+        (None, None) => return Err("Give a date with --until, a span with --for, or --clear".into()),
+    };
+    if until.is_some_and(|date| date <= today) {
+        return Err("The snooze date must be after today".into());
+    }
+    
+    let mut roadmap = state::load_state()?;
+    let task = roadmap.tasks.iter_mut().find(|t| t.id == task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    
+    match until {
+        Some(date) => {
+            if task.status == TaskStatus::Completed {
+                return Err(format!("Task #{} is already completed", task_id).into());
+            }
+            let date = date.format("%Y-%m-%d").to_string();
+            task.snoozed_until = Some(date.clone());
+            task.record_history("snoozed", Some(format!("until {}", date)));
+            utils::save_and_sync(&roadmap)?;
+            ui::display_success(&format!("Task #{} snoozed until {} (hidden from show, list and ready)", task_id, date));
+        }
+        None => {
+            if task.snoozed_until.take().is_none() {
+                ui::display_info(&format!("Task #{} is not snoozed", task_id));
+                return Ok(());
+            }
+            task.record_history("unsnoozed", None);
+            utils::save_and_sync(&roadmap)?;
+            ui::display_success(&format!("Task #{} is back in show, list and ready", task_id));
+        }
+    }
+    Ok(())
+}
+
+/// Edit the description, owners, energy, or contexts of an existing task
+pub fn edit_task(
+    task_id: usize,
+    new_description: Option<&str>,
+    owners: Option<&str>,
+    clear_owners: bool,
+    energy: Option<CliEnergy>,
+    contexts: Option<&str>,
+) -> CommandResult {
+    if new_description.is_none() && owners.is_none() && !clear_owners && energy.is_none() && contexts.is_none() {
+        return Err("Nothing to edit. Give a new description, --owner, --clear-owners, --energy, or --context".into());
+    }
+    
+    let parsed_owners = owners.map(utils::parse_owners).transpose()?;
+    let parsed_contexts = contexts.map(utils::parse_contexts).transpose()?;
+    
     // Load current state
     let mut roadmap = state::load_state()?;
     
     // Find and update the task
-    let task = roadmap.tasks.iter_mut().find(|t| t.id == task_id);
+    let task = roadmap.tasks.iter_mut().find(|t| t.id == task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
     
-    match task {
-        Some(task) => {
-            let old_description = task.description.clone();
-            task.description = new_description.to_string();
-            
-            // Save to both JSON state and original markdown file
-            utils::save_and_sync(&roadmap)?;
-            
-            // Display success and updated roadmap
-            ui::display_edit_success(task_id, &old_description, new_description);
-            ui::display_roadmap(&roadmap);
-            
-            Ok(())
+    let old_description = task.description.clone();
+    if let Some(description) = new_description {
+        task.description = description.to_string();
+    }
+    
+    let old_owners = task.owners.clone();
+    if clear_owners {
+        task.owners.clear();
+    }
+    if let Some(ref new_owners) = parsed_owners {
+        task.add_owners(new_owners);
+    }
+    if task.owners != old_owners {
+        let details = if task.owners.is_empty() { "none".to_string() } else { task.owners.join(", ") };
+        task.record_history("owners_changed", Some(details));
+    }
+    let current_owners = task.owners.clone();
+    
+    if let Some(energy) = energy {
+        task.energy = Some(energy.into());
+    }
+    if let Some(new_contexts) = parsed_contexts {
+        task.contexts = new_contexts;
+    }
+    let energy_summary = task.energy.map(|e| e.to_string()).unwrap_or_else(|| "unset".to_string());
+    let contexts_summary = task.contexts.join(", ");
+    let edited_task = task.clone();
+    hooks::run_pre_hook("pre-edit", &edited_task)?;
+    
+    // Save to both JSON state and original markdown file
+    let markdown_synced = utils::save_and_sync_source(&roadmap)?;
+    hooks::run_post_hook("post-edit", &edited_task);
+    
+    // Display success and updated roadmap
+    if let Some(description) = new_description {
+        ui::display_edit_success(task_id, &old_description, description, markdown_synced);
+    }
+    if current_owners != old_owners {
+        if current_owners.is_empty() {
+            ui::display_success(&format!("Cleared owners of task #{}", task_id));
+        } else {
+            ui::display_success(&format!("Task #{} owners: {}", task_id, current_owners.join(", ")));
         }
-        None => Err(format!("Task with ID {} not found.", task_id).into()),
     }
+    if energy.is_some() {
+        ui::display_success(&format!("Task #{} energy: {}", task_id, energy_summary));
+    }
+    if contexts.is_some() {
+        ui::display_success(&format!("Task #{} contexts: {}", task_id, contexts_summary));
+    }
+    ui::display_roadmap(&roadmap);
+    
+    Ok(())
 }
 
 /// Reset task(s) to pending status
@@ -681,12 +956,14 @@ pub fn reset_tasks(task_id: Option<usize>) -> CommandResult {
                 Some(task) => {
                     if task.status == TaskStatus::Completed {
                         task.mark_pending();
+                        let reset_task = task.clone();
                         
                         // Save to both JSON state and original markdown file
-                        utils::save_and_sync(&roadmap)?;
+                        let markdown_synced = utils::save_and_sync_source(&roadmap)?;
+                        hooks::run_post_hook("post-reset", &reset_task);
                         
                         // Display success and updated roadmap
-                        ui::display_reset_success(Some(id));
+                        ui::display_reset_success(Some(id), markdown_synced);
                         ui::display_roadmap(&roadmap);
                     } else {
                         ui::display_info(&format!("Task {} is already pending.", id));
@@ -694,7 +971,7 @@ pub fn reset_tasks(task_id: Option<usize>) -> CommandResult {
                     
                     Ok(())
                 }
-                None => Err(format!("Task with ID {} not found.", id).into()),
+                None => Err(CommandError::task_not_found(id).into()),
             }
         }
         None => {
@@ -704,15 +981,22 @@ pub fn reset_tasks(task_id: Option<usize>) -> CommandResult {
                 .count();
             
             if completed_count > 0 {
+                let reset: Vec<usize> = roadmap.tasks.iter()
+                    .filter(|t| t.status == TaskStatus::Completed)
+                    .map(|t| t.id)
+                    .collect();
                 for task in &mut roadmap.tasks {
                     task.mark_pending();
                 }
                 
                 // Save to both JSON state and original markdown file
-                utils::save_and_sync(&roadmap)?;
+                let markdown_synced = utils::save_and_sync_source(&roadmap)?;
+                for task in roadmap.tasks.iter().filter(|t| reset.contains(&t.id)) {
+                    hooks::run_post_hook("post-reset", task);
+                }
                 
                 // Display success and updated roadmap
-                ui::display_reset_success(None);
+                ui::display_reset_success(None, markdown_synced);
                 ui::display_roadmap(&roadmap);
             } else {
                 ui::display_info("All tasks are already pending.");
@@ -724,54 +1008,85 @@ pub fn reset_tasks(task_id: Option<usize>) -> CommandResult {
 }
 
 /// List and filter tasks with advanced options
+#[allow(clippy::too_many_arguments)]
 pub fn list_tasks(
     tags: &Option<String>,
     priority: &Option<CliPriority>,
     phase: &Option<String>,
     status: &Option<String>,
     search: &Option<String>,
+    owner: &Option<String>,
+    mine: bool,
+    stale: Option<u32>,
     detailed: bool,
+    all_projects: bool,
+    sort: Option<SortKey>,
+    reverse: bool,
+    include_snoozed: bool,
+    group_tags: Option<&str>,
 ) -> CommandResult {
-    let roadmap = state::load_state()?;
+    // Resolve the owner filter before touching state so --mine fails fast
+    let owner_filter = if mine {
+        Some(utils::current_user_name().ok_or(
+            "No user name configured. Set one with 'rask config set user.name <NAME>'"
+        )?)
+    } else {
+        owner.clone()
+    };
     
-    // Start with all tasks
-    let mut filtered_tasks: Vec<&Task> = roadmap.tasks.iter().collect();
+    // Tag, priority, phase, status and search filters are answered by the storage backend
+    let query = state::TaskQuery {
+        status: match status.as_deref().map(str::to_lowercase).as_deref() {
+            None | Some("all") => None,
+            Some("pending") => Some(TaskStatus::Pending),
+            Some("completed") => Some(TaskStatus::Completed),
+            Some(_) => return Err(format!("Invalid status filter: {}. Use 'pending', 'completed', or 'all'.", status.as_deref().unwrap_or_default()).into()),
+        },
+        priority: priority.clone().map(Priority::from),
+        phase: phase.as_deref().map(Phase::from_string),
+        tags: tags.as_deref()
+            .map(|tag_str| tag_str.split(',').map(|s| s.trim().to_string()).collect())
+            .unwrap_or_default(),
+        text: search.clone(),
+    };
     
-    // Apply tag filter
-    if let Some(tag_str) = tags {
-        let filter_tags: Vec<String> = tag_str.split(',').map(|s| s.trim().to_string()).collect();
-        filtered_tasks.retain(|task| {
-            filter_tags.iter().any(|tag| task.has_tag(tag))
-        });
+    let sort = sort.unwrap_or_else(SortKey::from_config);
+    if all_projects {
+        return super::list_workspace_tasks(&query, owner_filter.as_deref(), stale, detailed, sort, reverse, include_snoozed);
     }
     
-    // Apply priority filter
-    if let Some(ref priority_cli) = priority {
-        let priority_model: Priority = priority_cli.clone().into();
-        filtered_tasks.retain(|task| task.priority == priority_model);
+    // The summary supplies the title and totals; only matching tasks are loaded in full.
+    // Sorting by phase needs the phase registry, which only the full state has.
+    let roadmap = if sort == SortKey::Phase { state::load_state()? } else { state::load_state_summary()? };
+    let matching_tasks = state::query_tasks(&query)?;
+    let mut filtered_tasks: Vec<&Task> = matching_tasks.iter().collect();
+    
+    // Apply owner filter
+    if let Some(ref name) = owner_filter {
+        filtered_tasks.retain(|task| task.has_owner(name));
     }
-
-    // Apply phase filter
-    if let Some(ref phase_str) = phase {
-        let phase_model = Phase::from_string(phase_str);
-        filtered_tasks.retain(|task| task.phase == phase_model);
+    if let Some(days) = stale {
+        filtered_tasks.retain(|task| task.is_stale(i64::from(days)));
     }
-
-    // Apply status filter
-    if let Some(ref status_str) = status {
-        match status_str.to_lowercase().as_str() {
-            "pending" => filtered_tasks.retain(|task| task.status == TaskStatus::Pending),
-            "completed" => filtered_tasks.retain(|task| task.status == TaskStatus::Completed),
-            "all" => {}, // Keep all tasks
-            _ => return Err(format!("Invalid status filter: {}. Use 'pending', 'completed', or 'all'.", status_str).into()),
+    utils::hide_snoozed(&mut filtered_tasks, include_snoozed);
+    sorting::sort_tasks(&mut filtered_tasks, sort, reverse, &roadmap);
+    
+    if let Some(namespace) = group_tags {
+        let groups = group_by_tag(&filtered_tasks, namespace.trim().trim_start_matches('#'));
+        if utils::json_output() {
+            let groups: serde_json::Map<String, serde_json::Value> = groups.iter()
+                .map(|(name, tasks)| Ok((name.clone(), serde_json::to_value(tasks)?)))
+                .collect::<Result<_, serde_json::Error>>()?;
+            return utils::print_json(&groups);
+        }
+        if !ui::is_porcelain() {
+            ui::display_tag_groups(&roadmap, &groups, filtered_tasks.len(), detailed);
+            return Ok(());
         }
     }
     
-    // Apply search filter
-    if let Some(ref query) = search {
-        let search_results = roadmap.search_tasks(query);
-        let search_ids: std::collections::HashSet<usize> = search_results.iter().map(|t| t.id).collect();
-        filtered_tasks.retain(|task| search_ids.contains(&task.id));
+    if utils::json_output() {
+        return utils::print_json(&filtered_tasks);
     }
     
     // Display filtered results
@@ -780,13 +1095,66 @@ pub fn list_tasks(
     Ok(())
 }
 
+/// Name of the group for tasks without a matching tag in `list --group-tags`
+const UNGROUPED: &str = "(no tag)";
+
+/// Tasks grouped by the tag level just below `namespace`, or by top-level
+/// namespace when it is empty. A task appears once in each of its groups.
+fn group_by_tag<'a>(tasks: &[&'a Task], namespace: &str) -> Vec<(String, Vec<&'a Task>)> {
+    let namespace = namespace.trim_end_matches('/');
+    let mut groups: std::collections::BTreeMap<String, Vec<&'a Task>> = std::collections::BTreeMap::new();
+    let mut ungrouped = Vec::new();
+    for task in tasks {
+        let keys: std::collections::BTreeSet<String> = task.tags.iter()
+            .filter_map(|tag| {
+                if namespace.is_empty() {
+                    return Some(crate::model::tag_root(tag).to_string());
+                }
+                if !crate::model::tag_within(tag, namespace) {
+                    return None;
+                }
+                let child = tag[namespace.len()..].trim_start_matches('/').split('/').next().filter(|part| !part.is_empty());
+                Some(child.map_or_else(|| namespace.to_string(), |child| format!("{}/{}", namespace, child)))
+            })
+            .collect();
+        if keys.is_empty() {
+            ungrouped.push(*task);
+        }
+        for key in keys {
+            groups.entry(key).or_default().push(*task);
+        }
+    }
+    let mut groups: Vec<(String, Vec<&Task>)> = groups.into_iter().collect();
+    if !ungrouped.is_empty() {
+        groups.push((UNGROUPED.to_string(), ungrouped));
+    }
+    groups
+}
+
 /// View detailed information about a specific task
-pub fn view_task(task_id: usize) -> CommandResult {
+pub fn view_task(task_id: usize, history: bool) -> CommandResult {
     let roadmap = state::load_state()?;
     
     // Find the task
     let task = roadmap.find_task_by_id(task_id)
-        .ok_or_else(|| format!("Task #{} not found", task_id))?;
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    
+    if history {
+        if utils::json_output() {
+            return utils::print_json(&task.history);
+        }
+        ui::display_task_history_log(task);
+        return Ok(());
+    }
+    
+    if utils::json_output() {
+        let mut value = serde_json::to_value(task)?;
+        value["dependents"] = serde_json::json!(roadmap.get_dependents(task_id));
+        value["ready"] = serde_json::json!(
+            task.status == TaskStatus::Pending && task.can_be_started(&roadmap.get_completed_task_ids())
+        );
+        return utils::print_json(&value);
+    }
     
     // Display detailed task information
     ui::display_detailed_task_view(task, &roadmap);
@@ -808,9 +1176,15 @@ pub fn start_time_tracking(task_id: usize, description: Option<&str>) -> Command
         }
     }
     
+    // Starting work in a phase that is already over its WIP limit adds to the overload
+    let phase = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?
+        .phase.clone();
+    utils::check_wip_limit(&roadmap, &phase, roadmap.open_tasks_in_phase(&phase))?;
+    
     // Find the task to start tracking
     let task = roadmap.find_task_by_id_mut(task_id)
-        .ok_or_else(|| format!("Task #{} not found", task_id))?;
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
     
     // Get task description before borrowing mutably
     let task_description = task.description.clone();
@@ -851,8 +1225,9 @@ pub fn stop_time_tracking() -> CommandResult {
     let task = roadmap.find_task_by_id_mut(task_id)
         .ok_or("Task with active time session not found - data may be corrupted")?;
     let task_description = task.description.clone();
+    let trimmed_end = trimmed_session_end(task)?;
     
-    match task.end_current_time_session() {
+    match task.end_current_time_session_at(trimmed_end.unwrap_or_else(chrono::Utc::now)) {
         Ok(duration_hours) => {
             let estimated_hours = task.estimated_hours;
             let total_tracked = task.get_total_tracked_hours();
@@ -880,14 +1255,236 @@ pub fn stop_time_tracking() -> CommandResult {
     }
 }
 
+/// When the session `rask stop` is about to end ran past
+/// `behavior.max_session_hours`, offer to record it only up to the limit.
+///
+/// Returns the end time to record instead of now: `--yes` trims, and
+/// `--non-interactive` keeps the full duration.
+fn trimmed_session_end(task: &crate::model::Task) -> Result<Option<chrono::DateTime<chrono::Utc>>, Box<dyn std::error::Error>> {
+    let (Some(max_hours), Some(session)) = (max_session_hours(), task.get_active_time_session()) else {
+        return Ok(None);
+    };
+    let elapsed = session.elapsed_hours();
+    let Some(start) = crate::model::parse_timestamp(Some(&session.start_time)).filter(|_| elapsed > max_hours) else {
+        return Ok(None);
+    };
+    let trim = utils::assume_yes() || (!utils::is_non_interactive() && matches!(
+        utils::prompt_line(&format!("This session ran {:.1}h, over the {}h limit. Trim it to {}h or keep the full time? [t/F]: ", elapsed, max_hours, max_hours))?
+            .trim()
+            .to_lowercase()
+            .as_str(),
+        "t" | "trim"
+    ));
+    Ok(trim.then(|| start + chrono::Duration::minutes((max_hours * 60.0) as i64)))
+}
+
+/// `behavior.max_session_hours`, or `None` when long sessions are not checked
+fn max_session_hours() -> Option<f64> {
+    crate::config::RaskConfig::load().ok()
+        .map(|config| config.behavior.max_session_hours)
+        .filter(|hours| *hours > 0.0)
+}
+
+/// Warn about a time session left running past `behavior.max_session_hours`.
+///
+/// Runs before commands that change the roadmap. It only warns, on stderr,
+/// and never prompts; `rask stop` offers to trim the session.
+pub fn check_long_running_sessions() -> CommandResult {
+    if !state::has_local_workspace() {
+        return Ok(());
+    }
+    let Some(max_hours) = max_session_hours() else {
+        return Ok(());
+    };
+
+    let Ok(roadmap) = state::load_state_summary() else {
+        return Ok(());
+    };
+    let Some((task_id, elapsed)) = roadmap.tasks.iter().find_map(|task| {
+        Some((task.id, task.get_active_time_session()?.elapsed_hours()))
+    }) else {
+        return Ok(());
+    };
+    if elapsed > max_hours {
+        ui::display_stderr_warning(&format!(
+            "Time session on task #{} has been running for {:.1}h (limit {}h). Run 'rask stop' to close or trim it.",
+            task_id, elapsed, max_hours
+        ));
+    }
+    Ok(())
+}
+
+/// Backfill a finished time session, e.g. for work done away from the terminal
+pub fn add_time_entry(task_id: usize, hours: f64, date: Option<&str>, start: Option<&str>, note: Option<&str>) -> CommandResult {
+    validate_session_hours(hours)?;
+    let today = chrono::Local::now().date_naive();
+    let day = match date {
+        Some(date) => chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date '{}'. Use YYYY-MM-DD", date))?,
+        None => today,
+    };
+    let duration = chrono::Duration::minutes((hours * 60.0).round() as i64);
+    let start_time = match start {
+        Some(start) => local_session_start(day, start)?,
+        // Work logged for today most likely just finished
+        None if day == today => chrono::Utc::now() - duration,
+        None => local_session_start(day, "09:00")?,
+    };
+    let end_time = start_time + duration;
+    if end_time > chrono::Utc::now() {
+        return Err("That session would end in the future. Pass an earlier --date or --start".into());
+    }
+
+    let mut roadmap = state::load_state()?;
+    let task = roadmap.find_task_by_id_mut(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    let session = crate::model::TimeSession::completed(start_time, end_time, note.map(str::to_string));
+    if !task.add_completed_time_session(session) {
+        return Err(format!("Task #{} already has a session starting at that time", task_id).into());
+    }
+    task.record_history("time_added", Some(format!("{:.2}h on {}", hours, day)));
+    let total_tracked = task.get_total_tracked_hours();
+    let index = task.time_sessions.iter()
+        .position(|s| crate::model::parse_timestamp(Some(&s.start_time)) == Some(start_time))
+        .unwrap_or_default();
+    let task_description = task.description.clone();
+    utils::save_and_sync(&roadmap)?;
+
+    ui::display_success(&format!("Logged {:.2}h on task #{}: {}", hours, task_id, task_description));
+    ui::display_info(&format!("📅 {} (session #{})", format_session_range(start_time, Some(end_time)), index));
+    ui::display_info(&format!("📊 Total tracked time: {:.2} hours", total_tracked));
+    Ok(())
+}
+
+/// Correct the date, start, duration or note of a recorded time session
+pub fn edit_time_session(
+    task_id: usize,
+    index: usize,
+    hours: Option<f64>,
+    date: Option<&str>,
+    start: Option<&str>,
+    note: Option<&str>,
+    clear_note: bool,
+) -> CommandResult {
+    if hours.is_none() && date.is_none() && start.is_none() && note.is_none() && !clear_note {
+        return Err("Nothing to edit. Pass --hours, --date, --start, --note or --clear-note".into());
+    }
+    if let Some(hours) = hours {
+        validate_session_hours(hours)?;
+    }
+
+    let mut roadmap = state::load_state()?;
+    let task = roadmap.find_task_by_id_mut(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    let mut session = task.time_sessions.get(index).cloned().ok_or_else(|| format!(
+        "Task #{} has no time session #{}. It has {} session(s); see 'rask time {} --detailed'",
+        task_id, index, task.time_sessions.len(), task_id
+    ))?;
+    if session.is_active() && hours.is_some() {
+        return Err("That session is still running. Stop it with 'rask stop' before changing its duration".into());
+    }
+
+    let old_start = crate::model::parse_timestamp(Some(&session.start_time))
+        .ok_or("Session has an invalid start time")?;
+    let old_local = old_start.with_timezone(&chrono::Local);
+    let new_start = if date.is_some() || start.is_some() {
+        let day = match date {
+            Some(date) => chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| format!("Invalid date '{}'. Use YYYY-MM-DD", date))?,
+            None => old_local.date_naive(),
+        };
+        let time = start.map(str::to_string)
+            .unwrap_or_else(|| old_local.format("%H:%M").to_string());
+        local_session_start(day, &time)?
+    } else {
+        old_start
+    };
+    if new_start != old_start && task.time_sessions.iter().enumerate()
+        .any(|(i, s)| i != index && crate::model::parse_timestamp(Some(&s.start_time))
+            .is_some_and(|existing| (existing - new_start).num_seconds().abs() < 60)) {
+        return Err(format!("Task #{} already has a session starting at that time", task_id).into());
+    }
+
+    if session.is_active() {
+        session.start_time = new_start.to_rfc3339();
+    } else {
+        let minutes = match hours {
+            Some(hours) => (hours * 60.0).round() as i64,
+            None => session.duration_minutes.unwrap_or_default() as i64,
+        };
+        session = crate::model::TimeSession::completed(new_start, new_start + chrono::Duration::minutes(minutes), session.description);
+    }
+    if clear_note {
+        session.description = None;
+    } else if let Some(note) = note {
+        session.description = Some(note.to_string());
+    }
+
+    let end = crate::model::parse_timestamp(session.end_time.as_deref());
+    let range = format_session_range(new_start, end);
+    let duration = session.duration_hours();
+    task.replace_time_session(index, session)?;
+    task.record_history("time_edited", Some(format!("session {} now {}", index, range)));
+    let total_tracked = task.get_total_tracked_hours();
+    utils::save_and_sync(&roadmap)?;
+
+    ui::display_success(&format!("Updated time session on task #{}", task_id));
+    match duration {
+        Some(duration) => ui::display_info(&format!("📅 {} ({:.2}h)", range, duration)),
+        None => ui::display_info(&format!("📅 {}", range)),
+    }
+    ui::display_info(&format!("📊 Total tracked time: {:.2} hours", total_tracked));
+    Ok(())
+}
+
+/// Reject durations that cannot be a single sitting
+fn validate_session_hours(hours: f64) -> CommandResult {
+    if !hours.is_finite() || hours <= 0.0 || hours > 24.0 {
+        return Err(format!("Invalid hours {}. Use a value above 0 and up to 24", hours).into());
+    }
+    Ok(())
+}
+
+/// Combine a date and a local `HH:MM` time into a UTC start time
+fn local_session_start(day: chrono::NaiveDate, time: &str) -> Result<chrono::DateTime<chrono::Utc>, Box<dyn std::error::Error>> {
+    use chrono::TimeZone;
+    let time = chrono::NaiveTime::parse_from_str(time, "%H:%M")
+        .map_err(|_| format!("Invalid start time '{}'. Use HH:MM", time))?;
+    let start = chrono::Local.from_local_datetime(&day.and_time(time))
+        .earliest()
+        .ok_or_else(|| format!("{} {} does not exist in the local timezone", day, time))?;
+    Ok(start.with_timezone(&chrono::Utc))
+}
+
+/// Format a session's span in local time, e.g. `2024-05-01 09:00–10:30`
+fn format_session_range(start: chrono::DateTime<chrono::Utc>, end: Option<chrono::DateTime<chrono::Utc>>) -> String {
+    let start = start.with_timezone(&chrono::Local);
+    match end.map(|end| end.with_timezone(&chrono::Local)) {
+        Some(end) if end.date_naive() == start.date_naive() => format!("{}–{}", start.format("%Y-%m-%d %H:%M"), end.format("%H:%M")),
+        Some(end) => format!("{} – {}", start.format("%Y-%m-%d %H:%M"), end.format("%Y-%m-%d %H:%M")),
+        None => format!("{} – running", start.format("%Y-%m-%d %H:%M")),
+    }
+}
+
 /// Show time tracking information
-pub fn show_time_tracking(task_id: &Option<usize>, summary: bool, _detailed: bool) -> CommandResult {
+pub fn show_time_tracking(task_id: &Option<usize>, summary: bool, detailed: bool) -> CommandResult {
     let roadmap = state::load_state()?;
     
     if let Some(id) = task_id {
         // Show time info for specific task
         let task = roadmap.find_task_by_id(*id)
-            .ok_or_else(|| format!("Task #{} not found", id))?;
+            .ok_or_else(|| CommandError::task_not_found(*id))?;
+        
+        if utils::json_output() {
+            return utils::print_json(&serde_json::json!({
+                "id": task.id,
+                "description": task.description,
+                "estimated_hours": task.estimated_hours,
+                "actual_hours": task.actual_hours,
+                "active_session": task.has_active_time_session(),
+                "sessions": task.time_sessions,
+            }));
+        }
         
         // TODO: Implement proper time info display
         ui::display_info(&format!("⏰ Time tracking for task #{}: {}", id, task.description));
@@ -906,6 +1503,25 @@ pub fn show_time_tracking(task_id: &Option<usize>, summary: bool, _detailed: boo
         
         ui::display_info(&format!("📈 Total sessions: {}", task.time_sessions.len()));
         
+        if detailed {
+            for (index, session) in task.time_sessions.iter().enumerate() {
+                let Some(start) = crate::model::parse_timestamp(Some(&session.start_time)) else {
+                    continue;
+                };
+                let range = format_session_range(start, crate::model::parse_timestamp(session.end_time.as_deref()));
+                let duration = session.duration_hours()
+                    .map(|h| format!("{:.2}h", h))
+                    .unwrap_or_else(|| "running".to_string());
+                let note = session.description.as_deref()
+                    .map(|d| format!(" - {}", d))
+                    .unwrap_or_default();
+                println!("   #{} {} ({}){}", index, range, duration, note);
+            }
+            if !task.time_sessions.is_empty() {
+                ui::display_info(&format!("💡 Correct a session with 'rask time edit {} <index>'", id));
+            }
+        }
+        
     } else if summary {
         // Show summary across all tasks
         let total_estimated: f64 = roadmap.tasks.iter().filter_map(|t| t.estimated_hours).sum();
@@ -913,6 +1529,16 @@ pub fn show_time_tracking(task_id: &Option<usize>, summary: bool, _detailed: boo
         let tasks_with_estimates = roadmap.tasks.iter().filter(|t| t.estimated_hours.is_some()).count();
         let tasks_with_time = roadmap.tasks.iter().filter(|t| t.actual_hours.is_some()).count();
         
+        if utils::json_output() {
+            return utils::print_json(&serde_json::json!({
+                "total_estimated_hours": total_estimated,
+                "total_actual_hours": total_actual,
+                "tasks_with_estimates": tasks_with_estimates,
+                "tasks_with_tracked_time": tasks_with_time,
+                "variance_hours": (total_estimated > 0.0).then_some(total_actual - total_estimated),
+            }));
+        }
+        
         ui::display_info("📊 Time Tracking Summary");
         ui::display_info(&format!("Total estimated time: {:.2} hours ({} tasks)", total_estimated, tasks_with_estimates));
         ui::display_info(&format!("Total tracked time: {:.2} hours ({} tasks)", total_actual, tasks_with_time));
@@ -924,16 +1550,29 @@ pub fn show_time_tracking(task_id: &Option<usize>, summary: bool, _detailed: boo
         }
     } else {
         // Show time info for all tasks with time data
+        let timed_tasks = roadmap.tasks.iter()
+            .filter(|t| t.estimated_hours.is_some() || t.actual_hours.is_some() || !t.time_sessions.is_empty());
+        
+        if utils::json_output() {
+            let entries: Vec<_> = timed_tasks.map(|task| serde_json::json!({
+                "id": task.id,
+                "description": task.description,
+                "estimated_hours": task.estimated_hours,
+                "actual_hours": task.actual_hours,
+                "active_session": task.has_active_time_session(),
+                "sessions": task.time_sessions.len(),
+            })).collect();
+            return utils::print_json(&entries);
+        }
+        
         ui::display_info("📊 Time Tracking Overview");
-        for task in &roadmap.tasks {
-            if task.estimated_hours.is_some() || task.actual_hours.is_some() || !task.time_sessions.is_empty() {
-                let est = task.estimated_hours.map_or("--".to_string(), |h| format!("{:.2}h", h));
-                let actual = task.actual_hours.map_or("--".to_string(), |h| format!("{:.2}h", h));
-                let status = if task.has_active_time_session() { "🕐" } else { "  " };
-                
-                ui::display_info(&format!("{} #{}: {} | Est: {} | Actual: {}", 
-                    status, task.id, task.description, est, actual));
-            }
+        for task in timed_tasks {
+            let est = task.estimated_hours.map_or("--".to_string(), |h| format!("{:.2}h", h));
+            let actual = task.actual_hours.map_or("--".to_string(), |h| format!("{:.2}h", h));
+            let status = if task.has_active_time_session() { "🕐" } else { "  " };
+            
+            ui::display_info(&format!("{} #{}: {} | Est: {} | Actual: {}", 
+                status, task.id, task.description, est, actual));
         }
     }
     
@@ -1026,6 +1665,7 @@ fn smart_sync(force: bool, dry_run: bool) -> CommandResult {
         for action in &sync_actions {
             println!("   • {}", action);
         }
+        display_source_edits(&roadmap)?;
         return Ok(());
     }
     
@@ -1062,6 +1702,7 @@ fn sync_from_roadmap(force: bool, dry_run: bool) -> CommandResult {
     
     if dry_run {
         ui::display_info(&format!("🔍 Dry run - would sync from {}", source_file));
+        display_source_edits(&roadmap)?;
         return Ok(());
     }
     
@@ -1076,12 +1717,66 @@ fn sync_from_roadmap(force: bool, dry_run: bool) -> CommandResult {
     let markdown_content = fs::read_to_string(source_path)?;
     let mut updated_roadmap = parser::parse_markdown_to_roadmap(&markdown_content, Some(source_path), &roadmap.title)?;
     
-    // Preserve metadata and project ID
-    updated_roadmap.metadata = roadmap.metadata;
+    // Parsed tasks keep their UUIDs, and with stable IDs the IDs they had in the state
+    let previous = crate::model::Roadmap {
+        tasks: roadmap.tasks.iter().filter(|t| t.sync).cloned().collect(),
+        last_task_id: roadmap.get_next_task_id() - 1,
+        ..crate::model::Roadmap::new(String::new())
+    };
+    updated_roadmap.keep_uuids_from(&previous);
+    let stable_ids = crate::model::stable_ids();
+    if stable_ids {
+        updated_roadmap.keep_ids_from(&previous);
+    }
+    
+    // Preserve metadata and project ID, taking the frontmatter fields from the file
+    let frontmatter = updated_roadmap.metadata;
+    updated_roadmap.metadata = crate::model::ProjectMetadata {
+        name: frontmatter.name,
+        description: frontmatter.description,
+        default_phase: frontmatter.default_phase,
+        tags: frontmatter.tags,
+        estimated_hours: frontmatter.estimated_hours,
+        ..roadmap.metadata
+    };
     updated_roadmap.project_id = roadmap.project_id;
+    updated_roadmap.archived_phases = roadmap.archived_phases;
+    updated_roadmap.phase_limits = roadmap.phase_limits;
+    updated_roadmap.phases = roadmap.phases;
+    updated_roadmap.milestones = roadmap.milestones;
+    updated_roadmap.revision = roadmap.revision;
+    
+    // Local-only tasks never reach the markdown file, so carry them over from state
+    let local_tasks: Vec<Task> = roadmap.tasks.into_iter().filter(|t| !t.sync).collect();
+    if !local_tasks.is_empty() {
+        let local_count = local_tasks.len();
+        let first_local_id = updated_roadmap.get_next_task_id();
+        let id_map: std::collections::HashMap<usize, usize> = if stable_ids {
+            // IDs never change, so dependencies on markdown tasks still point at the right task
+            updated_roadmap.tasks.iter().chain(&local_tasks).map(|task| (task.id, task.id)).collect()
+        } else {
+            local_tasks.iter()
+                .enumerate()
+                .map(|(index, task)| (task.id, first_local_id + index))
+                .collect()
+        };
+        
+        for mut task in local_tasks {
+            // Only dependencies between local tasks survive unless IDs are stable; markdown task IDs may have shifted
+            task.dependencies = task.dependencies.iter().filter_map(|dep| id_map.get(dep).copied()).collect();
+            task.dependency_notes = std::mem::take(&mut task.dependency_notes).into_iter()
+                .filter_map(|(dep, note)| id_map.get(&dep).map(|new_dep| (*new_dep, note)))
+                .collect();
+            task.parent = task.parent.and_then(|parent| id_map.get(&parent).copied());
+            task.id = id_map[&task.id];
+            updated_roadmap.tasks.push(task);
+        }
+        ui::display_info(&format!("🔒 Kept {} local-only task(s) that are not in the roadmap file", local_count));
+    }
     
-    // Save the updated state
+    // Save the updated state; the file and the state now agree
     state::save_state(&updated_roadmap)?;
+    crate::markdown_writer::record_synced_source(source_path)?;
     
     // Regenerate project files
     let rask_dir = Path::new(".rask");
@@ -1092,6 +1787,33 @@ fn sync_from_roadmap(force: bool, dry_run: bool) -> CommandResult {
     Ok(())
 }
 
+/// Show how the markdown source differs from the state since rask last wrote it:
+/// the items the next save would add and the edits it would ask to overwrite
+fn display_source_edits(roadmap: &crate::model::Roadmap) -> CommandResult {
+    let Some(edits) = crate::markdown_writer::detect_source_edits(roadmap)? else {
+        return Ok(());
+    };
+    let source_file = roadmap.source_file.as_deref().unwrap_or_default();
+    if edits.new_items.is_empty() && edits.conflicts.is_empty() {
+        ui::display_info(&format!("📝 {} was edited outside rask, but no tasks changed", source_file));
+        return Ok(());
+    }
+    if !edits.new_items.is_empty() {
+        ui::display_info(&format!("📥 New items in {} (added as tasks on the next save):", source_file));
+        for item in &edits.new_items {
+            println!("   + {}", item.description);
+        }
+    }
+    if !edits.conflicts.is_empty() {
+        ui::display_warning(&format!("Conflicting edits in {} (the next save asks before overwriting them):", source_file));
+        for conflict in &edits.conflicts {
+            println!("   • {}", conflict);
+        }
+        ui::display_info("💡 Run 'rask sync --from-roadmap' to take the file's version instead");
+    }
+    Ok(())
+}
+
 /// Sync changes from task details file to Rask state
 fn sync_from_task_details(_force: bool, dry_run: bool) -> CommandResult {
     use crate::ui;
@@ -1226,250 +1948,59 @@ fn sync_to_local_files(force: bool, dry_run: bool) -> CommandResult {
     Ok(())
 }
 
-/// 🚀 Quick task creation with natural language parsing
-pub fn quick_add_task(text: &str) -> CommandResult {
-    let parsed = parse_natural_language_task(text);
-    
-    // Show what was parsed for user feedback
-    ui::display_info("🤖 Parsed task information:");
-    ui::display_info(&format!("📝 Description: {}", parsed.description));
-    ui::display_info(&format!("🏷️  Tags: {}", if parsed.tags.is_empty() { "None".to_string() } else { parsed.tags.join(", ") }));
-    ui::display_info(&format!("⚡ Priority: {}", parsed.priority));
-    ui::display_info(&format!("🚀 Phase: {}", parsed.phase.as_ref().unwrap_or(&"mvp".to_string())));
-    
-    // Convert to add_task_enhanced parameters
-    let tags_str = if parsed.tags.is_empty() { None } else { Some(parsed.tags.join(",")) };
-    let priority = Some(parsed.priority.into());
-    let phase = parsed.phase.clone();
-    
-    // Call the existing add_task_enhanced function
-    add_task_enhanced(
-        &parsed.description,
-        &tags_str,
-        &priority,
-        &phase,
-        &None, // notes
-        &None, // dependencies  
-        &parsed.estimated_hours,
-    )
-}
-
-/// Parse natural language text into task components
-struct ParsedTask {
-    description: String,
-    tags: Vec<String>,
-    priority: Priority,
-    phase: Option<String>,
-    estimated_hours: Option<f64>,
-}
-
-fn parse_natural_language_task(text: &str) -> ParsedTask {
-    let mut description = text.to_string();
-    let mut tags = Vec::new();
-    let mut priority = Priority::Medium;
-    let mut phase = None;
-    let mut estimated_hours = None;
-    
-    // Priority keywords (case insensitive) - more specific patterns
-    let priority_patterns = [
-        ("critical", Priority::Critical),
-        ("urgent", Priority::Critical),
-        ("high priority", Priority::High),
-        ("high", Priority::High), 
-        ("important", Priority::High),
-        ("medium priority", Priority::Medium),
-        ("medium", Priority::Medium),
-        ("normal", Priority::Medium),
-        ("low priority", Priority::Low),
-        ("low", Priority::Low),
-    ];
-    
-    // Phase keywords - more specific patterns
-    let phase_patterns = [
-        ("mvp", "mvp"),
-        ("beta", "beta"),
-        ("release", "release"),
-        ("future", "future"),
-        ("backlog", "backlog"),
-        ("later", "future"),
-        ("someday", "backlog"),
-    ];
-    
-    // Common tag patterns - more specific to avoid false matches
-    let tag_patterns = [
-        ("backend", "backend"),
-        ("frontend", "frontend"),
-        ("front-end", "frontend"),
-        ("api", "api"),
-        ("ui", "ui"),
-        ("ux", "ux"),
-        ("database", "database"),
-        ("db", "database"),
-        ("auth", "auth"),
-        ("authentication", "auth"),
-        ("security", "security"),
-        ("testing", "testing"),
-        ("test", "testing"),
-        ("bug", "bug"),
-        ("fix", "bug"),
-        ("feature", "feature"),
-        ("deploy", "deployment"),
-        ("deployment", "deployment"),
-        ("production", "production"),
-        ("staging", "staging"),
-        ("docs", "documentation"),
-        ("documentation", "documentation"),
-        ("refactor", "refactoring"),
-        ("performance", "performance"),
-        ("optimization", "performance"),
-        ("mobile", "mobile"),
-        ("web", "web"),
-        ("desktop", "desktop"),
-        ("infrastructure", "infrastructure"),
-    ];
-    
-    let text_lower = text.to_lowercase();
-    let words: Vec<&str> = text_lower.split_whitespace().collect();
-    
-    // Track which standalone words/phrases we've extracted to remove them more precisely
-    let mut standalone_words_to_remove: Vec<String> = Vec::new();
-    
-    // Extract priority - check longer phrases first, only if they are standalone
-    for (keyword, prio) in &priority_patterns {
-        // Check if the keyword appears as standalone words
-        if keyword.contains(' ') {
-            // Multi-word phrase - check if it appears exactly
-            if text_lower.contains(keyword) {
-                priority = prio.clone();
-                standalone_words_to_remove.push(keyword.to_string());
-                break;
-            }
-        } else {
-            // Single word - check if it appears as a standalone word
-            if words.iter().any(|&word| word == *keyword) {
-                priority = prio.clone();
-                standalone_words_to_remove.push(keyword.to_string());
-                break;
-            }
-        }
-    }
-    
-    // Extract phase - only if standalone words
-    for (keyword, phase_val) in &phase_patterns {
-        if words.iter().any(|&word| word == *keyword) {
-            phase = Some(phase_val.to_string());
-            standalone_words_to_remove.push(keyword.to_string());
-            break;
-        }
-    }
+/// 🎯 Show tasks ready to start (no blockers)
+pub fn show_ready_tasks(include_snoozed: bool) -> CommandResult {
+    let roadmap = state::load_state_summary()?;
+    let mut ready_tasks = roadmap.get_ready_tasks();
+    utils::hide_snoozed(&mut ready_tasks, include_snoozed);
     
-    // Extract tags - be very careful about context, only exact word matches for short words
-    for (keyword, tag) in &tag_patterns {
-        if keyword.len() <= 3 {
-            // Short words like "ui", "ux", "db" - must be exact standalone words
-            if words.iter().any(|&word| word == *keyword) {
-                tags.push(tag.to_string());
-                standalone_words_to_remove.push(keyword.to_string());
-            }
-        } else {
-            // Longer words - check if they appear as standalone words or as part of compound words
-            if words.iter().any(|&word| word == *keyword || word.contains(keyword)) {
-                tags.push(tag.to_string());
-                // DON'T remove longer words from description to preserve meaning
-                // Only remove if it's clearly a metadata word (like "backend", "frontend")
-                if *keyword == "backend" || *keyword == "frontend" || *keyword == "testing" || 
-                   *keyword == "deployment" || *keyword == "documentation" || *keyword == "infrastructure" {
-                    if words.iter().any(|&word| word == *keyword) {
-                        standalone_words_to_remove.push(keyword.to_string());
-                    }
-                }
-            }
-        }
+    if ready_tasks.is_empty() {
+        ui::display_info("🎯 No ready tasks found");
+        ui::display_info("💡 All tasks either have incomplete dependencies or are already completed");
+    } else {
+        ui::display_info(&format!("🎯 Ready Tasks ({} available to start)", ready_tasks.len()));
+        ui::display_filtered_tasks(&roadmap, &ready_tasks, false);
     }
     
-    // Extract time estimates
-    let time_keywords = [
-        ("hours", 1.0, "h"),
-        ("hour", 1.0, "h"), 
-        ("hrs", 1.0, "hr"),
-        ("hr", 1.0, "hr"),
-        ("days", 8.0, "day"),
-        ("day", 8.0, "day"),
-        ("weeks", 40.0, "week"),
-        ("week", 40.0, "week"),
-    ];
-    
-    // More precise time extraction
-    for i in 0..words.len().saturating_sub(1) {
-        if let Ok(time_val) = words[i].parse::<f64>() {
-            for (keyword, multiplier, _short) in &time_keywords {
-                if words[i + 1].starts_with(keyword) {
-                    estimated_hours = Some(time_val * multiplier);
-                    // Remove the time pattern more precisely
-                    standalone_words_to_remove.push(format!("{} {}", words[i], words[i + 1]));
-                    break;
-                }
-            }
-            if estimated_hours.is_some() {
-                break;
-            }
-        }
-    }
+    Ok(())
+}
+
+/// ⚡ Show ready tasks that fit the energy and context available right now
+pub fn show_now_tasks(energy: Option<CliEnergy>, context: Option<&str>, limit: usize) -> CommandResult {
+    let energy = energy.map(crate::model::Energy::from);
+    let context = context.map(utils::parse_contexts).transpose()?
+        .and_then(|contexts| contexts.into_iter().next());
     
-    // Very careful removal of extracted keywords while preserving description integrity
-    for word_to_remove in &standalone_words_to_remove {
-        // Use word boundary regex to ensure we only remove complete words
-        let regex_pattern = if word_to_remove.contains(' ') {
-            // Multi-word phrase - match exactly with word boundaries
-            format!(r"(?i)\b{}\b", regex::escape(word_to_remove))
-        } else {
-            // Single word - match with word boundaries
-            format!(r"(?i)\b{}\b", regex::escape(word_to_remove))
-        };
-        
-        if let Ok(re) = regex::Regex::new(&regex_pattern) {
-            description = re.replace_all(&description, " ").to_string();
-        }
-    }
+    let roadmap = state::load_state()?;
+    let mut tasks: Vec<&Task> = roadmap.get_ready_tasks().into_iter()
+        .filter(|task| task.fits(energy, context.as_deref()))
+        .collect();
     
-    // Clean up description (remove extra spaces, trim)
-    description = description
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .join(" ")
-        .trim()
-        .to_string();
+    // Tasks made for this context first, then the most important, then the oldest
+    let in_context = |task: &Task| context.as_ref().is_some_and(|c| task.contexts.contains(c));
+    tasks.sort_by(|a, b| {
+        in_context(b).cmp(&in_context(a))
+            .then(b.priority.rank().cmp(&a.priority.rank()))
+            .then(a.id.cmp(&b.id))
+    });
+    let total = tasks.len();
+    tasks.truncate(limit);
     
-    // If description is empty or too short after cleaning, preserve more of the original
-    if description.is_empty() || description.len() < 3 {
-        // Keep the original but still extract metadata
-        description = text.to_string();
+    let mut situation = Vec::new();
+    if let Some(energy) = energy {
+        situation.push(format!("{} energy", energy.to_string().to_lowercase()));
     }
-    
-    // Remove duplicates from tags
-    tags.sort();
-    tags.dedup();
-    
-    ParsedTask {
-        description,
-        tags,
-        priority,
-        phase,
-        estimated_hours,
+    if let Some(ref context) = context {
+        situation.push(context.clone());
     }
-}
-
-/// 🎯 Show tasks ready to start (no blockers)
-pub fn show_ready_tasks() -> CommandResult {
-    let roadmap = state::load_state()?;
-    let ready_tasks = roadmap.get_ready_tasks();
+    let situation = if situation.is_empty() { String::new() } else { format!(" for {}", situation.join(", ")) };
     
-    if ready_tasks.is_empty() {
-        ui::display_info("🎯 No ready tasks found");
-        ui::display_info("💡 All tasks either have incomplete dependencies or are already completed");
+    if tasks.is_empty() {
+        ui::display_info(&format!("⚡ Nothing ready{}", situation));
+        ui::display_info("💡 Try a different --energy or --context, or check 'rask blocked'");
     } else {
-        ui::display_info(&format!("🎯 Ready Tasks ({} available to start)", ready_tasks.len()));
-        ui::display_filtered_tasks(&roadmap, &ready_tasks, false);
+        ui::display_info(&format!("⚡ What you can do now{} ({} of {} matching)", situation, tasks.len(), total));
+        ui::display_filtered_tasks(&roadmap, &tasks, false);
     }
     
     Ok(())
@@ -1477,7 +2008,7 @@ pub fn show_ready_tasks() -> CommandResult {
 
 /// 🔥 Show urgent tasks (high/critical priority)
 pub fn show_urgent_tasks() -> CommandResult {
-    let roadmap = state::load_state()?;
+    let roadmap = state::load_state_summary()?;
     let urgent_tasks: Vec<&Task> = roadmap.tasks.iter()
         .filter(|task| matches!(task.priority, Priority::High | Priority::Critical) && task.status == TaskStatus::Pending)
         .collect();
@@ -1504,17 +2035,73 @@ pub fn show_blocked_tasks() -> CommandResult {
     } else {
         ui::display_info(&format!("🔒 Blocked Tasks ({} waiting on dependencies)", blocked_tasks.len()));
         ui::display_filtered_tasks(&roadmap, &blocked_tasks, true); // Show detailed for dependencies
+        
+        // Explain the ordering where someone recorded why
+        let completed_ids = roadmap.get_completed_task_ids();
+        let reasons: Vec<String> = blocked_tasks.iter()
+            .flat_map(|task| task.dependency_notes.iter()
+                .filter(|(dep_id, _)| !completed_ids.contains(dep_id))
+                .map(move |(dep_id, note)| format!("   #{} waits on #{}: {}", task.id, dep_id, note)))
+            .collect();
+        if !reasons.is_empty() && !ui::is_quiet() {
+            ui::display_info("💬 Why they are blocked:");
+            for reason in reasons {
+                println!("{}", reason);
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+/// 📟 Print a plain one-line summary, reading only the summary index
+pub fn show_statusline() -> CommandResult {
+    // Stay silent outside a workspace so the command is safe to embed in prompts
+    if !state::has_local_workspace() {
+        return Ok(());
+    }
+    
+    let roadmap = state::load_state_summary()?;
+    let total = roadmap.tasks.len();
+    let completed = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+    let urgent = roadmap.tasks.iter()
+        .filter(|t| t.status == TaskStatus::Pending && matches!(t.priority, Priority::High | Priority::Critical))
+        .count();
+    let today = chrono::Local::now().date_naive();
+    let overdue = roadmap.tasks.iter()
+        .filter(|t| t.status == TaskStatus::Pending && t.due_date_parsed().is_some_and(|due| due < today))
+        .count();
+    
+    let ready_tasks = roadmap.get_ready_tasks();
+    let next = ready_tasks.iter()
+        .max_by(|a, b| a.priority.rank().cmp(&b.priority.rank()).then(b.id.cmp(&a.id)));
+    
+    let mut parts = vec![format!("{} {}/{}", roadmap.title, completed, total)];
+    if urgent > 0 {
+        parts.push(format!("{} urgent", urgent));
+    }
+    if overdue > 0 {
+        parts.push(format!("{} overdue", overdue));
+    }
+    parts.push(format!("{} ready", ready_tasks.len()));
+    if let Some(task) = next {
+        parts.push(format!("next #{} {}", task.id, task.description));
     }
     
+    println!("{}", parts.join(" | "));
     Ok(())
 }
 
 /// 🔍 Enhanced search tasks by description, notes, and tags
 pub fn find_tasks(query: &str) -> CommandResult {
-    let roadmap = state::load_state()?;
+    let roadmap = state::load_state_summary()?;
     
-    // Use the model's search_tasks method which includes tags, descriptions, and notes
-    let found_tasks = roadmap.search_tasks(query);
+    // Searches descriptions, notes and tags
+    let matching_tasks = state::query_tasks(&state::TaskQuery {
+        text: Some(query.to_string()),
+        ..Default::default()
+    })?;
+    let found_tasks: Vec<&Task> = matching_tasks.iter().collect();
     
     if found_tasks.is_empty() {
         ui::display_info(&format!("🔍 No tasks found matching '{}'", query));
@@ -1574,4 +2161,138 @@ pub fn find_tasks(query: &str) -> CommandResult {
     }
     
     Ok(())
-} 
\ No newline at end of file
+} 
+
+/// 🩹 Show, roll back, or discard the checkpoint of an interrupted operation
+pub fn recover_operation(rollback: bool, discard: bool) -> CommandResult {
+    let Some(checkpoint) = state::load_checkpoint()? else {
+        ui::display_success("No interrupted operation found; nothing to recover");
+        return Ok(());
+    };
+    
+    if rollback {
+        utils::save_and_sync(&checkpoint.snapshot)?;
+        state::finish_operation()?;
+        ui::display_success(&format!("Rolled back the interrupted '{}' run", checkpoint.operation));
+        return Ok(());
+    }
+    if discard {
+        state::finish_operation()?;
+        ui::display_success(&format!("Kept the current state and cleared the '{}' checkpoint", checkpoint.operation));
+        return Ok(());
+    }
+    
+    // Describe what changed since the snapshot
+    let current = state::load_state()?;
+    let before_ids: std::collections::HashSet<usize> = checkpoint.snapshot.tasks.iter().map(|t| t.id).collect();
+    let current_ids: std::collections::HashSet<usize> = current.tasks.iter().map(|t| t.id).collect();
+    let added = current_ids.difference(&before_ids).count();
+    let removed = before_ids.difference(&current_ids).count();
+    let status_changed = current.tasks.iter()
+        .filter(|t| checkpoint.snapshot.find_task_by_id(t.id).is_some_and(|old| old.status != t.status))
+        .count();
+    
+    ui::display_warning(&format!("Interrupted operation: '{}' started {}",
+        checkpoint.operation,
+        checkpoint.started_at.get(..19).unwrap_or(&checkpoint.started_at).replace('T', " ")));
+    println!("   Since the snapshot: {} task(s) added, {} removed, {} changed status", added, removed, status_changed);
+    println!();
+    ui::display_info("💡 'rask recover --rollback' restores the snapshot; 'rask recover --discard' keeps the current state");
+    
+    Ok(())
+}
+
+/// List state backups, or replace the current state with one of them
+pub fn restore_backup(backup: Option<&str>, list: bool) -> CommandResult {
+    let backups = state::list_backups()?;
+    
+    let Some(wanted) = backup.filter(|_| !list) else {
+        if utils::json_output() {
+            return utils::print_json(&backups);
+        }
+        if backups.is_empty() {
+            ui::display_info("No backups yet; one is taken automatically before each command that changes the project");
+            return Ok(());
+        }
+        ui::display_info(&format!("⏪ {} backup(s) in .rask/backups (newest first):", backups.len()));
+        for (index, entry) in backups.iter().enumerate() {
+            let tasks = entry.tasks.map_or("unreadable".to_string(), |n| format!("{} tasks", n));
+            println!("  {:>3}. {}  {}  ({})", index + 1, entry.created.format("%Y-%m-%d %H:%M:%S"), entry.name, tasks);
+        }
+        if backup.is_none() && !list {
+            println!();
+            ui::display_info("💡 Restore one with 'rask restore <name or number>'");
+        }
+        return Ok(());
+    };
+    
+    let wanted_name = wanted.trim_end_matches(".json");
+    let entry = wanted.parse::<usize>().ok()
+        .and_then(|n| n.checked_sub(1))
+        .and_then(|index| backups.get(index))
+        .or_else(|| backups.iter().find(|b| b.name == wanted_name))
+        .ok_or_else(|| CommandError::NotFound(format!("Backup '{}' not found; see 'rask restore --list'", wanted)))?;
+    let restored = state::load_backup(entry)
+        .map_err(|e| format!("Backup '{}' is not a valid Rask state: {}", entry.name, e))?;
+    
+    let prompt = format!(
+        "Replace the current state with backup '{}' ({} tasks)? The current state is backed up first.",
+        entry.name,
+        restored.tasks.len()
+    );
+    if !utils::confirm(&prompt)? {
+        ui::display_info("Restore cancelled");
+        return Ok(());
+    }
+    
+    utils::save_and_sync(&restored)?;
+    ui::display_success(&format!("Restored backup '{}' ({} tasks)", entry.name, restored.tasks.len()));
+    Ok(())
+}
+
+/// Report or apply the schema migrations the project state still needs
+pub fn migrate_state(check: bool) -> CommandResult {
+    let (version, steps) = state::check_migrations()?;
+    let current = crate::model::CURRENT_SCHEMA_VERSION;
+    
+    if steps.is_empty() {
+        ui::display_success(&format!("Project state is up to date (schema version {})", version));
+        return Ok(());
+    }
+    
+    ui::display_info(&format!("🧬 Project state is at schema version {}; version {} needs {} migration(s):", version, current, steps.len()));
+    for (index, step) in steps.iter().enumerate() {
+        println!("  {}. v{} → v{}: {}", index + 1, version as usize + index, version as usize + index + 1, step);
+    }
+    if check {
+        return Err("Migration needed; run 'rask migrate' to upgrade the state file".into());
+    }
+    
+    // Loading applies the migrations; saving writes the upgraded file after backing up the old one
+    let roadmap = state::load_state()?;
+    state::save_state(&roadmap)?;
+    ui::display_success(&format!("Migrated the project state to schema version {}", current));
+    Ok(())
+}
+
+/// Show the project's storage backend, or convert the state to another one
+pub fn set_storage_backend(backend: Option<&str>) -> CommandResult {
+    let current = state::storage_backend()?;
+    let Some(target) = backend.and_then(state::StorageBackend::from_name) else {
+        ui::display_info(&format!("🗄️  Project state is stored as {}", match current {
+            state::StorageBackend::Json => ".rask/state.json (json)",
+            state::StorageBackend::Sqlite => ".rask/state.db (sqlite)",
+        }));
+        return Ok(());
+    };
+    
+    if target == current {
+        ui::display_info(&format!("Project state already uses the {} backend", current.name()));
+        return Ok(());
+    }
+    
+    let tasks = state::load_state()?.tasks.len();
+    state::convert_storage(target)?;
+    ui::display_success(&format!("Moved {} tasks from the {} backend to {}", tasks, current.name(), target.name()));
+    Ok(())
+}
@@ -0,0 +1,451 @@
+//! Standalone analytics dashboard
+//!
+//! `rask analytics --export report.html` writes one self-contained HTML file:
+//! the analytics are embedded as JSON and drawn as SVG by a small inline
+//! script, so the report opens offline and can be mailed around. It holds a
+//! burndown of open tasks, a per-phase breakdown, estimated against actual
+//! hours per task, and a heatmap of completions per tag and week. The task
+//! table export (`rask export html`) is a separate report. Tags and phases
+//! keep the colors and emoji set in `theme.tag_colors`/`theme.phase_colors`.
+
+use crate::model::{Roadmap, TaskStatus};
+use super::analytics::ProgressAnalytics;
+use super::utils;
+use crate::ui::theme::{theme, LabelStyle};
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Weeks shown in the tag heatmap
+const HEATMAP_WEEKS: i64 = 12;
+
+/// Tags shown in the heatmap, most used first
+const HEATMAP_TAGS: usize = 15;
+
+/// Burndown points beyond which the series is sampled weekly
+const DAILY_BURNDOWN_LIMIT: i64 = 180;
+
+#[derive(Serialize)]
+struct BurndownPoint {
+    date: NaiveDate,
+    open: usize,
+    completed: usize,
+}
+
+#[derive(Serialize)]
+struct EstimatePoint {
+    id: usize,
+    description: String,
+    phase: String,
+    estimated: f64,
+    actual: f64,
+}
+
+/// Configured look of a tag or phase label
+#[derive(Serialize)]
+struct Label {
+    color: Option<String>,
+    emoji: Option<String>,
+}
+
+impl Label {
+    fn from_style(style: &LabelStyle) -> Label {
+        Label { color: style.css(), emoji: style.emoji.clone() }
+    }
+}
+
+#[derive(Serialize)]
+struct TagRow {
+    tag: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    label: Option<Label>,
+    pending: usize,
+    /// Tasks completed in each heatmap week, oldest first
+    completed: Vec<usize>,
+}
+
+#[derive(Serialize)]
+struct DashboardData<'a> {
+    title: &'a str,
+    generated_at: String,
+    analytics: &'a ProgressAnalytics,
+    burndown: Vec<BurndownPoint>,
+    estimates: Vec<EstimatePoint>,
+    /// Monday of each heatmap week
+    weeks: Vec<NaiveDate>,
+    tags: Vec<TagRow>,
+    /// Styled phases by name
+    phase_labels: HashMap<String, Label>,
+}
+
+/// Open and completed task counts at the end of each day since the first task
+fn burndown(roadmap: &Roadmap, today: NaiveDate) -> Vec<BurndownPoint> {
+    let local = |at: chrono::DateTime<chrono::Utc>| at.with_timezone(&Local).date_naive();
+    let created: Vec<Option<NaiveDate>> = roadmap.tasks.iter().map(|task| task.created_datetime().map(local)).collect();
+    let completed: Vec<Option<NaiveDate>> = roadmap.tasks.iter().map(|task| task.completed_datetime().map(local)).collect();
+    let Some(start) = created.iter().chain(&completed).flatten().min().copied() else {
+        return Vec::new();
+    };
+    let step = if (today - start).num_days() > DAILY_BURNDOWN_LIMIT { 7 } else { 1 };
+
+    let mut points = Vec::new();
+    let mut day = start;
+    loop {
+        // Tasks without a creation date count from the start
+        let existing = created.iter().filter(|at| at.is_none_or(|at| at <= day)).count();
+        let done = roadmap.tasks.iter().zip(&completed)
+            .filter(|(task, at)| task.status == TaskStatus::Completed && at.is_none_or(|at| at <= day))
+            .count();
+        points.push(BurndownPoint { date: day, open: existing.saturating_sub(done), completed: done });
+        if day >= today {
+            break;
+        }
+        day = (day + Duration::days(step)).min(today);
+    }
+    points
+}
+
+/// Estimated and tracked hours of every task that has both
+fn estimate_points(roadmap: &Roadmap) -> Vec<EstimatePoint> {
+    roadmap.tasks.iter()
+        .filter_map(|task| {
+            let estimated = task.estimated_hours?;
+            let actual = task.actual_hours.filter(|hours| *hours > 0.0)?;
+            Some(EstimatePoint {
+                id: task.id,
+                description: task.description.clone(),
+                phase: task.phase.name.clone(),
+                estimated,
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Completions per tag in each of the last weeks, for the most used tags
+fn tag_heatmap(roadmap: &Roadmap, today: NaiveDate) -> (Vec<NaiveDate>, Vec<TagRow>) {
+    let this_week = today - Duration::days(i64::from(today.weekday().num_days_from_monday()));
+    let weeks: Vec<NaiveDate> = (0..HEATMAP_WEEKS).rev().map(|back| this_week - Duration::weeks(back)).collect();
+
+    let mut rows: HashMap<&str, TagRow> = HashMap::new();
+    for task in &roadmap.tasks {
+        let week = task.completed_datetime()
+            .map(|at| at.with_timezone(&Local).date_naive())
+            .filter(|_| task.status == TaskStatus::Completed)
+            .and_then(|day| weeks.iter().rposition(|start| *start <= day));
+        for tag in &task.tags {
+            let row = rows.entry(tag.as_str()).or_insert_with(|| TagRow {
+                tag: tag.clone(),
+                label: theme().tag_style(tag).map(Label::from_style),
+                pending: 0,
+                completed: vec![0; weeks.len()],
+            });
+            if task.status == TaskStatus::Pending {
+                row.pending += 1;
+            }
+            if let Some(week) = week {
+                row.completed[week] += 1;
+            }
+        }
+    }
+
+    let mut rows: Vec<TagRow> = rows.into_values().collect();
+    rows.sort_by(|a, b| {
+        let total = |row: &TagRow| row.pending + row.completed.iter().sum::<usize>();
+        total(b).cmp(&total(a)).then_with(|| a.tag.cmp(&b.tag))
+    });
+    rows.truncate(HEATMAP_TAGS);
+    (weeks, rows)
+}
+
+/// The dashboard as a complete HTML document
+pub fn render_dashboard(roadmap: &Roadmap, analytics: &ProgressAnalytics) -> Result<String, Box<dyn std::error::Error>> {
+    let today = Local::now().date_naive();
+    let (weeks, tags) = tag_heatmap(roadmap, today);
+    let data = DashboardData {
+        title: &roadmap.title,
+        generated_at: Local::now().format("%Y-%m-%d %H:%M").to_string(),
+        analytics,
+        burndown: burndown(roadmap, today),
+        estimates: estimate_points(roadmap),
+        weeks,
+        tags,
+        phase_labels: analytics.phase_analytics.iter()
+            .filter_map(|phase| Some((phase.phase.name.clone(), Label::from_style(theme().phase_style(&phase.phase.name)?))))
+            .collect(),
+    };
+    // A "</script>" inside a task description must not end the data block
+    let json = serde_json::to_string(&data)?.replace("</", "<\\/");
+    let title = utils::html_escape(&format!("{} - Analytics", roadmap.title));
+
+    Ok(format!(r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>{title}</title>
+    <style>{DASHBOARD_CSS}</style>
+</head>
+<body>
+    <div class="container">
+        <h1 id="title"></h1>
+        <p class="generated" id="generated"></p>
+        <div class="stats" id="stats"></div>
+        <div class="grid">
+            <section class="card wide"><h2>📉 Burndown</h2><div class="legend" id="burndown-legend"></div><div id="burndown"></div></section>
+            <section class="card"><h2>🧭 Phases</h2><div class="toggle" id="phase-toggle"></div><div id="phases"></div></section>
+            <section class="card"><h2>🎯 Estimation Accuracy</h2><p class="hint" id="accuracy"></p><div id="estimates"></div></section>
+            <section class="card wide"><h2>🏷️ Tag Activity</h2><p class="hint">Tasks completed per week over the last {HEATMAP_WEEKS} weeks</p><div id="heatmap"></div></section>
+        </div>
+    </div>
+    <div class="tooltip" id="tooltip"></div>
+    <script type="application/json" id="data">{json}</script>
+    <script>{DASHBOARD_JS}</script>
+</body>
+</html>
+"#))
+}
+
+const DASHBOARD_CSS: &str = r#"
+        body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; margin: 20px; background: #f8f9fa; color: #2c3e50; }
+        .container { max-width: 1400px; margin: 0 auto; }
+        h1 { border-bottom: 3px solid #3498db; padding-bottom: 10px; margin-bottom: 4px; }
+        h2 { font-size: 1.1em; margin: 0 0 12px; }
+        .generated, .hint { color: #7f8c8d; font-size: 0.9em; margin: 0 0 12px; }
+        .stats { display: grid; grid-template-columns: repeat(auto-fit, minmax(160px, 1fr)); gap: 16px; margin: 24px 0; }
+        .stat-card { background: white; padding: 18px; border-radius: 8px; text-align: center; border-left: 4px solid #3498db; box-shadow: 0 2px 4px rgba(0,0,0,0.06); }
+        .stat-number { font-size: 1.8em; font-weight: bold; }
+        .stat-label { color: #7f8c8d; margin-top: 4px; font-size: 0.85em; }
+        .grid { display: grid; grid-template-columns: repeat(2, minmax(0, 1fr)); gap: 20px; }
+        .card { background: white; padding: 20px; border-radius: 12px; box-shadow: 0 4px 6px rgba(0,0,0,0.08); }
+        .card.wide { grid-column: 1 / -1; }
+        svg { width: 100%; height: auto; display: block; }
+        .axis { stroke: #bdc3c7; stroke-width: 1; }
+        .axis-label { fill: #7f8c8d; font-size: 11px; }
+        .legend, .toggle { display: flex; gap: 8px; margin-bottom: 8px; }
+        .legend button, .toggle button { border: 1px solid #d0d7de; background: white; border-radius: 14px; padding: 3px 12px; cursor: pointer; font-size: 0.85em; }
+        .legend button.off { opacity: 0.4; text-decoration: line-through; }
+        .toggle button.on { background: #34495e; color: white; border-color: #34495e; }
+        .swatch { display: inline-block; width: 10px; height: 10px; border-radius: 2px; margin-right: 6px; }
+        table.heatmap { border-collapse: collapse; font-size: 0.85em; }
+        table.heatmap th { font-weight: 600; color: #7f8c8d; padding: 4px 6px; white-space: nowrap; }
+        table.heatmap th.tag { text-align: right; color: #2c3e50; }
+        table.heatmap td { width: 34px; height: 26px; text-align: center; border: 2px solid white; border-radius: 4px; }
+        .tooltip { position: fixed; pointer-events: none; background: rgba(44,62,80,0.95); color: white; padding: 6px 10px; border-radius: 6px; font-size: 0.85em; display: none; max-width: 320px; }
+        .empty { color: #95a5a6; font-style: italic; }
+        @media (max-width: 900px) { .grid { grid-template-columns: 1fr; } }
+"#;
+
+const DASHBOARD_JS: &str = r#"
+const data = JSON.parse(document.getElementById('data').textContent);
+const a = data.analytics;
+const tooltip = document.getElementById('tooltip');
+const NS = 'http://www.w3.org/2000/svg';
+
+function el(tag, attrs, parent) {
+    const node = document.createElementNS(NS, tag);
+    for (const [key, value] of Object.entries(attrs || {})) node.setAttribute(key, value);
+    if (parent) parent.appendChild(node);
+    return node;
+}
+function tip(node, text) {
+    node.addEventListener('mousemove', e => {
+        tooltip.textContent = text;
+        tooltip.style.display = 'block';
+        tooltip.style.left = (e.clientX + 12) + 'px';
+        tooltip.style.top = (e.clientY + 12) + 'px';
+    });
+    node.addEventListener('mouseleave', () => { tooltip.style.display = 'none'; });
+}
+function svg(container, width, height) {
+    const root = el('svg', { viewBox: `0 0 ${width} ${height}` });
+    container.appendChild(root);
+    return root;
+}
+function empty(container, text) {
+    container.innerHTML = `<p class="empty">${text}</p>`;
+}
+function niceMax(value) {
+    if (value <= 0) return 1;
+    const magnitude = Math.pow(10, Math.floor(Math.log10(value)));
+    return Math.ceil(value / magnitude) * magnitude;
+}
+function yAxis(root, pad, width, height, max, format) {
+    for (let i = 0; i <= 4; i++) {
+        const y = height - pad.bottom - (height - pad.top - pad.bottom) * i / 4;
+        el('line', { x1: pad.left, x2: width - pad.right, y1: y, y2: y, class: 'axis', 'stroke-dasharray': i ? '3,3' : '' }, root);
+        el('text', { x: pad.left - 6, y: y + 4, 'text-anchor': 'end', class: 'axis-label' }, root).textContent = format(max * i / 4);
+    }
+}
+
+document.getElementById('title').textContent = `📊 ${data.title}`;
+document.getElementById('generated').textContent = `Analytics generated ${data.generated_at}`;
+
+const stats = [
+    [a.total_tasks, 'Total tasks'],
+    [a.completed_tasks, 'Completed'],
+    [`${a.completion_rate.toFixed(1)}%`, 'Completion'],
+    [a.velocity_tasks_per_day.toFixed(2), 'Tasks / day'],
+    [a.velocity_hours_per_day.toFixed(2), 'Hours / day'],
+    [`${a.estimation_accuracy.toFixed(1)}%`, 'Estimation accuracy'],
+];
+document.getElementById('stats').innerHTML = stats
+    .map(([value, label]) => `<div class="stat-card"><div class="stat-number">${value}</div><div class="stat-label">${label}</div></div>`)
+    .join('');
+
+// Burndown: open and completed tasks over time, series toggled from the legend
+(function () {
+    const container = document.getElementById('burndown');
+    const points = data.burndown;
+    if (points.length < 2) return empty(container, 'Not enough dated tasks for a burndown yet.');
+    const series = [
+        { key: 'open', label: 'Open', color: '#e67e22', on: true },
+        { key: 'completed', label: 'Completed', color: '#27ae60', on: true },
+    ];
+    const legend = document.getElementById('burndown-legend');
+    function draw() {
+        container.innerHTML = '';
+        const width = 900, height = 280, pad = { top: 10, right: 20, bottom: 30, left: 40 };
+        const root = svg(container, width, height);
+        const max = niceMax(Math.max(...series.filter(s => s.on).flatMap(s => points.map(p => p[s.key])), 1));
+        yAxis(root, pad, width, height, max, v => Math.round(v));
+        const x = i => pad.left + (width - pad.left - pad.right) * i / (points.length - 1);
+        const y = v => height - pad.bottom - (height - pad.top - pad.bottom) * v / max;
+        const labels = Math.min(points.length, 8);
+        for (let i = 0; i < labels; i++) {
+            const index = Math.round(i * (points.length - 1) / (labels - 1));
+            el('text', { x: x(index), y: height - 10, 'text-anchor': 'middle', class: 'axis-label' }, root).textContent = points[index].date.slice(5);
+        }
+        for (const s of series.filter(s => s.on)) {
+            el('polyline', { points: points.map((p, i) => `${x(i)},${y(p[s.key])}`).join(' '), fill: 'none', stroke: s.color, 'stroke-width': 2.5 }, root);
+        }
+        const step = (width - pad.left - pad.right) / (points.length - 1);
+        points.forEach((p, i) => {
+            const hit = el('rect', { x: x(i) - step / 2, y: pad.top, width: Math.max(step, 1), height: height - pad.top - pad.bottom, fill: 'transparent' }, root);
+            tip(hit, `${p.date}: ${p.open} open, ${p.completed} completed`);
+        });
+    }
+    for (const s of series) {
+        const button = document.createElement('button');
+        button.innerHTML = `<span class="swatch" style="background:${s.color}"></span>${s.label}`;
+        button.onclick = () => { s.on = !s.on; button.classList.toggle('off', !s.on); draw(); };
+        legend.appendChild(button);
+    }
+    draw();
+})();
+
+// Phases: stacked task counts, or estimated against actual hours
+(function () {
+    const container = document.getElementById('phases');
+    const phases = a.phase_analytics;
+    if (!phases.length) return empty(container, 'No phases yet.');
+    const modes = { tasks: 'Tasks', hours: 'Hours' };
+    let mode = 'tasks';
+    const toggle = document.getElementById('phase-toggle');
+    function draw() {
+        container.innerHTML = '';
+        const row = 34, pad = { top: 4, left: 130, right: 60 }, width = 600;
+        const height = pad.top + phases.length * row;
+        const root = svg(container, width, height);
+        const bars = mode === 'tasks'
+            ? phases.map(p => [[p.completed_tasks, '#27ae60', 'completed'], [p.total_tasks - p.completed_tasks, '#e67e22', 'pending']])
+            : phases.map(p => [[p.estimated_hours, '#3498db', 'h estimated'], [p.actual_hours, '#8e44ad', 'h tracked']]);
+        const max = niceMax(Math.max(...bars.map(parts => mode === 'tasks' ? parts[0][0] + parts[1][0] : Math.max(parts[0][0], parts[1][0])), 1));
+        const scale = v => (width - pad.left - pad.right) * v / max;
+        phases.forEach((p, i) => {
+            const top = pad.top + i * row;
+            const label = data.phase_labels[p.phase.name] || {};
+            const name = el('text', { x: pad.left - 8, y: top + row / 2 + 4, 'text-anchor': 'end', class: 'axis-label' }, root);
+            name.textContent = label.emoji ? `${label.emoji} ${p.phase.name}` : p.phase.name;
+            if (label.color) name.setAttribute('fill', label.color);
+            let offset = 0;
+            bars[i].forEach(([value, color, label], part) => {
+                const barHeight = mode === 'tasks' ? row - 10 : (row - 10) / 2;
+                const rect = el('rect', {
+                    x: pad.left + (mode === 'tasks' ? scale(offset) : 0),
+                    y: top + 4 + (mode === 'tasks' ? 0 : part * barHeight),
+                    width: Math.max(scale(value), value > 0 ? 2 : 0), height: barHeight - 1, fill: color, rx: 3,
+                }, root);
+                tip(rect, `${p.phase.name}: ${mode === 'tasks' ? value : value.toFixed(1)} ${label}`);
+                offset += value;
+            });
+            const text = mode === 'tasks' ? `${p.completion_rate.toFixed(0)}%` : `${p.variance_hours >= 0 ? '+' : ''}${p.variance_hours.toFixed(1)}h`;
+            el('text', { x: width - pad.right + 6, y: top + row / 2 + 4, class: 'axis-label' }, root).textContent = text;
+        });
+    }
+    for (const [key, label] of Object.entries(modes)) {
+        const button = document.createElement('button');
+        button.textContent = label;
+        button.classList.toggle('on', key === mode);
+        button.onclick = () => {
+            mode = key;
+            toggle.querySelectorAll('button').forEach(b => b.classList.toggle('on', b === button));
+            draw();
+        };
+        toggle.appendChild(button);
+    }
+    draw();
+})();
+
+// Estimation accuracy: one dot per task, the diagonal is a perfect estimate
+(function () {
+    const container = document.getElementById('estimates');
+    const points = data.estimates;
+    const t = a.time_analytics;
+    document.getElementById('accuracy').textContent =
+        `${t.accurate_estimates} accurate, ${t.under_estimated_tasks} under-estimated, ${t.over_estimated_tasks} over-estimated · variance ${t.variance_percentage >= 0 ? '+' : ''}${t.variance_percentage.toFixed(1)}%`;
+    if (!points.length) return empty(container, 'No tasks with both an estimate and tracked time yet.');
+    const width = 600, height = 320, pad = { top: 10, right: 20, bottom: 36, left: 44 };
+    const root = svg(container, width, height);
+    const max = niceMax(Math.max(...points.flatMap(p => [p.estimated, p.actual])));
+    yAxis(root, pad, width, height, max, v => `${v.toFixed(0)}h`);
+    const x = v => pad.left + (width - pad.left - pad.right) * v / max;
+    const y = v => height - pad.bottom - (height - pad.top - pad.bottom) * v / max;
+    el('line', { x1: x(0), y1: y(0), x2: x(max), y2: y(max), stroke: '#95a5a6', 'stroke-dasharray': '5,4' }, root);
+    el('text', { x: width / 2, y: height - 6, 'text-anchor': 'middle', class: 'axis-label' }, root).textContent = 'estimated hours → (above the line: took longer)';
+    for (const p of points) {
+        const over = p.actual > p.estimated * 1.1, under = p.actual < p.estimated * 0.9;
+        const dot = el('circle', { cx: x(p.estimated), cy: y(p.actual), r: 6, fill: over ? '#e74c3c' : under ? '#3498db' : '#27ae60', 'fill-opacity': 0.75 }, root);
+        tip(dot, `#${p.id} ${p.description} (${p.phase}): estimated ${p.estimated}h, tracked ${p.actual.toFixed(1)}h`);
+    }
+})();
+
+// Tag heatmap: completions per tag and week
+(function () {
+    const container = document.getElementById('heatmap');
+    if (!data.tags.length) return empty(container, 'No tagged tasks yet.');
+    const max = Math.max(1, ...data.tags.flatMap(row => row.completed));
+    const table = document.createElement('table');
+    table.className = 'heatmap';
+    const head = table.insertRow();
+    head.appendChild(document.createElement('th'));
+    for (const week of data.weeks) {
+        const th = document.createElement('th');
+        th.textContent = week.slice(5);
+        head.appendChild(th);
+    }
+    const pendingHead = document.createElement('th');
+    pendingHead.textContent = 'open';
+    head.appendChild(pendingHead);
+    for (const row of data.tags) {
+        const tr = table.insertRow();
+        const th = document.createElement('th');
+        th.className = 'tag';
+        const label = row.label || {};
+        th.textContent = label.emoji ? `${label.emoji} #${row.tag}` : `#${row.tag}`;
+        if (label.color) th.style.color = label.color;
+        tr.appendChild(th);
+        row.completed.forEach((count, i) => {
+            const td = tr.insertCell();
+            td.style.background = count ? `rgba(39, 174, 96, ${0.15 + 0.85 * count / max})` : '#f2f4f5';
+            td.style.color = count / max > 0.5 ? 'white' : '#2c3e50';
+            td.textContent = count || '';
+            tip(td, `#${row.tag}, week of ${data.weeks[i]}: ${count} completed`);
+        });
+        const pending = tr.insertCell();
+        pending.textContent = row.pending;
+        pending.style.color = '#e67e22';
+    }
+    container.appendChild(table);
+})();
+"#;
@@ -0,0 +1,260 @@
+//! Duplicate task detection
+//!
+//! `rask dedupe` compares task descriptions after normalizing them: case,
+//! punctuation, plurals and filler words are ignored, and the score is the
+//! higher of the shared-word and shared-letter-pair overlap, so both
+//! reworded and slightly misspelled duplicates are found. With `--ai`, pairs
+//! whose embeddings from the AI provider are close are reported as well.
+//!
+//! Each pair can be merged into either task (see [`Roadmap::merge_tasks`])
+//! or dismissed. Dismissed pairs are remembered by UUID in
+//! `.rask/dismissed_duplicates.json` and not shown again.
+
+use crate::model::{Roadmap, Task, TaskStatus};
+use crate::{state, ui};
+use super::{utils, CommandResult};
+use colored::*;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+
+/// Embedding similarity from which a pair is reported regardless of wording
+const EMBEDDING_THRESHOLD: f64 = 0.9;
+
+/// Words that say little about what a task is
+const FILLER_WORDS: &[&str] = &["a", "an", "the", "and", "or", "to", "of", "for", "in", "on", "with", "into", "from"];
+
+/// What was chosen for one pair
+enum Answer {
+    /// Keep the task with this UUID and merge the other into it
+    Keep(String),
+    Dismiss,
+    Skip,
+    Quit,
+}
+
+#[derive(Serialize)]
+struct Candidate {
+    first: usize,
+    second: usize,
+    #[serde(skip)]
+    first_uuid: String,
+    #[serde(skip)]
+    second_uuid: String,
+    text_similarity: f64,
+    embedding_similarity: Option<f64>,
+}
+
+impl Candidate {
+    fn score(&self) -> f64 {
+        self.text_similarity.max(self.embedding_similarity.unwrap_or(0.0))
+    }
+
+    fn key(&self) -> (String, String) {
+        pair_key(&self.first_uuid, &self.second_uuid)
+    }
+}
+
+/// The pair's UUIDs in a fixed order
+fn pair_key(a: &str, b: &str) -> (String, String) {
+    if a <= b { (a.to_string(), b.to_string()) } else { (b.to_string(), a.to_string()) }
+}
+
+/// Lowercase words without punctuation, filler words or plural endings
+fn normalize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .map(str::to_lowercase)
+        .filter(|word| !word.is_empty() && !FILLER_WORDS.contains(&word.as_str()))
+        .map(|word| match word.strip_suffix('s') {
+            Some(stem) if stem.chars().count() > 2 && !stem.ends_with('s') => stem.to_string(),
+            _ => word,
+        })
+        .collect()
+}
+
+/// Overlap of two sets, from 0 to 1
+fn jaccard<T: Eq + std::hash::Hash>(a: &HashSet<T>, b: &HashSet<T>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 { 0.0 } else { a.intersection(b).count() as f64 / union as f64 }
+}
+
+/// Dice coefficient of the letter pairs, which tolerates typos
+fn letter_pair_similarity(a: &str, b: &str) -> f64 {
+    let pairs = |text: &str| -> HashSet<(char, char)> {
+        let chars: Vec<char> = text.chars().collect();
+        chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+    };
+    let (a, b) = (pairs(a), pairs(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    2.0 * a.intersection(&b).count() as f64 / (a.len() + b.len()) as f64
+}
+
+fn text_similarity(a: &[String], b: &[String]) -> f64 {
+    let words = |words: &[String]| words.iter().cloned().collect::<HashSet<_>>();
+    jaccard(&words(a), &words(b)).max(letter_pair_similarity(&a.join(" "), &b.join(" ")))
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| f64::from(*x) * f64::from(*y)).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| f64::from(*x).powi(2)).sum::<f64>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 { 0.0 } else { dot / norms }
+}
+
+/// Embeddings of the task descriptions from the configured AI provider
+fn embeddings(tasks: &[&Task]) -> Result<Vec<Vec<f32>>, Box<dyn std::error::Error>> {
+    let config = crate::config::RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+    if !config.ai.is_ready() {
+        return Err("AI is not configured. Please run 'rask ai configure' first, or leave out --ai".into());
+    }
+    let texts: Vec<String> = tasks.iter().map(|task| task.description.clone()).collect();
+    let runtime = tokio::runtime::Runtime::new().map_err(|e| format!("Failed to create async runtime: {}", e))?;
+    runtime.block_on(async {
+        let service = crate::ai::service::AiService::new(config).await
+            .map_err(|e| format!("Failed to initialize AI service: {}", e))?;
+        service.embed(&texts).await
+            .map_err(|e| format!("Failed to get embeddings: {}", e).into())
+    })
+}
+
+/// Pairs at or above the threshold that were not dismissed, most alike first
+fn find_candidates(
+    tasks: &[&Task],
+    vectors: Option<&[Vec<f32>]>,
+    threshold: f64,
+    dismissed: &BTreeSet<(String, String)>,
+) -> Vec<Candidate> {
+    let words: Vec<Vec<String>> = tasks.iter().map(|task| normalize(&task.description)).collect();
+    let mut candidates = Vec::new();
+    for i in 0..tasks.len() {
+        for j in i + 1..tasks.len() {
+            if dismissed.contains(&pair_key(&tasks[i].uuid, &tasks[j].uuid)) {
+                continue;
+            }
+            let text = text_similarity(&words[i], &words[j]);
+            let embedding = vectors.map(|vectors| cosine(&vectors[i], &vectors[j]));
+            if text >= threshold || embedding.is_some_and(|score| score >= EMBEDDING_THRESHOLD) {
+                candidates.push(Candidate {
+                    first: tasks[i].id,
+                    second: tasks[j].id,
+                    first_uuid: tasks[i].uuid.clone(),
+                    second_uuid: tasks[j].uuid.clone(),
+                    text_similarity: text,
+                    embedding_similarity: embedding,
+                });
+            }
+        }
+    }
+    candidates.sort_by(|a, b| b.score().total_cmp(&a.score()).then(a.first.cmp(&b.first)));
+    candidates
+}
+
+fn load_dismissed() -> BTreeSet<(String, String)> {
+    state::get_dismissed_duplicates_file().ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn save_dismissed(dismissed: &BTreeSet<(String, String)>) -> CommandResult {
+    fs::write(state::get_dismissed_duplicates_file()?, serde_json::to_string_pretty(dismissed)?)?;
+    Ok(())
+}
+
+fn ask(first: &Task, second: &Task) -> Result<Answer, Box<dyn std::error::Error>> {
+    loop {
+        let answer = utils::prompt_line(&format!("  Keep {} or {} and merge the other into it, {}ismiss, {}kip or {}uit: ",
+            format!("[1] #{}", first.id).bright_white(), format!("[2] #{}", second.id).bright_white(),
+            "[d]".bright_black(), "[s]".bright_black(), "[q]".bright_black()
+        ))?;
+        // End of input stops rather than skipping every remaining pair
+        if answer.is_empty() {
+            return Ok(Answer::Quit);
+        }
+        match answer.trim().to_lowercase().as_str() {
+            "1" => return Ok(Answer::Keep(first.uuid.clone())),
+            "2" => return Ok(Answer::Keep(second.uuid.clone())),
+            "d" | "dismiss" => return Ok(Answer::Dismiss),
+            "" | "s" | "skip" => return Ok(Answer::Skip),
+            "q" | "quit" => return Ok(Answer::Quit),
+            _ => ui::display_warning("Answer 1, 2, d, s or q"),
+        }
+    }
+}
+
+/// Merge the pair, keeping the task with `keep_uuid`
+fn merge_pair(roadmap: &mut Roadmap, keep_uuid: &str, merge_uuid: &str) -> CommandResult {
+    let id = |roadmap: &Roadmap, uuid: &str| roadmap.tasks.iter().find(|task| task.uuid == uuid).map(|task| task.id);
+    let (Some(keep_id), Some(merge_id)) = (id(roadmap, keep_uuid), id(roadmap, merge_uuid)) else {
+        return Err("One of the tasks no longer exists".into());
+    };
+    let merged = roadmap.merge_tasks(keep_id, merge_id)?;
+    utils::save_and_sync(roadmap)?;
+    let keep_id = id(roadmap, keep_uuid).unwrap_or(keep_id);
+    ui::display_success(&format!("Merged \"{}\" into task #{}", merged.description, keep_id));
+    Ok(())
+}
+
+/// Find near-duplicate tasks and offer to merge or dismiss each pair
+pub fn find_duplicates(threshold: f64, all: bool, ai: bool) -> CommandResult {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err("The threshold must be between 0 and 1".into());
+    }
+    let mut roadmap = state::load_state()?;
+    let tasks: Vec<&Task> = roadmap.tasks.iter()
+        .filter(|task| all || task.status == TaskStatus::Pending)
+        .collect();
+    let vectors = if ai { Some(embeddings(&tasks)?) } else { None };
+    let mut dismissed = load_dismissed();
+    let candidates = find_candidates(&tasks, vectors.as_deref(), threshold, &dismissed);
+
+    if utils::json_output() {
+        return utils::print_json(&candidates);
+    }
+    if candidates.is_empty() {
+        ui::display_success("No likely duplicates found");
+        return Ok(());
+    }
+
+    let icon = |emoji: &str| ui::theme::theme().icon(emoji);
+    println!("\n  {}{} ({} possible pair(s))", icon("👯"), "Duplicate tasks".bold().bright_cyan(), candidates.len());
+    println!("  {}", ui::theme::theme().rule("─", 50).bright_black());
+
+    let (mut merged, mut dismissed_count) = (0, 0);
+    for (position, candidate) in candidates.iter().enumerate() {
+        let find = |uuid: &str| roadmap.tasks.iter().find(|task| task.uuid == uuid);
+        // Tasks merged away earlier in this run are gone
+        let (Some(first), Some(second)) = (find(&candidate.first_uuid), find(&candidate.second_uuid)) else {
+            continue;
+        };
+        let embedding = candidate.embedding_similarity
+            .map(|score| format!(", embeddings {:.0}%", score * 100.0))
+            .unwrap_or_default();
+        println!("\n  {} {}", format!("[{}/{}]", position + 1, candidates.len()).bright_black(),
+            format!("text {:.0}%{}", candidate.text_similarity * 100.0, embedding).bright_yellow());
+        ui::display_task_lines(&[first, second], true);
+
+        match ask(first, second)? {
+            Answer::Keep(keep_uuid) => {
+                let merge_uuid = if keep_uuid == candidate.first_uuid { &candidate.second_uuid } else { &candidate.first_uuid };
+                match merge_pair(&mut roadmap, &keep_uuid, merge_uuid) {
+                    Ok(()) => merged += 1,
+                    Err(e) => ui::display_warning(&e.to_string()),
+                }
+            }
+            Answer::Dismiss => {
+                dismissed.insert(candidate.key());
+                save_dismissed(&dismissed)?;
+                dismissed_count += 1;
+            }
+            Answer::Skip => {}
+            Answer::Quit => break,
+        }
+    }
+
+    println!();
+    ui::display_info(&format!("Dedupe finished: {} merged, {} dismissed", merged, dismissed_count));
+    Ok(())
+}
@@ -1,10 +1,11 @@
 //! Dependency analysis and visualization commands
 //! 
 //! This module handles all dependency-related operations including
-//! tree visualization, validation, and finding ready/blocked tasks.
+//! tree visualization, validation, finding ready/blocked tasks, and
+//! suggesting an execution order.
 
-use crate::{model::{Roadmap, TaskStatus}, state, ui};
-use super::CommandResult;
+use crate::{model::{Roadmap, Task, TaskStatus}, state, ui};
+use super::{CommandError, CommandResult, utils};
 
 /// Find tasks that become unblocked after completing a specific task
 pub fn find_newly_unblocked_tasks(roadmap: &Roadmap, completed_task_id: usize) -> Vec<usize> {
@@ -31,11 +32,29 @@ pub fn analyze_dependencies(
     validate: bool,
     show_ready: bool,
     show_blocked: bool,
+    order: bool,
+    apply: bool,
 ) -> CommandResult {
-    let roadmap = state::load_state()?;
+    let mut roadmap = state::load_state()?;
+    let json = utils::json_output();
+    let mut output = serde_json::Map::new();
     
     // If no specific options provided, show a summary
-    if tree_task_id.is_none() && !validate && !show_ready && !show_blocked {
+    if tree_task_id.is_none() && !validate && !show_ready && !show_blocked && !order {
+        if json {
+            let tasks: Vec<_> = roadmap.tasks.iter().map(|t| serde_json::json!({
+                "id": t.id,
+                "dependencies": t.dependencies,
+                "dependents": roadmap.get_dependents(t.id),
+            })).collect();
+            return utils::print_json(&serde_json::json!({
+                "total_tasks": roadmap.tasks.len(),
+                "ready": task_ids(&roadmap.get_ready_tasks()),
+                "blocked": task_ids(&roadmap.get_blocked_tasks()),
+                "valid": roadmap.validate_all_dependencies().is_ok(),
+                "tasks": tasks,
+            }));
+        }
         ui::display_dependency_overview(&roadmap);
         return Ok(());
     }
@@ -43,9 +62,18 @@ pub fn analyze_dependencies(
     // Validate dependencies if requested
     if validate {
         match roadmap.validate_all_dependencies() {
+            Ok(()) if json => {
+                output.insert("valid".into(), true.into());
+            }
             Ok(()) => {
                 ui::display_success("All dependencies are valid!");
             }
+            Err(errors) if json => {
+                output.insert("valid".into(), false.into());
+                output.insert("errors".into(), errors.iter().map(|e| e.to_string()).collect());
+                utils::print_json(&output)?;
+                return Err("Dependency validation failed".into());
+            }
             Err(errors) => {
                 ui::display_dependency_validation_errors(&errors);
                 return Err("Dependency validation failed".into());
@@ -56,23 +84,137 @@ pub fn analyze_dependencies(
     // Show dependency tree for specific task
     if let Some(task_id) = tree_task_id {
         if let Some(tree) = roadmap.get_dependency_tree(*task_id) {
-            ui::display_dependency_tree(&tree, &roadmap);
+            if json {
+                output.insert("tree".into(), serde_json::to_value(&tree)?);
+            } else {
+                ui::display_dependency_tree(&tree, &roadmap);
+            }
         } else {
-            return Err(format!("Task {} not found", task_id).into());
+            return Err(CommandError::task_not_found(*task_id).into());
         }
     }
     
     // Show ready tasks
     if show_ready {
         let ready_tasks = roadmap.get_ready_tasks();
-        ui::display_ready_tasks(&ready_tasks);
+        if json {
+            output.insert("ready".into(), serde_json::to_value(&ready_tasks)?);
+        } else {
+            ui::display_ready_tasks(&ready_tasks);
+        }
     }
     
     // Show blocked tasks
     if show_blocked {
         let blocked_tasks = roadmap.get_blocked_tasks();
-        ui::display_blocked_tasks(&blocked_tasks, &roadmap);
+        if json {
+            output.insert("blocked".into(), serde_json::to_value(&blocked_tasks)?);
+        } else {
+            ui::display_blocked_tasks(&blocked_tasks, &roadmap);
+        }
     }
     
+    // Suggest (and optionally apply) an execution order
+    if order {
+        let pending_order = roadmap.topological_order().map_err(|cycle| {
+            format!(
+                "Cannot order tasks: dependency cycle involving tasks {}",
+                cycle.iter().map(|id| format!("#{}", id)).collect::<Vec<_>>().join(", ")
+            )
+        })?;
+        
+        if json {
+            output.insert("order".into(), serde_json::json!(pending_order));
+            output.insert("applied".into(), apply.into());
+        } else {
+            let ordered_tasks: Vec<_> = pending_order.iter()
+                .filter_map(|id| roadmap.find_task_by_id(*id))
+                .collect();
+            let completed_count = roadmap.tasks.len() - pending_order.len();
+            ui::display_task_order(&ordered_tasks, completed_count, apply);
+        }
+        
+        if apply {
+            roadmap.apply_task_order(&pending_order);
+            utils::save_and_sync(&roadmap)?;
+            if !json {
+                ui::display_success(if crate::model::stable_ids() {
+                    "Tasks reordered to follow the suggested order; IDs are stable and were kept"
+                } else {
+                    "Tasks renumbered to follow the suggested order"
+                });
+            }
+        }
+    }
+    
+    if json {
+        utils::print_json(&output)?;
+    }
     Ok(())
-} 
\ No newline at end of file
+}
+
+fn task_ids(tasks: &[&Task]) -> Vec<usize> {
+    tasks.iter().map(|t| t.id).collect()
+}
+
+/// Attach, show, or clear the note explaining why a task depends on another
+pub fn dependency_note(edge: &str, text: Option<&str>, clear: bool) -> CommandResult {
+    let (task_id, dep_id) = parse_dependency_edge(edge)?;
+    let mut roadmap = state::load_state()?;
+    
+    let task = roadmap.find_task_by_id_mut(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    if !task.dependencies.contains(&dep_id) {
+        return Err(format!(
+            "Task #{} does not depend on task #{}. Dependencies: {}",
+            task_id,
+            dep_id,
+            if task.dependencies.is_empty() {
+                "none".to_string()
+            } else {
+                task.dependencies.iter().map(|id| format!("#{}", id)).collect::<Vec<_>>().join(", ")
+            }
+        ).into());
+    }
+    
+    if clear {
+        if task.dependency_notes.remove(&dep_id).is_none() {
+            ui::display_info(&format!("No note on #{} → #{}", task_id, dep_id));
+            return Ok(());
+        }
+        utils::save_and_sync(&roadmap)?;
+        ui::display_success(&format!("Removed the note from #{} → #{}", task_id, dep_id));
+        return Ok(());
+    }
+    
+    match text.map(str::trim) {
+        Some("") => Err("Note cannot be empty. Use --clear to remove it".into()),
+        Some(text) => {
+            task.dependency_notes.insert(dep_id, text.to_string());
+            utils::save_and_sync(&roadmap)?;
+            ui::display_success(&format!("#{} → #{}: {}", task_id, dep_id, text));
+            Ok(())
+        }
+        None => {
+            match task.dependency_notes.get(&dep_id) {
+                Some(note) => println!("#{} → #{}: {}", task_id, dep_id, note),
+                None => ui::display_info(&format!(
+                    "No note on #{} → #{}. Add one with: rask dep note {}→{} \"<why>\"",
+                    task_id, dep_id, task_id, dep_id
+                )),
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Parse a `TASK→DEPENDENCY` edge, also accepting `->`, `>` and `:` as separators
+fn parse_dependency_edge(edge: &str) -> Result<(usize, usize), String> {
+    let invalid = || format!("Invalid dependency edge '{}'. Use TASK→DEPENDENCY, e.g. 12→5 or 12->5", edge);
+    let (task, dep) = ["→", "->", ">", ":"].iter()
+        .find_map(|sep| edge.split_once(sep))
+        .ok_or_else(invalid)?;
+    let task_id = task.trim().trim_start_matches('#').parse().map_err(|_| invalid())?;
+    let dep_id = dep.trim().trim_start_matches('#').parse().map_err(|_| invalid())?;
+    Ok((task_id, dep_id))
+}
@@ -0,0 +1,193 @@
+//! Batch estimation
+//!
+//! `rask estimate` goes through the pending tasks that have no
+//! `estimated_hours`, most important first. Each task is shown with its notes
+//! and the completed tasks most like it, with the hours those actually took,
+//! so the estimate can lean on past work. Every estimate is saved as soon as
+//! it is entered, so quitting halfway keeps the ones already given.
+
+use crate::model::{Roadmap, Task, TaskStatus};
+use crate::ui;
+use super::{utils, CommandResult};
+use colored::*;
+use std::collections::HashSet;
+
+/// Completed tasks shown as reference for each estimate
+const SIMILAR_TASKS: usize = 3;
+
+/// Description words shorter than this are ignored when comparing tasks
+const MIN_WORD_LEN: usize = 4;
+
+/// What was entered for one task
+enum Answer {
+    Hours(f64),
+    Skip,
+    Quit,
+}
+
+/// A completed task like the one being estimated
+struct Similar<'a> {
+    task: &'a Task,
+    actual: f64,
+}
+
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= MIN_WORD_LEN)
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Overlap of two sets, from 0 to 1
+fn jaccard<T: Eq + std::hash::Hash>(a: &HashSet<T>, b: &HashSet<T>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 { 0.0 } else { a.intersection(b).count() as f64 / union as f64 }
+}
+
+/// Hours a completed task took: tracked time, or the actual hours recorded on it
+fn actual_hours(task: &Task) -> Option<f64> {
+    let tracked = task.get_total_tracked_hours();
+    let hours = if tracked > 0.0 { tracked } else { task.actual_hours.unwrap_or(0.0) };
+    (hours > 0.0).then_some(hours)
+}
+
+/// Completed tasks sharing tags, description words or the phase with `task`,
+/// closest first. Only tasks with known hours are useful as reference.
+fn similar_tasks<'a>(roadmap: &'a Roadmap, task: &Task) -> Vec<Similar<'a>> {
+    let task_words = words(&task.description);
+    let mut scored: Vec<(f64, Similar)> = roadmap.tasks.iter()
+        .filter(|other| other.status == TaskStatus::Completed && other.id != task.id)
+        .filter_map(|other| {
+            let actual = actual_hours(other)?;
+            let mut score = jaccard(&task.tags, &other.tags) * 2.0 + jaccard(&task_words, &words(&other.description)) * 3.0;
+            if score > 0.0 && other.phase.is_named(&task.phase.name) {
+                score += 0.5;
+            }
+            (score > 0.0).then_some((score, Similar { task: other, actual }))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.task.id.cmp(&b.1.task.id)));
+    scored.into_iter().take(SIMILAR_TASKS).map(|(_, similar)| similar).collect()
+}
+
+/// Parse an estimate such as `3`, `2.5`, `2.5h`, `90m` or `1h30m` into hours
+fn parse_hours(text: &str) -> Result<f64, String> {
+    let text = text.trim().to_lowercase();
+    let invalid = || format!("Invalid estimate '{}'. Use hours such as 3, 2.5h, 90m or 1h30m", text);
+    let hours = match text.split_once('h') {
+        Some((hours, minutes)) => {
+            let minutes = minutes.trim_end_matches('m');
+            let minutes: f64 = if minutes.is_empty() { 0.0 } else { minutes.parse().map_err(|_| invalid())? };
+            hours.parse::<f64>().map_err(|_| invalid())? + minutes / 60.0
+        }
+        None => match text.strip_suffix('m') {
+            Some(minutes) => minutes.parse::<f64>().map_err(|_| invalid())? / 60.0,
+            None => text.parse().map_err(|_| invalid())?,
+        },
+    };
+    if !hours.is_finite() || hours <= 0.0 {
+        return Err("Estimates must be more than 0 hours".to_string());
+    }
+    Ok(hours)
+}
+
+fn ask_estimate(suggested: Option<f64>) -> Result<Answer, Box<dyn std::error::Error>> {
+    let default = suggested.map(|hours| format!(" [{:.1}]", hours)).unwrap_or_default();
+    loop {
+        let answer = utils::prompt_line(&format!("  Estimate in hours, {}kip or {}uit{}: ",
+            "[s]".bright_black(), "[q]".bright_black(), default
+        ))?;
+        // End of input stops rather than skipping every remaining task
+        if answer.is_empty() {
+            return Ok(Answer::Quit);
+        }
+        match answer.trim().to_lowercase().as_str() {
+            "" => match suggested {
+                Some(hours) => return Ok(Answer::Hours(hours)),
+                None => return Ok(Answer::Skip),
+            },
+            "s" | "skip" => return Ok(Answer::Skip),
+            "q" | "quit" => return Ok(Answer::Quit),
+            text => match parse_hours(text) {
+                Ok(hours) => return Ok(Answer::Hours(hours)),
+                Err(e) => ui::display_warning(&e),
+            },
+        }
+    }
+}
+
+/// Show a task with what helps estimating it. Returns the average of the
+/// similar tasks' hours, offered as the default answer.
+fn show_task(roadmap: &Roadmap, task: &Task) -> Option<f64> {
+    let icon = |emoji: &str| ui::theme::theme().icon(emoji);
+    ui::display_task_lines(&[task], true);
+    for note in task.implementation_notes.iter().rev().take(3) {
+        println!("       {}{}", icon(note.kind.icon()), note.text.lines().next().unwrap_or("").bright_black());
+    }
+
+    let similar = similar_tasks(roadmap, task);
+    if similar.is_empty() {
+        println!("       {}", "No similar completed tasks with tracked time".bright_black());
+        return None;
+    }
+    println!("       {}", "Similar completed tasks:".bold());
+    for Similar { task: other, actual } in &similar {
+        let estimate = other.estimated_hours.map(|hours| format!(", estimated {:.1}h", hours)).unwrap_or_default();
+        println!("       {} {} {}",
+            format!("#{}", other.id).bright_white(),
+            other.description,
+            format!("(took {:.1}h{})", actual, estimate).bright_black()
+        );
+    }
+    Some(similar.iter().map(|similar| similar.actual).sum::<f64>() / similar.len() as f64)
+}
+
+/// Prompt for an estimate on every pending task that lacks one
+pub fn estimate_tasks(phase: Option<&str>, tag: Option<&str>) -> CommandResult {
+    let mut roadmap = crate::state::load_state()?;
+
+    let mut queue: Vec<&Task> = roadmap.tasks.iter()
+        .filter(|task| task.status == TaskStatus::Pending && task.estimated_hours.is_none())
+        .filter(|task| phase.is_none_or(|phase| task.phase.name.eq_ignore_ascii_case(phase)))
+        .filter(|task| tag.is_none_or(|tag| task.has_tag_within(tag)))
+        .collect();
+    queue.sort_by(|a, b| b.priority.rank().cmp(&a.priority.rank()).then(a.id.cmp(&b.id)));
+    let queue: Vec<String> = queue.into_iter().map(|task| task.uuid.clone()).collect();
+    if queue.is_empty() {
+        ui::display_success("Every matching pending task already has an estimate");
+        return Ok(());
+    }
+
+    let icon = |emoji: &str| ui::theme::theme().icon(emoji);
+    println!("\n  {}{} ({} tasks without an estimate)", icon("🃏"), "Estimation".bold().bright_cyan(), queue.len());
+    println!("  {}", ui::theme::theme().rule("─", 50).bright_black());
+
+    let mut estimated = 0;
+    let mut total = 0.0;
+    for (position, uuid) in queue.iter().enumerate() {
+        let Some(task) = roadmap.tasks.iter().find(|task| &task.uuid == uuid) else {
+            continue;
+        };
+        println!("\n  {}", format!("[{}/{}]", position + 1, queue.len()).bright_black());
+        let suggested = show_task(&roadmap, task);
+
+        let hours = match ask_estimate(suggested)? {
+            Answer::Hours(hours) => hours,
+            Answer::Skip => continue,
+            Answer::Quit => break,
+        };
+        let Some(task) = roadmap.tasks.iter_mut().find(|task| &task.uuid == uuid) else {
+            continue;
+        };
+        task.set_estimated_hours(hours);
+        let id = task.id;
+        utils::save_and_sync(&roadmap)?;
+        ui::display_success(&format!("Task #{} estimated at {:.1}h", id, hours));
+        estimated += 1;
+        total += hours;
+    }
+
+    println!();
+    ui::display_info(&format!("Estimation finished: {} of {} tasks estimated, {:.1}h in total", estimated, queue.len(), total));
+    Ok(())
+}
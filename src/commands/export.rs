@@ -1,7 +1,8 @@
 //! Export functionality commands
 //! 
 //! This module handles exporting roadmaps to different formats including
-//! JSON, CSV, and HTML with filtering and formatting options.
+//! JSON, CSV, HTML, Markdown, Mermaid Gantt charts, and iCalendar with
+//! filtering and formatting options.
 //! 
 //! **Phase 3 Enhancement**: Enhanced Export Capabilities 📤
 //! - Added comprehensive time tracking data integration
@@ -11,13 +12,14 @@
 
 use crate::{
     cli::CliPriority,
-    model::{TaskStatus, Priority, Phase, Task, Roadmap},
+    model::{TaskStatus, Priority, Task, Roadmap},
     state,
     ui
 };
 use super::{CommandResult, utils, ExportFormat};
+use crate::ui::theme::theme;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Export roadmap to different formats with enhanced time-based filtering (Phase 3)
 pub fn export_roadmap_enhanced(
@@ -38,9 +40,37 @@ pub fn export_roadmap_enhanced(
     active_sessions_only: bool,
     over_estimated_only: bool,
     under_estimated_only: bool,
+    max_rows: usize,
+    destinations: &[String],
 ) -> CommandResult {
     let roadmap = state::load_state()?;
     
+    // Resolve destinations up front so a typo fails before anything is written
+    let config = if destinations.is_empty() {
+        crate::config::RaskConfig::default()
+    } else {
+        crate::config::RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?
+    };
+    let targets = destinations.iter()
+        .map(|name| {
+            let destination = config.export.destinations.get(name)
+                .ok_or_else(|| format!("No export destination named '{}'. Add [export.destinations.{}] to your config", name, name))?;
+            let target = crate::destinations::from_config(destination)
+                .map_err(|e| format!("Export destination '{}': {}", name, e))?;
+            Ok((name.as_str(), target))
+        })
+        .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    
+    // Uploads need a file, so write to a temporary directory when no --output is given
+    let default_output = if output_path.is_none() && !targets.is_empty() {
+        let dir = std::env::temp_dir().join(format!("rask-export-{}", std::process::id()));
+        fs::create_dir_all(&dir)?;
+        Some(dir.join(format!("{}.{}", slugify(&roadmap.title), format.extension())))
+    } else {
+        None
+    };
+    let output_path = output_path.or(default_output.as_deref());
+    
     // Apply all filters to get the tasks to export
     let mut tasks_to_export: Vec<&Task> = roadmap.tasks.iter().collect();
     
@@ -57,7 +87,7 @@ pub fn export_roadmap_enhanced(
         
         if !filter_tags.is_empty() {
             tasks_to_export.retain(|task| {
-                filter_tags.iter().any(|tag| task.tags.contains(tag))
+                filter_tags.iter().any(|tag| task.has_tag_within(tag))
             });
         }
     }
@@ -68,8 +98,7 @@ pub fn export_roadmap_enhanced(
     }
 
     if let Some(phase_str) = phase_filter {
-        let target_phase = Phase::from_string(phase_str);
-        tasks_to_export.retain(|task| task.phase == target_phase);
+        tasks_to_export.retain(|task| task.phase.is_named(phase_str));
     }
 
     // NEW: Apply time-based filters (Phase 3 enhancement)
@@ -143,11 +172,29 @@ pub fn export_roadmap_enhanced(
     // Sort tasks by ID for consistent output
     tasks_to_export.sort_by_key(|task| task.id);
     
+    // A single HTML page with thousands of rows is too heavy for a browser
+    if matches!(format, ExportFormat::Html) && max_rows > 0 && tasks_to_export.len() > max_rows {
+        match output_path {
+            Some(path) => {
+                let written = export_html_chunked(&roadmap, &tasks_to_export, path, max_rows)?;
+                return publish_export(&targets, &written, default_output.is_some());
+            },
+            None => ui::display_warning(&format!(
+                "{} tasks exceed --max-rows {}; pass --output to split the export into per-phase pages",
+                tasks_to_export.len(), max_rows
+            )),
+        }
+    }
+    
     // Generate export content based on format
     let export_content = match format {
         ExportFormat::Json => export_to_json(&roadmap, &tasks_to_export, pretty)?,
         ExportFormat::Csv => export_to_csv(&roadmap, &tasks_to_export)?,
         ExportFormat::Html => export_to_html(&roadmap, &tasks_to_export)?,
+        ExportFormat::Markdown => export_to_markdown(&roadmap, &tasks_to_export)?,
+        ExportFormat::MermaidGantt => export_to_mermaid_gantt(&roadmap, &tasks_to_export)?,
+        ExportFormat::MermaidBoard => export_to_mermaid_board(&roadmap, &tasks_to_export)?,
+        ExportFormat::Ics => export_to_ics(&roadmap, &tasks_to_export)?,
     };
     
     // Output to file or stdout
@@ -157,6 +204,7 @@ pub fn export_roadmap_enhanced(
             ui::display_success(&format!("✅ Exported {} tasks to {}", 
                 tasks_to_export.len(), 
                 path.display()));
+            publish_export(&targets, &[path.to_path_buf()], default_output.is_some())?;
         },
         None => {
             println!("{}", export_content);
@@ -166,6 +214,26 @@ pub fn export_roadmap_enhanced(
     Ok(())
 }
 
+/// Upload exported files to each `--to` destination, removing them afterwards if they were temporary
+fn publish_export(targets: &[(&str, Box<dyn crate::destinations::Destination>)], files: &[PathBuf], temporary: bool) -> CommandResult {
+    let result = targets.iter().try_for_each(|(name, target)| -> CommandResult {
+        let locations = target.publish(files)
+            .map_err(|e| format!("Publishing to '{}' ({}) failed: {}", name, target.describe(), e))?;
+        ui::display_success(&format!("📤 Published {} file(s) to '{}' ({})", locations.len(), name, target.describe()));
+        for location in locations {
+            println!("   {}", location);
+        }
+        Ok(())
+    });
+
+    if temporary {
+        if let Some(dir) = files.first().and_then(|f| f.parent()) {
+            let _ = fs::remove_dir_all(dir);
+        }
+    }
+    result
+}
+
 
 
 /// Export roadmap to JSON format with comprehensive time tracking data
@@ -252,6 +320,7 @@ fn export_to_json(roadmap: &Roadmap, tasks: &[&Task], pretty: bool) -> Result<St
             
             serde_json::json!({
                 "id": task.id,
+                "uuid": task.uuid,
                 "description": task.description,
                 "status": match task.status {
                     TaskStatus::Pending => "pending",
@@ -270,11 +339,18 @@ fn export_to_json(roadmap: &Roadmap, tasks: &[&Task], pretty: bool) -> Result<St
                     "is_predefined": task.phase.is_predefined()
                 },
                 "tags": task.tags.iter().collect::<Vec<_>>(),
+                "owners": task.owners,
+                "energy": task.energy.map(|e| e.to_string().to_lowercase()),
+                "contexts": task.contexts,
                 "notes": task.notes,
                 "implementation_notes": task.implementation_notes,
                 "dependencies": task.dependencies,
+                "dependency_notes": task.dependency_notes,
                 "created_at": task.created_at,
                 "completed_at": task.completed_at,
+                "due_date": task.due_date,
+                "linked_commits": task.linked_commits,
+                "comments": task.comments,
                 // NEW: Comprehensive time tracking data for each task
                 "time_tracking": {
                     "estimated_hours": task.estimated_hours,
@@ -313,17 +389,21 @@ fn export_to_csv(_roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
     let mut csv_content = String::new();
     
     // Add enhanced header with time tracking columns
-    csv_content.push_str("ID,Description,Status,Priority,Phase,Phase Type,Tags,Notes,Implementation Notes,Dependencies,Created At,Completed At,Estimated Hours,Actual Hours,Variance Hours,Variance %,Total Sessions,Active Session,Is Over Estimated,Is Under Estimated,Session Details\n");
+    csv_content.push_str("ID,UUID,Description,Status,Priority,Phase,Phase Type,Tags,Owners,Notes,Implementation Notes,Dependencies,Created At,Completed At,Estimated Hours,Actual Hours,Variance Hours,Variance %,Total Sessions,Active Session,Is Over Estimated,Is Under Estimated,Session Details\n");
     
     // Add tasks with comprehensive time tracking data
     for task in tasks {
         let tags_str = task.tags.iter().cloned().collect::<Vec<_>>().join(";");
+        let owners_str = task.owners.join(";").replace("\"", "\"\"");
         let deps_str = task.dependencies.iter()
             .map(|id| id.to_string())
             .collect::<Vec<_>>()
             .join(";");
         let notes_escaped = task.notes.as_deref().unwrap_or("").replace("\"", "\"\"");
-        let impl_notes_str = task.implementation_notes.join(" | ");
+        let impl_notes_str = task.implementation_notes.iter()
+            .map(|note| note.to_string())
+            .collect::<Vec<_>>()
+            .join(" | ");
         let impl_notes_escaped = impl_notes_str.replace("\"", "\"\"");
         let desc_escaped = task.description.replace("\"", "\"\"");
         let phase_type = if task.phase.is_predefined() { "predefined" } else { "custom" };
@@ -355,8 +435,9 @@ fn export_to_csv(_roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
         let session_details_escaped = session_details.replace("\"", "\"\"");
         
         csv_content.push_str(&format!(
-            "{},\"{}\",{},{},\"{}\",{},\"{}\",\"{}\",\"{}\",\"{}\",{},{},{},{},{},{},{},{},{},{},\"{}\"\n",
+            "{},{},\"{}\",{},{},\"{}\",{},\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",{},{},{},{},{},{},{},{},{},{},\"{}\"\n",
             task.id,
+            task.uuid,
             desc_escaped,
             match task.status {
                 TaskStatus::Pending => "pending",
@@ -371,6 +452,7 @@ fn export_to_csv(_roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
             task.phase.name,
             phase_type,
             tags_str,
+            owners_str,
             notes_escaped,
             impl_notes_escaped,
             deps_str,
@@ -393,40 +475,29 @@ fn export_to_csv(_roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
 
 /// Export roadmap to HTML format with interactive time tracking visualizations
 fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn std::error::Error>> {
-    let completed_count = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
-    let progress_percentage = (completed_count as f64 / roadmap.tasks.len() as f64 * 100.0).round();
-    
-    // Calculate comprehensive time tracking metrics for HTML display
-    let total_estimated: f64 = tasks.iter().filter_map(|t| t.estimated_hours).sum();
-    let total_actual: f64 = tasks.iter().filter_map(|t| t.actual_hours).sum();
-    let tasks_with_estimates = tasks.iter().filter(|t| t.estimated_hours.is_some()).count();
-    let tasks_with_time = tasks.iter().filter(|t| t.actual_hours.is_some()).count();
-    let total_sessions: usize = tasks.iter().map(|t| t.time_sessions.len()).sum();
-    let active_sessions = tasks.iter().filter(|t| t.has_active_time_session()).count();
-    let over_estimated_count = tasks.iter().filter(|t| t.is_over_estimated()).count();
-    let under_estimated_count = tasks.iter().filter(|t| t.is_under_estimated()).count();
+    let mut html = html_page_start(&format!("{} - Time Tracking Report", roadmap.title));
+    html.push_str(&html_summary(roadmap, tasks));
+    html.push_str("\n        <h2>📋 Task Details</h2>");
+    html.push_str(&html_task_table(tasks));
+    html.push_str(HTML_PAGE_END);
     
-    let overall_variance = if total_estimated > 0.0 && total_actual > 0.0 {
-        total_actual - total_estimated
-    } else {
-        0.0
-    };
-    
-    let estimation_accuracy = if total_estimated > 0.0 { 
-        (100.0 - (overall_variance.abs() / total_estimated * 100.0)).max(0.0).round() 
-    } else { 
-        0.0 
-    };
-    
-    let mut html = String::new();
-    
-    // HTML header with embedded CSS
-    html.push_str(&format!(r#"<!DOCTYPE html>
+    Ok(html)
+}
+
+/// Closing markup shared by every HTML export page
+const HTML_PAGE_END: &str = r#"    </div>
+</body>
+</html>
+"#;
+
+/// Document head with the embedded stylesheet, up to the opening container
+fn html_page_start(title: &str) -> String {
+    format!(r#"<!DOCTYPE html>
 <html lang="en">
 <head>
     <meta charset="UTF-8">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>{} - Time Tracking Report</title>
+    <title>{}</title>
     <style>
         body {{ font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', Roboto, sans-serif; margin: 20px; background: #f8f9fa; }}
         .container {{ max-width: 1400px; margin: 0 auto; background: white; padding: 40px; border-radius: 12px; box-shadow: 0 4px 6px rgba(0,0,0,0.1); }}
@@ -476,6 +547,14 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
         .tag {{ background: #3498db; color: white; padding: 2px 8px; border-radius: 12px; font-size: 0.8em; }}
         .dependencies {{ color: #7f8c8d; font-style: italic; }}
         
+        /* Implementation Notes */
+        .impl-notes {{ margin-top: 6px; font-size: 0.85em; }}
+        .impl-note {{ margin: 3px 0; padding: 3px 8px; border-left: 3px solid #bdc3c7; white-space: pre-wrap; }}
+        .note-decision {{ border-color: #8e44ad; background: #f5eef8; font-weight: 600; }}
+        .note-blocker {{ border-color: #e74c3c; background: #fdedec; }}
+        .note-code {{ border-color: #27ae60; background: #f4f6f6; font-family: monospace; }}
+        .note-link {{ border-color: #3498db; }}
+        
         /* Time Tracking Columns */
         .time-estimate {{ color: #3498db; font-weight: bold; }}
         .time-actual {{ color: #27ae60; font-weight: bold; }}
@@ -497,6 +576,10 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
             100% {{ opacity: 1; }}
         }}
         
+        /* Page Navigation (chunked exports) */
+        .page-nav {{ margin: 20px 0; padding: 10px 15px; background: #e8f4fd; border-radius: 8px; }}
+        .page-nav a {{ color: #2c3e50; font-weight: 600; text-decoration: none; }}
+        
         /* Responsive Design */
         @media (max-width: 768px) {{
             .container {{ padding: 20px; margin: 10px; }}
@@ -507,7 +590,39 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
 </head>
 <body>
     <div class="container">
-        <h1>{}</h1>
+"#, utils::html_escape(title))
+}
+
+/// Title, progress and time tracking overview cards for an HTML export
+fn html_summary(roadmap: &Roadmap, tasks: &[&Task]) -> String {
+    let completed_count = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+    let progress_percentage = (completed_count as f64 / roadmap.tasks.len() as f64 * 100.0).round();
+    
+    // Calculate comprehensive time tracking metrics for HTML display
+    let total_estimated: f64 = tasks.iter().filter_map(|t| t.estimated_hours).sum();
+    let total_actual: f64 = tasks.iter().filter_map(|t| t.actual_hours).sum();
+    let tasks_with_estimates = tasks.iter().filter(|t| t.estimated_hours.is_some()).count();
+    let tasks_with_time = tasks.iter().filter(|t| t.actual_hours.is_some()).count();
+    let total_sessions: usize = tasks.iter().map(|t| t.time_sessions.len()).sum();
+    let active_sessions = tasks.iter().filter(|t| t.has_active_time_session()).count();
+    let over_estimated_count = tasks.iter().filter(|t| t.is_over_estimated()).count();
+    let under_estimated_count = tasks.iter().filter(|t| t.is_under_estimated()).count();
+    
+    let overall_variance = if total_estimated > 0.0 && total_actual > 0.0 {
+        total_actual - total_estimated
+    } else {
+        0.0
+    };
+    
+    let estimation_accuracy = if total_estimated > 0.0 { 
+        (100.0 - (overall_variance.abs() / total_estimated * 100.0)).max(0.0).round() 
+    } else { 
+        0.0 
+    };
+    
+    let mut html = String::new();
+    
+    html.push_str(&format!(r#"        <h1>{}</h1>
         
         <div class="export-info">
             <strong>📊 Export Information:</strong><br>
@@ -580,8 +695,7 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
                 <div class="stat-label">🔴 Active Now</div>
             </div>
         </div>
-"#, 
-        roadmap.title,
+"#,
         roadmap.title,
         chrono::Utc::now().format("%Y-%m-%d %H:%M UTC"),
         roadmap.tasks.len(),
@@ -606,9 +720,34 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
         active_sessions
     ));
     
+    html
+}
+
+/// Task table with time tracking columns
+/// A tag badge, in the color and with the emoji set in `theme.tag_colors`
+fn tag_html(tag: &str) -> String {
+    let style = theme().tag_style(tag);
+    let background = style.and_then(|style| style.css())
+        .map(|color| format!(" style=\"background: {}\"", color))
+        .unwrap_or_default();
+    let emoji = style.and_then(|style| style.emoji.as_deref()).map(|emoji| format!("{} ", emoji)).unwrap_or_default();
+    format!("<span class=\"tag\"{}>{}{}</span>", background, emoji, utils::html_escape(tag))
+}
+
+/// A phase name, in the color set in `theme.phase_colors`
+fn phase_name_html(phase: &str) -> String {
+    match theme().phase_style(phase).and_then(|style| style.css()) {
+        Some(color) => format!("<span style=\"color: {}\">{}</span>", color, utils::html_escape(phase)),
+        None => utils::html_escape(phase),
+    }
+}
+
+fn html_task_table(tasks: &[&Task]) -> String {
+    let mut html = String::new();
+    let notes_dir = crate::wiki_links::notes_dir();
+    
     // Enhanced Tasks table with time tracking columns
     html.push_str(r#"
-        <h2>📋 Task Details</h2>
         <table>
             <thead>
                 <tr>
@@ -622,6 +761,7 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
                     <th>📊 Variance</th>
                     <th>🔄 Sessions</th>
                     <th>Tags</th>
+                    <th>Owners</th>
                     <th>Dependencies</th>
                     <th>Created</th>
                 </tr>
@@ -647,7 +787,7 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
         } else {
             format!("<div class=\"tags\">{}</div>", 
                 task.tags.iter()
-                    .map(|tag| format!("<span class=\"tag\">{}</span>", tag))
+                    .map(|tag| tag_html(tag))
                     .collect::<Vec<_>>()
                     .join(""))
         };
@@ -662,6 +802,24 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
                     .join(", "))
         };
         
+        let task_notes_html = task.notes.as_deref()
+            .filter(|notes| !notes.trim().is_empty())
+            .map(|notes| format!("<div class=\"impl-note\">📄 {}</div>", note_html(notes, notes_dir.as_deref())))
+            .unwrap_or_default();
+        let notes_html = if task.implementation_notes.is_empty() && task_notes_html.is_empty() {
+            String::new()
+        } else {
+            format!("<div class=\"impl-notes\">{}{}</div>",
+                task_notes_html,
+                task.implementation_notes.iter()
+                    .map(|note| format!("<div class=\"impl-note note-{}\">{} {}</div>",
+                        note.kind.to_string().to_lowercase(),
+                        note.kind.icon(),
+                        note_html(&note.text, notes_dir.as_deref())))
+                    .collect::<Vec<_>>()
+                    .join(""))
+        };
+        
         // Generate time tracking data for the row
         let estimated_display = task.estimated_hours
             .map_or("--".to_string(), |h| format!("{:.1}h", h));
@@ -696,7 +854,7 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
         html.push_str(&format!(r#"
                 <tr>
                     <td>#{}</td>
-                    <td>{}</td>
+                    <td>{}{}</td>
                     <td class="{}">{}</td>
                     <td class="{}">{}</td>
                     <td>{} {}</td>
@@ -707,10 +865,12 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
                     <td>{}</td>
                     <td>{}</td>
                     <td>{}</td>
+                    <td>{}</td>
                 </tr>
 "#,
             task.id,
             utils::html_escape(&task.description),
+            notes_html,
             status_class,
             match task.status {
                 TaskStatus::Completed => "✅ Completed",
@@ -723,27 +883,517 @@ fn export_to_html(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn
                 Priority::Medium => "▶️ Medium",
                 Priority::Low => "⬇️ Low",
             },
-            task.phase.emoji(),
-            utils::html_escape(&task.phase.name),
+            theme().phase_emoji(&task.phase),
+            phase_name_html(&task.phase.name),
             estimated_display,
             actual_display,
             variance_class,
             variance_display,
             sessions_display,
             tags_html,
+            utils::html_escape(&task.owners.join(", ")),
             deps_html,
             task.created_at.as_deref().unwrap_or("").split('T').next().unwrap_or("")
         ));
     }
     
-    // Close HTML
     html.push_str(r#"
             </tbody>
         </table>
-    </div>
-</body>
-</html>
 "#);
     
-    Ok(html)
-} 
\ No newline at end of file
+    html
+}
+
+/// One page of a chunked HTML export
+struct HtmlChunk<'a> {
+    file_name: String,
+    phase: String,
+    tasks: &'a [&'a Task],
+}
+
+/// Write a large HTML export as an index page plus per-phase task pages.
+///
+/// The index goes to `output_path` with the usual summary cards and a table
+/// of phases. Each phase gets `<stem>-<phase>.html`, split further into
+/// numbered pages of at most `max_rows` tasks with previous/next links.
+fn export_html_chunked(roadmap: &Roadmap, tasks: &[&Task], output_path: &Path, max_rows: usize) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    let stem = output_path.file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "export".to_string());
+    let dir = output_path.parent().unwrap_or_else(|| Path::new(""));
+
+    // Group by phase in order of first appearance, which follows task IDs
+    let mut phases: Vec<(String, Vec<&Task>)> = Vec::new();
+    for task in tasks {
+        match phases.iter_mut().find(|(name, _)| *name == task.phase.name) {
+            Some((_, phase_tasks)) => phase_tasks.push(task),
+            None => phases.push((task.phase.name.clone(), vec![task])),
+        }
+    }
+
+    let mut used_slugs = std::collections::HashSet::new();
+    let mut chunks: Vec<HtmlChunk> = Vec::new();
+    let mut index_rows = String::new();
+    for (phase, phase_tasks) in &phases {
+        let mut slug = slugify(phase);
+        let base_slug = slug.clone();
+        let mut suffix = 2;
+        while !used_slugs.insert(slug.clone()) {
+            slug = format!("{}-{}", base_slug, suffix);
+            suffix += 1;
+        }
+
+        let first_chunk = chunks.len();
+        for (page, page_tasks) in phase_tasks.chunks(max_rows).enumerate() {
+            let file_name = if page == 0 {
+                format!("{}-{}.html", stem, slug)
+            } else {
+                format!("{}-{}-{}.html", stem, slug, page + 1)
+            };
+            chunks.push(HtmlChunk { file_name, phase: phase.clone(), tasks: page_tasks });
+        }
+
+        let completed = phase_tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+        let page_links = chunks[first_chunk..].iter().enumerate()
+            .map(|(i, chunk)| format!("<a href=\"{}\">{}</a>", utils::html_escape(&chunk.file_name), i + 1))
+            .collect::<Vec<_>>()
+            .join(" ");
+        index_rows.push_str(&format!(
+            "                <tr><td><a href=\"{}\">{}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+            utils::html_escape(&chunks[first_chunk].file_name),
+            utils::html_escape(phase),
+            phase_tasks.len(),
+            completed,
+            page_links
+        ));
+    }
+
+    // Index page
+    let mut index = html_page_start(&format!("{} - Time Tracking Report", roadmap.title));
+    index.push_str(&html_summary(roadmap, tasks));
+    index.push_str(&format!(r#"
+        <h2>🗂️ Phases</h2>
+        <table>
+            <thead>
+                <tr>
+                    <th>Phase</th>
+                    <th>Tasks</th>
+                    <th>Completed</th>
+                    <th>Pages</th>
+                </tr>
+            </thead>
+            <tbody>
+{}            </tbody>
+        </table>
+"#, index_rows));
+    index.push_str(HTML_PAGE_END);
+    fs::write(output_path, index)?;
+    let mut written = vec![output_path.to_path_buf()];
+
+    let index_name = output_path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let phase_pages: Vec<&HtmlChunk> = chunks.iter().filter(|c| c.phase == chunk.phase).collect();
+        let page_number = phase_pages.iter().position(|c| c.file_name == chunk.file_name).unwrap_or(0) + 1;
+        let first_row = (page_number - 1) * max_rows + 1;
+
+        let mut nav = format!("<a href=\"{}\">← Index</a>", utils::html_escape(&index_name));
+        if let Some(prev) = i.checked_sub(1).map(|p| &chunks[p]) {
+            nav.push_str(&format!(" | <a href=\"{}\">‹ Previous</a>", utils::html_escape(&prev.file_name)));
+        }
+        if let Some(next) = chunks.get(i + 1) {
+            nav.push_str(&format!(" | <a href=\"{}\">Next ›</a>", utils::html_escape(&next.file_name)));
+        }
+
+        let mut page = html_page_start(&format!("{} - {} ({}/{})", roadmap.title, chunk.phase, page_number, phase_pages.len()));
+        page.push_str(&format!(r#"        <h1>{}</h1>
+        <div class="page-nav">{}</div>
+        <h2>📋 {} — tasks {}–{} of {}</h2>"#,
+            utils::html_escape(&roadmap.title),
+            nav,
+            utils::html_escape(&chunk.phase),
+            first_row,
+            first_row + chunk.tasks.len() - 1,
+            phase_pages.iter().map(|c| c.tasks.len()).sum::<usize>()
+        ));
+        page.push_str(&html_task_table(chunk.tasks));
+        page.push_str(&format!("        <div class=\"page-nav\">{}</div>\n", nav));
+        page.push_str(HTML_PAGE_END);
+        fs::write(dir.join(&chunk.file_name), page)?;
+        written.push(dir.join(&chunk.file_name));
+    }
+
+    ui::display_success(&format!("✅ Exported {} tasks to {} with {} phase page(s) alongside it",
+        tasks.len(),
+        output_path.display(),
+        chunks.len()));
+
+    Ok(written)
+}
+
+/// Escape note text for HTML, turning `[[Note Name]]` links into links to the
+/// pages in the notes directory (or to `Note Name.md` beside the export when
+/// none is configured)
+fn note_html(text: &str, notes_dir: Option<&Path>) -> String {
+    let mut html = String::new();
+    let mut last = 0;
+    for link in crate::wiki_links::find_links(text) {
+        html.push_str(&utils::html_escape(&text[last..link.span.start]));
+        let href = match notes_dir {
+            Some(dir) => {
+                let file = crate::wiki_links::resolve(dir, &link.target);
+                let file = std::path::absolute(&file).unwrap_or(file);
+                format!("file://{}", file.to_string_lossy().replace(' ', "%20"))
+            }
+            None => format!("{}.md", link.target.replace(' ', "%20")),
+        };
+        html.push_str(&format!("<a class=\"wiki-link\" href=\"{}\">{}</a>", utils::html_escape(&href), utils::html_escape(&link.label)));
+        last = link.span.end;
+    }
+    html.push_str(&utils::html_escape(&text[last..]));
+    html
+}
+
+/// Turn a phase name into a file-name-safe slug
+fn slugify(name: &str) -> String {
+    let slug = name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect::<String>()
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() { "phase".to_string() } else { slug }
+}
+
+/// Export roadmap to Markdown that `rask init` can read back.
+///
+/// Tasks are written as a checklist in ID order so re-importing assigns the
+/// same IDs. Metadata that the checklist cannot express (priority, phase,
+/// tags, estimates, dependencies, owners, energy, contexts) is kept in a
+/// trailing HTML comment, which Markdown renderers hide and the parser skips.
+fn export_to_markdown(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn std::error::Error>> {
+    let mut markdown = format!("# {}\n\n", roadmap.title);
+    
+    if let Some(description) = &roadmap.metadata.description {
+        markdown.push_str(&format!("{}\n\n", description));
+    }
+    
+    for task in tasks {
+        let checkbox = match task.status {
+            TaskStatus::Pending => "[ ]",
+            TaskStatus::Completed => "[x]",
+        };
+        
+        let mut metadata = vec![
+            format!("id={}", task.id),
+            format!("uuid={}", task.uuid),
+            format!("priority={}", task.priority.to_string().to_lowercase()),
+            format!("phase=\"{}\"", task.phase.name.replace('"', "'")),
+        ];
+        if !task.tags.is_empty() {
+            let mut tags: Vec<&str> = task.tags.iter().map(String::as_str).collect();
+            tags.sort_unstable();
+            metadata.push(format!("tags={}", tags.join(",")));
+        }
+        if let Some(hours) = task.estimated_hours {
+            metadata.push(format!("est={}", hours));
+        }
+        if !task.dependencies.is_empty() {
+            let deps: Vec<String> = task.dependencies.iter().map(|d| d.to_string()).collect();
+            metadata.push(format!("deps={}", deps.join(",")));
+        }
+        if !task.owners.is_empty() {
+            metadata.push(format!("owners=\"{}\"", task.owners.join(",").replace('"', "'")));
+        }
+        if let Some(energy) = task.energy {
+            metadata.push(format!("energy={}", energy.to_string().to_lowercase()));
+        }
+        if !task.contexts.is_empty() {
+            metadata.push(format!("contexts={}", task.contexts.join(",")));
+        }
+        
+        // Keep the description on a single line so it parses as one list item
+        let description = task.description.lines().collect::<Vec<_>>().join(" ");
+        markdown.push_str(&format!("- {} {} <!-- rask: {} -->\n", checkbox, description, metadata.join(" ")));
+    }
+    
+    Ok(markdown)
+}
+
+/// Export roadmap to a Mermaid Gantt chart.
+///
+/// Each phase becomes a section. Task durations come from estimated hours
+/// (one working day when no estimate is set), and dependencies become
+/// `after` clauses so the chart follows the dependency order.
+fn export_to_mermaid_gantt(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn std::error::Error>> {
+    let exported_ids: std::collections::HashSet<usize> = tasks.iter().map(|t| t.id).collect();
+    let start_date = tasks.iter()
+        .filter_map(|t| t.created_datetime())
+        .min()
+        .unwrap_or_else(chrono::Utc::now)
+        .format("%Y-%m-%d")
+        .to_string();
+    
+    let mut gantt = String::from("gantt\n");
+    gantt.push_str(&format!("    title {}\n", mermaid_label(&roadmap.title)));
+    gantt.push_str("    dateFormat YYYY-MM-DD\n");
+    
+    // Group tasks by phase, keeping the roadmap's phase order
+    for phase in roadmap.get_all_phases() {
+        let phase_tasks: Vec<&&Task> = tasks.iter().filter(|t| t.phase.is_named(&phase.name)).collect();
+        if phase_tasks.is_empty() {
+            continue;
+        }
+        
+        gantt.push_str(&format!("    section {}\n", mermaid_label(&phase.name)));
+        for task in phase_tasks {
+            let mut tags = Vec::new();
+            if task.status == TaskStatus::Completed {
+                tags.push("done".to_string());
+            } else if task.has_active_time_session() {
+                tags.push("active".to_string());
+            }
+            if task.priority == Priority::Critical {
+                tags.push("crit".to_string());
+            }
+            tags.push(format!("t{}", task.id));
+            
+            let deps: Vec<String> = task.dependencies.iter()
+                .filter(|d| exported_ids.contains(d))
+                .map(|d| format!("t{}", d))
+                .collect();
+            if deps.is_empty() {
+                tags.push(start_date.clone());
+            } else {
+                tags.push(format!("after {}", deps.join(" ")));
+            }
+            
+            let duration = match task.estimated_hours {
+                Some(hours) if hours > 0.0 => format!("{}h", hours.ceil() as u64),
+                _ => "1d".to_string(),
+            };
+            tags.push(duration);
+            
+            let label = if task.owners.is_empty() {
+                mermaid_label(&task.description)
+            } else {
+                mermaid_label(&format!("{} ({})", task.description, task.owners.join(", ")))
+            };
+            gantt.push_str(&format!("    {} :{}\n", label, tags.join(", ")));
+        }
+    }
+    
+    Ok(gantt)
+}
+
+/// Export a Mermaid flowchart board: one column per phase, task nodes styled
+/// by status, and arrows from each dependency to the task waiting on it.
+///
+/// The output renders directly in GitHub markdown inside a ```mermaid block.
+fn export_to_mermaid_board(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn std::error::Error>> {
+    let exported_ids: std::collections::HashSet<usize> = tasks.iter().map(|t| t.id).collect();
+    let completed_ids = roadmap.get_completed_task_ids();
+    let is_blocked = |task: &Task| task.status == TaskStatus::Pending && !task.can_be_started(&completed_ids);
+    
+    let mut board = String::from("flowchart LR\n");
+    board.push_str(&format!("    %% {}\n", mermaid_label(&roadmap.title)));
+    
+    // One column per phase, keeping the roadmap's phase order
+    for (index, phase) in roadmap.get_all_phases().iter().enumerate() {
+        let phase_tasks: Vec<&&Task> = tasks.iter().filter(|t| t.phase.is_named(&phase.name)).collect();
+        if phase_tasks.is_empty() {
+            continue;
+        }
+        
+        let done = phase_tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+        let active = phase_tasks.iter().filter(|t| t.has_active_time_session()).count();
+        board.push_str(&format!(
+            "    subgraph phase{}[\"{} {} · {} in progress · {}/{} done\"]\n        direction TB\n",
+            index + 1,
+            phase.emoji(),
+            mermaid_text(&phase.name),
+            active,
+            done,
+            phase_tasks.len()
+        ));
+        for task in phase_tasks {
+            board.push_str(&format!("        t{}[\"#{} {}\"]\n", task.id, task.id, mermaid_text(&task.description)));
+        }
+        board.push_str("    end\n");
+    }
+    
+    // Dependency arrows, labelled with the dependency note when there is one
+    for task in tasks {
+        for dep in task.dependencies.iter().filter(|d| exported_ids.contains(d)) {
+            match task.dependency_notes.get(dep) {
+                Some(note) => board.push_str(&format!("    t{} -->|\"{}\"| t{}\n", dep, mermaid_text(note), task.id)),
+                None => board.push_str(&format!("    t{} --> t{}\n", dep, task.id)),
+            }
+        }
+    }
+    
+    board.push_str("    classDef done fill:#d4edda,stroke:#28a745,color:#155724\n");
+    board.push_str("    classDef active fill:#fff3cd,stroke:#d39e00,color:#856404\n");
+    board.push_str("    classDef blocked fill:#f8d7da,stroke:#dc3545,color:#721c24\n");
+    board.push_str("    classDef critical stroke-width:3px\n");
+    let mut classes: [(&str, Vec<String>); 4] = [("done", Vec::new()), ("active", Vec::new()), ("blocked", Vec::new()), ("critical", Vec::new())];
+    for task in tasks {
+        // Index into `classes` for the task's status, if it gets a status style
+        let status = if task.status == TaskStatus::Completed {
+            Some(0)
+        } else if task.has_active_time_session() {
+            Some(1)
+        } else if is_blocked(task) {
+            Some(2)
+        } else {
+            None
+        };
+        if let Some(index) = status {
+            classes[index].1.push(format!("t{}", task.id));
+        }
+        if task.priority == Priority::Critical {
+            classes[3].1.push(format!("t{}", task.id));
+        }
+    }
+    for (class, nodes) in classes.iter().filter(|(_, nodes)| !nodes.is_empty()) {
+        board.push_str(&format!("    class {} {}\n", nodes.join(","), class));
+    }
+    
+    Ok(board)
+}
+
+/// Text for a quoted Mermaid flowchart label
+fn mermaid_text(text: &str) -> String {
+    text.lines()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('"', "'")
+        .replace(';', ",")
+        .trim()
+        .to_string()
+}
+
+/// Strip characters that Mermaid treats as syntax from a label
+fn mermaid_label(text: &str) -> String {
+    text.lines()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace([':', ';', '#'], " ")
+        .trim()
+        .to_string()
+}
+
+/// Export due dates and completed time sessions as an iCalendar (.ics) file.
+///
+/// Tasks with a due date become all-day events, and every finished time
+/// session becomes a timed event, so Rask work can be overlaid on a calendar.
+fn export_to_ics(roadmap: &Roadmap, tasks: &[&Task]) -> Result<String, Box<dyn std::error::Error>> {
+    let stamp = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    let project_key = roadmap.project_id.clone()
+        .unwrap_or_else(|| roadmap.title.to_lowercase().replace(char::is_whitespace, "-"));
+    
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Rask//Rask CLI//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", ics_escape(&roadmap.title)),
+    ];
+    
+    for task in tasks {
+        let categories = if task.tags.is_empty() {
+            None
+        } else {
+            let mut tags: Vec<&str> = task.tags.iter().map(String::as_str).collect();
+            tags.sort_unstable();
+            Some(format!("CATEGORIES:{}", tags.iter().map(|t| ics_escape(t)).collect::<Vec<_>>().join(",")))
+        };
+        
+        // All-day event on the due date
+        if let Some(due) = task.due_date_parsed() {
+            let status = if task.status == TaskStatus::Completed { "completed" } else { "pending" };
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}-task-{}-due@rask", project_key, task.id));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART;VALUE=DATE:{}", due.format("%Y%m%d")));
+            lines.push(format!("DTEND;VALUE=DATE:{}", (due + chrono::Duration::days(1)).format("%Y%m%d")));
+            lines.push(format!("SUMMARY:{}", ics_escape(&format!("Due: #{} {}", task.id, task.description))));
+            lines.push(format!("DESCRIPTION:{}", ics_escape(&format!(
+                "Phase: {}\nPriority: {}\nStatus: {}{}{}",
+                task.phase.name,
+                task.priority,
+                status,
+                if task.owners.is_empty() { String::new() } else { format!("\nOwners: {}", task.owners.join(", ")) },
+                task.notes.as_deref().map(|n| format!("\n\n{}", n)).unwrap_or_default()
+            ))));
+            if let Some(categories) = &categories {
+                lines.push(categories.clone());
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+        
+        // Timed events for each finished session
+        for (index, session) in task.time_sessions.iter().enumerate() {
+            let (Some(start), Some(end)) = (
+                crate::model::parse_timestamp(Some(&session.start_time)),
+                crate::model::parse_timestamp(session.end_time.as_deref()),
+            ) else {
+                continue;
+            };
+            
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!("UID:{}-task-{}-session-{}@rask", project_key, task.id, index));
+            lines.push(format!("DTSTAMP:{}", stamp));
+            lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%SZ")));
+            lines.push(format!("DTEND:{}", end.format("%Y%m%dT%H%M%SZ")));
+            lines.push(format!("SUMMARY:{}", ics_escape(&format!("#{} {}", task.id, task.description))));
+            if let Some(description) = &session.description {
+                lines.push(format!("DESCRIPTION:{}", ics_escape(description)));
+            }
+            if let Some(categories) = &categories {
+                lines.push(categories.clone());
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+    }
+    
+    lines.push("END:VCALENDAR".to_string());
+    
+    let mut ics = String::new();
+    for line in &lines {
+        ics.push_str(&ics_fold(line));
+        ics.push_str("\r\n");
+    }
+    Ok(ics)
+}
+
+/// Escape text values per RFC 5545
+fn ics_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Fold content lines longer than 75 octets, as required by RFC 5545
+fn ics_fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut line_len = 0;
+    
+    for c in line.chars() {
+        let char_len = c.len_utf8();
+        if line_len + char_len > 75 {
+            folded.push_str("\r\n ");
+            line_len = 1;
+        }
+        folded.push(c);
+        line_len += char_len;
+    }
+    
+    folded
+}
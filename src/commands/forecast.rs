@@ -0,0 +1,214 @@
+//! Completion forecast
+//!
+//! `rask forecast` predicts when the pending tasks of the project, or of one
+//! phase, will be done at the rate tasks were completed over the last few
+//! weeks. The expected date uses the mean weekly rate; the optimistic and
+//! pessimistic dates use one standard deviation above and below it, with the
+//! slow end kept at a quarter of the mean or more so it stays finite.
+//!
+//! Each forecast is kept in `.rask/cache/forecasts.json`, per phase, so the
+//! next one can say how the dates moved and why: work completed, work added,
+//! or a change of pace.
+
+use crate::model::{Roadmap, Task, TaskStatus};
+use crate::{state, ui};
+use super::{utils, CommandResult};
+use chrono::{DateTime, Duration, Local, NaiveDate, Utc};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+
+/// Key of the whole-project forecast in the saved forecasts
+const PROJECT_SCOPE: &str = "*";
+
+/// The last forecast for one scope
+#[derive(Serialize, Deserialize, Clone)]
+struct SavedForecast {
+    generated_at: String,
+    remaining: usize,
+    velocity: f64,
+    expected: Option<NaiveDate>,
+}
+
+/// How things moved since the last forecast
+#[derive(Serialize)]
+struct ForecastChange {
+    previous_at: String,
+    previous_expected: Option<NaiveDate>,
+    /// Days the expected date moved; positive is later
+    expected_shift_days: Option<i64>,
+    completed_since: usize,
+    added_since: usize,
+    previous_velocity: f64,
+}
+
+#[derive(Serialize)]
+struct Forecast {
+    phase: Option<String>,
+    window_weeks: u32,
+    /// Tasks completed in each week of the window, oldest first
+    weekly_completions: Vec<usize>,
+    /// Mean tasks completed per week
+    velocity: f64,
+    remaining: usize,
+    optimistic: Option<NaiveDate>,
+    expected: Option<NaiveDate>,
+    pessimistic: Option<NaiveDate>,
+    change: Option<ForecastChange>,
+}
+
+/// Tasks completed in each of the last `weeks` weeks, oldest first
+fn weekly_completions(tasks: &[&Task], weeks: u32, now: DateTime<Utc>) -> Vec<usize> {
+    let mut counts = vec![0; weeks as usize];
+    for at in tasks.iter().filter(|task| task.status == TaskStatus::Completed).filter_map(|task| task.completed_datetime()) {
+        let weeks_ago = (now - at).num_days() / 7;
+        if (0..i64::from(weeks)).contains(&weeks_ago) {
+            counts[weeks as usize - 1 - weeks_ago as usize] += 1;
+        }
+    }
+    counts
+}
+
+/// Date by which `remaining` tasks are done at `per_week` tasks a week
+fn finish_date(today: NaiveDate, remaining: usize, per_week: f64) -> Option<NaiveDate> {
+    if per_week <= 0.0 {
+        return None;
+    }
+    let days = (remaining as f64 / per_week * 7.0).ceil();
+    today.checked_add_signed(Duration::days(days as i64))
+}
+
+fn build_forecast(roadmap: &Roadmap, phase: Option<&str>, weeks: u32, previous: Option<&SavedForecast>) -> Forecast {
+    let now = Utc::now();
+    let today = Local::now().date_naive();
+    let tasks: Vec<&Task> = roadmap.tasks.iter()
+        .filter(|task| phase.is_none_or(|phase| task.phase.name.eq_ignore_ascii_case(phase)))
+        .collect();
+    let remaining = tasks.iter().filter(|task| task.status == TaskStatus::Pending).count();
+
+    let weekly = weekly_completions(&tasks, weeks, now);
+    let mean = weekly.iter().sum::<usize>() as f64 / weekly.len() as f64;
+    let deviation = (weekly.iter().map(|count| (*count as f64 - mean).powi(2)).sum::<f64>() / weekly.len() as f64).sqrt();
+    let expected = finish_date(today, remaining, mean);
+
+    let change = previous.map(|previous| {
+        let since = crate::model::parse_timestamp(Some(&previous.generated_at));
+        let after = |at: Option<DateTime<Utc>>| matches!((at, since), (Some(at), Some(since)) if at > since);
+        ForecastChange {
+            previous_at: previous.generated_at.clone(),
+            previous_expected: previous.expected,
+            expected_shift_days: expected.zip(previous.expected).map(|(now, before)| (now - before).num_days()),
+            completed_since: tasks.iter().filter(|task| task.status == TaskStatus::Completed && after(task.completed_datetime())).count(),
+            added_since: tasks.iter().filter(|task| after(task.created_datetime())).count(),
+            previous_velocity: previous.velocity,
+        }
+    });
+
+    Forecast {
+        phase: phase.map(str::to_string),
+        window_weeks: weeks,
+        weekly_completions: weekly,
+        velocity: mean,
+        remaining,
+        optimistic: finish_date(today, remaining, mean + deviation),
+        expected,
+        pessimistic: finish_date(today, remaining, (mean - deviation).max(mean / 4.0)),
+        change,
+    }
+}
+
+fn load_saved() -> HashMap<String, SavedForecast> {
+    state::get_forecasts_file().ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default()
+}
+
+fn save(mut saved: HashMap<String, SavedForecast>, scope: String, forecast: &Forecast) -> CommandResult {
+    saved.insert(scope, SavedForecast {
+        generated_at: Utc::now().to_rfc3339(),
+        remaining: forecast.remaining,
+        velocity: forecast.velocity,
+        expected: forecast.expected,
+    });
+    let path = state::get_forecasts_file()?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&saved)?)?;
+    Ok(())
+}
+
+fn print_change(change: &ForecastChange, velocity: f64) {
+    let when = crate::model::parse_timestamp(Some(&change.previous_at))
+        .map(|at| at.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| change.previous_at.clone());
+    println!("\n  {} {}", "Since the last forecast".bold(), format!("({})", when).bright_black());
+    let was = change.previous_expected.map_or("none".to_string(), |date| date.to_string());
+    match change.expected_shift_days {
+        Some(0) => println!("    Expected date unchanged"),
+        Some(days) if days > 0 => println!("    Expected date {} (was {})", format!("slipped {} day(s)", days).bright_red(), was),
+        Some(days) => println!("    Expected date {} (was {})", format!("moved {} day(s) earlier", -days).bright_green(), was),
+        None => println!("    Expected date was {}", was),
+    }
+    println!("    {} completed, {} added", change.completed_since.to_string().bright_green(), change.added_since.to_string().bright_yellow());
+    if (velocity - change.previous_velocity).abs() >= 0.05 {
+        let direction = if velocity > change.previous_velocity { "up".bright_green() } else { "down".bright_red() };
+        println!("    Pace {} from {:.1} to {:.1} tasks/week", direction, change.previous_velocity, velocity);
+    }
+}
+
+/// Predict when the pending tasks will be done
+pub fn show_forecast(phase: Option<&str>, weeks: u32) -> CommandResult {
+    if weeks == 0 {
+        return Err("The velocity window must be at least 1 week".into());
+    }
+    let roadmap = state::load_state()?;
+    let phase = match phase {
+        Some(name) => match roadmap.tasks.iter().find(|task| task.phase.name.eq_ignore_ascii_case(name)) {
+            Some(task) => Some(task.phase.name.as_str()),
+            None => return Err(format!("No tasks in phase '{}'", name).into()),
+        },
+        None => None,
+    };
+
+    let scope = phase.map_or(PROJECT_SCOPE.to_string(), str::to_lowercase);
+    let saved = load_saved();
+    let forecast = build_forecast(&roadmap, phase, weeks, saved.get(&scope));
+    save(saved, scope, &forecast)?;
+
+    if utils::json_output() {
+        return utils::print_json(&forecast);
+    }
+
+    let title = phase.map_or("Project".to_string(), |phase| format!("{} phase", phase));
+    println!("\n  {} {}", "🔮 Forecast:".bold().bright_cyan(), title.bold());
+    println!("  {}", ui::theme::theme().rule("─", 60).bright_black());
+    let weekly: Vec<String> = forecast.weekly_completions.iter().map(usize::to_string).collect();
+    println!("  Pace: {} tasks/week over the last {} week(s) {}", format!("{:.1}", forecast.velocity).bright_white().bold(),
+        forecast.window_weeks, format!("[{}]", weekly.join(" ")).bright_black());
+    println!("  Remaining: {} pending task(s)", forecast.remaining.to_string().bright_white().bold());
+
+    if forecast.remaining == 0 {
+        println!();
+        ui::display_success("Nothing left to do here");
+    } else if let (Some(optimistic), Some(expected), Some(pessimistic)) = (forecast.optimistic, forecast.expected, forecast.pessimistic) {
+        let today = Local::now().date_naive();
+        let line = |label: &str, date: NaiveDate| format!("  {:<12} {} {}", label, date.format("%a %Y-%m-%d"),
+            format!("(in {} days)", (date - today).num_days()).bright_black());
+        println!();
+        println!("{}", line("Optimistic", optimistic).bright_green());
+        println!("{}", line("Expected", expected).bold());
+        println!("{}", line("Pessimistic", pessimistic).bright_yellow());
+    } else {
+        println!();
+        ui::display_warning(&format!("No tasks completed in the last {} week(s), so there is no pace to forecast from. Try a longer --weeks window", forecast.window_weeks));
+    }
+
+    if let Some(change) = &forecast.change {
+        print_change(change, forecast.velocity);
+    }
+    println!();
+    Ok(())
+}
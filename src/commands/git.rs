@@ -0,0 +1,378 @@
+//! Git integration commands
+//!
+//! This module connects tasks to a git repository: it creates branches named
+//! after tasks, links commits whose messages reference a task with
+//! `rask:#<id>`, and installs hooks that complete tasks from commits saying
+//! `closes rask:#<id>`. Git is invoked through the `git` executable so no
+//! extra dependencies are needed.
+
+use crate::{cli::GitCommands, hooks, model::{LinkedCommit, Roadmap, TaskStatus}, state, ui};
+use super::{CommandError, CommandResult, utils};
+use colored::*;
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::LazyLock;
+
+/// Maximum length of the description part of a branch name
+const BRANCH_SLUG_MAX_LEN: usize = 40;
+
+/// Marker line identifying hooks written by `rask git install-hooks`
+const HOOK_MARKER: &str = "# rask-managed hook";
+
+/// A task reference in a commit message: `rask:#12`
+static TASK_REFERENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)rask:#(\d+)").expect("valid task reference regex")
+});
+
+/// A closing reference in a commit message: `closes rask:#12`, `fixes rask:#3`
+static CLOSING_REFERENCE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:close[sd]?|fix(?:e[sd])?|resolve[sd]?)\s+rask:#(\d+)").expect("valid closing reference regex")
+});
+
+/// Hooks installed by Rask and the hidden subcommand each one runs
+const HOOKS: [(&str, &str); 2] = [
+    ("commit-msg", "hook-commit-msg \"$msg_file\""),
+    ("post-commit", "hook-post-commit"),
+];
+
+/// Handle git integration commands
+pub fn handle_git_command(git_command: &GitCommands) -> CommandResult {
+    match git_command {
+        GitCommands::Branch { task_id, prefix, no_checkout } => create_task_branch(*task_id, prefix, *no_checkout),
+        GitCommands::Scan { range, limit, dry_run } => scan_commits(range, *limit, *dry_run),
+        GitCommands::InstallHooks { force } => install_hooks(*force),
+        GitCommands::HookCommitMsg { file } => check_commit_message(file),
+        GitCommands::HookPostCommit => complete_tasks_from_last_commit(),
+    }
+}
+
+/// Create a branch named `<prefix>/<id>-<description-slug>` for a task
+pub fn create_task_branch(task_id: usize, prefix: &str, no_checkout: bool) -> CommandResult {
+    let roadmap = state::load_state()?;
+    let task = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+
+    let branch = branch_name(prefix, task_id, &task.description);
+    if no_checkout {
+        run_git(&["branch", &branch])?;
+        ui::display_success(&format!("Created branch {} for task #{}", branch.bright_cyan(), task_id));
+    } else {
+        run_git(&["checkout", "-b", &branch])?;
+        ui::display_success(&format!("Switched to new branch {} for task #{}", branch.bright_cyan(), task_id));
+    }
+
+    println!("   💡 Reference the task in commits with {} so 'rask git scan' can link them", format!("rask:#{}", task_id).bright_yellow());
+    Ok(())
+}
+
+/// Scan commit messages for `rask:#ID` references and attach the commits to tasks
+pub fn scan_commits(range: &str, limit: Option<usize>, dry_run: bool) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+
+    // Fields are separated by 0x1f and commits by 0x1e so message bodies can contain anything
+    let mut args = vec!["log".to_string(), "--format=%H%x1f%cI%x1f%s%x1f%B%x1e".to_string()];
+    if let Some(limit) = limit {
+        args.push(format!("--max-count={}", limit));
+    }
+    args.push(range.to_string());
+    let arg_refs: Vec<&str> = args.iter().map(String::as_str).collect();
+    let log = run_git(&arg_refs)?;
+
+    let mut scanned = 0;
+    let mut linked = 0;
+    let mut unknown_ids = Vec::new();
+
+    // git log lists newest first; link oldest first so tasks keep chronological order
+    for record in log.split('\x1e').rev().map(str::trim).filter(|r| !r.is_empty()) {
+        let mut fields = record.splitn(4, '\x1f');
+        let (Some(hash), Some(committed_at), Some(summary), Some(body)) =
+            (fields.next(), fields.next(), fields.next(), fields.next()) else {
+            continue;
+        };
+        scanned += 1;
+
+        let mut task_ids: Vec<usize> = TASK_REFERENCE.captures_iter(body)
+            .filter_map(|cap| cap[1].parse().ok())
+            .collect();
+        task_ids.sort_unstable();
+        task_ids.dedup();
+
+        for task_id in task_ids {
+            let Some(task) = roadmap.find_task_by_id_mut(task_id) else {
+                unknown_ids.push(task_id);
+                continue;
+            };
+
+            let commit = LinkedCommit {
+                hash: hash.to_string(),
+                summary: summary.to_string(),
+                committed_at: Some(committed_at.to_string()),
+            };
+            if task.link_commit(commit) {
+                linked += 1;
+                println!("  {} {} → #{} {}",
+                    "+".bright_green().bold(),
+                    hash.get(..7).unwrap_or(hash).bright_yellow(),
+                    task_id,
+                    summary
+                );
+            }
+        }
+    }
+
+    if !unknown_ids.is_empty() {
+        unknown_ids.sort_unstable();
+        unknown_ids.dedup();
+        ui::display_warning(&format!(
+            "Commits reference unknown tasks: {}",
+            unknown_ids.iter().map(|id| format!("#{}", id)).collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    if linked == 0 {
+        ui::display_info(&format!("Scanned {} commits - no new task references found", scanned));
+        return Ok(());
+    }
+
+    if dry_run {
+        ui::display_info(&format!("Dry run - {} link(s) found in {} commits, nothing saved", linked, scanned));
+        return Ok(());
+    }
+
+    utils::save_and_sync(&roadmap)?;
+    ui::display_success(&format!("Linked {} commit reference(s) from {} scanned commits", linked, scanned));
+    Ok(())
+}
+
+/// Write commit-msg and post-commit hooks that run Rask for this workspace
+pub fn install_hooks(force: bool) -> CommandResult {
+    // Make sure we are inside a workspace before touching the repository
+    state::load_state()?;
+
+    let hooks_dir = PathBuf::from(run_git(&["rev-parse", "--git-path", "hooks"])?.trim());
+    std::fs::create_dir_all(&hooks_dir)?;
+    let workspace = std::env::current_dir()?;
+    let rask_exe = std::env::current_exe()
+        .map(|p| p.display().to_string())
+        .unwrap_or_else(|_| "rask".to_string());
+
+    // Check every hook first so a refusal never leaves a half-installed set
+    let foreign_hooks: Vec<PathBuf> = HOOKS.iter()
+        .map(|(hook, _)| hooks_dir.join(hook))
+        .filter(|path| path.exists() && !std::fs::read_to_string(path).unwrap_or_default().contains(HOOK_MARKER))
+        .collect();
+    if !foreign_hooks.is_empty() && !force {
+        return Err(format!(
+            "Existing hooks were not installed by Rask: {}. Re-run with --force to replace them (a .bak copy is kept).",
+            foreign_hooks.iter().map(|p| p.display().to_string()).collect::<Vec<_>>().join(", ")
+        ).into());
+    }
+    for path in &foreign_hooks {
+        std::fs::copy(path, path.with_extension("bak"))?;
+    }
+
+    for (hook, subcommand) in HOOKS {
+        let path = hooks_dir.join(hook);
+        std::fs::write(&path, hook_script(&rask_exe, &workspace, subcommand))?;
+        make_executable(&path)?;
+        println!("   🪝 {}", path.display());
+    }
+
+    ui::display_success("Installed git hooks");
+    println!("   💡 Commits containing {} now complete that task after committing.", "closes rask:#<id>".bright_yellow());
+    println!("   💡 Commits closing tasks with incomplete dependencies are rejected (bypass with git commit --no-verify).");
+    Ok(())
+}
+
+/// commit-msg hook: reject commits that close tasks which cannot be completed yet
+pub fn check_commit_message(file: &Path) -> CommandResult {
+    let message = std::fs::read_to_string(file)?;
+    let closed = closing_references(&message);
+    if closed.is_empty() {
+        return Ok(());
+    }
+
+    let roadmap = state::load_state()?;
+    let problems: Vec<String> = closed.iter()
+        .filter_map(|id| closing_problem(&roadmap, *id))
+        .collect();
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+
+    for problem in &problems {
+        ui::display_error(problem);
+    }
+    Err("Commit rejected by rask: fix the task references or commit with --no-verify".into())
+}
+
+/// post-commit hook: link the new commit and complete the tasks it closes
+pub fn complete_tasks_from_last_commit() -> CommandResult {
+    let log = run_git(&["log", "-1", "--format=%H%x1f%cI%x1f%s%x1f%B"])?;
+    let mut fields = log.splitn(4, '\x1f');
+    let (Some(hash), Some(committed_at), Some(summary), Some(body)) =
+        (fields.next(), fields.next(), fields.next(), fields.next()) else {
+        return Ok(());
+    };
+
+    let closed = closing_references(body);
+    if closed.is_empty() {
+        return Ok(());
+    }
+
+    let mut roadmap = state::load_state()?;
+    let short_hash = hash.get(..7).unwrap_or(hash).to_string();
+    let mut changed = false;
+    let mut completed_ids = Vec::new();
+
+    for task_id in closed {
+        // Re-check here too: the commit-msg hook can be bypassed with --no-verify
+        let problem = closing_problem(&roadmap, task_id);
+        let Some(task) = roadmap.find_task_by_id_mut(task_id) else {
+            continue;
+        };
+
+        changed |= task.link_commit(LinkedCommit {
+            hash: hash.to_string(),
+            summary: summary.to_string(),
+            committed_at: Some(committed_at.to_string()),
+        });
+
+        if task.status == TaskStatus::Completed {
+            continue;
+        }
+        if let Some(problem) = problem {
+            ui::display_warning(&format!("{} - not completed", problem));
+            continue;
+        }
+        if !task.acceptance_criteria.is_empty() {
+            ui::display_warning(&format!(
+                "Task #{} has acceptance criteria - confirm them with 'rask complete {}'", task_id, task_id
+            ));
+            continue;
+        }
+
+        if let Err(reason) = hooks::run_pre_hook("pre-complete", task) {
+            ui::display_warning(&format!("{} - not completed", reason));
+            continue;
+        }
+
+        task.mark_completed();
+        task.record_history("completed", Some(format!("closed by commit {}", short_hash)));
+        changed = true;
+        completed_ids.push(task_id);
+        ui::display_success(&format!("Completed task #{} (closed by {})", task_id, short_hash));
+    }
+
+    if changed {
+        utils::save_and_sync(&roadmap)?;
+        for task in roadmap.tasks.iter().filter(|t| completed_ids.contains(&t.id)) {
+            hooks::run_post_hook("post-complete", task);
+        }
+    }
+    Ok(())
+}
+
+/// Task IDs a commit message closes, e.g. `closes rask:#12` or `fixes rask:#3`
+fn closing_references(message: &str) -> Vec<usize> {
+    let mut ids: Vec<usize> = CLOSING_REFERENCE.captures_iter(message)
+        .filter_map(|cap| cap[1].parse().ok())
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// Explain why a task cannot be closed by a commit, if it can't
+fn closing_problem(roadmap: &Roadmap, task_id: usize) -> Option<String> {
+    let Some(task) = roadmap.find_task_by_id(task_id) else {
+        return Some(format!("Task #{} does not exist", task_id));
+    };
+    if task.status == TaskStatus::Completed {
+        return None;
+    }
+
+    let completed_ids = roadmap.get_completed_task_ids();
+    let incomplete: Vec<String> = task.dependencies.iter()
+        .filter(|dep| !completed_ids.contains(dep))
+        .map(|dep| format!("#{}", dep))
+        .collect();
+    (!incomplete.is_empty()).then(|| format!(
+        "Task #{} cannot be closed: dependencies {} are not completed",
+        task_id,
+        incomplete.join(", ")
+    ))
+}
+
+/// Shell script for a hook that runs a hidden `rask git` subcommand from the workspace
+fn hook_script(rask_exe: &str, workspace: &Path, subcommand: &str) -> String {
+    format!(
+        "#!/bin/sh\n\
+        {marker}: installed by `rask git install-hooks`, safe to delete\n\
+        case \"$1\" in /*) msg_file=\"$1\" ;; *) msg_file=\"$PWD/$1\" ;; esac\n\
+        RASK='{exe}'\n\
+        [ -x \"$RASK\" ] || RASK=rask\n\
+        command -v \"$RASK\" >/dev/null 2>&1 || exit 0\n\
+        cd '{workspace}' || exit 0\n\
+        exec \"$RASK\" --non-interactive git {subcommand}\n",
+        marker = HOOK_MARKER,
+        exe = rask_exe.replace('\'', "'\\''"),
+        workspace = workspace.display().to_string().replace('\'', "'\\''"),
+        subcommand = subcommand,
+    )
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> std::io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// Build a branch name from a task, e.g. `task/42-implement-oauth-login`
+fn branch_name(prefix: &str, task_id: usize, description: &str) -> String {
+    let mut slug = String::new();
+    for word in description
+        .to_lowercase()
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        if !slug.is_empty() && slug.len() + word.len() + 1 > BRANCH_SLUG_MAX_LEN {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(word);
+    }
+    slug.truncate(BRANCH_SLUG_MAX_LEN);
+
+    let prefix = prefix.trim_matches('/');
+    match (prefix.is_empty(), slug.is_empty()) {
+        (true, true) => task_id.to_string(),
+        (true, false) => format!("{}-{}", task_id, slug),
+        (false, true) => format!("{}/{}", prefix, task_id),
+        (false, false) => format!("{}/{}-{}", prefix, task_id, slug),
+    }
+}
+
+/// Run a git command and return its stdout
+fn run_git(args: &[&str]) -> Result<String, Box<dyn std::error::Error>> {
+    let output = Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| format!("Failed to run git: {}", e))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("git {} failed: {}", args.first().unwrap_or(&""), stderr.trim()).into());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
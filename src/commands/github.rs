@@ -0,0 +1,174 @@
+//! GitHub Issues push
+//!
+//! `rask github push` mirrors the tasks carrying a tag (`github` by default)
+//! as issues of one repository. Pending tasks without an issue get one, and
+//! the issue number is kept in `Task::external_refs`. Linked issues take the
+//! task's description, notes and tags on every push. The open/closed state is
+//! synced both ways: a task completed in Rask closes its issue, and an issue
+//! closed on GitHub completes its task. Reopening is not synced.
+
+use crate::cli::GithubCommands;
+use crate::config::RaskConfig;
+use crate::integrations::github::{GithubClient, IssueContent, GITHUB_API, REF_KEY};
+use crate::model::{Task, TaskStatus};
+use crate::{hooks, state, ui};
+use super::{utils, CommandResult};
+use colored::*;
+
+/// Handle GitHub commands
+pub fn handle_github_command(github_command: &GithubCommands) -> CommandResult {
+    match github_command {
+        GithubCommands::Push { repo, tag, dry_run } => push_github(repo.as_deref(), tag, *dry_run),
+    }
+}
+
+/// What a task's issue should contain
+fn issue_content(task: &Task, push_tag: &str) -> IssueContent {
+    let mut body = String::new();
+    if let Some(notes) = task.notes.as_deref().filter(|notes| !notes.trim().is_empty()) {
+        body.push_str(notes.trim());
+        body.push_str("\n\n");
+    }
+    if !task.acceptance_criteria.is_empty() {
+        body.push_str("**Acceptance criteria**\n\n");
+        for criterion in &task.acceptance_criteria {
+            body.push_str(&format!("- {}\n", criterion));
+        }
+        body.push('\n');
+    }
+    body.push_str(&format!("**Priority:** {} · **Phase:** {}\n\n", task.priority, task.phase.name));
+    body.push_str(&format!(
+        "---\n_Synced from rask task #{} (`{}`). Title and body are overwritten by the next `rask github push`._",
+        task.id, task.uuid
+    ));
+
+    let mut labels: Vec<String> = task.tags.iter()
+        .filter(|tag| !tag.eq_ignore_ascii_case(push_tag))
+        .cloned()
+        .collect();
+    labels.sort();
+
+    IssueContent {
+        title: task.description.clone(),
+        body,
+        labels,
+        closed: task.status == TaskStatus::Completed,
+    }
+}
+
+/// Create or update the GitHub issues of tagged tasks and sync their state
+pub fn push_github(repo: Option<&str>, tag: &str, dry_run: bool) -> CommandResult {
+    let config = RaskConfig::load()?;
+    let repo = repo.map(str::to_string).or_else(|| config.github.repo.clone())
+        .ok_or("No repository given. Use --repo owner/name or 'rask config set github.repo owner/name'")?;
+    let token = config.github.resolve_token()
+        .ok_or("GitHub token is not set. Set github.token or the GITHUB_TOKEN environment variable")?;
+    let client = GithubClient::new(config.github.api_url.as_deref().unwrap_or(GITHUB_API), &repo, &token)?;
+
+    let mut roadmap = state::load_state()?;
+    let tagged: Vec<usize> = roadmap.tasks.iter().enumerate()
+        .filter(|(_, task)| task.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+        .map(|(index, _)| index)
+        .collect();
+    if tagged.is_empty() {
+        ui::display_info(&format!("No tasks tagged #{} to push. Tag tasks with 'rask bulk add-tags <ids> {}'", tag, tag));
+        return Ok(());
+    }
+
+    let title = if dry_run { "GitHub Push Preview (dry run)" } else { "GitHub Push" };
+    println!("\n{} {}", title.bold().bright_cyan(), format!("({})", client.repo()).bright_black());
+    println!("{}", "═".repeat(60).bright_blue());
+
+    let (mut created, mut updated, mut completed, mut skipped, mut failed) = (0, 0, 0, 0, 0);
+    let mut completed_ids = Vec::new();
+    for index in tagged {
+        let id = roadmap.tasks[index].id;
+        let linked = roadmap.tasks[index].external_refs.get(REF_KEY).and_then(|number| number.parse::<u64>().ok());
+
+        let Some(number) = linked else {
+            if roadmap.tasks[index].status == TaskStatus::Completed {
+                skipped += 1;
+                continue;
+            }
+            println!("  {} #{} {} {}", "+".bright_green().bold(), id, roadmap.tasks[index].description, "(new issue)".bright_black());
+            if !dry_run {
+                match client.create_issue(&issue_content(&roadmap.tasks[index], tag)) {
+                    Ok(issue) => {
+                        let task = &mut roadmap.tasks[index];
+                        task.external_refs.insert(REF_KEY.to_string(), issue.number.to_string());
+                        task.record_history("linked", Some(format!("GitHub issue #{} ({})", issue.number, issue.html_url)));
+                        println!("      {}", issue.html_url.bright_black());
+                    }
+                    Err(e) => {
+                        failed += 1;
+                        ui::display_warning(&format!("Could not create an issue for task #{}: {}", id, e));
+                        continue;
+                    }
+                }
+            }
+            created += 1;
+            continue;
+        };
+
+        let issue = match client.get_issue(number) {
+            Ok(issue) => issue,
+            Err(e) => {
+                failed += 1;
+                ui::display_warning(&format!("Could not read issue #{} of task #{}: {}", number, id, e));
+                continue;
+            }
+        };
+        let mut changes = Vec::new();
+        let task = &mut roadmap.tasks[index];
+        if issue.is_closed() && task.status == TaskStatus::Pending {
+            match if dry_run { Ok(()) } else { hooks::run_pre_hook("pre-complete", task) } {
+                Ok(()) => {
+                    task.mark_completed();
+                    task.record_history("completed", Some(format!("GitHub issue #{} was closed", number)));
+                    completed += 1;
+                    completed_ids.push(id);
+                    changes.push("completed from GitHub".to_string());
+                }
+                Err(reason) => ui::display_warning(&format!("{} - not completed", reason)),
+            }
+        } else if !issue.is_closed() && task.status == TaskStatus::Completed {
+            changes.push("closing issue".to_string());
+        }
+        if !dry_run {
+            if let Err(e) = client.update_issue(number, &issue_content(task, tag)) {
+                failed += 1;
+                ui::display_warning(&format!("Could not update issue #{} of task #{}: {}", number, id, e));
+                continue;
+            }
+        }
+        updated += 1;
+        let changes = if changes.is_empty() { String::new() } else { format!(", {}", changes.join(", ")) };
+        println!("  {} #{} → issue #{} {}", "~".bright_yellow().bold(), id, number, format!("(updated{})", changes).bright_black());
+    }
+
+    println!("{}", "─".repeat(60).bright_black());
+    println!("  {} created, {} updated, {} completed from GitHub\n",
+        created.to_string().bright_green().bold(),
+        updated.to_string().bright_yellow().bold(),
+        completed.to_string().bright_cyan().bold()
+    );
+    if skipped > 0 {
+        ui::display_info(&format!("{} completed task(s) without an issue were left out", skipped));
+    }
+
+    if dry_run {
+        ui::display_info("Dry run - no issues were changed and nothing was saved. Re-run without --dry-run to push.");
+        return Ok(());
+    }
+    if created + completed > 0 {
+        utils::save_and_sync(&roadmap)?;
+        for task in roadmap.tasks.iter().filter(|t| completed_ids.contains(&t.id)) {
+            hooks::run_post_hook("post-complete", task);
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} task(s) could not be pushed to GitHub; run the push again to retry", failed).into());
+    }
+    ui::display_success(&format!("Pushed {} task(s) to {}", created + updated, client.repo()));
+    Ok(())
+}
@@ -0,0 +1,280 @@
+//! Import commands
+//!
+//! This module wires the importers in `crate::importers` into the CLI: it
+//! fetches items from the chosen source, shows a diff against the current
+//! roadmap, and only writes state when not running in dry-run mode.
+
+use crate::{
+    cli::ImportCommands,
+    importers::{csv::CsvImporter, github::GithubImporter, json::JsonImporter, ImportedItem, Importer},
+    model::{Roadmap, Task, TaskStatus},
+    state, ui
+};
+use super::{CommandResult, utils};
+use colored::*;
+use std::collections::{HashMap, HashSet};
+
+/// Outcome for a single imported item
+enum ImportAction {
+    Create(Box<Task>),
+    Skip { description: String, reason: String },
+}
+
+/// Handle import commands
+pub fn handle_import_command(import_command: &ImportCommands) -> CommandResult {
+    let (importer, dry_run): (Box<dyn Importer>, bool) = match import_command {
+        ImportCommands::Json { file, dry_run } => (Box::new(JsonImporter::new(file.clone())), *dry_run),
+        ImportCommands::Csv { file, map, dry_run } => (Box::new(CsvImporter::new(file.clone(), map.as_deref())?), *dry_run),
+        ImportCommands::Github { repo, state, token, dry_run } => {
+            let token = token.clone().or_else(|| crate::config::RaskConfig::load().ok().and_then(|config| config.github.resolve_token()));
+            (Box::new(GithubImporter::new(repo, state, token)?), *dry_run)
+        }
+    };
+
+    run_import(importer.as_ref(), dry_run)
+}
+
+/// Fetch items from an importer, report the diff and apply it
+fn run_import(importer: &dyn Importer, dry_run: bool) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+
+    ui::display_info(&format!("📥 Reading items from {}...", importer.source_name()));
+    let items = importer.fetch_items()
+        .map_err(|e| format!("Import failed: {}", e))?;
+
+    if items.is_empty() {
+        ui::display_warning("No items found to import.");
+        return Ok(());
+    }
+
+    let actions = plan_import(&roadmap, &items);
+    display_import_diff(&actions, dry_run);
+
+    let skipped = actions.iter().filter(|action| matches!(action, ImportAction::Skip { .. })).count();
+    let new_tasks: Vec<Task> = actions.into_iter()
+        .filter_map(|action| match action {
+            ImportAction::Create(task) => Some(*task),
+            ImportAction::Skip { .. } => None,
+        })
+        .collect();
+
+    if dry_run {
+        ui::display_info("Dry run - no changes were written. Re-run without --dry-run to import.");
+        return Ok(());
+    }
+
+    if new_tasks.is_empty() {
+        ui::display_info("Nothing to import.");
+        return Ok(());
+    }
+
+    let count = new_tasks.len();
+    for task in new_tasks {
+        roadmap.add_task(task);
+    }
+    utils::save_and_sync(&roadmap)?;
+
+    let skipped = if skipped > 0 { format!(", skipped {}", skipped) } else { String::new() };
+    ui::display_success(&format!("Imported {} task(s) from {}{}", count, importer.source_name(), skipped));
+    Ok(())
+}
+
+/// Where an imported item ends up before its dependencies are checked
+enum Planned {
+    /// Becomes a new task
+    New,
+    /// Matches a task already in the project
+    Existing(usize),
+    /// Left out for the given reason
+    Skipped(String),
+}
+
+/// A dependency reference resolved to a project task or another imported item
+enum Reference {
+    Task(usize),
+    Item(usize),
+}
+
+/// Decide what to do with each imported item without touching the roadmap
+fn plan_import(roadmap: &Roadmap, items: &[ImportedItem]) -> Vec<ImportAction> {
+    let existing: HashMap<String, usize> = roadmap.tasks.iter()
+        .map(|t| (t.description.trim().to_lowercase(), t.id))
+        .collect();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut seen_uuids: HashSet<&str> = HashSet::new();
+
+    let mut planned: Vec<Planned> = items.iter()
+        .map(|item| {
+            if let Err(reason) = utils::validate_task_description(&item.description) {
+                return Planned::Skipped(reason);
+            }
+            let description = item.description.trim().to_lowercase();
+            if let Some(id) = existing.get(&description) {
+                return Planned::Existing(*id);
+            }
+            if let Some(task) = item.uuid.as_deref().and_then(|uuid| roadmap.tasks.iter().find(|t| t.uuid == uuid)) {
+                return Planned::Existing(task.id);
+            }
+            if !seen.insert(description) {
+                return Planned::Skipped("already exists".to_string());
+            }
+            if item.uuid.as_deref().is_some_and(|uuid| !seen_uuids.insert(uuid)) {
+                return Planned::Skipped("a task with this UUID already exists".to_string());
+            }
+            Planned::New
+        })
+        .collect();
+
+    let mut keys: HashMap<&str, usize> = HashMap::new();
+    for (index, item) in items.iter().enumerate() {
+        if let Some(key) = item.key.as_deref() {
+            keys.entry(key).or_insert(index);
+        }
+    }
+    let resolve = |index: usize, reference: &str| -> Result<Reference, String> {
+        if let Some(id) = reference.strip_prefix('#') {
+            return match id.parse::<usize>().ok().filter(|id| roadmap.find_task_by_id(*id).is_some()) {
+                Some(id) => Ok(Reference::Task(id)),
+                None => Err(format!("depends on unknown task {}", reference)),
+            };
+        }
+        match keys.get(reference) {
+            Some(&target) if target == index => Err("depends on itself".to_string()),
+            Some(&target) => Ok(Reference::Item(target)),
+            None => Err(format!("depends on unknown row '{}'", reference)),
+        }
+    };
+
+    // A task cannot be created when something it depends on is left out
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for index in 0..items.len() {
+            if !matches!(planned[index], Planned::New) {
+                continue;
+            }
+            for reference in &items[index].depends_on {
+                let problem = match resolve(index, reference) {
+                    Ok(Reference::Item(target)) if matches!(planned[target], Planned::Skipped(_)) => {
+                        Some(format!("depends on skipped row '{}'", reference))
+                    }
+                    Ok(_) => None,
+                    Err(reason) => Some(reason),
+                };
+                if let Some(reason) = problem {
+                    planned[index] = Planned::Skipped(reason);
+                    changed = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    // Rows left over once every row whose dependencies are satisfied has been taken form cycles
+    let mut placed: HashSet<usize> = HashSet::new();
+    loop {
+        let ready: Vec<usize> = (0..items.len())
+            .filter(|index| matches!(planned[*index], Planned::New) && !placed.contains(index))
+            .filter(|index| items[*index].depends_on.iter().all(|reference| match resolve(*index, reference) {
+                Ok(Reference::Item(target)) => !matches!(planned[target], Planned::New) || placed.contains(&target),
+                _ => true,
+            }))
+            .collect();
+        if ready.is_empty() {
+            break;
+        }
+        placed.extend(ready);
+    }
+    for (index, plan) in planned.iter_mut().enumerate() {
+        if matches!(plan, Planned::New) && !placed.contains(&index) {
+            *plan = Planned::Skipped("circular dependency".to_string());
+        }
+    }
+
+    let mut next_id = roadmap.get_next_task_id();
+    let ids: Vec<Option<usize>> = planned.iter()
+        .map(|plan| match plan {
+            Planned::New => {
+                next_id += 1;
+                Some(next_id - 1)
+            }
+            Planned::Existing(id) => Some(*id),
+            Planned::Skipped(_) => None,
+        })
+        .collect();
+
+    items.iter().zip(planned).enumerate()
+        .map(|(index, (item, plan))| {
+            let description = match item.description.trim() {
+                "" => item.source_ref.clone().unwrap_or_default(),
+                description => description.to_string(),
+            };
+            match plan {
+                Planned::New => {
+                    let mut task = item.to_task(ids[index].unwrap_or_default());
+                    for reference in &item.depends_on {
+                        let id = match resolve(index, reference) {
+                            Ok(Reference::Task(id)) => Some(id),
+                            Ok(Reference::Item(target)) => ids[target],
+                            Err(_) => None,
+                        };
+                        if let Some(id) = id.filter(|id| !task.dependencies.contains(id)) {
+                            task.dependencies.push(id);
+                        }
+                    }
+                    ImportAction::Create(Box::new(task))
+                }
+                Planned::Existing(id) => ImportAction::Skip { description, reason: format!("already exists as #{}", id) },
+                Planned::Skipped(reason) => ImportAction::Skip { description, reason },
+            }
+        })
+        .collect()
+}
+
+/// Print the planned changes as a diff-style listing
+fn display_import_diff(actions: &[ImportAction], dry_run: bool) {
+    let title = if dry_run { "Import Preview (dry run)" } else { "Import Changes" };
+    println!("\n{}", title.bold().bright_cyan());
+    println!("{}", "═".repeat(60).bright_blue());
+
+    let mut created = 0;
+    let mut skipped = 0;
+
+    for action in actions {
+        match action {
+            ImportAction::Create(task) => {
+                created += 1;
+                let status = if task.status == TaskStatus::Completed { " ✅" } else { "" };
+                let tags = if task.tags.is_empty() {
+                    String::new()
+                } else {
+                    let mut tags: Vec<_> = task.tags.iter().map(|t| format!("#{}", t)).collect();
+                    tags.sort();
+                    format!(" {}", tags.join(" "))
+                };
+                println!("  {} #{} {} {}{}{}",
+                    "+".bright_green().bold(),
+                    task.id,
+                    task.description.bright_white(),
+                    format!("[{} / {}]", task.phase.name, task.priority).bright_black(),
+                    tags.bright_cyan(),
+                    status
+                );
+            }
+            ImportAction::Skip { description, reason } => {
+                skipped += 1;
+                println!("  {} {} {}",
+                    "=".bright_yellow().bold(),
+                    description.dimmed(),
+                    format!("(skipped: {})", reason).bright_yellow()
+                );
+            }
+        }
+    }
+
+    println!("{}", "─".repeat(60).bright_black());
+    println!("  {} to create, {} skipped\n",
+        created.to_string().bright_green().bold(),
+        skipped.to_string().bright_yellow().bold()
+    );
+}
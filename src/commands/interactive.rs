@@ -5,7 +5,8 @@
 
 use crate::commands::CommandResult;
 use crate::ui::display_info;
-use crate::model::{Roadmap, Task, TaskStatus, Priority, Phase};
+use crate::cli::CliPriority;
+use crate::model::{Energy, Roadmap, Task, TaskStatus, Priority, Phase};
 use serde::{Deserialize, Serialize};
 use crossterm::{
     event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
@@ -25,6 +26,8 @@ use std::{
     io,
     fs,
     path::PathBuf,
+    sync::mpsc,
+    time::Duration,
 };
 use chrono;
 use crate::commands::ai::handle_ai_roadmap;
@@ -113,6 +116,31 @@ pub struct App {
     pub selected_template: Option<usize>,
     /// Selected settings item index
     pub selected_setting: Option<usize>,
+    /// Energy filter for the task list
+    pub energy_filter: Option<Energy>,
+    /// Context filter for the task list (e.g. "@home")
+    pub context_filter: Option<String>,
+    /// AI chat transcript, oldest message first
+    pub chat_messages: Vec<ChatMessage>,
+    /// Message being typed in the chat view
+    pub chat_input: String,
+    /// Chunks of the AI reply currently streaming in
+    pub chat_updates: Option<mpsc::Receiver<ChatUpdate>>,
+}
+
+/// One message in the TUI chat view
+#[derive(Debug, Clone)]
+pub struct ChatMessage {
+    pub from_user: bool,
+    pub text: String,
+}
+
+/// Progress of a streaming AI reply, sent from the request thread
+#[derive(Debug)]
+pub enum ChatUpdate {
+    Chunk(String),
+    Done,
+    Failed(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -121,6 +149,7 @@ pub enum PanelFocus {
     Tasks,
     Templates,
     Settings,
+    Chat,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -129,6 +158,7 @@ pub enum AppView {
     Tasks,
     Templates,
     Settings,
+    Chat,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -137,6 +167,7 @@ pub enum NavigationItem {
     Tasks,
     Templates,
     Settings,
+    Chat,
 }
 
 impl Default for App {
@@ -147,6 +178,7 @@ impl Default for App {
             NavigationItem::Tasks,
             NavigationItem::Templates,
             NavigationItem::Settings,
+            NavigationItem::Chat,
         ];
         
         let initial_view = settings.default_view.clone();
@@ -157,6 +189,7 @@ impl Default for App {
                 (NavigationItem::Tasks, AppView::Tasks) => true,
                 (NavigationItem::Templates, AppView::Templates) => true,
                 (NavigationItem::Settings, AppView::Settings) => true,
+                (NavigationItem::Chat, AppView::Chat) => true,
                 _ => false,
             })
             .unwrap_or(0);
@@ -174,14 +207,119 @@ impl Default for App {
             settings,
             selected_template: None,
             selected_setting: None,
+            energy_filter: None,
+            context_filter: None,
+            chat_messages: Vec::new(),
+            chat_input: String::new(),
+            chat_updates: None,
         }
     }
 }
 
-impl App {}
+impl App {
+    /// Indices into the roadmap's tasks that pass the energy/context filters
+    fn visible_task_indices(&self) -> Vec<usize> {
+        self.roadmap.as_ref().map_or_else(Vec::new, |roadmap| {
+            roadmap.tasks.iter().enumerate()
+                .filter(|(_, task)| task.fits(self.energy_filter, self.context_filter.as_deref()))
+                .map(|(i, _)| i)
+                .collect()
+        })
+    }
+
+    /// Step the energy filter through all, low, medium, high
+    fn cycle_energy_filter(&mut self) {
+        self.energy_filter = match self.energy_filter {
+            None => Some(Energy::Low),
+            Some(Energy::Low) => Some(Energy::Medium),
+            Some(Energy::Medium) => Some(Energy::High),
+            Some(Energy::High) => None,
+        };
+        self.selected_task = None;
+        self.task_scroll_offset = 0;
+    }
+
+    /// Step the context filter through every context used in the project
+    fn cycle_context_filter(&mut self) {
+        let mut contexts: Vec<String> = self.roadmap.as_ref()
+            .map(|r| r.tasks.iter().flat_map(|t| t.contexts.iter().cloned()).collect())
+            .unwrap_or_default();
+        contexts.sort();
+        contexts.dedup();
+        
+        self.context_filter = match &self.context_filter {
+            None => contexts.first().cloned(),
+            Some(current) => contexts.iter()
+                .position(|c| c == current)
+                .and_then(|i| contexts.get(i + 1).cloned()),
+        };
+        self.selected_task = None;
+        self.task_scroll_offset = 0;
+    }
+
+    /// Send the typed message to the AI and stream the reply into the transcript
+    fn send_chat_message(&mut self) {
+        let message = self.chat_input.trim().to_string();
+        if message.is_empty() || self.chat_updates.is_some() {
+            return;
+        }
+        self.chat_input.clear();
+        self.chat_messages.push(ChatMessage { from_user: true, text: message.clone() });
+        self.chat_messages.push(ChatMessage { from_user: false, text: String::new() });
+
+        let project_context = self.roadmap.as_ref().map(crate::ai::service::utils::create_project_context);
+        let (sender, receiver) = mpsc::channel();
+        self.chat_updates = Some(receiver);
+
+        // The TUI loop is synchronous, so the request runs on its own thread
+        std::thread::spawn(move || {
+            let result = tokio::runtime::Runtime::new()
+                .map_err(|e| e.to_string())
+                .and_then(|runtime| runtime.block_on(async {
+                    let config = crate::config::RaskConfig::load().map_err(|e| e.to_string())?;
+                    if !config.ai.is_ready() {
+                        return Err("AI is not configured. Run 'rask ai configure' first.".to_string());
+                    }
+                    let service = crate::ai::service::AiService::new(config).await.map_err(|e| e.to_string())?;
+                    service.start_chat_session(project_context).await.map_err(|e| e.to_string())?;
+                    service.chat_stream(message, |chunk| {
+                        let _ = sender.send(ChatUpdate::Chunk(chunk.to_string()));
+                    }).await.map_err(|e| e.to_string())
+                }));
+            let _ = sender.send(match result {
+                Ok(_) => ChatUpdate::Done,
+                Err(e) => ChatUpdate::Failed(e),
+            });
+        });
+    }
+
+    /// Append any reply chunks that arrived since the last frame
+    fn receive_chat_updates(&mut self) {
+        let Some(receiver) = &self.chat_updates else { return };
+        let mut finished = false;
+        while let Ok(update) = receiver.try_recv() {
+            let reply = self.chat_messages.last_mut();
+            match update {
+                ChatUpdate::Chunk(chunk) => if let Some(reply) = reply { reply.text.push_str(&chunk) },
+                ChatUpdate::Failed(error) => {
+                    if let Some(reply) = reply { reply.text.push_str(&format!("⚠️ {}", error)) }
+                    finished = true;
+                }
+                ChatUpdate::Done => finished = true,
+            }
+        }
+        if finished {
+            self.chat_updates = None;
+        }
+    }
+}
 
 /// Launch the interactive TUI mode
 pub fn run_interactive_mode(project: Option<&str>, no_welcome: bool) -> CommandResult {
+    if super::utils::is_non_interactive() {
+        return Err("The interactive TUI cannot run with --non-interactive".into());
+    }
+    
     display_info("Launching interactive TUI mode...");
     
     let settings = TuiSettings::load();
@@ -202,19 +340,32 @@ pub fn run_interactive_mode(project: Option<&str>, no_welcome: bool) -> CommandR
         }
     };
 
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let mut terminal = enter_tui()?;
 
     // Create app and run it
     let mut app = App::default();
     app.roadmap = roadmap;
     let res = run_app(&mut terminal, app);
 
-    // Restore terminal
+    leave_tui(&mut terminal)?;
+
+    if let Err(err) = res {
+        println!("An error occurred in the TUI: {:?}", err);
+    }
+
+    Ok(())
+}
+
+/// Switch the terminal to raw mode on the alternate screen
+fn enter_tui() -> Result<Terminal<CrosstermBackend<io::Stdout>>, Box<dyn Error>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    Ok(Terminal::new(CrosstermBackend::new(stdout))?)
+}
+
+/// Give the terminal back to the shell
+fn leave_tui(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> Result<(), Box<dyn Error>> {
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -222,26 +373,27 @@ pub fn run_interactive_mode(project: Option<&str>, no_welcome: bool) -> CommandR
         DisableMouseCapture
     )?;
     terminal.show_cursor()?;
-
-    if let Err(err) = res {
-        println!("An error occurred in the TUI: {:?}", err);
-    }
-
     Ok(())
 }
 
 /// Main application loop
 fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<(), Box<dyn Error>> {
     loop {
+        app.receive_chat_updates();
         // Clear terminal if needed for clean render
         terminal.draw(|f| ui(f, &mut app))?;
 
+        // Poll with a timeout so streamed chat replies keep rendering without key presses
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
         if let Event::Key(key) = event::read()? {
             match app.focus {
                 PanelFocus::Navigation => handle_navigation_keys(key, &mut app),
                 PanelFocus::Tasks => handle_tasks_keys(key, &mut app),
                 PanelFocus::Templates => handle_templates_keys(key, &mut app),
                 PanelFocus::Settings => handle_settings_keys(key, &mut app),
+                PanelFocus::Chat => handle_chat_keys(key, &mut app),
             }
         }
 
@@ -276,6 +428,7 @@ fn handle_navigation_keys(key: event::KeyEvent, app: &mut App) {
                     NavigationItem::Tasks => AppView::Tasks,
                     NavigationItem::Templates => AppView::Templates,
                     NavigationItem::Settings => AppView::Settings,
+                    NavigationItem::Chat => AppView::Chat,
                 };
                 
                 // Initialize selections for specific views
@@ -310,6 +463,7 @@ fn handle_navigation_keys(key: event::KeyEvent, app: &mut App) {
                         }
                         PanelFocus::Settings
                     },
+                    AppView::Chat => PanelFocus::Chat,
                     _ => PanelFocus::Navigation,
                 };
             }
@@ -320,6 +474,7 @@ fn handle_navigation_keys(key: event::KeyEvent, app: &mut App) {
                 AppView::Tasks => PanelFocus::Tasks,
                 AppView::Templates => PanelFocus::Templates,
                 AppView::Settings => PanelFocus::Settings,
+                AppView::Chat => PanelFocus::Chat,
                 _ => PanelFocus::Navigation,
             };
         }
@@ -331,7 +486,8 @@ fn handle_navigation_keys(key: event::KeyEvent, app: &mut App) {
 
 /// Handle key events for the Tasks panel
 fn handle_tasks_keys(key: event::KeyEvent, app: &mut App) {
-    let task_count = app.roadmap.as_ref().map_or(0, |r| r.tasks.len());
+    let visible = app.visible_task_indices();
+    let task_count = visible.len();
     match key.code {
         KeyCode::Esc | KeyCode::Tab => app.focus = PanelFocus::Navigation,
         KeyCode::Down => {
@@ -350,8 +506,11 @@ fn handle_tasks_keys(key: event::KeyEvent, app: &mut App) {
                 app.selected_task = None;
             }
         }
+        KeyCode::Char('e') => app.cycle_energy_filter(),
+        KeyCode::Char('c') if !key.modifiers.contains(KeyModifiers::CONTROL) => app.cycle_context_filter(),
         KeyCode::Enter => { // Toggle task status
-            if let (Some(roadmap), Some(idx)) = (&mut app.roadmap, app.selected_task) {
+            let task_idx = app.selected_task.and_then(|idx| visible.get(idx).copied());
+            if let (Some(roadmap), Some(idx)) = (&mut app.roadmap, task_idx) {
                 if let Some(task) = roadmap.tasks.get_mut(idx) {
                     task.status = match task.status {
                         TaskStatus::Pending => TaskStatus::Completed,
@@ -402,6 +561,7 @@ fn handle_templates_keys(key: event::KeyEvent, app: &mut App) {
                         let new_id = roadmap.tasks.iter().map(|t| t.id).max().unwrap_or(0) + 1;
                         let new_task = Task {
                             id: new_id,
+                            uuid: crate::model::new_task_uuid(),
                             description: format!("{}: {}", name, desc),
                             status: TaskStatus::Pending,
                             priority: Priority::Medium,
@@ -416,6 +576,23 @@ fn handle_templates_keys(key: event::KeyEvent, app: &mut App) {
                             implementation_notes: Vec::new(),
                             completed_at: None,
                             ai_info: crate::model::AiTaskInfo::default(),
+                            due_date: None,
+                            acceptance_criteria: Vec::new(),
+                            history: Vec::new(),
+                            linked_commits: Vec::new(),
+                            sync: true,
+                            owners: Vec::new(),
+                            comments: Vec::new(),
+                            energy: None,
+                            contexts: Vec::new(),
+                            dependency_notes: Default::default(),
+                            parent: None,
+                            reviewed_at: None,
+                            snoozed_until: None,
+                            external_refs: Default::default(),
+                            summary: None,
+                            created_at_cached: None,
+                            completed_at_cached: None,
                         };
                         roadmap.tasks.push(new_task);
                         let _ = crate::state::save_state(roadmap);
@@ -449,11 +626,11 @@ fn handle_settings_keys(key: event::KeyEvent, app: &mut App) {
                 match idx {
                     0 => { // Default View
                         let current_idx = match app.settings.default_view {
-                            AppView::Home => 0, AppView::Tasks => 1, AppView::Templates => 2, AppView::Settings => 3,
+                            AppView::Home => 0, AppView::Tasks => 1, AppView::Templates => 2, AppView::Settings => 3, AppView::Chat => 4,
                         };
-                        let next_idx = (current_idx + 1) % 4;
+                        let next_idx = (current_idx + 1) % 5;
                         app.settings.default_view = match next_idx {
-                            0 => AppView::Home, 1 => AppView::Tasks, 2 => AppView::Templates, _ => AppView::Settings,
+                            0 => AppView::Home, 1 => AppView::Tasks, 2 => AppView::Templates, 3 => AppView::Settings, _ => AppView::Chat,
                         };
                     },
                     1 => app.settings.remember_selection = !app.settings.remember_selection,
@@ -466,6 +643,18 @@ fn handle_settings_keys(key: event::KeyEvent, app: &mut App) {
     }
 }
 
+/// Handle key events for the Chat panel. Letters go to the input, so `q` does not quit here.
+fn handle_chat_keys(key: event::KeyEvent, app: &mut App) {
+    match key.code {
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => app.should_quit = true,
+        KeyCode::Esc | KeyCode::Tab => app.focus = PanelFocus::Navigation,
+        KeyCode::Enter => app.send_chat_message(),
+        KeyCode::Backspace => { app.chat_input.pop(); }
+        KeyCode::Char(c) => app.chat_input.push(c),
+        _ => {}
+    }
+}
+
 /// Handle global keys that work in any non-navigation context
 fn handle_global_keys(key: event::KeyEvent, app: &mut App) {
     match key.code {
@@ -491,6 +680,7 @@ fn ui(f: &mut Frame, app: &mut App) {
         AppView::Tasks => render_tasks_view(f, app, main_chunks[1]),
         AppView::Templates => render_templates_view(f, app, main_chunks[1]),
         AppView::Settings => render_settings_view(f, app, main_chunks[1]),
+        AppView::Chat => render_chat_view(f, app, main_chunks[1]),
     }
     
     render_help_text(f, app, main_chunks[2]);
@@ -504,6 +694,7 @@ fn render_navigation_bar(f: &mut Frame, app: &mut App, area: Rect) {
             NavigationItem::Tasks => "Tasks".to_string(),
             NavigationItem::Templates => "Templates".to_string(),
             NavigationItem::Settings => "Settings".to_string(),
+            NavigationItem::Chat => "AI Chat".to_string(),
         }
     }).collect();
 
@@ -583,15 +774,68 @@ fn render_home_view(f: &mut Frame, app: &App, area: Rect) {
 }
 
 /// Render the Task Manager view
+/// TUI color for a color from the theme
+fn tui_color(color: colored::Color) -> Color {
+    match color {
+        colored::Color::Black => Color::Black,
+        colored::Color::Red => Color::Red,
+        colored::Color::Green => Color::Green,
+        colored::Color::Yellow => Color::Yellow,
+        colored::Color::Blue => Color::Blue,
+        colored::Color::Magenta => Color::Magenta,
+        colored::Color::Cyan => Color::Cyan,
+        colored::Color::White => Color::Gray,
+        colored::Color::BrightBlack => Color::DarkGray,
+        colored::Color::BrightRed => Color::LightRed,
+        colored::Color::BrightGreen => Color::LightGreen,
+        colored::Color::BrightYellow => Color::LightYellow,
+        colored::Color::BrightBlue => Color::LightBlue,
+        colored::Color::BrightMagenta => Color::LightMagenta,
+        colored::Color::BrightCyan => Color::LightCyan,
+        colored::Color::BrightWhite => Color::White,
+        colored::Color::TrueColor { r, g, b } => Color::Rgb(r, g, b),
+    }
+}
+
+/// The task's tags, sorted, as spans in their theme colors. Rows drawn with
+/// `highlighted` keep the highlight colors.
+fn tag_spans(task: &Task, highlighted: Option<Style>) -> Vec<Span<'static>> {
+    let theme = crate::ui::theme::theme();
+    let mut tags: Vec<&String> = task.tags.iter().collect();
+    tags.sort();
+    tags.into_iter().map(|tag| {
+        let style = theme.tag_style(tag);
+        let emoji = style.and_then(|style| style.emoji.as_deref()).map(|emoji| theme.icon(emoji)).unwrap_or_default();
+        let color = style.and_then(|style| style.color).map(tui_color).unwrap_or(Color::Magenta);
+        Span::styled(format!(" {}#{}", emoji, tag), highlighted.unwrap_or_else(|| Style::default().fg(color)))
+    }).collect()
+}
+
 fn render_tasks_view(f: &mut Frame, app: &mut App, area: Rect) {
+    let mut filters = Vec::new();
+    if let Some(energy) = app.energy_filter {
+        filters.push(format!("energy ≤ {}", energy));
+    }
+    if let Some(context) = &app.context_filter {
+        filters.push(context.clone());
+    }
+    let title = if filters.is_empty() {
+        " 📝 Task List ".to_string()
+    } else {
+        format!(" 📝 Task List ({}) ", filters.join(", "))
+    };
+    let visible = app.visible_task_indices();
+    
     let block = Block::default()
-        .title(" 📝 Task List ")
+        .title(title)
         .borders(Borders::ALL)
         .border_style(if app.focus == PanelFocus::Tasks { Style::default().fg(Color::Yellow) } else { Style::default() });
     
     let task_items: Vec<ListItem> = if let Some(roadmap) = &app.roadmap {
         if roadmap.tasks.is_empty() {
             vec![ListItem::new("No tasks in this project yet.")]
+        } else if visible.is_empty() {
+            vec![ListItem::new("No tasks match the current filters (e: energy, c: context).")]
         } else {
             // Update max visible tasks based on area height
             app.max_visible_tasks = area.height.saturating_sub(2) as usize;
@@ -605,19 +849,22 @@ fn render_tasks_view(f: &mut Frame, app: &mut App, area: Rect) {
                 }
             }
 
-            roadmap.tasks.iter().enumerate()
+            visible.iter().map(|&idx| &roadmap.tasks[idx]).enumerate()
                 .skip(app.task_scroll_offset)
                 .take(app.max_visible_tasks)
                 .map(|(i, task)| {
                 let status_icon = if task.status == TaskStatus::Completed { "✅" } else { "⏳" };
                 let content = format!("{} #{} {}", status_icon, task.id, task.description);
-                // Fix: compare with the actual task index (i + scroll_offset) not just i
-                let style = if app.selected_task == Some(i + app.task_scroll_offset) {
+                // `enumerate` runs before `skip`, so `i` already includes the scroll offset
+                let selected = app.selected_task == Some(i);
+                let style = if selected {
                     Style::default().bg(Color::Blue).fg(Color::White)
                 } else {
                     Style::default()
                 };
-                ListItem::new(Line::from(Span::styled(content, style)))
+                let mut spans = vec![Span::styled(content, style)];
+                spans.extend(tag_spans(task, selected.then_some(style)));
+                ListItem::new(Line::from(spans))
             }).collect()
         }
     } else {
@@ -710,13 +957,57 @@ fn render_settings_view(f: &mut Frame, app: &mut App, area: Rect) {
     f.render_stateful_widget(list, area, &mut list_state);
 }
 
+/// Render the AI chat view
+fn render_chat_view(f: &mut Frame, app: &mut App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+        .split(area);
+
+    let mut lines: Vec<Line> = Vec::new();
+    if app.chat_messages.is_empty() {
+        lines.push(Line::from(Span::styled("Ask the AI about your project. Replies stream in as they are generated.", Style::default().fg(Color::DarkGray))));
+    }
+    for message in &app.chat_messages {
+        let (label, color) = if message.from_user { ("You: ", Color::Cyan) } else { ("🤖 AI: ", Color::Green) };
+        for (i, text) in message.text.split('\n').enumerate() {
+            let prefix = if i == 0 { label } else { "" };
+            lines.push(Line::from(vec![
+                Span::styled(prefix, Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::raw(text.to_string()),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Keep the newest text in view, estimating how lines wrap
+    let width = chunks[0].width.saturating_sub(2).max(1) as usize;
+    let height = chunks[0].height.saturating_sub(2) as usize;
+    let wrapped: usize = lines.iter().map(|l| l.width().max(1).div_ceil(width)).sum();
+    let scroll = wrapped.saturating_sub(height) as u16;
+
+    let title = if app.chat_updates.is_some() { " 🤖 AI Chat (replying…) " } else { " 🤖 AI Chat " };
+    let transcript = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .wrap(Wrap { trim: false })
+        .scroll((scroll, 0));
+    f.render_widget(transcript, chunks[0]);
+
+    let input = Paragraph::new(format!("{}▏", app.chat_input))
+        .block(Block::default().borders(Borders::ALL).title(" Message ").border_style(
+            if app.focus == PanelFocus::Chat { Style::default().fg(Color::Yellow) } else { Style::default() }
+        ));
+    f.render_widget(input, chunks[1]);
+}
+
 /// Render the footer help text
 fn render_help_text(f: &mut Frame, app: &App, area: Rect) {
     let help_text = match app.focus {
         PanelFocus::Navigation => "↑↓: Navigate menu | Enter: Select view | Tab: Focus content | q: Quit",
-        PanelFocus::Tasks => "↑↓: Navigate tasks | Enter: Toggle status | Tab/Esc: Back to navigation | q: Quit",
+        PanelFocus::Tasks => "↑↓: Navigate tasks | Enter: Toggle status | e: Energy filter | c: Context filter | Tab/Esc: Back to navigation | q: Quit",
         PanelFocus::Templates => "↑↓: Select template | Enter: Apply template | Tab/Esc: Back to navigation | q: Quit",
         PanelFocus::Settings => "↑↓: Select setting | Enter: Change value | Tab/Esc: Back to navigation | q: Quit",
+        PanelFocus::Chat => "Type a message | Enter: Send | Tab/Esc: Back to navigation | Ctrl+C: Quit",
     };
     let help = Paragraph::new(help_text).style(Style::default().fg(Color::DarkGray));
     f.render_widget(help, area);
@@ -727,4 +1018,231 @@ fn display_welcome_message() {
     println!("\n🚀 Welcome to Rask Interactive Mode!");
     println!("   Manage your project tasks efficiently from the terminal.");
     println!("   Loading TUI...\n");
-}
\ No newline at end of file
+}
+
+/// What `rask bulk select` does with the checked tasks
+#[derive(Debug, Clone)]
+pub enum BulkAction {
+    Complete,
+    AddTags(String),
+    SetPriority(CliPriority),
+    Remove,
+}
+
+/// The tasks checked in `rask bulk select` and what to do with them
+#[derive(Debug, Clone)]
+pub struct BulkChoice {
+    pub ids: Vec<usize>,
+    pub action: BulkAction,
+}
+
+const BULK_ACTIONS: &[&str] = &["✅ Complete", "🏷️  Add tags", "⚡ Set priority", "🗑️  Remove"];
+const BULK_PRIORITIES: &[CliPriority] = &[CliPriority::Low, CliPriority::Medium, CliPriority::High, CliPriority::Critical];
+
+/// Step of the bulk selection checklist
+#[derive(Debug, Clone, PartialEq)]
+enum SelectStage {
+    Tasks,
+    Action,
+    Tags,
+    Priority,
+}
+
+/// State of the `rask bulk select` checklist
+struct BulkSelectApp<'a> {
+    tasks: &'a [&'a Task],
+    checked: Vec<bool>,
+    cursor: ListState,
+    stage: SelectStage,
+    menu: ListState,
+    tag_input: String,
+    cancelled: bool,
+    chosen: Option<BulkAction>,
+}
+
+impl BulkSelectApp<'_> {
+    fn checked_ids(&self) -> Vec<usize> {
+        self.tasks.iter().zip(&self.checked)
+            .filter(|(_, &checked)| checked)
+            .map(|(task, _)| task.id)
+            .collect()
+    }
+
+    fn open_menu(&mut self, stage: SelectStage) {
+        self.stage = stage;
+        self.menu.select(Some(0));
+    }
+}
+
+/// Pick tasks from a checklist, then an action to apply to them.
+///
+/// Returns `None` if the checklist was closed without choosing an action.
+pub fn run_bulk_select(tasks: &[&Task]) -> Result<Option<BulkChoice>, Box<dyn Error>> {
+    if super::utils::is_non_interactive() {
+        return Err("'rask bulk select' cannot run with --non-interactive; pass task IDs or --filter to a bulk command instead".into());
+    }
+    
+    let mut app = BulkSelectApp {
+        tasks,
+        checked: vec![false; tasks.len()],
+        cursor: ListState::default(),
+        stage: SelectStage::Tasks,
+        menu: ListState::default(),
+        tag_input: String::new(),
+        cancelled: false,
+        chosen: None,
+    };
+    app.cursor.select(Some(0));
+    
+    let mut terminal = enter_tui()?;
+    let res = run_bulk_select_app(&mut terminal, &mut app);
+    leave_tui(&mut terminal)?;
+    res?;
+    
+    if app.cancelled {
+        return Ok(None);
+    }
+    Ok(app.chosen.take().map(|action| BulkChoice { ids: app.checked_ids(), action }))
+}
+
+fn run_bulk_select_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut BulkSelectApp) -> Result<(), Box<dyn Error>> {
+    while !app.cancelled && app.chosen.is_none() {
+        terminal.draw(|f| render_bulk_select(f, app))?;
+        if let Event::Key(key) = event::read()? {
+            if key.kind == event::KeyEventKind::Release {
+                continue;
+            }
+            handle_bulk_select_keys(key, app);
+        }
+    }
+    Ok(())
+}
+
+/// Move a list cursor up or down, wrapping around
+fn step_cursor(state: &mut ListState, len: usize, down: bool) {
+    if len == 0 {
+        return;
+    }
+    let current = state.selected().unwrap_or(0);
+    state.select(Some(if down { (current + 1) % len } else { (current + len - 1) % len }));
+}
+
+fn handle_bulk_select_keys(key: event::KeyEvent, app: &mut BulkSelectApp) {
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.cancelled = true;
+        return;
+    }
+    
+    match app.stage {
+        SelectStage::Tasks => match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => app.cancelled = true,
+            KeyCode::Down | KeyCode::Char('j') => step_cursor(&mut app.cursor, app.tasks.len(), true),
+            KeyCode::Up | KeyCode::Char('k') => step_cursor(&mut app.cursor, app.tasks.len(), false),
+            KeyCode::Char(' ') => {
+                if let Some(checked) = app.cursor.selected().and_then(|i| app.checked.get_mut(i)) {
+                    *checked = !*checked;
+                }
+            }
+            KeyCode::Char('a') => {
+                let all = app.checked.iter().all(|&checked| checked);
+                app.checked.iter_mut().for_each(|checked| *checked = !all);
+            }
+            KeyCode::Enter if app.checked.contains(&true) => app.open_menu(SelectStage::Action),
+            _ => {}
+        },
+        SelectStage::Action => match key.code {
+            KeyCode::Esc => app.stage = SelectStage::Tasks,
+            KeyCode::Down | KeyCode::Char('j') => step_cursor(&mut app.menu, BULK_ACTIONS.len(), true),
+            KeyCode::Up | KeyCode::Char('k') => step_cursor(&mut app.menu, BULK_ACTIONS.len(), false),
+            KeyCode::Enter => match app.menu.selected() {
+                Some(0) => app.chosen = Some(BulkAction::Complete),
+                Some(1) => app.stage = SelectStage::Tags,
+                Some(2) => app.open_menu(SelectStage::Priority),
+                Some(3) => app.chosen = Some(BulkAction::Remove),
+                _ => {}
+            },
+            _ => {}
+        },
+        SelectStage::Tags => match key.code {
+            KeyCode::Esc => app.open_menu(SelectStage::Action),
+            KeyCode::Enter if !app.tag_input.trim().is_empty() => {
+                app.chosen = Some(BulkAction::AddTags(app.tag_input.trim().to_string()));
+            }
+            KeyCode::Backspace => { app.tag_input.pop(); }
+            KeyCode::Char(c) => app.tag_input.push(c),
+            _ => {}
+        },
+        SelectStage::Priority => match key.code {
+            KeyCode::Esc => app.open_menu(SelectStage::Action),
+            KeyCode::Down | KeyCode::Char('j') => step_cursor(&mut app.menu, BULK_PRIORITIES.len(), true),
+            KeyCode::Up | KeyCode::Char('k') => step_cursor(&mut app.menu, BULK_PRIORITIES.len(), false),
+            KeyCode::Enter => {
+                app.chosen = app.menu.selected().and_then(|i| BULK_PRIORITIES.get(i)).cloned().map(BulkAction::SetPriority);
+            }
+            _ => {}
+        },
+    }
+}
+
+fn render_bulk_select(f: &mut Frame, app: &mut BulkSelectApp) {
+    let panel_height = match app.stage {
+        SelectStage::Tasks => 0,
+        SelectStage::Tags => 3,
+        SelectStage::Action | SelectStage::Priority => 6,
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(panel_height), Constraint::Length(1)].as_ref())
+        .split(f.size());
+    
+    let items: Vec<ListItem> = app.tasks.iter().zip(&app.checked).map(|(task, &checked)| {
+        let mark = if checked { "[x]" } else { "[ ]" };
+        let status_icon = if task.status == TaskStatus::Completed { "✅" } else { "⏳" };
+        let style = if checked { Style::default().fg(Color::Green) } else { Style::default() };
+        let phase_style = crate::ui::theme::theme().phase_style(&task.phase.name)
+            .and_then(|phase| phase.color)
+            .map_or(style, |color| style.fg(tui_color(color)));
+        let mut spans = vec![
+            Span::styled(format!("{} {} #{} {} ({}, ", mark, status_icon, task.id, task.description, task.priority), style),
+            Span::styled(task.phase.name.clone(), phase_style),
+            Span::styled(")", style),
+        ];
+        spans.extend(tag_spans(task, checked.then_some(style)));
+        ListItem::new(Line::from(spans))
+    }).collect();
+    let selected = app.checked.iter().filter(|&&checked| checked).count();
+    let list = List::new(items)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" ☑️  Select tasks ({} of {} selected) ", selected, app.tasks.len()))
+            .border_style(if app.stage == SelectStage::Tasks { Style::default().fg(Color::Yellow) } else { Style::default() }))
+        .highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+    f.render_stateful_widget(list, chunks[0], &mut app.cursor);
+    
+    match app.stage {
+        SelectStage::Tasks => {}
+        SelectStage::Action | SelectStage::Priority => {
+            let (title, options): (&str, Vec<String>) = if app.stage == SelectStage::Action {
+                (" Apply to the selected tasks ", BULK_ACTIONS.iter().map(|a| a.to_string()).collect())
+            } else {
+                (" Priority ", BULK_PRIORITIES.iter().map(|p| crate::model::Priority::from(p.clone()).to_string()).collect())
+            };
+            let menu = List::new(options.into_iter().map(ListItem::new).collect::<Vec<_>>())
+                .block(Block::default().borders(Borders::ALL).title(title).border_style(Style::default().fg(Color::Yellow)))
+                .highlight_style(Style::default().bg(Color::Blue).fg(Color::White));
+            f.render_stateful_widget(menu, chunks[1], &mut app.menu);
+        }
+        SelectStage::Tags => {
+            let input = Paragraph::new(format!("{}▏", app.tag_input))
+                .block(Block::default().borders(Borders::ALL).title(" Tags to add (comma-separated) ").border_style(Style::default().fg(Color::Yellow)));
+            f.render_widget(input, chunks[1]);
+        }
+    }
+    
+    let help_text = match app.stage {
+        SelectStage::Tasks => "↑↓: Move | Space: Toggle | a: Toggle all | Enter: Choose action | q/Esc: Cancel",
+        SelectStage::Action | SelectStage::Priority => "↑↓: Move | Enter: Apply | Esc: Back",
+        SelectStage::Tags => "Type tags | Enter: Apply | Esc: Back",
+    };
+    f.render_widget(Paragraph::new(help_text).style(Style::default().fg(Color::DarkGray)), chunks[2]);
+}
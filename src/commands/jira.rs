@@ -0,0 +1,166 @@
+//! Jira two-way sync
+//!
+//! `rask sync jira` links tasks to the issues of the configured Jira project
+//! through `Task::external_refs`. Issues without a task become tasks (or get
+//! linked to a task with the same description), and linked tasks take the
+//! issue's summary, priority and labels. Completion flows both ways: a done
+//! issue completes its task, and a completed task moves its issue through a
+//! done transition. Reopening is not synced in either direction.
+
+use crate::config::RaskConfig;
+use crate::importers::sanitize_tag;
+use crate::integrations::jira::{JiraClient, JiraIssue, REF_KEY};
+use crate::model::{Roadmap, TaskStatus};
+use crate::{hooks, state, ui};
+use super::{utils, CommandResult};
+use colored::*;
+use std::collections::HashMap;
+
+/// Apply the issue's fields to its linked task. Returns what changed.
+/// A completion goes through the `pre-complete` hook unless this is a dry run.
+fn update_task(roadmap: &mut Roadmap, index: usize, issue: &JiraIssue, dry_run: bool) -> Vec<String> {
+    let task = &mut roadmap.tasks[index];
+    let mut changes = Vec::new();
+
+    let summary = issue.summary.trim();
+    if !summary.is_empty() && task.description != summary {
+        task.description = summary.to_string();
+        changes.push("summary".to_string());
+    }
+    if let Some(priority) = issue.priority.as_ref().filter(|p| **p != task.priority) {
+        task.priority = priority.clone();
+        changes.push(format!("priority {}", priority));
+    }
+    // Labels are only added; tasks may carry tags of their own
+    let new_tags: Vec<String> = issue.labels.iter()
+        .filter_map(|label| sanitize_tag(label))
+        .filter(|tag| !task.tags.contains(tag))
+        .collect();
+    if !new_tags.is_empty() {
+        changes.push(format!("tags +{}", new_tags.join(" +")));
+        task.tags.extend(new_tags);
+    }
+    if issue.done && task.status == TaskStatus::Pending {
+        match if dry_run { Ok(()) } else { hooks::run_pre_hook("pre-complete", task) } {
+            Ok(()) => {
+                task.mark_completed();
+                task.record_history("completed", Some(format!("{} is {} in Jira", issue.key, issue.status)));
+                changes.push("completed".to_string());
+            }
+            Err(reason) => ui::display_warning(&format!("{} - not completed", reason)),
+        }
+    }
+    changes
+}
+
+/// Sync tasks with the configured Jira project
+pub fn sync_jira(dry_run: bool, include_done: bool) -> CommandResult {
+    let config = RaskConfig::load()?;
+    let client = JiraClient::from_config(&config.jira)?;
+
+    ui::display_info(&format!("🔄 Reading issues of Jira project {}...", client.project_key()));
+    let issues = client.fetch_issues()?;
+    let mut roadmap = state::load_state()?;
+
+    let linked: HashMap<String, usize> = roadmap.tasks.iter().enumerate()
+        .filter_map(|(index, task)| task.external_refs.get(REF_KEY).map(|key| (key.clone(), index)))
+        .collect();
+
+    let title = if dry_run { "Jira Sync Preview (dry run)" } else { "Jira Sync" };
+    println!("\n{}", title.bold().bright_cyan());
+    println!("{}", "═".repeat(60).bright_blue());
+
+    let (mut created, mut updated) = (0, 0);
+    let mut to_close: Vec<(String, usize)> = Vec::new();
+    let mut completed_ids = Vec::new();
+    for issue in &issues {
+        if let Some(&index) = linked.get(&issue.key) {
+            if !issue.done && roadmap.tasks[index].status == TaskStatus::Completed {
+                to_close.push((issue.key.clone(), roadmap.tasks[index].id));
+            }
+            let changes = update_task(&mut roadmap, index, issue, dry_run);
+            if changes.iter().any(|change| change == "completed") {
+                completed_ids.push(roadmap.tasks[index].id);
+            }
+            if !changes.is_empty() {
+                updated += 1;
+                println!("  {} #{} {} {}", "~".bright_yellow().bold(), roadmap.tasks[index].id,
+                    issue.key.bright_white(), format!("({})", changes.join(", ")).bright_black());
+            }
+            continue;
+        }
+
+        // A task with the same description is linked instead of duplicated
+        let same = roadmap.tasks.iter().position(|task| {
+            !task.external_refs.contains_key(REF_KEY)
+                && task.description.trim().eq_ignore_ascii_case(issue.summary.trim())
+        });
+        if let Some(index) = same {
+            roadmap.tasks[index].external_refs.insert(REF_KEY.to_string(), issue.key.clone());
+            roadmap.tasks[index].record_history("linked", Some(format!("Jira {}", issue.key)));
+            if !issue.done && roadmap.tasks[index].status == TaskStatus::Completed {
+                to_close.push((issue.key.clone(), roadmap.tasks[index].id));
+            }
+            let changes = update_task(&mut roadmap, index, issue, dry_run);
+            if changes.iter().any(|change| change == "completed") {
+                completed_ids.push(roadmap.tasks[index].id);
+            }
+            updated += 1;
+            let changes = if changes.is_empty() { String::new() } else { format!(", {}", changes.join(", ")) };
+            println!("  {} #{} {} {}", "=".bright_cyan().bold(), roadmap.tasks[index].id,
+                issue.key.bright_white(), format!("(linked{})", changes).bright_black());
+            continue;
+        }
+
+        if issue.done && !include_done {
+            continue;
+        }
+        if let Err(reason) = utils::validate_task_description(&issue.summary) {
+            println!("  {} {} {}", "!".bright_red().bold(), issue.key.bright_white(), format!("(skipped: {})", reason).bright_yellow());
+            continue;
+        }
+        let mut task = issue.to_item().to_task(roadmap.get_next_task_id());
+        task.external_refs.insert(REF_KEY.to_string(), issue.key.clone());
+        println!("  {} #{} {} {}", "+".bright_green().bold(), task.id, issue.key.bright_white(), task.description);
+        roadmap.add_task(task);
+        created += 1;
+    }
+
+    for (key, id) in &to_close {
+        println!("  {} #{} {} {}", "→".bright_magenta().bold(), id, key.bright_white(), "(close in Jira)".bright_black());
+    }
+    println!("{}", "─".repeat(60).bright_black());
+    println!("  {} created, {} updated, {} to close in Jira\n",
+        created.to_string().bright_green().bold(),
+        updated.to_string().bright_yellow().bold(),
+        to_close.len().to_string().bright_magenta().bold()
+    );
+
+    if dry_run {
+        ui::display_info("Dry run - nothing was saved or sent to Jira. Re-run without --dry-run to sync.");
+        return Ok(());
+    }
+    if created + updated > 0 {
+        utils::save_and_sync(&roadmap)?;
+        for task in roadmap.tasks.iter().filter(|t| completed_ids.contains(&t.id)) {
+            hooks::run_post_hook("post-complete", task);
+        }
+    }
+
+    let mut failed = 0;
+    for (key, id) in &to_close {
+        match client.close_issue(key) {
+            Ok(transition) => ui::display_success(&format!("Closed {} for task #{} ({})", key, id, transition)),
+            Err(e) => {
+                failed += 1;
+                ui::display_warning(&format!("Could not close {} for task #{}: {}", key, id, e));
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(format!("{} of {} Jira issues could not be closed; they will be retried on the next sync", failed, to_close.len()).into());
+    }
+
+    ui::display_success(&format!("Jira project {} is in sync", client.project_key()));
+    Ok(())
+}
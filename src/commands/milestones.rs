@@ -0,0 +1,149 @@
+//! Milestone commands
+//!
+//! A milestone is a named target date tracked by the tasks of a phase, a set
+//! of tags, or both. Progress is computed from those tasks whenever it is
+//! shown; `show` and `analytics` count down to open milestones and flag the
+//! ones falling behind.
+
+use crate::cli::MilestoneCommands;
+use crate::model::{Milestone, MilestoneStatus, Task};
+use crate::{state, ui};
+use super::{utils, CommandError, CommandResult};
+use colored::Colorize;
+
+/// Handle milestone subcommands
+pub fn handle_milestone_command(milestone_command: &MilestoneCommands) -> CommandResult {
+    match milestone_command {
+        MilestoneCommands::Add { name, target, phase, tag, description } => {
+            add_milestone(name, target, phase.as_deref(), tag.as_deref(), description.as_deref())
+        }
+        MilestoneCommands::List => list_milestones(),
+        MilestoneCommands::Show { name } => show_milestone(name),
+        MilestoneCommands::Remove { name } => remove_milestone(name),
+    }
+}
+
+/// Add a milestone tracked by a phase and/or tags
+pub fn add_milestone(name: &str, target: &str, phase: Option<&str>, tags: Option<&str>, description: Option<&str>) -> CommandResult {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Milestone name cannot be empty".into());
+    }
+    let target_date = utils::parse_due_date(target)?;
+    let tags = match tags {
+        Some(tags) => utils::validate_and_parse_tags(tags)?,
+        None => Vec::new(),
+    };
+    if phase.is_none() && tags.is_empty() {
+        return Err("Link the milestone to its tasks with --phase, --tag, or both".into());
+    }
+
+    let mut roadmap = state::load_state()?;
+    if roadmap.find_milestone(name).is_some() {
+        return Err(format!("A milestone named '{}' already exists", name).into());
+    }
+    let milestone = Milestone {
+        name: name.to_string(),
+        target_date: target_date.format("%Y-%m-%d").to_string(),
+        phase: phase.map(|p| roadmap.phase_named(p).name),
+        tags,
+        description: description.map(|d| d.to_string()),
+        created_at: chrono::Utc::now().to_rfc3339(),
+    };
+    let progress = milestone.progress(&roadmap, chrono::Local::now().date_naive());
+    roadmap.milestones.push(milestone);
+    state::save_state(&roadmap)?;
+
+    ui::display_success(&format!(
+        "🏁 Added milestone '{}' for {} ({} tasks tracked)",
+        name, progress.target_date, progress.total_tasks
+    ));
+    Ok(())
+}
+
+/// List milestones, soonest first
+pub fn list_milestones() -> CommandResult {
+    let roadmap = state::load_state()?;
+    let progress = roadmap.milestone_progress(chrono::Local::now().date_naive());
+    if utils::json_output() {
+        return utils::print_json(&progress);
+    }
+    if progress.is_empty() {
+        ui::display_info("No milestones yet. Add one with: rask milestone add <name> --target <date> --phase <phase>");
+        return Ok(());
+    }
+
+    ui::display_info(&format!("🏁 Milestones ({})", progress.len()));
+    println!();
+    for milestone in &progress {
+        println!("  {}", ui::format_milestone_line(milestone));
+    }
+    println!();
+    Ok(())
+}
+
+/// Show one milestone with its progress and tasks
+pub fn show_milestone(name: &str) -> CommandResult {
+    let roadmap = state::load_state()?;
+    let milestone = roadmap.find_milestone(name)
+        .ok_or_else(|| CommandError::NotFound(format!("No milestone named '{}'", name)))?;
+    let progress = milestone.progress(&roadmap, chrono::Local::now().date_naive());
+    let tasks: Vec<&Task> = roadmap.tasks.iter().filter(|t| milestone.includes(t)).collect();
+
+    if utils::json_output() {
+        return utils::print_json(&serde_json::json!({
+            "milestone": milestone,
+            "progress": progress,
+            "tasks": tasks,
+        }));
+    }
+
+    println!("\n{}", "═".repeat(60).bright_blue());
+    println!("  {}", ui::format_milestone_line(&progress));
+    println!("{}", "═".repeat(60).bright_blue());
+    if let Some(description) = &milestone.description {
+        println!("  {}", description);
+    }
+    let mut scope = Vec::new();
+    if let Some(phase) = &milestone.phase {
+        scope.push(format!("phase {}", phase));
+    }
+    if !milestone.tags.is_empty() {
+        scope.push(format!("tags {}", milestone.tags.join(", ")));
+    }
+    println!("  Tracks {}", scope.join(" and "));
+    ui::progress::display_progress_bar(progress.completed_tasks, progress.total_tasks);
+
+    match progress.status {
+        MilestoneStatus::AtRisk => ui::display_warning(&format!(
+            "Behind schedule: {}% done with {} days left",
+            progress.completion_percentage, progress.days_left
+        )),
+        MilestoneStatus::Overdue => ui::display_warning(&format!(
+            "Target date passed {} days ago with {} tasks still open",
+            -progress.days_left,
+            progress.total_tasks - progress.completed_tasks
+        )),
+        _ => {}
+    }
+
+    if tasks.is_empty() {
+        println!("\n  No tasks are linked to this milestone yet.");
+    } else {
+        ui::display_filtered_tasks(&roadmap, &tasks, false);
+    }
+    Ok(())
+}
+
+/// Remove a milestone
+pub fn remove_milestone(name: &str) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    let before = roadmap.milestones.len();
+    roadmap.milestones.retain(|m| !m.name.eq_ignore_ascii_case(name.trim()));
+    if roadmap.milestones.len() == before {
+        return Err(CommandError::NotFound(format!("No milestone named '{}'", name)).into());
+    }
+    state::save_state(&roadmap)?;
+    ui::display_success(&format!("Removed milestone '{}'", name.trim()));
+    Ok(())
+}
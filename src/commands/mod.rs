@@ -6,31 +6,115 @@
 pub mod ai;
 pub mod analytics;
 pub mod core;
+pub mod dashboard;
+pub mod dedupe;
 pub mod bulk;
+pub mod calendar;
+pub mod completions;
 pub mod export;
+pub mod forecast;
+pub mod import;
+pub mod jira;
+pub mod milestones;
+pub mod next;
+pub mod notify;
+pub mod git;
+pub mod github;
+pub mod time_trackers;
 pub mod config;
 pub mod dependencies;
+pub mod estimate;
 pub mod phases;
+pub mod plan;
+pub mod pomodoro;
+pub mod quick;
+pub mod reorder;
+pub mod report;
+pub mod retro;
+pub mod review;
+pub mod rules;
 pub mod notes;
 pub mod templates;
+pub mod timeline;
 pub mod utils;
 pub mod interactive;
+pub mod workspace;
 
 // Re-export all public command functions
 pub use ai::*;
 pub use analytics::*;
 pub use core::*;
+pub use dedupe::*;
 pub use bulk::*;
+pub use calendar::*;
+pub use completions::*;
 pub use export::*;
+pub use forecast::*;
+pub use import::*;
+pub use jira::*;
+pub use milestones::*;
+pub use next::*;
+pub use notify::*;
+pub use git::*;
+pub use github::*;
+pub use time_trackers::*;
 pub use config::*;
 pub use dependencies::*;
+pub use estimate::*;
 pub use phases::*;
+pub use plan::*;
+pub use pomodoro::*;
+pub use quick::*;
+pub use reorder::*;
+pub use report::*;
+pub use retro::*;
+pub use review::*;
+pub use rules::*;
 pub use notes::*;
 pub use templates::*;
+pub use timeline::*;
 pub use interactive::*;
+pub use workspace::*;
 
 // Common types used across all command modules
 pub type CommandResult = Result<(), Box<dyn std::error::Error>>;
 
+/// Failures scripts may want to tell apart, each with its own exit code.
+///
+/// Exit codes: 0 success, 1 any other error, 2 invalid arguments (from clap),
+/// 3 not found, 4 blocked by dependencies.
+#[derive(Debug)]
+pub enum CommandError {
+    /// A task or other item does not exist
+    NotFound(String),
+    /// A task cannot be completed before its dependencies, or a phase is at its WIP limit
+    Blocked(String),
+}
+
+impl CommandError {
+    /// The standard error for a task ID that does not exist
+    pub fn task_not_found(task_id: usize) -> Self {
+        CommandError::NotFound(format!("Task #{} not found", task_id))
+    }
+
+    /// Process exit code for this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CommandError::NotFound(_) => 3,
+            CommandError::Blocked(_) => 4,
+        }
+    }
+}
+
+impl std::fmt::Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::NotFound(message) | CommandError::Blocked(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
 // Re-export CLI types for convenience
 pub use crate::cli::{ConfigCommands, BulkCommands, ExportFormat}; 
\ No newline at end of file
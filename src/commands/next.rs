@@ -0,0 +1,120 @@
+//! "What should I work on next?"
+//!
+//! `rask next` scores every ready task and prints the best few with the
+//! reasons behind each pick. The score adds up priority, how close the due
+//! date is, and how many tasks would become ready once the task is done.
+//! With `--time`, tasks whose remaining estimate does not fit the window are
+//! left out, and the ones that fill it best get a small bonus.
+
+use crate::model::{Roadmap, Task};
+use crate::ui::theme::theme;
+use crate::{state, ui};
+use super::{dependencies, quick, utils, CommandResult};
+use colored::*;
+use serde::Serialize;
+
+/// A ready task with its score and the reasons behind it
+#[derive(Serialize)]
+struct Recommendation<'a> {
+    #[serde(flatten)]
+    task: &'a Task,
+    score: f64,
+    reasons: Vec<String>,
+}
+
+/// Hours of work left on a task, if it has an estimate
+fn remaining_hours(task: &Task) -> Option<f64> {
+    task.estimated_hours.map(|estimate| (estimate - task.actual_hours.unwrap_or(0.0)).max(0.0))
+}
+
+/// Score a ready task. Returns `None` when it does not fit in `window` hours.
+fn score<'a>(task: &'a Task, roadmap: &Roadmap, window: Option<f64>, today: chrono::NaiveDate) -> Option<Recommendation<'a>> {
+    let mut score = f64::from(task.priority.rank()) * 10.0;
+    let mut reasons = vec![format!("{} priority", task.priority.to_string().to_lowercase())];
+
+    if let Some(due) = task.due_date_parsed() {
+        let days = (due - today).num_days();
+        let (points, reason) = match days {
+            d if d < 0 => (30.0, format!("overdue by {} days", -d)),
+            0 => (25.0, "due today".to_string()),
+            1..=3 => (15.0, format!("due in {} days", days)),
+            4..=7 => (8.0, format!("due in {} days", days)),
+            _ => (0.0, String::new()),
+        };
+        score += points;
+        if !reason.is_empty() {
+            reasons.push(reason);
+        }
+    }
+
+    let unblocks = dependencies::find_newly_unblocked_tasks(roadmap, task.id).len();
+    if unblocks > 0 {
+        score += unblocks as f64 * 6.0;
+        reasons.push(format!("unblocks {}", unblocks));
+    }
+
+    match (remaining_hours(task), window) {
+        (Some(hours), Some(window)) if hours > window => return None,
+        (Some(hours), Some(window)) => {
+            // Tasks that use most of the window make the most of it
+            score += 5.0 + 5.0 * hours / window;
+            reasons.push(format!("~{:.1}h fits", hours));
+        }
+        (Some(hours), None) => reasons.push(format!("~{:.1}h", hours)),
+        (None, Some(_)) => reasons.push("no estimate".to_string()),
+        (None, None) => {}
+    }
+
+    Some(Recommendation { task, score, reasons })
+}
+
+/// Suggest the ready tasks most worth doing next, optionally within a time window
+pub fn show_next_tasks(time: Option<&str>, limit: usize) -> CommandResult {
+    let window = time
+        .map(|text| quick::parse_duration(text).ok_or_else(|| format!("Invalid time '{}'. Use e.g. 45m, 2h or 1h30m", text)))
+        .transpose()?;
+    let today = chrono::Local::now().date_naive();
+
+    let roadmap = state::load_state()?;
+    let ready: Vec<&Task> = roadmap.get_ready_tasks().into_iter()
+        .filter(|task| !task.is_snoozed(today))
+        .collect();
+    let mut picks: Vec<Recommendation> = ready.iter()
+        .filter_map(|task| score(task, &roadmap, window, today))
+        .collect();
+    let too_long = ready.len() - picks.len();
+    picks.sort_by(|a, b| b.score.total_cmp(&a.score).then(a.task.id.cmp(&b.task.id)));
+    picks.truncate(limit);
+
+    if utils::json_output() {
+        return utils::print_json(&picks);
+    }
+
+    let window_text = time.map(|text| format!(" in the next {}", text)).unwrap_or_default();
+    if picks.is_empty() {
+        ui::display_info(&format!("🧭 Nothing ready to work on{}", window_text));
+        if too_long > 0 {
+            ui::display_info(&format!("💡 {} ready task{} more time than that", too_long, if too_long == 1 { " needs" } else { "s need" }));
+        } else {
+            ui::display_info("💡 Check 'rask blocked' to see what is holding tasks up");
+        }
+        return Ok(());
+    }
+
+    ui::display_info(&format!("🧭 Suggested next{} ({} of {} ready tasks)", window_text, picks.len(), ready.len()));
+    println!();
+    for (rank, pick) in picks.iter().enumerate() {
+        println!("  {}. {} {} {}",
+            (rank + 1).to_string().bold(),
+            theme().priority_symbol(&pick.task.priority),
+            format!("#{}", pick.task.id).bright_white(),
+            theme().priority_text(&pick.task.priority, &pick.task.description)
+        );
+        println!("     {}", pick.reasons.join(", ").bright_black());
+    }
+    if too_long > 0 {
+        println!("\n  {}", format!("{} ready task{} left out for not fitting the time available", too_long, if too_long == 1 { " was" } else { "s were" }).bright_black());
+    }
+    println!();
+    Ok(())
+}
@@ -1,21 +1,21 @@
-use crate::{state};
-use super::{CommandResult, utils};
+use crate::{model::{NoteKind, TaskComment}, state, wiki_links};
+use super::{CommandError, CommandResult, utils};
 use colored::*;
-use std::io::{self, Write};
 
 /// Add an implementation note to a task
 pub fn add_implementation_note(
     task_id: usize,
     note: String,
+    kind: NoteKind,
 ) -> CommandResult {
     let mut roadmap = state::load_state()?;
     
     // Find the task
     let task = roadmap.find_task_by_id_mut(task_id)
-        .ok_or_else(|| format!("Task with ID {} not found", task_id))?;
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
     
     // Add the implementation note
-    task.add_implementation_note(note.clone());
+    task.add_typed_implementation_note(note.clone(), kind);
     let note_count = task.implementation_notes.len();
     let task_description = task.description.clone();
     
@@ -25,21 +25,22 @@ pub fn add_implementation_note(
     // Display success message
     println!("{}", "✅ Implementation note added successfully!".green());
     println!("📝 Task #{}: {}", task_id, task_description);
-    println!("💡 Added note: {}", note.bright_blue());
+    println!("{} Added {} note: {}", kind.icon(), kind.to_string().to_lowercase(), note.bright_blue());
     println!("📊 Total implementation notes: {}", note_count);
     
     Ok(())
 }
 
-/// List all implementation notes for a task
+/// List implementation notes for a task, optionally only those of one kind
 pub fn list_implementation_notes(
     task_id: usize,
+    kind: Option<NoteKind>,
 ) -> CommandResult {
     let roadmap = state::load_state()?;
     
     // Find the task
     let task = roadmap.find_task_by_id(task_id)
-        .ok_or_else(|| format!("Task with ID {} not found", task_id))?;
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
     
     // Display task information
     println!("\n{}", "📝 Implementation Notes".bright_cyan().bold());
@@ -52,14 +53,24 @@ pub fn list_implementation_notes(
         return Ok(());
     }
     
-    println!("\n📊 {} implementation note(s):", task.implementation_notes.len());
+    // Keep the original indices so they still work with edit/remove
+    let notes: Vec<_> = task.implementation_notes.iter().enumerate()
+        .filter(|(_, note)| kind.is_none_or(|kind| note.kind == kind))
+        .collect();
+    
+    if let (Some(kind), true) = (kind, notes.is_empty()) {
+        println!("\n{}", format!("💡 No {} notes found for this task.", kind.to_string().to_lowercase()).yellow());
+        return Ok(());
+    }
+    
+    println!("\n📊 {} implementation note(s):", notes.len());
     println!("{}", "─".repeat(50).bright_black());
     
-    for (index, note) in task.implementation_notes.iter().enumerate() {
-        println!("\n{} {}:", "📌".bright_blue(), format!("Note #{}", index).bright_white().bold());
+    for (index, note) in notes {
+        println!("\n{} {} {}:", note.kind.icon(), format!("Note #{}", index).bright_white().bold(), format!("[{}]", note.kind).bright_blue());
         
         // Format multi-line notes nicely
-        for line in note.lines() {
+        for line in note.text.lines() {
             if line.trim().is_empty() {
                 println!();
             } else {
@@ -84,7 +95,7 @@ pub fn remove_implementation_note(
     
     // Find the task
     let task = roadmap.find_task_by_id_mut(task_id)
-        .ok_or_else(|| format!("Task with ID {} not found", task_id))?;
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
     
     // Check if index is valid
     if index >= task.implementation_notes.len() {
@@ -108,7 +119,7 @@ pub fn remove_implementation_note(
     // Display success message
     println!("{}", "✅ Implementation note removed successfully!".green());
     println!("📝 Task #{}: {}", task_id, task_description);
-    println!("🗑️  Removed note #{}: {}", index, removed_note.bright_red());
+    println!("🗑️  Removed note #{}: {}", index, removed_note.text.bright_red());
     println!("📊 Remaining implementation notes: {}", remaining_count);
     
     Ok(())
@@ -122,7 +133,7 @@ pub fn clear_implementation_notes(
     
     // Find the task
     let task = roadmap.find_task_by_id_mut(task_id)
-        .ok_or_else(|| format!("Task with ID {} not found", task_id))?;
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
     
     let note_count = task.implementation_notes.len();
     
@@ -132,14 +143,9 @@ pub fn clear_implementation_notes(
     }
     
     // Confirm before clearing
-    print!("⚠️  Are you sure you want to clear all {} implementation notes from task #{}? (y/N): ", 
+    let prompt = format!("⚠️  Are you sure you want to clear all {} implementation notes from task #{}?", 
            note_count, task_id);
-    io::stdout().flush()?;
-    
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    
-    if !input.trim().to_lowercase().starts_with('y') {
+    if !utils::confirm(&prompt)? {
         println!("{}", "❌ Operation cancelled.".yellow());
         return Ok(());
     }
@@ -169,7 +175,7 @@ pub fn edit_implementation_note(
     
     // Find the task
     let task = roadmap.find_task_by_id_mut(task_id)
-        .ok_or_else(|| format!("Task with ID {} not found", task_id))?;
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
     
     // Check if index is valid
     if index >= task.implementation_notes.len() {
@@ -181,11 +187,8 @@ pub fn edit_implementation_note(
         ).into());
     }
     
-    // Store old note for display
-    let old_note = task.implementation_notes[index].clone();
-    
-    // Update the note
-    task.implementation_notes[index] = new_note.clone();
+    // Store old note for display, keeping its kind
+    let old_note = std::mem::replace(&mut task.implementation_notes[index].text, new_note.clone());
     let task_description = task.description.clone();
     
     // Save the roadmap
@@ -201,4 +204,164 @@ pub fn edit_implementation_note(
     Ok(())
 }
 
- 
\ No newline at end of file
+/// Open the `[[Note Name]]` pages linked from an implementation note, or from
+/// the task's own notes, in the editor. Links resolve inside the configured
+/// `advanced.notes_dir`.
+pub fn open_note_links(task_id: usize, index: Option<usize>) -> CommandResult {
+    let roadmap = state::load_state()?;
+    let task = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    
+    let text = match index {
+        Some(index) => &task.implementation_notes.get(index)
+            .ok_or_else(|| format!(
+                "Invalid note index {}. Task has {} implementation notes (indices 0-{})",
+                index,
+                task.implementation_notes.len(),
+                task.implementation_notes.len().saturating_sub(1)
+            ))?
+            .text,
+        None => task.notes.as_deref().unwrap_or_default(),
+    };
+    let links = wiki_links::find_links(text);
+    if links.is_empty() {
+        return Err(match index {
+            Some(index) => format!("Note #{} of task #{} has no [[Note Name]] links", index, task_id),
+            None => format!("The notes of task #{} have no [[Note Name]] links", task_id),
+        }.into());
+    }
+    
+    let notes_dir = wiki_links::notes_dir()
+        .ok_or("No notes directory configured. Point rask at your vault with 'rask config set advanced.notes_dir <dir>'")?;
+    if !notes_dir.is_dir() {
+        return Err(format!("Notes directory not found: {}", notes_dir.display()).into());
+    }
+    
+    let mut files = Vec::new();
+    for link in &links {
+        let file = wiki_links::resolve(&notes_dir, &link.target);
+        if !file.exists() {
+            if let Some(parent) = file.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            println!("📄 [[{}]] → {} {}", link.target, file.display(), "(new note)".dimmed());
+        } else {
+            println!("📄 [[{}]] → {}", link.target, file.display());
+        }
+        if !files.contains(&file) {
+            files.push(file);
+        }
+    }
+    
+    let status = utils::editor_command()?.args(&files).status()
+        .map_err(|e| format!("Could not start the editor: {}", e))?;
+    if !status.success() {
+        return Err(format!("Editor exited with {}", status).into());
+    }
+    Ok(())
+}
+
+/// Add a comment to a task's discussion thread
+pub fn add_task_comment(
+    task_id: usize,
+    text: &str,
+    author: Option<&str>,
+) -> CommandResult {
+    let text = text.trim();
+    if text.is_empty() {
+        return Err("Comment cannot be empty".into());
+    }
+    
+    // Fall back to the configured user, then the login name
+    let author = author.map(str::to_string)
+        .or_else(utils::current_user_name)
+        .or_else(|| std::env::var("USER").ok())
+        .unwrap_or_else(|| "anonymous".to_string());
+    
+    let mut roadmap = state::load_state()?;
+    
+    // Find the task
+    let task = roadmap.find_task_by_id_mut(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    
+    task.comments.push(TaskComment::new(&author, text));
+    let comment_count = task.comments.len();
+    let task_description = task.description.clone();
+    
+    // Save the roadmap
+    utils::save_and_sync(&roadmap)?;
+    
+    // Display success message
+    println!("{}", "✅ Comment added successfully!".green());
+    println!("📝 Task #{}: {}", task_id, task_description);
+    println!("💬 {}: {}", author.bright_white().bold(), text.bright_blue());
+    println!("📊 Total comments: {}", comment_count);
+    
+    Ok(())
+}
+
+/// Show the discussion thread for a task
+pub fn list_task_comments(
+    task_id: usize,
+) -> CommandResult {
+    let roadmap = state::load_state()?;
+    
+    // Find the task
+    let task = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    
+    println!("\n{}", "💬 Comments".bright_cyan().bold());
+    println!("{}", "═".repeat(50).bright_cyan());
+    println!("📋 Task #{}: {}", task_id, task.description.bright_white().bold());
+    
+    if task.comments.is_empty() {
+        println!("\n{}", "💡 No comments yet for this task.".yellow());
+        println!("{}", format!("   Use 'rask comment {} \"<text>\"' to start the discussion.", task_id).dimmed());
+        return Ok(());
+    }
+    
+    println!("{}", "─".repeat(50).bright_black());
+    crate::ui::display_task_comments(&task.comments);
+    println!("{}", "─".repeat(50).bright_black());
+    
+    Ok(())
+}
+
+/// List decision notes from every task in the project, oldest first
+pub fn list_decisions() -> CommandResult {
+    let roadmap = state::load_state()?;
+    
+    let mut decisions: Vec<_> = roadmap.tasks.iter()
+        .flat_map(|task| task.implementation_notes.iter()
+            .filter(|note| note.kind == NoteKind::Decision)
+            .map(move |note| (task, note)))
+        .collect();
+    // Notes from before timestamps were recorded sort first
+    decisions.sort_by(|(a_task, a), (b_task, b)| a.created_at.cmp(&b.created_at).then(a_task.id.cmp(&b_task.id)));
+    
+    println!("\n{}", "⚖️  Decision Log".bright_cyan().bold());
+    println!("{}", "═".repeat(50).bright_cyan());
+    println!("📋 Project: {}", roadmap.title.bright_white().bold());
+    
+    if decisions.is_empty() {
+        println!("\n{}", "💡 No decisions recorded yet.".yellow());
+        println!("{}", "   Use 'rask notes add <task_id> \"<note>\" --type decision' to record one.".dimmed());
+        return Ok(());
+    }
+    
+    println!("{}", "─".repeat(50).bright_black());
+    for (task, note) in &decisions {
+        let when = note.created_at.as_deref()
+            .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok())
+            .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "undated".to_string());
+        println!("\n{} {} {}", when.bright_black(), format!("#{}", task.id).bright_white().bold(), task.description.bright_white());
+        for line in note.text.lines() {
+            println!("   {}", line);
+        }
+    }
+    println!("\n{}", "─".repeat(50).bright_black());
+    println!("📊 {} decision(s) across the project", decisions.len());
+    
+    Ok(())
+}
@@ -0,0 +1,58 @@
+//! Notification commands
+//!
+//! Webhooks are configured as `[[notifications.webhooks]]` and fire on their
+//! own when state is saved (see `crate::notifications`); `rask notify test`
+//! checks that each of them is reachable, and shows a desktop notification
+//! when `notifications.desktop` is on.
+
+use crate::cli::NotifyCommands;
+use crate::config::RaskConfig;
+use crate::{notifications, state, ui};
+use super::CommandResult;
+
+/// Handle notify subcommands
+pub fn handle_notify_command(notify_command: &NotifyCommands) -> CommandResult {
+    match notify_command {
+        NotifyCommands::Test => send_test_notification(),
+    }
+}
+
+/// Post a test message to every configured webhook and the desktop
+pub fn send_test_notification() -> CommandResult {
+    let config = RaskConfig::load()?;
+    let desktop = config.notifications.desktop;
+    if config.notifications.webhooks.is_empty() && !desktop {
+        ui::display_info("No notifications configured. Add a webhook to the config:");
+        println!("\n  [[notifications.webhooks]]\n  url = \"https://hooks.slack.com/services/...\"\n  events = [\"task_completed\", \"milestone_reached\"]\n");
+        ui::display_info("or turn on desktop notifications with 'rask config set notifications.desktop true'");
+        return Ok(());
+    }
+
+    let project = state::load_state().map(|roadmap| roadmap.title).unwrap_or_else(|_| "Rask".to_string());
+    let notification = notifications::test_notification(&project);
+    let mut failed = 0;
+    if desktop {
+        match notifications::show_desktop("🔔 Rask", &notification.render(&config)) {
+            Ok(()) => ui::display_success("Showed a desktop notification"),
+            Err(e) => {
+                failed += 1;
+                ui::display_error(&format!("Desktop: {}", e));
+            }
+        }
+    }
+    for webhook in &config.notifications.webhooks {
+        let host = notifications::webhook_host(webhook);
+        match notifications::send(webhook, &notification, &config) {
+            Ok(()) => ui::display_success(&format!("Sent a test message to {} ({:?})", host, webhook.kind())),
+            Err(e) => {
+                failed += 1;
+                ui::display_error(&format!("{}: {}", host, e));
+            }
+        }
+    }
+    if failed > 0 {
+        let total = config.notifications.webhooks.len() + usize::from(desktop);
+        return Err(format!("{} of {} notification targets failed", failed, total).into());
+    }
+    Ok(())
+}
@@ -2,12 +2,16 @@
 //! 
 //! This module provides functionality for managing task phases including
 //! listing phases, showing tasks by phase, setting task phases, creating custom phases,
-//! and displaying phase overviews.
+//! displaying phase overviews, and archiving completed phases.
+//!
+//! Phases are named on each task; `Roadmap::phases` additionally registers
+//! phases with their details and display order, which `create`, `rename`,
+//! `reorder` and `archive` maintain.
 
-use crate::model::{Phase};
-use crate::state;
+use crate::model::{Phase, PhaseEntry, Roadmap, TaskStatus};
+use crate::{markdown_writer, state};
 use crate::ui;
-use super::CommandResult;
+use super::{CommandError, CommandResult, utils};
 use colored::Colorize;
 
 /// List all phases with their task counts
@@ -27,11 +31,11 @@ pub fn list_phases() -> CommandResult {
     }
     
     for (phase, count) in &stats.tasks_by_phase {
-        let emoji = phase.emoji();
+        let emoji = ui::theme::theme().phase_emoji(phase);
         let description = phase.description();
         let phase_type = if phase.is_predefined() { "predefined" } else { "custom" };
         
-        println!("  {} {} - {} tasks ({})", emoji, phase, count, phase_type);
+        println!("  {} {} - {} tasks ({})", emoji, ui::theme::theme().phase_text(&phase.name, phase.name.normal()), count, phase_type);
         println!("    {}", description);
         println!();
     }
@@ -42,7 +46,7 @@ pub fn list_phases() -> CommandResult {
 /// Show all tasks in a specific phase
 pub fn show_phase_tasks(phase_name: &str) -> CommandResult {
     let roadmap = state::load_state()?;
-    let phase = Phase::from_string(phase_name);
+    let phase = roadmap.phase_named(phase_name);
     let tasks = roadmap.filter_by_phase(&phase);
     
     if tasks.is_empty() {
@@ -70,7 +74,13 @@ pub fn show_phase_tasks(phase_name: &str) -> CommandResult {
 /// Set the phase for a specific task
 pub fn set_task_phase(task_id: usize, phase_name: &str) -> CommandResult {
     let mut roadmap = state::load_state()?;
-    let phase = Phase::from_string(phase_name);
+    let phase = roadmap.phase_named(phase_name);
+    
+    if let Some(task) = roadmap.find_task_by_id(task_id) {
+        if task.status == TaskStatus::Pending && task.phase.name != phase.name {
+            utils::check_wip_limit(&roadmap, &phase, roadmap.open_tasks_in_phase(&phase) + 1)?;
+        }
+    }
     
     if let Some(task) = roadmap.find_task_by_id_mut(task_id) {
         let old_phase = task.phase.clone();
@@ -106,15 +116,38 @@ pub fn create_custom_phase(name: &str, description: Option<&str>, emoji: Option<
         return Ok(());
     }
     
-    // Create the phase
-    let phase = Phase::with_details(
-        phase_name.clone(),
-        description.map(|s| s.to_string()),
-        emoji.map(|s| s.to_string()),
-    );
+    // Register the phase, or update and unarchive an existing registration
+    let mut roadmap = state::load_state()?;
+    let existing = roadmap.phases.iter().position(|e| e.name.eq_ignore_ascii_case(&phase_name));
+    let entry = match existing {
+        Some(index) => {
+            let entry = &mut roadmap.phases[index];
+            if description.is_some() {
+                entry.description = description.map(|s| s.to_string());
+            }
+            if emoji.is_some() {
+                entry.emoji = emoji.map(|s| s.to_string());
+            }
+            entry.archived = false;
+            entry.clone()
+        }
+        None => {
+            let phase = Phase::with_details(
+                phase_name.clone(),
+                description.map(|s| s.to_string()),
+                emoji.map(|s| s.to_string()),
+            );
+            roadmap.phases.push(PhaseEntry::from_phase(&phase));
+            PhaseEntry::from_phase(&phase)
+        }
+    };
+    state::save_state(&roadmap)?;
     
+    let phase = entry.to_phase();
+    let action = if existing.is_some() { "updated" } else { "created" };
     ui::display_success(&format!(
-        "Custom phase created: {} {} - {}", 
+        "Custom phase {}: {} {} - {}", 
+        action,
         phase.emoji(), 
         phase.name,
         phase.description()
@@ -128,32 +161,172 @@ pub fn create_custom_phase(name: &str, description: Option<&str>, emoji: Option<
     Ok(())
 }
 
+/// Set, show or clear the WIP limit of a phase
+pub fn set_phase_limit(phase_name: &str, limit: Option<usize>, clear: bool) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    let phase = roadmap.phase_named(phase_name);
+    let open = roadmap.open_tasks_in_phase(&phase);
+    
+    if clear {
+        if roadmap.phase_limits.remove(&phase.name).is_none() {
+            ui::display_info(&format!("Phase {} has no WIP limit", phase.name));
+            return Ok(());
+        }
+        state::save_state(&roadmap)?;
+        ui::display_success(&format!("Removed the WIP limit of {} {}", phase.emoji(), phase.name));
+        return Ok(());
+    }
+    
+    let Some(limit) = limit else {
+        match roadmap.phase_limit(&phase) {
+            Some(limit) => ui::display_info(&format!("{} {}: {} of {} open tasks allowed", phase.emoji(), phase.name, open, limit)),
+            None => ui::display_info(&format!("{} {} has no WIP limit ({} open tasks)", phase.emoji(), phase.name, open)),
+        }
+        return Ok(());
+    };
+    if limit == 0 {
+        return Err("A WIP limit must be at least 1; use --clear to remove it".into());
+    }
+    
+    roadmap.phase_limits.insert(phase.name.clone(), limit);
+    state::save_state(&roadmap)?;
+    ui::display_success(&format!("{} {} is limited to {} open tasks", phase.emoji(), phase.name, limit));
+    if open > limit {
+        ui::display_warning(&format!("{} already has {} open tasks; finish or move {} to get under the limit", phase.name, open, open - limit));
+    }
+    Ok(())
+}
+
+/// Rename a phase on every task that uses it, keeping its position and WIP limit
+pub fn rename_phase(phase_name: &str, new_name: &str) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    let old = roadmap.phase_named(phase_name);
+    let new = Phase::from_string(new_name);
+    if new.name.is_empty() {
+        return Err("Phase name cannot be empty".into());
+    }
+    
+    let in_use = |name: &str| {
+        roadmap.tasks.iter().any(|t| t.phase.name.eq_ignore_ascii_case(name)) || roadmap.registered_phase(name).is_some()
+    };
+    if !in_use(&old.name) {
+        return Err(CommandError::NotFound(format!("No phase named '{}'", phase_name)).into());
+    }
+    if !old.name.eq_ignore_ascii_case(&new.name) && in_use(&new.name) {
+        return Err(format!(
+            "Phase '{}' already exists; move tasks into it with 'rask phase set' instead",
+            new.name
+        ).into());
+    }
+    
+    let renamed = roadmap.rename_phase(&old.name, &new.name);
+    utils::save_and_sync(&roadmap)?;
+    
+    let new = roadmap.phase_named(&new.name);
+    ui::display_success(&format!(
+        "Renamed phase {} {} to {} {} ({} tasks)",
+        old.emoji(), old.name, new.emoji(), new.name, renamed
+    ));
+    if roadmap.archived_phases.iter().any(|a| a.phase.eq_ignore_ascii_case(&old.name)) {
+        ui::display_info(&format!("The archive of '{}' keeps its original name", old.name));
+    }
+    Ok(())
+}
+
+/// Move a phase within the registry order that show, overview and export follow
+pub fn reorder_phase(phase_name: &str, before: Option<&str>, after: Option<&str>, position: Option<usize>) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    // Pin down the current order first so moving one phase leaves the others in place
+    roadmap.register_phases();
+    
+    let find = |roadmap: &Roadmap, name: &str| {
+        roadmap.phases.iter()
+            .position(|e| e.name.eq_ignore_ascii_case(&Phase::from_string(name).name))
+            .ok_or_else(|| CommandError::NotFound(format!("No phase named '{}'", name)))
+    };
+    let index = find(&roadmap, phase_name)?;
+    let entry = roadmap.phases.remove(index);
+    
+    let target = match (before, after, position) {
+        (Some(other), _, _) => find(&roadmap, other)?,
+        (_, Some(other), _) => find(&roadmap, other)? + 1,
+        (_, _, Some(position)) => position.clamp(1, roadmap.phases.len() + 1) - 1,
+        _ => return Err("Say where to move the phase with --before, --after or --position".into()),
+    };
+    roadmap.phases.insert(target, entry);
+    utils::save_and_sync(&roadmap)?;
+    
+    let order: Vec<String> = roadmap.phases.iter()
+        .filter(|e| !e.archived)
+        .map(|e| {
+            let phase = e.to_phase();
+            format!("{} {}", phase.emoji(), phase.name)
+        })
+        .collect();
+    ui::display_success(&format!("Moved phase {} to position {}", roadmap.phases[target].name, target + 1));
+    println!("   {}", order.join(" → "));
+    Ok(())
+}
+
 /// Show comprehensive phase overview with statistics and progress
 pub fn show_phase_overview() -> CommandResult {
     let roadmap = state::load_state()?;
     let stats = roadmap.get_statistics();
+    let completed_ids = roadmap.get_completed_task_ids();
+    
+    if utils::json_output() {
+        let phases: Vec<_> = stats.tasks_by_phase.iter()
+            .filter(|(_, count)| *count > 0)
+            .map(|(phase, count)| {
+                let phase_tasks = roadmap.filter_by_phase(phase);
+                let completed = phase_tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+                let pending = phase_tasks.iter().filter(|t| t.status == TaskStatus::Pending);
+                let ready = pending.clone().filter(|t| t.can_be_started(&completed_ids)).count();
+                serde_json::json!({
+                    "name": phase.name,
+                    "emoji": phase.emoji(),
+                    "custom": !phase.is_predefined(),
+                    "wip_limit": roadmap.phase_limit(phase),
+                    "open_tasks": roadmap.open_tasks_in_phase(phase),
+                    "total_tasks": count,
+                    "completed_tasks": completed,
+                    "completion_percentage": completed * 100 / count,
+                    "ready_tasks": ready,
+                    "blocked_tasks": pending.count() - ready,
+                })
+            })
+            .collect();
+        return utils::print_json(&serde_json::json!({
+            "total_tasks": stats.total_tasks,
+            "completed_tasks": stats.completed_tasks,
+            "pending_tasks": stats.pending_tasks,
+            "completion_percentage": stats.completion_percentage,
+            "phases": phases,
+        }));
+    }
     
+    let theme = ui::theme::theme();
     ui::display_info("🎯 Project Phase Overview");
     println!();
     
     // Overall project statistics
-    println!("📈 Overall Progress:");
+    println!("{}Overall Progress:", theme.icon("📈"));
     println!("  Total Tasks: {}", stats.total_tasks);
     println!("  Completed: {} ({}%)", stats.completed_tasks, stats.completion_percentage);
     println!("  Pending: {}", stats.pending_tasks);
     println!();
     
     if stats.tasks_by_phase.is_empty() {
-        println!("📊 No phases found.");
+        println!("{}No phases found.", theme.icon("📊"));
         println!();
-        println!("💡 Get started:");
+        println!("{}Get started:", theme.icon("💡"));
         println!("  • Add tasks with predefined phases: rask add \"My task\" --phase mvp");
         println!("  • Create custom phases: rask phase create \"Phase 1\" --description \"First iteration\"");
         return Ok(());
     }
     
     // Phase breakdown
-    println!("📊 Phase Breakdown:");
+    println!("{}Phase Breakdown:", theme.icon("📊"));
     for (phase, count) in &stats.tasks_by_phase {
         if *count > 0 {
             let phase_tasks = roadmap.filter_by_phase(phase);
@@ -163,27 +336,37 @@ pub fn show_phase_overview() -> CommandResult {
             let completion_rate = if *count > 0 { (completed_in_phase * 100) / count } else { 0 };
             
             let phase_type = if phase.is_predefined() { "" } else { " (custom)" };
-            println!("  {} {} ({} tasks, {}% complete){}", 
-                phase.emoji(), phase, count, completion_rate, phase_type);
+            println!("  {}{} ({} tasks, {}% complete){}", 
+                theme.icon(&theme.phase_emoji(phase)), theme.phase_text(&phase.name, phase.name.normal()), count, completion_rate, phase_type);
+            
+            if let Some(limit) = roadmap.phase_limit(phase) {
+                let open = roadmap.open_tasks_in_phase(phase);
+                let wip = format!("    {}WIP {}/{} open", theme.icon("🚦"), open, limit);
+                if open > limit {
+                    println!("{} {}", wip.red(), "(over limit)".red().bold());
+                } else {
+                    println!("{}", wip);
+                }
+            }
             
             // Show ready tasks in this phase
             let ready_tasks: Vec<_> = phase_tasks.iter()
                 .filter(|t| t.status == crate::model::TaskStatus::Pending && 
-                          t.can_be_started(&roadmap.get_completed_task_ids()))
+                          t.can_be_started(&completed_ids))
                 .collect();
             
             if !ready_tasks.is_empty() {
-                println!("    ✅ {} tasks ready to start", ready_tasks.len());
+                println!("    {}{} tasks ready to start", theme.icon("✅"), ready_tasks.len());
             }
             
             // Show blocked tasks in this phase
             let blocked_tasks: Vec<_> = phase_tasks.iter()
                 .filter(|t| t.status == crate::model::TaskStatus::Pending && 
-                          !t.can_be_started(&roadmap.get_completed_task_ids()))
+                          !t.can_be_started(&completed_ids))
                 .collect();
             
             if !blocked_tasks.is_empty() {
-                println!("    ⏸️  {} tasks blocked by dependencies", blocked_tasks.len());
+                println!("    {}{} tasks blocked by dependencies", theme.icon("⏸️ "), blocked_tasks.len());
             }
             
             println!();
@@ -191,7 +374,7 @@ pub fn show_phase_overview() -> CommandResult {
     }
     
     // Phase recommendations
-    println!("💡 Recommendations:");
+    println!("{}Recommendations:", theme.icon("💡"));
     
     // Find the phase with the most ready tasks
     let mut phase_ready_counts = Vec::new();
@@ -199,7 +382,7 @@ pub fn show_phase_overview() -> CommandResult {
         let phase_tasks = roadmap.filter_by_phase(phase);
         let ready_count = phase_tasks.iter()
             .filter(|t| t.status == crate::model::TaskStatus::Pending && 
-                      t.can_be_started(&roadmap.get_completed_task_ids()))
+                      t.can_be_started(&completed_ids))
             .count();
         if ready_count > 0 {
             phase_ready_counts.push((phase, ready_count));
@@ -209,7 +392,7 @@ pub fn show_phase_overview() -> CommandResult {
     phase_ready_counts.sort_by(|a, b| b.1.cmp(&a.1));
     
     if let Some((top_phase, count)) = phase_ready_counts.first() {
-        println!("  • Focus on {} {} phase - {} tasks ready to start", top_phase.emoji(), top_phase, count);
+        println!("  • Focus on {}{} phase - {} tasks ready to start", theme.icon(&theme.phase_emoji(top_phase)), top_phase, count);
     }
     
     // Suggest predefined phases if none are being used
@@ -220,7 +403,10 @@ pub fn show_phase_overview() -> CommandResult {
     
     if used_predefined.is_empty() && !stats.tasks_by_phase.is_empty() {
         println!("  • Consider using predefined phases for better organization:");
-        println!("    🚀 MVP, 🧪 Beta, 🎯 Release, 🔮 Future, 💡 Backlog");
+        let names: Vec<String> = Phase::predefined_phases().iter()
+            .map(|phase| format!("{}{}", theme.icon(&phase.emoji()), phase.name))
+            .collect();
+        println!("    {}", names.join(", "));
     }
     
     println!("  • Create custom phases: rask phase create \"<name>\" --description \"<desc>\" --emoji \"<emoji>\"");
@@ -333,7 +519,7 @@ pub fn fork_phase_or_tasks(
                 new_task.phase = new_phase.clone();
                 
                 // Reset some fields for the copy
-                new_task.status = crate::model::TaskStatus::Pending;
+                new_task.status = TaskStatus::Pending;
                 new_task.completed_at = None;
                 new_task.actual_hours = None;
                 new_task.time_sessions = Vec::new();
@@ -341,6 +527,9 @@ pub fn fork_phase_or_tasks(
                 
                 // Clear dependencies to avoid conflicts (user can re-add if needed)
                 new_task.dependencies = Vec::new();
+                new_task.dependency_notes.clear();
+                new_task.parent = None;
+                new_task.refresh_timestamp_cache();
                 
                 roadmap.add_task(new_task);
                 forked_count += 1;
@@ -391,4 +580,110 @@ pub fn fork_phase_or_tasks(
     println!("📊 Phase overview: rask phase overview");
     
     Ok(())
-} 
\ No newline at end of file
+}
+
+/// Move all tasks of a completed phase into .rask/archive/<phase>.json
+pub fn archive_phase(phase_name: &str) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    let phase = roadmap.phase_named(phase_name);
+    let tasks = roadmap.filter_by_phase(&phase);
+    
+    if tasks.is_empty() {
+        return Err(format!("No tasks found in phase '{}'", phase.name).into());
+    }
+    
+    let pending: Vec<String> = tasks.iter()
+        .filter(|t| t.status == TaskStatus::Pending)
+        .map(|t| format!("#{}", t.id))
+        .collect();
+    if !pending.is_empty() {
+        return Err(format!(
+            "Phase '{}' still has pending tasks ({}). Complete or move them before archiving.",
+            phase.name,
+            pending.join(", ")
+        ).into());
+    }
+    
+    let mut archive = roadmap.archive_phase(&phase.name);
+    let archived_count = archive.tasks.len();
+    
+    // Archiving the same phase again appends to the existing archive
+    if let Some(previous) = state::load_phase_archive(&archive.summary.phase)? {
+        archive.absorb(previous);
+        if let Some(summary) = roadmap.archived_phases.iter_mut().find(|a| a.phase == archive.summary.phase) {
+            *summary = archive.summary.clone();
+        }
+    }
+    
+    // Keep the phase's place in the registry but hide it from listings
+    match roadmap.phases.iter_mut().find(|e| e.name.eq_ignore_ascii_case(&archive.summary.phase)) {
+        Some(entry) => entry.archived = true,
+        None => roadmap.phases.push(PhaseEntry {
+            name: archive.summary.phase.clone(),
+            archived: true,
+            ..PhaseEntry::from_phase(&phase)
+        }),
+    }
+    
+    let archive_file = state::save_phase_archive(&archive)?;
+    let markdown_file = archive_file.with_extension("md");
+    markdown_writer::write_phase_archive(&archive, &markdown_file)?;
+    utils::save_and_sync(&roadmap)?;
+    
+    ui::display_success(&format!(
+        "📦 Archived {} tasks from {} {} phase",
+        archived_count,
+        phase.emoji(),
+        archive.summary.phase
+    ));
+    println!("   📄 {}", archive_file.display());
+    println!("   📝 {}", markdown_file.display());
+    println!();
+    println!("💡 Remaining tasks were renumbered. View the archive with: rask phase archived \"{}\"", archive.summary.phase);
+    
+    Ok(())
+}
+
+/// List archived phase summaries, or load one archive on demand and show its tasks
+pub fn show_archived_phases(phase_name: Option<&str>) -> CommandResult {
+    let roadmap = state::load_state()?;
+    
+    let Some(phase_name) = phase_name else {
+        if roadmap.archived_phases.is_empty() {
+            ui::display_info("No archived phases. Archive a completed phase with: rask phase archive <name>");
+            return Ok(());
+        }
+        
+        ui::display_info("📦 Archived Phases");
+        println!();
+        for summary in &roadmap.archived_phases {
+            let phase = Phase::from_string(&summary.phase);
+            println!("  {} {} - {} tasks, {:.1}h estimated, {:.1}h tracked",
+                phase.emoji(),
+                summary.phase.bold(),
+                summary.total_tasks,
+                summary.estimated_hours,
+                summary.actual_hours
+            );
+            println!("    Archived {}", summary.archived_at.get(..10).unwrap_or(&summary.archived_at).bright_black());
+        }
+        println!();
+        println!("💡 Show archived tasks: rask phase archived <name>");
+        return Ok(());
+    };
+    
+    let archive = state::load_phase_archive(phase_name)?
+        .ok_or_else(|| format!("No archive found for phase '{}'", phase_name))?;
+    
+    let mut archived_roadmap = Roadmap::new(format!("{} (archived)", archive.roadmap_title));
+    archived_roadmap.tasks = archive.tasks;
+    let tasks: Vec<_> = archived_roadmap.tasks.iter().collect();
+    
+    let phase = Phase::from_string(&archive.summary.phase);
+    ui::display_info(&format!("📦 {} {} Archived Tasks ({} tasks)", phase.emoji(), phase.name, tasks.len()));
+    println!("  Archived {} - task IDs are as they were at archive time", archive.summary.archived_at.get(..10).unwrap_or(&archive.summary.archived_at));
+    
+    ui::display_filtered_tasks(&archived_roadmap, &tasks, false);
+    
+    Ok(())
+}
@@ -0,0 +1,260 @@
+//! Capacity planning
+//!
+//! `rask plan` checks the open milestones against the work left in them. A
+//! phase gets a target date through a milestone on that phase. Milestones are
+//! worked in target date order, so each one needs the remaining estimates of
+//! its own tasks plus those of every milestone due before it. The hours
+//! available until a target come from the recent velocity: time logged in
+//! the last few weeks, plus the estimates of tasks completed in that window
+//! without tracked time. Overcommitted milestones get a list of low-priority
+//! tasks that could be deferred to fit.
+
+use crate::config::RaskConfig;
+use crate::model::{MilestoneStatus, Priority, Roadmap, Task, TaskStatus};
+use crate::ui::helpers::{output_width, Table};
+use crate::{state, ui};
+use super::{utils, CommandResult};
+use chrono::{Duration, Local, NaiveDate, Utc};
+use colored::*;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// Where the hours-per-day figure comes from
+#[derive(Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum VelocitySource {
+    /// Work done in the window
+    Recent,
+    /// No recent work; `behavior.daily_capacity_hours` on weekdays
+    Capacity,
+}
+
+/// A pending task suggested for deferral
+#[derive(Serialize)]
+struct Deferral {
+    id: usize,
+    description: String,
+    priority: Priority,
+    hours: f64,
+}
+
+/// How one milestone fits the time left
+#[derive(Serialize)]
+struct MilestonePlan {
+    name: String,
+    target_date: String,
+    days_left: i64,
+    pending_tasks: usize,
+    unestimated_tasks: usize,
+    /// Remaining hours of this milestone's own tasks
+    remaining_hours: f64,
+    /// Remaining hours of this milestone and every one due before it
+    cumulative_hours: f64,
+    available_hours: f64,
+    overcommitted: bool,
+    defer: Vec<Deferral>,
+}
+
+#[derive(Serialize)]
+struct CapacityPlan {
+    velocity_hours_per_day: f64,
+    velocity_source: VelocitySource,
+    window_weeks: u32,
+    /// Hours assumed for pending tasks without an estimate
+    assumed_estimate: f64,
+    milestones: Vec<MilestonePlan>,
+}
+
+/// Hours per day of work done in the last `weeks` weeks
+fn recent_velocity(roadmap: &Roadmap, weeks: u32) -> f64 {
+    let days = i64::from(weeks) * 7;
+    let since = Utc::now() - Duration::days(days);
+    let logged: f64 = roadmap.tasks.iter()
+        .flat_map(|task| &task.time_sessions)
+        .filter(|session| crate::model::parse_timestamp(Some(&session.start_time)).is_some_and(|start| start >= since))
+        .map(|session| session.duration_hours().unwrap_or_else(|| session.elapsed_hours()))
+        .sum();
+    // Completed work that was never timed still counts with its estimate
+    let untimed: f64 = roadmap.tasks.iter()
+        .filter(|task| task.status == TaskStatus::Completed && task.time_sessions.is_empty())
+        .filter(|task| task.completed_datetime().is_some_and(|at| at >= since))
+        .filter_map(|task| task.estimated_hours)
+        .sum();
+    (logged + untimed) / days as f64
+}
+
+/// Estimated hours still needed for a pending task
+fn remaining_hours(task: &Task, assumed_estimate: f64) -> f64 {
+    match task.estimated_hours {
+        Some(estimate) => (estimate - task.get_total_tracked_hours()).max(0.0),
+        None => assumed_estimate,
+    }
+}
+
+/// Low and medium priority tasks whose deferral covers `shortfall`, least
+/// important and largest first. Tasks other pending tasks wait on are kept.
+fn suggest_deferrals(roadmap: &Roadmap, tasks: &[&Task], shortfall: f64, assumed_estimate: f64) -> Vec<Deferral> {
+    let needed: HashSet<usize> = roadmap.tasks.iter()
+        .filter(|task| task.status == TaskStatus::Pending)
+        .flat_map(|task| task.dependencies.iter().copied())
+        .collect();
+    let mut candidates: Vec<&&Task> = tasks.iter()
+        .filter(|task| matches!(task.priority, Priority::Low | Priority::Medium) && !needed.contains(&task.id))
+        .collect();
+    candidates.sort_by(|a, b| {
+        a.priority.rank().cmp(&b.priority.rank())
+            .then(remaining_hours(b, assumed_estimate).total_cmp(&remaining_hours(a, assumed_estimate)))
+    });
+
+    let mut freed = 0.0;
+    let mut defer = Vec::new();
+    for task in candidates {
+        if freed >= shortfall {
+            break;
+        }
+        let hours = remaining_hours(task, assumed_estimate);
+        if hours <= 0.0 {
+            continue;
+        }
+        freed += hours;
+        defer.push(Deferral { id: task.id, description: task.description.clone(), priority: task.priority.clone(), hours });
+    }
+    defer
+}
+
+/// Work out whether each open milestone can be finished by its target
+fn build_plan(roadmap: &Roadmap, weeks: u32, capacity: f64, today: NaiveDate) -> CapacityPlan {
+    let (velocity, source) = match recent_velocity(roadmap, weeks) {
+        velocity if velocity > 0.0 => (velocity, VelocitySource::Recent),
+        _ => (capacity * 5.0 / 7.0, VelocitySource::Capacity),
+    };
+    let estimates: Vec<f64> = roadmap.tasks.iter().filter_map(|task| task.estimated_hours).collect();
+    let assumed_estimate = if estimates.is_empty() { 0.0 } else { estimates.iter().sum::<f64>() / estimates.len() as f64 };
+
+    let mut milestones: Vec<_> = roadmap.milestones.iter()
+        .filter_map(|milestone| Some((milestone, milestone.target()?)))
+        .filter(|(milestone, _)| milestone.progress(roadmap, today).status != MilestoneStatus::Done)
+        .collect();
+    milestones.sort_by_key(|(_, target)| *target);
+
+    // A task in several milestones is done once, for the earliest
+    let mut planned: HashSet<usize> = HashSet::new();
+    let mut cumulative = 0.0;
+    let mut plans = Vec::new();
+    for (milestone, target) in milestones {
+        let tasks: Vec<&Task> = roadmap.tasks.iter()
+            .filter(|task| task.status == TaskStatus::Pending && milestone.includes(task))
+            .collect();
+        let remaining: f64 = tasks.iter()
+            .filter(|task| !planned.contains(&task.id))
+            .map(|task| remaining_hours(task, assumed_estimate))
+            .sum();
+        planned.extend(tasks.iter().map(|task| task.id));
+        cumulative += remaining;
+
+        // Today counts as a working day
+        let days_left = (target - today).num_days();
+        let available = (days_left + 1).max(0) as f64 * velocity;
+        let overcommitted = cumulative > available;
+        let defer = if overcommitted {
+            suggest_deferrals(roadmap, &tasks, cumulative - available, assumed_estimate)
+        } else {
+            Vec::new()
+        };
+        plans.push(MilestonePlan {
+            name: milestone.name.clone(),
+            target_date: milestone.target_date.clone(),
+            days_left,
+            pending_tasks: tasks.len(),
+            unestimated_tasks: tasks.iter().filter(|task| task.estimated_hours.is_none()).count(),
+            remaining_hours: remaining,
+            cumulative_hours: cumulative,
+            available_hours: available,
+            overcommitted,
+            defer,
+        });
+    }
+
+    CapacityPlan {
+        velocity_hours_per_day: velocity,
+        velocity_source: source,
+        window_weeks: weeks,
+        assumed_estimate,
+        milestones: plans,
+    }
+}
+
+/// Flag milestones that can't be finished on time at the current velocity
+pub fn show_plan(weeks: u32) -> CommandResult {
+    if weeks == 0 {
+        return Err("The velocity window must be at least 1 week".into());
+    }
+    let capacity = RaskConfig::load().map(|config| config.behavior.daily_capacity_hours).unwrap_or(6.0);
+    let roadmap = state::load_state()?;
+    let plan = build_plan(&roadmap, weeks, capacity, Local::now().date_naive());
+
+    if utils::json_output() {
+        return utils::print_json(&plan);
+    }
+
+    if plan.milestones.is_empty() {
+        ui::display_info("No open milestones to plan against. Give a phase a target with 'rask milestone add <name> --target <date> --phase <phase>'");
+        return Ok(());
+    }
+
+    println!("\n  {}", "📐 Capacity Plan".bold().bright_cyan());
+    println!("  {}", ui::theme::theme().rule("─", 60).bright_black());
+    let velocity = match plan.velocity_source {
+        VelocitySource::Recent => format!("{:.1}h/day, from the last {} week(s) of work", plan.velocity_hours_per_day, plan.window_weeks),
+        VelocitySource::Capacity => format!("{:.1}h/day, from behavior.daily_capacity_hours on weekdays (no work logged in the last {} week(s))", plan.velocity_hours_per_day, plan.window_weeks),
+    };
+    println!("  Velocity: {}", velocity.bright_white());
+    if plan.milestones.iter().any(|m| m.unestimated_tasks > 0) {
+        println!("  {}", format!("Tasks without an estimate count as {:.1}h, the project average", plan.assumed_estimate).bright_black());
+    }
+    println!();
+
+    let mut table = Table::new("  ").indent("  ").min_column_width(6);
+    table.add_row(["Milestone", "Target", "Tasks", "Needed", "Available", "Status"].iter().map(|h| h.bold().to_string()).collect());
+    for milestone in &plan.milestones {
+        let status = if milestone.days_left < 0 {
+            "overdue".bright_red().bold().to_string()
+        } else if milestone.overcommitted {
+            format!("over by {:.1}h", milestone.cumulative_hours - milestone.available_hours).bright_red().bold().to_string()
+        } else {
+            "fits".bright_green().to_string()
+        };
+        let target = format!("{} ({}d)", milestone.target_date, milestone.days_left.max(0));
+        table.add_row(vec![
+            milestone.name.bright_white().to_string(),
+            target,
+            milestone.pending_tasks.to_string(),
+            format!("{:.1}h", milestone.cumulative_hours),
+            format!("{:.1}h", milestone.available_hours),
+            status,
+        ]);
+    }
+    for line in table.render(output_width()) {
+        println!("{}", line);
+    }
+
+    for milestone in plan.milestones.iter().filter(|m| m.overcommitted) {
+        let shortfall = milestone.cumulative_hours - milestone.available_hours;
+        println!("\n  {} {}", "⚠".bright_red(), format!("{} needs {:.1}h more than the time left", milestone.name, shortfall).bold());
+        if milestone.defer.is_empty() {
+            println!("    {}", "No low or medium priority tasks to defer; move the target date or add capacity".bright_black());
+            continue;
+        }
+        let freed: f64 = milestone.defer.iter().map(|task| task.hours).sum();
+        println!("    Defer to free {:.1}h:", freed);
+        for task in &milestone.defer {
+            println!("    {} {} {}", format!("#{}", task.id).bright_white(), task.description,
+                format!("({}, {:.1}h)", task.priority, task.hours).bright_black());
+        }
+        if freed < shortfall {
+            println!("    {}", "Still not enough; the target date needs to move".bright_yellow());
+        }
+    }
+    println!();
+    Ok(())
+}
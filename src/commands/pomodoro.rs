@@ -0,0 +1,147 @@
+//! Pomodoro timer built on time tracking
+//!
+//! `rask pomodoro <id>` alternates work and break intervals with a terminal
+//! countdown. Every work interval is recorded as a regular time session on
+//! the task, so pomodoros show up in `rask time`, analytics and exports like
+//! any other tracked time. Ctrl+C stops early and still records the partial
+//! work interval. Interval ends also show a desktop notification when
+//! `notifications.desktop` is on.
+
+use crate::{model::TaskStatus, notifications, state, ui};
+use super::{CommandError, CommandResult};
+use colored::*;
+use std::io::Write;
+use std::time::Duration;
+
+/// How a countdown ended
+enum Countdown {
+    Finished,
+    Interrupted,
+}
+
+/// Run work/break cycles on a task, recording each work interval as a time session
+pub fn run_pomodoro(task_id: usize, work_minutes: u64, break_minutes: u64, cycles: u32) -> CommandResult {
+    if work_minutes == 0 {
+        return Err("Work interval must be at least 1 minute".into());
+    }
+    if cycles == 0 {
+        return Err("Run at least one cycle".into());
+    }
+
+    let roadmap = state::load_state()?;
+    if let Some(active) = roadmap.tasks.iter().find(|t| t.has_active_time_session()) {
+        return Err(format!(
+            "Task #{} already has an active time session. Stop it first with 'rask stop'",
+            active.id
+        ).into());
+    }
+    let task = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    if task.status == TaskStatus::Completed {
+        return Err(format!("Task #{} is already completed", task_id).into());
+    }
+
+    ui::display_info(&format!("🍅 Pomodoro for task #{}: {}", task_id, task.description));
+    ui::display_info(&format!("   {} × {} min work, {} min breaks (Ctrl+C to stop early)", cycles, work_minutes, break_minutes));
+
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| format!("Failed to create async runtime: {}", e))?;
+
+    let mut completed_cycles = 0;
+    let mut focused_hours = 0.0;
+
+    for cycle in 1..=cycles {
+        start_session(task_id, &format!("Pomodoro {}/{}", cycle, cycles))?;
+        let outcome = runtime.block_on(countdown(&format!("🍅 Work {}/{}", cycle, cycles), work_minutes * 60));
+        focused_hours += end_session(task_id)?;
+
+        if matches!(outcome, Countdown::Interrupted) {
+            println!();
+            ui::display_warning("Pomodoro stopped early; the partial work interval was recorded");
+            break;
+        }
+        completed_cycles += 1;
+        notify(&format!("Work interval {}/{} done", cycle, cycles));
+
+        if cycle < cycles && break_minutes > 0 {
+            let outcome = runtime.block_on(countdown(&format!("☕ Break {}/{}", cycle, cycles - 1), break_minutes * 60));
+            if matches!(outcome, Countdown::Interrupted) {
+                println!();
+                ui::display_warning("Pomodoro stopped during a break");
+                break;
+            }
+            notify("Break over, back to work");
+        }
+    }
+
+    display_summary(task_id, completed_cycles, cycles, focused_hours)
+}
+
+/// Open a time session on the task and save it straight away
+fn start_session(task_id: usize, description: &str) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    let task = roadmap.find_task_by_id_mut(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    task.start_time_session(Some(description.to_string()))?;
+    state::save_state(&roadmap)?;
+    Ok(())
+}
+
+/// Close the task's running session, returning its length in hours
+fn end_session(task_id: usize) -> Result<f64, Box<dyn std::error::Error>> {
+    let mut roadmap = state::load_state()?;
+    let task = roadmap.find_task_by_id_mut(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    let hours = task.end_current_time_session()?;
+    state::save_state(&roadmap)?;
+    Ok(hours)
+}
+
+/// Count down on a single terminal line, stopping early on Ctrl+C
+async fn countdown(label: &str, seconds: u64) -> Countdown {
+    let mut ticker = tokio::time::interval(Duration::from_secs(1));
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+    // The first tick completes immediately
+    ticker.tick().await;
+
+    for remaining in (1..=seconds).rev() {
+        print!("\r   {} — {:02}:{:02} remaining ", label.bold(), remaining / 60, remaining % 60);
+        let _ = std::io::stdout().flush();
+
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = &mut ctrl_c => return Countdown::Interrupted,
+        }
+    }
+
+    print!("\r   {} — done{}\n", label.bold(), " ".repeat(16));
+    Countdown::Finished
+}
+
+/// Ring the terminal bell with a message between intervals
+fn notify(message: &str) {
+    print!("\x07");
+    ui::display_success(message);
+    notifications::desktop_alert("🍅 Pomodoro", message);
+}
+
+/// Print what the pomodoro run recorded
+fn display_summary(task_id: usize, completed_cycles: u32, planned_cycles: u32, focused_hours: f64) -> CommandResult {
+    let roadmap = state::load_state()?;
+    let task = roadmap.find_task_by_id(task_id)
+        .ok_or_else(|| CommandError::task_not_found(task_id))?;
+    let total_tracked = task.get_total_tracked_hours();
+
+    println!("\n{}", "🍅 Pomodoro Summary".bright_cyan().bold());
+    println!("{}", "─".repeat(50).bright_black());
+    println!("  Cycles completed: {}/{}", completed_cycles, planned_cycles);
+    println!("  Focused time: {:.0} min", focused_hours * 60.0);
+    match task.estimated_hours {
+        Some(estimated) => println!("  Task #{} total: {:.2}h tracked of {:.2}h estimated", task_id, total_tracked, estimated),
+        None => println!("  Task #{} total: {:.2}h tracked", task_id, total_tracked),
+    }
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,492 @@
+//! Quick task creation from a single line of text
+//!
+//! `rask quick` understands explicit markers anywhere in the text:
+//! `#tag`, `!high` (priority), `@Beta` (phase), `due friday`, `est 3h` and
+//! `after 12,14` (dependencies), so "Fix login bug #backend !high @Beta due
+//! friday est 3h after 12" fills in everything at once. When no marker is
+//! used, plain keywords such as "high priority", "backend" or "2 hours" are
+//! recognised instead.
+
+use crate::model::Priority;
+use crate::{state, ui};
+use super::{add_task_enhanced, utils, CommandResult};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A word or number (`1.5`) in a task description
+static DESCRIPTION_WORD: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\w+(?:\.\w+)*").expect("valid description word regex")
+});
+
+/// A whole duration such as `1h30m`
+static DURATION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:(\d+(?:\.\d+)?)([hmdw]))+$").expect("valid duration regex")
+});
+
+/// One `<number><unit>` part of a duration
+static DURATION_PART: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(\d+(?:\.\d+)?)([hmdw])").expect("valid duration part regex")
+});
+
+/// 🚀 Quick task creation with natural language parsing
+pub fn quick_add_task(text: &str, dry_run: bool) -> CommandResult {
+    let parsed = parse_quick_text(text, chrono::Local::now().date_naive())?;
+    
+    // Show what was parsed for user feedback
+    ui::display_info("🤖 Parsed task information:");
+    ui::display_info(&format!("📝 Description: {}", parsed.description));
+    ui::display_info(&format!("🏷️  Tags: {}", if parsed.tags.is_empty() { "None".to_string() } else { parsed.tags.join(", ") }));
+    ui::display_info(&format!("⚡ Priority: {}", parsed.priority));
+    ui::display_info(&format!("🚀 Phase: {}", parsed.phase.as_deref().unwrap_or("mvp")));
+    if let Some(due) = parsed.due {
+        ui::display_info(&format!("📅 Due: {}", due.format("%Y-%m-%d (%A)")));
+    }
+    if let Some(hours) = parsed.estimated_hours {
+        ui::display_info(&format!("⏱️  Estimate: {}h", hours));
+    }
+    if !parsed.dependencies.is_empty() {
+        ui::display_info(&format!("🔗 After: {}", parsed.dependencies.iter().map(|id| format!("#{}", id)).collect::<Vec<_>>().join(", ")));
+    }
+    
+    // Convert to add_task_enhanced parameters
+    let tags_str = if parsed.tags.is_empty() { None } else { Some(parsed.tags.join(",")) };
+    let priority = Some(parsed.priority.into());
+    let dependencies = if parsed.dependencies.is_empty() {
+        None
+    } else {
+        Some(parsed.dependencies.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(","))
+    };
+    let due = parsed.due.map(|d| d.format("%Y-%m-%d").to_string());
+    
+    if dry_run {
+        // Run the same checks as a real add so the preview doesn't promise a task that would be rejected
+        utils::validate_task_description(&parsed.description)?;
+        if let Some(tags) = &tags_str {
+            utils::validate_and_parse_tags(tags)?;
+        }
+        if let Some(deps) = &dependencies {
+            utils::validate_and_parse_dependencies(deps, &state::load_state()?)?;
+        }
+        ui::display_info("Dry run: no task was added");
+        return Ok(());
+    }
+    
+    // Call the existing add_task_enhanced function
+    add_task_enhanced(
+        &parsed.description,
+        &tags_str,
+        &priority,
+        &parsed.phase,
+        &None, // notes
+        &dependencies,
+        &parsed.estimated_hours,
+        &due,
+        &[], // acceptance criteria
+        &None, // owners
+        None, // energy
+        &None, // contexts
+        false, // no_markdown
+    )
+}
+
+/// Task fields recognised in quick-add text
+#[derive(Default)]
+struct ParsedTask {
+    description: String,
+    tags: Vec<String>,
+    priority: Priority,
+    phase: Option<String>,
+    estimated_hours: Option<f64>,
+    due: Option<NaiveDate>,
+    dependencies: Vec<usize>,
+}
+
+/// Parse quick-add text, preferring explicit markers over keyword guessing
+fn parse_quick_text(text: &str, today: NaiveDate) -> Result<ParsedTask, String> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let mut parsed = ParsedTask::default();
+    let mut explicit = false;
+    let mut description = Vec::new();
+    
+    let mut i = 0;
+    while i < words.len() {
+        let word = words[i];
+        let lower = word.to_lowercase();
+        
+        // Markers that take the following word(s); each returns how many words it consumed
+        let consumed = match lower.as_str() {
+            "due" => parse_due(&words[i + 1..], today)?.map(|(date, used)| {
+                parsed.due = Some(date);
+                used
+            }),
+            "est" | "estimate" => words.get(i + 1).and_then(|w| parse_duration(w)).map(|hours| {
+                parsed.estimated_hours = Some(hours);
+                1
+            }),
+            "after" => words.get(i + 1).and_then(|w| parse_task_ids(w)).map(|ids| {
+                parsed.dependencies.extend(ids);
+                1
+            }),
+            _ => None,
+        };
+        if let Some(used) = consumed {
+            explicit = true;
+            i += used + 1;
+            continue;
+        }
+        
+        // Single-word markers
+        if let Some(tag) = word.strip_prefix('#').filter(|t| !t.is_empty() && !t.chars().all(|c| c.is_ascii_digit())) {
+            parsed.tags.push(tag.to_lowercase());
+        } else if let Some(priority) = lower.strip_prefix('!').and_then(parse_priority) {
+            parsed.priority = priority;
+        } else if let Some(phase) = word.strip_prefix('@').filter(|p| !p.is_empty()) {
+            parsed.phase = Some(phase.to_string());
+        } else if let Some(hours) = word.strip_prefix('~').and_then(parse_duration) {
+            parsed.estimated_hours = Some(hours);
+        } else {
+            description.push(word);
+            i += 1;
+            continue;
+        }
+        explicit = true;
+        i += 1;
+    }
+    
+    if !explicit {
+        return Ok(guess_from_keywords(text));
+    }
+    
+    parsed.description = description.join(" ");
+    if parsed.description.is_empty() {
+        parsed.description = text.to_string();
+    }
+    parsed.tags.sort();
+    parsed.tags.dedup();
+    parsed.dependencies.sort_unstable();
+    parsed.dependencies.dedup();
+    Ok(parsed)
+}
+
+/// Priority from a `!marker`
+fn parse_priority(name: &str) -> Option<Priority> {
+    match name {
+        "critical" | "crit" | "urgent" => Some(Priority::Critical),
+        "high" | "h" => Some(Priority::High),
+        "medium" | "med" | "m" => Some(Priority::Medium),
+        "low" | "l" => Some(Priority::Low),
+        _ => None,
+    }
+}
+
+/// Duration such as `3h`, `90m`, `1.5d`, `1w` or `1h30m`, in hours (a day is 8h, a week 40h)
+pub(crate) fn parse_duration(text: &str) -> Option<f64> {
+    let lower = text.to_lowercase();
+    if !DURATION.is_match(&lower) {
+        return None;
+    }
+    let hours: f64 = DURATION_PART.captures_iter(&lower)
+        .filter_map(|c| {
+            let value: f64 = c[1].parse().ok()?;
+            Some(match &c[2] {
+                "m" => value / 60.0,
+                "d" => value * 8.0,
+                "w" => value * 40.0,
+                _ => value,
+            })
+        })
+        .sum();
+    (hours > 0.0).then_some(hours)
+}
+
+/// Task IDs such as `12`, `#12` or `12,14`
+fn parse_task_ids(text: &str) -> Option<Vec<usize>> {
+    text.split(',')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.trim_start_matches('#').parse::<usize>().ok().filter(|id| *id > 0))
+        .collect::<Option<Vec<_>>>()
+        .filter(|ids| !ids.is_empty())
+}
+
+/// Largest N accepted in `due in N days/weeks`, about a hundred years
+const MAX_DUE_IN_DAYS: u64 = 36_500;
+
+/// Due date from the words after `due`: a date, a weekday, `next <weekday>`,
+/// `next week` or `in N days/weeks`. Returns the date and the words used, or
+/// `None` when the words are not a due date; a date too far ahead is an error.
+fn parse_due(words: &[&str], today: NaiveDate) -> Result<Option<(NaiveDate, usize)>, String> {
+    let Some(first) = words.first().map(|w| w.to_lowercase()) else {
+        return Ok(None);
+    };
+    let second = words.get(1).map(|w| w.to_lowercase());
+    
+    if let Ok(date) = utils::parse_due_date(&first) {
+        return Ok(Some((date, 1)));
+    }
+    if let Some(weekday) = parse_weekday(&first) {
+        return Ok(Some((next_weekday(today, weekday, true), 1)));
+    }
+    Ok(match (first.as_str(), second.as_deref()) {
+        ("next", Some("week")) => Some((next_weekday(today, Weekday::Mon, false), 2)),
+        ("next", Some(day)) => parse_weekday(day).map(|weekday| (next_weekday(today, weekday, false), 2)),
+        ("in", Some(count)) if count.bytes().all(|b| b.is_ascii_digit()) => {
+            let Some(unit) = words.get(2).map(|w| w.to_lowercase()) else {
+                return Ok(None);
+            };
+            let per_unit = match unit.trim_end_matches('s') {
+                "day" => 1,
+                "week" => 7,
+                _ => return Ok(None),
+            };
+            let too_far = || format!("'due in {} {}' is too far in the future", count, unit);
+            let days = count.parse::<u64>().ok()
+                .and_then(|count| count.checked_mul(per_unit))
+                .filter(|days| *days <= MAX_DUE_IN_DAYS)
+                .ok_or_else(too_far)?;
+            let date = today.checked_add_signed(Duration::days(days as i64)).ok_or_else(too_far)?;
+            Some((date, 3))
+        }
+        _ => None,
+    })
+}
+
+fn parse_weekday(name: &str) -> Option<Weekday> {
+    match name {
+        "monday" | "mon" => Some(Weekday::Mon),
+        "tuesday" | "tue" | "tues" => Some(Weekday::Tue),
+        "wednesday" | "wed" => Some(Weekday::Wed),
+        "thursday" | "thu" | "thur" | "thurs" => Some(Weekday::Thu),
+        "friday" | "fri" => Some(Weekday::Fri),
+        "saturday" | "sat" => Some(Weekday::Sat),
+        "sunday" | "sun" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// The next date falling on `weekday`, counting today only when `include_today` is set
+fn next_weekday(today: NaiveDate, weekday: Weekday, include_today: bool) -> NaiveDate {
+    let mut days = (7 + weekday.num_days_from_monday() as i64 - today.weekday().num_days_from_monday() as i64) % 7;
+    if days == 0 && !include_today {
+        days = 7;
+    }
+    today + Duration::days(days)
+}
+
+/// Guess metadata from plain keywords ("high priority", "backend", "2 hours")
+fn guess_from_keywords(text: &str) -> ParsedTask {
+    let mut description = text.to_string();
+    let mut tags = Vec::new();
+    let mut priority = Priority::Medium;
+    let mut phase = None;
+    let mut estimated_hours = None;
+    
+    // Priority keywords (case insensitive) - more specific patterns
+    let priority_patterns = [
+        ("critical", Priority::Critical),
+        ("urgent", Priority::Critical),
+        ("high priority", Priority::High),
+        ("high", Priority::High), 
+        ("important", Priority::High),
+        ("medium priority", Priority::Medium),
+        ("medium", Priority::Medium),
+        ("normal", Priority::Medium),
+        ("low priority", Priority::Low),
+        ("low", Priority::Low),
+    ];
+    
+    // Phase keywords - more specific patterns
+    let phase_patterns = [
+        ("mvp", "mvp"),
+        ("beta", "beta"),
+        ("release", "release"),
+        ("future", "future"),
+        ("backlog", "backlog"),
+        ("later", "future"),
+        ("someday", "backlog"),
+    ];
+    
+    // Common tag patterns - more specific to avoid false matches
+    let tag_patterns = [
+        ("backend", "backend"),
+        ("frontend", "frontend"),
+        ("front-end", "frontend"),
+        ("api", "api"),
+        ("ui", "ui"),
+        ("ux", "ux"),
+        ("database", "database"),
+        ("db", "database"),
+        ("auth", "auth"),
+        ("authentication", "auth"),
+        ("security", "security"),
+        ("testing", "testing"),
+        ("test", "testing"),
+        ("bug", "bug"),
+        ("fix", "bug"),
+        ("feature", "feature"),
+        ("deploy", "deployment"),
+        ("deployment", "deployment"),
+        ("production", "production"),
+        ("staging", "staging"),
+        ("docs", "documentation"),
+        ("documentation", "documentation"),
+        ("refactor", "refactoring"),
+        ("performance", "performance"),
+        ("optimization", "performance"),
+        ("mobile", "mobile"),
+        ("web", "web"),
+        ("desktop", "desktop"),
+        ("infrastructure", "infrastructure"),
+    ];
+    
+    let text_lower = text.to_lowercase();
+    let words: Vec<&str> = text_lower.split_whitespace().collect();
+    
+    // Track which standalone words/phrases we've extracted to remove them more precisely
+    let mut standalone_words_to_remove: Vec<String> = Vec::new();
+    
+    // Extract priority - check longer phrases first, only if they are standalone
+    for (keyword, prio) in &priority_patterns {
+        // Check if the keyword appears as standalone words
+        if keyword.contains(' ') {
+            // Multi-word phrase - check if it appears exactly
+            if text_lower.contains(keyword) {
+                priority = prio.clone();
+                standalone_words_to_remove.push(keyword.to_string());
+                break;
+            }
+        } else {
+            // Single word - check if it appears as a standalone word
+            if words.contains(keyword) {
+                priority = prio.clone();
+                standalone_words_to_remove.push(keyword.to_string());
+                break;
+            }
+        }
+    }
+    
+    // Extract phase - only if standalone words
+    for (keyword, phase_val) in &phase_patterns {
+        if words.contains(keyword) {
+            phase = Some(phase_val.to_string());
+            standalone_words_to_remove.push(keyword.to_string());
+            break;
+        }
+    }
+    
+    // Extract tags - be very careful about context, only exact word matches for short words
+    for (keyword, tag) in &tag_patterns {
+        if keyword.len() <= 3 {
+            // Short words like "ui", "ux", "db" - must be exact standalone words
+            if words.contains(keyword) {
+                tags.push(tag.to_string());
+                standalone_words_to_remove.push(keyword.to_string());
+            }
+        } else {
+            // Longer words - check if they appear as standalone words or as part of compound words
+            if words.iter().any(|&word| word == *keyword || word.contains(keyword)) {
+                tags.push(tag.to_string());
+                // DON'T remove longer words from description to preserve meaning
+                // Only remove if it's clearly a metadata word (like "backend", "frontend")
+                let metadata_word = matches!(*keyword,
+                    "backend" | "frontend" | "testing" | "deployment" | "documentation" | "infrastructure");
+                if metadata_word && words.contains(keyword) {
+                    standalone_words_to_remove.push(keyword.to_string());
+                }
+            }
+        }
+    }
+    
+    // Extract time estimates
+    let time_keywords = [
+        ("hours", 1.0, "h"),
+        ("hour", 1.0, "h"), 
+        ("hrs", 1.0, "hr"),
+        ("hr", 1.0, "hr"),
+        ("days", 8.0, "day"),
+        ("day", 8.0, "day"),
+        ("weeks", 40.0, "week"),
+        ("week", 40.0, "week"),
+    ];
+    
+    // More precise time extraction
+    for i in 0..words.len().saturating_sub(1) {
+        if let Ok(time_val) = words[i].parse::<f64>() {
+            for (keyword, multiplier, _short) in &time_keywords {
+                if words[i + 1].starts_with(keyword) {
+                    estimated_hours = Some(time_val * multiplier);
+                    // Remove the time pattern more precisely
+                    standalone_words_to_remove.push(format!("{} {}", words[i], words[i + 1]));
+                    break;
+                }
+            }
+            if estimated_hours.is_some() {
+                break;
+            }
+        }
+    }
+    
+    // Very careful removal of extracted keywords while preserving description integrity
+    if !standalone_words_to_remove.is_empty() {
+        description = remove_phrases(&description, &standalone_words_to_remove);
+    }
+    
+    // Clean up description (remove extra spaces, trim)
+    description = description
+        .split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .trim()
+        .to_string();
+    
+    // If description is empty or too short after cleaning, preserve more of the original
+    if description.is_empty() || description.len() < 3 {
+        // Keep the original but still extract metadata
+        description = text.to_string();
+    }
+    
+    // Remove duplicates from tags
+    tags.sort();
+    tags.dedup();
+    
+    ParsedTask {
+        description,
+        tags,
+        priority,
+        phase,
+        estimated_hours,
+        ..Default::default()
+    }
+}
+
+/// Remove whole-word occurrences of `phrases` (lowercase, words separated by
+/// single spaces) from `description`, ignoring case
+fn remove_phrases(description: &str, phrases: &[String]) -> String {
+    let words: Vec<_> = DESCRIPTION_WORD.find_iter(description).collect();
+    let mut result = String::with_capacity(description.len());
+    let mut copied_to = 0;
+    let mut i = 0;
+    while i < words.len() {
+        let matched = phrases.iter()
+            .map(|phrase| phrase.split(' ').collect::<Vec<_>>())
+            .find(|parts| {
+                parts.len() <= words.len() - i
+                    && parts.iter().enumerate().all(|(j, part)| {
+                        words[i + j].as_str().eq_ignore_ascii_case(part)
+                            && (j == 0 || description[words[i + j - 1].end()..words[i + j].start()].trim().is_empty())
+                    })
+            })
+            .map(|parts| parts.len());
+        match matched {
+            Some(len) => {
+                result.push_str(&description[copied_to..words[i].start()]);
+                result.push(' ');
+                copied_to = words[i + len - 1].end();
+                i += len;
+            }
+            None => i += 1,
+        }
+    }
+    result.push_str(&description[copied_to..]);
+    result
+}
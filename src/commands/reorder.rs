@@ -0,0 +1,183 @@
+//! Editor-driven reordering of tasks
+//!
+//! `rask reorder` writes the tasks (optionally one phase) to a todo file in
+//! the style of `git rebase -i` and opens it in the user's editor. Moving
+//! lines reorders tasks, changing the command sets the priority, and `drop`
+//! deletes the task. All edits are applied together, tasks are renumbered to
+//! the new order (unless `behavior.stable_ids` is set) and the markdown file
+//! is synced once.
+
+use crate::model::{Priority, Task, TaskStatus};
+use crate::{state, ui};
+use super::{utils, CommandResult};
+use std::collections::HashSet;
+use std::fs;
+
+/// What to do with one task from the todo file
+#[derive(Debug, Clone, PartialEq)]
+enum ReorderAction {
+    Pick,
+    Prioritize(Priority),
+    Drop,
+}
+
+/// Open the reorder todo list for all tasks or one phase and apply the result
+pub fn reorder_tasks(phase: Option<&str>) -> CommandResult {
+    if utils::is_non_interactive() {
+        return Err("rask reorder needs an editor and cannot run with --non-interactive".into());
+    }
+
+    let roadmap = state::load_state()?;
+    let scope: Vec<&Task> = roadmap.tasks.iter()
+        .filter(|t| phase.is_none_or(|p| t.phase.name.eq_ignore_ascii_case(p)))
+        .collect();
+    if scope.is_empty() {
+        return match phase {
+            Some(p) => Err(format!("No tasks in phase '{}'", p).into()),
+            None => Err("No tasks to reorder".into()),
+        };
+    }
+
+    let todo_path = std::env::temp_dir().join(format!("rask-reorder-{}.txt", std::process::id()));
+    fs::write(&todo_path, todo_list(&scope, phase))?;
+    let status = utils::editor_command()?.arg(&todo_path).status();
+    let edited = fs::read_to_string(&todo_path);
+    let _ = fs::remove_file(&todo_path);
+    if !status?.success() {
+        return Err("Editor exited with error; nothing was changed".into());
+    }
+
+    let scope_ids: Vec<usize> = scope.iter().map(|t| t.id).collect();
+    let plan = parse_todo_list(&edited?, &scope_ids)?;
+    if plan.is_empty() {
+        ui::display_info("Nothing to do: the todo list was emptied");
+        return Ok(());
+    }
+
+    // Refuse to drop a task that a remaining task still depends on
+    let dropped: HashSet<usize> = plan.iter()
+        .filter(|(_, action)| *action == ReorderAction::Drop)
+        .map(|(id, _)| *id)
+        .collect();
+    for task in roadmap.tasks.iter().filter(|t| !dropped.contains(&t.id)) {
+        if let Some(dep) = task.dependencies.iter().find(|d| dropped.contains(d)) {
+            return Err(format!("Cannot drop task #{}: task #{} depends on it", dep, task.id).into());
+        }
+    }
+
+    let new_order: Vec<usize> = plan.iter()
+        .filter(|(_, action)| *action != ReorderAction::Drop)
+        .map(|(id, _)| *id)
+        .collect();
+    let moved = new_order.iter().zip(scope_ids.iter().filter(|id| !dropped.contains(id))).any(|(a, b)| a != b);
+    let reprioritized: Vec<(usize, Priority)> = plan.iter()
+        .filter_map(|(id, action)| match action {
+            ReorderAction::Prioritize(priority) => Some((*id, priority.clone())),
+            _ => None,
+        })
+        .filter(|(id, priority)| roadmap.find_task_by_id(*id).is_some_and(|t| t.priority != *priority))
+        .collect();
+
+    if !moved && dropped.is_empty() && reprioritized.is_empty() {
+        ui::display_info("No changes");
+        return Ok(());
+    }
+
+    utils::run_with_checkpoint("reorder", || {
+        let mut roadmap = state::load_state()?;
+        for (id, priority) in &reprioritized {
+            if let Some(task) = roadmap.find_task_by_id_mut(*id) {
+                let old = std::mem::replace(&mut task.priority, priority.clone());
+                task.record_history("priority_changed", Some(format!("{} → {} (reorder)", old, priority)));
+            }
+        }
+        let removed = roadmap.rearrange_tasks(&scope_ids, &new_order);
+        utils::save_and_sync(&roadmap)?;
+
+        ui::display_success(&format!(
+            "Reordered {} task(s){}{}; {}",
+            new_order.len(),
+            if reprioritized.is_empty() { String::new() } else { format!(", reprioritized {}", reprioritized.len()) },
+            if removed.is_empty() { String::new() } else { format!(", dropped {}", removed.len()) },
+            if crate::model::stable_ids() { "IDs are stable and were kept" } else { "tasks were renumbered to match" }
+        ));
+        for task in &removed {
+            println!("   🗑️  Dropped: {}", task.description);
+        }
+        Ok(())
+    })
+}
+
+/// Render the editable todo list
+fn todo_list(tasks: &[&Task], phase: Option<&str>) -> String {
+    let mut content = String::new();
+    for task in tasks {
+        content.push_str(&format!(
+            "pick {} [{}]{} {}\n",
+            task.id,
+            task.priority,
+            if task.status == TaskStatus::Completed { " (done)" } else { "" },
+            task.description
+        ));
+    }
+    content.push_str(&format!(
+        "\n# Reorder {} ({} task(s)).\n\
+         #\n\
+         # Commands:\n\
+         # p, pick <id> = keep the task\n\
+         # c, critical <id> / h, high <id> / m, medium <id> / l, low <id> = keep and set priority\n\
+         # d, drop <id> = delete the task\n\
+         #\n\
+         # Lines are applied top to bottom and tasks are renumbered to the new order.\n\
+         # Every task must keep a line; use 'drop' to delete one.\n\
+         # Text after the ID is ignored. Empty the file to abort.\n",
+        phase.map(|p| format!("phase {}", p)).unwrap_or_else(|| "all tasks".to_string()),
+        tasks.len()
+    ));
+    content
+}
+
+/// Parse the edited todo list, checking that every task in scope appears exactly once
+fn parse_todo_list(content: &str, scope: &[usize]) -> Result<Vec<(usize, ReorderAction)>, String> {
+    let mut plan: Vec<(usize, ReorderAction)> = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let action = match command.to_lowercase().as_str() {
+            "p" | "pick" => ReorderAction::Pick,
+            "d" | "drop" => ReorderAction::Drop,
+            "c" | "critical" => ReorderAction::Prioritize(Priority::Critical),
+            "h" | "high" => ReorderAction::Prioritize(Priority::High),
+            "m" | "medium" => ReorderAction::Prioritize(Priority::Medium),
+            "l" | "low" => ReorderAction::Prioritize(Priority::Low),
+            _ => return Err(format!("Line {}: unknown command '{}'", number + 1, command)),
+        };
+        let id = words.next()
+            .and_then(|w| w.trim_start_matches('#').parse::<usize>().ok())
+            .ok_or_else(|| format!("Line {}: expected a task ID after '{}'", number + 1, command))?;
+
+        if !scope.contains(&id) {
+            return Err(format!("Line {}: task #{} is not part of this reorder", number + 1, id));
+        }
+        if plan.iter().any(|(seen, _)| *seen == id) {
+            return Err(format!("Line {}: task #{} appears more than once", number + 1, id));
+        }
+        plan.push((id, action));
+    }
+
+    if !plan.is_empty() {
+        let missing: Vec<String> = scope.iter()
+            .filter(|id| !plan.iter().any(|(seen, _)| seen == *id))
+            .map(|id| format!("#{}", id))
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!("Missing task(s) {}; use 'drop <id>' to delete a task", missing.join(", ")));
+        }
+    }
+    Ok(plan)
+}
@@ -0,0 +1,263 @@
+//! Reports built from tracked time
+//!
+//! `rask report timesheet` adds up finished time sessions over a calendar
+//! week (one column per day) or month (one column per week), with a row per
+//! task, tag or phase. Sessions count towards the local day they started on.
+//! The same table can be written as CSV for invoicing or standup notes.
+
+use crate::cli::TimesheetGroup;
+use crate::model::{parse_timestamp, Task};
+use crate::{state, ui};
+use super::{time_trackers::csv_field, CommandResult};
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use colored::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Row label for tasks without tags when grouping by tag
+const UNTAGGED: &str = "(untagged)";
+
+/// A column of the timesheet: an inclusive range of days
+struct Column {
+    label: String,
+    first: NaiveDate,
+    last: NaiveDate,
+}
+
+/// Aggregated hours for one row of the timesheet
+#[derive(Default)]
+struct Row {
+    hours: Vec<f64>,
+    sessions: usize,
+}
+
+impl Row {
+    fn total(&self) -> f64 {
+        self.hours.iter().sum()
+    }
+}
+
+/// Print a weekly or monthly timesheet, optionally writing it as CSV
+pub fn generate_timesheet(month: bool, date: Option<&str>, group_by: TimesheetGroup, csv: Option<&Path>) -> CommandResult {
+    let day = match date {
+        Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date '{}'. Use YYYY-MM-DD", date))?,
+        None => Local::now().date_naive(),
+    };
+    let (title, columns) = if month { month_columns(day) } else { week_columns(day) };
+    let period_start = columns.first().map(|c| c.first).unwrap_or(day);
+    let period_end = columns.last().map(|c| c.last).unwrap_or(day);
+
+    let roadmap = state::load_state()?;
+    let mut rows: BTreeMap<(String, usize), Row> = BTreeMap::new();
+    let mut running = 0;
+    for task in &roadmap.tasks {
+        for session in &task.time_sessions {
+            let Some(start) = parse_timestamp(Some(&session.start_time)) else {
+                continue;
+            };
+            let start_day = start.with_timezone(&Local).date_naive();
+            if start_day < period_start || start_day > period_end {
+                continue;
+            }
+            let Some(hours) = session.duration_hours() else {
+                running += 1;
+                continue;
+            };
+            // Sessions shorter than a minute record no time
+            if hours <= 0.0 {
+                continue;
+            }
+            let Some(column) = columns.iter().position(|c| c.first <= start_day && start_day <= c.last) else {
+                continue;
+            };
+            for key in row_keys(task, group_by) {
+                let row = rows.entry(key).or_default();
+                row.hours.resize(columns.len(), 0.0);
+                row.hours[column] += hours;
+                row.sessions += 1;
+            }
+        }
+    }
+
+    display_timesheet(&title, &columns, &rows, group_by);
+    if running > 0 {
+        ui::display_info(&format!("⏱️  {} running session(s) in this period are not counted until stopped", running));
+    }
+
+    if let Some(path) = csv {
+        fs::write(path, timesheet_csv(&columns, &rows, group_by))?;
+        ui::display_success(&format!("Timesheet written to {}", path.display()));
+    }
+
+    Ok(())
+}
+
+/// Monday-to-Sunday columns for the week containing `day`
+fn week_columns(day: NaiveDate) -> (String, Vec<Column>) {
+    let monday = day - Duration::days(day.weekday().num_days_from_monday() as i64);
+    let columns = (0..7)
+        .map(|offset| {
+            let date = monday + Duration::days(offset);
+            Column { label: date.format("%a %d").to_string(), first: date, last: date }
+        })
+        .collect();
+    let week = monday.iso_week();
+    (format!("Week {} of {} ({} – {})", week.week(), week.year(), monday, monday + Duration::days(6)), columns)
+}
+
+/// Monday-to-Sunday week columns covering the month containing `day`, clipped to the month
+fn month_columns(day: NaiveDate) -> (String, Vec<Column>) {
+    let first = day.with_day(1).unwrap_or(day);
+    let next_month = if first.month() == 12 {
+        NaiveDate::from_ymd_opt(first.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(first.year(), first.month() + 1, 1)
+    };
+    let last = next_month.map(|d| d - Duration::days(1)).unwrap_or(first);
+
+    let mut columns = Vec::new();
+    let mut week_start = first;
+    while week_start <= last {
+        let days_to_sunday = 6 - week_start.weekday().num_days_from_monday() as i64;
+        let week_end = (week_start + Duration::days(days_to_sunday)).min(last);
+        columns.push(Column {
+            label: format!("{}–{}", week_start.format("%d"), week_end.format("%d")),
+            first: week_start,
+            last: week_end,
+        });
+        week_start = week_end + Duration::days(1);
+    }
+    (first.format("%B %Y").to_string(), columns)
+}
+
+/// The rows a task's time counts towards; the ID keeps task rows in order
+fn row_keys(task: &Task, group_by: TimesheetGroup) -> Vec<(String, usize)> {
+    match group_by {
+        TimesheetGroup::Task => vec![(format!("#{} {}", task.id, task.description), task.id)],
+        TimesheetGroup::Phase => vec![(task.phase.name.clone(), 0)],
+        TimesheetGroup::Tag if task.tags.is_empty() => vec![(UNTAGGED.to_string(), 0)],
+        TimesheetGroup::Tag => {
+            let mut tags: Vec<_> = task.tags.iter().map(|tag| (tag.clone(), 0)).collect();
+            tags.sort();
+            tags
+        }
+    }
+}
+
+/// Rows in display order: by task ID for tasks, alphabetically otherwise
+fn ordered_rows(rows: &BTreeMap<(String, usize), Row>, group_by: TimesheetGroup) -> Vec<(&String, &Row)> {
+    let mut ordered: Vec<_> = rows.iter().collect();
+    if group_by == TimesheetGroup::Task {
+        ordered.sort_by_key(|((_, id), _)| *id);
+    }
+    ordered.into_iter().map(|((label, _), row)| (label, row)).collect()
+}
+
+/// Hours as a compact cell, blank for zero
+fn hours_cell(hours: f64) -> String {
+    if hours > 0.0 { format!("{:.2}", hours) } else { "-".to_string() }
+}
+
+fn display_timesheet(title: &str, columns: &[Column], rows: &BTreeMap<(String, usize), Row>, group_by: TimesheetGroup) {
+    println!("\n{}", format!("🧾 Timesheet — {}", title).bright_cyan().bold());
+
+    if rows.is_empty() {
+        println!("{}", "─".repeat(60).bright_black());
+        println!("  {}", "No finished time sessions in this period.".yellow());
+        println!("  {}", "Track time with 'rask start'/'rask stop' or backfill with 'rask time add'.".dimmed());
+        return;
+    }
+
+    let label_header = match group_by {
+        TimesheetGroup::Task => "Task",
+        TimesheetGroup::Tag => "Tag",
+        TimesheetGroup::Phase => "Phase",
+    };
+    let ordered = ordered_rows(rows, group_by);
+    let label_width = ordered.iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        .clamp(label_header.len(), 40);
+    let cell_width = columns.iter().map(|c| c.label.chars().count()).max().unwrap_or(0).max(6);
+    let line_width = label_width + (columns.len() + 1) * (cell_width + 2);
+
+    println!("{}", "─".repeat(line_width).bright_black());
+    let mut header = format!("{:<width$}", label_header, width = label_width);
+    for column in columns {
+        header.push_str(&format!("  {:>width$}", column.label, width = cell_width));
+    }
+    header.push_str(&format!("  {:>width$}", "Total", width = cell_width));
+    println!("{}", header.bold());
+    println!("{}", "─".repeat(line_width).bright_black());
+
+    let mut column_totals = vec![0.0; columns.len()];
+    for (label, row) in &ordered {
+        let label: String = if label.chars().count() > label_width {
+            label.chars().take(label_width - 1).chain(std::iter::once('…')).collect()
+        } else {
+            label.to_string()
+        };
+        let mut line = format!("{:<width$}", label, width = label_width);
+        for (index, hours) in row.hours.iter().enumerate() {
+            column_totals[index] += hours;
+            line.push_str(&format!("  {:>width$}", hours_cell(*hours), width = cell_width));
+        }
+        line.push_str(&format!("  {}", format!("{:>width$.2}", row.total(), width = cell_width).bright_white().bold()));
+        println!("{}", line);
+    }
+
+    println!("{}", "─".repeat(line_width).bright_black());
+    let mut footer = format!("{:<width$}", "Total", width = label_width);
+    for total in &column_totals {
+        footer.push_str(&format!("  {:>width$}", hours_cell(*total), width = cell_width));
+    }
+    footer.push_str(&format!("  {:>width$}", format!("{:.2}", column_totals.iter().sum::<f64>()), width = cell_width));
+    println!("{}", footer.bold());
+
+    let sessions: usize = rows.values().map(|row| row.sessions).sum();
+    if group_by == TimesheetGroup::Tag {
+        println!("{}", "💡 Tasks with several tags count under each tag, so totals can exceed the time tracked.".dimmed());
+    } else {
+        println!("{}", format!("📊 {} session(s)", sessions).dimmed());
+    }
+    println!();
+}
+
+/// The timesheet table as CSV, one row per group plus a total row
+fn timesheet_csv(columns: &[Column], rows: &BTreeMap<(String, usize), Row>, group_by: TimesheetGroup) -> String {
+    let label_header = match group_by {
+        TimesheetGroup::Task => "Task",
+        TimesheetGroup::Tag => "Tag",
+        TimesheetGroup::Phase => "Phase",
+    };
+    let mut csv = String::from(label_header);
+    for column in columns {
+        csv.push(',');
+        csv.push_str(&csv_field(&if column.first == column.last {
+            column.first.to_string()
+        } else {
+            format!("{} to {}", column.first, column.last)
+        }));
+    }
+    csv.push_str(",Total,Sessions\n");
+
+    let mut column_totals = vec![0.0; columns.len()];
+    for (label, row) in ordered_rows(rows, group_by) {
+        csv.push_str(&csv_field(label));
+        for (index, hours) in row.hours.iter().enumerate() {
+            column_totals[index] += hours;
+            csv.push_str(&format!(",{:.2}", hours));
+        }
+        csv.push_str(&format!(",{:.2},{}\n", row.total(), row.sessions));
+    }
+
+    csv.push_str("Total");
+    for total in &column_totals {
+        csv.push_str(&format!(",{:.2}", total));
+    }
+    csv.push_str(&format!(",{:.2},{}\n", column_totals.iter().sum::<f64>(), rows.values().map(|row| row.sessions).sum::<usize>()));
+    csv
+}
@@ -0,0 +1,370 @@
+//! Phase retrospectives
+//!
+//! `rask retro --phase <name>` looks back over one phase (live or archived):
+//! what was planned and what shipped, how good the estimates were, which
+//! tasks slipped the most, and how long work sat blocked on dependencies.
+//! Lessons-learned bullets come from simple heuristics, or from the
+//! configured AI provider with `--ai`, and the whole report can be written
+//! to markdown for the team's retro doc.
+
+use crate::ai::service::AiService;
+use crate::config::RaskConfig;
+use crate::model::{Phase, Roadmap, Task, TaskStatus};
+use crate::{state, ui};
+use super::CommandResult;
+use chrono::{DateTime, Utc};
+use colored::*;
+use std::path::Path;
+
+/// Number of slipped tasks listed in the report
+const MAX_SLIPS: usize = 5;
+
+/// A task that took longer than planned
+struct Slip {
+    id: usize,
+    description: String,
+    over_hours: Option<f64>,
+    days_late: Option<i64>,
+}
+
+/// Everything a phase retrospective reports
+struct Retrospective {
+    project: String,
+    phase: String,
+    archived: bool,
+    planned: usize,
+    delivered: usize,
+    not_delivered: Vec<(usize, String)>,
+    estimated_hours: f64,
+    actual_hours: f64,
+    estimation_accuracy: Option<f64>,
+    slips: Vec<Slip>,
+    blocked_hours: f64,
+    most_blocked: Option<(usize, String, f64)>,
+    lessons: Vec<String>,
+}
+
+/// Generate a retrospective for a phase, optionally with AI-written lessons
+pub fn generate_retro(phase_name: &str, use_ai: bool, output: Option<&Path>) -> CommandResult {
+    let roadmap = state::load_state()?;
+    let phase = Phase::from_string(phase_name);
+
+    // Fall back to the phase archive once a phase has been archived
+    let (tasks, archived): (Vec<Task>, bool) = {
+        let live: Vec<Task> = roadmap.filter_by_phase(&phase).into_iter().cloned().collect();
+        if !live.is_empty() {
+            (live, false)
+        } else if let Some(archive) = state::load_phase_archive(&phase.name)? {
+            (archive.tasks, true)
+        } else {
+            return Err(format!("No tasks found in '{}' phase, live or archived", phase.name).into());
+        }
+    };
+
+    let mut retro = build_retrospective(&roadmap, &phase, &tasks, archived);
+
+    retro.lessons = if use_ai {
+        match ai_lessons(&retro) {
+            Ok(lessons) if !lessons.is_empty() => lessons,
+            Ok(_) => {
+                ui::display_warning("AI returned no lessons; using the built-in summary instead");
+                heuristic_lessons(&retro)
+            }
+            Err(e) => {
+                ui::display_warning(&format!("AI lessons unavailable ({}); using the built-in summary instead", e));
+                heuristic_lessons(&retro)
+            }
+        }
+    } else {
+        heuristic_lessons(&retro)
+    };
+
+    display_retrospective(&retro);
+
+    if let Some(path) = output {
+        std::fs::write(path, retrospective_to_markdown(&retro))
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        ui::display_success(&format!("📝 Retrospective written to {}", path.display()));
+    }
+
+    Ok(())
+}
+
+/// Compute the retrospective figures for a phase's tasks
+fn build_retrospective(roadmap: &Roadmap, phase: &Phase, tasks: &[Task], archived: bool) -> Retrospective {
+    let delivered = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
+    let not_delivered = tasks.iter()
+        .filter(|t| t.status == TaskStatus::Pending)
+        .map(|t| (t.id, t.description.clone()))
+        .collect();
+
+    // Estimate accuracy only counts tasks that have both numbers
+    let measured: Vec<&Task> = tasks.iter()
+        .filter(|t| t.estimated_hours.is_some() && t.actual_hours.is_some())
+        .collect();
+    let estimated_hours: f64 = measured.iter().filter_map(|t| t.estimated_hours).sum();
+    let actual_hours: f64 = measured.iter().filter_map(|t| t.actual_hours).sum();
+    let estimation_accuracy = (estimated_hours > 0.0).then(|| {
+        let variance = (actual_hours - estimated_hours).abs();
+        ((estimated_hours - variance) / estimated_hours * 100.0).max(0.0)
+    });
+
+    // Slips: over the estimate, or finished after the due date
+    let mut slips: Vec<Slip> = tasks.iter()
+        .filter_map(|task| {
+            let over_hours = task.get_time_variance().filter(|v| *v > 0.0);
+            let days_late = match (task.due_date_parsed(), task.completed_datetime()) {
+                (Some(due), Some(done)) => Some((done.date_naive() - due).num_days()).filter(|d| *d > 0),
+                _ => None,
+            };
+            (over_hours.is_some() || days_late.is_some()).then(|| Slip {
+                id: task.id,
+                description: task.description.clone(),
+                over_hours,
+                days_late,
+            })
+        })
+        .collect();
+    slips.sort_by(|a, b| slip_weight(b).total_cmp(&slip_weight(a)));
+    slips.truncate(MAX_SLIPS);
+
+    // Blocked time: from creation until the last dependency was done
+    let mut blocked_hours = 0.0;
+    let mut most_blocked: Option<(usize, String, f64)> = None;
+    for task in tasks {
+        let hours = blocked_hours_for(task, roadmap, tasks);
+        if hours <= 0.0 {
+            continue;
+        }
+        blocked_hours += hours;
+        if most_blocked.as_ref().is_none_or(|(_, _, worst)| hours > *worst) {
+            most_blocked = Some((task.id, task.description.clone(), hours));
+        }
+    }
+
+    Retrospective {
+        project: roadmap.title.clone(),
+        phase: phase.name.clone(),
+        archived,
+        planned: tasks.len(),
+        delivered,
+        not_delivered,
+        estimated_hours,
+        actual_hours,
+        estimation_accuracy,
+        slips,
+        blocked_hours,
+        most_blocked,
+        lessons: Vec::new(),
+    }
+}
+
+/// Rank slips by overrun, treating a day late like a working day of hours
+fn slip_weight(slip: &Slip) -> f64 {
+    slip.over_hours.unwrap_or(0.0) + slip.days_late.unwrap_or(0) as f64 * 8.0
+}
+
+/// Hours a task waited on its dependencies after it was created
+fn blocked_hours_for(task: &Task, roadmap: &Roadmap, phase_tasks: &[Task]) -> f64 {
+    if task.dependencies.is_empty() {
+        return 0.0;
+    }
+    let Some(created) = task.created_datetime() else {
+        return 0.0;
+    };
+
+    let mut unblocked_at: Option<DateTime<Utc>> = None;
+    for dep_id in &task.dependencies {
+        // Archived tasks keep their old IDs, so look in the phase first
+        let dep = phase_tasks.iter().find(|t| t.id == *dep_id)
+            .or_else(|| roadmap.find_task_by_id(*dep_id));
+        let Some(dep) = dep else {
+            continue;
+        };
+
+        // A dependency still open blocks until now, or until the task itself was done
+        let done = dep.completed_datetime()
+            .or_else(|| task.completed_datetime())
+            .unwrap_or_else(Utc::now);
+        unblocked_at = Some(unblocked_at.map_or(done, |current| current.max(done)));
+    }
+
+    unblocked_at
+        .map(|until| (until - created).num_minutes() as f64 / 60.0)
+        .unwrap_or(0.0)
+        .max(0.0)
+}
+
+/// Lessons derived from the numbers when no AI is involved
+fn heuristic_lessons(retro: &Retrospective) -> Vec<String> {
+    let mut lessons = Vec::new();
+
+    if retro.planned > 0 && retro.delivered < retro.planned {
+        lessons.push(format!(
+            "{} of {} planned tasks were not delivered; trim scope earlier or move them to the next phase explicitly.",
+            retro.planned - retro.delivered, retro.planned
+        ));
+    }
+
+    if retro.estimated_hours > 0.0 {
+        let ratio = retro.actual_hours / retro.estimated_hours;
+        if ratio > 1.2 {
+            lessons.push(format!(
+                "Work took {:.0}% longer than estimated; pad similar estimates or split large tasks.",
+                (ratio - 1.0) * 100.0
+            ));
+        } else if ratio < 0.8 {
+            lessons.push(format!(
+                "Work took {:.0}% less time than estimated; estimates for this kind of work can be tightened.",
+                (1.0 - ratio) * 100.0
+            ));
+        } else {
+            lessons.push("Estimates were within 20% of actual time; keep the current estimating approach.".to_string());
+        }
+    } else {
+        lessons.push("No task had both an estimate and tracked time; estimate and track time to learn from the next phase.".to_string());
+    }
+
+    if let Some((id, _, hours)) = &retro.most_blocked {
+        lessons.push(format!(
+            "Tasks spent {:.1}h waiting on dependencies (worst: #{} at {:.1}h); start blocking work earlier.",
+            retro.blocked_hours, id, hours
+        ));
+    }
+
+    if retro.slips.iter().any(|s| s.days_late.is_some()) {
+        lessons.push("Some tasks finished after their due date; review due dates when scope changes.".to_string());
+    }
+
+    lessons
+}
+
+/// Ask the configured AI provider for lessons-learned bullets
+fn ai_lessons(retro: &Retrospective) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+    let config = RaskConfig::load().map_err(|e| format!("Failed to load configuration: {}", e))?;
+    if !config.ai.is_ready() {
+        return Err("AI is not configured; run 'rask ai configure' first".into());
+    }
+
+    let prompt = format!(
+        "You are helping a software team run a retrospective. Write 3 to 5 short lessons-learned \
+        bullet points, one per line starting with '- ', based only on this phase summary. \
+        Do not repeat the numbers back verbatim.\n\n{}",
+        retrospective_to_markdown(retro)
+    );
+
+    let runtime = tokio::runtime::Runtime::new()
+        .map_err(|e| format!("Failed to create async runtime: {}", e))?;
+    let response = runtime.block_on(async {
+        let service = AiService::new(config).await?;
+        service.chat(prompt).await
+    }).map_err(|e| e.to_string())?;
+
+    Ok(response.lines()
+        .map(str::trim)
+        .filter_map(|line| line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")).or_else(|| line.strip_prefix("• ")))
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+        .collect())
+}
+
+/// Print the retrospective to the terminal
+fn display_retrospective(retro: &Retrospective) {
+    println!("\n{}", format!("🔁 Retrospective: {} — {}{}", retro.project, retro.phase,
+        if retro.archived { " (archived)" } else { "" }).bright_cyan().bold());
+    println!("{}", "═".repeat(60).bright_cyan());
+
+    let delivery_rate = if retro.planned > 0 { retro.delivered as f64 / retro.planned as f64 * 100.0 } else { 0.0 };
+    println!("\n  📦 {}: {} planned, {} delivered ({:.0}%)",
+        "Planned vs delivered".bold(), retro.planned, retro.delivered, delivery_rate);
+    for (id, description) in &retro.not_delivered {
+        println!("      {} #{} {}", "⏳".yellow(), id, description);
+    }
+
+    match retro.estimation_accuracy {
+        Some(accuracy) => println!("\n  🎯 {}: {:.1}h estimated, {:.1}h actual ({:.0}% accurate)",
+            "Estimates".bold(), retro.estimated_hours, retro.actual_hours, accuracy),
+        None => println!("\n  🎯 {}: {}", "Estimates".bold(), "no tasks with both an estimate and tracked time".bright_black()),
+    }
+
+    if retro.slips.is_empty() {
+        println!("\n  📉 {}: none", "Biggest slips".bold());
+    } else {
+        println!("\n  📉 {}:", "Biggest slips".bold());
+        for slip in &retro.slips {
+            println!("      #{} {} {}", slip.id, slip.description, describe_slip(slip).bright_red());
+        }
+    }
+
+    println!("\n  🔒 {}: {:.1}h across the phase", "Blocked time".bold(), retro.blocked_hours);
+    if let Some((id, description, hours)) = &retro.most_blocked {
+        println!("      Longest wait: #{} {} ({:.1}h)", id, description, hours);
+    }
+
+    println!("\n  💡 {}:", "Lessons learned".bold());
+    for lesson in &retro.lessons {
+        println!("      • {}", lesson);
+    }
+    println!();
+}
+
+/// Short description of how a task slipped
+fn describe_slip(slip: &Slip) -> String {
+    let mut parts = Vec::new();
+    if let Some(hours) = slip.over_hours {
+        parts.push(format!("+{:.1}h over estimate", hours));
+    }
+    if let Some(days) = slip.days_late {
+        parts.push(format!("{} day(s) late", days));
+    }
+    format!("({})", parts.join(", "))
+}
+
+/// Render the retrospective as markdown for a retro doc
+fn retrospective_to_markdown(retro: &Retrospective) -> String {
+    let mut md = format!("# Retrospective: {} — {}\n\n", retro.project, retro.phase);
+    md.push_str(&format!("_Generated {}{}_\n\n",
+        chrono::Local::now().format("%Y-%m-%d"),
+        if retro.archived { " from the phase archive" } else { "" }));
+
+    md.push_str("## Planned vs delivered\n\n");
+    md.push_str(&format!("- Planned: {}\n- Delivered: {}\n", retro.planned, retro.delivered));
+    if !retro.not_delivered.is_empty() {
+        md.push_str("- Not delivered:\n");
+        for (id, description) in &retro.not_delivered {
+            md.push_str(&format!("  - #{} {}\n", id, description));
+        }
+    }
+
+    md.push_str("\n## Estimates\n\n");
+    match retro.estimation_accuracy {
+        Some(accuracy) => md.push_str(&format!(
+            "- Estimated: {:.1}h\n- Actual: {:.1}h\n- Accuracy: {:.0}%\n",
+            retro.estimated_hours, retro.actual_hours, accuracy
+        )),
+        None => md.push_str("- No tasks with both an estimate and tracked time\n"),
+    }
+
+    md.push_str("\n## Biggest slips\n\n");
+    if retro.slips.is_empty() {
+        md.push_str("- None\n");
+    }
+    for slip in &retro.slips {
+        md.push_str(&format!("- #{} {} {}\n", slip.id, slip.description, describe_slip(slip)));
+    }
+
+    md.push_str("\n## Blocked time\n\n");
+    md.push_str(&format!("- Total: {:.1}h\n", retro.blocked_hours));
+    if let Some((id, description, hours)) = &retro.most_blocked {
+        md.push_str(&format!("- Longest wait: #{} {} ({:.1}h)\n", id, description, hours));
+    }
+
+    if !retro.lessons.is_empty() {
+        md.push_str("\n## Lessons learned\n\n");
+        for lesson in &retro.lessons {
+            md.push_str(&format!("- {}\n", lesson));
+        }
+    }
+
+    md
+}
@@ -0,0 +1,182 @@
+//! Guided task review
+//!
+//! `rask review` walks through the pending tasks that need grooming, one at a
+//! time: tasks that were never reviewed, and tasks with no activity (history,
+//! time sessions, or an earlier review) within `--older-than`. Each one can be kept,
+//! completed, rescheduled, retagged, or removed. Every decision is saved right
+//! away, so quitting halfway keeps the work done so far, and stamps the task's
+//! `reviewed_at` so it does not come up again until it goes stale.
+
+use crate::model::{Roadmap, Task, TaskStatus};
+use crate::{hooks, ui};
+use super::{utils, CommandResult};
+use chrono::{DateTime, Utc};
+use colored::*;
+
+/// What the reviewer decided for one task
+enum Decision {
+    Keep,
+    Complete,
+    Reschedule,
+    Retag,
+    Remove,
+    Skip,
+    Quit,
+}
+
+/// Whether a pending task is due for review
+fn needs_review(task: &Task, cutoff: DateTime<Utc>) -> bool {
+    task.status == TaskStatus::Pending
+        && (task.reviewed_at.is_none() || task.last_activity().is_none_or(|at| at < cutoff))
+}
+
+fn ask_decision() -> Result<Decision, Box<dyn std::error::Error>> {
+    loop {
+        let answer = utils::prompt_line(&format!("  {}eep, {}omplete, {}eschedule, re{}ag, remove ({}), {}kip, {}uit [k]: ",
+            "[k]".bright_green(), "[c]".bright_green(), "[r]".bright_yellow(), "[t]".bright_yellow(),
+            "x".bright_red(), "[s]".bright_black(), "[q]".bright_black()
+        ))?;
+        // End of input stops the review rather than keeping every remaining task
+        if answer.is_empty() {
+            return Ok(Decision::Quit);
+        }
+        let decision = match answer.trim().to_lowercase().as_str() {
+            "" | "k" | "keep" => Decision::Keep,
+            "c" | "complete" => Decision::Complete,
+            "r" | "reschedule" => Decision::Reschedule,
+            "t" | "retag" => Decision::Retag,
+            "x" | "remove" => Decision::Remove,
+            "s" | "skip" => Decision::Skip,
+            "q" | "quit" => Decision::Quit,
+            other => {
+                ui::display_warning(&format!("Unknown choice '{}'", other));
+                continue;
+            }
+        };
+        return Ok(decision);
+    }
+}
+
+/// Stamp a task as reviewed, noting the decision in its history
+fn mark_reviewed(task: &mut Task, outcome: &str) {
+    task.reviewed_at = Some(Utc::now().to_rfc3339());
+    task.record_history("reviewed", Some(outcome.to_string()));
+}
+
+/// Apply a decision to the task with `uuid`. Returns a short description of
+/// what changed, or `None` when the task was left alone.
+fn apply_decision(roadmap: &mut Roadmap, uuid: &str, decision: &Decision) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    let Some(index) = roadmap.tasks.iter().position(|t| t.uuid == uuid) else {
+        return Ok(None);
+    };
+    let id = roadmap.tasks[index].id;
+    let outcome = match decision {
+        Decision::Keep => "kept".to_string(),
+        Decision::Complete => {
+            let completed = roadmap.get_completed_task_ids();
+            if !roadmap.tasks[index].can_be_started(&completed) {
+                ui::display_warning(&format!("Task #{} still has open dependencies; kept as is", id));
+                return Ok(None);
+            }
+            hooks::run_pre_hook("pre-complete", &roadmap.tasks[index])?;
+            roadmap.tasks[index].mark_completed();
+            roadmap.tasks[index].record_history("completed", None);
+            "completed".to_string()
+        }
+        Decision::Reschedule => {
+            let answer = utils::prompt_line("  New due date (YYYY-MM-DD, today, tomorrow; empty to clear): ")?;
+            let due = match answer.trim() {
+                "" => None,
+                date => Some(utils::parse_due_date(date)?.format("%Y-%m-%d").to_string()),
+            };
+            let outcome = match &due {
+                Some(date) => format!("rescheduled to {}", date),
+                None => "due date cleared".to_string(),
+            };
+            roadmap.tasks[index].due_date = due;
+            outcome
+        }
+        Decision::Retag => {
+            let answer = utils::prompt_line("  Tags (comma-separated, replaces the current ones; empty to clear): ")?;
+            let tags = if answer.trim().is_empty() { Vec::new() } else { utils::validate_and_parse_tags(&answer)? };
+            roadmap.tasks[index].tags = tags.iter().cloned().collect();
+            if tags.is_empty() { "tags cleared".to_string() } else { format!("retagged {}", tags.join(", ")) }
+        }
+        Decision::Remove => {
+            let dependents: Vec<usize> = roadmap.tasks.iter()
+                .filter(|t| t.dependencies.contains(&id))
+                .map(|t| t.id)
+                .collect();
+            if !dependents.is_empty() {
+                ui::display_warning(&format!("Task #{} cannot be removed, other tasks depend on it: {:?}", id, dependents));
+                return Ok(None);
+            }
+            if !utils::confirm(&format!("  Remove task #{}?", id))? {
+                return Ok(None);
+            }
+            hooks::run_pre_hook("pre-remove", &roadmap.tasks[index])?;
+            if let Some(removed) = roadmap.remove_task(id) {
+                utils::save_and_sync(roadmap)?;
+                hooks::run_post_hook("post-remove", &removed);
+            }
+            return Ok(Some("removed".to_string()));
+        }
+        Decision::Skip | Decision::Quit => return Ok(None),
+    };
+
+    mark_reviewed(&mut roadmap.tasks[index], &outcome);
+    utils::save_and_sync(roadmap)?;
+    if matches!(decision, Decision::Complete) {
+        hooks::run_post_hook("post-complete", &roadmap.tasks[index]);
+    }
+    Ok(Some(outcome))
+}
+
+/// Walk through stale and never-reviewed pending tasks one by one
+pub fn review_tasks(older_than: &str) -> CommandResult {
+    let span = utils::parse_day_span(older_than)?;
+    let cutoff = Utc::now() - span;
+    let mut roadmap = crate::state::load_state()?;
+
+    // Tasks are tracked by UUID since removing one renumbers the rest
+    let queue: Vec<String> = roadmap.tasks.iter()
+        .filter(|t| needs_review(t, cutoff))
+        .map(|t| t.uuid.clone())
+        .collect();
+    if queue.is_empty() {
+        ui::display_success(&format!("Nothing to review: every pending task was reviewed or active in the last {} days", span.num_days()));
+        return Ok(());
+    }
+
+    let icon = |emoji: &str| ui::theme::theme().icon(emoji);
+    println!("\n  {}{} ({} tasks)", icon("🧹"), "Task Review".bold().bright_cyan(), queue.len());
+    println!("  {}", ui::theme::theme().rule("─", 50).bright_black());
+
+    let mut changed = 0;
+    for (position, uuid) in queue.iter().enumerate() {
+        let Some(task) = roadmap.tasks.iter().find(|t| &t.uuid == uuid) else {
+            continue;
+        };
+        println!("\n  {}", format!("[{}/{}]", position + 1, queue.len()).bright_black());
+        ui::display_task_lines(&[task], true);
+        let idle = match (task.idle_days(Utc::now()), &task.reviewed_at) {
+            (_, None) => "never reviewed".to_string(),
+            (Some(days), Some(_)) => format!("no activity for {} days", days),
+            (None, Some(_)) => "no recorded activity".to_string(),
+        };
+        println!("       {}", idle.bright_black());
+
+        let decision = ask_decision()?;
+        if matches!(decision, Decision::Quit) {
+            break;
+        }
+        if let Some(outcome) = apply_decision(&mut roadmap, uuid, &decision)? {
+            ui::display_success(&format!("Task {}", outcome));
+            changed += 1;
+        }
+    }
+
+    println!();
+    ui::display_info(&format!("Review finished: {} of {} tasks updated", changed, queue.len()));
+    Ok(())
+}
@@ -0,0 +1,225 @@
+//! Automation rule commands
+//!
+//! Rules themselves live in config.toml and run automatically whenever a
+//! change is saved, or for escalations whenever state is loaded (see
+//! [`crate::rules`]). These commands list them, dry-run them against a
+//! simulated event, apply escalations on demand, and show the audit log of
+//! past runs.
+
+use crate::cli::{RuleTestEvent, RulesCommands};
+use crate::config::{EscalationRule, RaskConfig, RuleTrigger};
+use crate::model::TaskStatus;
+use crate::{rules, state, ui};
+use super::{utils, CommandError, CommandResult};
+use colored::Colorize;
+
+/// Handle rules subcommands
+pub fn handle_rules_command(rules_command: &RulesCommands) -> CommandResult {
+    match rules_command {
+        RulesCommands::List => list_rules(),
+        RulesCommands::Test { task, event, phase } => test_rules(*task, *event, phase.as_deref()),
+        RulesCommands::Run { dry_run } => run_escalations(*dry_run),
+        RulesCommands::Log { limit } => show_rules_log(*limit),
+    }
+}
+
+/// List configured rules with their trigger and actions
+pub fn list_rules() -> CommandResult {
+    let config = RaskConfig::load()?;
+    if config.rules.is_empty() && config.escalations.is_empty() {
+        ui::display_info("No automation rules configured. Add [[rules]] or [[escalations]] entries to config.toml (see README)");
+        return Ok(());
+    }
+    if !config.escalations.is_empty() {
+        list_escalations(&config.escalations);
+    }
+    if config.rules.is_empty() {
+        return Ok(());
+    }
+
+    println!("⚙️  {} automation rule(s)", config.rules.len());
+    for rule in &config.rules {
+        let mut filters = Vec::new();
+        if let Some(tag) = &rule.tag {
+            filters.push(format!("#{}", tag.trim_start_matches('#')));
+        }
+        if let Some(phase) = &rule.phase {
+            filters.push(format!("phase {}", phase));
+        }
+        let filters = if filters.is_empty() { String::new() } else { format!(" [{}]", filters.join(", ")) };
+        let name = if rule.enabled { rule.name.bold() } else { format!("{} (disabled)", rule.name).dimmed() };
+
+        println!("\n  {} — when {}{}", name, rule.when, filters);
+        if let Some(task) = &rule.add_task {
+            println!("    → add task \"{}\"{}", task, if rule.depends_on_trigger { " depending on the trigger" } else { "" });
+        }
+        if let Some(url) = &rule.webhook {
+            println!("    → post to {}", url);
+        }
+        if rule.add_task.is_none() && rule.webhook.is_none() {
+            println!("    {}", "→ no actions".dimmed());
+        }
+    }
+    Ok(())
+}
+
+/// List escalation rules with their conditions and actions
+fn list_escalations(escalations: &[EscalationRule]) {
+    println!("⏫ {} escalation rule(s)", escalations.len());
+    for rule in escalations {
+        let mut conditions = Vec::new();
+        if let Some(days) = rule.due_within_days {
+            conditions.push(format!("due within {} days", days));
+        }
+        if let Some(days) = rule.blocked_for_days {
+            conditions.push(format!("blocked for over {} days", days));
+        }
+        if let Some(priority) = &rule.priority_below {
+            conditions.push(format!("priority below {}", priority));
+        }
+        if let Some(tag) = &rule.tag {
+            conditions.push(format!("#{}", tag.trim_start_matches('#')));
+        }
+        if let Some(phase) = &rule.phase {
+            conditions.push(format!("phase {}", phase));
+        }
+        let conditions = if conditions.is_empty() { "every open task".to_string() } else { conditions.join(", ") };
+        let name = if rule.enabled { rule.name.bold() } else { format!("{} (disabled)", rule.name).dimmed() };
+
+        println!("\n  {} — when {}", name, conditions);
+        for problem in [rules::rule_priority(&rule.priority_below), rules::rule_priority(&rule.set_priority)].into_iter().filter_map(Result::err) {
+            println!("    {}", format!("⚠️  {}; the rule is skipped", problem).yellow());
+        }
+        if let Some(priority) = &rule.set_priority {
+            println!("    → raise priority to {}", priority);
+        }
+        if let Some(tag) = &rule.add_tag {
+            println!("    → tag #{}", tag.trim_start_matches('#'));
+        }
+        if rule.set_priority.is_none() && rule.add_tag.is_none() {
+            println!("    {}", "→ no actions".dimmed());
+        }
+    }
+    println!();
+}
+
+/// Apply escalation rules to the saved state, or preview them with `--dry-run`
+pub fn run_escalations(dry_run: bool) -> CommandResult {
+    let config = RaskConfig::load()?;
+    if config.escalations.is_empty() {
+        ui::display_info("No escalation rules configured. Add [[escalations]] entries to config.toml (see README)");
+        return Ok(());
+    }
+
+    let mut roadmap = state::load_stored_state()?;
+    let escalations = rules::apply_escalations(&config.escalations, &mut roadmap, chrono::Local::now().date_naive());
+    if escalations.is_empty() {
+        ui::display_info("No open task matches an escalation rule");
+        return Ok(());
+    }
+
+    if dry_run {
+        println!("🧪 Escalation preview (dry run, nothing is saved)");
+    } else {
+        println!("⏫ Applying escalation rules");
+    }
+    for escalation in &escalations {
+        let description = roadmap.find_task_by_id(escalation.task_id).map(|t| t.description.as_str()).unwrap_or_default();
+        println!("\n  #{} {} — {}", escalation.task_id, description, escalation.rule.bold());
+        for action in &escalation.actions {
+            if dry_run {
+                println!("     → {}", action.replace("raised", "would raise").replace("tagged", "would tag"));
+            } else {
+                println!("     → {}", action);
+            }
+        }
+    }
+    if dry_run {
+        return Ok(());
+    }
+
+    utils::save_and_sync(&roadmap)?;
+    if let Err(e) = rules::record_escalations(&escalations) {
+        ui::display_warning(&format!("Could not write the audit log: {}", e));
+    }
+    ui::display_success(&format!("Escalated {} task(s)", escalations.len()));
+    Ok(())
+}
+
+/// Dry-run the rules for a simulated event without saving or sending anything
+pub fn test_rules(task_id: Option<usize>, event: RuleTestEvent, phase: Option<&str>) -> CommandResult {
+    let config = RaskConfig::load()?;
+    let mut roadmap = state::load_state()?;
+
+    let simulated = match (task_id, phase) {
+        (Some(id), _) => {
+            let task = roadmap.find_task_by_id(id).ok_or_else(|| CommandError::task_not_found(id))?;
+            let trigger = match event {
+                RuleTestEvent::Added => RuleTrigger::TaskAdded,
+                RuleTestEvent::Completed => RuleTrigger::TaskCompleted,
+            };
+            rules::task_event(trigger, task)
+        }
+        (None, Some(phase)) => {
+            let name = roadmap.tasks.iter()
+                .find(|t| t.phase.name.eq_ignore_ascii_case(phase))
+                .map(|t| t.phase.name.clone())
+                .ok_or_else(|| format!("No tasks in phase '{}'", phase))?;
+            for task in roadmap.tasks.iter_mut().filter(|t| t.phase.name == name) {
+                task.status = TaskStatus::Completed;
+            }
+            rules::RuleEvent { trigger: RuleTrigger::PhaseCompleted, task_id: None, phase: name }
+        }
+        (None, None) => return Err("Pass --task <ID> or --phase <PHASE> to simulate an event".into()),
+    };
+    if simulated.trigger == RuleTrigger::TaskCompleted {
+        if let Some(task) = simulated.task_id.and_then(|id| roadmap.find_task_by_id_mut(id)) {
+            task.status = TaskStatus::Completed;
+        }
+    }
+
+    println!("🧪 Simulating {} (dry run, nothing is saved or sent)", simulated.describe().bold());
+    let runs = rules::run_rules(&config.rules, std::slice::from_ref(&simulated), &mut roadmap, false);
+    let skipped: Vec<&str> = config.rules.iter()
+        .filter(|r| !r.enabled && rules::rule_matches(r, &simulated, &roadmap))
+        .map(|r| r.name.as_str())
+        .collect();
+
+    if runs.is_empty() {
+        ui::display_info("No enabled rule matches this event");
+    }
+    for run in &runs {
+        println!("\n  ✅ {}", run.rule.bold());
+        if run.actions.is_empty() {
+            println!("     {}", "no actions".dimmed());
+        }
+        for action in &run.actions {
+            println!("     → {}", action.replace("added task", "would add task"));
+        }
+    }
+    for name in skipped {
+        println!("\n  {} {} (disabled)", "⏸️".dimmed(), name.dimmed());
+    }
+    Ok(())
+}
+
+/// Show the most recent rule runs from `.rask/audit.log`
+pub fn show_rules_log(limit: usize) -> CommandResult {
+    let entries = rules::read_audit_log()?;
+    if entries.is_empty() {
+        ui::display_info("No rule runs recorded yet");
+        return Ok(());
+    }
+
+    println!("📜 Rule runs (most recent {} of {})", limit.min(entries.len()), entries.len());
+    for entry in entries.iter().rev().take(limit) {
+        let when = chrono::DateTime::parse_from_rfc3339(&entry.timestamp)
+            .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|_| entry.timestamp.clone());
+        println!("\n  {} {} — {}", when.dimmed(), entry.rule.bold(), entry.event);
+        for action in &entry.actions {
+            println!("     → {}", action);
+        }
+    }
+    Ok(())
+}
@@ -1,8 +1,12 @@
 use crate::{
-    cli::{TemplateCommands, CliPriority},
-    model::{TaskTemplate, TemplateCollection, TemplateCategory, Priority, Phase},
-    state,
+    cli::{TemplateCommands, TemplatePackCommands, CliPriority, TemplateConflict},
+    model::{fill_placeholders, placeholder_names, Task, TaskTemplate, TemplateCollection, TemplateCategory, TemplatePack, Priority, Phase},
+    state, ui,
 };
+use super::{utils, CommandError};
+use crate::config::RaskConfig;
+use crate::destinations::{git::GitDestination, webdav::WebdavDestination, Destination};
+use std::collections::HashMap;
 use std::path::Path;
 use std::fs;
 use colored::*;
@@ -16,8 +20,8 @@ pub fn handle_template_command(cmd: TemplateCommands) -> Result<(), Box<dyn std:
         TemplateCommands::Show { name } => {
             show_template(&name)
         }
-        TemplateCommands::Use { template_name, description, add_tags, priority, phase } => {
-            use_template(&template_name, description, add_tags, priority, phase)
+        TemplateCommands::Use { template_name, description, add_tags, priority, phase, vars } => {
+            use_template(&template_name, description, add_tags, priority, phase, &vars)
         }
         TemplateCommands::Create { name, description, tags, priority, phase, notes, category } => {
             create_template(name, description, tags, priority, phase, notes, category)
@@ -31,9 +35,20 @@ pub fn handle_template_command(cmd: TemplateCommands) -> Result<(), Box<dyn std:
         TemplateCommands::Import { input, merge } => {
             import_templates(&input, merge)
         }
+        TemplateCommands::Fetch { url, sha256, on_conflict } => {
+            fetch_templates(url, sha256.as_deref(), on_conflict)
+        }
+        TemplateCommands::Publish { target } => {
+            publish_templates(&target)
+        }
         TemplateCommands::Examples => {
             show_template_help()
         }
+        TemplateCommands::Pack { command } => match command {
+            TemplatePackCommands::List => list_template_packs(),
+            TemplatePackCommands::Show { name } => show_template_pack(&name),
+            TemplatePackCommands::Use { name, vars, add_tags } => use_template_pack(&name, &vars, add_tags),
+        },
         TemplateCommands::Generate { description, count, category, phase, apply } => {
             generate_templates_with_ai(&description, count, category.as_deref(), phase.as_deref(), apply)
         }
@@ -168,6 +183,12 @@ fn show_template(name: &str) -> Result<(), Box<dyn std::error::Error>> {
             }
         }
         
+        let placeholders = template.placeholders();
+        if !placeholders.is_empty() {
+            let vars: Vec<String> = placeholders.iter().map(|name| format!("--var {}=...", name)).collect();
+            println!("\n  🧩 Variables: {}", vars.join(" ").bright_magenta());
+        }
+        
         println!("\n  📅 Created: {}", template.created_at.dimmed());
         
         println!("\n  💡 {} To use this template:", "Usage:".bright_green().bold());
@@ -188,7 +209,8 @@ fn use_template(
     custom_description: Option<String>,
     add_tags: Option<String>,
     priority_override: Option<CliPriority>,
-    phase_override: Option<String>
+    phase_override: Option<String>,
+    vars: &[String],
 ) -> Result<(), Box<dyn std::error::Error>> {
     let templates = load_templates()?;
     let mut roadmap = state::load_state()?;
@@ -196,6 +218,7 @@ fn use_template(
     if let Some(template) = templates.find_template(template_name) {
         let task_id = roadmap.get_next_task_id();
         let mut task = template.create_task(task_id, custom_description);
+        fill_template_variables(template, &mut task, vars)?;
         
         // Apply overrides
         if let Some(priority) = priority_override {
@@ -251,6 +274,170 @@ fn use_template(
     Ok(())
 }
 
+/// Substitute `[PLACEHOLDER]` variables in a task made from a template
+fn fill_template_variables(template: &TaskTemplate, task: &mut Task, vars: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    // Placeholders the new task contains; a custom description may have dropped some or added its own
+    let texts = std::iter::once(task.description.as_str())
+        .chain(task.notes.as_deref())
+        .chain(task.implementation_notes.iter().map(|note| note.text.as_str()));
+    let mut placeholders: Vec<String> = Vec::new();
+    for name in texts.flat_map(placeholder_names) {
+        if !placeholders.contains(&name) {
+            placeholders.push(name);
+        }
+    }
+    
+    let values = variable_values(&format!("Template '{}'", template.name), &placeholders, vars)?;
+    task.description = fill_placeholders(&task.description, &values);
+    task.notes = task.notes.as_deref().map(|notes| fill_placeholders(notes, &values));
+    for note in &mut task.implementation_notes {
+        note.text = fill_placeholders(&note.text, &values);
+    }
+    Ok(())
+}
+
+/// Values for `[PLACEHOLDER]` variables.
+///
+/// Values come from `--var NAME=VALUE` first; the user is asked for the rest,
+/// and an empty answer (or running non-interactively) leaves the placeholder.
+fn variable_values(owner: &str, placeholders: &[String], vars: &[String]) -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
+    let mut values = HashMap::new();
+    for var in vars {
+        let (name, value) = var.split_once('=')
+            .ok_or_else(|| format!("Invalid --var '{}'. Use NAME=VALUE, e.g. --var BUG_DESCRIPTION=\"login 500\"", var))?;
+        let name = name.trim().trim_start_matches('[').trim_end_matches(']').to_uppercase();
+        values.insert(name, value.to_string());
+    }
+    
+    for name in values.keys().filter(|name| !placeholders.contains(name)) {
+        ui::display_warning(&format!("{} has no [{}] placeholder", owner, name));
+    }
+    
+    let unfilled: Vec<&String> = placeholders.iter().filter(|name| !values.contains_key(*name)).collect();
+    if utils::is_non_interactive() {
+        if !unfilled.is_empty() {
+            let names: Vec<String> = unfilled.iter().map(|name| format!("[{}]", name)).collect();
+            ui::display_warning(&format!("Left unfilled: {}. Pass them with --var NAME=VALUE", names.join(", ")));
+        }
+    } else {
+        if !unfilled.is_empty() {
+            println!("  🧩 Fill in the template variables:");
+        }
+        for name in unfilled {
+            let answer = utils::prompt_line(&format!("  {} (enter to leave as is): ", name))?;
+            if !answer.trim().is_empty() {
+                values.insert(name.clone(), answer.trim().to_string());
+            }
+        }
+    }
+    Ok(values)
+}
+
+/// List the predefined and custom template packs
+fn list_template_packs() -> Result<(), Box<dyn std::error::Error>> {
+    let packs = load_templates()?.all_packs();
+    if utils::json_output() {
+        return utils::print_json(&packs);
+    }
+    
+    println!("{}", "═".repeat(80).bright_cyan());
+    println!("  📦 {} Template Packs", "Rask".bright_cyan().bold());
+    println!("{}", "═".repeat(80).bright_cyan());
+    for pack in &packs {
+        let mut phases: Vec<&str> = Vec::new();
+        for task in &pack.tasks {
+            if !phases.contains(&task.phase.as_str()) {
+                phases.push(&task.phase);
+            }
+        }
+        println!("\n  {} ({} tasks)", pack.name.bright_white().bold(), pack.tasks.len());
+        println!("     {}", pack.description.dimmed());
+        println!("     🚀 Phases: {}", phases.join(" → "));
+    }
+    println!("\n  💡 Use 'rask template pack show <pack>' for details or 'rask template pack use <pack>' to create the tasks");
+    Ok(())
+}
+
+/// Show the tasks of a pack with their dependencies
+fn show_template_pack(name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let pack = find_pack(name)?;
+    if utils::json_output() {
+        return utils::print_json(&pack);
+    }
+    
+    println!("{}", "═".repeat(80).bright_cyan());
+    println!("  📦 Template Pack: {}", pack.name.bright_white().bold());
+    println!("{}", "═".repeat(80).bright_cyan());
+    println!("  {}", pack.description);
+    
+    let mut phase = "";
+    for task in &pack.tasks {
+        if task.phase != phase {
+            phase = &task.phase;
+            println!("\n  🚀 {}", phase.bright_cyan().bold());
+        }
+        let estimate = task.estimated_hours.map(|h| format!(" ({}h)", h)).unwrap_or_default();
+        let deps = if task.depends_on.is_empty() { String::new() } else { format!(" ← {}", task.depends_on.join(", ")).dimmed().to_string() };
+        println!("     {:<12} {}{}{}", task.key.bright_yellow(), task.description, estimate.dimmed(), deps);
+    }
+    
+    let placeholders = pack.placeholders();
+    if !placeholders.is_empty() {
+        let vars: Vec<String> = placeholders.iter().map(|name| format!("--var {}=...", name)).collect();
+        println!("\n  🧩 Variables: {}", vars.join(" ").bright_magenta());
+    }
+    Ok(())
+}
+
+/// Create every task of a pack, remapping the pack's dependency keys to the new task IDs
+fn use_template_pack(name: &str, vars: &[String], add_tags: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let pack = find_pack(name)?;
+    pack.validate()?;
+    let mut roadmap = state::load_state()?;
+    
+    let values = variable_values(&format!("Pack '{}'", pack.name), &pack.placeholders(), vars)?;
+    let extra_tags = match add_tags {
+        Some(tags) => utils::validate_and_parse_tags(&tags)?,
+        None => Vec::new(),
+    };
+    
+    let first_id = roadmap.get_next_task_id();
+    let ids: HashMap<&str, usize> = pack.tasks.iter().enumerate()
+        .map(|(index, task)| (task.key.as_str(), first_id + index))
+        .collect();
+    for pack_task in &pack.tasks {
+        let mut task = Task::new(ids[pack_task.key.as_str()], fill_placeholders(&pack_task.description, &values));
+        task.tags = pack_task.tags.iter().chain(&extra_tags).cloned().collect();
+        task.priority = pack_task.priority.clone();
+        task.phase = roadmap.phase_named(&pack_task.phase);
+        task.estimated_hours = pack_task.estimated_hours;
+        task.notes = pack_task.notes.as_deref().map(|notes| fill_placeholders(notes, &values));
+        task.dependencies = pack_task.depends_on.iter().map(|key| ids[key.as_str()]).collect();
+        roadmap.add_task(task);
+    }
+    utils::save_and_sync(&roadmap)?;
+    
+    let last_id = first_id + pack.tasks.len() - 1;
+    ui::display_success(&format!("Created {} tasks (#{}–#{}) from pack '{}'", pack.tasks.len(), first_id, last_id, pack.name));
+    if !ui::is_quiet() {
+        for task in roadmap.tasks.iter().filter(|t| t.id >= first_id) {
+            let deps = if task.dependencies.is_empty() {
+                String::new()
+            } else {
+                format!(" (after {})", task.dependencies.iter().map(|d| format!("#{}", d)).collect::<Vec<_>>().join(", ")).dimmed().to_string()
+            };
+            println!("  #{:<4} {} {}{}", task.id, task.phase.emoji(), task.description, deps);
+        }
+    }
+    Ok(())
+}
+
+fn find_pack(name: &str) -> Result<TemplatePack, Box<dyn std::error::Error>> {
+    load_templates()?.find_pack(name).ok_or_else(|| {
+        CommandError::NotFound(format!("Template pack '{}' not found. Use 'rask template pack list' to see available packs", name)).into()
+    })
+}
+
 /// Create a new custom template
 fn create_template(
     name: String,
@@ -337,10 +524,8 @@ fn delete_template(name: &str, force: bool) -> Result<(), Box<dyn std::error::Er
         }
         
         if !force {
-            println!("  {} Are you sure you want to delete template '{}'? (y/N)", "⚠️".bright_yellow(), name.bright_white());
-            let mut input = String::new();
-            std::io::stdin().read_line(&mut input)?;
-            if !input.trim().to_lowercase().starts_with('y') {
+            let prompt = format!("  {} Are you sure you want to delete template '{}'?", "⚠️".bright_yellow(), name.bright_white());
+            if !super::utils::confirm(&prompt)? {
                 println!("  Template deletion cancelled");
                 return Ok(());
             }
@@ -408,10 +593,30 @@ fn import_templates(input: &Path, merge: bool) -> Result<(), Box<dyn std::error:
         imported_count += 1;
     }
     
+    let mut imported_packs = 0;
+    for pack in imported_templates.packs {
+        pack.validate()?;
+        if let Some(existing) = current_templates.packs.iter_mut().find(|p| p.name.eq_ignore_ascii_case(&pack.name)) {
+            if merge {
+                println!("  {} Skipping existing pack '{}'", "⚠️".bright_yellow(), pack.name);
+                skipped_count += 1;
+            } else {
+                *existing = pack;
+                imported_packs += 1;
+            }
+            continue;
+        }
+        current_templates.packs.push(pack);
+        imported_packs += 1;
+    }
+    
     save_templates(&current_templates)?;
     
     println!("  {} Templates imported from '{}'", "✅".bright_green(), input.display().to_string().bright_white());
     println!("     {} templates imported", imported_count);
+    if imported_packs > 0 {
+        println!("     {} template packs imported", imported_packs);
+    }
     if skipped_count > 0 {
         println!("     {} templates skipped (already exist)", skipped_count);
     }
@@ -419,6 +624,238 @@ fn import_templates(input: &Path, merge: bool) -> Result<(), Box<dyn std::error:
     Ok(())
 }
 
+/// Environment variable holding the shared team key for the integrity MAC of published templates
+const TEMPLATE_KEY_ENV: &str = "RASK_TEMPLATE_KEY";
+
+/// Fetch a published template collection and merge it into the local one.
+///
+/// The download must match `--sha256` or the `.sha256` file published next to
+/// it, and its `.mac` when `RASK_TEMPLATE_KEY` is set. The MAC only shows the
+/// file came from someone holding the team key: anyone who can check it can
+/// also produce one, so it is not a signature. Templates and packs
+/// that differ from local ones with the same name are resolved per `on_conflict`.
+fn fetch_templates(url: Option<String>, expected_hash: Option<&str>, on_conflict: TemplateConflict) -> Result<(), Box<dyn std::error::Error>> {
+    let url = match url {
+        Some(url) => url,
+        None => RaskConfig::load().ok()
+            .and_then(|config| config.advanced.template_registry)
+            .ok_or("No URL given and no registry configured. Set one with 'rask config set advanced.template_registry <url>'")?,
+    };
+    
+    let content = read_shared_file(&url)?
+        .ok_or_else(|| CommandError::NotFound(format!("No template collection at {}", url)))?;
+    let hash = sha256_hex(&content);
+    let published_hash = match expected_hash {
+        Some(expected) => Some(expected.trim().to_lowercase()),
+        None => read_shared_file(&format!("{}.sha256", url))?
+            .map(|file| String::from_utf8_lossy(&file).split_whitespace().next().unwrap_or_default().to_lowercase()),
+    };
+    match published_hash {
+        Some(expected) if expected != hash => {
+            return Err(format!("Hash mismatch for {}: expected {}, got {}. The file may have been tampered with or changed mid-publish", url, expected, hash).into());
+        }
+        Some(_) => println!("  {} SHA-256 verified ({})", "🔒".bright_green(), &hash[..12]),
+        None => ui::display_warning(&format!("{} has no published hash; its integrity could not be verified", url)),
+    }
+    
+    let published_mac = read_shared_file(&format!("{}.mac", url))?;
+    match (std::env::var(TEMPLATE_KEY_ENV).ok().filter(|k| !k.is_empty()), published_mac) {
+        (Some(key), Some(published_mac)) => {
+            let published_mac = String::from_utf8_lossy(&published_mac).trim().to_lowercase();
+            if !constant_time_eq(published_mac.as_bytes(), integrity_mac(&content, &key).as_bytes()) {
+                return Err(format!("Integrity MAC check failed for {}: it was not published with your team key", url).into());
+            }
+            println!("  {} Integrity MAC verified", "🔏".bright_green());
+        }
+        (Some(_), None) => {
+            return Err(format!("{} has no integrity MAC, but {} is set. Ask the publisher to publish with the team key", url, TEMPLATE_KEY_ENV).into());
+        }
+        (None, Some(_)) => ui::display_info(&format!("{} has an integrity MAC; set {} to verify it", url, TEMPLATE_KEY_ENV)),
+        (None, None) => {}
+    }
+    
+    let incoming: TemplateCollection = serde_json::from_slice(&content)
+        .map_err(|e| format!("{} is not a rask template collection: {}", url, e))?;
+    for pack in &incoming.packs {
+        pack.validate()?;
+    }
+    
+    let mut templates = load_templates()?;
+    let mut report = MergeReport::default();
+    for template in incoming.templates {
+        match templates.templates.iter().position(|t| t.name == template.name) {
+            None => {
+                report.added.push(template.name.clone());
+                templates.add_template(template);
+            }
+            Some(index) if same_content(&templates.templates[index], &template)? => report.unchanged += 1,
+            Some(index) => {
+                if resolve_conflict("Template", &template.name, on_conflict)? {
+                    report.replaced.push(template.name.clone());
+                    templates.templates[index] = template;
+                } else {
+                    report.kept.push(template.name.clone());
+                }
+            }
+        }
+    }
+    for pack in incoming.packs {
+        match templates.packs.iter().position(|p| p.name.eq_ignore_ascii_case(&pack.name)) {
+            None => {
+                report.added.push(format!("{} (pack)", pack.name));
+                templates.packs.push(pack);
+            }
+            Some(index) if same_content(&templates.packs[index], &pack)? => report.unchanged += 1,
+            Some(index) => {
+                if resolve_conflict("Pack", &pack.name, on_conflict)? {
+                    report.replaced.push(format!("{} (pack)", pack.name));
+                    templates.packs[index] = pack;
+                } else {
+                    report.kept.push(format!("{} (pack)", pack.name));
+                }
+            }
+        }
+    }
+    save_templates(&templates)?;
+    
+    ui::display_success(&format!("Fetched templates from {}", url));
+    for (label, names) in [("Added", &report.added), ("Replaced", &report.replaced), ("Kept local", &report.kept)] {
+        if !names.is_empty() {
+            println!("     {}: {}", label, names.join(", "));
+        }
+    }
+    println!("     {} already up to date", report.unchanged);
+    Ok(())
+}
+
+/// Outcome of merging a fetched collection
+#[derive(Default)]
+struct MergeReport {
+    added: Vec<String>,
+    replaced: Vec<String>,
+    kept: Vec<String>,
+    unchanged: usize,
+}
+
+/// Whether two templates or packs are the same apart from when they were
+/// created (tags are a set, so their order does not count either)
+fn same_content<T: serde::Serialize>(local: &T, fetched: &T) -> Result<bool, Box<dyn std::error::Error>> {
+    let strip = |value: &T| -> Result<serde_json::Value, serde_json::Error> {
+        let mut value = serde_json::to_value(value)?;
+        if let Some(fields) = value.as_object_mut() {
+            fields.remove("created_at");
+            if let Some(serde_json::Value::Array(tags)) = fields.get_mut("tags") {
+                tags.sort_by_key(|tag| tag.to_string());
+            }
+        }
+        Ok(value)
+    };
+    Ok(strip(local)? == strip(fetched)?)
+}
+
+/// Decide whether a fetched template replaces the local one with the same name
+fn resolve_conflict(kind: &str, name: &str, on_conflict: TemplateConflict) -> Result<bool, Box<dyn std::error::Error>> {
+    match on_conflict {
+        TemplateConflict::Keep => Ok(false),
+        TemplateConflict::Replace => Ok(true),
+        TemplateConflict::Ask if utils::is_non_interactive() && !utils::assume_yes() => {
+            ui::display_warning(&format!("{} '{}' differs from yours; keeping your version (use --on-conflict replace to take theirs)", kind, name));
+            Ok(false)
+        }
+        TemplateConflict::Ask => utils::confirm(&format!("  {} '{}' differs from yours. Replace it with the fetched version?", kind, name)),
+    }
+}
+
+/// Read a shared file from an http(s) URL or a local path, `None` when it does not exist
+fn read_shared_file(location: &str) -> Result<Option<Vec<u8>>, Box<dyn std::error::Error>> {
+    if location.starts_with("http://") || location.starts_with("https://") {
+        let runtime = tokio::runtime::Runtime::new()?;
+        return runtime.block_on(async {
+            let response = reqwest::get(location).await
+                .map_err(|e| format!("Failed to fetch {}: {}", location, e))?;
+            if response.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
+            if !response.status().is_success() {
+                return Err(format!("Fetching {} failed with {}", location, response.status()).into());
+            }
+            Ok(Some(response.bytes().await?.to_vec()))
+        });
+    }
+    let path = location.strip_prefix("file://").unwrap_or(location);
+    match fs::read(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Failed to read {}: {}", path, e).into()),
+    }
+}
+
+/// Publish the template collection with its hash, and an integrity MAC when a team key is set
+fn publish_templates(target: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let destination = template_destination(target)?;
+    let content = serde_json::to_string_pretty(&load_templates()?)?;
+    let hash = sha256_hex(content.as_bytes());
+    
+    let dir = std::env::temp_dir().join(format!("rask-templates-{}", std::process::id()));
+    fs::create_dir_all(&dir)?;
+    let mut files = vec![dir.join("templates.json"), dir.join("templates.json.sha256")];
+    fs::write(&files[0], &content)?;
+    fs::write(&files[1], format!("{}  templates.json\n", hash))?;
+    let key = std::env::var(TEMPLATE_KEY_ENV).ok().filter(|k| !k.is_empty());
+    if let Some(key) = &key {
+        let mac_file = dir.join("templates.json.mac");
+        fs::write(&mac_file, format!("{}\n", integrity_mac(content.as_bytes(), key)))?;
+        files.push(mac_file);
+    }
+    
+    let result = destination.publish(&files);
+    let _ = fs::remove_dir_all(&dir);
+    let locations = result.map_err(|e| format!("Publishing to {} failed: {}", destination.describe(), e))?;
+    
+    ui::display_success(&format!("📤 Published templates to {}", destination.describe()));
+    for location in &locations {
+        println!("   {}", location);
+    }
+    println!("   SHA-256: {}{}", hash, if key.is_some() { " (with integrity MAC)" } else { "" });
+    if key.is_none() {
+        println!("   {}", format!("💡 Set {} to add an integrity MAC for your team", TEMPLATE_KEY_ENV).dimmed());
+    }
+    Ok(())
+}
+
+/// Where to publish: a configured export destination, a WebDAV/PUT URL or a git repository
+fn template_destination(target: &str) -> Result<Box<dyn Destination>, Box<dyn std::error::Error>> {
+    let config = RaskConfig::load().ok();
+    if let Some(destination) = config.as_ref().and_then(|c| c.export.destinations.get(target)) {
+        return crate::destinations::from_config(destination)
+            .map_err(|e| format!("Export destination '{}': {}", target, e).into());
+    }
+    if target.starts_with("http://") || target.starts_with("https://") {
+        return Ok(Box::new(WebdavDestination::new(target, None, None)));
+    }
+    if Path::new(target).join(".git").exists() {
+        return Ok(Box::new(GitDestination::new(target, "", false).with_message("Update shared rask templates")));
+    }
+    Err(format!(
+        "Unknown publish target '{}'. Use a destination from [export.destinations], an http(s) URL or a git repository path",
+        target
+    ).into())
+}
+
+fn sha256_hex(content: &[u8]) -> String {
+    hmac_sha256::Hash::hash(content).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Hex HMAC-SHA256 of the content under the team key
+fn integrity_mac(content: &[u8], key: &str) -> String {
+    hmac_sha256::HMAC::mac(content, key.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Compare two byte strings in time that depends only on their length
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
 /// Show template help and examples
 fn show_template_help() -> Result<(), Box<dyn std::error::Error>> {
     println!("{}", "═".repeat(80).bright_cyan());
@@ -434,6 +871,7 @@ fn show_template_help() -> Result<(), Box<dyn std::error::Error>> {
     println!("     rask template list --detailed         # List with full details");
     println!("     rask template show \"Bug Fix\"          # Show template details");
     println!("     rask template use \"Feature Implementation\" \"Add user login\"");
+    println!("     rask template use \"Bug Fix\" --var BUG_DESCRIPTION=\"login 500\"");
     println!("     rask template create \"My Template\" \"Custom task description\"");
     
     println!("\n  {} Template Categories:", "📁".bright_yellow().bold());
@@ -487,6 +925,13 @@ fn show_template_help() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Names of the saved templates, used for shell completion
+pub fn template_names() -> Vec<String> {
+    load_templates()
+        .map(|collection| collection.get_template_names().into_iter().cloned().collect())
+        .unwrap_or_default()
+}
+
 /// Load templates from file or create default collection
 fn load_templates() -> Result<TemplateCollection, Box<dyn std::error::Error>> {
     let templates_path = get_templates_path()?;
@@ -951,6 +1396,14 @@ fn generate_roadmap_from_template(
         let mut answers = std::collections::HashMap::new();
         for question in &roadmap_template.interactive_questions {
             let answer_str = match question.question_type {
+                crate::model::QuestionType::Text if super::utils::is_non_interactive() || super::utils::assume_yes() => {
+                    // Fall back to the template default when prompting is not allowed
+                    match &question.default_value {
+                        Some(default) => default.clone(),
+                        None if super::utils::assume_yes() => String::new(),
+                        None => return Err(format!("Template question '{}' has no default and prompting is disabled", question.key).into()),
+                    }
+                }
                 crate::model::QuestionType::Text => {
                     inquire::Text::new(&question.prompt)
                         .with_default(question.default_value.as_deref().unwrap_or(""))
@@ -0,0 +1,319 @@
+//! Time session exchange with Toggl Track and Clockify
+//!
+//! Sessions are exported as CSV files in the shape each tracker imports, with
+//! the task ID at the start of the description (`#42 Build API`). Imports read
+//! the trackers' detailed report CSVs and map entries back to tasks, first by
+//! an ID in the description and otherwise by a fuzzy title match, so tracked
+//! time still feeds Rask's estimate variance analytics.
+
+use crate::{
+    cli::{TimeCommands, TimeTracker},
+    importers::csv::parse_csv,
+    model::{Roadmap, Task, TimeSession},
+    state, ui
+};
+use super::{CommandResult, utils};
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use colored::*;
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::Path;
+use std::process::Command;
+use std::sync::LazyLock;
+
+/// Minimum word overlap for a title-only match
+const FUZZY_MATCH_THRESHOLD: f64 = 0.6;
+
+/// A task ID in an entry description: `#42` or `rask:#42`
+static TASK_ID_REF: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:rask:)?#(\d+)\b").expect("valid task reference regex")
+});
+
+/// Handle `rask time` subcommands
+pub fn handle_time_command(time_command: &TimeCommands) -> CommandResult {
+    match time_command {
+        TimeCommands::Export { to, output, email } => export_time_sessions(*to, output.as_deref(), email.as_deref()),
+        TimeCommands::Import { file, from, dry_run } => import_time_sessions(file, *from, *dry_run),
+        TimeCommands::Add { task_id, hours, date, start, note } => {
+            super::add_time_entry(*task_id, *hours, date.as_deref(), start.as_deref(), note.as_deref())
+        },
+        TimeCommands::Edit { task_id, session_index, hours, date, start, note, clear_note } => {
+            super::edit_time_session(*task_id, *session_index, *hours, date.as_deref(), start.as_deref(), note.as_deref(), *clear_note)
+        },
+    }
+}
+
+/// Export all finished time sessions as a Toggl or Clockify CSV
+pub fn export_time_sessions(tracker: TimeTracker, output: Option<&Path>, email: Option<&str>) -> CommandResult {
+    let roadmap = state::load_state()?;
+    let email = email.map(str::to_string)
+        .or_else(|| crate::config::RaskConfig::load().ok().and_then(|config| config.user.email))
+        .or_else(git_user_email)
+        .unwrap_or_default();
+
+    let mut csv = String::from(match tracker {
+        TimeTracker::Toggl => "Email,Project,Description,Start date,Start time,Duration,Tags\n",
+        TimeTracker::Clockify => "Project,Description,Email,Tags,Start Date,Start Time,Duration (h)\n",
+    });
+
+    let mut exported = 0;
+    for task in &roadmap.tasks {
+        let description = format!("#{} {}", task.id, task.description);
+        let mut tags: Vec<&str> = task.tags.iter().map(String::as_str).collect();
+        tags.sort_unstable();
+        let tags = tags.join(", ");
+
+        for session in &task.time_sessions {
+            let (Some(start), Some(end)) = (
+                crate::model::parse_timestamp(Some(&session.start_time)),
+                crate::model::parse_timestamp(session.end_time.as_deref()),
+            ) else {
+                continue;
+            };
+
+            let start = start.with_timezone(&Local);
+            let seconds = (end - start.with_timezone(&Utc)).num_seconds().max(0);
+            let duration = format!("{:02}:{:02}:{:02}", seconds / 3600, (seconds % 3600) / 60, seconds % 60);
+            let fields = match tracker {
+                TimeTracker::Toggl => [
+                    email.clone(), roadmap.title.clone(), description.clone(),
+                    start.format("%Y-%m-%d").to_string(), start.format("%H:%M:%S").to_string(),
+                    duration, tags.clone(),
+                ],
+                TimeTracker::Clockify => [
+                    roadmap.title.clone(), description.clone(), email.clone(), tags.clone(),
+                    start.format("%Y-%m-%d").to_string(), start.format("%H:%M:%S").to_string(),
+                    duration,
+                ],
+            };
+            csv.push_str(&fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>().join(","));
+            csv.push('\n');
+            exported += 1;
+        }
+    }
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, &csv)?;
+            ui::display_success(&format!("Exported {} time sessions for {:?} to {}", exported, tracker, path.display()));
+        }
+        None => print!("{}", csv),
+    }
+    Ok(())
+}
+
+/// Outcome of matching one time tracker entry
+enum EntryMatch {
+    Add { task_id: usize, how: String, session: TimeSession },
+    Duplicate { task_id: usize },
+    Unmatched { reason: String },
+}
+
+/// Import time entries from a Toggl or Clockify CSV into task sessions
+pub fn import_time_sessions(file: &Path, tracker: Option<TimeTracker>, dry_run: bool) -> CommandResult {
+    let mut roadmap = state::load_state()?;
+    let content = std::fs::read_to_string(file)
+        .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+    let mut rows = parse_csv(&content).into_iter();
+    let header = rows.next().ok_or("CSV file is empty")?;
+
+    let column = |names: &[&str]| header.iter().position(|h| {
+        let h = h.trim().trim_start_matches('\u{feff}').to_lowercase();
+        names.contains(&h.as_str())
+    });
+    let tracker = tracker.unwrap_or(
+        if column(&["duration (h)", "duration (decimal)"]).is_some() { TimeTracker::Clockify } else { TimeTracker::Toggl }
+    );
+
+    let description_col = column(&["description"]).ok_or("CSV header needs a Description column")?;
+    let task_col = column(&["task"]);
+    let start_date_col = column(&["start date"]).ok_or("CSV header needs a Start date column")?;
+    let start_time_col = column(&["start time"]).ok_or("CSV header needs a Start time column")?;
+    let end_date_col = column(&["end date"]);
+    let end_time_col = column(&["end time"]);
+    let duration_col = column(&["duration", "duration (h)"]);
+    let decimal_col = column(&["duration (decimal)"]);
+
+    let mut matches = Vec::new();
+    for (index, row) in rows.enumerate() {
+        let cell = |col: Option<usize>| col.and_then(|c| row.get(c)).map(|v| v.trim()).filter(|v| !v.is_empty());
+        let line = index + 2;
+
+        let Some(start) = parse_local_datetime(cell(Some(start_date_col)), cell(Some(start_time_col))) else {
+            matches.push((line, EntryMatch::Unmatched { reason: "invalid start date/time".to_string() }));
+            continue;
+        };
+        let end = parse_local_datetime(cell(end_date_col), cell(end_time_col)).or_else(|| {
+            let hours = cell(duration_col).and_then(parse_duration_hours)
+                .or_else(|| cell(decimal_col).and_then(|v| v.parse::<f64>().ok()))?;
+            Some(start + chrono::Duration::seconds((hours * 3600.0).round() as i64))
+        });
+        let Some(end) = end.filter(|end| *end > start) else {
+            matches.push((line, EntryMatch::Unmatched { reason: "missing or invalid duration".to_string() }));
+            continue;
+        };
+
+        let description = cell(Some(description_col)).or(cell(task_col)).unwrap_or("");
+        matches.push((line, match_entry(&roadmap, description, start, end)));
+    }
+
+    display_time_import(&matches, tracker, dry_run);
+
+    if dry_run {
+        ui::display_info("Dry run - no changes were written. Re-run without --dry-run to import.");
+        return Ok(());
+    }
+
+    let mut imported = 0;
+    for (_, entry) in matches {
+        if let EntryMatch::Add { task_id, session, .. } = entry {
+            if let Some(task) = roadmap.find_task_by_id_mut(task_id) {
+                if task.add_completed_time_session(session) {
+                    imported += 1;
+                }
+            }
+        }
+    }
+
+    if imported == 0 {
+        ui::display_info("Nothing to import.");
+        return Ok(());
+    }
+
+    utils::save_and_sync(&roadmap)?;
+    ui::display_success(&format!("Imported {} time sessions from {:?}", imported, tracker));
+    Ok(())
+}
+
+/// Find the task a time entry belongs to
+fn match_entry(roadmap: &Roadmap, description: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> EntryMatch {
+    let by_id = TASK_ID_REF.captures(description)
+        .and_then(|cap| cap[1].parse::<usize>().ok())
+        .and_then(|id| roadmap.find_task_by_id(id));
+    let (task, how) = match by_id {
+        Some(task) => (task, "by id".to_string()),
+        None => match fuzzy_match_task(roadmap, description) {
+            Some((task, score)) => (task, format!("title match {:.0}%", score * 100.0)),
+            None if description.is_empty() => return EntryMatch::Unmatched { reason: "empty description".to_string() },
+            None => return EntryMatch::Unmatched { reason: format!("no task matches '{}'", description) },
+        },
+    };
+
+    if task.has_session_starting_near(start) {
+        return EntryMatch::Duplicate { task_id: task.id };
+    }
+
+    // Keep the tracker's text as the session note unless it only repeats the task title
+    let note = TASK_ID_REF.replace(description, "").trim().to_string();
+    let note = (!note.is_empty() && !note.eq_ignore_ascii_case(&task.description)).then_some(note);
+
+    EntryMatch::Add {
+        task_id: task.id,
+        how,
+        session: TimeSession::completed(start, end, note),
+    }
+}
+
+/// Pick the task whose description shares the most words with the entry
+fn fuzzy_match_task<'a>(roadmap: &'a Roadmap, description: &str) -> Option<(&'a Task, f64)> {
+    let entry_words = words(description);
+    if entry_words.is_empty() {
+        return None;
+    }
+
+    roadmap.tasks.iter()
+        .map(|task| {
+            let task_words = words(&task.description);
+            let shared = entry_words.intersection(&task_words).count() as f64;
+            let total = entry_words.union(&task_words).count() as f64;
+            (task, if total > 0.0 { shared / total } else { 0.0 })
+        })
+        .filter(|(_, score)| *score >= FUZZY_MATCH_THRESHOLD)
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal).then(b.0.id.cmp(&a.0.id)))
+}
+
+fn words(text: &str) -> HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Print how each entry maps to a task
+fn display_time_import(matches: &[(usize, EntryMatch)], tracker: TimeTracker, dry_run: bool) {
+    let title = if dry_run { format!("{:?} Import Preview (dry run)", tracker) } else { format!("{:?} Import", tracker) };
+    println!("\n{}", title.bold().bright_cyan());
+    println!("{}", "═".repeat(60).bright_blue());
+
+    let (mut added, mut duplicates, mut unmatched) = (0, 0, 0);
+    for (line, entry) in matches {
+        match entry {
+            EntryMatch::Add { task_id, how, session } => {
+                added += 1;
+                let start = crate::model::parse_timestamp(Some(&session.start_time))
+                    .map(|dt| dt.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default();
+                println!("  {} {} {:.2}h → #{} {}",
+                    "+".bright_green().bold(),
+                    start,
+                    session.duration_hours().unwrap_or(0.0),
+                    task_id,
+                    format!("({})", how).bright_black()
+                );
+            }
+            EntryMatch::Duplicate { task_id } => {
+                duplicates += 1;
+                println!("  {} line {} already tracked on #{}", "=".bright_yellow().bold(), line, task_id);
+            }
+            EntryMatch::Unmatched { reason } => {
+                unmatched += 1;
+                println!("  {} line {} {}", "?".bright_red().bold(), line, reason.bright_red());
+            }
+        }
+    }
+
+    println!("{}", "─".repeat(60).bright_black());
+    println!("  {} to add, {} already tracked, {} unmatched\n",
+        added.to_string().bright_green().bold(),
+        duplicates.to_string().bright_yellow().bold(),
+        unmatched.to_string().bright_red().bold()
+    );
+}
+
+/// Parse a tracker date and time (local time zone) into UTC
+pub(super) fn parse_local_datetime(date: Option<&str>, time: Option<&str>) -> Option<DateTime<Utc>> {
+    let date = ["%Y-%m-%d", "%m/%d/%Y", "%d/%m/%Y", "%d.%m.%Y"].iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(date?, fmt).ok())?;
+    let time = ["%H:%M:%S", "%H:%M", "%I:%M:%S %p", "%I:%M %p"].iter()
+        .find_map(|fmt| NaiveTime::parse_from_str(time?, fmt).ok())?;
+    Local.from_local_datetime(&NaiveDateTime::new(date, time))
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Parse an `HH:MM:SS` or `HH:MM` duration into hours
+fn parse_duration_hours(value: &str) -> Option<f64> {
+    let parts: Vec<f64> = value.split(':').map(|p| p.trim().parse().ok()).collect::<Option<_>>()?;
+    match parts.as_slice() {
+        [h, m, s] => Some(h + m / 60.0 + s / 3600.0),
+        [h, m] => Some(h + m / 60.0),
+        _ => None,
+    }
+}
+
+/// Quote a CSV field when needed
+pub(super) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Read the email configured for git, used as the default entry owner
+fn git_user_email() -> Option<String> {
+    let output = Command::new("git").args(["config", "user.email"]).output().ok()?;
+    let email = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !email.is_empty()).then_some(email)
+}
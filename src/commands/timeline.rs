@@ -0,0 +1,221 @@
+//! Project activity timeline
+//!
+//! `rask timeline` lists what happened in the project, oldest first: task
+//! history entries (edits, phase moves, status and tag changes, reviews),
+//! time tracking sessions starting and stopping, and AI operations. Tasks
+//! from before the activity log still show their creation and completion,
+//! taken from the task timestamps. Events can be narrowed down by type, date
+//! range, and task. `--phases` keeps the phase-by-phase layout.
+
+use crate::model::{parse_timestamp, Roadmap, Task, TaskHistoryEntry};
+use crate::ui::helpers::{output_width, Table};
+use crate::{state, ui};
+use super::{utils, CommandResult};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use clap::ValueEnum;
+use colored::*;
+use serde::Serialize;
+
+/// Event types accepted by `--type`
+#[derive(ValueEnum, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EventKind {
+    /// Task created or imported
+    Created,
+    /// Task completed or reopened
+    Status,
+    /// Description, notes, due date, dependency, estimate or owner changes
+    Edit,
+    /// Tag changes
+    Tags,
+    /// Moves between phases
+    Phase,
+    /// Priority changes and escalations
+    Priority,
+    /// Time tracking sessions and logged time
+    Session,
+    /// Tasks generated or estimated with AI
+    Ai,
+    /// Reviews and snoozes
+    Review,
+    /// Anything else, such as automation rules being applied
+    Other,
+}
+
+impl EventKind {
+    /// The type of a task history entry. AI operations note it in the details.
+    fn of_entry(entry: &TaskHistoryEntry) -> EventKind {
+        let details = entry.details.as_deref().unwrap_or("");
+        if details.starts_with("by AI") || details.contains("(AI,") {
+            return EventKind::Ai;
+        }
+        match entry.action.as_str() {
+            "created" | "imported" => EventKind::Created,
+            "completed" | "reopened" => EventKind::Status,
+            "edited" | "notes_edited" | "due_changed" | "dependencies_changed" | "estimated" | "owners_changed" => EventKind::Edit,
+            "tags_changed" => EventKind::Tags,
+            "phase_changed" => EventKind::Phase,
+            "priority_changed" | "escalated" => EventKind::Priority,
+            "time_added" | "time_edited" => EventKind::Session,
+            "reviewed" | "snoozed" | "unsnoozed" => EventKind::Review,
+            _ => EventKind::Other,
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            EventKind::Created => "🆕",
+            EventKind::Status => "✅",
+            EventKind::Edit => "✏️",
+            EventKind::Tags => "🏷️",
+            EventKind::Phase => "🚚",
+            EventKind::Priority => "⚡",
+            EventKind::Session => "⏱️",
+            EventKind::Ai => "🤖",
+            EventKind::Review => "🧹",
+            EventKind::Other => "•",
+        }
+    }
+}
+
+/// One thing that happened to a task
+#[derive(Serialize)]
+struct TimelineEvent<'a> {
+    timestamp: DateTime<Utc>,
+    kind: EventKind,
+    action: String,
+    details: Option<String>,
+    task_id: usize,
+    task_uuid: &'a str,
+    description: &'a str,
+}
+
+/// Every event recorded for `task`
+fn task_events(task: &Task) -> Vec<TimelineEvent<'_>> {
+    let event = |timestamp: DateTime<Utc>, kind: EventKind, action: &str, details: Option<String>| TimelineEvent {
+        timestamp,
+        kind,
+        action: action.to_string(),
+        details,
+        task_id: task.id,
+        task_uuid: &task.uuid,
+        description: &task.description,
+    };
+
+    let mut events: Vec<TimelineEvent> = task.history.iter()
+        .filter_map(|entry| {
+            let at = parse_timestamp(Some(&entry.timestamp))?;
+            Some(event(at, EventKind::of_entry(entry), &entry.action, entry.details.clone()))
+        })
+        .collect();
+
+    // Tasks from before the activity log only have their timestamps
+    let logged = |action: &str| task.history.iter().any(|entry| entry.action == action);
+    if !logged("created") && !logged("imported") {
+        if let Some(at) = task.created_datetime() {
+            let created = match (task.is_ai_generated(), task.get_ai_operation()) {
+                (true, Some(operation)) => event(at, EventKind::Ai, "created", Some(format!("by AI {}", operation))),
+                _ => event(at, EventKind::Created, "created", None),
+            };
+            events.push(created);
+        }
+    }
+    if !logged("completed") {
+        if let Some(at) = task.completed_datetime() {
+            events.push(event(at, EventKind::Status, "completed", None));
+        }
+    }
+
+    for session in &task.time_sessions {
+        if let Some(at) = parse_timestamp(Some(&session.start_time)) {
+            events.push(event(at, EventKind::Session, "session_started", session.description.clone()));
+        }
+        if let Some(at) = parse_timestamp(session.end_time.as_deref()) {
+            let duration = session.duration_minutes.map(|minutes| format!("{}h {:02}m", minutes / 60, minutes % 60));
+            events.push(event(at, EventKind::Session, "session_stopped", duration));
+        }
+    }
+    events
+}
+
+/// Start of `date` in local time, as UTC
+fn local_day_start(date: NaiveDate) -> Option<DateTime<Utc>> {
+    date.and_hms_opt(0, 0, 0)
+        .and_then(|start| start.and_local_timezone(Local).earliest())
+        .map(|start| start.with_timezone(&Utc))
+}
+
+/// Show project activity in chronological order
+pub fn show_activity_timeline(kinds: &[EventKind], since: Option<&str>, until: Option<&str>, task_id: Option<usize>, limit: usize) -> CommandResult {
+    let from = since.map(utils::parse_since).transpose()?;
+    let to = until.map(utils::parse_since).transpose()?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(format!("--since ({}) is after --until ({})", from, to).into());
+        }
+    }
+    let start = from.and_then(local_day_start);
+    // --until includes the whole day
+    let end = to.and_then(|day| day.succ_opt()).and_then(local_day_start);
+
+    let roadmap: Roadmap = state::load_state()?;
+    if let Some(id) = task_id {
+        if roadmap.find_task_by_id(id).is_none() {
+            return Err(super::CommandError::task_not_found(id).into());
+        }
+    }
+
+    let mut events: Vec<TimelineEvent> = roadmap.tasks.iter()
+        .filter(|task| task_id.is_none_or(|id| task.id == id))
+        .flat_map(task_events)
+        .filter(|event| kinds.is_empty() || kinds.contains(&event.kind))
+        .filter(|event| start.is_none_or(|start| event.timestamp >= start))
+        .filter(|event| end.is_none_or(|end| event.timestamp < end))
+        .collect();
+    events.sort_by_key(|event| event.timestamp);
+    let total = events.len();
+    // Keep the most recent events
+    events.drain(..total.saturating_sub(limit));
+
+    if utils::json_output() {
+        return utils::print_json(&events);
+    }
+
+    if events.is_empty() {
+        ui::display_info("🕒 No activity matches these filters");
+        return Ok(());
+    }
+
+    let icon = |emoji: &str| ui::theme::theme().icon(emoji);
+    println!("\n  {}{}", icon("🕒"), "Project Activity".bold().bright_cyan());
+    println!("  {}", ui::theme::theme().rule("─", 60).bright_black());
+
+    let mut table = Table::new("  ").indent("  ").min_column_width(8);
+    let mut last_day = None;
+    for event in &events {
+        let local = event.timestamp.with_timezone(&Local);
+        let day = local.date_naive();
+        let day_cell = if last_day == Some(day) { String::new() } else { day.format("%a %Y-%m-%d").to_string().bold().to_string() };
+        last_day = Some(day);
+        let what = match &event.details {
+            Some(details) => format!("{} — {}", event.action.bright_white(), details),
+            None => event.action.bright_white().to_string(),
+        };
+        table.add_row(vec![
+            day_cell,
+            local.format("%H:%M").to_string().bright_black().to_string(),
+            format!("{}{}", icon(event.kind.icon()), format!("#{}", event.task_id).bright_white()),
+            event.description.to_string(),
+            what,
+        ]);
+    }
+    for line in table.render(output_width()) {
+        println!("{}", line);
+    }
+
+    if total > events.len() {
+        println!("\n  {}", format!("Showing the latest {} of {} events; use --limit or --since to see more", events.len(), total).bright_black());
+    }
+    println!();
+    Ok(())
+}
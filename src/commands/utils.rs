@@ -3,8 +3,77 @@
 //! This module contains common validation functions and utilities
 //! used across multiple command modules.
 
-use crate::{model::{Roadmap}, state, markdown_writer};
-use super::CommandResult;
+use crate::{model::{Roadmap}, state, markdown_writer, ui};
+use crate::config::RaskConfig;
+use super::{CommandError, CommandResult};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by `--non-interactive`: prompts become errors instead of reading stdin
+static NON_INTERACTIVE: AtomicBool = AtomicBool::new(false);
+/// Set by `--yes`: confirmation prompts are answered with "yes"
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+/// Set by `--json`: read commands print JSON instead of formatted text
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+/// Configure how prompts behave for the rest of this invocation
+pub fn set_prompt_mode(non_interactive: bool, assume_yes: bool) {
+    NON_INTERACTIVE.store(non_interactive, Ordering::Relaxed);
+    ASSUME_YES.store(assume_yes, Ordering::Relaxed);
+}
+
+/// Whether prompts must not read from stdin
+pub fn is_non_interactive() -> bool {
+    NON_INTERACTIVE.load(Ordering::Relaxed)
+}
+
+/// Whether confirmations are pre-approved with `--yes`
+pub fn assume_yes() -> bool {
+    ASSUME_YES.load(Ordering::Relaxed)
+}
+
+/// Switch read commands to JSON output for the rest of this invocation
+pub fn set_json_output(json: bool) {
+    JSON_OUTPUT.store(json, Ordering::Relaxed);
+}
+
+/// Whether read commands should print JSON
+pub fn json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// Print a value as pretty JSON on stdout
+pub fn print_json<T: serde::Serialize + ?Sized>(value: &T) -> CommandResult {
+    writeln!(std::io::stdout(), "{}", serde_json::to_string_pretty(value)?)?;
+    Ok(())
+}
+
+/// Ask a yes/no question, defaulting to "no".
+///
+/// Returns `true` immediately with `--yes`, and fails instead of blocking
+/// when running with `--non-interactive`.
+pub fn confirm(prompt: &str) -> Result<bool, Box<dyn std::error::Error>> {
+    if assume_yes() {
+        return Ok(true);
+    }
+    let answer = prompt_line(&format!("{} (y/N): ", prompt))?;
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
+
+/// Read a line of free-form input, failing in non-interactive mode
+pub fn prompt_line(prompt: &str) -> Result<String, Box<dyn std::error::Error>> {
+    if is_non_interactive() {
+        return Err(format!(
+            "Input required but running non-interactively: {}\n   Re-run with --yes to confirm automatically or pass the value as an argument.",
+            prompt.trim().trim_end_matches(':').trim_end_matches("(y/N)").trim()
+        ).into());
+    }
+    print!("{}", prompt);
+    std::io::stdout().flush()?;
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(input)
+}
 
 /// Enhanced input validation for task descriptions
 pub fn validate_task_description(description: &str) -> Result<(), String> {
@@ -42,14 +111,183 @@ pub fn validate_and_parse_tags(tags_str: &str) -> Result<Vec<String>, String> {
         if tag.len() > 50 {
             return Err(format!("Tag '{}' is too long (max 50 characters)", tag));
         }
-        if !tag.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
-            return Err(format!("Tag '{}' contains invalid characters. Use only letters, numbers, hyphens, and underscores", tag));
+        if !tag.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_' || c == '/') {
+            return Err(format!("Tag '{}' contains invalid characters. Use only letters, numbers, hyphens, underscores, and / between nested parts", tag));
+        }
+        if tag.split('/').any(str::is_empty) {
+            return Err(format!("Tag '{}' has an empty part. Nest tags like area/backend/auth", tag));
         }
     }
     
     Ok(tags)
 }
 
+/// Parse task owners from a comma-separated string
+pub fn parse_owners(owners_str: &str) -> Result<Vec<String>, String> {
+    let owners: Vec<String> = owners_str.split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    
+    if owners.is_empty() {
+        return Err("No owners given".to_string());
+    }
+    for owner in &owners {
+        if owner.len() > 100 {
+            return Err(format!("Owner '{}' is too long (max 100 characters)", owner));
+        }
+        if owner.contains(['\n', '|', '=']) {
+            return Err(format!("Owner '{}' contains invalid characters", owner));
+        }
+    }
+    
+    Ok(owners)
+}
+
+/// Parse GTD-style contexts from a comma-separated string, adding the `@` prefix if missing
+pub fn parse_contexts(contexts_str: &str) -> Result<Vec<String>, String> {
+    let mut contexts = Vec::new();
+    for context in contexts_str.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+        let name = context.trim_start_matches('@');
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '-' || c == '_') {
+            return Err(format!("Context '{}' is invalid. Use a single word such as @home or @office", context));
+        }
+        contexts.push(format!("@{}", name.to_lowercase()));
+    }
+    
+    if contexts.is_empty() {
+        return Err("No contexts given".to_string());
+    }
+    
+    Ok(contexts)
+}
+
+/// Name of the current user from `user.name` in the config, falling back to git
+pub fn current_user_name() -> Option<String> {
+    crate::config::RaskConfig::load().ok()
+        .and_then(|config| config.user.name)
+        .or_else(|| {
+            let output = std::process::Command::new("git").args(["config", "user.name"]).output().ok()?;
+            let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            (output.status.success() && !name.is_empty()).then_some(name)
+        })
+}
+
+/// Command for the user's editor from `advanced.editor` or `$EDITOR`, split so values like "code --wait" work
+pub fn editor_command() -> Result<std::process::Command, Box<dyn std::error::Error>> {
+    let editor = crate::config::RaskConfig::load().ok()
+        .and_then(|config| config.advanced.editor)
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|e| !e.trim().is_empty())
+        .ok_or("No editor configured. Set EDITOR environment variable or use 'rask config set advanced.editor <editor>'")?;
+    
+    let mut parts = editor.split_whitespace();
+    let mut command = std::process::Command::new(parts.next().unwrap_or_default());
+    command.args(parts);
+    Ok(command)
+}
+
+/// Switch into another project's directory for the rest of this invocation
+///
+/// `project` is either a name from the `[projects]` config table or a path to a
+/// project directory (or its `.rask` folder). Nothing persistent changes; file
+/// arguments are made absolute beforehand (see `Commands::absolutize_paths`),
+/// so they still resolve against the directory the command was run from.
+pub fn enter_project(project: &str) -> CommandResult {
+    let dir = resolve_project_dir(project)?;
+    std::env::set_current_dir(&dir)
+        .map_err(|e| format!("Could not enter project directory '{}': {}", dir.display(), e))?;
+    Ok(())
+}
+
+/// Find the directory of a project given by registered name or path
+pub fn resolve_project_dir(project: &str) -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let registered = crate::config::RaskConfig::load_user_config().ok()
+        .and_then(|config| config.projects.get(project).cloned());
+    let raw = registered.as_deref().unwrap_or(project);
+
+    let mut dir = match raw.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().ok_or("Could not determine home directory")?.join(rest),
+        None => std::path::PathBuf::from(raw),
+    };
+    if dir.file_name().is_some_and(|name| name == ".rask") {
+        dir.pop();
+        if dir.as_os_str().is_empty() {
+            dir.push(".");
+        }
+    }
+
+    if !dir.join(".rask").is_dir() {
+        return Err(match registered {
+            Some(_) => format!("Project '{}' points to '{}', which has no .rask workspace", project, dir.display()),
+            None => format!("No project named '{}' and '{}' is not a rask project directory", project, dir.display()),
+        }.into());
+    }
+    Ok(dir)
+}
+
+/// Parse a due date given as YYYY-MM-DD, "today", or "tomorrow"
+pub fn parse_due_date(date_str: &str) -> Result<chrono::NaiveDate, String> {
+    let today = chrono::Local::now().date_naive();
+    match date_str.trim().to_lowercase().as_str() {
+        "today" => Ok(today),
+        "tomorrow" => Ok(today + chrono::Duration::days(1)),
+        other => chrono::NaiveDate::parse_from_str(other, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid due date '{}'. Use YYYY-MM-DD, today, or tomorrow", date_str)),
+    }
+}
+
+/// Parse a `--since` style date: yesterday, today, a weekday (most recent), Nd, or YYYY-MM-DD
+pub fn parse_since(since: &str) -> Result<chrono::NaiveDate, String> {
+    use chrono::Datelike;
+
+    let today = chrono::Local::now().date_naive();
+    let value = since.trim().to_lowercase();
+    let weekdays = ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"];
+
+    if value == "today" {
+        Ok(today)
+    } else if value == "yesterday" {
+        Ok(today - chrono::Duration::days(1))
+    } else if let Some(index) = weekdays.iter().position(|day| value.len() >= 3 && day.starts_with(value.as_str())) {
+        let back = (7 + today.weekday().num_days_from_monday() as i64 - index as i64 - 1) % 7 + 1;
+        Ok(today - chrono::Duration::days(back))
+    } else if let Some(days) = value.strip_suffix('d').and_then(|n| n.parse::<i64>().ok()) {
+        Ok(today - chrono::Duration::days(days))
+    } else {
+        chrono::NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date '{}'. Use yesterday, today, a weekday, Nd, or YYYY-MM-DD", since))
+    }
+}
+
+/// Parse a span of days given as "30d", "2w", or a plain number of days
+pub fn parse_day_span(text: &str) -> Result<chrono::Duration, String> {
+    let text = text.trim().to_lowercase();
+    let (number, days_per_unit) = match text.strip_suffix('w') {
+        Some(weeks) => (weeks, 7),
+        None => (text.strip_suffix('d').unwrap_or(&text), 1),
+    };
+    match number.trim().parse::<i64>() {
+        Ok(n) if n >= 0 => Ok(chrono::Duration::days(n * days_per_unit)),
+        _ => Err(format!("Invalid span '{}'. Use a number of days or weeks, e.g. 30d or 2w", text)),
+    }
+}
+
+/// Drop snoozed tasks unless `--include-snoozed` was given, and say how many
+/// were hidden in the formatted output
+pub fn hide_snoozed<T: std::borrow::Borrow<crate::model::Task>>(tasks: &mut Vec<T>, include_snoozed: bool) {
+    if include_snoozed {
+        return;
+    }
+    let today = chrono::Local::now().date_naive();
+    let before = tasks.len();
+    tasks.retain(|task| !task.borrow().is_snoozed(today));
+    let hidden = before - tasks.len();
+    if hidden > 0 && !json_output() && !ui::is_porcelain() {
+        ui::display_info(&format!("💤 {} snoozed task{} hidden (use --include-snoozed to show)", hidden, if hidden == 1 { "" } else { "s" }));
+    }
+}
+
 /// Validate and parse dependencies from a comma-separated string
 pub fn validate_and_parse_dependencies(deps_str: &str, roadmap: &Roadmap) -> Result<Vec<usize>, String> {
     let deps: Vec<usize> = deps_str.split(',')
@@ -114,11 +352,267 @@ pub fn parse_and_validate_task_ids(ids_str: &str, roadmap: &Roadmap) -> Result<V
     Ok(task_ids)
 }
 
+/// A `--filter` expression such as `tag:backend phase:MVP status:pending`.
+///
+/// Keys fill the same query `rask list` uses; `tag:` matches any of the given
+/// tags, and words without a key are searched in descriptions, tags and notes.
+/// Quote values that contain spaces: `phase:"Code Review"`.
+pub struct TaskFilter {
+    query: state::TaskQuery,
+    owner: Option<String>,
+}
+
+impl TaskFilter {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let mut query = state::TaskQuery::default();
+        let mut owner = None;
+        let mut words = Vec::new();
+        
+        for term in split_filter_terms(expression) {
+            let Some((key, value)) = term.split_once(':') else {
+                words.push(term);
+                continue;
+            };
+            let value = value.trim();
+            if value.is_empty() {
+                return Err(format!("Filter term '{}' has no value", term));
+            }
+            match key.to_lowercase().as_str() {
+                "tag" | "tags" => query.tags.extend(
+                    value.split(',').map(|tag| tag.trim().trim_start_matches('#').to_string()).filter(|tag| !tag.is_empty())
+                ),
+                "priority" => query.priority = Some(
+                    <crate::cli::CliPriority as clap::ValueEnum>::from_str(value, true)
+                        .map_err(|_| format!("Invalid priority in filter: {}. Use low, medium, high or critical.", value))?
+                        .into()
+                ),
+                "phase" => query.phase = Some(crate::model::Phase::from_string(value)),
+                "status" => query.status = match value.to_lowercase().as_str() {
+                    "all" => None,
+                    "pending" => Some(crate::model::TaskStatus::Pending),
+                    "completed" | "done" => Some(crate::model::TaskStatus::Completed),
+                    _ => return Err(format!("Invalid status in filter: {}. Use 'pending', 'completed', or 'all'.", value)),
+                },
+                "owner" => owner = Some(value.to_string()),
+                _ => return Err(format!("Unknown filter key '{}'. Use tag, priority, phase, status or owner.", key)),
+            }
+        }
+        
+        if !words.is_empty() {
+            query.text = Some(words.join(" "));
+        }
+        Ok(TaskFilter { query, owner })
+    }
+    
+    pub fn matches(&self, task: &crate::model::Task) -> bool {
+        self.query.matches(task) && self.owner.as_ref().is_none_or(|owner| task.has_owner(owner))
+    }
+}
+
+/// Split a filter on whitespace, keeping double-quoted values together
+fn split_filter_terms(expression: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    for c in expression.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    terms.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        terms.push(current);
+    }
+    terms
+}
+
+/// Resolve the tasks a bulk command applies to.
+///
+/// Explicit IDs are used as given. A `--filter` selection is previewed and
+/// must be confirmed first; `None` means the run was cancelled.
+pub fn select_bulk_tasks(
+    selection: &crate::cli::TaskSelection,
+    roadmap: &Roadmap,
+) -> Result<Option<Vec<usize>>, Box<dyn std::error::Error>> {
+    let Some(expression) = selection.filter.as_deref() else {
+        return Ok(Some(parse_and_validate_task_ids(selection.ids.as_deref().unwrap_or_default(), roadmap)?));
+    };
+    
+    let filter = TaskFilter::parse(expression)?;
+    let matching: Vec<&crate::model::Task> = roadmap.tasks.iter().filter(|task| filter.matches(task)).collect();
+    if matching.is_empty() {
+        ui::display_info(&format!("No tasks match the filter \"{}\"", expression));
+        return Ok(None);
+    }
+    
+    ui::display_info(&format!("🔎 {} tasks match \"{}\":", matching.len(), expression));
+    for task in &matching {
+        let status = if task.status == crate::model::TaskStatus::Completed { "✅" } else { "⬜" };
+        println!("   {} #{} {} ({}, {})", status, task.id, task.description, task.priority, task.phase.name);
+    }
+    
+    if !confirm(&format!("Apply this to {} tasks?", matching.len()))? {
+        ui::display_info("Bulk operation cancelled");
+        return Ok(None);
+    }
+    Ok(Some(matching.iter().map(|task| task.id).collect()))
+}
+
+/// Enforce a phase's WIP limit for a change that leaves `open` pending tasks in it.
+///
+/// Going over the limit prints a warning, or fails when
+/// `behavior.wip_limit_mode` is "refuse".
+pub fn check_wip_limit(roadmap: &Roadmap, phase: &crate::model::Phase, open: usize) -> CommandResult {
+    let Some(limit) = roadmap.phase_limit(phase) else {
+        return Ok(());
+    };
+    if open <= limit {
+        return Ok(());
+    }
+    
+    let message = format!("Phase {} would have {} open tasks, over its WIP limit of {}", phase.name, open, limit);
+    let refuse = RaskConfig::load().is_ok_and(|config| config.behavior.wip_limit_mode == "refuse");
+    if refuse {
+        return Err(CommandError::Blocked(format!(
+            "{}. Finish or move tasks first, or raise the limit with 'rask phase limit {} <n>'.",
+            message, phase.name
+        )).into());
+    }
+    ui::display_warning(&message);
+    Ok(())
+}
+
 /// Common pattern for saving state and syncing to markdown
+///
+/// Edits made to the markdown source outside rask are merged or confirmed
+/// before it is overwritten (see [`reconcile_source_edits`]). The file is
+/// written from the state as saved, so tasks added by automation rules
+/// appear in it too.
 pub fn save_and_sync(roadmap: &Roadmap) -> CommandResult {
-    state::save_state(roadmap)?;
-    markdown_writer::sync_to_source_file(roadmap)?;
-    Ok(())
+    save_and_sync_source(roadmap).map(|_| ())
+}
+
+/// Like [`save_and_sync`], returning whether the markdown source was written
+pub fn save_and_sync_source(roadmap: &Roadmap) -> Result<bool, Box<dyn std::error::Error>> {
+    let (merged, write_source) = reconcile_source_edits(roadmap)?;
+    let saved = state::commit_state(merged.as_ref().unwrap_or(roadmap))?;
+    if !write_source || saved.source_file.is_none() {
+        return Ok(false);
+    }
+    markdown_writer::write_source_file(&saved)?;
+    Ok(true)
+}
+
+/// Deal with edits made to the markdown source since rask last wrote it.
+///
+/// New unchecked items are added as tasks. Any other difference would be
+/// lost by overwriting the file, so the user is asked first; declining (or
+/// running non-interactively) keeps the file as it is. Returns the roadmap
+/// with merged tasks, if any, and whether the source file may be written.
+fn reconcile_source_edits(roadmap: &Roadmap) -> Result<(Option<Roadmap>, bool), Box<dyn std::error::Error>> {
+    // Without a synced copy of the file, compare with the saved state so the
+    // change being saved is not mistaken for a conflict
+    let saved = if state::get_synced_source_file()?.exists() { None } else { state::load_stored_state().ok() };
+    let Some(edits) = markdown_writer::detect_source_edits(saved.as_ref().unwrap_or(roadmap))? else {
+        return Ok((None, true));
+    };
+    let source_file = roadmap.source_file.as_deref().unwrap_or_default();
+
+    let merged = if edits.new_items.is_empty() {
+        None
+    } else {
+        let mut merged = roadmap.clone();
+        let added = edits.merge_into(&mut merged);
+        ui::display_info(&format!("📥 Added {} new item(s) from {} as task(s): {}", added.len(), source_file,
+            added.iter().map(|id| format!("#{}", id)).collect::<Vec<_>>().join(", ")));
+        Some(merged)
+    };
+
+    if edits.conflicts.is_empty() {
+        return Ok((merged, true));
+    }
+    ui::display_warning(&format!("{} was edited outside rask and differs from the project state:", source_file));
+    for conflict in &edits.conflicts {
+        println!("   • {}", conflict);
+    }
+    let overwrite = (assume_yes() || !is_non_interactive()) && confirm(&format!("Overwrite {} with the current tasks?", source_file))?;
+    if !overwrite {
+        ui::display_info(&format!("Left {} unchanged. Import its edits with 'rask sync --from-roadmap'", source_file));
+    }
+    Ok((merged, overwrite))
+}
+
+/// Run a multi-step operation under a checkpoint.
+///
+/// If an earlier checkpointed run was interrupted, the user first chooses to
+/// resume from the current state or roll back to the snapshot. The checkpoint
+/// is removed when the operation succeeds, or when it fails without having
+/// changed anything.
+pub fn run_with_checkpoint(operation: &str, run: impl FnOnce() -> CommandResult) -> CommandResult {
+    resolve_interrupted_operation()?;
+    
+    let before = state::load_state()?;
+    state::begin_operation(operation, &before)?;
+    
+    let result = run();
+    let unchanged = || -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(state_fingerprint(&state::load_state()?)? == state_fingerprint(&before)?)
+    };
+    if result.is_ok() || unchanged()? {
+        state::finish_operation()?;
+    }
+    result
+}
+
+/// The state as JSON with task tags in a fixed order, so loading the same
+/// state twice gives the same value despite `HashSet` iteration order
+fn state_fingerprint(roadmap: &Roadmap) -> Result<serde_json::Value, serde_json::Error> {
+    let mut value = serde_json::to_value(roadmap)?;
+    if let Some(tasks) = value["tasks"].as_array_mut() {
+        for tags in tasks.iter_mut().filter_map(|task| task["tags"].as_array_mut()) {
+            tags.sort_by(|a, b| a.as_str().cmp(&b.as_str()));
+        }
+    }
+    Ok(value)
+}
+
+/// Deal with a checkpoint left behind by an interrupted operation, if any
+fn resolve_interrupted_operation() -> CommandResult {
+    let Some(checkpoint) = state::load_checkpoint()? else {
+        return Ok(());
+    };
+    
+    crate::ui::display_warning(&format!(
+        "The last '{}' run (started {}) did not finish; its changes may be half-applied.",
+        checkpoint.operation,
+        checkpoint.started_at.get(..19).unwrap_or(&checkpoint.started_at).replace('T', " ")
+    ));
+    if is_non_interactive() {
+        return Err("Run 'rask recover --rollback' to restore the state from before that run, or 'rask recover --discard' to keep the current state.".into());
+    }
+    
+    loop {
+        let answer = prompt_line("Resume from the current state, roll back to before that run, or abort? [r/b/a]: ")?;
+        match answer.trim().to_lowercase().as_str() {
+            "r" | "resume" => {
+                state::finish_operation()?;
+                return Ok(());
+            }
+            "b" | "rollback" => {
+                save_and_sync(&checkpoint.snapshot)?;
+                state::finish_operation()?;
+                crate::ui::display_success(&format!("Rolled back the interrupted '{}' run", checkpoint.operation));
+                return Ok(());
+            }
+            "a" | "abort" | "" => return Err("Aborted; the interrupted run's checkpoint was kept".into()),
+            _ => continue,
+        }
+    }
 }
 
 /// Escape HTML special characters for export functionality
@@ -0,0 +1,243 @@
+//! Workspace commands
+//!
+//! Link child projects to a parent project (see [`crate::workspace`]) and
+//! report on all of them at once.
+
+use crate::cli::WorkspaceCommands;
+use crate::model::{Priority, Task, TaskStatus};
+use crate::sorting::{self, SortKey};
+use crate::workspace::{self, LinkedProject, Member, Workspace};
+use crate::{state, ui};
+use super::{utils, CommandError, CommandResult};
+use colored::Colorize;
+use serde::Serialize;
+use std::path::{Component, Path};
+
+/// Handle workspace subcommands
+pub fn handle_workspace_command(workspace_command: &WorkspaceCommands) -> CommandResult {
+    match workspace_command {
+        WorkspaceCommands::Add { project, name } => add_workspace_project(project, name.as_deref()),
+        WorkspaceCommands::Remove { name } => remove_workspace_project(name),
+        WorkspaceCommands::List => list_workspace_projects(),
+        WorkspaceCommands::Status => show_workspace_status(),
+    }
+}
+
+/// Link a child project to the workspace rooted at the current directory
+pub fn add_workspace_project(project: &str, name: Option<&str>) -> CommandResult {
+    let dir = utils::resolve_project_dir(project)?.canonicalize()?;
+    let root = std::env::current_dir()?.canonicalize()?;
+    if dir == root {
+        return Err("A workspace cannot link to itself; run 'rask workspace add' from the parent project".into());
+    }
+
+    let registered = crate::config::RaskConfig::load_user_config().ok()
+        .is_some_and(|config| config.projects.contains_key(project));
+    let name = match name {
+        Some(name) => name.to_string(),
+        None if registered => project.to_string(),
+        None => dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_else(|| project.to_string()),
+    };
+
+    let mut workspace = Workspace::load_here()?;
+    if workspace.projects.iter().any(|p| p.name == name) {
+        return Err(format!("The workspace already has a project named '{}'; pick another with --name", name).into());
+    }
+
+    // Children inside the workspace are stored relative to it so the repository can move
+    let path = match dir.strip_prefix(&root) {
+        Ok(relative) => relative.to_string_lossy().to_string(),
+        Err(_) => dir.to_string_lossy().to_string(),
+    };
+    if let Some(existing) = workspace.projects.iter().find(|p| p.path == path) {
+        return Err(format!("{} is already linked as '{}'", path, existing.name).into());
+    }
+    workspace.projects.push(LinkedProject { name: name.clone(), path: path.clone() });
+    workspace.save_here()?;
+
+    ui::display_success(&format!("Linked project '{}' ({}) to the workspace", name, path));
+    Ok(())
+}
+
+/// Unlink a child project from the workspace in the current directory
+pub fn remove_workspace_project(name: &str) -> CommandResult {
+    let mut workspace = Workspace::load_here()?;
+    let before = workspace.projects.len();
+    workspace.projects.retain(|p| p.name != name);
+    if workspace.projects.len() == before {
+        return Err(CommandError::NotFound(format!("No project named '{}' in this workspace", name)).into());
+    }
+    workspace.save_here()?;
+    ui::display_success(&format!("Unlinked project '{}' from the workspace", name));
+    Ok(())
+}
+
+/// List the linked projects of the current workspace
+pub fn list_workspace_projects() -> CommandResult {
+    let (root, workspace) = find_workspace()?;
+    if utils::json_output() {
+        return utils::print_json(&workspace.projects);
+    }
+    if workspace.projects.is_empty() {
+        ui::display_info("No projects linked yet. Link one with 'rask workspace add <dir>'");
+        return Ok(());
+    }
+
+    ui::display_info(&format!("🗂️  Workspace {} links {} project(s):", root.display(), workspace.projects.len()));
+    for project in &workspace.projects {
+        let dir = root.join(&project.path);
+        let missing = if dir.join(".rask").is_dir() { String::new() } else { " (missing)".red().to_string() };
+        println!("  {:<16} {}{}", project.name.bold(), project.path, missing);
+    }
+    Ok(())
+}
+
+/// Progress figures for one workspace member
+#[derive(Serialize)]
+struct ProjectStatus {
+    project: String,
+    title: String,
+    path: String,
+    total: usize,
+    completed: usize,
+    ready: usize,
+    urgent: usize,
+    overdue: usize,
+}
+
+/// Show progress across every project in the workspace
+pub fn show_workspace_status() -> CommandResult {
+    let (root, workspace) = find_workspace()?;
+    let mut statuses = Vec::new();
+    for member in workspace.members(&root) {
+        match workspace::in_project(&member.dir, state::load_state_summary)? {
+            Ok(roadmap) => {
+                let today = chrono::Local::now().date_naive();
+                let pending = || roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Pending);
+                statuses.push(ProjectStatus {
+                    project: member.name.clone(),
+                    title: roadmap.title.clone(),
+                    path: display_path(&root, &member),
+                    total: roadmap.tasks.len(),
+                    completed: roadmap.tasks.len() - pending().count(),
+                    ready: roadmap.get_ready_tasks().len(),
+                    urgent: pending().filter(|t| matches!(t.priority, Priority::High | Priority::Critical)).count(),
+                    overdue: pending()
+                        .filter(|t| t.due_date.as_deref().and_then(|d| utils::parse_due_date(d).ok()).is_some_and(|d| d < today))
+                        .count(),
+                });
+            }
+            Err(e) => ui::display_warning(&format!("Skipping '{}': {}", member.name, e)),
+        }
+    }
+
+    if utils::json_output() {
+        return utils::print_json(&statuses);
+    }
+
+    let total: usize = statuses.iter().map(|s| s.total).sum();
+    let completed: usize = statuses.iter().map(|s| s.completed).sum();
+    println!("\n{}", "═".repeat(72).bright_blue());
+    println!("  🗂️  {} ({} projects)", "Workspace Status".bold().bright_cyan(), statuses.len());
+    println!("{}", "═".repeat(72).bright_blue());
+    println!("  {:<16} {:>11} {:>6} {:>6} {:>7} {:>8}", "Project".bold(), "Done".bold(), "%".bold(), "Ready".bold(), "Urgent".bold(), "Overdue".bold());
+    println!("  {}", "─".repeat(68).bright_black());
+    for status in &statuses {
+        let percent = (status.completed * 100).checked_div(status.total).unwrap_or(0);
+        println!(
+            "  {:<16} {:>11} {:>5}% {:>6} {:>7} {:>8}",
+            status.project,
+            format!("{}/{}", status.completed, status.total),
+            percent,
+            status.ready,
+            status.urgent,
+            status.overdue
+        );
+    }
+    println!("  {}", "─".repeat(68).bright_black());
+    let percent = (completed * 100).checked_div(total).unwrap_or(0);
+    println!("  {:<16} {:>11} {:>5}%", "Total".bold(), format!("{}/{}", completed, total), percent);
+    println!();
+    ui::progress::display_progress_bar(completed, total);
+    Ok(())
+}
+
+/// Tasks of one workspace member that matched a cross-project query
+#[derive(Serialize)]
+struct ProjectTasks {
+    project: String,
+    total: usize,
+    tasks: Vec<Task>,
+}
+
+/// `rask list --all-projects`: run the list filters against every workspace member
+pub fn list_workspace_tasks(query: &state::TaskQuery, owner: Option<&str>, stale: Option<u32>, detailed: bool, sort: SortKey, reverse: bool, include_snoozed: bool) -> CommandResult {
+    let (root, workspace) = find_workspace()?;
+    let mut results = Vec::new();
+    for member in workspace.members(&root) {
+        let loaded = workspace::in_project(&member.dir, || -> Result<_, std::io::Error> {
+            Ok((state::load_state_summary()?, state::query_tasks(query)?))
+        })?;
+        match loaded {
+            Ok((roadmap, mut tasks)) => {
+                if let Some(name) = owner {
+                    tasks.retain(|task| task.has_owner(name));
+                }
+                if let Some(days) = stale {
+                    tasks.retain(|task| task.is_stale(i64::from(days)));
+                }
+                utils::hide_snoozed(&mut tasks, include_snoozed);
+                sorting::sort_tasks(&mut tasks, sort, reverse, &roadmap);
+                results.push(ProjectTasks { project: member.name, total: roadmap.tasks.len(), tasks });
+            }
+            Err(e) => ui::display_warning(&format!("Skipping '{}': {}", member.name, e)),
+        }
+    }
+
+    if utils::json_output() {
+        return utils::print_json(&results);
+    }
+    if ui::is_porcelain() {
+        ui::print_porcelain_lines(results.iter().flat_map(|result| {
+            result.tasks.iter().map(|task| format!("{}\t{}", result.project, ui::porcelain_line(task)))
+        }));
+        return Ok(());
+    }
+
+    let matched: usize = results.iter().map(|r| r.tasks.len()).sum();
+    let total: usize = results.iter().map(|r| r.total).sum();
+    println!("\n{}", "═".repeat(60).bright_blue());
+    println!("  {} (Showing {} of {} tasks in {} projects)",
+        "All Projects".bold().bright_cyan(),
+        matched.to_string().bright_white(),
+        total.to_string().bright_white(),
+        results.len()
+    );
+    println!("{}", "═".repeat(60).bright_blue());
+    for result in results.iter().filter(|r| !r.tasks.is_empty()) {
+        println!("\n  📁 {} ({} of {})", result.project.bold(), result.tasks.len(), result.total);
+        println!("  {}", "─".repeat(50).bright_black());
+        let tasks: Vec<&Task> = result.tasks.iter().collect();
+        ui::display_task_lines(&tasks, detailed);
+    }
+    if matched == 0 {
+        println!("\n  🔍 No tasks match your filter criteria in any project.");
+    }
+    println!();
+    Ok(())
+}
+
+fn find_workspace() -> Result<(std::path::PathBuf, Workspace), Box<dyn std::error::Error>> {
+    Workspace::find()?.ok_or_else(|| {
+        "Not inside a workspace. Link child projects with 'rask workspace add <dir>' from the parent project".into()
+    })
+}
+
+/// Member path as shown to the user: relative to the workspace root when possible
+fn display_path(root: &Path, member: &Member) -> String {
+    match member.dir.strip_prefix(root) {
+        Ok(relative) if relative.components().next().is_none() => ".".to_string(),
+        Ok(relative) if !relative.components().any(|c| c == Component::ParentDir) => relative.display().to_string(),
+        _ => member.dir.display().to_string(),
+    }
+}
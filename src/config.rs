@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use std::fs;
 use std::io::{Error, ErrorKind};
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 /// The main configuration structure for Rask
 /// This struct holds all user-configurable settings and preferences
@@ -25,6 +26,38 @@ pub struct RaskConfig {
     
     /// AI integration settings
     pub ai: AiConfig,
+    
+    /// Identity used for task ownership (e.g. `rask list --mine`)
+    #[serde(default)]
+    pub user: UserConfig,
+    
+    /// Jira connection for `rask sync jira`
+    #[serde(default)]
+    pub jira: JiraConfig,
+    
+    /// GitHub access for `rask github push` and `rask import github`
+    #[serde(default)]
+    pub github: GithubConfig,
+    
+    /// Chat webhooks notified about project events, configured under `[notifications]`
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+    
+    /// Named project directories for the global `--project` option
+    #[serde(default)]
+    pub projects: HashMap<String, String>,
+    
+    /// Automation rules evaluated after every change, configured as `[[rules]]`
+    #[serde(default)]
+    pub rules: Vec<AutomationRule>,
+    
+    /// Escalation rules applied to open tasks whenever state is loaded, configured as `[[escalations]]`
+    #[serde(default)]
+    pub escalations: Vec<EscalationRule>,
+    
+    /// Named partial configs selected with `--profile`, configured as `[profiles.<name>]`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, toml::Table>,
 }
 
 /// UI and display configuration
@@ -36,7 +69,7 @@ pub struct UiConfig {
     /// Show completed tasks by default in list command
     pub show_completed: bool,
     
-    /// Default sort order: "priority", "date", "id", "description"
+    /// Default sort order for `list` and `show`: "id", "priority", "due", "created", "estimate" or "phase"
     pub default_sort: String,
     
     /// Use compact view by default (less spacing and details)
@@ -47,6 +80,14 @@ pub struct UiConfig {
     
     /// Maximum terminal width to use (0 = auto-detect)
     pub max_width: usize,
+    
+    /// Screen-reader friendly output: text labels instead of emoji, colors and box drawing
+    #[serde(default)]
+    pub accessible: bool,
+    
+    /// Pending tasks idle for this many days are marked stale (0 = never)
+    #[serde(default = "default_stale_days")]
+    pub stale_days: u32,
 }
 
 /// Behavior and workflow configuration
@@ -72,6 +113,49 @@ pub struct BehaviorConfig {
     
     /// Automatically sync to markdown file after changes
     pub auto_sync_markdown: bool,
+    
+    /// Warn about time sessions running longer than this many hours (0 = never)
+    #[serde(default = "default_max_session_hours")]
+    pub max_session_hours: f64,
+    
+    /// Hours of work a day can hold; `rask calendar` flags days above it
+    #[serde(default = "default_daily_capacity_hours")]
+    pub daily_capacity_hours: f64,
+    
+    /// Number of automatic state backups to keep in .rask/backups (0 = none)
+    #[serde(default = "default_backup_count")]
+    pub backup_count: usize,
+    
+    /// What happens when a change exceeds a phase's WIP limit: "warn" or "refuse"
+    #[serde(default = "default_wip_limit_mode")]
+    pub wip_limit_mode: String,
+    
+    /// Keep task IDs for good: removing or reordering tasks leaves gaps instead of renumbering
+    #[serde(default)]
+    pub stable_ids: bool,
+}
+
+fn default_stale_days() -> u32 {
+    30
+}
+
+fn default_max_session_hours() -> f64 {
+    8.0
+}
+
+fn default_daily_capacity_hours() -> f64 {
+    6.0
+}
+
+/// Automatic state backups kept when `behavior.backup_count` is not set
+pub const DEFAULT_BACKUP_COUNT: usize = 10;
+
+fn default_backup_count() -> usize {
+    DEFAULT_BACKUP_COUNT
+}
+
+fn default_wip_limit_mode() -> String {
+    "warn".to_string()
 }
 
 /// Export and integration configuration
@@ -88,6 +172,81 @@ pub struct ExportConfig {
     
     /// Include metadata in exports
     pub include_metadata: bool,
+    
+    /// Named upload targets for `rask export --to <name>`
+    #[serde(default)]
+    pub destinations: HashMap<String, ExportDestination>,
+}
+
+/// An upload target for exported files, configured under `[export.destinations.<name>]`
+///
+/// Credential fields starting with `$` are read from that environment variable.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ExportDestination {
+    /// S3-compatible object storage (AWS S3, MinIO, Cloudflare R2, ...)
+    S3 {
+        /// Endpoint URL, e.g. "https://s3.eu-west-1.amazonaws.com"
+        endpoint: String,
+        
+        /// Bucket name (addressed path-style)
+        bucket: String,
+        
+        /// Signing region
+        #[serde(default = "default_s3_region")]
+        region: String,
+        
+        /// Key prefix for uploaded files, e.g. "reports/rask"
+        #[serde(default)]
+        prefix: String,
+        
+        /// Access key ID (defaults to $AWS_ACCESS_KEY_ID)
+        access_key: Option<String>,
+        
+        /// Secret access key (defaults to $AWS_SECRET_ACCESS_KEY)
+        secret_key: Option<String>,
+    },
+    
+    /// WebDAV collection (Nextcloud, SharePoint, Apache mod_dav, ...)
+    Webdav {
+        /// Collection URL files are uploaded into
+        url: String,
+        
+        /// Basic auth user name
+        username: Option<String>,
+        
+        /// Basic auth password
+        password: Option<String>,
+    },
+    
+    /// Commit into a local clone of a docs repository
+    Git {
+        /// Path to the repository working tree
+        repo: String,
+        
+        /// Directory inside the repository to copy files into
+        #[serde(default)]
+        path: String,
+        
+        /// Push after committing
+        #[serde(default)]
+        push: bool,
+    },
+}
+
+impl ExportDestination {
+    /// Short name of the destination type
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ExportDestination::S3 { .. } => "s3",
+            ExportDestination::Webdav { .. } => "webdav",
+            ExportDestination::Git { .. } => "git",
+        }
+    }
+}
+
+fn default_s3_region() -> String {
+    "us-east-1".to_string()
 }
 
 /// Advanced power user configuration
@@ -99,18 +258,26 @@ pub struct AdvancedConfig {
     /// External editor command for editing notes/descriptions
     pub editor: Option<String>,
     
+    /// Notes directory or Obsidian vault that `[[Note Name]]` links in task notes point into
+    #[serde(default)]
+    pub notes_dir: Option<String>,
+    
+    /// Where `rask template fetch` gets the team's shared templates from
+    #[serde(default)]
+    pub template_registry: Option<String>,
+    
     /// Custom task templates (future feature)
     pub templates: HashMap<String, String>,
     
-    /// Enable debug output
+    /// Log debug diagnostics to stderr, as with `-vv`
     pub debug: bool,
 }
 
-/// Theme configuration for future expansion
-/// This allows for complete UI customization
+/// Theme configuration
+/// `name` picks a built-in theme (see `ui::theme`); the other fields override it
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ThemeConfig {
-    /// Theme name
+    /// Built-in theme: "default", "dark", "light" or "no-emoji"
     pub name: String,
     
     /// Colors for different priority levels
@@ -119,6 +286,19 @@ pub struct ThemeConfig {
     /// Colors for different task statuses
     pub status_colors: HashMap<String, String>,
     
+    /// Indicators shown before tasks for each priority level
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub priority_symbols: HashMap<String, String>,
+    
+    /// Color and/or emoji per tag, e.g. `backend = "blue ⚙️"`; nested tags
+    /// inherit from their closest configured ancestor
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub tag_colors: HashMap<String, String>,
+    
+    /// Color and/or emoji per phase name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub phase_colors: HashMap<String, String>,
+    
     /// Icons/symbols to use for different elements
     pub symbols: SymbolConfig,
 }
@@ -140,6 +320,14 @@ pub struct SymbolConfig {
     
     /// Symbol for dependencies
     pub dependency: String,
+    
+    /// Character for the filled part of progress bars
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_filled: Option<String>,
+    
+    /// Character for the empty part of progress bars
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub progress_empty: Option<String>,
 }
 
 /// AI integration configuration
@@ -168,6 +356,281 @@ pub struct AiConfig {
     
     /// Context window size for conversations
     pub context_window: usize,
+    
+    /// Minutes to reuse a cached response for an identical request (0 disables the cache)
+    #[serde(default = "default_cache_ttl_minutes")]
+    pub cache_ttl_minutes: u64,
+    
+    /// Maximum AI requests per minute (0 means unlimited)
+    #[serde(default)]
+    pub requests_per_minute: u32,
+}
+
+fn default_cache_ttl_minutes() -> u64 {
+    60
+}
+
+/// User identity configuration
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct UserConfig {
+    /// Name matched against task owners
+    pub name: Option<String>,
+    
+    /// Optional email, used by time tracker exports
+    pub email: Option<String>,
+}
+
+/// Event a notification can be sent for
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationEvent {
+    /// A task was completed
+    TaskCompleted,
+    /// The last open task of a milestone was completed
+    MilestoneReached,
+}
+
+impl NotificationEvent {
+    /// Name used in the config and in message templates
+    pub fn name(self) -> &'static str {
+        match self {
+            NotificationEvent::TaskCompleted => "task_completed",
+            NotificationEvent::MilestoneReached => "milestone_reached",
+        }
+    }
+}
+
+/// Chat service a webhook posts to, which decides the payload shape
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookKind {
+    /// `{"text": ...}`, also understood by Mattermost and Rocket.Chat
+    Slack,
+    /// `{"content": ...}`
+    Discord,
+    /// `{"event": ..., "text": ..., ...}` with the event's fields, for custom receivers
+    Generic,
+}
+
+/// A webhook notified about project events, configured as `[[notifications.webhooks]]`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WebhookConfig {
+    /// Webhook URL, or `$VAR` to read it from the environment
+    pub url: String,
+    
+    /// Payload format; guessed from the URL when not set
+    pub kind: Option<WebhookKind>,
+    
+    /// Events sent to this webhook (all events when empty)
+    #[serde(default)]
+    pub events: Vec<NotificationEvent>,
+}
+
+impl WebhookConfig {
+    /// The payload format, guessed from the host when not configured
+    pub fn kind(&self) -> WebhookKind {
+        self.kind.unwrap_or(if self.url.contains("hooks.slack.com") {
+            WebhookKind::Slack
+        } else if self.url.contains("discord.com/api/webhooks") || self.url.contains("discordapp.com/api/webhooks") {
+            WebhookKind::Discord
+        } else {
+            WebhookKind::Generic
+        })
+    }
+    
+    /// Whether this webhook wants `event`
+    pub fn wants(&self, event: NotificationEvent) -> bool {
+        self.events.is_empty() || self.events.contains(&event)
+    }
+}
+
+/// Notification settings
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotificationsConfig {
+    /// Chat webhooks to post events to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<WebhookConfig>,
+    
+    /// Message templates by event name, overriding the built-in ones
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub templates: HashMap<String, String>,
+    
+    /// Show native desktop notifications (needs a build with the `desktop-notifications` feature)
+    #[serde(default)]
+    pub desktop: bool,
+    
+    /// Remind once when an active time session passes this many minutes (0 turns it off)
+    #[serde(default = "default_session_reminder_minutes")]
+    pub session_reminder_minutes: u64,
+    
+    /// Remind about pending tasks due today, once a day
+    #[serde(default = "default_true")]
+    pub due_today: bool,
+}
+
+fn default_session_reminder_minutes() -> u64 {
+    90
+}
+
+impl Default for NotificationsConfig {
+    fn default() -> Self {
+        NotificationsConfig {
+            webhooks: Vec::new(),
+            templates: HashMap::new(),
+            desktop: false,
+            session_reminder_minutes: default_session_reminder_minutes(),
+            due_today: true,
+        }
+    }
+}
+
+/// GitHub access used by `rask github push` and `rask import github`
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct GithubConfig {
+    /// Personal access token, or `$VAR` to read it from the environment (defaults to $GITHUB_TOKEN)
+    pub token: Option<String>,
+    
+    /// Repository issues are pushed to, in owner/name form
+    pub repo: Option<String>,
+    
+    /// API root for GitHub Enterprise Server, e.g. "https://github.example.com/api/v3"
+    pub api_url: Option<String>,
+}
+
+impl GithubConfig {
+    /// The configured token, falling back to $GITHUB_TOKEN
+    pub fn resolve_token(&self) -> Option<String> {
+        match self.token.as_deref() {
+            Some(token) => crate::destinations::expand_env(token).ok(),
+            None => std::env::var("GITHUB_TOKEN").ok(),
+        }
+    }
+}
+
+/// Jira connection used by `rask sync jira`, usually set in the project's `.rask/config.toml`
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct JiraConfig {
+    /// Site URL, e.g. "https://example.atlassian.net"
+    pub base_url: Option<String>,
+    
+    /// Key of the Jira project to sync, e.g. "PROJ"
+    pub project_key: Option<String>,
+    
+    /// Account email for Jira Cloud; without it the token is sent as a bearer token (Jira Server / Data Center)
+    pub email: Option<String>,
+    
+    /// API token, or `$VAR` to read it from the environment (defaults to $JIRA_API_TOKEN)
+    pub api_token: Option<String>,
+    
+    /// Transition used to close the issue of a completed task (defaults to the first one into a done status)
+    pub done_transition: Option<String>,
+}
+
+/// Event an automation rule reacts to
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleTrigger {
+    /// A task was added
+    TaskAdded,
+    
+    /// A task was marked as completed
+    TaskCompleted,
+    
+    /// The last open task of a phase was completed
+    PhaseCompleted,
+}
+
+impl std::fmt::Display for RuleTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleTrigger::TaskAdded => write!(f, "task_added"),
+            RuleTrigger::TaskCompleted => write!(f, "task_completed"),
+            RuleTrigger::PhaseCompleted => write!(f, "phase_completed"),
+        }
+    }
+}
+
+/// An automation rule, configured as a `[[rules]]` entry
+///
+/// Text fields accept the placeholders `{id}`, `{description}` and `{phase}`,
+/// filled in from the task (or phase) that triggered the rule.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AutomationRule {
+    /// Name shown in output and the audit log
+    pub name: String,
+    
+    /// Event that triggers the rule
+    pub when: RuleTrigger,
+    
+    /// Only match tasks with this tag, or a tag nested under it
+    pub tag: Option<String>,
+    
+    /// Only match tasks in (or completion of) this phase
+    pub phase: Option<String>,
+    
+    /// Disabled rules are listed but never run
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    
+    /// Description of a task to add
+    pub add_task: Option<String>,
+    
+    /// Tags for the added task
+    #[serde(default)]
+    pub task_tags: Vec<String>,
+    
+    /// Priority for the added task (defaults to medium)
+    pub task_priority: Option<String>,
+    
+    /// Phase for the added task (defaults to the triggering task's phase)
+    pub task_phase: Option<String>,
+    
+    /// Make the added task depend on the triggering task
+    #[serde(default)]
+    pub depends_on_trigger: bool,
+    
+    /// URL that receives a JSON POST describing the event
+    pub webhook: Option<String>,
+}
+
+/// An escalation rule, configured as an `[[escalations]]` entry
+///
+/// Unlike automation rules, escalations look at the current state rather
+/// than at changes: every condition that is set must hold for a pending
+/// task, and then the actions are applied to it.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EscalationRule {
+    /// Name shown in output, task history and the audit log
+    pub name: String,
+    
+    /// Disabled rules are listed but never run
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    
+    /// Match tasks due within this many days, including overdue ones
+    pub due_within_days: Option<i64>,
+    
+    /// Match tasks blocked by dependencies and created more than this many days ago
+    pub blocked_for_days: Option<i64>,
+    
+    /// Match tasks whose priority is lower than this
+    pub priority_below: Option<String>,
+    
+    /// Only match tasks with this tag, or a tag nested under it
+    pub tag: Option<String>,
+    
+    /// Only match tasks in this phase
+    pub phase: Option<String>,
+    
+    /// Raise the task's priority to this level
+    pub set_priority: Option<String>,
+    
+    /// Add this tag to the task
+    pub add_tag: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 /// Google Gemini specific configuration
@@ -196,6 +659,14 @@ impl Default for RaskConfig {
             advanced: AdvancedConfig::default(),
             theme: ThemeConfig::default(),
             ai: AiConfig::default(),
+            user: UserConfig::default(),
+            jira: JiraConfig::default(),
+            github: GithubConfig::default(),
+            notifications: NotificationsConfig::default(),
+            projects: HashMap::new(),
+            rules: Vec::new(),
+            escalations: Vec::new(),
+            profiles: HashMap::new(),
         }
     }
 }
@@ -209,6 +680,8 @@ impl Default for UiConfig {
             compact_view: false,
             show_task_ids: true,
             max_width: 0, // Auto-detect
+            accessible: false,
+            stale_days: default_stale_days(),
         }
     }
 }
@@ -223,6 +696,11 @@ impl Default for BehaviorConfig {
             warn_on_circular: true,
             confirm_destructive: true,
             auto_sync_markdown: true,
+            max_session_hours: default_max_session_hours(),
+            daily_capacity_hours: default_daily_capacity_hours(),
+            backup_count: default_backup_count(),
+            wip_limit_mode: default_wip_limit_mode(),
+            stable_ids: false,
         }
     }
 }
@@ -234,6 +712,7 @@ impl Default for ExportConfig {
             default_path: None,
             include_completed: true,
             include_metadata: true,
+            destinations: HashMap::new(),
         }
     }
 }
@@ -251,6 +730,8 @@ impl Default for AdvancedConfig {
         AdvancedConfig {
             aliases,
             editor: std::env::var("EDITOR").ok(),
+            notes_dir: None,
+            template_registry: None,
             templates: HashMap::new(),
             debug: false,
         }
@@ -274,6 +755,9 @@ impl Default for ThemeConfig {
             name: "default".to_string(),
             priority_colors,
             status_colors,
+            priority_symbols: HashMap::new(),
+            tag_colors: HashMap::new(),
+            phase_colors: HashMap::new(),
             symbols: SymbolConfig::default(),
         }
     }
@@ -287,6 +771,8 @@ impl Default for SymbolConfig {
             blocked: "🚫".to_string(),
             current_project: "👉".to_string(),
             dependency: "🔗".to_string(),
+            progress_filled: None,
+            progress_empty: None,
         }
     }
 }
@@ -302,6 +788,8 @@ impl Default for AiConfig {
             temperature: 0.7,
             auto_suggestions: false,
             context_window: 10,
+            cache_ttl_minutes: default_cache_ttl_minutes(),
+            requests_per_minute: 0,
         }
     }
 }
@@ -321,6 +809,18 @@ impl Default for GeminiConfig {
     }
 }
 
+/// Recursively merge `overlay` into `base`, replacing values that are not tables
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(nested)) => merge_tables(existing, nested),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
 /// Get the path to the Rask configuration directory
 /// On Linux: ~/.config/rask/
 /// Creates the directory if it doesn't exist
@@ -366,12 +866,48 @@ pub fn get_local_rask_dir() -> Result<PathBuf, Error> {
     Ok(local_dir)
 }
 
+/// Profile chosen with `--profile` for this invocation
+static ACTIVE_PROFILE: OnceLock<String> = OnceLock::new();
+
+/// Select the profile applied by [`RaskConfig::load`], failing if the user config does not define it.
+/// Without a call, `RASK_PROFILE` is used when set.
+pub fn set_active_profile(name: &str) -> Result<(), Error> {
+    let config = RaskConfig::load_files()?;
+    if !config.profiles.contains_key(name) {
+        let mut known: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+        known.sort();
+        let known = if known.is_empty() { "none, add [profiles.<name>] with 'rask config edit'".to_string() } else { known.join(", ") };
+        return Err(Error::new(ErrorKind::NotFound, format!("Unknown config profile '{}' (available: {})", name, known)));
+    }
+    let _ = ACTIVE_PROFILE.set(name.to_string());
+    Ok(())
+}
+
+/// Name of the profile in effect, if any
+pub fn active_profile() -> Option<String> {
+    ACTIVE_PROFILE.get().cloned()
+        .or_else(|| std::env::var("RASK_PROFILE").ok().filter(|name| !name.is_empty()))
+}
+
 impl RaskConfig {
     /// Load configuration with the following priority:
-    /// 1. Local project config (.rask/config.toml)
-    /// 2. User config (~/.config/rask/config.toml)
-    /// 3. Default configuration
+    /// 1. `RASK_<SECTION>__<KEY>` environment variables
+    /// 2. The active profile (`--profile` or `RASK_PROFILE`)
+    /// 3. Local project config (.rask/config.toml)
+    /// 4. User config (~/.config/rask/config.toml)
+    /// 5. Default configuration
     pub fn load() -> Result<Self, Error> {
+        let mut config = Self::load_files()?;
+        if let Some(profile) = active_profile() {
+            config = config.with_profile(&profile)?;
+        }
+        config.apply_env_overrides(std::env::vars())?;
+        Ok(config)
+    }
+    
+    /// Load only the user and project config files, without profile or environment overrides.
+    /// Commands that write the configuration back start from this.
+    pub fn load_files() -> Result<Self, Error> {
         let mut config = RaskConfig::default();
         
         // Try to load user config first (as base)
@@ -387,6 +923,29 @@ impl RaskConfig {
         Ok(config)
     }
     
+    /// Overlay a named profile; its tables are merged key by key into the current settings
+    fn with_profile(self, name: &str) -> Result<Self, Error> {
+        let Some(overlay) = self.profiles.get(name).cloned() else {
+            return Err(Error::new(ErrorKind::NotFound, format!("Unknown config profile '{}'", name)));
+        };
+        let mut merged = toml::Table::try_from(&self)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("Failed to serialize config: {}", e)))?;
+        merge_tables(&mut merged, overlay);
+        merged.try_into()
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("Invalid settings in profile '{}': {}", name, e)))
+    }
+    
+    /// Apply `RASK_<SECTION>__<KEY>=value` variables, e.g. `RASK_AI__PROVIDER=mock` sets `ai.provider`
+    fn apply_env_overrides(&mut self, vars: impl Iterator<Item = (String, String)>) -> Result<(), Error> {
+        for (name, value) in vars {
+            let Some(key) = name.strip_prefix("RASK_").filter(|key| key.contains("__")) else { continue };
+            let key = key.to_lowercase().replacen("__", ".", 1);
+            self.set(&key, &value)
+                .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("{}: {}", name, e)))?;
+        }
+        Ok(())
+    }
+    
     /// Load user configuration from ~/.config/rask/config.toml
     pub fn load_user_config() -> Result<Self, Error> {
         let config_dir = get_rask_config_dir()?;
@@ -419,9 +978,13 @@ impl RaskConfig {
     }
     
     /// Merge two configurations, with the second taking precedence
-    fn merge_configs(_base: RaskConfig, overlay: RaskConfig) -> RaskConfig {
+    fn merge_configs(base: RaskConfig, mut overlay: RaskConfig) -> RaskConfig {
         // For now, we'll do a simple overlay where overlay completely replaces sections
         // In the future, we could implement more sophisticated merging
+        // Profiles are personal setups, so the user's stay available inside any project
+        for (name, profile) in base.profiles {
+            overlay.profiles.entry(name).or_insert(profile);
+        }
         overlay
     }
     
@@ -431,7 +994,7 @@ impl RaskConfig {
         let config_path = config_dir.join("config.toml");
         
         let config_str = toml::to_string_pretty(self)
-            .map_err(|e| Error::new(ErrorKind::Other, format!("Failed to serialize config: {}", e)))?;
+            .map_err(|e| Error::other(format!("Failed to serialize config: {}", e)))?;
         
         fs::write(&config_path, config_str)?;
         Ok(())
@@ -443,7 +1006,7 @@ impl RaskConfig {
         let config_path = local_dir.join("config.toml");
         
         let config_str = toml::to_string_pretty(self)
-            .map_err(|e| Error::new(ErrorKind::Other, format!("Failed to serialize config: {}", e)))?;
+            .map_err(|e| Error::other(format!("Failed to serialize config: {}", e)))?;
         
         fs::write(&config_path, config_str)?;
         Ok(())
@@ -466,6 +1029,12 @@ impl RaskConfig {
     /// Get a configuration value by key (dot notation support)
     /// Example: "ui.color_scheme", "behavior.default_priority"
     pub fn get(&self, key: &str) -> Option<String> {
+        if let Some(tag) = key.strip_prefix("theme.tag_colors.") {
+            return self.theme.tag_colors.get(tag).cloned();
+        }
+        if let Some(phase) = key.strip_prefix("theme.phase_colors.") {
+            return self.theme.phase_colors.get(phase).cloned();
+        }
         let parts: Vec<&str> = key.split('.').collect();
         if parts.len() != 2 {
             return None;
@@ -476,13 +1045,23 @@ impl RaskConfig {
             ("ui", "show_completed") => Some(self.ui.show_completed.to_string()),
             ("ui", "default_sort") => Some(self.ui.default_sort.clone()),
             ("ui", "compact_view") => Some(self.ui.compact_view.to_string()),
+            ("ui", "accessible") => Some(self.ui.accessible.to_string()),
+            ("ui", "max_width") => Some(self.ui.max_width.to_string()),
+            ("ui", "stale_days") => Some(self.ui.stale_days.to_string()),
             ("behavior", "default_project") => self.behavior.default_project.clone(),
             ("behavior", "default_priority") => Some(self.behavior.default_priority.clone()),
             ("behavior", "warn_on_circular") => Some(self.behavior.warn_on_circular.to_string()),
             ("behavior", "confirm_destructive") => Some(self.behavior.confirm_destructive.to_string()),
+            ("behavior", "max_session_hours") => Some(self.behavior.max_session_hours.to_string()),
+            ("behavior", "daily_capacity_hours") => Some(self.behavior.daily_capacity_hours.to_string()),
+            ("behavior", "backup_count") => Some(self.behavior.backup_count.to_string()),
+            ("behavior", "wip_limit_mode") => Some(self.behavior.wip_limit_mode.clone()),
+            ("behavior", "stable_ids") => Some(self.behavior.stable_ids.to_string()),
             ("export", "default_format") => Some(self.export.default_format.clone()),
             ("export", "default_path") => self.export.default_path.clone(),
             ("advanced", "editor") => self.advanced.editor.clone(),
+            ("advanced", "notes_dir") => self.advanced.notes_dir.clone(),
+            ("advanced", "template_registry") => self.advanced.template_registry.clone(),
             ("advanced", "debug") => Some(self.advanced.debug.to_string()),
             ("theme", "name") => Some(self.theme.name.clone()),
             ("ai", "enabled") => Some(self.ai.enabled.to_string()),
@@ -492,14 +1071,44 @@ impl RaskConfig {
             ("ai", "temperature") => Some(self.ai.temperature.to_string()),
             ("ai", "auto_suggestions") => Some(self.ai.auto_suggestions.to_string()),
             ("ai", "context_window") => Some(self.ai.context_window.to_string()),
+            ("ai", "cache_ttl_minutes") => Some(self.ai.cache_ttl_minutes.to_string()),
+            ("ai", "requests_per_minute") => Some(self.ai.requests_per_minute.to_string()),
             ("gemini", "endpoint") => Some(self.ai.gemini.endpoint.clone()),
             ("gemini", "timeout") => Some(self.ai.gemini.timeout.to_string()),
+            ("user", "name") => self.user.name.clone(),
+            ("user", "email") => self.user.email.clone(),
+            ("jira", "base_url") => self.jira.base_url.clone(),
+            ("jira", "project_key") => self.jira.project_key.clone(),
+            ("jira", "email") => self.jira.email.clone(),
+            ("jira", "api_token") => self.jira.api_token.clone(),
+            ("jira", "done_transition") => self.jira.done_transition.clone(),
+            ("github", "token") => self.github.token.clone(),
+            ("github", "repo") => self.github.repo.clone(),
+            ("github", "api_url") => self.github.api_url.clone(),
+            ("notifications", "desktop") => Some(self.notifications.desktop.to_string()),
+            ("notifications", "session_reminder_minutes") => Some(self.notifications.session_reminder_minutes.to_string()),
+            ("notifications", "due_today") => Some(self.notifications.due_today.to_string()),
+            ("projects", name) => self.projects.get(name).cloned(),
             _ => None,
         }
     }
     
     /// Set a configuration value by key
     pub fn set(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        // Tag and phase styles are keyed by name; an empty value removes the entry
+        for (prefix, styles) in [("theme.tag_colors.", &mut self.theme.tag_colors), ("theme.phase_colors.", &mut self.theme.phase_colors)] {
+            let Some(name) = key.strip_prefix(prefix) else { continue };
+            if name.is_empty() {
+                return Err(Error::new(ErrorKind::InvalidInput, format!("Name the tag or phase, e.g. '{}backend'", prefix)));
+            }
+            if value.trim().is_empty() {
+                styles.remove(name);
+            } else {
+                crate::ui::theme::LabelStyle::parse(value).map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+                styles.insert(name.to_string(), value.trim().to_string());
+            }
+            return Ok(());
+        }
         let parts: Vec<&str> = key.split('.').collect();
         if parts.len() != 2 {
             return Err(Error::new(ErrorKind::InvalidInput, "Key must be in format 'section.key'"));
@@ -508,17 +1117,54 @@ impl RaskConfig {
         match (parts[0], parts[1]) {
             ("ui", "color_scheme") => self.ui.color_scheme = Some(value.to_string()),
             ("ui", "show_completed") => self.ui.show_completed = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
-            ("ui", "default_sort") => self.ui.default_sort = value.to_string(),
+            ("ui", "default_sort") => {
+                if crate::sorting::SortKey::parse(value).is_none() {
+                    return Err(Error::new(ErrorKind::InvalidInput, "Invalid sort order. Use: id, priority, due, created, estimate, phase"));
+                }
+                self.ui.default_sort = value.to_lowercase();
+            },
             ("ui", "compact_view") => self.ui.compact_view = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
+            ("ui", "accessible") => self.ui.accessible = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
+            ("ui", "max_width") => self.ui.max_width = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number value"))?,
+            ("ui", "stale_days") => self.ui.stale_days = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number of days"))?,
             ("behavior", "default_project") => self.behavior.default_project = if value.is_empty() { None } else { Some(value.to_string()) },
             ("behavior", "default_priority") => self.behavior.default_priority = value.to_string(),
             ("behavior", "warn_on_circular") => self.behavior.warn_on_circular = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
             ("behavior", "confirm_destructive") => self.behavior.confirm_destructive = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
+            ("behavior", "max_session_hours") => {
+                let hours: f64 = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number of hours"))?;
+                if !hours.is_finite() || hours < 0.0 {
+                    return Err(Error::new(ErrorKind::InvalidInput, "Max session hours must be 0 (disabled) or more"));
+                }
+                self.behavior.max_session_hours = hours;
+            },
+            ("behavior", "daily_capacity_hours") => {
+                let hours: f64 = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number of hours"))?;
+                if !hours.is_finite() || hours <= 0.0 || hours > 24.0 {
+                    return Err(Error::new(ErrorKind::InvalidInput, "Daily capacity must be more than 0 and at most 24 hours"));
+                }
+                self.behavior.daily_capacity_hours = hours;
+            },
+            ("behavior", "backup_count") => self.behavior.backup_count = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number of backups"))?,
+            ("behavior", "wip_limit_mode") => {
+                if !matches!(value, "warn" | "refuse") {
+                    return Err(Error::new(ErrorKind::InvalidInput, "WIP limit mode must be 'warn' or 'refuse'"));
+                }
+                self.behavior.wip_limit_mode = value.to_string();
+            },
+            ("behavior", "stable_ids") => self.behavior.stable_ids = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
             ("export", "default_format") => self.export.default_format = value.to_string(),
             ("export", "default_path") => self.export.default_path = if value.is_empty() { None } else { Some(value.to_string()) },
             ("advanced", "editor") => self.advanced.editor = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("advanced", "notes_dir") => self.advanced.notes_dir = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("advanced", "template_registry") => self.advanced.template_registry = if value.is_empty() { None } else { Some(value.to_string()) },
             ("advanced", "debug") => self.advanced.debug = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
-            ("theme", "name") => self.theme.name = value.to_string(),
+            ("theme", "name") => {
+                if !crate::ui::theme::BUILTIN_THEMES.contains(&value) {
+                    return Err(Error::new(ErrorKind::InvalidInput, format!("Unknown theme '{}'. Built-in themes: {}", value, crate::ui::theme::BUILTIN_THEMES.join(", "))));
+                }
+                self.theme.name = value.to_string();
+            },
             ("ai", "enabled") => self.ai.enabled = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
             ("ai", "provider") => self.ai.provider = value.to_string(),
             ("ai", "default_model") => self.ai.default_model = value.to_string(),
@@ -526,8 +1172,30 @@ impl RaskConfig {
             ("ai", "temperature") => self.ai.temperature = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid float value"))?,
             ("ai", "auto_suggestions") => self.ai.auto_suggestions = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
             ("ai", "context_window") => self.ai.context_window = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number value"))?,
+            ("ai", "cache_ttl_minutes") => self.ai.cache_ttl_minutes = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number value"))?,
+            ("ai", "requests_per_minute") => self.ai.requests_per_minute = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number value"))?,
             ("gemini", "endpoint") => self.ai.gemini.endpoint = value.to_string(),
             ("gemini", "timeout") => self.ai.gemini.timeout = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number value"))?,
+            ("user", "name") => self.user.name = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("user", "email") => self.user.email = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("jira", "base_url") => self.jira.base_url = if value.is_empty() { None } else { Some(value.trim_end_matches('/').to_string()) },
+            ("jira", "project_key") => self.jira.project_key = if value.is_empty() { None } else { Some(value.to_uppercase()) },
+            ("jira", "email") => self.jira.email = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("jira", "api_token") => self.jira.api_token = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("jira", "done_transition") => self.jira.done_transition = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("github", "token") => self.github.token = if value.is_empty() { None } else { Some(value.to_string()) },
+            ("github", "repo") => self.github.repo = if value.is_empty() { None } else { Some(value.trim_matches('/').to_string()) },
+            ("github", "api_url") => self.github.api_url = if value.is_empty() { None } else { Some(value.trim_end_matches('/').to_string()) },
+            ("notifications", "desktop") => self.notifications.desktop = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
+            ("notifications", "session_reminder_minutes") => self.notifications.session_reminder_minutes = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid number value"))?,
+            ("notifications", "due_today") => self.notifications.due_today = value.parse().map_err(|_| Error::new(ErrorKind::InvalidInput, "Invalid boolean value"))?,
+            ("projects", name) => {
+                if value.is_empty() {
+                    self.projects.remove(name);
+                } else {
+                    self.projects.insert(name.to_string(), value.to_string());
+                }
+            },
             _ => return Err(Error::new(ErrorKind::InvalidInput, "Unknown configuration key")),
         }
         
@@ -550,9 +1218,10 @@ impl AiConfig {
         }
     }
 
-    /// Check if AI features are properly configured and ready to use
+    /// Check if AI features are properly configured and ready to use.
+    /// The offline `mock` provider needs no API key.
     pub fn is_ready(&self) -> bool {
-        self.enabled && self.get_api_key().is_some()
+        self.enabled && (self.provider == "mock" || self.get_api_key().is_some())
     }
 }
 
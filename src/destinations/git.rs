@@ -0,0 +1,104 @@
+//! Git destination
+//!
+//! Copies files into a local clone of a docs repository, commits them and
+//! optionally pushes. Nothing is committed when the files did not change.
+
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::{remote_name, Destination};
+
+/// Commits files into a docs repository
+pub struct GitDestination {
+    repo: PathBuf,
+    path: String,
+    push: bool,
+    message: Option<String>,
+}
+
+impl GitDestination {
+    pub fn new(repo: &str, path: &str, push: bool) -> Self {
+        let repo = match repo.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().map(|home| home.join(rest)).unwrap_or_else(|| PathBuf::from(repo)),
+            None => PathBuf::from(repo),
+        };
+        Self {
+            repo,
+            path: path.trim_matches('/').to_string(),
+            push,
+            message: None,
+        }
+    }
+
+    /// Use this commit message instead of the export one
+    pub fn with_message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    fn git(&self, args: &[&str]) -> Result<std::process::Output> {
+        tracing::debug!(repo = %self.repo.display(), "git {}", args.join(" "));
+        Command::new("git")
+            .arg("-C")
+            .arg(&self.repo)
+            .args(args)
+            .output()
+            .context("Failed to run git")
+    }
+
+    fn run(&self, args: &[&str]) -> Result<()> {
+        let output = self.git(args)?;
+        if !output.status.success() {
+            anyhow::bail!("git {} failed: {}", args.first().unwrap_or(&""), String::from_utf8_lossy(&output.stderr).trim());
+        }
+        Ok(())
+    }
+}
+
+impl Destination for GitDestination {
+    fn describe(&self) -> String {
+        format!("git repository {}", self.repo.display())
+    }
+
+    fn publish(&self, files: &[PathBuf]) -> Result<Vec<String>> {
+        if !self.repo.join(".git").exists() {
+            anyhow::bail!("'{}' is not a git repository", self.repo.display());
+        }
+
+        let target_dir = self.repo.join(&self.path);
+        std::fs::create_dir_all(&target_dir)
+            .with_context(|| format!("Failed to create {}", target_dir.display()))?;
+
+        let mut relative = Vec::new();
+        for file in files {
+            let name = remote_name(file)?;
+            std::fs::copy(file, target_dir.join(&name))
+                .with_context(|| format!("Failed to copy {} into the repository", file.display()))?;
+            relative.push(Path::new(&self.path).join(name).to_string_lossy().to_string());
+        }
+
+        let mut add_args = vec!["add", "--"];
+        add_args.extend(relative.iter().map(|p| p.as_str()));
+        self.run(&add_args)?;
+
+        // `diff --cached --quiet` exits 0 when nothing is staged
+        let mut diff_args = vec!["diff", "--cached", "--quiet", "--"];
+        diff_args.extend(relative.iter().map(|p| p.as_str()));
+        if self.git(&diff_args)?.status.success() {
+            return Ok(relative.into_iter().map(|p| format!("{} (unchanged)", p)).collect());
+        }
+
+        // Commit only the exported files, leaving anything else staged alone
+        let message = self.message.clone()
+            .unwrap_or_else(|| format!("Update roadmap export ({})", chrono::Local::now().format("%Y-%m-%d %H:%M")));
+        let mut commit_args = vec!["commit", "-m", &message, "--"];
+        commit_args.extend(relative.iter().map(|p| p.as_str()));
+        self.run(&commit_args)?;
+        if self.push {
+            self.run(&["push"])?;
+        }
+
+        Ok(relative)
+    }
+}
@@ -0,0 +1,79 @@
+//! Upload targets for exported files
+//!
+//! Every target implements the [`Destination`] trait. Destinations are
+//! configured by name under `[export.destinations.<name>]` and used with
+//! `rask export --to <name>`, so a cron job or CI schedule can publish
+//! nightly reports without any extra scripting.
+
+pub mod git;
+pub mod s3;
+pub mod webdav;
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+use crate::config::ExportDestination;
+
+/// Trait implemented by every export destination
+pub trait Destination {
+    /// Human-readable description of the target
+    fn describe(&self) -> String;
+
+    /// Upload the files, returning where each one ended up
+    fn publish(&self, files: &[PathBuf]) -> Result<Vec<String>>;
+}
+
+/// Build the destination described by a config entry
+pub fn from_config(config: &ExportDestination) -> Result<Box<dyn Destination>> {
+    Ok(match config {
+        ExportDestination::S3 { endpoint, bucket, region, prefix, access_key, secret_key } => Box::new(s3::S3Destination::new(
+            endpoint,
+            bucket,
+            region,
+            prefix,
+            resolve_secret(access_key.as_deref(), "AWS_ACCESS_KEY_ID")?,
+            resolve_secret(secret_key.as_deref(), "AWS_SECRET_ACCESS_KEY")?,
+        )?),
+        ExportDestination::Webdav { url, username, password } => Box::new(webdav::WebdavDestination::new(
+            url,
+            username.as_deref().map(expand_env).transpose()?,
+            password.as_deref().map(expand_env).transpose()?,
+        )),
+        ExportDestination::Git { repo, path, push } => Box::new(git::GitDestination::new(repo, path, *push)),
+    })
+}
+
+/// Resolve a credential, falling back to `default_env` when it is not configured
+fn resolve_secret(value: Option<&str>, default_env: &str) -> Result<String> {
+    match value {
+        Some(v) => expand_env(v),
+        None => std::env::var(default_env).map_err(|_| anyhow::anyhow!("Environment variable {} is not set", default_env)),
+    }
+}
+
+/// Read `$VAR` values from the environment, returning other values unchanged
+pub fn expand_env(value: &str) -> Result<String> {
+    match value.strip_prefix('$') {
+        Some(var) => std::env::var(var).map_err(|_| anyhow::anyhow!("Environment variable {} is not set", var)),
+        None => Ok(value.to_string()),
+    }
+}
+
+/// File name used as the remote object name
+fn remote_name(file: &Path) -> Result<String> {
+    file.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not a file", file.display()))
+}
+
+/// Content type for an exported file, based on its extension
+fn content_type(file: &Path) -> &'static str {
+    match file.extension().and_then(|e| e.to_str()).unwrap_or_default() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "json" => "application/json",
+        "csv" => "text/csv; charset=utf-8",
+        "md" => "text/markdown; charset=utf-8",
+        "ics" => "text/calendar; charset=utf-8",
+        _ => "text/plain; charset=utf-8",
+    }
+}
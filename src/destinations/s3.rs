@@ -0,0 +1,141 @@
+//! S3-compatible object storage destination
+//!
+//! Uploads each file with a `PUT` signed with AWS Signature Version 4. Buckets
+//! are addressed path-style (`<endpoint>/<bucket>/<key>`), which AWS, MinIO,
+//! Cloudflare R2 and most other S3-compatible services accept.
+
+use anyhow::{Context, Result};
+use hmac_sha256::{Hash, HMAC};
+use std::path::PathBuf;
+
+use super::{content_type, remote_name, Destination};
+
+/// Uploads files into an S3 bucket
+pub struct S3Destination {
+    endpoint: reqwest::Url,
+    bucket: String,
+    region: String,
+    prefix: String,
+    access_key: String,
+    secret_key: String,
+}
+
+impl S3Destination {
+    pub fn new(endpoint: &str, bucket: &str, region: &str, prefix: &str, access_key: String, secret_key: String) -> Result<Self> {
+        let endpoint = reqwest::Url::parse(endpoint)
+            .with_context(|| format!("Invalid S3 endpoint '{}'", endpoint))?;
+        if endpoint.host_str().is_none() {
+            anyhow::bail!("S3 endpoint '{}' has no host", endpoint);
+        }
+        if bucket.is_empty() {
+            anyhow::bail!("S3 bucket name is empty");
+        }
+
+        Ok(Self {
+            endpoint,
+            bucket: bucket.to_string(),
+            region: region.to_string(),
+            prefix: prefix.trim_matches('/').to_string(),
+            access_key,
+            secret_key,
+        })
+    }
+
+    fn object_key(&self, name: &str) -> String {
+        if self.prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}/{}", self.prefix, name)
+        }
+    }
+
+    /// Build the `Authorization` header for a `PUT` to `path`
+    fn authorization(&self, host: &str, path: &str, payload_hash: &str, amz_date: &str) -> String {
+        let date = &amz_date[..8];
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "PUT\n{}\n\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            path, host, payload_hash, amz_date, signed_headers, payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, self.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date, scope, hex(&Hash::hash(canonical_request.as_bytes()))
+        );
+
+        let date_key = HMAC::mac(date, format!("AWS4{}", self.secret_key));
+        let region_key = HMAC::mac(&self.region, date_key);
+        let service_key = HMAC::mac("s3", region_key);
+        let signing_key = HMAC::mac("aws4_request", service_key);
+        let signature = hex(&HMAC::mac(string_to_sign, signing_key));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.access_key, scope, signed_headers, signature
+        )
+    }
+
+    async fn upload(&self, files: &[PathBuf]) -> Result<Vec<String>> {
+        let client = reqwest::Client::new();
+        let host = match self.endpoint.port() {
+            Some(port) => format!("{}:{}", self.endpoint.host_str().unwrap_or_default(), port),
+            None => self.endpoint.host_str().unwrap_or_default().to_string(),
+        };
+        let base_path = self.endpoint.path().trim_end_matches('/');
+        let mut locations = Vec::new();
+
+        for file in files {
+            let body = std::fs::read(file).with_context(|| format!("Failed to read {}", file.display()))?;
+            let key = self.object_key(&remote_name(file)?);
+            let path = format!("{}/{}/{}", base_path, uri_encode(&self.bucket), uri_encode(&key));
+            let payload_hash = hex(&Hash::hash(&body));
+            let amz_date = chrono::Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+            let url = format!("{}://{}{}", self.endpoint.scheme(), host, path);
+
+            let response = client.put(&url)
+                .header("x-amz-date", &amz_date)
+                .header("x-amz-content-sha256", &payload_hash)
+                .header(reqwest::header::AUTHORIZATION, self.authorization(&host, &path, &payload_hash, &amz_date))
+                .header(reqwest::header::CONTENT_TYPE, content_type(file))
+                .body(body)
+                .send()
+                .await
+                .with_context(|| format!("Failed to upload to {}", url))?;
+
+            let status = response.status();
+            tracing::debug!(%url, %status, "S3 upload");
+            if !status.is_success() {
+                let detail = response.text().await.unwrap_or_default();
+                anyhow::bail!("S3 upload of {} failed with {}: {}", key, status, detail.trim());
+            }
+            locations.push(format!("s3://{}/{}", self.bucket, key));
+        }
+
+        Ok(locations)
+    }
+}
+
+impl Destination for S3Destination {
+    fn describe(&self) -> String {
+        format!("s3://{}/{}", self.bucket, self.prefix)
+    }
+
+    fn publish(&self, files: &[PathBuf]) -> Result<Vec<String>> {
+        let rt = tokio::runtime::Runtime::new().context("Failed to create async runtime")?;
+        rt.block_on(self.upload(files))
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Percent-encode a key for the canonical URI, keeping `/` separators
+fn uri_encode(value: &str) -> String {
+    value.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,65 @@
+//! WebDAV destination
+//!
+//! Uploads each file with an HTTP `PUT` into an existing collection, which
+//! works with Nextcloud, SharePoint and plain Apache/nginx WebDAV shares.
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+
+use super::{content_type, remote_name, Destination};
+
+/// Uploads files into a WebDAV collection
+pub struct WebdavDestination {
+    url: String,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl WebdavDestination {
+    pub fn new(url: &str, username: Option<String>, password: Option<String>) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            username,
+            password,
+        }
+    }
+
+    async fn upload(&self, files: &[PathBuf]) -> Result<Vec<String>> {
+        let client = reqwest::Client::new();
+        let mut locations = Vec::new();
+
+        for file in files {
+            let body = std::fs::read(file).with_context(|| format!("Failed to read {}", file.display()))?;
+            let url = format!("{}/{}", self.url, remote_name(file)?);
+
+            let mut request = client.put(&url)
+                .header(reqwest::header::CONTENT_TYPE, content_type(file))
+                .body(body);
+            if let Some(username) = &self.username {
+                request = request.basic_auth(username, self.password.as_ref());
+            }
+
+            let response = request.send().await.with_context(|| format!("Failed to upload to {}", url))?;
+            let status = response.status();
+            tracing::debug!(%url, %status, "WebDAV upload");
+            if !status.is_success() {
+                let hint = if status == reqwest::StatusCode::CONFLICT { " (does the collection exist?)" } else { "" };
+                anyhow::bail!("WebDAV upload to {} failed with {}{}", url, status, hint);
+            }
+            locations.push(url);
+        }
+
+        Ok(locations)
+    }
+}
+
+impl Destination for WebdavDestination {
+    fn describe(&self) -> String {
+        format!("WebDAV {}", self.url)
+    }
+
+    fn publish(&self, files: &[PathBuf]) -> Result<Vec<String>> {
+        let rt = tokio::runtime::Runtime::new().context("Failed to create async runtime")?;
+        rt.block_on(self.upload(files))
+    }
+}
@@ -0,0 +1,90 @@
+//! Command lifecycle hooks
+//!
+//! Executable scripts in `.rask/hooks/`, named after the event they handle
+//! (`pre-add`, `post-complete`, ...), run when a command adds, completes,
+//! edits, resets or removes a task. The task is passed as JSON on stdin and
+//! the hook name and task ID as `RASK_HOOK` / `RASK_TASK_ID`. A `pre-` hook
+//! that exits non-zero cancels the change; a failing `post-` hook only
+//! prints a warning because the change has already been saved.
+
+use crate::model::Task;
+use crate::{state, ui};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+/// Every hook name Rask invokes
+pub const HOOK_NAMES: &[&str] = &[
+    "pre-add",
+    "post-add",
+    "pre-complete",
+    "post-complete",
+    "pre-edit",
+    "post-edit",
+    "post-reset",
+    "pre-remove",
+    "post-remove",
+];
+
+/// Run a `pre-` hook, returning an error when it rejects the change
+pub fn run_pre_hook(name: &str, task: &Task) -> Result<(), String> {
+    match run_hook(name, task) {
+        Some(Ok(true)) | None => Ok(()),
+        Some(Ok(false)) => Err(format!("The {} hook rejected the change to task #{}", name, task.id)),
+        Some(Err(e)) => Err(format!("Could not run the {} hook: {}", name, e)),
+    }
+}
+
+/// Run a `post-` hook, warning when it fails
+pub fn run_post_hook(name: &str, task: &Task) {
+    match run_hook(name, task) {
+        Some(Ok(false)) => ui::display_warning(&format!("The {} hook failed for task #{}", name, task.id)),
+        Some(Err(e)) => ui::display_warning(&format!("Could not run the {} hook: {}", name, e)),
+        _ => {}
+    }
+}
+
+fn hook_path(name: &str) -> Option<PathBuf> {
+    let path = state::get_hooks_dir().ok()?.join(name);
+    path.is_file().then_some(path)
+}
+
+/// Run the hook if it exists, returning whether it exited successfully
+fn run_hook(name: &str, task: &Task) -> Option<std::io::Result<bool>> {
+    debug_assert!(HOOK_NAMES.contains(&name), "unknown hook {}", name);
+    let path = hook_path(name)?;
+    if !is_executable(&path) {
+        ui::display_warning(&format!("Ignoring {}: the file is not executable (chmod +x to enable it)", path.display()));
+        return None;
+    }
+
+    tracing::info!(hook = name, task = task.id, "running hook");
+    Some((|| {
+        let payload = serde_json::to_vec(task).map_err(std::io::Error::other)?;
+        let mut child = Command::new(&path)
+            .env("RASK_HOOK", name)
+            .env("RASK_TASK_ID", task.id.to_string())
+            .stdin(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            // A hook that ignores its input may exit before reading it all
+            if let Err(e) = stdin.write_all(&payload) {
+                if e.kind() != std::io::ErrorKind::BrokenPipe {
+                    return Err(e);
+                }
+            }
+        }
+        Ok(child.wait()?.success())
+    })())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &std::path::Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().map(|m| m.permissions().mode() & 0o111 != 0).unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &std::path::Path) -> bool {
+    true
+}
@@ -0,0 +1,178 @@
+//! CSV importer
+//!
+//! Reads a CSV file with a header row. Columns are recognised by common
+//! header names (case-insensitive), e.g. `Description`/`Title`,
+//! `Tags`/`Labels`, `Priority`, `Phase`/`Milestone`, `Notes`, `Status`
+//! and `Estimated Hours`, or picked explicitly with `--map field=Column`.
+//! Unknown columns are ignored.
+//!
+//! A `Dependencies` column lists the rows a task depends on. Rows are
+//! referred to by their `ID` column when the file has one (as in a rask CSV
+//! export), otherwise by row number with the header as row 1. `#N` refers to
+//! task N of the current project.
+
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+
+use super::{is_completed_status, parse_priority, split_labels, ImportedItem, Importer};
+
+/// Task fields a CSV column can be mapped to, with the headers recognised for each
+const FIELDS: &[(&str, &[&str])] = &[
+    ("description", &["description", "title", "name", "summary", "task"]),
+    ("tags", &["tags", "labels", "label"]),
+    ("priority", &["priority"]),
+    ("phase", &["phase", "milestone"]),
+    ("notes", &["notes", "body", "details"]),
+    ("status", &["status", "state", "done", "completed"]),
+    ("estimate", &["estimated hours", "estimated_hours", "estimate"]),
+    ("uuid", &["uuid"]),
+    ("id", &["id", "key", "ref"]),
+    ("dependencies", &["dependencies", "depends on", "depends_on", "blocked by"]),
+];
+
+/// Imports items from a CSV file
+pub struct CsvImporter {
+    path: PathBuf,
+    /// Columns chosen with `--map`, as (field, header) pairs
+    mapping: Vec<(String, String)>,
+}
+
+impl CsvImporter {
+    /// Create an importer; `map` is a `field=Column,...` list overriding the recognised headers
+    pub fn new(path: PathBuf, map: Option<&str>) -> Result<Self> {
+        let mut mapping = Vec::new();
+        for pair in map.into_iter().flat_map(|map| map.split(',')).filter(|pair| !pair.trim().is_empty()) {
+            let Some((field, column)) = pair.split_once('=') else {
+                bail!("Invalid mapping '{}'. Use field=Column, e.g. description=Title", pair.trim());
+            };
+            let field = field.trim().to_lowercase();
+            if !FIELDS.iter().any(|(name, _)| *name == field) {
+                let names: Vec<&str> = FIELDS.iter().map(|(name, _)| *name).collect();
+                bail!("Unknown field '{}' in --map. Fields: {}", field, names.join(", "));
+            }
+            mapping.push((field, column.trim().to_string()));
+        }
+        Ok(Self { path, mapping })
+    }
+}
+
+impl Importer for CsvImporter {
+    fn source_name(&self) -> String {
+        format!("CSV file {}", self.path.display())
+    }
+
+    fn fetch_items(&self) -> Result<Vec<ImportedItem>> {
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        let mut rows = parse_csv_numbered(&content).into_iter();
+
+        let (_, header) = rows.next().context("CSV file is empty")?;
+        let find = |name: &str| header.iter().position(|h| h.trim().eq_ignore_ascii_case(name.trim()));
+        let mut columns = Vec::new();
+        for (field, aliases) in FIELDS {
+            let column = match self.mapping.iter().find(|(mapped, _)| mapped == field) {
+                Some((_, name)) => Some(find(name).with_context(|| format!("Column '{}' not found in the CSV header", name))?),
+                None => aliases.iter().find_map(|alias| find(alias)),
+            };
+            columns.push(column);
+        }
+        let column = |field: &str| FIELDS.iter().position(|(name, _)| *name == field).and_then(|i| columns[i]);
+
+        let description_col = column("description")
+            .context("CSV header needs a Description or Title column (or use --map description=<column>)")?;
+        let tags_col = column("tags");
+        let priority_col = column("priority");
+        let phase_col = column("phase");
+        let notes_col = column("notes");
+        let status_col = column("status");
+        let estimate_col = column("estimate");
+        let uuid_col = column("uuid");
+        let id_col = column("id");
+        let dependencies_col = column("dependencies");
+
+        let items = rows
+            .map(|(number, row)| {
+                let cell = |col: Option<usize>| {
+                    col.and_then(|c| row.get(c))
+                        .map(|v| v.trim().to_string())
+                        .filter(|v| !v.is_empty())
+                };
+
+                // Rows without a description are kept so the import reports them as skipped
+                ImportedItem {
+                    description: cell(Some(description_col)).unwrap_or_default(),
+                    labels: cell(tags_col).map(|v| split_labels(&v)).unwrap_or_default(),
+                    priority: cell(priority_col).and_then(|v| parse_priority(&v)),
+                    phase: cell(phase_col),
+                    notes: cell(notes_col),
+                    completed: cell(status_col).map(|v| is_completed_status(&v)).unwrap_or(false),
+                    estimated_hours: cell(estimate_col).and_then(|v| v.trim_end_matches('h').parse().ok()),
+                    source_ref: Some(format!("{} row {}", self.path.display(), number)),
+                    uuid: cell(uuid_col),
+                    key: Some(match id_col {
+                        Some(col) => cell(Some(col)).unwrap_or_default(),
+                        None => number.to_string(),
+                    }).filter(|key| !key.is_empty()),
+                    depends_on: cell(dependencies_col)
+                        .map(|v| split_labels(&v).iter().flat_map(|r| r.split_whitespace()).map(str::to_string).collect())
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        Ok(items)
+    }
+}
+
+/// Parse CSV text into rows of fields, honouring quoted fields and escaped quotes
+pub fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    parse_csv_numbered(content).into_iter().map(|(_, row)| row).collect()
+}
+
+/// Like [`parse_csv`], with each row's number in the file (blank rows count
+/// too, as they do in a spreadsheet)
+pub fn parse_csv_numbered(content: &str) -> Vec<(usize, Vec<String>)> {
+    let mut rows = Vec::new();
+    let mut number = 0;
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                row.push(std::mem::take(&mut field));
+                number += 1;
+                if row.iter().any(|f| !f.is_empty()) {
+                    rows.push((number, std::mem::take(&mut row)));
+                } else {
+                    row.clear();
+                }
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push((number + 1, row));
+    }
+
+    rows
+}
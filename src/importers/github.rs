@@ -0,0 +1,145 @@
+//! GitHub Issues importer
+//!
+//! Fetches issues through the GitHub REST API. Labels become tags (labels that
+//! look like priorities set the task priority instead), milestones become
+//! phases, and closed issues are imported as completed tasks. Pull requests
+//! returned by the issues endpoint are skipped.
+
+use anyhow::{Context, Result};
+use reqwest::header;
+use serde::Deserialize;
+
+use super::{parse_priority, ImportedItem, Importer};
+
+const GITHUB_API: &str = "https://api.github.com";
+const PAGE_SIZE: usize = 100;
+
+/// Imports issues from a GitHub repository
+pub struct GithubImporter {
+    repo: String,
+    state: String,
+    token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubIssue {
+    number: u64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    html_url: String,
+    #[serde(default)]
+    labels: Vec<GithubLabel>,
+    milestone: Option<GithubMilestone>,
+    pull_request: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubMilestone {
+    title: String,
+}
+
+impl GithubImporter {
+    pub fn new(repo: &str, state: &str, token: Option<String>) -> Result<Self> {
+        if repo.split('/').filter(|part| !part.is_empty()).count() != 2 {
+            anyhow::bail!("Repository must be in owner/name form (got '{}')", repo);
+        }
+        if !matches!(state, "open" | "closed" | "all") {
+            anyhow::bail!("Issue state must be one of: open, closed, all");
+        }
+
+        Ok(Self {
+            repo: repo.trim_matches('/').to_string(),
+            state: state.to_string(),
+            token: token.or_else(|| std::env::var("GITHUB_TOKEN").ok()),
+        })
+    }
+
+    async fn fetch_issues(&self) -> Result<Vec<GithubIssue>> {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::USER_AGENT, header::HeaderValue::from_static("rask-cli"));
+        headers.insert(header::ACCEPT, header::HeaderValue::from_static("application/vnd.github+json"));
+        if let Some(token) = &self.token {
+            let value = header::HeaderValue::from_str(&format!("Bearer {}", token))
+                .context("Invalid GitHub token")?;
+            headers.insert(header::AUTHORIZATION, value);
+        }
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        let mut issues = Vec::new();
+        for page in 1.. {
+            let url = format!(
+                "{}/repos/{}/issues?state={}&per_page={}&page={}",
+                GITHUB_API, self.repo, self.state, PAGE_SIZE, page
+            );
+            let response = client
+                .get(&url)
+                .send()
+                .await
+                .context("Failed to reach the GitHub API")?;
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                anyhow::bail!("GitHub API error ({}): {}", status, error_text);
+            }
+
+            let batch: Vec<GithubIssue> = response
+                .json()
+                .await
+                .context("Failed to parse GitHub API response")?;
+            let batch_len = batch.len();
+            issues.extend(batch);
+
+            if batch_len < PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(issues)
+    }
+}
+
+impl Importer for GithubImporter {
+    fn source_name(&self) -> String {
+        format!("GitHub issues of {}", self.repo)
+    }
+
+    fn fetch_items(&self) -> Result<Vec<ImportedItem>> {
+        let rt = tokio::runtime::Runtime::new().context("Failed to create async runtime")?;
+        let issues = rt.block_on(self.fetch_issues())?;
+
+        Ok(issues
+            .into_iter()
+            .filter(|issue| issue.pull_request.is_none())
+            .map(|issue| {
+                let (priority_labels, labels): (Vec<String>, Vec<String>) = issue
+                    .labels
+                    .into_iter()
+                    .map(|l| l.name)
+                    .partition(|name| parse_priority(name).is_some());
+
+                ImportedItem {
+                    description: issue.title,
+                    labels,
+                    priority: priority_labels.first().and_then(|p| parse_priority(p)),
+                    phase: issue.milestone.map(|m| m.title),
+                    notes: issue.body,
+                    completed: issue.state == "closed",
+                    estimated_hours: None,
+                    source_ref: Some(format!("GitHub issue #{} ({})", issue.number, issue.html_url)),
+                    ..Default::default()
+                }
+            })
+            .collect())
+    }
+}
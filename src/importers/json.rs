@@ -0,0 +1,120 @@
+//! JSON importer
+//!
+//! Accepts either a plain array of items or an object with a `tasks` array
+//! (the shape produced by `rask export json`). Field names are matched
+//! loosely so exports from other tools usually work without conversion.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::PathBuf;
+
+use super::{is_completed_status, parse_priority, split_labels, ImportedItem, Importer};
+
+/// Imports items from a JSON file
+pub struct JsonImporter {
+    path: PathBuf,
+}
+
+impl JsonImporter {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl Importer for JsonImporter {
+    fn source_name(&self) -> String {
+        format!("JSON file {}", self.path.display())
+    }
+
+    fn fetch_items(&self) -> Result<Vec<ImportedItem>> {
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        let root: Value = serde_json::from_str(&content)
+            .with_context(|| format!("Invalid JSON in {}", self.path.display()))?;
+
+        let entries = match &root {
+            Value::Array(items) => items.clone(),
+            Value::Object(map) => match map.get("tasks").or_else(|| map.get("items")) {
+                Some(Value::Array(items)) => items.clone(),
+                _ => anyhow::bail!("Expected a JSON array or an object with a \"tasks\" array"),
+            },
+            _ => anyhow::bail!("Expected a JSON array or an object with a \"tasks\" array"),
+        };
+
+        Ok(entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, entry)| item_from_value(entry, index))
+            .collect())
+    }
+}
+
+fn item_from_value(entry: &Value, index: usize) -> Option<ImportedItem> {
+    let description = first_str(entry, &["description", "title", "name", "content"])?;
+
+    let labels = match first_value(entry, &["tags", "labels"]) {
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(|v| match v {
+                Value::String(s) => Some(s.clone()),
+                Value::Object(_) => v.get("name").and_then(Value::as_str).map(str::to_string),
+                _ => None,
+            })
+            .collect(),
+        Some(Value::String(s)) => split_labels(s),
+        _ => Vec::new(),
+    };
+
+    let phase = match first_value(entry, &["phase", "milestone"]) {
+        Some(Value::String(s)) => Some(s.clone()),
+        Some(v @ Value::Object(_)) => v
+            .get("name")
+            .or_else(|| v.get("title"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        _ => None,
+    };
+
+    let completed = match first_value(entry, &["status", "state", "completed", "done"]) {
+        Some(Value::Bool(b)) => *b,
+        Some(Value::String(s)) => is_completed_status(s),
+        _ => false,
+    };
+
+    let estimated_hours = first_value(entry, &["estimated_hours", "estimate"])
+        .and_then(Value::as_f64)
+        .or_else(|| {
+            entry
+                .get("time_tracking")
+                .and_then(|t| t.get("estimated_hours"))
+                .and_then(Value::as_f64)
+        });
+
+    Some(ImportedItem {
+        description,
+        labels,
+        priority: first_str(entry, &["priority"]).and_then(|p| parse_priority(&p)),
+        phase,
+        notes: first_str(entry, &["notes", "body", "desc"]),
+        completed,
+        estimated_hours,
+        source_ref: Some(match first_value(entry, &["url", "id"]) {
+            Some(Value::String(s)) => s.clone(),
+            Some(v) => format!("item {}", v),
+            None => format!("item #{}", index + 1),
+        }),
+        uuid: first_str(entry, &["uuid"]),
+        ..Default::default()
+    })
+}
+
+fn first_value<'a>(entry: &'a Value, keys: &[&str]) -> Option<&'a Value> {
+    keys.iter().find_map(|key| entry.get(*key).filter(|v| !v.is_null()))
+}
+
+fn first_str(entry: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|key| entry.get(*key).and_then(Value::as_str))
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
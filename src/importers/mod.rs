@@ -0,0 +1,148 @@
+//! Importers for bringing tasks in from external tools
+//!
+//! Every source implements the [`Importer`] trait and yields a list of
+//! [`ImportedItem`]s. The items are source-agnostic, so the import command can
+//! compute a diff against the current roadmap and convert them into tasks the
+//! same way regardless of where they came from.
+
+pub mod csv;
+pub mod github;
+pub mod json;
+
+use anyhow::Result;
+use crate::model::{Phase, Priority, Task};
+
+/// A task-shaped item read from an external source
+#[derive(Debug, Clone, Default)]
+pub struct ImportedItem {
+    /// Title or description of the item
+    pub description: String,
+
+    /// Labels from the source, mapped to tags
+    pub labels: Vec<String>,
+
+    /// Priority, if the source provides one
+    pub priority: Option<Priority>,
+
+    /// Milestone or phase name, mapped to a phase
+    pub phase: Option<String>,
+
+    /// Longer body text, mapped to task notes
+    pub notes: Option<String>,
+
+    /// Whether the item is already done in the source
+    pub completed: bool,
+
+    /// Estimated effort in hours
+    pub estimated_hours: Option<f64>,
+
+    /// Source reference such as an issue URL or row number
+    pub source_ref: Option<String>,
+    /// Task UUID from a rask export, kept so the task can be recognised again
+    pub uuid: Option<String>,
+
+    /// Name other items in the same import use to refer to this one
+    pub key: Option<String>,
+
+    /// Items this one depends on: keys of other imported items, or `#N` for
+    /// task N of the current project
+    pub depends_on: Vec<String>,
+}
+
+/// Trait implemented by every import source
+pub trait Importer {
+    /// Human-readable name of the source
+    fn source_name(&self) -> String;
+
+    /// Read all items from the source
+    fn fetch_items(&self) -> Result<Vec<ImportedItem>>;
+}
+
+impl ImportedItem {
+    /// Convert the imported item into a task with the given ID
+    pub fn to_task(&self, id: usize) -> Task {
+        let mut task = Task::new(id, self.description.trim().to_string())
+            .with_tags(self.labels.iter().filter_map(|l| sanitize_tag(l)).collect());
+
+        if let Some(priority) = &self.priority {
+            task = task.with_priority(priority.clone());
+        }
+        if let Some(phase) = &self.phase {
+            task = task.with_phase(Phase::from_string(phase));
+        }
+
+        let mut notes = self.notes.clone().unwrap_or_default().trim().to_string();
+        if let Some(source) = &self.source_ref {
+            if !notes.is_empty() {
+                notes.push_str("\n\n");
+            }
+            notes.push_str(&format!("Imported from {}", source));
+        }
+        if !notes.is_empty() {
+            task = task.with_notes(truncate_chars(&notes, 1000));
+        }
+
+        if let Some(hours) = self.estimated_hours.filter(|h| *h > 0.0) {
+            task.set_estimated_hours(hours);
+        }
+        if self.completed {
+            task.mark_completed();
+        }
+        if let Some(uuid) = self.uuid.as_ref().filter(|uuid| uuid::Uuid::parse_str(uuid).is_ok()) {
+            task.uuid = uuid.clone();
+        }
+        task.record_history("imported", self.source_ref.clone());
+        task
+    }
+}
+
+/// Parse a priority name, accepting common label spellings such as `priority:high` or `P1`
+pub fn parse_priority(value: &str) -> Option<Priority> {
+    let normalized = value.trim().to_lowercase();
+    let normalized = normalized
+        .trim_start_matches("priority")
+        .trim_start_matches([':', '/', '-', ' '])
+        .trim();
+    match normalized {
+        "low" | "p3" | "minor" => Some(Priority::Low),
+        "medium" | "normal" | "p2" => Some(Priority::Medium),
+        "high" | "p1" | "major" => Some(Priority::High),
+        "critical" | "urgent" | "p0" | "blocker" => Some(Priority::Critical),
+        _ => None,
+    }
+}
+
+/// Turn a free-form label into a valid Rask tag, or `None` if nothing usable remains
+pub fn sanitize_tag(label: &str) -> Option<String> {
+    let tag: String = label
+        .trim()
+        .chars()
+        .map(|c| if c.is_whitespace() { '-' } else { c })
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_' || *c == '/')
+        .collect();
+    // Keep `area/backend` nesting, dropping empty parts
+    let tag: Vec<&str> = tag.split('/').map(|part| part.trim_matches('-')).filter(|part| !part.is_empty()).collect();
+    let tag = truncate_chars(&tag.join("/"), 50);
+    if tag.is_empty() { None } else { Some(tag) }
+}
+
+/// Split a label list on commas or semicolons
+pub fn split_labels(value: &str) -> Vec<String> {
+    value
+        .split([',', ';'])
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// Interpret common "done" spellings from external status fields
+pub fn is_completed_status(value: &str) -> bool {
+    matches!(
+        value.trim().to_lowercase().as_str(),
+        "completed" | "complete" | "done" | "closed" | "resolved" | "yes" | "true" | "x"
+    )
+}
+
+fn truncate_chars(text: &str, max: usize) -> String {
+    text.chars().take(max).collect()
+}
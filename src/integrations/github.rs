@@ -0,0 +1,118 @@
+//! GitHub Issues REST client
+//!
+//! Creates, updates and reads the issues that mirror tasks. Requests are
+//! authenticated with a personal access token that needs the `repo` scope
+//! (or "Issues: read and write" for fine-grained tokens).
+
+use anyhow::{bail, Context, Result};
+use reqwest::header;
+use serde::Deserialize;
+use serde_json::json;
+
+/// API root of github.com; GitHub Enterprise Server uses `https://<host>/api/v3`
+pub const GITHUB_API: &str = "https://api.github.com";
+
+/// Key under which issue numbers are stored in `Task::external_refs`
+pub const REF_KEY: &str = "github";
+
+/// The parts of an issue the sync looks at
+#[derive(Debug, Deserialize)]
+pub struct GithubIssue {
+    pub number: u64,
+    pub state: String,
+    pub html_url: String,
+}
+
+impl GithubIssue {
+    pub fn is_closed(&self) -> bool {
+        self.state == "closed"
+    }
+}
+
+/// Content written to an issue
+pub struct IssueContent {
+    pub title: String,
+    pub body: String,
+    pub labels: Vec<String>,
+    pub closed: bool,
+}
+
+/// Client for the issues of one repository
+pub struct GithubClient {
+    api_url: String,
+    repo: String,
+    client: reqwest::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl GithubClient {
+    pub fn new(api_url: &str, repo: &str, token: &str) -> Result<Self> {
+        if repo.split('/').filter(|part| !part.is_empty()).count() != 2 {
+            bail!("Repository must be in owner/name form (got '{}')", repo);
+        }
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::USER_AGENT, header::HeaderValue::from_static("rask-cli"));
+        headers.insert(header::ACCEPT, header::HeaderValue::from_static("application/vnd.github+json"));
+        let value = header::HeaderValue::from_str(&format!("Bearer {}", token)).context("Invalid GitHub token")?;
+        headers.insert(header::AUTHORIZATION, value);
+
+        Ok(Self {
+            api_url: api_url.trim_end_matches('/').to_string(),
+            repo: repo.trim_matches('/').to_string(),
+            client: reqwest::Client::builder()
+                .default_headers(headers)
+                .build()
+                .context("Failed to create HTTP client")?,
+            runtime: tokio::runtime::Runtime::new().context("Failed to create async runtime")?,
+        })
+    }
+
+    /// Repository in owner/name form
+    pub fn repo(&self) -> &str {
+        &self.repo
+    }
+
+    /// Read an issue
+    pub fn get_issue(&self, number: u64) -> Result<GithubIssue> {
+        self.runtime.block_on(self.send(self.client.get(self.issue_url(Some(number)))))
+    }
+
+    /// Open a new issue, closing it right away when `content.closed` is set
+    pub fn create_issue(&self, content: &IssueContent) -> Result<GithubIssue> {
+        let body = json!({ "title": content.title, "body": content.body, "labels": content.labels });
+        let issue = self.runtime.block_on(self.send(self.client.post(self.issue_url(None)).json(&body)))?;
+        if content.closed {
+            return self.update_issue(issue.number, content);
+        }
+        Ok(issue)
+    }
+
+    /// Overwrite an issue's title, body, labels and state
+    pub fn update_issue(&self, number: u64, content: &IssueContent) -> Result<GithubIssue> {
+        let body = json!({
+            "title": content.title,
+            "body": content.body,
+            "labels": content.labels,
+            "state": if content.closed { "closed" } else { "open" },
+        });
+        self.runtime.block_on(self.send(self.client.patch(self.issue_url(Some(number))).json(&body)))
+    }
+
+    fn issue_url(&self, number: Option<u64>) -> String {
+        match number {
+            Some(number) => format!("{}/repos/{}/issues/{}", self.api_url, self.repo, number),
+            None => format!("{}/repos/{}/issues", self.api_url, self.repo),
+        }
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<GithubIssue> {
+        let response = request.send().await.context("Failed to reach the GitHub API")?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            bail!("GitHub API error ({}): {}", status, error_text);
+        }
+        response.json().await.context("Failed to parse GitHub API response")
+    }
+}
@@ -0,0 +1,270 @@
+//! Jira REST client
+//!
+//! Reads the issues of one Jira project and closes issues through workflow
+//! transitions. Jira Cloud is addressed with the account email and an API
+//! token over basic auth, and its `search/jql` endpoint; without an email the
+//! token is sent as a bearer token and the classic `search` endpoint is used,
+//! as Jira Server and Data Center expect.
+
+use anyhow::{bail, Context, Result};
+use reqwest::header;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::config::JiraConfig;
+use crate::importers::{parse_priority, ImportedItem};
+use crate::model::Priority;
+
+const PAGE_SIZE: usize = 100;
+
+/// Key under which Jira issue keys are stored in `Task::external_refs`
+pub const REF_KEY: &str = "jira";
+
+/// An issue of the synced project
+#[derive(Debug, Clone)]
+pub struct JiraIssue {
+    pub key: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub status: String,
+    /// Whether the status is in Jira's "done" category
+    pub done: bool,
+    pub priority: Option<Priority>,
+    pub labels: Vec<String>,
+    pub url: String,
+}
+
+impl JiraIssue {
+    /// The issue as an item for creating a task
+    pub fn to_item(&self) -> ImportedItem {
+        ImportedItem {
+            description: self.summary.clone(),
+            labels: self.labels.clone(),
+            priority: self.priority.clone(),
+            notes: self.description.clone(),
+            completed: self.done,
+            source_ref: Some(format!("Jira {} ({})", self.key, self.url)),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SearchPage {
+    issues: Vec<RawIssue>,
+    #[serde(default)]
+    total: Option<usize>,
+    #[serde(default, rename = "nextPageToken")]
+    next_page_token: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RawIssue {
+    key: String,
+    fields: RawFields,
+}
+
+#[derive(Deserialize)]
+struct RawFields {
+    summary: String,
+    #[serde(default)]
+    description: Option<serde_json::Value>,
+    status: RawStatus,
+    #[serde(default)]
+    priority: Option<Named>,
+    #[serde(default)]
+    labels: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RawStatus {
+    name: String,
+    #[serde(rename = "statusCategory")]
+    category: Category,
+}
+
+#[derive(Deserialize)]
+struct Category {
+    key: String,
+}
+
+#[derive(Deserialize)]
+struct Named {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct Transitions {
+    transitions: Vec<Transition>,
+}
+
+#[derive(Deserialize)]
+struct Transition {
+    id: String,
+    name: String,
+    to: RawStatus,
+}
+
+/// Map a Jira priority name to a task priority
+pub fn map_priority(name: &str) -> Option<Priority> {
+    match name.trim().to_lowercase().as_str() {
+        "highest" => Some(Priority::Critical),
+        "lowest" | "trivial" => Some(Priority::Low),
+        other => parse_priority(other),
+    }
+}
+
+/// Client for one Jira project
+pub struct JiraClient {
+    base_url: String,
+    project_key: String,
+    /// Set for Jira Cloud, which takes basic auth with the account email
+    email: Option<String>,
+    token: String,
+    done_transition: Option<String>,
+    client: reqwest::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl JiraClient {
+    /// Build a client from the `[jira]` config section
+    pub fn from_config(config: &JiraConfig) -> Result<Self> {
+        let base_url = config.base_url.as_deref().filter(|url| !url.is_empty())
+            .context("Jira base URL is not set. Run 'rask config set jira.base_url https://<site>.atlassian.net'")?
+            .trim_end_matches('/')
+            .to_string();
+        let project_key = config.project_key.as_deref().filter(|key| !key.is_empty())
+            .context("Jira project key is not set. Run 'rask config set jira.project_key <KEY>'")?
+            .to_string();
+        let token = match config.api_token.as_deref() {
+            Some(token) => crate::destinations::expand_env(token)?,
+            None => std::env::var("JIRA_API_TOKEN")
+                .map_err(|_| anyhow::anyhow!("Jira API token is not set. Set jira.api_token or the JIRA_API_TOKEN environment variable"))?,
+        };
+
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::USER_AGENT, header::HeaderValue::from_static("rask-cli"));
+        headers.insert(header::ACCEPT, header::HeaderValue::from_static("application/json"));
+
+        Ok(Self {
+            base_url,
+            project_key,
+            email: config.email.clone(),
+            token,
+            done_transition: config.done_transition.clone(),
+            client: reqwest::Client::builder()
+                .default_headers(headers)
+                .build()
+                .context("Failed to create HTTP client")?,
+            runtime: tokio::runtime::Runtime::new().context("Failed to create async runtime")?,
+        })
+    }
+
+    /// A request with the configured credentials
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self.client.request(method, format!("{}{}", self.base_url, path));
+        match &self.email {
+            Some(email) => request.basic_auth(email, Some(&self.token)),
+            None => request.bearer_auth(&self.token),
+        }
+    }
+
+    /// Key of the synced project
+    pub fn project_key(&self) -> &str {
+        &self.project_key
+    }
+
+    /// Every issue of the project, oldest first
+    pub fn fetch_issues(&self) -> Result<Vec<JiraIssue>> {
+        self.runtime.block_on(self.search())
+    }
+
+    /// Move an issue into a done status. Returns the name of the transition used.
+    pub fn close_issue(&self, key: &str) -> Result<String> {
+        self.runtime.block_on(self.transition_to_done(key))
+    }
+
+    async fn search(&self) -> Result<Vec<JiraIssue>> {
+        let jql = format!("project = \"{}\" ORDER BY created ASC", self.project_key);
+        let fields = ["summary", "description", "status", "priority", "labels"];
+        let mut issues = Vec::new();
+        let mut next_page_token: Option<String> = None;
+
+        loop {
+            let request = if self.email.is_some() {
+                let mut body = json!({ "jql": jql, "maxResults": PAGE_SIZE, "fields": fields });
+                if let Some(token) = &next_page_token {
+                    body["nextPageToken"] = json!(token);
+                }
+                self.request(reqwest::Method::POST, "/rest/api/2/search/jql").json(&body)
+            } else {
+                let body = json!({ "jql": jql, "startAt": issues.len(), "maxResults": PAGE_SIZE, "fields": fields });
+                self.request(reqwest::Method::POST, "/rest/api/2/search").json(&body)
+            };
+            let response = request.send().await.context("Failed to reach Jira")?;
+            if !response.status().is_success() {
+                let status = response.status();
+                let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                bail!("Jira API error ({}): {}", status, error_text);
+            }
+            let page: SearchPage = response.json().await.context("Failed to parse Jira search response")?;
+            let page_len = page.issues.len();
+            issues.extend(page.issues.into_iter().map(|issue| self.convert(issue)));
+
+            let more = match (&page.next_page_token, page.total) {
+                (Some(_), _) => true,
+                (None, Some(total)) if self.email.is_none() => issues.len() < total && page_len > 0,
+                _ => false,
+            };
+            if !more {
+                break;
+            }
+            next_page_token = page.next_page_token;
+        }
+
+        Ok(issues)
+    }
+
+    fn convert(&self, issue: RawIssue) -> JiraIssue {
+        let fields = issue.fields;
+        JiraIssue {
+            url: format!("{}/browse/{}", self.base_url, issue.key),
+            key: issue.key,
+            summary: fields.summary,
+            // Jira Cloud may send rich text documents; only plain text is kept
+            description: fields.description.and_then(|d| d.as_str().map(str::to_string)).filter(|d| !d.trim().is_empty()),
+            done: fields.status.category.key == "done",
+            status: fields.status.name,
+            priority: fields.priority.and_then(|p| map_priority(&p.name)),
+            labels: fields.labels,
+        }
+    }
+
+    async fn transition_to_done(&self, key: &str) -> Result<String> {
+        let path = format!("/rest/api/2/issue/{}/transitions", key);
+        let response = self.request(reqwest::Method::GET, &path).send().await.context("Failed to reach Jira")?;
+        if !response.status().is_success() {
+            bail!("Could not read the transitions of {} ({})", key, response.status());
+        }
+        let available: Transitions = response.json().await.context("Failed to parse Jira transitions")?;
+
+        let transition = match &self.done_transition {
+            Some(name) => available.transitions.iter().find(|t| t.name.eq_ignore_ascii_case(name))
+                .with_context(|| format!("{} has no '{}' transition", key, name))?,
+            None => available.transitions.iter().find(|t| t.to.category.key == "done")
+                .with_context(|| format!("{} has no transition into a done status", key))?,
+        };
+
+        let response = self.request(reqwest::Method::POST, &path)
+            .json(&json!({ "transition": { "id": transition.id } }))
+            .send()
+            .await
+            .context("Failed to reach Jira")?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            bail!("Jira rejected the '{}' transition of {} ({}): {}", transition.name, key, status, error_text);
+        }
+        Ok(format!("{} → {}", transition.name, transition.to.name))
+    }
+}
@@ -0,0 +1,8 @@
+//! Two-way integrations with external issue trackers
+//!
+//! Unlike the importers in `crate::importers`, which only bring items in,
+//! an integration keeps tasks linked to their remote issues through
+//! `Task::external_refs` and sends changes made in Rask back.
+
+pub mod github;
+pub mod jira;
@@ -0,0 +1,80 @@
+//! Diagnostic logging with `tracing`
+//!
+//! Nothing is written to stderr unless `-v` is given (or `advanced.debug` is
+//! set), so regular output stays clean. Every run also appends info-level
+//! events to `rask.log` in the data directory, which is rotated once it grows
+//! past [`MAX_LOG_BYTES`].
+
+use std::fs::{self, OpenOptions};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::field::MakeExt;
+use tracing_subscriber::prelude::*;
+
+use crate::config;
+
+/// Size at which the log file is moved to `rask.log.1` and started afresh
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+
+/// Path of the log file in the Rask data directory
+pub fn log_file_path() -> Result<PathBuf, std::io::Error> {
+    Ok(config::get_rask_data_dir()?.join("rask.log"))
+}
+
+/// Stderr level for a `-v` count; `advanced.debug` raises it to at least debug
+fn stderr_level(verbosity: u8, debug: bool) -> LevelFilter {
+    match verbosity {
+        0 if debug => LevelFilter::DEBUG,
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::INFO,
+        2 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Install the global subscriber. Logging problems never stop a command, so
+/// a log file that cannot be opened is simply left out.
+pub fn init(verbosity: u8) {
+    let debug = config::RaskConfig::load().map(|c| c.advanced.debug).unwrap_or(false);
+    let stderr_level = stderr_level(verbosity, debug);
+
+    let stderr_layer = tracing_subscriber::fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal() && colored::control::SHOULD_COLORIZE.should_colorize())
+        .with_target(false)
+        .without_time()
+        .with_filter(stderr_level);
+
+    let file_level = stderr_level.max(LevelFilter::INFO);
+    let file_layer = open_log_file().map(|file| {
+        // A field formatter of its own keeps the stderr colors out of span fields in the file
+        let plain_fields = tracing_subscriber::fmt::format::debug_fn(|writer, field, value| {
+            match field.name() {
+                "message" => write!(writer, "{:?}", value),
+                name => write!(writer, "{}={:?}", name, value),
+            }
+        })
+        .delimited(" ");
+        tracing_subscriber::fmt::layer()
+            .with_writer(Mutex::new(file))
+            .with_ansi(false)
+            .fmt_fields(plain_fields)
+            .with_filter(file_level)
+    });
+
+    let _ = tracing_subscriber::registry()
+        .with(stderr_layer)
+        .with(file_layer)
+        .try_init();
+}
+
+fn open_log_file() -> Option<fs::File> {
+    let path = log_file_path().ok()?;
+    if fs::metadata(&path).is_ok_and(|m| m.len() > MAX_LOG_BYTES) {
+        let _ = fs::rename(&path, path.with_extension("log.1"));
+    }
+    OpenOptions::new().create(true).append(true).open(path).ok()
+}
@@ -3,13 +3,23 @@ mod ai;
 mod cli;
 mod commands;
 mod config;
+mod destinations;
+mod hooks;
+mod importers;
+mod integrations;
+mod logging;
 mod markdown_writer;
 mod model;
+mod notifications;
 mod parser;
+mod rules;
+mod sorting;
 mod state;
+mod wiki_links;
+mod workspace;
 mod ui;
 
-use cli::{Commands, PhaseCommands, NotesCommands};
+use cli::{Commands, DependencyCommands, PhaseCommands, NotesCommands, ReportCommands};
 use std::process;
 
 fn main() {
@@ -19,17 +29,96 @@ fn main() {
     }
     
     // Parse command line arguments
-    let cli = cli::parse_args();
+    let mut cli = cli::parse_args();
     
-    // Execute the command and handle errors
-    if let Err(e) = run_command(&cli.command) {
+    // Configure prompt behaviour before any command can ask for input
+    let non_interactive = cli.non_interactive
+        || std::env::var("RASK_NON_INTERACTIVE").is_ok_and(|v| !v.is_empty() && v != "0");
+    commands::utils::set_prompt_mode(non_interactive, cli.yes);
+    commands::utils::set_json_output(cli.json);
+    ui::set_output_mode(cli.quiet, cli.porcelain);
+    ui::set_color_mode(cli.no_color);
+    
+    // Work in another project for this invocation only
+    if let Some(project) = &cli.project {
+        cli.command.absolutize_paths();
+        if let Err(e) = commands::utils::enter_project(project) {
+            ui::display_error(&e.to_string());
+            process::exit(1);
+        }
+    }
+    
+    // Resolve the config profile and check environment overrides once, before anything reads the config
+    if let Some(profile) = cli.profile.clone().or_else(config::active_profile) {
+        if let Err(e) = config::set_active_profile(&profile) {
+            ui::display_error(&e.to_string());
+            process::exit(1);
+        }
+    }
+    if let Err(e) = config::RaskConfig::load() {
         ui::display_error(&e.to_string());
         process::exit(1);
     }
+    
+    ui::theme::init(cli.accessible);
+    
+    // Diagnostics go to stderr with -v and to the log file; completion lookups stay out of the log
+    if !matches!(cli.command, Commands::CompleteValues { .. }) {
+        logging::init(cli.verbose);
+    }
+    let _command_span = tracing::info_span!("command", name = %cli.command_name).entered();
+    tracing::info!(dir = %std::env::current_dir().unwrap_or_default().display(), "running rask {}", cli.command_name);
+    
+    // Catch time sessions that were left running, unless the output is meant for another program
+    let machine_output = cli.json || cli.porcelain
+        || matches!(cli.command, Commands::Completions { .. } | Commands::CompleteValues { .. });
+    if !machine_output {
+        if checks_sessions(&cli.command) {
+            if let Err(e) = commands::check_long_running_sessions() {
+                ui::display_stderr_warning(&format!("Session check failed: {}", e));
+            }
+        }
+        notifications::check_desktop_reminders();
+    }
+    
+    // Execute the command and handle errors
+    let started = std::time::Instant::now();
+    let result = run_command(&cli.command);
+    tracing::debug!(elapsed_ms = started.elapsed().as_millis() as u64, "command finished");
+    if let Err(e) = result {
+        tracing::error!("{}", e);
+        ui::display_error(&e.to_string());
+        let code = e.downcast_ref::<commands::CommandError>().map_or(1, commands::CommandError::exit_code);
+        process::exit(code);
+    }
 }
 
 /// Initialize Rask configuration and directory structure
 /// This handles first-time setup and migration from legacy versions
+/// Whether to warn about over-long time sessions before this command.
+/// Read-only commands, which may be embedded in a shell prompt, stay quiet,
+/// and `stop` offers to trim the session itself.
+fn checks_sessions(command: &Commands) -> bool {
+    !matches!(command,
+        Commands::Stop
+            | Commands::Statusline
+            | Commands::Show { .. }
+            | Commands::List { .. }
+            | Commands::Find { .. }
+            | Commands::View { .. }
+            | Commands::Ready { .. }
+            | Commands::Now { .. }
+            | Commands::Next { .. }
+            | Commands::Urgent
+            | Commands::Blocked
+            | Commands::Dependencies { .. }
+            | Commands::Decisions
+            | Commands::Analytics { .. }
+            | Commands::Forecast { .. }
+            | Commands::Timeline { .. }
+    )
+}
+
 fn initialize_rask() -> Result<(), Box<dyn std::error::Error>> {
     // Create necessary directories
     config::get_rask_config_dir()?;
@@ -47,28 +136,40 @@ fn initialize_rask() -> Result<(), Box<dyn std::error::Error>> {
 fn run_command(command: &Commands) -> commands::CommandResult {
     match command {
         Commands::Init { filepath } => commands::init_project(filepath),
-        Commands::Show { group_by_phase, phase, detailed, collapse_completed } => {
-            commands::show_project_enhanced(*group_by_phase, phase.as_deref(), *detailed, *collapse_completed)
+        Commands::Show { group_by_phase, phase, detailed, collapse_completed, sort, reverse, include_snoozed } => {
+            commands::show_project_enhanced(*group_by_phase, phase.as_deref(), *detailed, *collapse_completed, *sort, *reverse, *include_snoozed)
         },
-        Commands::Complete { id } => commands::complete_task(*id),
-        Commands::Add { description, tag, priority, phase, note, dependencies, estimated_hours } => {
-            commands::add_task_enhanced(description, tag, priority, phase, note, dependencies, estimated_hours)
+        Commands::Complete { id, force } => commands::complete_task(*id, *force),
+        Commands::Add { description, tag, priority, phase, note, dependencies, estimated_hours, due, criteria, owner, energy, context, no_markdown } => {
+            commands::add_task_enhanced(description, tag, priority, phase, note, dependencies, estimated_hours, due, criteria, owner, *energy, context, *no_markdown)
         },
-        Commands::Quick { text } => {
-            commands::quick_add_task(text)
+        Commands::Quick { text, dry_run } => {
+            commands::quick_add_task(text, *dry_run)
         },
         Commands::Remove { id } => commands::remove_task(*id),
-        Commands::Edit { id, description } => commands::edit_task(*id, description),
+        Commands::Merge { keep_id, merge_id } => commands::merge_task(*keep_id, *merge_id),
+        Commands::Edit { id, description, owner, clear_owners, energy, context } => {
+            commands::edit_task(*id, description.as_deref(), owner.as_deref(), *clear_owners, *energy, context.as_deref())
+        },
         Commands::Reset { id } => commands::reset_tasks(*id),
-        Commands::List { tag, priority, phase, status, search, detailed } => {
-            commands::list_tasks(tag, priority, phase, status, search, *detailed)
+        Commands::List { tag, priority, phase, status, search, owner, mine, stale, detailed, all_projects, sort, reverse, include_snoozed, group_tags } => {
+            commands::list_tasks(tag, priority, phase, status, search, owner, *mine, *stale, *detailed, *all_projects, *sort, *reverse, *include_snoozed, group_tags.as_deref())
         },
-        Commands::Dependencies { task_id, validate, show_ready, show_blocked } => {
-            commands::analyze_dependencies(task_id, *validate, *show_ready, *show_blocked)
+        Commands::Dependencies { action: Some(DependencyCommands::Note { edge, text, clear }), .. } => {
+            commands::dependency_note(edge, text.as_deref(), *clear)
         },
-        Commands::Ready => commands::show_ready_tasks(),
+        Commands::Dependencies { task_id, validate, show_ready, show_blocked, order, apply, action: None } => {
+            commands::analyze_dependencies(task_id, *validate, *show_ready, *show_blocked, *order, *apply)
+        },
+        Commands::Ready { include_snoozed } => commands::show_ready_tasks(*include_snoozed),
+        Commands::Snooze { id, until, for_span, clear } => {
+            commands::snooze_task(*id, until.as_deref(), for_span.as_deref(), *clear)
+        },
+        Commands::Now { energy, context, limit } => commands::show_now_tasks(*energy, context.as_deref(), *limit),
+        Commands::Next { time, limit } => commands::show_next_tasks(time.as_deref(), *limit),
         Commands::Urgent => commands::show_urgent_tasks(),
         Commands::Blocked => commands::show_blocked_tasks(),
+        Commands::Statusline => commands::show_statusline(),
         Commands::Find { query } => commands::find_tasks(query),
         Commands::Phase(phase_command) => {
             match phase_command {
@@ -76,17 +177,30 @@ fn run_command(command: &Commands) -> commands::CommandResult {
                 PhaseCommands::Show { phase } => commands::show_phase_tasks(phase),
                 PhaseCommands::Set { task_id, phase } => commands::set_task_phase(*task_id, phase),
                 PhaseCommands::Overview => commands::show_phase_overview(),
+                PhaseCommands::Limit { phase, limit, clear } => commands::set_phase_limit(phase, *limit, *clear),
                 PhaseCommands::Create { name, description, emoji } => commands::create_custom_phase(name, description.as_deref(), emoji.as_deref()),
                 PhaseCommands::Fork { new_phase, from_phase, task_ids, description, emoji, copy } => {
                     commands::fork_phase_or_tasks(new_phase, from_phase.as_deref(), task_ids.as_deref(), description.as_deref(), emoji.as_deref(), *copy)
                 },
+                PhaseCommands::Rename { phase, new_name } => commands::rename_phase(phase, new_name),
+                PhaseCommands::Reorder { phase, before, after, position } => {
+                    commands::reorder_phase(phase, before.as_deref(), after.as_deref(), *position)
+                }
+                PhaseCommands::Archive { phase } => commands::archive_phase(phase),
+                PhaseCommands::Archived { phase } => commands::show_archived_phases(phase.as_deref()),
             }
         },
         Commands::Config(config_command) => {
             commands::handle_config_command(config_command)
         },
-        Commands::View { id } => {
-            commands::view_task(*id)
+        Commands::Comment { id, text, author } => match text {
+            Some(text) => commands::add_task_comment(*id, text, author.as_deref()),
+            None => commands::list_task_comments(*id),
+        },
+        Commands::Decisions => commands::list_decisions(),
+        Commands::Reorder { phase } => commands::reorder_tasks(phase.as_deref()),
+        Commands::View { id, history } => {
+            commands::view_task(*id, *history)
         },
         Commands::Bulk(bulk_command) => {
             commands::handle_bulk_command(bulk_command)
@@ -98,7 +212,7 @@ fn run_command(command: &Commands) -> commands::CommandResult {
             format, output, include_completed, tags, priority, phase, pretty,
             created_after, created_before, min_estimated_hours, max_estimated_hours,
             min_actual_hours, max_actual_hours, with_time_data, active_sessions_only,
-            over_estimated_only, under_estimated_only
+            over_estimated_only, under_estimated_only, max_rows, to
         } => {
             commands::export_roadmap_enhanced(
                 format, output.as_deref(), *include_completed, tags.as_deref(), 
@@ -107,9 +221,30 @@ fn run_command(command: &Commands) -> commands::CommandResult {
                 *min_estimated_hours, *max_estimated_hours,
                 *min_actual_hours, *max_actual_hours,
                 *with_time_data, *active_sessions_only,
-                *over_estimated_only, *under_estimated_only
+                *over_estimated_only, *under_estimated_only, *max_rows, to
             )
         },
+        Commands::Import(import_command) => {
+            commands::handle_import_command(import_command)
+        },
+        Commands::Git(git_command) => {
+            commands::handle_git_command(git_command)
+        },
+        Commands::Github(github_command) => {
+            commands::handle_github_command(github_command)
+        },
+        Commands::Notify(notify_command) => {
+            commands::handle_notify_command(notify_command)
+        },
+        Commands::Milestone(milestone_command) => {
+            commands::handle_milestone_command(milestone_command)
+        },
+        Commands::Workspace(workspace_command) => {
+            commands::handle_workspace_command(workspace_command)
+        },
+        Commands::Rules(rules_command) => {
+            commands::handle_rules_command(rules_command)
+        },
         Commands::Template(template_command) => {
             commands::handle_template_command(template_command.clone())
         },
@@ -119,21 +254,52 @@ fn run_command(command: &Commands) -> commands::CommandResult {
         Commands::Stop => {
             commands::stop_time_tracking()
         },
-        Commands::Time { task_id, summary, detailed } => {
+        Commands::Plan { weeks } => {
+            commands::show_plan(*weeks)
+        },
+        Commands::Calendar { month } => {
+            commands::show_calendar(month.as_deref())
+        },
+        Commands::Pomodoro { task_id, work, break_minutes, cycles } => {
+            commands::run_pomodoro(*task_id, *work, *break_minutes, *cycles)
+        },
+        Commands::Time { action: Some(time_command), .. } => {
+            commands::handle_time_command(time_command)
+        },
+        Commands::Time { task_id, summary, detailed, action: None } => {
             commands::show_time_tracking(task_id, *summary, *detailed)
         },
-        Commands::Analytics { overview, time, phases, priorities, trends, export, all } => {
+        Commands::Recover { rollback, discard } => commands::recover_operation(*rollback, *discard),
+        Commands::Migrate { check } => commands::migrate_state(*check),
+        Commands::Storage { backend } => commands::set_storage_backend(backend.as_deref()),
+        Commands::Restore { backup, list } => commands::restore_backup(backup.as_deref(), *list),
+        Commands::Report(ReportCommands::Timesheet { week: _, month, date, group_by, csv }) => {
+            commands::generate_timesheet(*month, date.as_deref(), *group_by, csv.as_deref())
+        },
+        Commands::Review { older_than } => commands::review_tasks(older_than),
+        Commands::Forecast { phase, weeks } => commands::show_forecast(phase.as_deref(), *weeks),
+        Commands::Estimate { phase, tag } => commands::estimate_tasks(phase.as_deref(), tag.as_deref()),
+        Commands::Dedupe { threshold, all, ai } => commands::find_duplicates(*threshold, *all, *ai),
+        Commands::Retro { phase, ai, output } => commands::generate_retro(phase, *ai, output.as_deref()),
+        Commands::Analytics { overview, time, phases, priorities, tags, trends, stale, export, all } => {
             commands::show_analytics(
                 *overview || *all, 
                 *time || *all, 
                 *phases || *all, 
                 *priorities || *all, 
+                *tags || *all, 
                 *trends || *all, 
+                *stale || *all, 
                 export.as_ref().map(|p| p.to_string_lossy().to_string())
             )
         },
-        Commands::Timeline { detailed, active_only, compact, page, page_size } => {
-            commands::show_timeline(*detailed, *active_only, *compact, *page, *page_size)
+        Commands::Timeline { kinds, since, until, task, limit, phases, detailed, active_only, compact, page, page_size } => {
+            // The layout options only apply to the phase view
+            if *phases || *detailed || *active_only || *compact || page.is_some() || page_size.is_some() {
+                commands::show_timeline(*detailed, *active_only, *compact, *page, *page_size)
+            } else {
+                commands::show_activity_timeline(kinds, since.as_deref(), until.as_deref(), *task, *limit)
+            }
         },
         Commands::Ai(ai_command) => {
             commands::handle_ai_command(ai_command)
@@ -141,7 +307,12 @@ fn run_command(command: &Commands) -> commands::CommandResult {
         Commands::Interactive { project, no_welcome } => {
             commands::run_interactive_mode(project.as_deref(), *no_welcome)
         },
-        Commands::Sync { from_roadmap, from_details, from_global, to_files, force, dry_run } => {
+        Commands::Completions { shell } => commands::generate_completions(*shell),
+        Commands::CompleteValues { words } => commands::complete_values(words),
+        Commands::Sync { target: Some(cli::SyncCommands::Jira { dry_run, include_done }), .. } => {
+            commands::sync_jira(*dry_run, *include_done)
+        },
+        Commands::Sync { target: None, from_roadmap, from_details, from_global, to_files, force, dry_run } => {
             commands::sync_project_files(*from_roadmap, *from_details, *from_global, *to_files, *force, *dry_run)
         },
     }
@@ -150,11 +321,11 @@ fn run_command(command: &Commands) -> commands::CommandResult {
 /// Handle notes command routing
 fn handle_notes_command(notes_command: &NotesCommands) -> commands::CommandResult {
     match notes_command {
-        NotesCommands::Add { task_id, note } => {
-            commands::add_implementation_note(*task_id, note.clone())
+        NotesCommands::Add { task_id, note, kind } => {
+            commands::add_implementation_note(*task_id, note.clone(), (*kind).into())
         },
-        NotesCommands::List { task_id } => {
-            commands::list_implementation_notes(*task_id)
+        NotesCommands::List { task_id, kind } => {
+            commands::list_implementation_notes(*task_id, kind.map(Into::into))
         },
         NotesCommands::Remove { task_id, index } => {
             commands::remove_implementation_note(*task_id, *index)
@@ -162,6 +333,9 @@ fn handle_notes_command(notes_command: &NotesCommands) -> commands::CommandResul
         NotesCommands::Clear { task_id } => {
             commands::clear_implementation_notes(*task_id)
         },
+        NotesCommands::Open { task_id, index } => {
+            commands::open_note_links(*task_id, *index)
+        },
         NotesCommands::Edit { task_id, index, note } => {
             commands::edit_implementation_note(*task_id, *index, note.clone())
         },
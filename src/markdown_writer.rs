@@ -1,4 +1,5 @@
-use crate::model::{Roadmap, TaskStatus};
+use crate::model::{Phase, PhaseArchive, Priority, Roadmap, Task, TaskStatus};
+use std::collections::HashMap;
 use std::fs;
 use std::io::{Error, ErrorKind};
 use std::path::Path;
@@ -9,9 +10,12 @@ pub fn write_roadmap_to_file(roadmap: &Roadmap, file_path: &Path) -> Result<(),
     fs::write(file_path, markdown_content)
 }
 
-/// Convert a roadmap back to markdown format
+/// Convert a roadmap back to markdown format, in the layout the parser reads:
+/// a `## Phase` section per phase (unless every task is in the default
+/// phase), subtasks indented under their parent, and due dates, estimates,
+/// priorities and tags as inline `(due: ..., est: ...)` metadata
 fn roadmap_to_markdown(roadmap: &Roadmap) -> String {
-    let mut content = String::new();
+    let mut content = frontmatter(roadmap);
     
     // Add the title
     content.push_str(&format!("# {}\n\n", roadmap.title));
@@ -19,25 +23,119 @@ fn roadmap_to_markdown(roadmap: &Roadmap) -> String {
     // Add description (if we want to preserve it, we'd need to store it)
     content.push_str("This file outlines the tasks required to build the MVP for the Rask application.\n\n");
     
-    // Add tasks
-    for task in &roadmap.tasks {
-        let checkbox = match task.status {
-            TaskStatus::Pending => "[ ]",
-            TaskStatus::Completed => "[x]",
-        };
-        content.push_str(&format!("- {} {}\n", checkbox, task.description));
+    // Add tasks, leaving out local-only ones
+    let synced: Vec<&Task> = roadmap.tasks.iter().filter(|t| t.sync).collect();
+    let is_top_level = |task: &Task| task.parent.is_none_or(|parent| !synced.iter().any(|t| t.id == parent));
+    let default_phase = roadmap.metadata.default_phase.as_deref().map(Phase::from_string).unwrap_or_default();
+    
+    if synced.iter().all(|t| t.phase.name == default_phase.name) {
+        for task in synced.iter().filter(|t| is_top_level(t)) {
+            write_task(&mut content, &synced, task, 0, &default_phase.name);
+        }
+        return content;
+    }
+    
+    for phase in roadmap.get_all_phases() {
+        let top_level: Vec<&&Task> = synced.iter()
+            .filter(|t| t.phase.name == phase.name && is_top_level(t))
+            .collect();
+        if top_level.is_empty() {
+            continue;
+        }
+        content.push_str(&format!("## {}\n\n", phase.name));
+        for task in top_level {
+            write_task(&mut content, &synced, task, 0, &phase.name);
+        }
+        content.push('\n');
     }
     
     content
 }
 
-/// Update the original markdown file with current task statuses
+/// The `---` YAML block carrying project metadata, when there is any to keep
+fn frontmatter(roadmap: &Roadmap) -> String {
+    let metadata = &roadmap.metadata;
+    if !metadata.has_frontmatter(&roadmap.title) {
+        return String::new();
+    }
+    let mut fields = serde_yaml::Mapping::new();
+    fields.insert("name".into(), metadata.name.clone().into());
+    if let Some(description) = &metadata.description {
+        fields.insert("description".into(), description.clone().into());
+    }
+    if let Some(phase) = &metadata.default_phase {
+        fields.insert("default_phase".into(), phase.clone().into());
+    }
+    if !metadata.tags.is_empty() {
+        fields.insert("tags".into(), metadata.tags.clone().into());
+    }
+    if let Some(hours) = metadata.estimated_hours {
+        fields.insert("estimate".into(), format!("{}h", (hours * 100.0).round() / 100.0).into());
+    }
+    format!("---\n{}---\n\n", serde_yaml::to_string(&fields).unwrap_or_default())
+}
+
+/// Write a task line followed by its subtasks, indented one level deeper
+fn write_task(content: &mut String, synced: &[&Task], task: &Task, depth: usize, section_phase: &str) {
+    let checkbox = match task.status {
+        TaskStatus::Pending => "[ ]",
+        TaskStatus::Completed => "[x]",
+    };
+    
+    let mut metadata = Vec::new();
+    if let Some(due) = &task.due_date {
+        metadata.push(format!("due: {}", due));
+    }
+    if let Some(hours) = task.estimated_hours {
+        metadata.push(format!("est: {}h", (hours * 100.0).round() / 100.0));
+    }
+    if task.priority != Priority::Medium {
+        metadata.push(format!("priority: {}", task.priority.to_string().to_lowercase()));
+    }
+    if !task.tags.is_empty() {
+        let mut tags: Vec<&String> = task.tags.iter().collect();
+        tags.sort();
+        metadata.push(format!("tags: {}", tags.iter().map(|t| t.as_str()).collect::<Vec<_>>().join(", ")));
+    }
+    if task.phase.name != section_phase {
+        metadata.push(format!("phase: {}", task.phase.name));
+    }
+    let metadata = if metadata.is_empty() { String::new() } else { format!(" ({})", metadata.join(", ")) };
+    
+    content.push_str(&format!("{}- {} {}{}\n", "  ".repeat(depth), checkbox, task.description, metadata));
+    for child in synced.iter().filter(|t| t.parent == Some(task.id)) {
+        write_task(content, synced, child, depth + 1, section_phase);
+    }
+}
+
+/// Update the original markdown file with current task statuses.
+///
+/// The file is left alone when it was edited outside rask since the last
+/// sync; `rask sync --dry-run` shows what changed there.
 pub fn sync_to_source_file(roadmap: &Roadmap) -> Result<(), Error> {
+    if let Some(source_file) = &roadmap.source_file {
+        if source_changed(Path::new(source_file))? {
+            crate::ui::display_warning(&format!(
+                "{} was edited outside rask; not overwriting it. Review with 'rask sync --dry-run'",
+                source_file
+            ));
+            return Ok(());
+        }
+    }
+    write_source_file(roadmap)
+}
+
+/// Write the roadmap to its markdown source and remember the written content,
+/// regardless of edits made there since the last sync
+pub fn write_source_file(roadmap: &Roadmap) -> Result<(), Error> {
     if let Some(source_file) = &roadmap.source_file {
         let path = Path::new(source_file);
         if path.exists() {
             write_roadmap_to_file(roadmap, path)?;
-            println!("   📝 Synced changes to {}", source_file);
+            record_synced_source(path)?;
+            if !crate::ui::is_quiet() {
+                println!("   📝 Synced changes to {}", source_file);
+            }
         } else {
             return Err(Error::new(
                 ErrorKind::NotFound,
@@ -46,4 +144,189 @@ pub fn sync_to_source_file(roadmap: &Roadmap) -> Result<(), Error> {
         }
     }
     Ok(())
-} 
\ No newline at end of file
+}
+
+/// Remember the current content of the markdown source as in sync with the
+/// state: its hash, and a copy to tell later edits in the file from changes
+/// made in rask
+pub fn record_synced_source(path: &Path) -> Result<(), Error> {
+    let content = fs::read_to_string(path)?;
+    fs::write(crate::state::get_synced_source_file()?, &content)?;
+    fs::write(crate::state::get_source_hash_file()?, content_hash(&content))
+}
+
+/// Whether the markdown source differs from what rask last wrote or read.
+/// Projects synced before hashes were recorded count as unchanged.
+fn source_changed(path: &Path) -> Result<bool, Error> {
+    let Ok(stored) = fs::read_to_string(crate::state::get_source_hash_file()?) else {
+        return Ok(false);
+    };
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(false);
+    };
+    Ok(content_hash(&content) != stored.trim())
+}
+
+fn content_hash(content: &str) -> String {
+    hmac_sha256::Hash::hash(content.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Edits made to the markdown source outside rask since the last sync
+#[derive(Debug, Default)]
+pub struct SourceEdits {
+    /// Unchecked items that match no task; these can be added safely
+    pub new_items: Vec<Task>,
+    /// Changes that would be lost by overwriting the file, one line each
+    pub conflicts: Vec<String>,
+    /// File task ID → state task ID for items that matched a task
+    matched: HashMap<usize, usize>,
+}
+
+impl SourceEdits {
+    /// Add the new items to the roadmap as tasks, keeping them under their
+    /// parent item. Returns the IDs of the added tasks.
+    pub fn merge_into(&self, roadmap: &mut Roadmap) -> Vec<usize> {
+        let mut ids = self.matched.clone();
+        let mut added = Vec::new();
+        for item in &self.new_items {
+            let mut task = item.clone();
+            task.id = roadmap.get_next_task_id();
+            task.parent = item.parent.and_then(|parent| ids.get(&parent).copied());
+            task.dependencies.clear();
+            ids.insert(item.id, task.id);
+            added.push(task.id);
+            roadmap.add_task(task);
+        }
+        added
+    }
+}
+
+/// Compare the markdown source with the roadmap when it was edited outside
+/// rask since the last sync. Tasks are matched to file items by description.
+///
+/// Only edits made in the file count: it is compared with the copy rask last
+/// wrote (see [`record_synced_source`]), so tasks added or changed in rask
+/// since then are not conflicts. Projects synced before that copy was kept
+/// are compared with the roadmap directly.
+pub fn detect_source_edits(roadmap: &Roadmap) -> Result<Option<SourceEdits>, Error> {
+    let Some(source_file) = &roadmap.source_file else {
+        return Ok(None);
+    };
+    let path = Path::new(source_file);
+    if !path.exists() || !source_changed(path)? {
+        return Ok(None);
+    }
+
+    let parse = |content: &str| crate::parser::parse_markdown_to_roadmap(content, Some(path), &roadmap.title);
+    let parsed = parse(&fs::read_to_string(path)?)?;
+    let base = match fs::read_to_string(crate::state::get_synced_source_file()?) {
+        Ok(content) => Some(parse(&content)?),
+        Err(_) => None,
+    };
+    let synced: Vec<&Task> = roadmap.tasks.iter().filter(|t| t.sync).collect();
+    let mut used = vec![false; synced.len()];
+    let mut edits = SourceEdits::default();
+    let metadata_key = |m: &crate::model::ProjectMetadata| {
+        (m.name.clone(), m.description.clone(), m.default_phase.clone(), m.tags.clone(), m.estimated_hours)
+    };
+    let file_metadata = metadata_key(&parsed.metadata);
+    let edited_metadata = base.as_ref().is_none_or(|base| metadata_key(&base.metadata) != file_metadata);
+    if edited_metadata && metadata_key(&roadmap.metadata) != file_metadata {
+        edits.conflicts.push("the frontmatter was changed".to_string());
+    }
+
+    // Items of the last synced copy, matched by description like the tasks
+    let base_items: Vec<&Task> = base.iter().flat_map(|base| &base.tasks).collect();
+    let mut base_used = vec![false; base_items.len()];
+
+    for item in &parsed.tasks {
+        let base_index = base_items.iter().enumerate()
+            .position(|(index, old)| !base_used[index] && old.description == item.description);
+        if let Some(index) = base_index {
+            base_used[index] = true;
+        }
+        let found = synced.iter().enumerate()
+            .position(|(index, task)| !used[index] && task.description == item.description);
+        let Some(index) = found else {
+            match base_index {
+                // Unchanged in the file, so rask removed or reworded it
+                Some(index) if changed_fields(base_items[index], item).is_empty() => {}
+                Some(_) => edits.conflicts.push(format!("'{}' was changed in the file but is no longer a task", item.description)),
+                None if item.status == TaskStatus::Pending => edits.new_items.push(item.clone()),
+                None => edits.conflicts.push(format!("'{}' was added already checked off", item.description)),
+            }
+            continue;
+        };
+        used[index] = true;
+        let task = synced[index];
+        edits.matched.insert(item.id, task.id);
+        let changes = changed_fields(task, item);
+        let edited_in_file = base.is_none() || base_index.is_none_or(|index| !changed_fields(base_items[index], item).is_empty());
+        if edited_in_file && !changes.is_empty() {
+            edits.conflicts.push(format!("#{} '{}' has a different {} in the file", task.id, task.description, changes.join(", ")));
+        }
+    }
+    for (task, _) in synced.iter().zip(&used).filter(|(_, used)| !**used) {
+        // Tasks the last synced copy did not have were added in rask
+        let removed_in_file = base.is_none() || base_items.iter().zip(&base_used)
+            .any(|(old, used)| !*used && old.description == task.description);
+        if removed_in_file {
+            edits.conflicts.push(format!("#{} '{}' was removed or reworded in the file", task.id, task.description));
+        }
+    }
+    Ok(Some(edits))
+}
+
+/// Fields the markdown carries that differ between a task and its file item
+fn changed_fields(task: &Task, item: &Task) -> Vec<&'static str> {
+    let round = |hours: Option<f64>| hours.map(|h| (h * 100.0).round() as i64);
+    let sorted_tags = |task: &Task| {
+        let mut tags: Vec<String> = task.tags.iter().cloned().collect();
+        tags.sort();
+        tags
+    };
+    let mut changes = Vec::new();
+    if task.status != item.status {
+        changes.push("status");
+    }
+    if task.due_date != item.due_date {
+        changes.push("due date");
+    }
+    if round(task.estimated_hours) != round(item.estimated_hours) {
+        changes.push("estimate");
+    }
+    if task.priority != item.priority {
+        changes.push("priority");
+    }
+    if sorted_tags(task) != sorted_tags(item) {
+        changes.push("tags");
+    }
+    if !task.phase.name.eq_ignore_ascii_case(&item.phase.name) {
+        changes.push("phase");
+    }
+    changes
+}
+
+/// Write a human-readable markdown copy of an archived phase
+pub fn write_phase_archive(archive: &PhaseArchive, file_path: &Path) -> Result<(), Error> {
+    let mut content = String::new();
+    
+    content.push_str(&format!("# {} - {} (archived)\n\n", archive.roadmap_title, archive.summary.phase));
+    content.push_str(&format!(
+        "Archived on {} with {} tasks ({:.1}h estimated, {:.1}h tracked).\n\n",
+        archive.summary.archived_at.get(..10).unwrap_or(&archive.summary.archived_at),
+        archive.summary.total_tasks,
+        archive.summary.estimated_hours,
+        archive.summary.actual_hours
+    ));
+    
+    for task in &archive.tasks {
+        let checkbox = match task.status {
+            TaskStatus::Pending => "[ ]",
+            TaskStatus::Completed => "[x]",
+        };
+        content.push_str(&format!("- {} #{} {}\n", checkbox, task.id, task.description));
+    }
+    
+    fs::write(file_path, content)
+}
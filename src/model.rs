@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
-use std::collections::{HashSet, HashMap};
+use std::collections::{BTreeMap, HashSet, HashMap};
+use std::sync::{LazyLock, OnceLock};
 
 /// Task template for creating reusable task patterns
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -47,6 +48,23 @@ impl std::fmt::Display for TemplateCategory {
     }
 }
 
+/// Template variables look like `[BUG_DESCRIPTION]`: capitals, digits and underscores
+static PLACEHOLDER: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r"\[([A-Z][A-Z0-9_]*)\]").expect("valid placeholder regex")
+});
+
+/// Names of the `[NAME]` placeholders in a text, in order
+pub fn placeholder_names(text: &str) -> Vec<String> {
+    PLACEHOLDER.captures_iter(text).map(|caps| caps[1].to_string()).collect()
+}
+
+/// Replace the `[NAME]` placeholders that have a value in `vars`, leaving the rest
+pub fn fill_placeholders(text: &str, vars: &HashMap<String, String>) -> String {
+    PLACEHOLDER
+        .replace_all(text, |caps: &regex::Captures| vars.get(&caps[1]).cloned().unwrap_or_else(|| caps[0].to_string()))
+        .into_owned()
+}
+
 impl TaskTemplate {
     /// Create a new task template
     pub fn new(name: String, description: String) -> Self {
@@ -67,13 +85,16 @@ impl TaskTemplate {
     pub fn create_task(&self, id: usize, custom_description: Option<String>) -> Task {
         Task {
             id,
+            uuid: new_task_uuid(),
             description: custom_description.unwrap_or_else(|| self.description.clone()),
             status: TaskStatus::Pending,
             tags: self.tags.clone(),
             priority: self.priority.clone(),
             phase: self.phase.clone(),
             notes: self.notes.clone(),
-            implementation_notes: self.implementation_notes.clone(),
+            implementation_notes: self.implementation_notes.iter()
+                .map(|note| ImplementationNote::new(note.clone(), NoteKind::General))
+                .collect(),
             dependencies: Vec::new(),
             created_at: Some(chrono::Utc::now().to_rfc3339()),
             completed_at: None,
@@ -81,7 +102,39 @@ impl TaskTemplate {
             actual_hours: None,
             time_sessions: Vec::new(),
             ai_info: AiTaskInfo::default(),
+            due_date: None,
+            acceptance_criteria: Vec::new(),
+            history: Vec::new(),
+            linked_commits: Vec::new(),
+            sync: true,
+            owners: Vec::new(),
+            comments: Vec::new(),
+            energy: None,
+            contexts: Vec::new(),
+            dependency_notes: BTreeMap::new(),
+            parent: None,
+            reviewed_at: None,
+            snoozed_until: None,
+            external_refs: BTreeMap::new(),
+            summary: None,
+            created_at_cached: None,
+            completed_at_cached: None,
+        }
+    }
+
+    /// Names of the `[PLACEHOLDER]` variables in the description, notes and
+    /// implementation notes, in order of first appearance
+    pub fn placeholders(&self) -> Vec<String> {
+        let texts = std::iter::once(self.description.as_str())
+            .chain(self.notes.as_deref())
+            .chain(self.implementation_notes.iter().map(String::as_str));
+        let mut names: Vec<String> = Vec::new();
+        for name in texts.flat_map(placeholder_names) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
         }
+        names
     }
 
     /// Get predefined development templates
@@ -191,10 +244,134 @@ impl TaskTemplate {
 pub struct TemplateCollection {
     pub templates: Vec<TaskTemplate>,
     pub roadmap_templates: Vec<RoadmapTemplate>,
+    /// Custom template packs; the predefined ones are always available
+    #[serde(default)]
+    pub packs: Vec<TemplatePack>,
     pub created_at: String,
     pub last_modified: String,
 }
 
+/// A set of related tasks created together, such as everything a new web app needs
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TemplatePack {
+    pub name: String,
+    pub description: String,
+    pub tasks: Vec<PackTask>,
+}
+
+/// One task of a template pack. Dependencies name other tasks of the same
+/// pack by key, so they can be remapped to real task IDs when the pack is used.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PackTask {
+    /// Short name of the task within the pack, e.g. "auth"
+    pub key: String,
+    pub description: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub priority: Priority,
+    pub phase: String,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub estimated_hours: Option<f64>,
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+impl PackTask {
+    fn new(key: &str, description: &str, phase: &str, tags: &[&str], depends_on: &[&str]) -> Self {
+        PackTask {
+            key: key.to_string(),
+            description: description.to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            priority: Priority::Medium,
+            phase: phase.to_string(),
+            depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+            estimated_hours: None,
+            notes: None,
+        }
+    }
+
+    fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    fn estimate(mut self, hours: f64) -> Self {
+        self.estimated_hours = Some(hours);
+        self
+    }
+}
+
+impl TemplatePack {
+    /// Check that keys are unique and every dependency names a task of the pack
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, task) in self.tasks.iter().enumerate() {
+            if self.tasks[..index].iter().any(|t| t.key == task.key) {
+                return Err(format!("Pack '{}' has two tasks with key '{}'", self.name, task.key));
+            }
+            if let Some(missing) = task.depends_on.iter().find(|dep| !self.tasks.iter().any(|t| &t.key == *dep)) {
+                return Err(format!("Task '{}' of pack '{}' depends on unknown key '{}'", task.key, self.name, missing));
+            }
+        }
+        Ok(())
+    }
+
+    /// Names of the `[PLACEHOLDER]` variables used in the pack's tasks
+    pub fn placeholders(&self) -> Vec<String> {
+        let texts = self.tasks.iter()
+            .flat_map(|task| std::iter::once(task.description.as_str()).chain(task.notes.as_deref()));
+        let mut names: Vec<String> = Vec::new();
+        for name in texts.flat_map(placeholder_names) {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Packs that ship with rask
+    pub fn predefined_packs() -> Vec<TemplatePack> {
+        vec![
+            TemplatePack {
+                name: "Web App Starter".to_string(),
+                description: "Everything to take a web application from repository to launch".to_string(),
+                tasks: vec![
+                    PackTask::new("repo", "Set up repository and tooling for [PROJECT_NAME]", "MVP", &["setup"], &[]).priority(Priority::High).estimate(2.0),
+                    PackTask::new("ci", "Configure CI pipeline", "MVP", &["devops"], &["repo"]).estimate(3.0),
+                    PackTask::new("schema", "Design database schema", "MVP", &["backend", "database"], &["repo"]).priority(Priority::High).estimate(4.0),
+                    PackTask::new("api", "Build core REST API", "MVP", &["backend", "api"], &["schema"]).priority(Priority::High).estimate(8.0),
+                    PackTask::new("auth", "Implement user authentication", "MVP", &["backend", "security"], &["schema"]).priority(Priority::High).estimate(6.0),
+                    PackTask::new("layout", "Create frontend layout and routing", "MVP", &["frontend"], &["repo"]).estimate(4.0),
+                    PackTask::new("login-ui", "Build sign-up and login pages", "MVP", &["frontend", "security"], &["layout", "auth"]).estimate(4.0),
+                    PackTask::new("main-ui", "Build the main screens on top of the API", "MVP", &["frontend"], &["layout", "api"]).estimate(8.0),
+                    PackTask::new("unit-tests", "Write unit tests for the API", "Beta", &["testing"], &["api"]).estimate(4.0),
+                    PackTask::new("e2e-tests", "Add end-to-end tests for the main flows", "Beta", &["testing"], &["main-ui", "login-ui"]).estimate(5.0),
+                    PackTask::new("errors", "Add error tracking and logging", "Beta", &["devops", "monitoring"], &["api"]).estimate(2.0),
+                    PackTask::new("performance", "Review performance and add caching", "Beta", &["performance"], &["main-ui"]).priority(Priority::Low).estimate(4.0),
+                    PackTask::new("staging", "Deploy to a staging environment", "Release", &["devops", "deployment"], &["ci", "e2e-tests"]).priority(Priority::High).estimate(3.0),
+                    PackTask::new("docs", "Write user and API documentation", "Release", &["documentation"], &["api", "main-ui"]).estimate(3.0),
+                    PackTask::new("launch", "Launch [PROJECT_NAME] to production", "Release", &["deployment"], &["staging", "docs", "errors"]).priority(Priority::Critical).estimate(2.0),
+                ],
+            },
+            TemplatePack {
+                name: "CLI Tool Starter".to_string(),
+                description: "A command-line tool from argument parsing to a published release".to_string(),
+                tasks: vec![
+                    PackTask::new("setup", "Set up the [TOOL_NAME] project", "MVP", &["setup"], &[]).estimate(1.0),
+                    PackTask::new("args", "Define commands and argument parsing", "MVP", &["cli"], &["setup"]).priority(Priority::High).estimate(3.0),
+                    PackTask::new("core", "Implement the core commands", "MVP", &["cli"], &["args"]).priority(Priority::High).estimate(8.0),
+                    PackTask::new("errors", "Add clear error messages and exit codes", "Beta", &["cli", "ux"], &["core"]).estimate(2.0),
+                    PackTask::new("tests", "Write integration tests for the commands", "Beta", &["testing"], &["core"]).estimate(4.0),
+                    PackTask::new("docs", "Write the README and --help texts", "Release", &["documentation"], &["core"]).estimate(2.0),
+                    PackTask::new("release", "Publish the first release of [TOOL_NAME]", "Release", &["release"], &["tests", "docs", "errors"]).priority(Priority::High).estimate(2.0),
+                ],
+            },
+        ]
+    }
+}
+
 /// Represents a question to ask the user during interactive roadmap generation.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct InteractiveQuestion {
@@ -249,6 +426,7 @@ impl Default for TemplateCollection {
                     base_tasks: vec![],
                 },
             ],
+            packs: Vec::new(),
             created_at: chrono::Utc::now().to_rfc3339(),
             last_modified: chrono::Utc::now().to_rfc3339(),
         }
@@ -256,6 +434,22 @@ impl Default for TemplateCollection {
 }
 
 impl TemplateCollection {
+    /// Custom packs followed by the predefined ones they don't replace
+    pub fn all_packs(&self) -> Vec<TemplatePack> {
+        let mut packs = self.packs.clone();
+        for pack in TemplatePack::predefined_packs() {
+            if !packs.iter().any(|p| p.name.eq_ignore_ascii_case(&pack.name)) {
+                packs.push(pack);
+            }
+        }
+        packs
+    }
+
+    /// Find a pack by name, ignoring case
+    pub fn find_pack(&self, name: &str) -> Option<TemplatePack> {
+        self.all_packs().into_iter().find(|p| p.name.eq_ignore_ascii_case(name))
+    }
+
     /// Create a new template collection
     pub fn new() -> Self {
         Self::default()
@@ -289,7 +483,6 @@ impl TemplateCollection {
     }
 
     /// Get all template names
-    #[allow(dead_code)]
     pub fn get_template_names(&self) -> Vec<&String> {
         self.templates.iter().map(|t| &t.name).collect()
     }
@@ -314,6 +507,18 @@ pub enum Priority {
     Critical,
 }
 
+impl Priority {
+    /// Numeric weight for ordering tasks, higher is more important
+    pub fn rank(&self) -> u8 {
+        match self {
+            Priority::Low => 0,
+            Priority::Medium => 1,
+            Priority::High => 2,
+            Priority::Critical => 3,
+        }
+    }
+}
+
 impl Default for Priority {
     fn default() -> Self {
         Priority::Medium
@@ -331,6 +536,122 @@ impl std::fmt::Display for Priority {
     }
 }
 
+/// How much energy or focus a task needs, for picking work that fits the moment
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum Energy {
+    Low,
+    Medium,
+    High,
+}
+
+impl Energy {
+    /// Numeric weight for comparing energy levels, higher needs more focus
+    pub fn rank(&self) -> u8 {
+        match self {
+            Energy::Low => 0,
+            Energy::Medium => 1,
+            Energy::High => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for Energy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Energy::Low => write!(f, "Low"),
+            Energy::Medium => write!(f, "Medium"),
+            Energy::High => write!(f, "High"),
+        }
+    }
+}
+
+/// What kind of information an implementation note records
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum NoteKind {
+    #[default]
+    General,
+    Decision,
+    Blocker,
+    Code,
+    Link,
+}
+
+impl NoteKind {
+    /// Icon used when rendering notes of this kind
+    pub fn icon(&self) -> &'static str {
+        match self {
+            NoteKind::General => "📝",
+            NoteKind::Decision => "⚖️",
+            NoteKind::Blocker => "🚧",
+            NoteKind::Code => "💻",
+            NoteKind::Link => "🔗",
+        }
+    }
+}
+
+impl std::fmt::Display for NoteKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NoteKind::General => write!(f, "General"),
+            NoteKind::Decision => write!(f, "Decision"),
+            NoteKind::Blocker => write!(f, "Blocker"),
+            NoteKind::Code => write!(f, "Code"),
+            NoteKind::Link => write!(f, "Link"),
+        }
+    }
+}
+
+/// A typed implementation note attached to a task
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(from = "ImplementationNoteRepr")]
+pub struct ImplementationNote {
+    pub text: String,
+    pub kind: NoteKind,
+    pub created_at: Option<String>,
+}
+
+impl ImplementationNote {
+    pub fn new(text: String, kind: NoteKind) -> Self {
+        Self {
+            text,
+            kind,
+            created_at: Some(chrono::Utc::now().to_rfc3339()),
+        }
+    }
+}
+
+impl std::fmt::Display for ImplementationNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            NoteKind::General => write!(f, "{}", self.text),
+            kind => write!(f, "[{}] {}", kind, self.text),
+        }
+    }
+}
+
+/// Older state files stored implementation notes as plain strings
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ImplementationNoteRepr {
+    Plain(String),
+    Typed {
+        text: String,
+        #[serde(default)]
+        kind: NoteKind,
+        #[serde(default)]
+        created_at: Option<String>,
+    },
+}
+
+impl From<ImplementationNoteRepr> for ImplementationNote {
+    fn from(repr: ImplementationNoteRepr) -> Self {
+        match repr {
+            ImplementationNoteRepr::Plain(text) => Self { text, kind: NoteKind::General, created_at: None },
+            ImplementationNoteRepr::Typed { text, kind, created_at } => Self { text, kind, created_at },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Phase {
     pub name: String,
@@ -418,6 +739,12 @@ impl Phase {
         }
     }
     
+    /// Whether this is the phase called `name`, ignoring case. Tasks carry
+    /// the registered description and emoji, so phases are matched by name.
+    pub fn is_named(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name.trim())
+    }
+    
     /// Create a new custom phase with description and emoji
     pub fn with_details(name: String, description: Option<String>, emoji: Option<String>) -> Self {
         Phase {
@@ -570,21 +897,34 @@ impl TimeSession {
         }
     }
 
-    /// End the current session
-    pub fn end_now(&mut self) {
-        let now = chrono::Utc::now().to_rfc3339();
-        self.end_time = Some(now.clone());
+    /// Create a finished session from known start and end times
+    pub fn completed(start: chrono::DateTime<chrono::Utc>, end: chrono::DateTime<chrono::Utc>, description: Option<String>) -> Self {
+        TimeSession {
+            start_time: start.to_rfc3339(),
+            end_time: Some(end.to_rfc3339()),
+            duration_minutes: Some((end - start).num_minutes().max(0) as u32),
+            description,
+        }
+    }
+
+    /// End the session at a given time, e.g. now or trimmed for a forgotten session
+    pub fn end_at(&mut self, end: chrono::DateTime<chrono::Utc>) {
+        self.end_time = Some(end.to_rfc3339());
         
         // Calculate duration
-        if let (Ok(start), Ok(end)) = (
-            chrono::DateTime::parse_from_rfc3339(&self.start_time),
-            chrono::DateTime::parse_from_rfc3339(&now)
-        ) {
-            let duration = end - start;
-            self.duration_minutes = Some(duration.num_minutes() as u32);
+        if let Ok(start) = chrono::DateTime::parse_from_rfc3339(&self.start_time) {
+            let duration = end - start.with_timezone(&chrono::Utc);
+            self.duration_minutes = Some(duration.num_minutes().max(0) as u32);
         }
     }
 
+    /// How long the session has been running, or ran, in hours
+    pub fn elapsed_hours(&self) -> f64 {
+        let start = parse_timestamp(Some(&self.start_time));
+        let end = parse_timestamp(self.end_time.as_deref()).unwrap_or_else(chrono::Utc::now);
+        start.map_or(0.0, |start| (end - start).num_minutes().max(0) as f64 / 60.0)
+    }
+
     /// Check if session is currently active
     pub fn is_active(&self) -> bool {
         self.end_time.is_none()
@@ -596,6 +936,89 @@ impl TimeSession {
     }
 }
 
+/// Parse an optional RFC 3339 timestamp into UTC
+pub fn parse_timestamp(value: Option<&str>) -> Option<chrono::DateTime<chrono::Utc>> {
+    value
+        .and_then(|v| chrono::DateTime::parse_from_rfc3339(v).ok())
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+}
+
+/// A single entry in a task's history log
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskHistoryEntry {
+    pub timestamp: String, // ISO 8601 timestamp
+    pub action: String, // Short action identifier (e.g., "completed")
+    #[serde(default)]
+    pub details: Option<String>, // Optional human-readable details
+}
+
+impl TaskHistoryEntry {
+    /// Create a new history entry timestamped now
+    pub fn now(action: &str, details: Option<String>) -> Self {
+        TaskHistoryEntry {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            action: action.to_string(),
+            details,
+        }
+    }
+}
+
+/// A discussion comment left on a task
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskComment {
+    pub author: String, // Who wrote the comment
+    pub text: String, // Comment body
+    pub created_at: String, // ISO 8601 timestamp
+}
+
+impl TaskComment {
+    /// Create a new comment timestamped now
+    pub fn new(author: &str, text: &str) -> Self {
+        TaskComment {
+            author: author.to_string(),
+            text: text.to_string(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+fn default_sync() -> bool {
+    true
+}
+
+/// A fresh random task UUID
+pub fn new_task_uuid() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// A git commit linked to a task through a `rask:#ID` reference
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LinkedCommit {
+    pub hash: String, // Full commit hash
+    pub summary: String, // First line of the commit message
+    #[serde(default)]
+    pub committed_at: Option<String>, // ISO 8601 commit timestamp
+}
+
+/// Short AI-written digest of a task's notes, implementation notes and comments
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct NotesSummary {
+    pub text: String,
+    pub generated_at: String, // ISO 8601 timestamp
+    #[serde(default)]
+    pub model: Option<String>,
+}
+
+impl NotesSummary {
+    pub fn new(text: String, model: Option<String>) -> Self {
+        NotesSummary {
+            text,
+            generated_at: chrono::Utc::now().to_rfc3339(),
+            model,
+        }
+    }
+}
+
 /// Information about AI-generated content in tasks
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AiTaskInfo {
@@ -645,6 +1068,8 @@ impl AiTaskInfo {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Task {
     pub id: usize,
+    #[serde(default = "new_task_uuid")]
+    pub uuid: String, // Stable identifier that survives renumbering and project moves
     pub description: String,  
     pub status: TaskStatus,
     #[serde(default)]
@@ -656,7 +1081,7 @@ pub struct Task {
     #[serde(default)]
     pub notes: Option<String>,
     #[serde(default)]
-    pub implementation_notes: Vec<String>, // Detailed implementation notes, code snippets, etc.
+    pub implementation_notes: Vec<ImplementationNote>, // Detailed implementation notes, decisions, code snippets, etc.
     #[serde(default)]
     pub dependencies: Vec<usize>, // Task IDs this task depends on
     #[serde(default)]
@@ -671,12 +1096,48 @@ pub struct Task {
     pub time_sessions: Vec<TimeSession>, // Individual time tracking sessions
     #[serde(default)]
     pub ai_info: AiTaskInfo, // AI-generated content and suggestions
+    #[serde(default)]
+    pub due_date: Option<String>, // Due date (YYYY-MM-DD)
+    #[serde(default)]
+    pub acceptance_criteria: Vec<String>, // Conditions that must hold before completion
+    #[serde(default)]
+    pub history: Vec<TaskHistoryEntry>, // Chronological log of notable task events
+    #[serde(default)]
+    pub linked_commits: Vec<LinkedCommit>, // Git commits that reference this task
+    #[serde(default = "default_sync")]
+    pub sync: bool, // Whether the task is written to the shared markdown file
+    #[serde(default)]
+    pub owners: Vec<String>, // People responsible for the task
+    #[serde(default)]
+    pub comments: Vec<TaskComment>, // Discussion thread, oldest first
+    #[serde(default)]
+    pub energy: Option<Energy>, // Energy the task needs (GTD-style)
+    #[serde(default)]
+    pub contexts: Vec<String>, // Where the task can be done (e.g. "@home")
+    #[serde(default)]
+    pub dependency_notes: BTreeMap<usize, String>, // Why each dependency exists, keyed by dependency ID
+    #[serde(default)]
+    pub parent: Option<usize>, // Task this one is a subtask of
+    #[serde(default)]
+    pub reviewed_at: Option<String>, // ISO 8601 timestamp of the last `rask review` decision
+    #[serde(default)]
+    pub snoozed_until: Option<String>, // Hidden from show, list and ready until this date (YYYY-MM-DD)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub external_refs: BTreeMap<String, String>, // Keys of the linked items in other tools, by tool (e.g. "jira" → "PROJ-42")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<NotesSummary>, // Condensed notes and comments, from `rask ai summarize`
+    #[serde(skip)]
+    pub created_at_cached: Option<chrono::DateTime<chrono::Utc>>, // Parsed created_at, filled on load
+    #[serde(skip)]
+    pub completed_at_cached: Option<chrono::DateTime<chrono::Utc>>, // Parsed completed_at, filled on load
 }
 
 impl Task {
     pub fn new(id: usize, description: String) -> Self {
+        let now = chrono::Utc::now();
         Task {
             id,
+            uuid: new_task_uuid(),
             description,
             status: TaskStatus::Pending,
             tags: HashSet::new(),
@@ -685,12 +1146,29 @@ impl Task {
             notes: None,
             implementation_notes: Vec::new(),
             dependencies: Vec::new(),
-            created_at: Some(chrono::Utc::now().to_rfc3339()),
+            created_at: Some(now.to_rfc3339()),
             completed_at: None,
             estimated_hours: None,
             actual_hours: None,
             time_sessions: Vec::new(),
             ai_info: AiTaskInfo::default(),
+            due_date: None,
+            acceptance_criteria: Vec::new(),
+            history: Vec::new(),
+            linked_commits: Vec::new(),
+            sync: true,
+            owners: Vec::new(),
+            comments: Vec::new(),
+            energy: None,
+            contexts: Vec::new(),
+            dependency_notes: BTreeMap::new(),
+            parent: None,
+            reviewed_at: None,
+            snoozed_until: None,
+            external_refs: BTreeMap::new(),
+            summary: None,
+            created_at_cached: Some(now),
+            completed_at_cached: None,
         }
     }
 
@@ -719,14 +1197,81 @@ impl Task {
         self
     }
 
+    pub fn with_due_date(mut self, due_date: chrono::NaiveDate) -> Self {
+        self.due_date = Some(due_date.format("%Y-%m-%d").to_string());
+        self
+    }
+
+    /// Parsed due date, if one is set and valid
+    pub fn due_date_parsed(&self) -> Option<chrono::NaiveDate> {
+        self.due_date.as_deref()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+    }
+
+    /// Most recent sign of work on the task: creation, completion, a time
+    /// session, a history entry, or a review
+    pub fn last_activity(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let history = self.history.iter().map(|entry| entry.timestamp.as_str());
+        let sessions = self.time_sessions.iter()
+            .flat_map(|session| std::iter::once(session.start_time.as_str()).chain(session.end_time.as_deref()));
+        history.chain(sessions)
+            .chain(self.reviewed_at.as_deref())
+            .filter_map(|timestamp| parse_timestamp(Some(timestamp)))
+            .chain(self.created_datetime())
+            .chain(self.completed_datetime())
+            .max()
+    }
+
+    /// Whole days since the last activity, if any is known
+    pub fn idle_days(&self, now: chrono::DateTime<chrono::Utc>) -> Option<i64> {
+        self.last_activity().map(|at| (now - at).num_days())
+    }
+
+    /// Whether the task is pending and has been idle for at least `days` days
+    pub fn is_stale(&self, days: i64) -> bool {
+        self.status == TaskStatus::Pending
+            && self.idle_days(chrono::Utc::now()).is_some_and(|idle| idle >= days)
+    }
+
+    /// Whether the task is snoozed on `today`; it shows up again on the snooze date
+    pub fn is_snoozed(&self, today: chrono::NaiveDate) -> bool {
+        self.snoozed_until.as_deref()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .is_some_and(|until| until > today)
+    }
+
+    pub fn with_acceptance_criteria(mut self, criteria: Vec<String>) -> Self {
+        self.acceptance_criteria = criteria;
+        self
+    }
+
     pub fn mark_completed(&mut self) {
+        let now = chrono::Utc::now();
         self.status = TaskStatus::Completed;
-        self.completed_at = Some(chrono::Utc::now().to_rfc3339());
+        self.completed_at = Some(now.to_rfc3339());
+        self.completed_at_cached = Some(now);
     }
 
     pub fn mark_pending(&mut self) {
         self.status = TaskStatus::Pending;
         self.completed_at = None;
+        self.completed_at_cached = None;
+    }
+
+    /// Re-parse the RFC 3339 timestamp strings into the in-memory cache
+    pub fn refresh_timestamp_cache(&mut self) {
+        self.created_at_cached = parse_timestamp(self.created_at.as_deref());
+        self.completed_at_cached = parse_timestamp(self.completed_at.as_deref());
+    }
+
+    /// Creation time, from the cache when available
+    pub fn created_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.created_at_cached.or_else(|| parse_timestamp(self.created_at.as_deref()))
+    }
+
+    /// Completion time, from the cache when available
+    pub fn completed_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.completed_at_cached.or_else(|| parse_timestamp(self.completed_at.as_deref()))
     }
 
     #[allow(dead_code)]
@@ -743,15 +1288,65 @@ impl Task {
         self.tags.contains(tag)
     }
 
+    /// Whether the task has `tag` or a tag nested under it (`area/backend` for `area`)
+    pub fn has_tag_within(&self, tag: &str) -> bool {
+        self.tags.iter().any(|own| tag_within(own, tag))
+    }
+
+    /// Whether the description, a tag or the notes contain an already lowercased query
+    pub fn matches_search(&self, query_lower: &str) -> bool {
+        self.description.to_lowercase().contains(query_lower)
+            || self.tags.iter().any(|tag| tag.to_lowercase().contains(query_lower))
+            || self.notes.as_ref().map_or(false, |notes| notes.to_lowercase().contains(query_lower))
+    }
+
+    /// Whether the given person is one of the task's owners (case-insensitive)
+    pub fn has_owner(&self, name: &str) -> bool {
+        self.owners.iter().any(|owner| owner.eq_ignore_ascii_case(name))
+    }
+
+    /// Whether the task can be done with the given energy and in the given context.
+    ///
+    /// Tasks without an energy level or contexts are treated as fitting anywhere.
+    pub fn fits(&self, energy: Option<Energy>, context: Option<&str>) -> bool {
+        let energy_ok = match (energy, self.energy) {
+            (Some(available), Some(needed)) => needed.rank() <= available.rank(),
+            _ => true,
+        };
+        let context_ok = match context {
+            Some(context) if !self.contexts.is_empty() => {
+                self.contexts.iter().any(|c| c.eq_ignore_ascii_case(context))
+            }
+            _ => true,
+        };
+        energy_ok && context_ok
+    }
+
+    /// Add owners not already assigned, returning how many were added
+    pub fn add_owners(&mut self, owners: &[String]) -> usize {
+        let mut added = 0;
+        for owner in owners {
+            if !self.has_owner(owner) {
+                self.owners.push(owner.clone());
+                added += 1;
+            }
+        }
+        added
+    }
+
     pub fn can_be_started(&self, completed_tasks: &HashSet<usize>) -> bool {
         self.dependencies.iter().all(|dep_id| completed_tasks.contains(dep_id))
     }
 
     pub fn add_implementation_note(&mut self, note: String) {
-        self.implementation_notes.push(note);
+        self.add_typed_implementation_note(note, NoteKind::General);
+    }
+
+    pub fn add_typed_implementation_note(&mut self, note: String, kind: NoteKind) {
+        self.implementation_notes.push(ImplementationNote::new(note, kind));
     }
 
-    pub fn remove_implementation_note(&mut self, index: usize) -> Option<String> {
+    pub fn remove_implementation_note(&mut self, index: usize) -> Option<ImplementationNote> {
         if index < self.implementation_notes.len() {
             Some(self.implementation_notes.remove(index))
         } else {
@@ -768,7 +1363,99 @@ impl Task {
         !self.implementation_notes.is_empty()
     }
 
+    // History methods
+    pub fn record_history(&mut self, action: &str, details: Option<String>) {
+        self.history.push(TaskHistoryEntry::now(action, details));
+    }
+
+    /// Log the changes made since `before` (the saved copy of this task).
+    ///
+    /// Entries already in the saved history are kept even when this copy was
+    /// loaded before they were written, and a change the command already
+    /// logged itself (e.g. "completed" with the criteria checklist) is not
+    /// logged twice. Dependencies are compared by task UUID, looked up in
+    /// `uuids` (current IDs) and `before_uuids` (saved IDs), so renumbering
+    /// after a removal is not a change.
+    pub fn record_changes_since(&mut self, before: &Task, uuids: &HashMap<usize, String>, before_uuids: &HashMap<usize, String>) {
+        let same = |a: &TaskHistoryEntry, b: &TaskHistoryEntry| {
+            a.timestamp == b.timestamp && a.action == b.action && a.details == b.details
+        };
+        let new_entries: Vec<TaskHistoryEntry> = self.history.iter()
+            .filter(|entry| !before.history.iter().any(|old| same(old, entry)))
+            .cloned()
+            .collect();
+        // Escalations log their own priority and tag changes
+        let logged = |action: &str| new_entries.iter().any(|entry| {
+            entry.action == action || (entry.action == "escalated" && matches!(action, "priority_changed" | "tags_changed"))
+        });
+        
+        let mut changes: Vec<(&str, Option<String>)> = Vec::new();
+        if self.status != before.status {
+            changes.push(match self.status {
+                TaskStatus::Completed => ("completed", None),
+                TaskStatus::Pending => ("reopened", None),
+            });
+        }
+        if self.description != before.description {
+            changes.push(("edited", Some(format!("was: {}", before.description))));
+        }
+        if self.tags != before.tags {
+            let mut diff: Vec<String> = self.tags.difference(&before.tags).map(|t| format!("+{}", t))
+                .chain(before.tags.difference(&self.tags).map(|t| format!("-{}", t)))
+                .collect();
+            diff.sort_by(|a, b| a[1..].cmp(&b[1..]));
+            changes.push(("tags_changed", Some(diff.join(" "))));
+        }
+        if self.phase.name != before.phase.name {
+            changes.push(("phase_changed", Some(format!("{} → {}", before.phase.name, self.phase.name))));
+        }
+        if self.priority != before.priority {
+            changes.push(("priority_changed", Some(format!("{} → {}", before.priority, self.priority))));
+        }
+        if self.due_date != before.due_date {
+            let due = |date: &Option<String>| date.clone().unwrap_or_else(|| "none".to_string());
+            changes.push(("due_changed", Some(format!("{} → {}", due(&before.due_date), due(&self.due_date)))));
+        }
+        let uuid_of = |map: &HashMap<usize, String>, id: &usize| map.get(id).cloned().unwrap_or_else(|| format!("#{}", id));
+        let now: HashSet<String> = self.dependencies.iter().map(|id| uuid_of(uuids, id)).collect();
+        let was: HashSet<String> = before.dependencies.iter().map(|id| uuid_of(before_uuids, id)).collect();
+        if now != was {
+            let diff: Vec<String> = self.dependencies.iter().filter(|id| !was.contains(&uuid_of(uuids, id))).map(|id| format!("+#{}", id))
+                .chain(before.dependencies.iter().filter(|id| !now.contains(&uuid_of(before_uuids, id))).map(|id| format!("-#{}", id)))
+                .collect();
+            changes.push(("dependencies_changed", Some(diff.join(" "))));
+        }
+        if self.estimated_hours != before.estimated_hours {
+            let hours = |h: Option<f64>| h.map_or("none".to_string(), |h| format!("{:.1}h", h));
+            changes.push(("estimated", Some(format!("{} → {}", hours(before.estimated_hours), hours(self.estimated_hours)))));
+        }
+        if self.notes != before.notes {
+            changes.push(("notes_edited", None));
+        }
+        if self.owners != before.owners {
+            changes.push(("owners_changed", Some(self.owners.join(", "))));
+        }
+        
+        // Start from the saved history so entries written since this copy was loaded survive
+        self.history = before.history.clone();
+        self.history.extend(new_entries.iter().cloned());
+        for (action, details) in changes {
+            if !logged(action) {
+                self.record_history(action, details);
+            }
+        }
+    }
+
     // Time tracking methods
+    /// Link a commit to this task, returning false if it was already linked
+    pub fn link_commit(&mut self, commit: LinkedCommit) -> bool {
+        if self.linked_commits.iter().any(|c| c.hash == commit.hash) {
+            return false;
+        }
+        self.linked_commits.push(commit);
+        true
+    }
+
     pub fn set_estimated_hours(&mut self, hours: f64) {
         self.estimated_hours = Some(hours);
     }
@@ -785,12 +1472,17 @@ impl Task {
     }
 
     pub fn end_current_time_session(&mut self) -> Result<f64, String> {
+        self.end_current_time_session_at(chrono::Utc::now())
+    }
+
+    /// End the active session at the given time instead of now
+    pub fn end_current_time_session_at(&mut self, end: chrono::DateTime<chrono::Utc>) -> Result<f64, String> {
         // Find the active session index
         let active_index = self.time_sessions.iter().position(|s| s.is_active());
         
         if let Some(index) = active_index {
             // End the session
-            self.time_sessions[index].end_now();
+            self.time_sessions[index].end_at(end);
             
             // Update actual hours
             self.update_actual_hours();
@@ -802,11 +1494,43 @@ impl Task {
         }
     }
 
+    /// Whether a session already starts within a minute of the given time.
+    /// External trackers round timestamps, so exact equality is too strict.
+    pub fn has_session_starting_near(&self, start: chrono::DateTime<chrono::Utc>) -> bool {
+        self.time_sessions.iter()
+            .filter_map(|s| parse_timestamp(Some(&s.start_time)))
+            .any(|existing| (existing - start).num_seconds().abs() < 60)
+    }
+
+    /// Add a finished session (e.g. from an external time tracker), skipping
+    /// sessions that start at the same moment as an existing one
+    pub fn add_completed_time_session(&mut self, session: TimeSession) -> bool {
+        let Some(start) = parse_timestamp(Some(&session.start_time)) else {
+            return false;
+        };
+        if self.has_session_starting_near(start) {
+            return false;
+        }
+        self.time_sessions.push(session);
+        self.time_sessions.sort_by_key(|s| parse_timestamp(Some(&s.start_time)));
+        self.update_actual_hours();
+        true
+    }
+
+    /// Replace a recorded session, keeping sessions ordered by start and totals current
+    pub fn replace_time_session(&mut self, index: usize, session: TimeSession) -> Result<(), String> {
+        let slot = self.time_sessions.get_mut(index)
+            .ok_or_else(|| format!("Task has no time session #{}", index))?;
+        *slot = session;
+        self.time_sessions.sort_by_key(|s| parse_timestamp(Some(&s.start_time)));
+        self.update_actual_hours();
+        Ok(())
+    }
+
     pub fn has_active_time_session(&self) -> bool {
         self.time_sessions.iter().any(|s| s.is_active())
     }
 
-    #[allow(dead_code)]
     pub fn get_active_time_session(&self) -> Option<&TimeSession> {
         self.time_sessions.iter().find(|s| s.is_active())
     }
@@ -859,6 +1583,16 @@ impl Task {
         self.ai_info.ai_generated
     }
     
+    /// Whether implementation notes or comments were added after the summary was written
+    pub fn summary_is_stale(&self) -> bool {
+        let Some(generated_at) = self.summary.as_ref().and_then(|summary| parse_timestamp(Some(&summary.generated_at))) else {
+            return false;
+        };
+        self.implementation_notes.iter().filter_map(|note| parse_timestamp(note.created_at.as_deref()))
+            .chain(self.comments.iter().filter_map(|comment| parse_timestamp(Some(&comment.created_at))))
+            .any(|at| at > generated_at)
+    }
+    
     pub fn get_ai_operation(&self) -> Option<&String> {
         self.ai_info.ai_operation.as_ref()
     }
@@ -880,6 +1614,26 @@ pub struct ProjectMetadata {
     pub created_at: String,
     pub last_modified: String,
     pub version: String,
+    /// Phase of tasks outside any `## Phase` section of the roadmap file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_phase: Option<String>,
+    /// Project-wide tags
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Overall time estimate for the project, in hours
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_hours: Option<f64>,
+}
+
+impl ProjectMetadata {
+    /// Whether the roadmap file needs a frontmatter block to carry this metadata
+    pub fn has_frontmatter(&self, title: &str) -> bool {
+        self.name != title
+            || self.description.is_some()
+            || self.default_phase.is_some()
+            || !self.tags.is_empty()
+            || self.estimated_hours.is_some()
+    }
 }
 
 impl Default for ProjectMetadata {
@@ -890,12 +1644,21 @@ impl Default for ProjectMetadata {
             created_at: chrono::Utc::now().to_rfc3339(),
             last_modified: chrono::Utc::now().to_rfc3339(),
             version: "1.0.0".to_string(),
+            default_phase: None,
+            tags: Vec::new(),
+            estimated_hours: None,
         }
     }
 }
 
+/// Layout version of the state files this build writes; older files are
+/// upgraded on load by the migrations in `state.rs`
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Roadmap {
+    #[serde(default)]
+    pub schema_version: u32, // 0 for state files written before versioning
     pub title: String,
     pub tasks: Vec<Task>,
     #[serde(default)]
@@ -904,6 +1667,295 @@ pub struct Roadmap {
     pub metadata: ProjectMetadata,
     #[serde(default)]
     pub project_id: Option<String>, // Unique identifier for multi-project support
+    #[serde(default)]
+    pub archived_phases: Vec<ArchivedPhaseSummary>, // Phases moved to .rask/archive/
+    #[serde(default)]
+    pub phase_limits: BTreeMap<String, usize>, // Maximum open tasks per phase name (WIP limits)
+    #[serde(default)]
+    pub phases: Vec<PhaseEntry>, // Registered phases in display order
+    #[serde(default)]
+    pub milestones: Vec<Milestone>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub last_task_id: usize, // Highest ID handed out with behavior.stable_ids, so removed IDs are never reused
+    #[serde(skip)]
+    pub revision: Option<String>, // Save revision of the stored state this was loaded from, checked by state::save_state
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+/// Whether `behavior.stable_ids` is set: tasks keep their IDs for good and
+/// removing or reordering tasks leaves gaps instead of renumbering. The config
+/// is read once per process, not on every renumber.
+pub fn stable_ids() -> bool {
+    static STABLE_IDS: OnceLock<bool> = OnceLock::new();
+    *STABLE_IDS.get_or_init(|| crate::config::RaskConfig::load().is_ok_and(|config| config.behavior.stable_ids))
+}
+
+/// A dated goal tracked by the tasks of a phase, a set of tags, or both
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Milestone {
+    pub name: String,
+    pub target_date: String, // YYYY-MM-DD
+    #[serde(default)]
+    pub phase: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>, // Tasks with any of these tags, or tags nested under them, belong to the milestone
+    #[serde(default)]
+    pub description: Option<String>,
+    pub created_at: String,
+}
+
+/// How a milestone is doing against its target date
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MilestoneStatus {
+    Done,
+    OnTrack,
+    AtRisk,
+    Overdue,
+}
+
+/// Progress of a milestone, computed from its tasks
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneProgress {
+    pub name: String,
+    pub target_date: String,
+    pub total_tasks: usize,
+    pub completed_tasks: usize,
+    pub completion_percentage: usize,
+    pub days_left: i64,
+    pub status: MilestoneStatus,
+}
+
+impl Milestone {
+    pub fn target(&self) -> Option<chrono::NaiveDate> {
+        chrono::NaiveDate::parse_from_str(&self.target_date, "%Y-%m-%d").ok()
+    }
+
+    /// Whether a task counts towards this milestone
+    pub fn includes(&self, task: &Task) -> bool {
+        self.phase.as_ref().is_none_or(|phase| task.phase.name.eq_ignore_ascii_case(phase))
+            && (self.tags.is_empty() || self.tags.iter().any(|tag| task.has_tag_within(tag)))
+    }
+
+    /// Progress as of `today`. A milestone is at risk when a larger share of the
+    /// time from its creation to its target has passed than of its tasks is done.
+    pub fn progress(&self, roadmap: &Roadmap, today: chrono::NaiveDate) -> MilestoneProgress {
+        let tasks: Vec<&Task> = roadmap.tasks.iter().filter(|task| self.includes(task)).collect();
+        // Archived phases only hold completed tasks
+        let archived: usize = match (&self.phase, self.tags.is_empty()) {
+            (Some(phase), true) => roadmap.archived_phases.iter()
+                .filter(|a| a.phase.eq_ignore_ascii_case(phase))
+                .map(|a| a.total_tasks)
+                .sum(),
+            _ => 0,
+        };
+        let total = tasks.len() + archived;
+        let completed = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count() + archived;
+        let percentage = (completed * 100).checked_div(total).unwrap_or(0);
+
+        let target = self.target().unwrap_or(today);
+        let days_left = (target - today).num_days();
+        let status = if total > 0 && completed == total {
+            MilestoneStatus::Done
+        } else if days_left < 0 {
+            MilestoneStatus::Overdue
+        } else {
+            let created = chrono::DateTime::parse_from_rfc3339(&self.created_at)
+                .map(|d| d.date_naive())
+                .unwrap_or(today);
+            let span = (target - created).num_days().max(1) as f64;
+            let elapsed = ((today - created).num_days() as f64 / span).clamp(0.0, 1.0);
+            let done = if total > 0 { completed as f64 / total as f64 } else { 0.0 };
+            if elapsed > done { MilestoneStatus::AtRisk } else { MilestoneStatus::OnTrack }
+        };
+
+        MilestoneProgress {
+            name: self.name.clone(),
+            target_date: self.target_date.clone(),
+            total_tasks: total,
+            completed_tasks: completed,
+            completion_percentage: percentage,
+            days_left,
+            status,
+        }
+    }
+}
+
+/// Whether `tag` is `namespace` itself or nested under it. Tags are
+/// hierarchical with `/` separators, so `area/backend/auth` is within
+/// `area/backend` and `area`, but not within `area/back`.
+pub fn tag_within(tag: &str, namespace: &str) -> bool {
+    let namespace = namespace.trim_end_matches('/');
+    tag == namespace || tag.strip_prefix(namespace).is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Top-level namespace of a tag: `area` for `area/backend/auth`
+pub fn tag_root(tag: &str) -> &str {
+    tag.split('/').next().unwrap_or(tag)
+}
+
+/// A phase registered with the project, kept in `Roadmap::phases` in display order
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PhaseEntry {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub emoji: Option<String>,
+    #[serde(default)]
+    pub archived: bool, // Hidden from phase listings and the overview
+}
+
+impl PhaseEntry {
+    pub fn from_phase(phase: &Phase) -> Self {
+        PhaseEntry {
+            name: phase.name.clone(),
+            description: phase.description.clone(),
+            emoji: phase.emoji.clone(),
+            archived: false,
+        }
+    }
+
+    pub fn to_phase(&self) -> Phase {
+        let base = Phase::from_string(&self.name);
+        Phase::with_details(
+            base.name,
+            self.description.clone().or(base.description),
+            self.emoji.clone().or(base.emoji),
+        )
+    }
+}
+
+/// Summary statistics kept in state for a phase whose tasks were archived
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ArchivedPhaseSummary {
+    pub phase: String,
+    pub archived_at: String,
+    pub total_tasks: usize,
+    pub estimated_hours: f64,
+    pub actual_hours: f64,
+    pub task_ids: Vec<usize>, // IDs the tasks had when they were archived
+}
+
+/// Full task data for an archived phase, stored in `.rask/archive/<phase>.json`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PhaseArchive {
+    pub roadmap_title: String,
+    pub summary: ArchivedPhaseSummary,
+    pub tasks: Vec<Task>,
+}
+
+impl PhaseArchive {
+    /// File-system friendly name for the archive files of a phase
+    pub fn file_stem(phase: &str) -> String {
+        let stem: String = phase.trim().to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        stem.trim_matches('-').to_string()
+    }
+
+    /// Fold an earlier archive of the same phase into this one
+    pub fn absorb(&mut self, previous: PhaseArchive) {
+        let mut tasks = previous.tasks;
+        tasks.append(&mut self.tasks);
+        self.tasks = tasks;
+        
+        let mut task_ids = previous.summary.task_ids;
+        task_ids.append(&mut self.summary.task_ids);
+        self.summary.task_ids = task_ids;
+        self.summary.total_tasks += previous.summary.total_tasks;
+        self.summary.estimated_hours += previous.summary.estimated_hours;
+        self.summary.actual_hours += previous.summary.actual_hours;
+    }
+}
+
+/// Lightweight copy of the fields read-only commands need, stored in `.rask/index.json`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TaskSummary {
+    pub id: usize,
+    pub description: String,
+    pub status: TaskStatus,
+    pub priority: Priority,
+    pub phase: Phase,
+    #[serde(default)]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub dependencies: Vec<usize>,
+    #[serde(default)]
+    pub ai_generated: bool,
+    #[serde(default)]
+    pub snoozed_until: Option<String>,
+    /// Start of the running time session, if any
+    #[serde(default)]
+    pub active_session_start: Option<String>,
+}
+
+impl TaskSummary {
+    pub fn from_task(task: &Task) -> Self {
+        let mut tags: Vec<String> = task.tags.iter().cloned().collect();
+        tags.sort();
+        TaskSummary {
+            id: task.id,
+            description: task.description.clone(),
+            status: task.status.clone(),
+            priority: task.priority.clone(),
+            phase: task.phase.clone(),
+            due_date: task.due_date.clone(),
+            tags,
+            dependencies: task.dependencies.clone(),
+            ai_generated: task.is_ai_generated(),
+            snoozed_until: task.snoozed_until.clone(),
+            active_session_start: task.get_active_time_session().map(|session| session.start_time.clone()),
+        }
+    }
+}
+
+/// Summary index persisted next to the state so read-only commands can skip
+/// deserializing sessions, notes and AI details
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StateIndex {
+    pub title: String,
+    pub tasks: Vec<TaskSummary>,
+}
+
+impl StateIndex {
+    pub fn from_roadmap(roadmap: &Roadmap) -> Self {
+        StateIndex {
+            title: roadmap.title.clone(),
+            tasks: roadmap.tasks.iter().map(TaskSummary::from_task).collect(),
+        }
+    }
+
+    /// Build a roadmap holding only the indexed fields, for listing and filtering
+    pub fn into_roadmap(self) -> Roadmap {
+        let mut roadmap = Roadmap::new(self.title);
+        roadmap.tasks = self.tasks.into_iter()
+            .map(|summary| {
+                let mut task = Task::new(summary.id, summary.description)
+                    .with_tags(summary.tags)
+                    .with_priority(summary.priority)
+                    .with_phase(summary.phase)
+                    .with_dependencies(summary.dependencies);
+                task.status = summary.status;
+                task.due_date = summary.due_date;
+                task.ai_info.ai_generated = summary.ai_generated;
+                task.snoozed_until = summary.snoozed_until;
+                task.time_sessions = summary.active_session_start.into_iter()
+                    .map(|start_time| TimeSession { start_time, end_time: None, duration_minutes: None, description: None })
+                    .collect();
+                task.created_at = None;
+                task.created_at_cached = None;
+                task
+            })
+            .collect();
+        roadmap
+    }
 }
 
 impl Roadmap {
@@ -912,11 +1964,18 @@ impl Roadmap {
         metadata.name = title.clone();
         
         Roadmap {
+            schema_version: CURRENT_SCHEMA_VERSION,
             title,
             tasks: Vec::new(),
             source_file: None,
             metadata,
             project_id: None,
+            archived_phases: Vec::new(),
+            phase_limits: BTreeMap::new(),
+            phases: Vec::new(),
+            milestones: Vec::new(),
+            last_task_id: 0,
+            revision: None,
         }
     }
 
@@ -932,17 +1991,39 @@ impl Roadmap {
     }
 
     pub fn get_next_task_id(&self) -> usize {
-        self.tasks.iter().map(|t| t.id).max().unwrap_or(0) + 1
+        self.tasks.iter().map(|t| t.id).max().unwrap_or(0).max(self.last_task_id) + 1
     }
 
     pub fn find_task_by_id(&self, id: usize) -> Option<&Task> {
         self.tasks.iter().find(|t| t.id == id)
     }
 
+    /// Log every task change since `before` (the saved state) in the task
+    /// histories; tasks that are new and have no history yet get "created"
+    pub fn record_activity_since(&mut self, before: &Roadmap) {
+        let saved: HashMap<&str, &Task> = before.tasks.iter().map(|t| (t.uuid.as_str(), t)).collect();
+        let uuids: HashMap<usize, String> = self.tasks.iter().map(|t| (t.id, t.uuid.clone())).collect();
+        let before_uuids: HashMap<usize, String> = before.tasks.iter().map(|t| (t.id, t.uuid.clone())).collect();
+        for task in &mut self.tasks {
+            match saved.get(task.uuid.as_str()) {
+                Some(old) => task.record_changes_since(old, &uuids, &before_uuids),
+                None if task.history.is_empty() => task.record_history("created", None),
+                None => {}
+            }
+        }
+    }
+
     pub fn find_task_by_id_mut(&mut self, id: usize) -> Option<&mut Task> {
         self.tasks.iter_mut().find(|t| t.id == id)
     }
 
+    /// Parse every task's timestamps once so display and analytics loops can reuse them
+    pub fn refresh_timestamp_cache(&mut self) {
+        for task in &mut self.tasks {
+            task.refresh_timestamp_cache();
+        }
+    }
+
     pub fn get_completed_task_ids(&self) -> HashSet<usize> {
         self.tasks
             .iter()
@@ -953,6 +2034,10 @@ impl Roadmap {
 
     pub fn add_task(&mut self, mut task: Task) {
         task.id = self.get_next_task_id();
+        // A task imported twice must not share its UUID with the first copy
+        if self.tasks.iter().any(|t| t.uuid == task.uuid) {
+            task.uuid = new_task_uuid();
+        }
         self.tasks.push(task);
         self.update_last_modified();
     }
@@ -960,7 +2045,16 @@ impl Roadmap {
     pub fn remove_task(&mut self, id: usize) -> Option<Task> {
         if let Some(pos) = self.tasks.iter().position(|t| t.id == id) {
             let removed_task = self.tasks.remove(pos);
-            // Renumber tasks to maintain sequential IDs
+            for task in &mut self.tasks {
+                task.dependencies.retain(|dep| *dep != id);
+                task.dependency_notes.remove(&id);
+                // Subtasks of the removed task become top-level tasks
+                if task.parent == Some(id) {
+                    task.parent = None;
+                }
+            }
+            // Renumber tasks to maintain sequential IDs, unless IDs are stable
+            self.last_task_id = self.last_task_id.max(id);
             self.renumber_tasks();
             self.update_last_modified();
             Some(removed_task)
@@ -969,14 +2063,198 @@ impl Roadmap {
         }
     }
 
+    /// Fold task `merge_id` into task `keep_id` and remove it. Tags, owners,
+    /// acceptance criteria and dependencies are combined, notes,
+    /// implementation notes, comments, linked commits and time sessions
+    /// appended, and tasks that depended on or were subtasks of the merged
+    /// task now point at the kept one. The merge is logged in the kept task's
+    /// history. Nothing changes when the result would have a dependency cycle
+    /// or the merged task's timer is running. Returns the removed task; the
+    /// kept task may be renumbered, so look it up again by UUID.
+    pub fn merge_tasks(&mut self, keep_id: usize, merge_id: usize) -> Result<Task, String> {
+        if keep_id == merge_id {
+            return Err("A task cannot be merged into itself".to_string());
+        }
+        let merged = self.find_task_by_id(merge_id).cloned()
+            .ok_or_else(|| format!("Task #{} not found", merge_id))?;
+        if self.find_task_by_id(keep_id).is_none() {
+            return Err(format!("Task #{} not found", keep_id));
+        }
+        if merged.has_active_time_session() {
+            return Err(format!("Task #{} has a running timer; stop it with 'rask stop' first", merge_id));
+        }
+
+        let mut result = self.clone();
+        for task in result.tasks.iter_mut().filter(|t| t.id != keep_id && t.id != merge_id) {
+            if task.dependencies.contains(&merge_id) {
+                task.dependencies.retain(|dep| *dep != merge_id);
+                if !task.dependencies.contains(&keep_id) {
+                    task.dependencies.push(keep_id);
+                }
+                if let Some(note) = task.dependency_notes.remove(&merge_id) {
+                    task.dependency_notes.entry(keep_id).or_insert(note);
+                }
+            }
+            if task.parent == Some(merge_id) {
+                task.parent = Some(keep_id);
+            }
+        }
+
+        let keep = result.tasks.iter_mut().find(|t| t.id == keep_id)
+            .ok_or_else(|| format!("Task #{} not found", keep_id))?;
+        keep.tags.extend(merged.tags.iter().cloned());
+        keep.notes = match (keep.notes.take(), &merged.notes) {
+            (Some(notes), Some(other)) if !other.trim().is_empty() => Some(format!("{}\n\n{}", notes, other)),
+            (notes, other) => notes.or_else(|| other.clone()),
+        };
+        keep.implementation_notes.extend(merged.implementation_notes.iter().cloned());
+        keep.comments.extend(merged.comments.iter().cloned());
+        keep.comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        keep.add_owners(&merged.owners);
+        for criterion in &merged.acceptance_criteria {
+            if !keep.acceptance_criteria.contains(criterion) {
+                keep.acceptance_criteria.push(criterion.clone());
+            }
+        }
+        for commit in &merged.linked_commits {
+            if !keep.linked_commits.iter().any(|c| c.hash == commit.hash) {
+                keep.linked_commits.push(commit.clone());
+            }
+        }
+        keep.time_sessions.extend(merged.time_sessions.iter().cloned());
+        keep.time_sessions.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+        keep.actual_hours = match (keep.actual_hours, merged.actual_hours) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0.0) + b.unwrap_or(0.0)),
+        };
+        keep.dependencies.retain(|dep| *dep != merge_id);
+        keep.dependency_notes.remove(&merge_id);
+        for dep in merged.dependencies.iter().filter(|dep| **dep != keep_id) {
+            if !keep.dependencies.contains(dep) {
+                keep.dependencies.push(*dep);
+            }
+            if let Some(note) = merged.dependency_notes.get(dep) {
+                keep.dependency_notes.entry(*dep).or_insert_with(|| note.clone());
+            }
+        }
+        if keep.parent == Some(merge_id) {
+            keep.parent = merged.parent.filter(|parent| *parent != keep_id);
+        }
+        keep.record_history("merged", Some(format!("#{} {}", merge_id, merged.description)));
+
+        // Any new cycle runs through the kept task, the only one whose edges grew
+        if let Err(cycle) = result.check_circular_dependencies_for_task(keep_id) {
+            let cycle: Vec<String> = cycle.iter().map(|id| format!("#{}", id)).collect();
+            return Err(format!("Merging would create a dependency cycle: {}", cycle.join(" → ")));
+        }
+
+        let removed = result.remove_task(merge_id)
+            .ok_or_else(|| format!("Task #{} not found", merge_id))?;
+        *self = result;
+        Ok(removed)
+    }
+
+    /// Move every task of a phase out of the roadmap and record its summary.
+    /// Dependencies on the archived tasks are dropped since they are all
+    /// completed, and the remaining tasks are renumbered.
+    pub fn archive_phase(&mut self, phase_name: &str) -> PhaseArchive {
+        let (archived, remaining): (Vec<Task>, Vec<Task>) = std::mem::take(&mut self.tasks)
+            .into_iter()
+            .partition(|t| t.phase.name.eq_ignore_ascii_case(phase_name));
+        self.tasks = remaining;
+        
+        let task_ids: Vec<usize> = archived.iter().map(|t| t.id).collect();
+        for task in &mut self.tasks {
+            task.dependencies.retain(|dep| !task_ids.contains(dep));
+            task.dependency_notes.retain(|dep, _| !task_ids.contains(dep));
+            if task.parent.is_some_and(|parent| task_ids.contains(&parent)) {
+                task.parent = None;
+            }
+        }
+        self.last_task_id = task_ids.iter().copied().fold(self.last_task_id, usize::max);
+        self.renumber_tasks();
+        
+        let summary = ArchivedPhaseSummary {
+            phase: archived.first().map(|t| t.phase.name.clone()).unwrap_or_else(|| phase_name.to_string()),
+            archived_at: chrono::Utc::now().to_rfc3339(),
+            total_tasks: archived.len(),
+            estimated_hours: archived.iter().filter_map(|t| t.estimated_hours).sum(),
+            actual_hours: archived.iter().filter_map(|t| t.actual_hours).sum(),
+            task_ids,
+        };
+        self.archived_phases.retain(|a| !a.phase.eq_ignore_ascii_case(&summary.phase));
+        self.archived_phases.push(summary.clone());
+        self.update_last_modified();
+        
+        PhaseArchive {
+            roadmap_title: self.title.clone(),
+            summary,
+            tasks: archived,
+        }
+    }
+
+    /// Carry task UUIDs over from an earlier version of this roadmap,
+    /// matching tasks by description like `keep_ids_from`
+    pub fn keep_uuids_from(&mut self, previous: &Roadmap) {
+        let mut previous_uuids: HashMap<&str, std::collections::VecDeque<&str>> = HashMap::new();
+        for task in &previous.tasks {
+            previous_uuids.entry(task.description.as_str()).or_default().push_back(task.uuid.as_str());
+        }
+        for task in &mut self.tasks {
+            if let Some(uuid) = previous_uuids.get_mut(task.description.as_str()).and_then(|uuids| uuids.pop_front()) {
+                task.uuid = uuid.to_string();
+            }
+        }
+    }
+
+    /// Carry task IDs over from an earlier version of this roadmap, such as
+    /// the state before the markdown was parsed again. Tasks are matched by
+    /// description; unmatched tasks get IDs that were never used, and
+    /// dependencies and parents follow the new IDs.
+    pub fn keep_ids_from(&mut self, previous: &Roadmap) {
+        let mut previous_ids: HashMap<&str, std::collections::VecDeque<usize>> = HashMap::new();
+        for task in &previous.tasks {
+            previous_ids.entry(task.description.as_str()).or_default().push_back(task.id);
+        }
+        
+        let mut next_id = previous.get_next_task_id();
+        let mut id_mappings = HashMap::new();
+        for task in &mut self.tasks {
+            let new_id = previous_ids.get_mut(task.description.as_str())
+                .and_then(|ids| ids.pop_front())
+                .unwrap_or_else(|| {
+                    next_id += 1;
+                    next_id - 1
+                });
+            id_mappings.insert(task.id, new_id);
+            task.id = new_id;
+        }
+        
+        for task in &mut self.tasks {
+            task.dependencies = task.dependencies.iter().filter_map(|dep| id_mappings.get(dep).copied()).collect();
+            task.dependency_notes = std::mem::take(&mut task.dependency_notes).into_iter()
+                .filter_map(|(dep, note)| id_mappings.get(&dep).map(|new_dep| (*new_dep, note)))
+                .collect();
+            task.parent = task.parent.and_then(|parent| id_mappings.get(&parent).copied());
+        }
+        self.last_task_id = next_id - 1;
+    }
+
+    /// Give tasks sequential IDs in their current order, updating every
+    /// reference. With `behavior.stable_ids` IDs are kept and gaps stay.
     fn renumber_tasks(&mut self) {
+        if stable_ids() {
+            return;
+        }
+        self.last_task_id = 0;
+        
         // First pass: collect ID mappings
-        let mut id_mappings = Vec::new();
+        let mut id_mappings = HashMap::new();
         for (index, task) in self.tasks.iter().enumerate() {
             let old_id = task.id;
             let new_id = index + 1;
             if old_id != new_id {
-                id_mappings.push((old_id, new_id));
+                id_mappings.insert(old_id, new_id);
             }
         }
         
@@ -985,21 +2263,134 @@ impl Roadmap {
             task.id = index + 1;
         }
         
-        // Third pass: update dependencies
+        // Third pass: update dependencies (looked up once each, so swapped IDs don't collide)
         for task in &mut self.tasks {
-            for (old_id, new_id) in &id_mappings {
-                if let Some(pos) = task.dependencies.iter().position(|&dep| dep == *old_id) {
-                    task.dependencies[pos] = *new_id;
+            for dep in &mut task.dependencies {
+                if let Some(new_id) = id_mappings.get(dep) {
+                    *dep = *new_id;
+                }
+            }
+            task.dependency_notes = std::mem::take(&mut task.dependency_notes).into_iter()
+                .map(|(dep, note)| (id_mappings.get(&dep).copied().unwrap_or(dep), note))
+                .collect();
+            if let Some(new_id) = task.parent.and_then(|parent| id_mappings.get(&parent)) {
+                task.parent = Some(*new_id);
+            }
+        }
+    }
+
+    /// Order pending tasks so every task comes after its pending dependencies,
+    /// picking the highest priority (then lowest ID) among the available tasks.
+    /// Returns the IDs of tasks caught in dependency cycles on failure.
+    pub fn topological_order(&self) -> Result<Vec<usize>, Vec<usize>> {
+        use std::cmp::Reverse;
+        use std::collections::BinaryHeap;
+        
+        let pending: HashMap<usize, &Task> = self.tasks.iter()
+            .filter(|t| t.status == TaskStatus::Pending)
+            .map(|t| (t.id, t))
+            .collect();
+        
+        // Completed or missing dependencies don't constrain the order
+        let mut remaining_deps: HashMap<usize, usize> = HashMap::new();
+        let mut dependents: HashMap<usize, Vec<usize>> = HashMap::new();
+        for task in pending.values() {
+            let deps: HashSet<usize> = task.dependencies.iter()
+                .copied()
+                .filter(|dep| pending.contains_key(dep))
+                .collect();
+            for dep in &deps {
+                dependents.entry(*dep).or_default().push(task.id);
+            }
+            remaining_deps.insert(task.id, deps.len());
+        }
+        
+        let mut available: BinaryHeap<(u8, Reverse<usize>)> = remaining_deps.iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| (pending[id].priority.rank(), Reverse(*id)))
+            .collect();
+        
+        let mut order = Vec::with_capacity(pending.len());
+        while let Some((_, Reverse(id))) = available.pop() {
+            order.push(id);
+            for dependent in dependents.get(&id).into_iter().flatten() {
+                if let Some(count) = remaining_deps.get_mut(dependent) {
+                    *count -= 1;
+                    if *count == 0 {
+                        available.push((pending[dependent].priority.rank(), Reverse(*dependent)));
+                    }
                 }
             }
         }
+        
+        if order.len() < pending.len() {
+            let mut stuck: Vec<usize> = remaining_deps.into_iter()
+                .filter(|(_, count)| *count > 0)
+                .map(|(id, _)| id)
+                .collect();
+            stuck.sort_unstable();
+            return Err(stuck);
+        }
+        
+        Ok(order)
+    }
+
+    /// Reorder tasks so the given pending IDs follow the completed tasks in
+    /// that sequence, then renumber everything to match the new order
+    pub fn apply_task_order(&mut self, pending_order: &[usize]) {
+        let position: HashMap<usize, usize> = pending_order.iter()
+            .enumerate()
+            .map(|(index, id)| (*id, index))
+            .collect();
+        
+        // Stable sort keeps completed tasks (and anything not in the order) in place at the front
+        self.tasks.sort_by_key(|t| position.get(&t.id).map_or((0, 0), |index| (1, *index)));
+        self.renumber_tasks();
+        self.update_last_modified();
+    }
+
+    /// Refill the positions held by the `scope` tasks with `new_order`, in
+    /// sequence. Scope tasks missing from `new_order` are removed and returned;
+    /// everything is renumbered afterwards.
+    pub fn rearrange_tasks(&mut self, scope: &[usize], new_order: &[usize]) -> Vec<Task> {
+        type Slotted = Vec<(usize, Task)>;
+        let (mut scoped, others): (Slotted, Slotted) = std::mem::take(&mut self.tasks)
+            .into_iter()
+            .enumerate()
+            .partition(|(_, t)| scope.contains(&t.id));
+        let slots: Vec<usize> = scoped.iter().map(|(slot, _)| *slot).collect();
+
+        let mut reordered = Vec::new();
+        for id in new_order {
+            if let Some(index) = scoped.iter().position(|(_, t)| t.id == *id) {
+                reordered.push(scoped.remove(index).1);
+            }
+        }
+        let dropped: Vec<Task> = scoped.into_iter().map(|(_, t)| t).collect();
+
+        let mut placed: Vec<(usize, Task)> = slots.into_iter().zip(reordered).collect();
+        placed.extend(others);
+        placed.sort_by_key(|(slot, _)| *slot);
+        self.tasks = placed.into_iter().map(|(_, t)| t).collect();
+
+        for task in &mut self.tasks {
+            task.dependencies.retain(|dep| !dropped.iter().any(|d| d.id == *dep));
+            task.dependency_notes.retain(|dep, _| !dropped.iter().any(|d| d.id == *dep));
+            if task.parent.is_some_and(|parent| dropped.iter().any(|d| d.id == parent)) {
+                task.parent = None;
+            }
+        }
+        self.last_task_id = dropped.iter().map(|t| t.id).fold(self.last_task_id, usize::max);
+        self.renumber_tasks();
+        self.update_last_modified();
+        dropped
     }
 
     #[allow(dead_code)]
     pub fn filter_by_tags(&self, tags: &[String]) -> Vec<&Task> {
         self.tasks
             .iter()
-            .filter(|task| tags.iter().any(|tag| task.has_tag(tag)))
+            .filter(|task| tags.iter().any(|tag| task.has_tag_within(tag)))
             .collect()
     }
 
@@ -1019,22 +2410,22 @@ impl Roadmap {
             .collect()
     }
 
-    pub fn filter_by_phase(&self, phase: &Phase) -> Vec<&Task> {
-        self.tasks
-            .iter()
-            .filter(|task| &task.phase == phase)
-            .collect()
+    /// Pending tasks in a phase, the number its WIP limit applies to
+    pub fn open_tasks_in_phase(&self, phase: &Phase) -> usize {
+        self.tasks.iter()
+            .filter(|task| task.status == TaskStatus::Pending && task.phase.is_named(&phase.name))
+            .count()
+    }
+
+    /// WIP limit configured for a phase, if any
+    pub fn phase_limit(&self, phase: &Phase) -> Option<usize> {
+        self.phase_limits.get(&phase.name).copied()
     }
 
-    pub fn search_tasks(&self, query: &str) -> Vec<&Task> {
-        let query_lower = query.to_lowercase();
+    pub fn filter_by_phase(&self, phase: &Phase) -> Vec<&Task> {
         self.tasks
             .iter()
-            .filter(|task| {
-                task.description.to_lowercase().contains(&query_lower)
-                    || task.tags.iter().any(|tag| tag.to_lowercase().contains(&query_lower))
-                    || task.notes.as_ref().map_or(false, |notes| notes.to_lowercase().contains(&query_lower))
-            })
+            .filter(|task| task.phase.is_named(&phase.name))
             .collect()
     }
 
@@ -1059,11 +2450,16 @@ impl Roadmap {
         for task in &self.tasks {
             *phase_counts.entry(task.phase.name.clone()).or_insert(0) += 1;
         }
+        // Registered phases are listed before they have tasks, unless archived
+        for entry in self.phases.iter().filter(|entry| !entry.archived) {
+            phase_counts.entry(entry.name.clone()).or_insert(0);
+        }
         
-        // Convert to Vec<(Phase, usize)> for compatibility
-        let by_phase: Vec<(Phase, usize)> = phase_counts.into_iter()
-            .map(|(name, count)| (Phase::from_string(&name), count))
+        // Convert to Vec<(Phase, usize)> for compatibility, in phase order
+        let mut by_phase: Vec<(Phase, usize)> = phase_counts.into_iter()
+            .map(|(name, count)| (self.phase_named(&name), count))
             .collect();
+        by_phase.sort_by_key(|(phase, _)| self.phase_sort_key(phase));
 
         let all_tags: HashSet<String> = self.tasks.iter()
             .flat_map(|t| &t.tags)
@@ -1243,6 +2639,7 @@ impl Roadmap {
                 status: TaskStatus::Pending,
                 dependencies: Vec::new(),
                 is_circular: true,
+                note: None,
             };
         }
         
@@ -1258,12 +2655,16 @@ impl Roadmap {
                     status: TaskStatus::Pending,
                     dependencies: Vec::new(),
                     is_circular: false,
+                    note: None,
                 };
             }
         };
         let dependencies = task.dependencies
             .iter()
-            .map(|&dep_id| self.build_dependency_tree_recursive(dep_id, visited))
+            .map(|&dep_id| DependencyNode {
+                note: task.dependency_notes.get(&dep_id).cloned(),
+                ..self.build_dependency_tree_recursive(dep_id, visited)
+            })
             .collect();
         
         visited.remove(&task_id);
@@ -1274,6 +2675,7 @@ impl Roadmap {
             status: task.status.clone(),
             dependencies,
             is_circular: false,
+            note: None,
         }
     }
 
@@ -1289,28 +2691,96 @@ impl Roadmap {
             }
         }
         
-        // Sort phases: predefined phases first in their natural order, then custom phases alphabetically
-        phases.sort_by(|a, b| {
-            let a_predefined = a.is_predefined();
-            let b_predefined = b.is_predefined();
-            
-            match (a_predefined, b_predefined) {
-                (true, true) => {
-                    // Both predefined - use predefined order
-                    let predefined_order = ["MVP", "Beta", "Release", "Future", "Backlog"];
-                    let a_index = predefined_order.iter().position(|&x| x == a.name).unwrap_or(999);
-                    let b_index = predefined_order.iter().position(|&x| x == b.name).unwrap_or(999);
-                    a_index.cmp(&b_index)
-                }
-                (true, false) => std::cmp::Ordering::Less,  // Predefined comes first
-                (false, true) => std::cmp::Ordering::Greater, // Custom comes after
-                (false, false) => a.name.cmp(&b.name), // Both custom - alphabetical
-            }
-        });
+        phases.sort_by_key(|phase| self.phase_sort_key(phase));
         
         phases
     }
 
+    /// Sort key for a phase: registered phases first in registry order, then
+    /// predefined phases in their natural order, then custom phases alphabetically
+    pub fn phase_sort_key(&self, phase: &Phase) -> (usize, usize, String) {
+        if let Some(position) = self.phases.iter().position(|entry| entry.name == phase.name) {
+            return (0, position, String::new());
+        }
+        let predefined_order = ["MVP", "Beta", "Release", "Future", "Backlog"];
+        match predefined_order.iter().position(|&x| x == phase.name) {
+            Some(index) => (1, index, String::new()),
+            None => (2, 0, phase.name.clone()),
+        }
+    }
+
+    /// Registry entry of a phase, matched case-insensitively
+    pub fn registered_phase(&self, name: &str) -> Option<&PhaseEntry> {
+        let name = Phase::from_string(name).name;
+        self.phases.iter().find(|entry| entry.name.eq_ignore_ascii_case(&name))
+    }
+
+    /// A phase by name, with the description and emoji it was registered with
+    pub fn phase_named(&self, name: &str) -> Phase {
+        self.registered_phase(name)
+            .map(PhaseEntry::to_phase)
+            .unwrap_or_else(|| Phase::from_string(name))
+    }
+
+    /// Register every phase in use, so the registry holds the full current order
+    pub fn register_phases(&mut self) {
+        for phase in self.get_all_phases() {
+            if self.registered_phase(&phase.name).is_none() {
+                self.phases.push(PhaseEntry::from_phase(&phase));
+            }
+        }
+    }
+
+    /// Rename a phase on its tasks, registry entry, milestones and WIP limit; returns the number of tasks moved
+    pub fn rename_phase(&mut self, old_name: &str, new_name: &str) -> usize {
+        let new_phase = Phase::from_string(new_name);
+        // Pin the order first so the renamed phase keeps its position
+        self.register_phases();
+        let mut renamed = 0;
+        for task in &mut self.tasks {
+            if task.phase.name.eq_ignore_ascii_case(old_name) {
+                // The emoji and description carry over, unless the new name is a predefined phase with its own
+                if new_phase.is_predefined() {
+                    task.phase = new_phase.clone();
+                } else {
+                    task.phase.name = new_phase.name.clone();
+                }
+                renamed += 1;
+            }
+        }
+        if let Some(entry) = self.phases.iter_mut().find(|entry| entry.name.eq_ignore_ascii_case(old_name)) {
+            if !new_phase.is_predefined() {
+                let old_phase = entry.to_phase();
+                entry.description = old_phase.description;
+                entry.emoji = old_phase.emoji;
+            }
+            entry.name = new_phase.name.clone();
+        }
+        for milestone in &mut self.milestones {
+            if milestone.phase.as_ref().is_some_and(|p| p.eq_ignore_ascii_case(old_name)) {
+                milestone.phase = Some(new_phase.name.clone());
+            }
+        }
+        let old_key = self.phase_limits.keys().find(|k| k.eq_ignore_ascii_case(old_name)).cloned();
+        if let Some(limit) = old_key.and_then(|key| self.phase_limits.remove(&key)) {
+            self.phase_limits.insert(new_phase.name.clone(), limit);
+        }
+        self.update_last_modified();
+        renamed
+    }
+
+    /// Milestone by name, matched case-insensitively
+    pub fn find_milestone(&self, name: &str) -> Option<&Milestone> {
+        self.milestones.iter().find(|m| m.name.eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Progress of every milestone, soonest target first
+    pub fn milestone_progress(&self, today: chrono::NaiveDate) -> Vec<MilestoneProgress> {
+        let mut progress: Vec<MilestoneProgress> = self.milestones.iter().map(|m| m.progress(self, today)).collect();
+        progress.sort_by(|a, b| a.target_date.cmp(&b.target_date));
+        progress
+    }
+
     /// Get phases that have tasks (non-empty phases)
     pub fn get_active_phases(&self) -> Vec<Phase> {
         self.get_all_phases().into_iter()
@@ -1319,13 +2789,14 @@ impl Roadmap {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DependencyNode {
     pub task_id: usize,
     pub description: String,
     pub status: TaskStatus,
     pub dependencies: Vec<DependencyNode>,
     pub is_circular: bool,
+    pub note: Option<String>, // Why the parent task depends on this one
 }
 
 #[derive(Debug, Clone)]
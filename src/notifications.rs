@@ -0,0 +1,280 @@
+//! Webhook and desktop notifications
+//!
+//! Posts project events to the chat webhooks configured as
+//! `[[notifications.webhooks]]` (Slack, Discord, or any receiver taking JSON).
+//! Events are found by comparing each saved state with the previous one, so
+//! a task completed by any command (`complete`, `bulk`, `review`, a sync)
+//! is announced once. Messages come from templates with `{placeholder}`
+//! fields; `[notifications.templates]` overrides the built-in ones. A
+//! webhook that fails only prints a warning, since the change is already
+//! saved.
+//!
+//! With `notifications.desktop` set, rask also shows native desktop
+//! notifications when a pomodoro interval ends, when an active time session
+//! passes `notifications.session_reminder_minutes`, and for tasks due today.
+//! Reminders are checked when rask runs and shown once each, as recorded in
+//! `.rask/cache/reminders.json`. They need a build with the
+//! `desktop-notifications` feature (notify-rust); other builds skip them.
+
+use crate::config::{NotificationEvent, RaskConfig, WebhookConfig, WebhookKind};
+use crate::model::{MilestoneStatus, Roadmap, TaskStatus};
+use crate::ui;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashSet;
+use std::fs;
+use std::time::Duration;
+
+/// Name of the event `rask notify test` sends
+pub const TEST_EVENT: &str = "test";
+
+/// An event with the fields its template can use
+pub struct Notification {
+    pub event: Option<NotificationEvent>,
+    pub fields: Vec<(&'static str, String)>,
+}
+
+impl Notification {
+    fn event_name(&self) -> &'static str {
+        self.event.map_or(TEST_EVENT, NotificationEvent::name)
+    }
+
+    /// The message text, from the configured template or the built-in one
+    pub fn render(&self, config: &RaskConfig) -> String {
+        let template = config.notifications.templates.get(self.event_name())
+            .map(String::as_str)
+            .unwrap_or(match self.event {
+                Some(NotificationEvent::TaskCompleted) => "✅ Task #{id} completed in {project}: {description}",
+                Some(NotificationEvent::MilestoneReached) => "🏁 Milestone {milestone} reached in {project}: all {total} tasks done (target {target_date})",
+                None => "🔔 Test notification from Rask for {project}",
+            });
+        self.fields.iter().fold(template.to_string(), |text, (name, value)| text.replace(&format!("{{{}}}", name), value))
+    }
+}
+
+/// The notification sent by `rask notify test`
+pub fn test_notification(project: &str) -> Notification {
+    Notification { event: None, fields: vec![("project", project.to_string())] }
+}
+
+/// Tasks completed and milestones reached between two saved states
+pub fn changes_between(before: &Roadmap, after: &Roadmap) -> Vec<Notification> {
+    let project = after.title.clone();
+    let was_pending: HashSet<&str> = before.tasks.iter()
+        .filter(|task| task.status == TaskStatus::Pending)
+        .map(|task| task.uuid.as_str())
+        .collect();
+
+    let mut notifications: Vec<Notification> = after.tasks.iter()
+        .filter(|task| task.status == TaskStatus::Completed && was_pending.contains(task.uuid.as_str()))
+        .map(|task| {
+            let mut tags: Vec<&str> = task.tags.iter().map(String::as_str).collect();
+            tags.sort();
+            Notification {
+                event: Some(NotificationEvent::TaskCompleted),
+                fields: vec![
+                    ("project", project.clone()),
+                    ("id", task.id.to_string()),
+                    ("description", task.description.clone()),
+                    ("priority", task.priority.to_string()),
+                    ("phase", task.phase.name.clone()),
+                    ("tags", tags.join(", ")),
+                ],
+            }
+        })
+        .collect();
+
+    let today = chrono::Local::now().date_naive();
+    let done_before: HashSet<String> = before.milestone_progress(today).into_iter()
+        .filter(|progress| progress.status == MilestoneStatus::Done)
+        .map(|progress| progress.name.to_lowercase())
+        .collect();
+    let known_before: HashSet<String> = before.milestones.iter().map(|m| m.name.to_lowercase()).collect();
+    for progress in after.milestone_progress(today) {
+        let name = progress.name.to_lowercase();
+        if progress.status == MilestoneStatus::Done && progress.total_tasks > 0 && known_before.contains(&name) && !done_before.contains(&name) {
+            notifications.push(Notification {
+                event: Some(NotificationEvent::MilestoneReached),
+                fields: vec![
+                    ("project", project.clone()),
+                    ("milestone", progress.name.clone()),
+                    ("target_date", progress.target_date.clone()),
+                    ("total", progress.total_tasks.to_string()),
+                ],
+            });
+        }
+    }
+    notifications
+}
+
+/// Post the events between two saved states to the webhooks that want them
+pub fn notify_changes(before: &Roadmap, after: &Roadmap) {
+    let Ok(config) = RaskConfig::load() else { return };
+    if config.notifications.webhooks.is_empty() {
+        return;
+    }
+    for notification in changes_between(before, after) {
+        for webhook in config.notifications.webhooks.iter().filter(|w| notification.event.is_some_and(|e| w.wants(e))) {
+            if let Err(e) = send(webhook, &notification, &config) {
+                ui::display_warning(&format!("Could not notify {}: {}", webhook_host(webhook), e));
+            }
+        }
+    }
+}
+
+/// Host of a webhook, safe to print (the path usually holds its secret)
+pub fn webhook_host(webhook: &WebhookConfig) -> String {
+    reqwest::Url::parse(&webhook.url).ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "webhook".to_string())
+}
+
+/// Post one notification to one webhook
+pub fn send(webhook: &WebhookConfig, notification: &Notification, config: &RaskConfig) -> Result<(), String> {
+    let url = crate::destinations::expand_env(&webhook.url).map_err(|e| e.to_string())?;
+    let text = notification.render(config);
+    let payload = match webhook.kind() {
+        WebhookKind::Slack => json!({ "text": text }),
+        WebhookKind::Discord => json!({ "content": text }),
+        WebhookKind::Generic => {
+            let mut payload = json!({ "event": notification.event_name(), "text": text });
+            for (name, value) in &notification.fields {
+                payload[*name] = json!(value);
+            }
+            payload
+        }
+    };
+
+    post_json(&url, &payload)
+}
+
+/// POST a JSON payload and check the answer.
+///
+/// Saves happen inside the async AI handlers too, where a second runtime
+/// cannot be started, so the request runs on its own thread with its own runtime.
+pub fn post_json(url: &str, payload: &serde_json::Value) -> Result<(), String> {
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            let runtime = tokio::runtime::Runtime::new().map_err(|e| format!("Failed to create async runtime: {}", e))?;
+            runtime.block_on(async {
+                let client = reqwest::Client::builder()
+                    .timeout(Duration::from_secs(10))
+                    .build()
+                    .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+                let response = client.post(url).json(payload).send().await.map_err(|e| e.without_url().to_string())?;
+                if !response.status().is_success() {
+                    return Err(format!("the webhook answered {}", response.status()));
+                }
+                Ok(())
+            })
+        })
+        .join()
+        .map_err(|_| "webhook thread panicked".to_string())?
+    })
+}
+
+/// Whether this build can show desktop notifications
+pub const DESKTOP_SUPPORTED: bool = cfg!(feature = "desktop-notifications");
+
+/// Show a native desktop notification
+#[cfg(feature = "desktop-notifications")]
+pub fn show_desktop(summary: &str, body: &str) -> Result<(), String> {
+    notify_rust::Notification::new()
+        .appname("rask")
+        .summary(summary)
+        .body(body)
+        .show()
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Show a native desktop notification
+#[cfg(not(feature = "desktop-notifications"))]
+pub fn show_desktop(_summary: &str, _body: &str) -> Result<(), String> {
+    Err("this build of rask has no desktop notifications; install it with --features desktop-notifications".to_string())
+}
+
+/// Show a desktop notification when they are turned on in the config
+pub fn desktop_alert(summary: &str, body: &str) {
+    let enabled = RaskConfig::load().map(|config| config.notifications.desktop).unwrap_or(false);
+    if enabled && DESKTOP_SUPPORTED {
+        if let Err(e) = show_desktop(summary, body) {
+            tracing::warn!("desktop notification failed: {}", e);
+        }
+    }
+}
+
+/// Reminders already shown, so each appears once
+#[derive(Serialize, Deserialize, Default)]
+struct ShownReminders {
+    /// Last day the due-today reminder was shown (YYYY-MM-DD)
+    #[serde(default)]
+    due_today: Option<String>,
+    /// Start times of the sessions already reported as running long
+    #[serde(default)]
+    long_sessions: Vec<String>,
+}
+
+/// Show the due-today and long-session reminders that are due. Runs before every command.
+pub fn check_desktop_reminders() {
+    let Ok(config) = RaskConfig::load() else { return };
+    let settings = &config.notifications;
+    if !settings.desktop || !DESKTOP_SUPPORTED || !crate::state::has_local_workspace() {
+        return;
+    }
+    let (Ok(roadmap), Ok(path)) = (crate::state::load_state(), crate::state::get_reminders_file()) else { return };
+    let mut shown: ShownReminders = fs::read_to_string(&path).ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default();
+    let mut changed = false;
+
+    let today = chrono::Local::now().date_naive();
+    let today_key = today.format("%Y-%m-%d").to_string();
+    if settings.due_today && shown.due_today.as_deref() != Some(today_key.as_str()) {
+        let due: Vec<String> = roadmap.tasks.iter()
+            .filter(|task| task.status == TaskStatus::Pending && task.due_date_parsed() == Some(today))
+            .map(|task| format!("#{} {}", task.id, task.description))
+            .collect();
+        if !due.is_empty() {
+            let summary = format!("{} task(s) due today in {}", due.len(), roadmap.title);
+            if let Err(e) = show_desktop(&summary, &due.join("\n")) {
+                tracing::warn!("desktop notification failed: {}", e);
+            }
+        }
+        shown.due_today = Some(today_key);
+        changed = true;
+    }
+
+    if settings.session_reminder_minutes > 0 {
+        let threshold_hours = settings.session_reminder_minutes as f64 / 60.0;
+        for task in &roadmap.tasks {
+            let Some(session) = task.get_active_time_session() else { continue };
+            if session.elapsed_hours() < threshold_hours || shown.long_sessions.contains(&session.start_time) {
+                continue;
+            }
+            let body = format!("#{} {} has been tracked for {:.0} min. Take a break or 'rask stop'.",
+                task.id, task.description, session.elapsed_hours() * 60.0);
+            if let Err(e) = show_desktop("Time session still running", &body) {
+                tracing::warn!("desktop notification failed: {}", e);
+            }
+            shown.long_sessions.push(session.start_time.clone());
+            changed = true;
+        }
+        // Only running sessions need remembering
+        let active: HashSet<&str> = roadmap.tasks.iter()
+            .filter_map(|task| task.get_active_time_session().map(|session| session.start_time.as_str()))
+            .collect();
+        let before = shown.long_sessions.len();
+        shown.long_sessions.retain(|start| active.contains(start.as_str()));
+        changed |= shown.long_sessions.len() != before;
+    }
+
+    if changed {
+        if let Some(dir) = path.parent() {
+            let _ = fs::create_dir_all(dir);
+        }
+        if let Ok(text) = serde_json::to_string_pretty(&shown) {
+            let _ = fs::write(&path, text);
+        }
+    }
+}
@@ -1,12 +1,14 @@
-use crate::model::{Roadmap, Task, TaskStatus};
+use crate::model::{Phase, ProjectMetadata, Roadmap, Task, TaskStatus};
+use std::collections::HashMap;
 use pulldown_cmark::{Event, Parser as CmarkParser, Tag};
+use serde_yaml::Value;
 use std::io::{Error, ErrorKind};
 use std::path::Path;
 
 fn extract_text(parser: &mut CmarkParser) -> String {
     let mut text = String::new();
     
-    // Continue parsing until we reach the end of the list item
+    // Continue parsing until we reach the end of the heading
     while let Some(event) = parser.next() {
         match event {
             Event::Text(t) => text.push_str(&t),
@@ -19,40 +21,144 @@ fn extract_text(parser: &mut CmarkParser) -> String {
     text
 }
 
+/// A list item being read. Its text ends where a nested list starts, at
+/// which point the task is created so subtasks can point at it.
+#[derive(Default)]
+struct OpenItem {
+    text: String,
+    metadata: Option<String>,
+    task_id: Option<usize>,
+}
+
+/// Tasks read so far, with exported IDs and dependencies to remap at the end
+#[derive(Default)]
+struct TaskCollector {
+    tasks: Vec<Task>,
+    exported_ids: HashMap<usize, usize>,
+    exported_deps: Vec<(usize, Vec<usize>)>,
+}
+
+impl TaskCollector {
+    /// Turn a finished item into a task under `phase` and `parent`
+    fn finish(&mut self, item: &mut OpenItem, phase: Option<&Phase>, parent: Option<usize>) {
+        if item.task_id.is_some() {
+            return;
+        }
+        let task_id = self.tasks.len() + 1;
+        item.task_id = Some(task_id);
+        
+        // Check if task is already completed (checkbox syntax)
+        let (text, status) = parse_task_text(&item.text);
+        let (description, inline) = split_inline_metadata(&text);
+        
+        let mut task = Task::new(task_id, description);
+        if status == TaskStatus::Completed {
+            task.mark_completed();
+        }
+        if let Some(phase) = phase {
+            task.phase = phase.clone();
+        }
+        task.parent = parent;
+        apply_inline_metadata(&mut task, &inline);
+        
+        if let Some(metadata) = &item.metadata {
+            let (original_id, deps) = apply_export_metadata(&mut task, metadata);
+            if let Some(original_id) = original_id {
+                self.exported_ids.insert(original_id, task.id);
+            }
+            if !deps.is_empty() {
+                self.exported_deps.push((task.id, deps));
+            }
+        }
+        
+        self.tasks.push(task);
+    }
+}
+
+/// Parse a roadmap file. `## Heading` sections set the phase of the tasks
+/// below them, nested list items become subtasks of the item they are
+/// indented under, and `(due: 2024-08-01, est: 4h)` style metadata at the end
+/// of an item is read into the task. A leading `---` YAML frontmatter block
+/// fills in the project metadata.
 pub fn parse_markdown_to_roadmap(markdown_input: &str, source_file: Option<&Path>, project_name: &str) -> Result<Roadmap, Error> {
-    let mut parser = CmarkParser::new(markdown_input);
+    let (frontmatter, body) = split_frontmatter(markdown_input);
+    let mut metadata = ProjectMetadata { name: String::new(), ..ProjectMetadata::default() };
+    if let Some(yaml) = frontmatter {
+        apply_frontmatter(&mut metadata, yaml)?;
+    }
+    let default_phase = metadata.default_phase.as_deref().map(Phase::from_string);
+
+    let mut parser = CmarkParser::new(body);
     let mut roadmap_title = String::new();
-    let mut tasks: Vec<Task> = Vec::new();
-    let mut task_id_counter = 0;
+    let mut collector = TaskCollector::default();
+    let mut current_phase: Option<Phase> = None;
+    let mut items: Vec<OpenItem> = Vec::new();
 
     while let Some(event) = parser.next() {
         match event {
             Event::Start(Tag::Heading(pulldown_cmark::HeadingLevel::H1, _, _)) => {
                 roadmap_title = extract_text(&mut parser);
             }
-            Event::Start(Tag::Item) => {
-                let task_text = extract_text(&mut parser);
-                task_id_counter += 1;
-                
-                // Check if task is already completed (checkbox syntax)
-                let (description, status) = parse_task_text(&task_text);
-                
-                let mut task = Task::new(task_id_counter, description);
-                if status == TaskStatus::Completed {
-                    task.mark_completed();
+            Event::Start(Tag::Heading(pulldown_cmark::HeadingLevel::H2, _, _)) => {
+                current_phase = phase_from_heading(&extract_text(&mut parser));
+            }
+            Event::Start(Tag::Item) => items.push(OpenItem::default()),
+            Event::Start(Tag::List(_)) => {
+                // A nested list: the enclosing item's own text is complete
+                if let Some((item, outer)) = items.split_last_mut() {
+                    let parent = outer.last().and_then(|o| o.task_id);
+                    collector.finish(item, current_phase.as_ref().or(default_phase.as_ref()), parent);
+                }
+            }
+            Event::End(Tag::Item) => {
+                if let Some(mut item) = items.pop() {
+                    let parent = items.last().and_then(|o| o.task_id);
+                    collector.finish(&mut item, current_phase.as_ref().or(default_phase.as_ref()), parent);
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(item) = items.last_mut().filter(|i| i.task_id.is_none()) {
+                    item.text.push_str(&text);
+                }
+            }
+            Event::SoftBreak => {
+                if let Some(item) = items.last_mut().filter(|i| i.task_id.is_none()) {
+                    item.text.push(' ');
+                }
+            }
+            Event::Html(html) => {
+                let html = html.trim();
+                if let Some(body) = html.strip_prefix("<!-- rask:").and_then(|b| b.strip_suffix("-->")) {
+                    if let Some(item) = items.last_mut() {
+                        item.metadata = Some(body.trim().to_string());
+                    }
                 }
-                
-                tasks.push(task);
             }
             _ => {}
         }
     }
 
+    // Re-point exported dependencies at the newly assigned IDs
+    let TaskCollector { mut tasks, exported_ids, exported_deps } = collector;
+    for (task_id, deps) in exported_deps {
+        if let Some(task) = tasks.iter_mut().find(|t| t.id == task_id) {
+            task.dependencies = deps.iter()
+                .filter_map(|dep| exported_ids.get(dep).copied())
+                .collect();
+        }
+    }
+
     if roadmap_title.is_empty() {
-        roadmap_title = project_name.to_string();
+        roadmap_title = if metadata.name.is_empty() { project_name.to_string() } else { metadata.name.clone() };
     }
 
     let mut roadmap = Roadmap::new(roadmap_title);
+    if frontmatter.is_some() {
+        if metadata.name.is_empty() {
+            metadata.name = roadmap.title.clone();
+        }
+        roadmap.metadata = metadata;
+    }
     roadmap.tasks = tasks;
     if let Some(source) = source_file {
         roadmap = roadmap.with_source_file(source.to_string_lossy().to_string());
@@ -61,6 +167,76 @@ pub fn parse_markdown_to_roadmap(markdown_input: &str, source_file: Option<&Path
     Ok(roadmap)
 }
 
+/// Split a leading `---` frontmatter block off the markdown, returning its YAML and the rest
+fn split_frontmatter(input: &str) -> (Option<&str>, &str) {
+    let Some(rest) = input.strip_prefix("---\n").or_else(|| input.strip_prefix("---\r\n")) else {
+        return (None, input);
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if matches!(line.trim_end(), "---" | "...") {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, input)
+}
+
+/// Read the frontmatter keys rask understands into the project metadata:
+/// `name`, `description`, `default_phase`, `tags` (a list or comma-separated)
+/// and `estimate` (hours, or a duration like "120h")
+fn apply_frontmatter(metadata: &mut ProjectMetadata, yaml: &str) -> Result<(), Error> {
+    let value: Value = serde_yaml::from_str(yaml)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, format!("Invalid frontmatter: {}", e)))?;
+    let text = |key: &str| value.get(key).and_then(scalar_text);
+
+    if let Some(name) = text("name").or_else(|| text("title")) {
+        metadata.name = name;
+    }
+    metadata.description = text("description");
+    metadata.default_phase = text("default_phase");
+    let tags: Vec<String> = match value.get("tags") {
+        Some(Value::Sequence(items)) => items.iter().filter_map(scalar_text).collect(),
+        Some(other) => scalar_text(other).map(|t| t.split(',').map(str::to_string).collect()).unwrap_or_default(),
+        None => Vec::new(),
+    };
+    metadata.tags = tags.iter()
+        .map(|t| t.trim().trim_start_matches('#').to_string())
+        .filter(|t| !t.is_empty())
+        .collect();
+    metadata.estimated_hours = match value.get("estimate") {
+        Some(Value::Number(hours)) => hours.as_f64(),
+        Some(other) => scalar_text(other).and_then(|t| parse_hours(&t)),
+        None => None,
+    };
+    Ok(())
+}
+
+/// A frontmatter value as text; lists and maps have none
+fn scalar_text(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::String(text) => text.trim().to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::Bool(flag) => flag.to_string(),
+        _ => return None,
+    };
+    Some(text).filter(|t| !t.is_empty())
+}
+
+/// Phase named by a `##` heading: "🧪 Beta Phase" and "Beta" both give Beta
+fn phase_from_heading(heading: &str) -> Option<Phase> {
+    let name = heading.trim_start_matches(|c: char| !c.is_alphanumeric()).trim();
+    let name = match name.len().checked_sub(" phase".len()) {
+        Some(cut) if name.is_char_boundary(cut) && name[cut..].eq_ignore_ascii_case(" phase") => name[..cut].trim(),
+        _ => name,
+    };
+    if name.is_empty() {
+        None
+    } else {
+        Some(Phase::from_string(name))
+    }
+}
+
 /// Parse task text to extract description and status
 /// Supports both checkbox syntax and plain text
 fn parse_task_text(text: &str) -> (String, TaskStatus) {
@@ -81,3 +257,173 @@ fn parse_task_text(text: &str) -> (String, TaskStatus) {
     // Default: plain text, assume pending
     (trimmed.to_string(), TaskStatus::Pending)
 }
+
+/// Keys accepted in inline `(key: value)` metadata
+const INLINE_KEYS: [&str; 5] = ["due", "est", "priority", "tags", "phase"];
+
+/// Split trailing `(due: 2024-08-01, est: 4h)` groups off an item's text.
+/// Parentheses that don't start with a known key are left in the description.
+fn split_inline_metadata(text: &str) -> (String, Vec<(String, String)>) {
+    let mut description = text.trim_end();
+    let mut groups = Vec::new();
+    
+    while let Some(body) = description.strip_suffix(')') {
+        let Some(open) = body.rfind('(') else { break };
+        match parse_inline_group(&body[open + 1..]) {
+            Some(pairs) => {
+                groups.push(pairs);
+                description = body[..open].trim_end();
+            }
+            None => break,
+        }
+    }
+    
+    // Groups were read from the end, so later ones win when applied in order
+    let pairs = groups.into_iter().rev().flatten().collect();
+    (description.to_string(), pairs)
+}
+
+/// Parse `key: value, key: value`; a piece without a key continues the
+/// previous value, so `tags: api, backend` keeps both tags
+fn parse_inline_group(body: &str) -> Option<Vec<(String, String)>> {
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    for piece in body.split(',') {
+        let pair = piece.split_once(':')
+            .map(|(key, value)| (key.trim().to_lowercase(), value.trim().to_string()))
+            .filter(|(key, _)| INLINE_KEYS.contains(&key.as_str()));
+        match (pair, pairs.last_mut()) {
+            (Some(pair), _) => pairs.push(pair),
+            (None, Some((_, value))) => {
+                value.push(',');
+                value.push_str(piece.trim());
+            }
+            (None, None) => return None,
+        }
+    }
+    Some(pairs)
+}
+
+/// Apply inline metadata read by `split_inline_metadata`
+fn apply_inline_metadata(task: &mut Task, pairs: &[(String, String)]) {
+    for (key, value) in pairs {
+        match key.as_str() {
+            "due" => {
+                if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+                    task.due_date = Some(date.format("%Y-%m-%d").to_string());
+                }
+            }
+            "est" => {
+                if let Some(hours) = parse_hours(value) {
+                    task.set_estimated_hours(hours);
+                }
+            }
+            "priority" => {
+                if let Some(priority) = crate::importers::parse_priority(value) {
+                    task.priority = priority;
+                }
+            }
+            "tags" => {
+                task.tags.extend(value.split(',')
+                    .map(|t| t.trim().trim_start_matches('#').to_string())
+                    .filter(|t| !t.is_empty()));
+            }
+            "phase" => task.phase = Phase::from_string(value),
+            _ => {}
+        }
+    }
+}
+
+/// Parse an estimate such as `4h`, `1.5h`, `30m` or `2` (hours)
+fn parse_hours(value: &str) -> Option<f64> {
+    let value = value.trim().to_lowercase();
+    if let Some(minutes) = value.strip_suffix('m') {
+        return minutes.trim().parse::<f64>().ok().map(|m| m / 60.0);
+    }
+    value.strip_suffix('h').unwrap_or(&value).trim().parse().ok()
+}
+
+/// Apply `key=value` metadata from a `rask export markdown` comment to a task.
+///
+/// Returns the task's original ID and its original dependency IDs so the
+/// caller can remap them once all tasks are numbered.
+fn apply_export_metadata(task: &mut Task, metadata: &str) -> (Option<usize>, Vec<usize>) {
+    let mut original_id = None;
+    let mut deps = Vec::new();
+    
+    for (key, value) in split_metadata_pairs(metadata) {
+        match key.as_str() {
+            "id" => original_id = value.parse().ok(),
+            "uuid" if uuid::Uuid::parse_str(&value).is_ok() => task.uuid = value,
+            "priority" => {
+                task.priority = match value.as_str() {
+                    "low" => crate::model::Priority::Low,
+                    "high" => crate::model::Priority::High,
+                    "critical" => crate::model::Priority::Critical,
+                    _ => crate::model::Priority::Medium,
+                };
+            }
+            "phase" => task.phase = Phase::from_string(&value),
+            "tags" => {
+                task.tags = value.split(',')
+                    .map(|t| t.trim().to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect();
+            }
+            "est" => {
+                if let Ok(hours) = value.parse::<f64>() {
+                    task.set_estimated_hours(hours);
+                }
+            }
+            "deps" => deps = value.split(',').filter_map(|d| d.trim().parse().ok()).collect(),
+            "energy" => {
+                task.energy = match value.as_str() {
+                    "low" => Some(crate::model::Energy::Low),
+                    "medium" => Some(crate::model::Energy::Medium),
+                    "high" => Some(crate::model::Energy::High),
+                    _ => None,
+                };
+            }
+            "contexts" => {
+                task.contexts = value.split(',')
+                    .map(|c| c.trim().to_string())
+                    .filter(|c| !c.is_empty())
+                    .collect();
+            }
+            "owners" => {
+                task.owners = value.split(',')
+                    .map(|o| o.trim().to_string())
+                    .filter(|o| !o.is_empty())
+                    .collect();
+            }
+            _ => {}
+        }
+    }
+    
+    (original_id, deps)
+}
+
+/// Split `key=value key="quoted value"` pairs
+fn split_metadata_pairs(metadata: &str) -> Vec<(String, String)> {
+    let mut pairs = Vec::new();
+    let mut rest = metadata.trim();
+    
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_string();
+        let after = &rest[eq + 1..];
+        let (value, remaining) = if let Some(quoted) = after.strip_prefix('"') {
+            match quoted.find('"') {
+                Some(end) => (&quoted[..end], &quoted[end + 1..]),
+                None => (quoted, ""),
+            }
+        } else {
+            match after.find(char::is_whitespace) {
+                Some(end) => (&after[..end], &after[end..]),
+                None => (after, ""),
+            }
+        };
+        pairs.push((key, value.to_string()));
+        rest = remaining.trim_start();
+    }
+    
+    pairs
+}
@@ -141,7 +141,7 @@ impl ProjectsConfig {
         }
         
         let json_data = serde_json::to_string_pretty(self)
-            .map_err(|e| Error::new(ErrorKind::Other, format!("Failed to serialize projects config: {}", e)))?;
+            .map_err(|e| Error::other(format!("Failed to serialize projects config: {}", e)))?;
         
         fs::write(&config_file, json_data)
     }
@@ -0,0 +1,394 @@
+//! Automation rules engine
+//!
+//! Rules are configured as `[[rules]]` entries in config.toml. Whenever a
+//! change is saved, by any command, the stored state is compared with the new
+//! one to find events (a task was added, a task was completed, a phase was
+//! finished). Each enabled rule matching an event can add a follow-up task and/or POST
+//! the event to a webhook. Every rule run is appended to `.rask/audit.log`.
+//!
+//! Rules only see changes made by the user: tasks added by a rule never
+//! trigger further rules, so rules cannot loop.
+//!
+//! Escalation rules (`[[escalations]]`) are condition-based instead: they are
+//! applied to pending tasks every time the state is loaded, so a task due
+//! tomorrow is escalated the next time anyone looks at the project, and the
+//! change is kept with the next save. Their actions only ever raise a
+//! priority or add a tag, so applying them again changes nothing.
+
+use crate::config::{AutomationRule, EscalationRule, RuleTrigger};
+use crate::model::{Phase, Priority, Roadmap, Task, TaskStatus};
+use crate::{importers, state};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
+/// Something that happened between two saved states
+#[derive(Debug, Clone)]
+pub struct RuleEvent {
+    pub trigger: RuleTrigger,
+    /// Task the event is about (`None` for phase events)
+    pub task_id: Option<usize>,
+    pub phase: String,
+}
+
+impl RuleEvent {
+    /// Short description used in output and the audit log
+    pub fn describe(&self) -> String {
+        match self.task_id {
+            Some(id) => format!("{} #{}", self.trigger, id),
+            None => format!("{} {}", self.trigger, self.phase),
+        }
+    }
+}
+
+/// The outcome of one rule running for one event
+#[derive(Debug, Clone)]
+pub struct RuleRun {
+    pub rule: String,
+    pub event: RuleEvent,
+    pub actions: Vec<String>,
+}
+
+/// One line of `.rask/audit.log`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: String,
+    pub rule: String,
+    pub event: String,
+    pub actions: Vec<String>,
+}
+
+/// Compare two states and list the events rules can react to
+pub fn detect_events(before: &Roadmap, after: &Roadmap) -> Vec<RuleEvent> {
+    let shared = shared_timestamps(before, after);
+    let previous: HashMap<String, &Task> = before.tasks.iter().map(|t| (task_key(t, &shared), t)).collect();
+    let mut events = Vec::new();
+
+    for task in &after.tasks {
+        match previous.get(&task_key(task, &shared)) {
+            None => events.push(task_event(RuleTrigger::TaskAdded, task)),
+            Some(old) if old.status != TaskStatus::Completed && task.status == TaskStatus::Completed => {
+                events.push(task_event(RuleTrigger::TaskCompleted, task));
+            }
+            _ => {}
+        }
+    }
+
+    let mut phases: Vec<&str> = Vec::new();
+    for task in &after.tasks {
+        if !phases.contains(&task.phase.name.as_str()) {
+            phases.push(&task.phase.name);
+        }
+    }
+    for phase in phases {
+        let done_now = after.tasks.iter()
+            .filter(|t| t.phase.name == phase)
+            .all(|t| t.status == TaskStatus::Completed);
+        let open_before = before.tasks.iter()
+            .any(|t| t.phase.name == phase && t.status != TaskStatus::Completed);
+        if done_now && open_before {
+            events.push(RuleEvent { trigger: RuleTrigger::PhaseCompleted, task_id: None, phase: phase.to_string() });
+        }
+    }
+
+    events
+}
+
+/// Run the configured rules for the changes between the stored state and
+/// `roadmap`, which is about to be saved.
+///
+/// Tasks the rules add go into `roadmap`; each run is printed and written to
+/// the audit log. Called by every save, so rules fire however a change is made.
+pub fn apply_on_save(before: &Roadmap, roadmap: &mut Roadmap) {
+    let automation = crate::config::RaskConfig::load().map(|c| c.rules).unwrap_or_default();
+    if !automation.iter().any(|r| r.enabled) {
+        return;
+    }
+    let events = detect_events(before, roadmap);
+    let runs = run_rules(&automation, &events, roadmap, true);
+    if runs.is_empty() {
+        return;
+    }
+    for run in &runs {
+        crate::ui::display_info(&format!("⚙️  Rule '{}' ({}): {}", run.rule, run.event.describe(),
+            if run.actions.is_empty() { "no actions".to_string() } else { run.actions.join("; ") }));
+    }
+    if let Err(e) = record_runs(&runs) {
+        crate::ui::display_warning(&format!("Could not write the audit log: {}", e));
+    }
+}
+
+/// Build the event for a task, used by `rask rules test` to simulate one
+pub fn task_event(trigger: RuleTrigger, task: &Task) -> RuleEvent {
+    RuleEvent { trigger, task_id: Some(task.id), phase: task.phase.name.clone() }
+}
+
+/// Whether a rule reacts to an event
+pub fn rule_matches(rule: &AutomationRule, event: &RuleEvent, roadmap: &Roadmap) -> bool {
+    if rule.when != event.trigger {
+        return false;
+    }
+    if let Some(phase) = &rule.phase {
+        if !phase.eq_ignore_ascii_case(&event.phase) {
+            return false;
+        }
+    }
+    if let Some(tag) = &rule.tag {
+        let tag = tag.trim_start_matches('#').to_lowercase();
+        let tagged = event.task_id
+            .and_then(|id| roadmap.find_task_by_id(id))
+            .is_some_and(|t| t.tags.iter().any(|own| crate::model::tag_within(&own.to_lowercase(), &tag)));
+        if !tagged {
+            return false;
+        }
+    }
+    true
+}
+
+/// Run every enabled rule matching the events against `roadmap`.
+///
+/// With `live` unset nothing leaves the process: webhooks are described
+/// but not sent, so callers can pass a copy of the roadmap for a dry run.
+pub fn run_rules(rules: &[AutomationRule], events: &[RuleEvent], roadmap: &mut Roadmap, live: bool) -> Vec<RuleRun> {
+    let mut runs = Vec::new();
+    for event in events {
+        for rule in rules.iter().filter(|r| r.enabled) {
+            if rule_matches(rule, event, roadmap) {
+                let actions = apply_rule(rule, event, roadmap, live);
+                runs.push(RuleRun { rule: rule.name.clone(), event: event.clone(), actions });
+            }
+        }
+    }
+    runs
+}
+
+fn apply_rule(rule: &AutomationRule, event: &RuleEvent, roadmap: &mut Roadmap, live: bool) -> Vec<String> {
+    let trigger = event.task_id.and_then(|id| roadmap.find_task_by_id(id)).cloned();
+    let render = |text: &str| {
+        text.replace("{id}", &event.task_id.map(|id| id.to_string()).unwrap_or_default())
+            .replace("{description}", trigger.as_ref().map(|t| t.description.as_str()).unwrap_or_default())
+            .replace("{phase}", &event.phase)
+    };
+    let mut actions = Vec::new();
+
+    if let Some(template) = &rule.add_task {
+        let description = render(template);
+        let exists = roadmap.tasks.iter()
+            .any(|t| t.status != TaskStatus::Completed && t.description == description);
+        if exists {
+            actions.push(format!("skipped adding '{}': an open task with that description exists", description));
+        } else {
+            let mut task = Task::new(0, description.clone());
+            task.tags = rule.task_tags.iter().map(|t| t.trim_start_matches('#').to_string()).collect();
+            if let Some(priority) = &rule.task_priority {
+                match importers::parse_priority(priority) {
+                    Some(priority) => task.priority = priority,
+                    None => actions.push(format!("unknown priority '{}', using {}", priority, task.priority)),
+                }
+            }
+            task.phase = match &rule.task_phase {
+                Some(phase) => Phase::from_string(&render(phase)),
+                None => trigger.as_ref().map(|t| t.phase.clone()).unwrap_or_else(|| Phase::from_string(&event.phase)),
+            };
+            if rule.depends_on_trigger {
+                task.dependencies.extend(event.task_id);
+            }
+            task.record_history("created", Some(format!("by rule '{}' ({})", rule.name, event.describe())));
+            let id = roadmap.get_next_task_id();
+            roadmap.add_task(task);
+            actions.push(format!("added task #{} '{}'", id, description));
+        }
+    }
+
+    if let Some(url) = &rule.webhook {
+        if live {
+            match send_webhook(url, rule, event, trigger.as_ref(), &roadmap.title) {
+                Ok(()) => actions.push(format!("posted to {}", url)),
+                Err(e) => actions.push(format!("webhook {} failed: {}", url, e)),
+            }
+        } else {
+            actions.push(format!("would post to {}", url));
+        }
+    }
+
+    if live {
+        if let Some(task) = event.task_id.and_then(|id| roadmap.find_task_by_id_mut(id)) {
+            task.record_history("rule_applied", Some(rule.name.clone()));
+        }
+    }
+    actions
+}
+
+/// POST the event as JSON
+fn send_webhook(url: &str, rule: &AutomationRule, event: &RuleEvent, task: Option<&Task>, project: &str) -> Result<(), String> {
+    let payload = serde_json::json!({
+        "rule": rule.name,
+        "event": event.trigger.to_string(),
+        "phase": event.phase,
+        "project": project,
+        "timestamp": chrono::Utc::now().to_rfc3339(),
+        "task": task.map(|t| serde_json::json!({
+            "id": t.id,
+            "description": t.description,
+            "tags": t.tags,
+            "priority": t.priority.to_string(),
+            "status": if t.status == TaskStatus::Completed { "completed" } else { "pending" },
+        })),
+    });
+    crate::notifications::post_json(url, &payload)
+}
+
+/// Changes an escalation rule made to one task
+#[derive(Debug, Clone)]
+pub struct Escalation {
+    pub rule: String,
+    pub task_id: usize,
+    pub actions: Vec<String>,
+}
+
+/// Apply every enabled escalation rule to the pending tasks of `roadmap`
+pub fn apply_escalations(rules: &[EscalationRule], roadmap: &mut Roadmap, today: chrono::NaiveDate) -> Vec<Escalation> {
+    let mut escalations = Vec::new();
+    for rule in rules.iter().filter(|r| r.enabled) {
+        let (Ok(priority_below), Ok(set_priority)) = (rule_priority(&rule.priority_below), rule_priority(&rule.set_priority)) else {
+            continue;
+        };
+        let completed_ids = roadmap.get_completed_task_ids();
+        let matching: Vec<usize> = roadmap.tasks.iter()
+            .filter(|task| escalation_matches(rule, priority_below.as_ref(), task, &completed_ids, today))
+            .map(|task| task.id)
+            .collect();
+
+        for task_id in matching {
+            let Some(task) = roadmap.find_task_by_id_mut(task_id) else { continue };
+            let mut actions = Vec::new();
+            if let Some(priority) = &set_priority {
+                if task.priority.rank() < priority.rank() {
+                    actions.push(format!("raised priority {} → {}", task.priority, priority));
+                    task.priority = priority.clone();
+                }
+            }
+            if let Some(tag) = &rule.add_tag {
+                let tag = tag.trim_start_matches('#');
+                if !tag.is_empty() && !task.has_tag(tag) {
+                    task.add_tag(tag.to_string());
+                    actions.push(format!("tagged #{}", tag));
+                }
+            }
+            if !actions.is_empty() {
+                task.record_history("escalated", Some(format!("{} (rule '{}')", actions.join(", "), rule.name)));
+                escalations.push(Escalation { rule: rule.name.clone(), task_id, actions });
+            }
+        }
+    }
+    escalations
+}
+
+/// Parse an optional priority from an escalation rule
+pub fn rule_priority(value: &Option<String>) -> Result<Option<Priority>, String> {
+    match value {
+        Some(value) => importers::parse_priority(value)
+            .map(Some)
+            .ok_or_else(|| format!("unknown priority '{}'", value)),
+        None => Ok(None),
+    }
+}
+
+fn escalation_matches(
+    rule: &EscalationRule,
+    priority_below: Option<&Priority>,
+    task: &Task,
+    completed_ids: &HashSet<usize>,
+    today: chrono::NaiveDate,
+) -> bool {
+    if task.status != TaskStatus::Pending {
+        return false;
+    }
+    if rule.phase.as_ref().is_some_and(|phase| !task.phase.name.eq_ignore_ascii_case(phase)) {
+        return false;
+    }
+    if rule.tag.as_ref().is_some_and(|tag| !task.has_tag_within(tag.trim_start_matches('#'))) {
+        return false;
+    }
+    if priority_below.is_some_and(|below| task.priority.rank() >= below.rank()) {
+        return false;
+    }
+    if let Some(days) = rule.due_within_days {
+        let due = task.due_date.as_deref()
+            .and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok());
+        let due_soon = due.is_some_and(|due| (due - today).num_days() <= days);
+        if !due_soon {
+            return false;
+        }
+    }
+    if let Some(days) = rule.blocked_for_days {
+        let created = task.created_datetime().map(|c| c.with_timezone(&chrono::Local).date_naive());
+        let blocked_long = !task.can_be_started(completed_ids)
+            && created.is_some_and(|created| (today - created).num_days() > days);
+        if !blocked_long {
+            return false;
+        }
+    }
+    true
+}
+
+/// Record escalations in `.rask/audit.log`
+pub fn record_escalations(escalations: &[Escalation]) -> std::io::Result<()> {
+    append_audit(escalations.iter().map(|escalation| (
+        escalation.rule.clone(),
+        format!("escalation #{}", escalation.task_id),
+        escalation.actions.clone(),
+    )))
+}
+
+/// Append rule runs to `.rask/audit.log`
+pub fn record_runs(runs: &[RuleRun]) -> std::io::Result<()> {
+    append_audit(runs.iter().map(|run| (run.rule.clone(), run.event.describe(), run.actions.clone())))
+}
+
+/// Append (rule, event, actions) entries to the audit log with one timestamp
+fn append_audit(entries: impl Iterator<Item = (String, String, Vec<String>)>) -> std::io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(state::get_audit_log_file()?)?;
+    let timestamp = chrono::Utc::now().to_rfc3339();
+    for (rule, event, actions) in entries {
+        let entry = AuditEntry { timestamp: timestamp.clone(), rule, event, actions };
+        writeln!(file, "{}", serde_json::to_string(&entry).map_err(std::io::Error::other)?)?;
+    }
+    Ok(())
+}
+
+/// Read the audit log, oldest entry first
+pub fn read_audit_log() -> std::io::Result<Vec<AuditEntry>> {
+    let path = state::get_audit_log_file()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Identify a task across saves by its creation time, so renumbering does
+/// not look like new tasks. Tasks sharing a timestamp fall back to their ID.
+fn task_key(task: &Task, shared: &HashSet<&str>) -> String {
+    match task.created_at.as_deref() {
+        Some(created) if !shared.contains(created) => created.to_string(),
+        _ => format!("#{}", task.id),
+    }
+}
+
+/// Creation timestamps used by more than one task in either state
+fn shared_timestamps<'a>(before: &'a Roadmap, after: &'a Roadmap) -> HashSet<&'a str> {
+    let mut shared = HashSet::new();
+    for roadmap in [before, after] {
+        let mut seen = HashSet::new();
+        for created in roadmap.tasks.iter().filter_map(|t| t.created_at.as_deref()) {
+            if !seen.insert(created) {
+                shared.insert(created);
+            }
+        }
+    }
+    shared
+}
@@ -0,0 +1,74 @@
+//! Task sort orders shared by `list` and `show`
+//!
+//! `--sort` picks the order for one run; without it `ui.default_sort` from the
+//! config is used. Sorting is stable, so tasks that compare equal keep their
+//! stored order (the one set with `rask reorder`). Tasks without a due date or
+//! estimate always come last, also with `--reverse`.
+
+use crate::model::{Roadmap, Task};
+use clap::ValueEnum;
+use std::cmp::Ordering;
+
+/// Orders accepted by `--sort` and `ui.default_sort`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum SortKey {
+    /// Task ID, lowest first
+    Id,
+    /// Most important first
+    Priority,
+    /// Earliest due date first
+    Due,
+    /// Oldest first
+    Created,
+    /// Smallest estimate first
+    Estimate,
+    /// Phase order of the roadmap
+    Phase,
+}
+
+impl SortKey {
+    /// Parse a `ui.default_sort` value; "date" is kept as a name for `created`
+    pub fn parse(value: &str) -> Option<SortKey> {
+        match value.trim().to_lowercase().as_str() {
+            "date" => Some(SortKey::Created),
+            value => SortKey::from_str(value, true).ok(),
+        }
+    }
+
+    /// The order from `ui.default_sort`, or `id` when it is missing or unknown
+    pub fn from_config() -> SortKey {
+        crate::config::RaskConfig::load()
+            .ok()
+            .and_then(|config| SortKey::parse(&config.ui.default_sort))
+            .unwrap_or(SortKey::Id)
+    }
+}
+
+/// Compare two optional values with missing ones last regardless of direction
+fn compare_present<T: PartialOrd>(a: Option<T>, b: Option<T>, reverse: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            if reverse { ordering.reverse() } else { ordering }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+/// Sort tasks in place by `key`; phases are ordered as in `roadmap`
+pub fn sort_tasks<T: std::borrow::Borrow<Task>>(tasks: &mut [T], key: SortKey, reverse: bool, roadmap: &Roadmap) {
+    tasks.sort_by(|a, b| {
+        let (a, b) = (a.borrow(), b.borrow());
+        let ordering = match key {
+            SortKey::Id => a.id.cmp(&b.id),
+            SortKey::Priority => b.priority.rank().cmp(&a.priority.rank()),
+            SortKey::Phase => roadmap.phase_sort_key(&a.phase).cmp(&roadmap.phase_sort_key(&b.phase)),
+            SortKey::Due => return compare_present(a.due_date_parsed(), b.due_date_parsed(), reverse),
+            SortKey::Created => return compare_present(a.created_datetime(), b.created_datetime(), reverse),
+            SortKey::Estimate => return compare_present(a.estimated_hours, b.estimated_hours, reverse),
+        };
+        if reverse { ordering.reverse() } else { ordering }
+    });
+}
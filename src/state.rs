@@ -1,35 +1,755 @@
-use crate::model::Roadmap;
-use std::fs;
-use std::io::{Error, ErrorKind};
-use std::path::Path;
+use crate::model::{Phase, PhaseArchive, Priority, Roadmap, StateIndex, Task, TaskStatus, CURRENT_SCHEMA_VERSION};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::io::{Error, ErrorKind, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
-/// Save state to local .rask/state.json only
-pub fn save_state(roadmap: &Roadmap) -> Result<(), Error> {
+/// How long a save waits for another process to release the state lock
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// File name prefix of the rolling backups taken before each save
+const AUTO_BACKUP_PREFIX: &str = "state_";
+/// Set once this invocation has backed up the state it started from
+static BACKED_UP: AtomicBool = AtomicBool::new(false);
+/// Revisions this process saved, oldest first, since another process last saved
+static OWN_REVISIONS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+/// The stored state as this process last loaded or saved it, which the next save is compared with
+static BASELINE: Mutex<Option<Roadmap>> = Mutex::new(None);
+
+#[cfg(feature = "sqlite")]
+mod sqlite;
+
+/// How a project's state is stored in .rask
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StorageBackend {
+    /// `state.json` with an `index.json` summary beside it (the default)
+    Json,
+    /// `state.db`, one row per task, for projects with thousands of tasks
+    Sqlite,
+}
+
+impl StorageBackend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            StorageBackend::Json => "json",
+            StorageBackend::Sqlite => "sqlite",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "json" => Some(StorageBackend::Json),
+            "sqlite" | "sqlite3" => Some(StorageBackend::Sqlite),
+            _ => None,
+        }
+    }
+}
+
+/// Operations every storage backend provides
+trait StateStore {
+    /// Whether the backend holds saved state
+    fn exists(&self) -> bool;
+
+    /// The stored state as JSON, before schema migrations
+    fn load_raw(&self) -> Result<Value, Error>;
+
+    /// A roadmap with only the summary fields, when the backend has a cheap way to build one
+    fn load_summary(&self) -> Result<Option<Roadmap>, Error>;
+
+    /// Replace the stored state
+    fn save(&self, roadmap: &Roadmap) -> Result<(), Error>;
+
+    /// Write a JSON copy of the stored state to `path`
+    fn backup_to(&self, path: &Path) -> Result<(), Error>;
+
+    /// Tasks matching a query, in roadmap order
+    fn query(&self, query: &TaskQuery) -> Result<Vec<Task>, Error> {
+        let roadmap = roadmap_from_value(self.load_raw()?)?;
+        Ok(roadmap.tasks.into_iter().filter(|task| query.matches(task)).collect())
+    }
+}
+
+/// `.rask/state.json` plus the `.rask/index.json` summary
+struct JsonStore {
+    state_file: PathBuf,
+}
+
+impl StateStore for JsonStore {
+    fn exists(&self) -> bool {
+        self.state_file.exists()
+    }
+
+    fn load_raw(&self) -> Result<Value, Error> {
+        serde_json::from_str(&fs::read_to_string(&self.state_file)?)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// Read the index unless it is missing or older than state.json
+    fn load_summary(&self) -> Result<Option<Roadmap>, Error> {
+        let index_file = self.state_file.with_file_name("index.json");
+        let index_is_fresh = match (fs::metadata(&self.state_file), fs::metadata(&index_file)) {
+            (Ok(state_meta), Ok(index_meta)) => match (state_meta.modified(), index_meta.modified()) {
+                (Ok(state_time), Ok(index_time)) => index_time >= state_time,
+                _ => false,
+            },
+            _ => false,
+        };
+        if !index_is_fresh {
+            return Ok(None);
+        }
+        
+        Ok(fs::read_to_string(&index_file).ok()
+            .and_then(|data| serde_json::from_str::<StateIndex>(&data).ok())
+            .map(StateIndex::into_roadmap))
+    }
+
+    fn save(&self, roadmap: &Roadmap) -> Result<(), Error> {
+        let json_data = serde_json::to_string_pretty(roadmap)
+            .map_err(Error::other)?;
+        write_atomic(&self.state_file, json_data.as_bytes())?;
+        
+        // The summary index read by list-style commands
+        let index_data = serde_json::to_string(&StateIndex::from_roadmap(roadmap))
+            .map_err(Error::other)?;
+        write_atomic(&self.state_file.with_file_name("index.json"), index_data.as_bytes())
+    }
+
+    fn backup_to(&self, path: &Path) -> Result<(), Error> {
+        fs::copy(&self.state_file, path).map(|_| ())
+    }
+}
+
+/// Filters that `list` and `find` hand to the storage backend
+#[derive(Debug, Default)]
+pub struct TaskQuery {
+    pub status: Option<TaskStatus>,
+    pub priority: Option<Priority>,
+    pub phase: Option<Phase>,
+    pub tags: Vec<String>, // Matches tasks with any of these tags, or tags nested under them
+    pub text: Option<String>, // Searched in descriptions, tags and notes
+}
+
+impl TaskQuery {
+    pub fn matches(&self, task: &Task) -> bool {
+        self.status.as_ref().is_none_or(|status| &task.status == status)
+            && self.priority.as_ref().is_none_or(|priority| &task.priority == priority)
+            && self.phase.as_ref().is_none_or(|phase| task.phase.is_named(&phase.name))
+            && (self.tags.is_empty() || self.tags.iter().any(|tag| task.has_tag_within(tag)))
+            && self.text.as_ref().is_none_or(|text| task.matches_search(&text.to_lowercase()))
+    }
+}
+
+/// Backend of the current project: SQLite once `.rask/state.db` exists
+pub fn storage_backend() -> Result<StorageBackend, Error> {
     let state_file = get_local_state_file()?;
-    let json_data = serde_json::to_string_pretty(roadmap)
-        .map_err(|e| Error::new(ErrorKind::Other, e))?;
+    Ok(if Path::new(&state_file).with_file_name("state.db").exists() {
+        StorageBackend::Sqlite
+    } else {
+        StorageBackend::Json
+    })
+}
+
+fn open_store() -> Result<Box<dyn StateStore>, Error> {
+    store_for(storage_backend()?)
+}
+
+fn store_for(backend: StorageBackend) -> Result<Box<dyn StateStore>, Error> {
+    let state_file = PathBuf::from(get_local_state_file()?);
+    match backend {
+        StorageBackend::Json => Ok(Box::new(JsonStore { state_file })),
+        StorageBackend::Sqlite => open_sqlite(&state_file.with_file_name("state.db")),
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn open_sqlite(path: &Path) -> Result<Box<dyn StateStore>, Error> {
+    Ok(Box::new(sqlite::SqliteStore::open(path)?))
+}
+
+#[cfg(not(feature = "sqlite"))]
+fn open_sqlite(path: &Path) -> Result<Box<dyn StateStore>, Error> {
+    Err(Error::new(ErrorKind::Unsupported, format!(
+        "This project is stored in SQLite ({}), but this rask was built without SQLite support. Reinstall with 'cargo install --path . --features sqlite'.",
+        path.display()
+    )))
+}
+
+/// Save the project state with the project's storage backend.
+pub fn save_state(roadmap: &Roadmap) -> Result<(), Error> {
+    commit_state(roadmap).map(|_| ())
+}
+
+/// Save the project state and return it as it was saved.
+///
+/// Nothing is written when another process saved the project after
+/// `roadmap` was loaded (see [`ensure_current`]), so concurrent commands
+/// cannot overwrite each other's changes.
+///
+/// Automation rules run against the change first (see
+/// [`crate::rules::apply_on_save`]) and the tasks they add are saved with it.
+/// Task changes since the stored state are then logged in each task's history
+/// (see [`Roadmap::record_activity_since`]), so every command leaves an
+/// activity trail however it saves. Once saved, the same changes are sent to
+/// the configured webhooks. The stored state they are compared with is the
+/// copy kept when it was loaded, so saving does not read it back.
+pub fn commit_state(roadmap: &Roadmap) -> Result<Roadmap, Error> {
+    let store = open_store()?;
+    let current = stored_revision()?;
+    ensure_current(roadmap, &current)?;
+    let mut roadmap = roadmap.clone();
+    let saved = stored_baseline(store.as_ref(), &current);
+    // Rule webhooks may take a while, so they run before other processes are locked out
+    if let Some(saved) = &saved {
+        crate::rules::apply_on_save(saved, &mut roadmap);
+    }
+    let lock = lock_state()?;
+    let previous = stored_revision()?;
+    ensure_current(&roadmap, &previous)?;
+    if let Err(e) = backup_before_save(store.as_ref()) {
+        crate::ui::display_warning(&format!("Could not back up the state before saving: {}", e));
+    }
+    if let Some(saved) = &saved {
+        roadmap.record_activity_since(saved);
+    }
+    tracing::debug!(tasks = roadmap.tasks.len(), "saving project state");
+    store.save(&roadmap)?;
+    roadmap.revision = Some(lock.write_revision(&previous)?);
+    *BASELINE.lock().unwrap_or_else(PoisonError::into_inner) = Some(roadmap.clone());
+    // Other commands need not wait for the webhooks
+    drop(lock);
+    if let Some(saved) = &saved {
+        crate::notifications::notify_changes(saved, &roadmap);
+    }
+    Ok(roadmap)
+}
+
+/// Move the project state to another storage backend.
+///
+/// The state is written to the new backend before the old files are removed,
+/// and a backup of it is taken first.
+pub fn convert_storage(target: StorageBackend) -> Result<(), Error> {
+    let roadmap = load_state()?;
+    let source = open_store()?;
+    let _lock = lock_state()?;
+    backup_before_save(source.as_ref())?;
     
-    // Ensure the .rask directory exists
-    if let Some(parent) = Path::new(&state_file).parent() {
-        fs::create_dir_all(parent)?;
+    let state_file = PathBuf::from(get_local_state_file()?);
+    let database = state_file.with_file_name("state.db");
+    let result = store_for(target).and_then(|store| store.save(&roadmap));
+    match target {
+        StorageBackend::Sqlite => {
+            if result.is_err() && database.exists() {
+                fs::remove_file(&database)?;
+            }
+            result?;
+            fs::remove_file(&state_file)?;
+            let index_file = state_file.with_file_name("index.json");
+            if index_file.exists() {
+                fs::remove_file(index_file)?;
+            }
+        }
+        StorageBackend::Json => {
+            result?;
+            fs::remove_file(&database)?;
+        }
     }
+    Ok(())
+}
+
+/// Refuse to save a roadmap loaded before another process saved the project.
+///
+/// Saving it would silently drop that process's changes. A roadmap this
+/// process loaded and then saved, possibly several times, is still current;
+/// one built from scratch (init, import, restore) has no revision to check.
+fn ensure_current(roadmap: &Roadmap, current: &str) -> Result<(), Error> {
+    let Some(loaded) = roadmap.revision.as_deref() else {
+        return Ok(());
+    };
+    let own = OWN_REVISIONS.lock().unwrap_or_else(PoisonError::into_inner);
+    let saved_by_us_since = own.last().is_some_and(|last| last == current) && own.iter().any(|r| r == loaded);
+    if loaded == current || saved_by_us_since {
+        return Ok(());
+    }
+    Err(Error::other(
+        "The project was changed by another rask process while this command ran, so nothing was saved. Run the command again."
+    ))
+}
+
+/// The stored state at `revision`: the copy kept when this process last
+/// loaded or saved it, read from the store only when that copy is out of date
+fn stored_baseline(store: &dyn StateStore, revision: &str) -> Option<Roadmap> {
+    let cached = BASELINE.lock().unwrap_or_else(PoisonError::into_inner).take()
+        .filter(|baseline| baseline.revision.as_deref() == Some(revision));
+    cached.or_else(|| if store.exists() { store.load_raw().and_then(roadmap_from_value).ok() } else { None })
+}
+
+/// Revision of the stored state, kept in .rask/state.lock and replaced by every save
+fn stored_revision() -> Result<String, Error> {
+    match fs::read_to_string(get_lock_file()?) {
+        Ok(revision) => Ok(revision.trim().to_string()),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(String::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Exclusive advisory lock on .rask/state.lock, released when dropped
+pub struct StateLock {
+    file: File,
+}
+
+impl StateLock {
+    /// Record a save by writing a new revision over `previous` in the lock file
+    fn write_revision(&self, previous: &str) -> Result<String, Error> {
+        let revision = uuid::Uuid::new_v4().to_string();
+        let mut file = &self.file;
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(revision.as_bytes())?;
+        
+        let mut own = OWN_REVISIONS.lock().unwrap_or_else(PoisonError::into_inner);
+        if own.last().is_none_or(|last| last != previous) {
+            own.clear();
+            own.push(previous.to_string());
+        }
+        own.push(revision.clone());
+        Ok(revision)
+    }
+}
+
+/// Take the lock that serialises writes to the state files.
+///
+/// Another `rask` process (a second terminal, a script, the TUI) may be
+/// saving at the same moment, so this retries for a few seconds before
+/// giving up with an error naming the lock file.
+pub fn lock_state() -> Result<StateLock, Error> {
+    let lock_file = get_lock_file()?;
+    let file = OpenOptions::new().create(true).truncate(false).write(true).open(&lock_file)?;
+    let started = Instant::now();
+    let mut waiting = false;
     
-    fs::write(&state_file, json_data)
+    loop {
+        match file.try_lock() {
+            Ok(()) => return Ok(StateLock { file }),
+            Err(TryLockError::WouldBlock) if started.elapsed() < LOCK_TIMEOUT => {
+                if !waiting {
+                    crate::ui::display_info("⏳ Another rask process is saving this project, waiting...");
+                    waiting = true;
+                }
+                std::thread::sleep(LOCK_RETRY_INTERVAL);
+            }
+            Err(TryLockError::WouldBlock) => {
+                return Err(Error::new(ErrorKind::WouldBlock, format!(
+                    "Project is locked by another process ({}). Try again once it has finished.",
+                    lock_file.display()
+                )));
+            }
+            Err(TryLockError::Error(e)) => return Err(e),
+        }
+    }
+}
+
+/// Replace a file in one step: write a temporary file beside it, then rename
+/// it over the original so readers never see a half-written file
+fn write_atomic(path: &Path, data: &[u8]) -> Result<(), Error> {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("state");
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name));
+    let mut file = File::create(&temp_path)?;
+    file.write_all(data)?;
+    file.sync_all()?;
+    fs::rename(&temp_path, path)
+}
+
+/// Load a roadmap containing only the fields in the summary index.
+/// Falls back to the full state when the backend has no up-to-date summary,
+/// or when escalation rules need the full tasks.
+pub fn load_state_summary() -> Result<Roadmap, Error> {
+    if !escalation_rules().is_empty() {
+        return load_state();
+    }
+    match open_store()?.load_summary()? {
+        Some(roadmap) => Ok(roadmap),
+        None => load_state(),
+    }
 }
 
-/// Load state from local .rask/state.json only
+/// Load the project state from .rask, with escalation rules applied
 pub fn load_state() -> Result<Roadmap, Error> {
-    let state_file = get_local_state_file()?;
-    if !Path::new(&state_file).exists() {
+    let mut roadmap = load_stored_state()?;
+    let escalations = escalation_rules();
+    if !escalations.is_empty() {
+        crate::rules::apply_escalations(&escalations, &mut roadmap, chrono::Local::now().date_naive());
+    }
+    Ok(roadmap)
+}
+
+/// Load the project state exactly as it was saved
+pub fn load_stored_state() -> Result<Roadmap, Error> {
+    let store = open_store()?;
+    if !store.exists() {
         return Err(Error::new(ErrorKind::NotFound, 
             "No .rask directory found. Please run 'rask init <roadmap.md>' in this directory first."));
     }
-    let json_data = fs::read_to_string(&state_file)?;
-    let roadmap: Roadmap = serde_json::from_str(&json_data)
+    // Read before the state, so a save in between is caught rather than missed
+    let revision = stored_revision()?;
+    let mut roadmap = roadmap_from_value(store.load_raw()?)?;
+    roadmap.revision = Some(revision);
+    *BASELINE.lock().unwrap_or_else(PoisonError::into_inner) = Some(roadmap.clone());
+    tracing::debug!(tasks = roadmap.tasks.len(), "loaded project state");
+    Ok(roadmap)
+}
+
+/// Load only the tasks matching a query, using the backend's indexes when it has them
+pub fn query_tasks(query: &TaskQuery) -> Result<Vec<Task>, Error> {
+    // Escalations can change priorities and tags, so filter the escalated tasks instead
+    if !escalation_rules().is_empty() {
+        return Ok(load_state()?.tasks.into_iter().filter(|task| query.matches(task)).collect());
+    }
+    open_store()?.query(query)
+}
+
+/// Enabled escalation rules from the config (none if it cannot be read)
+fn escalation_rules() -> Vec<crate::config::EscalationRule> {
+    crate::config::RaskConfig::load()
+        .map(|config| config.escalations.into_iter().filter(|r| r.enabled).collect())
+        .unwrap_or_default()
+}
+
+/// Deserialize a state file, upgrading it to the current schema first
+fn parse_state(json_data: &str) -> Result<Roadmap, Error> {
+    roadmap_from_value(serde_json::from_str(json_data)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?)
+}
+
+/// Build a roadmap from raw state JSON, upgrading it to the current schema first
+fn roadmap_from_value(mut value: Value) -> Result<Roadmap, Error> {
+    upgrade_state(&mut value)?;
+    let mut roadmap: Roadmap = serde_json::from_value(value)
         .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+    roadmap.refresh_timestamp_cache();
     Ok(roadmap)
 }
 
+/// One step that upgrades raw state JSON from `from` to `from + 1`
+struct Migration {
+    from: u32,
+    description: &'static str,
+    apply: fn(&mut Value),
+}
+
+/// Every schema upgrade in order; the last one must end at `CURRENT_SCHEMA_VERSION`
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from: 0,
+        description: "store plain-text implementation notes as typed notes and record each task's sync flag",
+        apply: migrate_v0_to_v1,
+    },
+    Migration {
+        from: 1,
+        description: "give every task a UUID that survives renumbering and project moves",
+        apply: migrate_v1_to_v2,
+    },
+];
+
+/// Schema version a raw state file declares
+fn schema_version(value: &Value) -> u32 {
+    value.get("schema_version").and_then(Value::as_u64).map_or(0, |v| v as u32)
+}
+
+/// Migrations still to run on a state file at `version`
+fn pending_migrations(version: u32) -> Result<Vec<&'static Migration>, Error> {
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(Error::new(ErrorKind::InvalidData, format!(
+            "The project state uses schema version {} but this rask only understands up to version {}. Upgrade rask to open it.",
+            version, CURRENT_SCHEMA_VERSION
+        )));
+    }
+    debug_assert_eq!(MIGRATIONS.last().map_or(0, |m| m.from + 1), CURRENT_SCHEMA_VERSION);
+    Ok(MIGRATIONS.iter().filter(|m| m.from >= version).collect())
+}
+
+/// Apply pending migrations one version at a time
+fn upgrade_state(value: &mut Value) -> Result<(), Error> {
+    for migration in pending_migrations(schema_version(value))? {
+        tracing::debug!(from = migration.from, to = migration.from + 1, "migrating project state");
+        (migration.apply)(value);
+        value["schema_version"] = Value::from(migration.from + 1);
+    }
+    Ok(())
+}
+
+fn migrate_v0_to_v1(value: &mut Value) {
+    let Some(tasks) = value.get_mut("tasks").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for task in tasks.iter_mut().filter_map(Value::as_object_mut) {
+        if let Some(notes) = task.get_mut("implementation_notes").and_then(Value::as_array_mut) {
+            for note in notes.iter_mut() {
+                if let Value::String(text) = note {
+                    *note = serde_json::json!({ "text": text, "kind": "General" });
+                }
+            }
+        }
+        task.entry("sync").or_insert(Value::Bool(true));
+    }
+}
+
+/// Derive each task's UUID from the project and the task itself, so a state
+/// that is only upgraded in memory shows the same UUIDs on every load
+fn migrate_v1_to_v2(value: &mut Value) {
+    let project = value.get("project_id").and_then(Value::as_str)
+        .or_else(|| value.get("title").and_then(Value::as_str))
+        .unwrap_or_default()
+        .to_string();
+    let Some(tasks) = value.get_mut("tasks").and_then(Value::as_array_mut) else {
+        return;
+    };
+    for (index, task) in tasks.iter_mut().enumerate() {
+        let Some(task) = task.as_object_mut().filter(|task| !task.contains_key("uuid")) else {
+            continue;
+        };
+        let seed = format!(
+            "{}/{}/{}/{}/{}",
+            project,
+            index,
+            task.get("id").map(Value::to_string).unwrap_or_default(),
+            task.get("created_at").and_then(Value::as_str).unwrap_or_default(),
+            task.get("description").and_then(Value::as_str).unwrap_or_default()
+        );
+        let digest = hmac_sha256::Hash::hash(seed.as_bytes());
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        task.insert("uuid".into(), Value::from(uuid::Builder::from_random_bytes(bytes).into_uuid().to_string()));
+    }
+}
+
+/// Schema version of the state file on disk and the migrations it still needs
+pub fn check_migrations() -> Result<(u32, Vec<&'static str>), Error> {
+    let value = open_store()?.load_raw()?;
+    let version = schema_version(&value);
+    let steps = pending_migrations(version)?.into_iter().map(|m| m.description).collect();
+    Ok((version, steps))
+}
+
+/// A saved copy of state.json in .rask/backups
+#[derive(Debug, Serialize)]
+pub struct StateBackup {
+    pub name: String,
+    pub path: PathBuf,
+    pub created: chrono::DateTime<chrono::Local>,
+    pub tasks: Option<usize>, // None when the file is not a readable state
+}
+
+/// Copy state.json into .rask/backups the first time a command saves.
+///
+/// Only the oldest automatic backups beyond `behavior.backup_count` are
+/// pruned; the `backup_*.json` files written by `rask sync` are left alone.
+fn backup_before_save(store: &dyn StateStore) -> Result<(), Error> {
+    if BACKED_UP.swap(true, Ordering::Relaxed) || !store.exists() {
+        return Ok(());
+    }
+    let keep = crate::config::RaskConfig::load()
+        .map(|config| config.behavior.backup_count)
+        .unwrap_or(crate::config::DEFAULT_BACKUP_COUNT);
+    if keep == 0 {
+        return Ok(());
+    }
+    
+    let backup_dir = get_backups_dir()?;
+    fs::create_dir_all(&backup_dir)?;
+    let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S_%3f");
+    store.backup_to(&backup_dir.join(format!("{}{}.json", AUTO_BACKUP_PREFIX, timestamp)))?;
+    
+    let mut automatic: Vec<PathBuf> = fs::read_dir(&backup_dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.file_name().and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with(AUTO_BACKUP_PREFIX) && n.ends_with(".json")))
+        .collect();
+    automatic.sort();
+    let excess = automatic.len().saturating_sub(keep);
+    for path in &automatic[..excess] {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// List the backups in .rask/backups, newest first
+pub fn list_backups() -> Result<Vec<StateBackup>, Error> {
+    let backup_dir = get_backups_dir()?;
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
+    
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(&backup_dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(name) = path.file_stem().and_then(|n| n.to_str()).map(String::from) else {
+            continue;
+        };
+        let created = path.metadata()?.modified()?.into();
+        let tasks = fs::read_to_string(&path).ok()
+            .and_then(|json| parse_state(&json).ok())
+            .map(|roadmap| roadmap.tasks.len());
+        backups.push(StateBackup { name, path, created, tasks });
+    }
+    backups.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| b.name.cmp(&a.name)));
+    Ok(backups)
+}
+
+/// Read the roadmap stored in a backup
+pub fn load_backup(backup: &StateBackup) -> Result<Roadmap, Error> {
+    parse_state(&fs::read_to_string(&backup.path)?)
+}
+
+/// Save an archived phase to .rask/archive/<phase>.json and return the path
+pub fn save_phase_archive(archive: &PhaseArchive) -> Result<PathBuf, Error> {
+    let archive_dir = get_archive_dir()?;
+    fs::create_dir_all(&archive_dir)?;
+    
+    let archive_file = archive_dir.join(format!("{}.json", PhaseArchive::file_stem(&archive.summary.phase)));
+    let json_data = serde_json::to_string_pretty(archive)
+        .map_err(Error::other)?;
+    write_atomic(&archive_file, json_data.as_bytes())?;
+    Ok(archive_file)
+}
+
+/// Load an archived phase on demand, returning None if it was never archived
+pub fn load_phase_archive(phase: &str) -> Result<Option<PhaseArchive>, Error> {
+    let archive_file = get_archive_dir()?.join(format!("{}.json", PhaseArchive::file_stem(phase)));
+    if !archive_file.exists() {
+        return Ok(None);
+    }
+    let json_data = fs::read_to_string(&archive_file)?;
+    let mut archive: PhaseArchive = serde_json::from_str(&json_data)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+    for task in &mut archive.tasks {
+        task.refresh_timestamp_cache();
+    }
+    Ok(Some(archive))
+}
+
+/// Snapshot taken before a multi-step operation (bulk edits, AI apply).
+///
+/// The checkpoint file only exists while an operation is running, so finding
+/// one means the last run stopped partway and the state may be half-applied.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OperationCheckpoint {
+    pub operation: String, // Command that was running, e.g. "bulk complete"
+    pub started_at: String, // ISO 8601 timestamp
+    pub snapshot: Roadmap, // State before the operation touched anything
+}
+
+/// Record that an operation is starting, keeping a snapshot for rollback
+pub fn begin_operation(operation: &str, roadmap: &Roadmap) -> Result<(), Error> {
+    let checkpoint = OperationCheckpoint {
+        operation: operation.to_string(),
+        started_at: chrono::Utc::now().to_rfc3339(),
+        snapshot: roadmap.clone(),
+    };
+    let json_data = serde_json::to_string(&checkpoint).map_err(Error::other)?;
+    write_atomic(&get_checkpoint_file()?, json_data.as_bytes())
+}
+
+/// Mark the running operation as finished by removing its checkpoint
+pub fn finish_operation() -> Result<(), Error> {
+    let checkpoint_file = get_checkpoint_file()?;
+    if checkpoint_file.exists() {
+        fs::remove_file(checkpoint_file)?;
+    }
+    Ok(())
+}
+
+/// Load the checkpoint of an operation that did not finish, if any
+pub fn load_checkpoint() -> Result<Option<OperationCheckpoint>, Error> {
+    let checkpoint_file = get_checkpoint_file()?;
+    if !checkpoint_file.exists() {
+        return Ok(None);
+    }
+    let json_data = fs::read_to_string(&checkpoint_file)?;
+    let mut checkpoint: OperationCheckpoint = serde_json::from_str(&json_data)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+    checkpoint.snapshot.refresh_timestamp_cache();
+    Ok(Some(checkpoint))
+}
+
+/// Get the local .rask/checkpoint.json file path
+fn get_checkpoint_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("checkpoint.json"))
+}
+
+/// Get the local .rask/state.lock file path
+fn get_lock_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("state.lock"))
+}
+
+/// Get the local .rask/audit.log file path
+pub fn get_audit_log_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("audit.log"))
+}
+
+/// Get the local .rask/source.hash file path, holding the hash of the
+/// markdown source as rask last wrote or read it
+pub fn get_source_hash_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("source.hash"))
+}
+
+/// Get the local .rask/source.synced.md file path, holding a copy of the
+/// markdown source as rask last wrote or read it
+pub fn get_synced_source_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("source.synced.md"))
+}
+
+/// Get the local .rask/hooks directory path
+pub fn get_hooks_dir() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("hooks"))
+}
+
+/// Get the local .rask/backups directory path
+pub fn get_backups_dir() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("backups"))
+}
+
+/// Get the local .rask/cache/reminders.json file path, recording which
+/// desktop reminders were already shown
+pub fn get_reminders_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("cache").join("reminders.json"))
+}
+
+/// Get the local .rask/cache/forecasts.json file path, holding the last
+/// `rask forecast` per phase
+pub fn get_forecasts_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("cache").join("forecasts.json"))
+}
+
+/// Get the local .rask/dismissed_duplicates.json file path, holding the task
+/// pairs `rask dedupe` was told are not duplicates
+pub fn get_dismissed_duplicates_file() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("dismissed_duplicates.json"))
+}
+
+/// Get the local .rask/archive directory path
+pub fn get_archive_dir() -> Result<PathBuf, Error> {
+    let state_file = get_local_state_file()?;
+    Ok(Path::new(&state_file).with_file_name("archive"))
+}
+
 /// Get the local .rask/state.json file path
 /// This is the only state file location in the simplified local-only approach
 fn get_local_state_file() -> Result<String, Error> {
@@ -0,0 +1,245 @@
+//! SQLite state backend
+//!
+//! Keeps each task in its own row of `.rask/state.db`, next to indexed
+//! status, priority and phase columns and a lowercased search column. Saving
+//! only rewrites the rows whose task changed, and `list`/`find` select the
+//! matching rows instead of deserializing the whole roadmap. Everything else
+//! in the roadmap is stored as a single JSON document in the `meta` table.
+
+use super::{schema_version, StateStore, TaskQuery, LOCK_TIMEOUT};
+use crate::model::{Roadmap, StateIndex, Task, TaskStatus, TaskSummary, CURRENT_SCHEMA_VERSION};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS meta (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS tasks (
+        id INTEGER PRIMARY KEY,
+        position INTEGER NOT NULL,
+        status TEXT NOT NULL,
+        priority TEXT NOT NULL,
+        phase TEXT NOT NULL,
+        tags TEXT NOT NULL,
+        search_text TEXT NOT NULL,
+        summary TEXT NOT NULL,
+        data TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS tasks_status ON tasks (status);
+    CREATE INDEX IF NOT EXISTS tasks_priority ON tasks (priority);
+    CREATE INDEX IF NOT EXISTS tasks_phase ON tasks (phase);
+    CREATE INDEX IF NOT EXISTS tasks_position ON tasks (position);
+";
+
+pub struct SqliteStore {
+    conn: Connection,
+}
+
+fn db_error(e: rusqlite::Error) -> Error {
+    Error::other(e)
+}
+
+fn invalid_data(e: serde_json::Error) -> Error {
+    Error::new(ErrorKind::InvalidData, e)
+}
+
+fn status_key(status: &TaskStatus) -> &'static str {
+    match status {
+        TaskStatus::Pending => "pending",
+        TaskStatus::Completed => "completed",
+    }
+}
+
+/// Sorted tags wrapped in commas, so `,tag,` matches a whole tag
+fn tags_key<'a>(tags: impl Iterator<Item = &'a String>) -> String {
+    let mut tags: Vec<&str> = tags.map(String::as_str).collect();
+    tags.sort_unstable();
+    format!(",{},", tags.join(","))
+}
+
+/// Lowercased text `find` and `list --search` look through
+fn search_text(task: &Task) -> String {
+    let mut parts = vec![task.description.to_lowercase()];
+    parts.extend(task.tags.iter().map(|tag| tag.to_lowercase()));
+    parts.extend(task.notes.as_ref().map(|notes| notes.to_lowercase()));
+    parts.join("\n")
+}
+
+impl SqliteStore {
+    pub fn open(path: &Path) -> Result<Self, Error> {
+        let conn = Connection::open(path).map_err(db_error)?;
+        conn.busy_timeout(LOCK_TIMEOUT).map_err(db_error)?;
+        conn.execute_batch(SCHEMA).map_err(db_error)?;
+        Ok(SqliteStore { conn })
+    }
+
+    /// The roadmap document without its tasks
+    fn meta(&self) -> Result<Option<Value>, Error> {
+        let json: Option<String> = self.conn
+            .query_row("SELECT value FROM meta WHERE key = 'roadmap'", [], |row| row.get(0))
+            .optional()
+            .map_err(db_error)?;
+        json.map(|json| serde_json::from_str(&json).map_err(invalid_data)).transpose()
+    }
+
+    /// Whether the rows can be read as-is, without running migrations first
+    fn is_current(&self) -> Result<bool, Error> {
+        Ok(self.meta()?.is_some_and(|meta| schema_version(&meta) == CURRENT_SCHEMA_VERSION))
+    }
+
+    fn column(&self, sql: &str, args: &[String]) -> Result<Vec<String>, Error> {
+        let mut statement = self.conn.prepare(sql).map_err(db_error)?;
+        let rows = statement
+            .query_map(params_from_iter(args.iter()), |row| row.get::<_, String>(0))
+            .map_err(db_error)?;
+        rows.collect::<Result<_, _>>().map_err(db_error)
+    }
+}
+
+impl StateStore for SqliteStore {
+    fn exists(&self) -> bool {
+        matches!(self.meta(), Ok(Some(_)))
+    }
+
+    fn load_raw(&self) -> Result<Value, Error> {
+        let mut state = self.meta()?
+            .ok_or_else(|| Error::new(ErrorKind::NotFound, "The SQLite state database is empty"))?;
+        let tasks = self.column("SELECT data FROM tasks ORDER BY position", &[])?
+            .iter()
+            .map(|data| serde_json::from_str(data).map_err(invalid_data))
+            .collect::<Result<Vec<Value>, Error>>()?;
+        state["tasks"] = Value::Array(tasks);
+        Ok(state)
+    }
+
+    fn load_summary(&self) -> Result<Option<Roadmap>, Error> {
+        let Some(meta) = self.meta()? else {
+            return Ok(None);
+        };
+        if schema_version(&meta) != CURRENT_SCHEMA_VERSION {
+            return Ok(None);
+        }
+        
+        let tasks = self.column("SELECT summary FROM tasks ORDER BY position", &[])?
+            .iter()
+            .map(|summary| serde_json::from_str::<TaskSummary>(summary).map_err(invalid_data))
+            .collect::<Result<Vec<_>, Error>>()?;
+        let title = meta.get("title").and_then(Value::as_str).unwrap_or_default().to_string();
+        Ok(Some(StateIndex { title, tasks }.into_roadmap()))
+    }
+
+    /// Write the roadmap document and only the task rows that changed
+    fn save(&self, roadmap: &Roadmap) -> Result<(), Error> {
+        let mut meta = serde_json::to_value(roadmap).map_err(Error::other)?;
+        if let Some(object) = meta.as_object_mut() {
+            object.remove("tasks");
+        }
+        
+        let transaction = self.conn.unchecked_transaction().map_err(db_error)?;
+        transaction.execute(
+            "INSERT INTO meta (key, value) VALUES ('roadmap', ?1) ON CONFLICT (key) DO UPDATE SET value = excluded.value",
+            [meta.to_string()],
+        ).map_err(db_error)?;
+        
+        let existing: HashMap<i64, (i64, String)> = {
+            let mut statement = transaction.prepare("SELECT id, position, data FROM tasks").map_err(db_error)?;
+            let rows = statement
+                .query_map([], |row| Ok((row.get(0)?, (row.get(1)?, row.get(2)?))))
+                .map_err(db_error)?;
+            rows.collect::<Result<_, _>>().map_err(db_error)?
+        };
+        
+        {
+            let mut upsert = transaction.prepare(
+                "INSERT OR REPLACE INTO tasks (id, position, status, priority, phase, tags, search_text, summary, data)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            ).map_err(db_error)?;
+            for (position, task) in roadmap.tasks.iter().enumerate() {
+                let (id, position) = (task.id as i64, position as i64);
+                let data = serde_json::to_string(task).map_err(Error::other)?;
+                if existing.get(&id).is_some_and(|(old_position, old_data)| *old_position == position && *old_data == data) {
+                    continue;
+                }
+                let summary = serde_json::to_string(&TaskSummary::from_task(task)).map_err(Error::other)?;
+                upsert.execute(params![
+                    id,
+                    position,
+                    status_key(&task.status),
+                    task.priority.to_string().to_lowercase(),
+                    task.phase.name,
+                    tags_key(task.tags.iter()),
+                    search_text(task),
+                    summary,
+                    data,
+                ]).map_err(db_error)?;
+            }
+            
+            let kept: HashSet<i64> = roadmap.tasks.iter().map(|task| task.id as i64).collect();
+            let mut delete = transaction.prepare("DELETE FROM tasks WHERE id = ?1").map_err(db_error)?;
+            for id in existing.keys().filter(|id| !kept.contains(id)) {
+                delete.execute([id]).map_err(db_error)?;
+            }
+        }
+        
+        transaction.commit().map_err(db_error)
+    }
+
+    fn backup_to(&self, path: &Path) -> Result<(), Error> {
+        let json_data = serde_json::to_string_pretty(&self.load_raw()?).map_err(Error::other)?;
+        fs::write(path, json_data)
+    }
+
+    /// Narrow the rows with the indexed columns, then check each task exactly
+    fn query(&self, query: &TaskQuery) -> Result<Vec<Task>, Error> {
+        if !self.is_current()? {
+            let roadmap = super::roadmap_from_value(self.load_raw()?)?;
+            return Ok(roadmap.tasks.into_iter().filter(|task| query.matches(task)).collect());
+        }
+        
+        let mut sql = String::from("SELECT data FROM tasks WHERE 1 = 1");
+        let mut args = Vec::new();
+        if let Some(status) = &query.status {
+            sql.push_str(" AND status = ?");
+            args.push(status_key(status).to_string());
+        }
+        if let Some(priority) = &query.priority {
+            sql.push_str(" AND priority = ?");
+            args.push(priority.to_string().to_lowercase());
+        }
+        if let Some(phase) = &query.phase {
+            sql.push_str(" AND phase = ? COLLATE NOCASE");
+            args.push(phase.name.clone());
+        }
+        if !query.tags.is_empty() {
+            // `,area,` finds the tag itself and `,area/` the tags nested under it
+            let conditions = vec!["instr(tags, ?) > 0 OR instr(tags, ?) > 0"; query.tags.len()];
+            sql.push_str(&format!(" AND ({})", conditions.join(" OR ")));
+            for tag in &query.tags {
+                let tag = tag.trim_end_matches('/');
+                args.push(format!(",{},", tag));
+                args.push(format!(",{}/", tag));
+            }
+        }
+        if let Some(text) = &query.text {
+            sql.push_str(" AND instr(search_text, ?) > 0");
+            args.push(text.to_lowercase());
+        }
+        sql.push_str(" ORDER BY position");
+        
+        let mut tasks = Vec::new();
+        for data in self.column(&sql, &args)? {
+            let mut task: Task = serde_json::from_str(&data).map_err(invalid_data)?;
+            if query.matches(&task) {
+                task.refresh_timestamp_cache();
+                tasks.push(task);
+            }
+        }
+        Ok(tasks)
+    }
+}
@@ -1,5 +1,6 @@
-use crate::commands::analytics::{ProgressAnalytics, PhaseAnalytics, PriorityAnalytics, TimeAnalytics};
-use crate::model::{Roadmap, Priority};
+use crate::commands::analytics::{ProgressAnalytics, PhaseAnalytics, PriorityAnalytics, StalenessAnalytics, TagAnalytics, TimeAnalytics};
+use crate::model::{MilestoneProgress, MilestoneStatus, Roadmap, Priority};
+use crate::ui::theme::theme;
 use colored::*;
 
 /// Display comprehensive analytics overview
@@ -20,6 +21,9 @@ pub fn display_analytics_overview(analytics: &ProgressAnalytics) {
     // Quick phase summary
     display_phase_summary(&analytics.phase_analytics);
     
+    // Milestone countdown
+    display_milestone_summary(&analytics.milestones);
+    
     println!("\n💡 Use {} for detailed analytics", "rask analytics --help".bright_cyan());
     println!();
 }
@@ -78,6 +82,44 @@ pub fn display_time_analytics(time_analytics: &TimeAnalytics) {
     println!();
 }
 
+/// Display how long pending tasks have gone without activity
+pub fn display_staleness_analytics(staleness: &StalenessAnalytics) {
+    println!("\n{}", "═".repeat(70).bright_blue());
+    println!("  {}", "🕸️ Staleness Analytics".bold().bright_cyan());
+    println!("{}", "═".repeat(70).bright_blue());
+    
+    let pending: usize = staleness.idle_buckets.iter().sum();
+    if pending == 0 {
+        println!("\n  📊 No pending tasks with activity data.");
+        println!();
+        return;
+    }
+    
+    println!("\n  📈 {}:", "Idle Time".bold());
+    if staleness.stale_after_days > 0 {
+        println!("      Stale tasks: {} (no activity for {}+ days)",
+            staleness.stale_tasks.to_string().bright_red(),
+            staleness.stale_after_days
+        );
+    }
+    println!("      Average idle time: {:.1} days", staleness.average_idle_days);
+    
+    println!("\n  📊 {}:", "Pending Tasks by Idle Time".bold());
+    let labels = ["Under a week", "1-4 weeks", "1-3 months", "Over 3 months"];
+    for (label, count) in labels.iter().zip(staleness.idle_buckets) {
+        println!("      {:<14} {} {}", label, create_progress_bar(count, pending, 20), count);
+    }
+    
+    if !staleness.oldest.is_empty() {
+        println!("\n  🕰️ {}:", "Longest Idle".bold());
+        for task in &staleness.oldest {
+            println!("      #{} {} {}", task.id, task.description, format!("({}d)", task.idle_days).dimmed());
+        }
+    }
+    
+    println!();
+}
+
 /// Display phase analytics
 pub fn display_phase_analytics(phase_analytics: &[PhaseAnalytics]) {
     println!("\n{}", "═".repeat(70).bright_blue());
@@ -92,8 +134,8 @@ pub fn display_phase_analytics(phase_analytics: &[PhaseAnalytics]) {
     
     for phase in phase_analytics {
         println!("\n  {} {} {}:", 
-            phase.phase.emoji(), 
-            phase.phase.name.bold().bright_white(),
+            theme().phase_emoji(&phase.phase), 
+            theme().phase_text(&phase.phase.name, phase.phase.name.bold().bright_white()),
             if phase.phase.is_predefined() { "" } else { "(custom)" }.dimmed()
         );
         
@@ -164,6 +206,37 @@ pub fn display_priority_analytics(priority_analytics: &[PriorityAnalytics]) {
     println!();
 }
 
+/// Display analytics per top-level tag namespace
+pub fn display_tag_analytics(tag_analytics: &[TagAnalytics]) {
+    println!("\n{}", "═".repeat(70).bright_blue());
+    println!("  {}", "🏷️  Tag Namespace Analytics".bold().bright_cyan());
+    println!("{}", "═".repeat(70).bright_blue());
+    
+    if tag_analytics.is_empty() {
+        println!("\n  📊 No tagged tasks yet.");
+        return;
+    }
+    
+    for namespace in tag_analytics {
+        let nested = if namespace.tags > 1 { format!(" ({} tags)", namespace.tags) } else { String::new() };
+        println!("\n  #{}{}:", namespace.namespace.bold().bright_magenta(), nested.bright_black());
+        
+        let progress_bar = create_progress_bar(namespace.completed_tasks, namespace.total_tasks, 25);
+        println!("      Tasks: {} {:.1}% ({}/{})", 
+            progress_bar,
+            namespace.completion_rate,
+            namespace.completed_tasks.to_string().bright_green(),
+            namespace.total_tasks.to_string().bright_white()
+        );
+        
+        if namespace.estimated_hours > 0.0 || namespace.actual_hours > 0.0 {
+            println!("      Time: {:.1}h estimated, {:.1}h tracked", namespace.estimated_hours, namespace.actual_hours);
+        }
+    }
+    
+    println!();
+}
+
 /// Display trend analytics (placeholder for future implementation)
 pub fn display_trend_analytics(roadmap: &Roadmap, analytics: &ProgressAnalytics) -> Result<(), Box<dyn std::error::Error>> {
     println!("\n{}", "═".repeat(70).bright_blue());
@@ -189,13 +262,11 @@ pub fn display_trend_analytics(roadmap: &Roadmap, analytics: &ProgressAnalytics)
         println!("      Tasks with timestamps: {}/{}", tasks_with_dates, roadmap.tasks.len());
         
         // Find project start date
-        if let Some(earliest_task) = roadmap.tasks.iter()
-            .filter_map(|t| t.created_at.as_ref())
+        if let Some(start_date) = roadmap.tasks.iter()
+            .filter_map(|t| t.created_datetime())
             .min() {
-            if let Ok(start_date) = chrono::DateTime::parse_from_rfc3339(earliest_task) {
-                let days_active = (chrono::Utc::now() - start_date.with_timezone(&chrono::Utc)).num_days();
-                println!("      Project active: {} days", days_active);
-            }
+            let days_active = (chrono::Utc::now() - start_date).num_days();
+            println!("      Project active: {} days", days_active);
         }
     }
     
@@ -317,8 +388,8 @@ fn display_phase_summary(phase_analytics: &[PhaseAnalytics]) {
             
             println!("      {} {} {}: {:.0}% ({}/{})", 
                 progress_indicator,
-                phase.phase.emoji(),
-                phase.phase.name,
+                theme().phase_emoji(&phase.phase),
+                theme().phase_text(&phase.phase.name, phase.phase.name.normal()),
                 phase.completion_rate,
                 phase.completed_tasks,
                 phase.total_tasks
@@ -331,18 +402,39 @@ fn display_phase_summary(phase_analytics: &[PhaseAnalytics]) {
     }
 }
 
+/// Display open milestones and warn about those falling behind
+fn display_milestone_summary(milestones: &[MilestoneProgress]) {
+    let open: Vec<&MilestoneProgress> = milestones.iter()
+        .filter(|m| m.status != MilestoneStatus::Done)
+        .collect();
+    if open.is_empty() {
+        return;
+    }
+    
+    println!("\n  🏁 {}:", "Milestones".bold());
+    for milestone in &open {
+        println!("      {}", super::format_milestone_line(milestone));
+    }
+    
+    let behind = open.iter().filter(|m| matches!(m.status, MilestoneStatus::AtRisk | MilestoneStatus::Overdue)).count();
+    if behind > 0 {
+        println!("      {}", format!("⚠️  {} milestone(s) at risk or overdue", behind).yellow());
+    }
+}
+
 /// Create a visual progress bar
 fn create_progress_bar(completed: usize, total: usize, width: usize) -> String {
+    let theme = crate::ui::theme::theme();
     if total == 0 {
-        return "▱".repeat(width).bright_black().to_string();
+        return theme.progress_empty.repeat(width).bright_black().to_string();
     }
     
     let percentage = completed as f64 / total as f64;
     let filled_width = (percentage * width as f64).round() as usize;
     let empty_width = width - filled_width;
     
-    let filled = "█".repeat(filled_width);
-    let empty = "░".repeat(empty_width);
+    let filled = theme.progress_filled.repeat(filled_width);
+    let empty = theme.progress_empty.repeat(empty_width);
     
     match percentage {
         p if p >= 0.8 => format!("{}{}", filled.bright_green(), empty.bright_black()),
@@ -4,6 +4,9 @@ use colored::*;
 
 /// Display dependency error with helpful information
 pub fn display_dependency_error(task_id: usize, incomplete_deps: &[usize], roadmap: &crate::model::Roadmap) {
+    if super::is_quiet() {
+        return;
+    }
     println!("\n🚫 {}: Cannot complete task #{}", "Dependency Error".red().bold(), task_id);
     
     if let Some(task) = roadmap.find_task_by_id(task_id) {
@@ -135,6 +138,9 @@ fn display_dependency_node(node: &crate::model::DependencyNode, depth: usize, is
         node.task_id.to_string().bright_white(), 
         task_desc
     );
+    if let Some(note) = &node.note {
+        println!("{}      💬 {}", indent, note.italic().bright_black());
+    }
     
     for (i, dep) in node.dependencies.iter().enumerate() {
         let is_last_dep = i == node.dependencies.len() - 1;
@@ -170,6 +176,47 @@ pub fn display_ready_tasks(ready_tasks: &[&Task]) {
     println!();
 }
 
+/// Display a suggested execution order for pending tasks
+pub fn display_task_order(ordered_tasks: &[&Task], completed_count: usize, apply: bool) {
+    println!("\n{}", "═".repeat(60).bright_blue());
+    println!("  {} ({})", 
+        "Suggested Execution Order".bold().bright_cyan(),
+        ordered_tasks.len().to_string().bright_white()
+    );
+    println!("{}", "═".repeat(60).bright_blue());
+    
+    if ordered_tasks.is_empty() {
+        println!("\n  ✨ No pending tasks to order.");
+        println!();
+        return;
+    }
+    
+    // With behavior.stable_ids the tasks move but keep their IDs
+    let renumbers = !crate::model::stable_ids();
+    println!("\n  📋 Dependencies first, higher priority breaks ties:");
+    for (index, task) in ordered_tasks.iter().enumerate() {
+        let new_id = completed_count + index + 1;
+        let renumber = if apply && renumbers && new_id != task.id {
+            format!(" → #{}", new_id).bright_yellow().to_string()
+        } else {
+            String::new()
+        };
+        println!("      {:>2}. {} #{}{} {}", 
+            index + 1,
+            get_priority_indicator(&task.priority),
+            task.id.to_string().bright_white(),
+            renumber,
+            task.description
+        );
+    }
+    
+    if !apply {
+        let verb = if renumbers { "Renumber" } else { "Reorder" };
+        println!("\n  💡 {} tasks in this order with: rask dependencies --order --apply", verb);
+    }
+    println!();
+}
+
 /// Display tasks blocked by dependencies
 pub fn display_blocked_tasks(blocked_tasks: &[&Task], roadmap: &crate::model::Roadmap) {
     println!("\n{}", "═".repeat(60).bright_blue());
@@ -208,6 +255,11 @@ pub fn display_blocked_tasks(blocked_tasks: &[&Task], roadmap: &crate::model::Ro
                         .join(", ")
                         .bright_yellow()
                 );
+                for dep_id in &incomplete_deps {
+                    if let Some(note) = task.dependency_notes.get(dep_id) {
+                        println!("          💬 #{}: {}", dep_id, note.italic());
+                    }
+                }
             }
         }
     }
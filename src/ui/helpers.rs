@@ -1,22 +1,170 @@
 use crate::model::Priority;
-use colored::*;
+use crate::ui::theme::theme;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+use unicode_width::UnicodeWidthChar;
 
 /// Get priority indicator with appropriate color
 pub fn get_priority_indicator(priority: &Priority) -> colored::ColoredString {
-    match priority {
-        Priority::Critical => "🔥".red(),
-        Priority::High => "⬆️".bright_red(),
-        Priority::Medium => "▶️".yellow(),
-        Priority::Low => "⬇️".green(),
+    theme().priority_symbol(priority)
+}
+
+/// Color task text based on priority level
+pub fn priority_text(priority: &Priority, text: &str) -> colored::ColoredString {
+    theme().priority_text(priority, text)
+}
+
+/// Columns available for terminal output: `ui.max_width` when set, capped by the
+/// terminal width. `None` when output is piped and no maximum is configured.
+pub fn output_width() -> Option<usize> {
+    static WIDTH: OnceLock<Option<usize>> = OnceLock::new();
+    *WIDTH.get_or_init(|| {
+        let max_width = crate::config::RaskConfig::load().map(|c| c.ui.max_width).unwrap_or(0);
+        let terminal = std::io::stdout().is_terminal()
+            .then(|| crossterm::terminal::size().ok())
+            .flatten()
+            .map(|(columns, _)| columns as usize)
+            .filter(|columns| *columns > 0);
+        match (max_width, terminal) {
+            (0, terminal) => terminal,
+            (max_width, Some(terminal)) => Some(max_width.min(terminal)),
+            (max_width, None) => Some(max_width),
+        }
+    })
+}
+
+/// Columns a character takes up. The emoji variation selector turns symbols
+/// such as ▶ and ⬆ into two-column emoji, so it adds the missing column.
+fn char_width(c: char, previous: Option<char>) -> usize {
+    match (c, previous) {
+        ('\u{fe0f}', Some(previous)) if previous.width() == Some(1) => 1,
+        _ => c.width().unwrap_or(0),
+    }
+}
+
+/// Days without activity after which pending tasks are marked stale, from
+/// `ui.stale_days`; `None` when the marker is turned off
+pub fn stale_after_days() -> Option<i64> {
+    static DAYS: OnceLock<u32> = OnceLock::new();
+    let days = *DAYS.get_or_init(|| crate::config::RaskConfig::load().map(|c| c.ui.stale_days).unwrap_or(30));
+    (days > 0).then_some(i64::from(days))
+}
+
+/// Width of text on screen, ignoring ANSI color codes
+pub fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut previous = None;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|c| c.is_ascii_alphabetic());
+        } else {
+            width += char_width(c, previous);
+            previous = Some(c);
+        }
+    }
+    width
+}
+
+/// Cut text to `width` columns, ending with an ellipsis. Color codes are kept
+/// and reset after the cut.
+pub fn truncate_to_width(text: &str, width: usize) -> String {
+    if visible_width(text) <= width {
+        return text.to_string();
+    }
+    let mut result = String::new();
+    let mut used = 0;
+    let mut colored = false;
+    let mut previous = None;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            colored = true;
+            result.push(c);
+            for c in chars.by_ref() {
+                result.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        let w = char_width(c, previous);
+        if used + w + 1 > width {
+            break;
+        }
+        used += w;
+        previous = Some(c);
+        result.push(c);
+    }
+    if width > 0 {
+        result.push('…');
     }
+    if colored {
+        result.push_str("\x1b[0m");
+    }
+    result
+}
+
+/// Pad text with spaces to `width` columns
+pub fn pad_to_width(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width.saturating_sub(visible_width(text))))
+}
+
+/// Rows of cells laid out in aligned columns. When the rows are wider than the
+/// output, the widest columns are shortened first and their cells truncated.
+pub struct Table {
+    rows: Vec<Vec<String>>,
+    separator: String,
+    indent: String,
+    min_column_width: usize,
 }
 
-/// Get priority color for task text based on priority level
-pub fn get_priority_color(priority: &Priority) -> fn(&str) -> colored::ColoredString {
-    match priority {
-        Priority::Critical => |s: &str| s.bright_red().bold(),
-        Priority::High => |s: &str| s.red(),
-        Priority::Medium => |s: &str| s.normal(),
-        Priority::Low => |s: &str| s.bright_black(),
+impl Table {
+    /// An empty table whose columns are joined by `separator`
+    pub fn new(separator: &str) -> Self {
+        Table { rows: Vec::new(), separator: separator.to_string(), indent: String::new(), min_column_width: 4 }
+    }
+
+    /// Text printed before every row
+    pub fn indent(mut self, indent: &str) -> Self {
+        self.indent = indent.to_string();
+        self
+    }
+
+    /// Narrowest a column may be shortened to
+    pub fn min_column_width(mut self, width: usize) -> Self {
+        self.min_column_width = width;
+        self
+    }
+
+    /// Add a row; cells may contain color codes
+    pub fn add_row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    /// Lay out the rows to fit within `max_width` columns (no limit when `None`)
+    pub fn render(&self, max_width: Option<usize>) -> Vec<String> {
+        let columns = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let mut widths: Vec<usize> = (0..columns)
+            .map(|col| self.rows.iter().filter_map(|row| row.get(col)).map(|cell| visible_width(cell)).max().unwrap_or(0))
+            .collect();
+
+        if let Some(max_width) = max_width {
+            let fixed = visible_width(&self.indent) + visible_width(&self.separator) * columns.saturating_sub(1);
+            let available = max_width.saturating_sub(fixed);
+            while widths.iter().sum::<usize>() > available {
+                let Some(widest) = (0..columns).filter(|&col| widths[col] > self.min_column_width).max_by_key(|&col| widths[col]) else { break };
+                widths[widest] -= 1;
+            }
+        }
+
+        self.rows.iter().map(|row| {
+            let cells: Vec<String> = row.iter().enumerate().map(|(col, cell)| {
+                let cell = truncate_to_width(cell, widths[col]);
+                if col + 1 == row.len() { cell } else { pad_to_width(&cell, widths[col]) }
+            }).collect();
+            format!("{}{}", self.indent, cells.join(&self.separator)).trim_end().to_string()
+        }).collect()
     }
-}
\ No newline at end of file
+}
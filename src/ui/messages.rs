@@ -1,23 +1,75 @@
+use crate::ui::theme::theme;
 use colored::*;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by `--quiet` (and `--porcelain`): informational output is suppressed
+static QUIET: AtomicBool = AtomicBool::new(false);
+/// Set by `--porcelain`: task lists are printed as stable tab-separated lines
+static PORCELAIN: AtomicBool = AtomicBool::new(false);
+
+/// Configure output for scripts. Porcelain implies quiet and plain, uncolored text.
+pub fn set_output_mode(quiet: bool, porcelain: bool) {
+    QUIET.store(quiet || porcelain, Ordering::Relaxed);
+    PORCELAIN.store(porcelain, Ordering::Relaxed);
+    if porcelain {
+        colored::control::set_override(false);
+    }
+}
+
+/// Turn colors off for `--no-color` or a non-empty `NO_COLOR`
+pub fn set_color_mode(no_color: bool) {
+    if no_color || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        colored::control::set_override(false);
+    }
+}
+
+/// Whether informational output is suppressed
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Whether task lists use the porcelain format
+pub fn is_porcelain() -> bool {
+    PORCELAIN.load(Ordering::Relaxed)
+}
 
 /// Display informational messages
 pub fn display_info(message: &str) {
-    println!("\n💡 {}: {}", "Info".blue().bold(), message);
+    if is_quiet() {
+        return;
+    }
+    println!("\n{}{}: {}", theme().icon("💡"), "Info".blue().bold(), theme().plain_text(message));
 }
 
 /// Display error messages
 pub fn display_error(message: &str) {
-    eprintln!("\n❌ {}: {}", "Error".red().bold(), message);
+    eprintln!("\n{}{}: {}", theme().icon("❌"), "Error".red().bold(), theme().plain_text(message));
 }
 
 /// Display success messages
 pub fn display_success(message: &str) {
-    println!("\n✅ {}: {}", "Success".green().bold(), message);
+    if is_quiet() {
+        return;
+    }
+    println!("\n{}{}: {}", theme().icon("✅"), "Success".green().bold(), theme().plain_text(message));
 }
 
-/// Display warning messages
+/// Display warning messages (on stderr in quiet mode, so stdout stays clean)
 pub fn display_warning(message: &str) {
-    println!("\n⚠️  {}: {}", "Warning".yellow().bold(), message);
+    if is_quiet() {
+        eprintln!("Warning: {}", theme().plain_text(message));
+        return;
+    }
+    println!("\n{}{}: {}", theme().icon("⚠️ "), "Warning".yellow().bold(), theme().plain_text(message));
+}
+
+/// Display a warning on stderr, keeping stdout clean for whatever the command prints
+pub fn display_stderr_warning(message: &str) {
+    if is_quiet() {
+        eprintln!("Warning: {}", theme().plain_text(message));
+        return;
+    }
+    eprintln!("\n{}{}: {}", theme().icon("⚠️ "), "Warning".yellow().bold(), theme().plain_text(message));
 }
 
 /// Display success message for project initialization
@@ -30,30 +82,39 @@ pub fn display_init_success(roadmap: &crate::model::Roadmap) {
 }
 
 /// Display success message for task removal
-pub fn display_remove_success(description: &str) {
+pub fn display_remove_success(description: &str, markdown_synced: bool) {
+    if is_quiet() {
+        return;
+    }
     println!("\n🗑️  {}: Task removed successfully!", "Success".green().bold());
     println!("   📝 Removed: {}", description.strikethrough().bright_black());
-    println!("   💡 Task removed from both state and markdown file!");
+    println!("   💡 {}", if markdown_synced { "Task removed from both state and markdown file!" } else { "Task removed; the markdown file was not changed" });
 }
 
 /// Display success message for task editing
-pub fn display_edit_success(task_id: usize, old_description: &str, new_description: &str) {
+pub fn display_edit_success(task_id: usize, old_description: &str, new_description: &str, markdown_synced: bool) {
+    if is_quiet() {
+        return;
+    }
     println!("\n✏️  {}: Task #{} updated successfully!", "Success".green().bold(), task_id.to_string().bright_white());
     println!("   📝 Old: {}", old_description.strikethrough().bright_black());
     println!("   📝 New: {}", new_description.bright_white());
-    println!("   💡 Changes synced to both state and markdown file!");
+    println!("   💡 {}", if markdown_synced { "Changes synced to both state and markdown file!" } else { "Changes saved; the markdown file was not changed" });
 }
 
 /// Display success message for task reset
-pub fn display_reset_success(task_id: Option<usize>) {
+pub fn display_reset_success(task_id: Option<usize>, markdown_synced: bool) {
+    if is_quiet() {
+        return;
+    }
     match task_id {
         Some(id) => {
             println!("\n🔄 {}: Task #{} reset to pending!", "Success".green().bold(), id.to_string().bright_white());
-            println!("   💡 Task status updated in both state and markdown file!");
+            println!("   💡 {}", if markdown_synced { "Task status updated in both state and markdown file!" } else { "Task status saved; the markdown file was not changed" });
         },
         None => {
             println!("\n🔄 {}: All tasks reset to pending!", "Success".green().bold());
-            println!("   💡 All task statuses updated in both state and markdown file!");
+            println!("   💡 {}", if markdown_synced { "All task statuses updated in both state and markdown file!" } else { "All task statuses saved; the markdown file was not changed" });
         }
     }
 }
\ No newline at end of file
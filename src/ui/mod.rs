@@ -5,6 +5,7 @@ pub mod messages;
 pub mod progress;
 pub mod roadmap;
 pub mod tasks;
+pub mod theme;
 
 // Re-export commonly used functions
 pub use analytics::*;
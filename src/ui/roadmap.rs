@@ -1,11 +1,16 @@
-use crate::model::{Priority, Roadmap, TaskStatus, Phase};
+use crate::model::{MilestoneProgress, MilestoneStatus, Priority, Roadmap, TaskStatus, Phase};
 use crate::ui::progress::{display_progress_bar, display_motivational_message};
-use crate::ui::tasks::display_task_line;
+use crate::ui::tasks::display_task_lines;
+use crate::ui::helpers::{get_priority_indicator, output_width, Table};
+use crate::ui::theme::theme;
 use colored::*;
 use std::collections::HashMap;
 
 /// Displays the project roadmap with a beautiful formatted output
 pub fn display_roadmap(roadmap: &Roadmap) {
+    if super::is_quiet() {
+        return;
+    }
     display_roadmap_enhanced(roadmap, false);
 }
 
@@ -17,34 +22,36 @@ pub fn display_roadmap_enhanced(roadmap: &Roadmap, show_detailed: bool) {
     let _progress_percentage = if total_tasks > 0 { (completed_tasks * 100) / total_tasks } else { 0 };
     
     // Print header with project title
-    println!("\n{}", "═".repeat(60).bright_blue());
+    println!("\n{}", theme().rule("═", 60).bright_blue());
     println!("  {}", roadmap.title.bold().bright_cyan());
     
     // Show local project directory information
     if let Ok(current_dir) = std::env::current_dir() {
-        println!("  📁 Directory: {}", 
+        println!("  {}Directory: {}", 
+            theme().icon("📁"),
             current_dir.display().to_string().bright_yellow()
         );
     }
     
-    println!("{}", "═".repeat(60).bright_blue());
+    println!("{}", theme().rule("═", 60).bright_blue());
     
     // Print progress bar
     display_progress_bar(completed_tasks, total_tasks);
+    display_milestone_countdown(roadmap);
     
     // Print task list header
-    println!("\n  📋 {}{}:", 
+    println!("\n  {}{}{}:", 
+        theme().icon("📋"),
         "Tasks".bold(),
         if show_detailed { " (Detailed View)" } else { "" }
     );
-    println!("  {}", "─".repeat(50).bright_black());
+    println!("  {}", theme().rule("─", 50).bright_black());
     
     // Print each task with enhanced formatting
-    for task in &roadmap.tasks {
-        display_task_line(task, show_detailed);
-    }
+    let tasks: Vec<&crate::model::Task> = roadmap.tasks.iter().collect();
+    display_task_lines(&tasks, show_detailed);
     
-    println!("  {}", "─".repeat(50).bright_black());
+    println!("  {}", theme().rule("─", 50).bright_black());
     
     // Print motivational message
     display_motivational_message(completed_tasks, total_tasks);
@@ -57,6 +64,49 @@ pub fn display_roadmap_enhanced(roadmap: &Roadmap, show_detailed: bool) {
     println!();
 }
 
+/// Countdown line for each open milestone, flagging those at risk or overdue
+pub fn display_milestone_countdown(roadmap: &Roadmap) {
+    let today = chrono::Local::now().date_naive();
+    let open: Vec<MilestoneProgress> = roadmap.milestone_progress(today).into_iter()
+        .filter(|m| m.status != MilestoneStatus::Done)
+        .collect();
+    if open.is_empty() {
+        return;
+    }
+    println!();
+    for milestone in &open {
+        println!("  {}", format_milestone_line(milestone));
+    }
+}
+
+/// One-line milestone summary: name, countdown, progress and status
+pub fn format_milestone_line(milestone: &MilestoneProgress) -> String {
+    let countdown = match milestone.days_left {
+        d if d < -1 => format!("{} days overdue", -d),
+        -1 => "1 day overdue".to_string(),
+        0 => "due today".to_string(),
+        1 => "due tomorrow".to_string(),
+        d => format!("in {} days", d),
+    };
+    let icon = |emoji: &str| theme().icon(emoji);
+    let status = match milestone.status {
+        MilestoneStatus::Done => format!("{}done", icon("✅")).green(),
+        MilestoneStatus::OnTrack => format!("{}on track", icon("🟢")).green(),
+        MilestoneStatus::AtRisk => format!("{}at risk", icon("⚠️ ")).yellow().bold(),
+        MilestoneStatus::Overdue => format!("{}overdue", icon("🔴")).red().bold(),
+    };
+    format!("{}{} ({}, {}) {}% ({}/{}) {}",
+        icon("🏁"),
+        milestone.name.bold(),
+        milestone.target_date,
+        countdown,
+        milestone.completion_percentage,
+        milestone.completed_tasks,
+        milestone.total_tasks,
+        status
+    )
+}
+
 /// Display project statistics summary
 fn display_project_statistics(roadmap: &Roadmap) {
     let total_tasks = roadmap.tasks.len();
@@ -70,28 +120,32 @@ fn display_project_statistics(roadmap: &Roadmap) {
     let low_tasks = roadmap.tasks.iter().filter(|t| t.priority == Priority::Low && t.status == TaskStatus::Pending).count();
     
     // Dependency analysis
+    let completed_ids = roadmap.get_completed_task_ids();
     let ready_tasks = roadmap.tasks.iter()
-        .filter(|t| t.status == TaskStatus::Pending && t.can_be_started(&roadmap.get_completed_task_ids()))
+        .filter(|t| t.status == TaskStatus::Pending && t.can_be_started(&completed_ids))
         .count();
     let blocked_tasks = pending_tasks - ready_tasks;
     
-    println!("\n  📊 {}:", "Project Statistics".bold().bright_cyan());
-    println!("       📈 Progress: {}/{} completed ({:.1}%)", 
+    let icon = |emoji: &str| theme().icon(emoji);
+    println!("\n  {}{}:", icon("📊"), "Project Statistics".bold().bright_cyan());
+    println!("       {}Progress: {}/{} completed ({:.1}%)", 
+        icon("📈"),
         completed_tasks, total_tasks, 
         if total_tasks > 0 { (completed_tasks as f64 / total_tasks as f64) * 100.0 } else { 0.0 }
     );
     
     if pending_tasks > 0 {
-        println!("       🎯 Priority Breakdown:");
-        if critical_tasks > 0 { println!("          🔥 Critical: {}", critical_tasks.to_string().bright_red()); }
-        if high_tasks > 0 { println!("          ⬆️  High: {}", high_tasks.to_string().red()); }
-        if medium_tasks > 0 { println!("          ▶️  Medium: {}", medium_tasks.to_string().yellow()); }
-        if low_tasks > 0 { println!("          ⬇️  Low: {}", low_tasks.to_string().green()); }
+        println!("       {}Priority Breakdown:", icon("🎯"));
+        for (priority, count) in [(Priority::Critical, critical_tasks), (Priority::High, high_tasks), (Priority::Medium, medium_tasks), (Priority::Low, low_tasks)] {
+            if count > 0 {
+                println!("          {} {}: {}", get_priority_indicator(&priority), priority, theme().priority_text(&priority, &count.to_string()));
+            }
+        }
         
-        println!("       🚀 Task Status:");
-        println!("          ✅ Ready to start: {}", ready_tasks.to_string().bright_green());
+        println!("       {}Task Status:", icon("🚀"));
+        println!("          {}Ready to start: {}", icon("✅"), ready_tasks.to_string().bright_green());
         if blocked_tasks > 0 {
-            println!("          🔒 Blocked by dependencies: {}", blocked_tasks.to_string().bright_red());
+            println!("          {}Blocked by dependencies: {}", icon("🔒"), blocked_tasks.to_string().bright_red());
         }
     }
 }
@@ -102,20 +156,22 @@ pub fn display_roadmap_grouped_by_phase(roadmap: &Roadmap, detailed: bool, colla
     let completed_tasks = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
     
     // Print header
-    println!("\n{}", "═".repeat(80).bright_blue());
+    println!("\n{}", theme().rule("═", 80).bright_blue());
     println!("  {} - {} tasks across phases", roadmap.title.bold().bright_cyan(), total_tasks);
     
     // Show local project directory information
     if let Ok(current_dir) = std::env::current_dir() {
-        println!("  📁 Directory: {}", 
+        println!("  {}Directory: {}", 
+            theme().icon("📁"),
             current_dir.display().to_string().bright_yellow()
         );
     }
     
-    println!("{}", "═".repeat(80).bright_blue());
+    println!("{}", theme().rule("═", 80).bright_blue());
     
     // Overall progress bar
     display_progress_bar(completed_tasks, total_tasks);
+    display_milestone_countdown(roadmap);
     
     // Group tasks by phase
     let mut phase_groups: HashMap<String, Vec<&crate::model::Task>> = HashMap::new();
@@ -130,11 +186,11 @@ pub fn display_roadmap_grouped_by_phase(roadmap: &Roadmap, detailed: bool, colla
     // Display phases in order
     for phase in &all_phases {
         if let Some(tasks) = phase_groups.get(&phase.name) {
-            display_phase_section(&phase.name, &phase.emoji(), tasks, detailed, collapse_completed);
+            display_phase_section(&phase.name, &theme().icon(&theme().phase_emoji(phase)), tasks, detailed, collapse_completed);
         }
     }
     
-    println!("\n  💡 {} Use 'rask show --phase <name>' to focus on a specific phase", "Tip:".bright_green().bold());
+    println!("\n  {}{} Use 'rask show --phase <name>' to focus on a specific phase", theme().icon("💡"), "Tip:".bright_green().bold());
     println!("     Use 'rask timeline' for a horizontal phase view");
     println!();
 }
@@ -146,7 +202,7 @@ pub fn display_roadmap_filtered_by_phase(roadmap: &Roadmap, phase_filter: &str,
         .collect();
     
     if filtered_tasks.is_empty() {
-        println!("\n  {} No tasks found in phase '{}'", "ℹ️".bright_blue(), phase_filter.bright_yellow());
+        println!("\n  {}No tasks found in phase '{}'", theme().icon("ℹ️"), phase_filter.bright_yellow());
         println!("  Use 'rask phase list' to see available phases");
         return;
     }
@@ -155,45 +211,45 @@ pub fn display_roadmap_filtered_by_phase(roadmap: &Roadmap, phase_filter: &str,
     let total_tasks = filtered_tasks.len();
     
     // Print header
-    println!("\n{}", "═".repeat(80).bright_blue());
+    println!("\n{}", theme().rule("═", 80).bright_blue());
     println!("  {} - {} Phase", roadmap.title.bold().bright_cyan(), phase_filter.bright_yellow().bold());
-    println!("  📊 {} tasks in this phase", total_tasks);
-    println!("{}", "═".repeat(80).bright_blue());
+    println!("  {}{} tasks in this phase", theme().icon("📊"), total_tasks);
+    println!("{}", theme().rule("═", 80).bright_blue());
     
     // Phase-specific progress bar
     display_progress_bar(completed_tasks, total_tasks);
     
     // Find the phase emoji from actual roadmap phases
     let phase_emoji = if let Some(phase) = roadmap.get_all_phases().iter().find(|p| p.name.to_lowercase() == phase_filter.to_lowercase()) {
-        phase.emoji()
+        theme().phase_emoji(phase)
     } else {
         "📋".to_string()
     };
     
-    println!("\n  {} {} Phase Tasks:", phase_emoji, phase_filter.bright_yellow().bold());
-    println!("  {}", "─".repeat(50).bright_black());
+    println!("\n  {}{} Phase Tasks:", theme().icon(&phase_emoji), theme().phase_text(phase_filter, phase_filter.bright_yellow().bold()));
+    println!("  {}", theme().rule("─", 50).bright_black());
     
     // Display tasks
-    for task in &filtered_tasks {
-        display_task_line(task, detailed);
-    }
+    display_task_lines(&filtered_tasks, detailed);
     
-    println!("  {}", "─".repeat(50).bright_black());
+    println!("  {}", theme().rule("─", 50).bright_black());
     
     // Phase-specific statistics
+    let completed_ids = roadmap.get_completed_task_ids();
     let ready_tasks = filtered_tasks.iter()
-        .filter(|t| t.status == TaskStatus::Pending && t.can_be_started(&roadmap.get_completed_task_ids()))
+        .filter(|t| t.status == TaskStatus::Pending && t.can_be_started(&completed_ids))
         .count();
     let blocked_tasks = total_tasks - completed_tasks - ready_tasks;
     
-    println!("\n  📊 {} Phase Statistics:", phase_filter.bright_yellow().bold());
-    println!("     ✅ Completed: {}", completed_tasks.to_string().bright_green());
-    println!("     🚀 Ready to start: {}", ready_tasks.to_string().bright_cyan());
+    let icon = |emoji: &str| theme().icon(emoji);
+    println!("\n  {}{} Phase Statistics:", icon("📊"), phase_filter.bright_yellow().bold());
+    println!("     {}Completed: {}", icon("✅"), completed_tasks.to_string().bright_green());
+    println!("     {}Ready to start: {}", icon("🚀"), ready_tasks.to_string().bright_cyan());
     if blocked_tasks > 0 {
-        println!("     🔒 Blocked: {}", blocked_tasks.to_string().bright_red());
+        println!("     {}Blocked: {}", icon("🔒"), blocked_tasks.to_string().bright_red());
     }
     
-    println!("\n  💡 {} Use 'rask show --group-by-phase' to see all phases", "Tip:".bright_green().bold());
+    println!("\n  {}{} Use 'rask show --group-by-phase' to see all phases", icon("💡"), "Tip:".bright_green().bold());
     println!();
 }
 
@@ -203,12 +259,13 @@ pub fn display_project_timeline(roadmap: &Roadmap, _detailed: bool, active_only:
     let completed_tasks = roadmap.tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
     
     // Print header
-    println!("\n{}", "═".repeat(100).bright_blue());
-    println!("  📅 {} Project Timeline", roadmap.title.bold().bright_cyan());
-    println!("{}", "═".repeat(100).bright_blue());
+    println!("\n{}", theme().rule("═", 100).bright_blue());
+    println!("  {}{} Project Timeline", theme().icon("📅"), roadmap.title.bold().bright_cyan());
+    println!("{}", theme().rule("═", 100).bright_blue());
     
     // Overall progress
-    println!("  📈 Overall Progress: [{}] {}% ({}/{})", 
+    println!("  {}Overall Progress: [{}] {}% ({}/{})", 
+        theme().icon("📈"),
         create_progress_bar(completed_tasks, total_tasks, 30),
         if total_tasks > 0 { (completed_tasks * 100) / total_tasks } else { 0 },
         completed_tasks,
@@ -231,7 +288,7 @@ pub fn display_project_timeline(roadmap: &Roadmap, _detailed: bool, active_only:
     };
     
     if phases_to_show.is_empty() {
-        println!("\n  {} No active phases found", "ℹ️".bright_blue());
+        println!("\n  {}No active phases found", theme().icon("ℹ️"));
         return;
     }
     
@@ -247,7 +304,7 @@ pub fn display_project_timeline(roadmap: &Roadmap, _detailed: bool, active_only:
     
     // Check if page is valid
     if start_idx >= total_phases {
-        println!("\n  {} Page {} not found. Total pages: {}", "❌".bright_red(), current_page, total_pages);
+        println!("\n  {}Page {} not found. Total pages: {}", theme().icon("❌"), current_page, total_pages);
         println!("  Use --page 1 to {} or omit --page for page 1", total_pages);
         return;
     }
@@ -257,7 +314,8 @@ pub fn display_project_timeline(roadmap: &Roadmap, _detailed: bool, active_only:
     
     // Show pagination info if there are multiple pages
     if total_pages > 1 {
-        println!("  📄 Page {} of {} (showing {} of {} phases)", 
+        println!("  {}Page {} of {} (showing {} of {} phases)", 
+            theme().icon("📄"),
             current_page.to_string().bright_cyan(),
             total_pages.to_string().bright_cyan(),
             phases_to_show.len().to_string().bright_yellow(),
@@ -265,93 +323,74 @@ pub fn display_project_timeline(roadmap: &Roadmap, _detailed: bool, active_only:
         );
     }
     
-    println!("\n");
-    
-    // Display phase headers
-    for (i, phase) in phases_to_show.iter().enumerate() {
-        let empty_vec = vec![];
-        let tasks = phase_groups.get(&phase.name).unwrap_or(&empty_vec);
-        let phase_completed = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
-        let phase_total = tasks.len();
-        let _percentage = if phase_total > 0 { (phase_completed * 100) / phase_total } else { 0 };
-        
-        print!("  {} {} ", phase.emoji(), phase.name.bright_yellow().bold());
-        if compact {
-            print!("({})", phase_total);
-        } else {
-            print!("({} tasks)", phase_total);
-        }
-        
-        if i < phases_to_show.len() - 1 {
-            print!("  →  ");
-        }
-    }
-    println!("\n");
+    println!();
     
-    // Display phase progress bars
-    for (i, phase) in phases_to_show.iter().enumerate() {
+    // Phases are laid out side by side as table columns: a header row, a
+    // progress row, then one row per task
+    let mut table = Table::new("  │  ").indent("  ").min_column_width(8);
+    let mut headers = Vec::new();
+    let mut progress = Vec::new();
+    for phase in &phases_to_show {
         let empty_vec = vec![];
         let tasks = phase_groups.get(&phase.name).unwrap_or(&empty_vec);
         let phase_completed = tasks.iter().filter(|t| t.status == TaskStatus::Completed).count();
         let phase_total = tasks.len();
-        let _percentage = if phase_total > 0 { (phase_completed * 100) / phase_total } else { 0 };
+        let percentage = if phase_total > 0 { (phase_completed * 100) / phase_total } else { 0 };
         
-        print!("  {}% [{}]", 
-            format!("{:3}", _percentage).bright_white(),
+        headers.push(format!("{}{} {}",
+            theme().icon(&theme().phase_emoji(phase)),
+            theme().phase_text(&phase.name, phase.name.bright_yellow().bold()),
+            if compact { format!("({})", phase_total) } else { format!("({} tasks)", phase_total) }
+        ));
+        progress.push(format!("{}% [{}]",
+            format!("{:3}", percentage).bright_white(),
             create_progress_bar(phase_completed, phase_total, 12)
-        );
-        
-        if i < phases_to_show.len() - 1 {
-            print!("     ");
-        }
+        ));
     }
-    println!("\n");
+    table.add_row(headers);
+    table.add_row(progress);
     
     // Display task boxes
     let max_tasks_to_show = if compact { 3 } else { 5 };
     
     for row in 0..max_tasks_to_show {
-        for (i, phase) in phases_to_show.iter().enumerate() {
+        let mut cells = Vec::new();
+        for phase in &phases_to_show {
             let empty_vec = vec![];
             let tasks = phase_groups.get(&phase.name).unwrap_or(&empty_vec);
             
-            if row < tasks.len() {
-                let task = tasks[row];
-                let status_icon = if task.status == TaskStatus::Completed { "✓" } else { "□" };
-                let priority_icon = match task.priority {
-                    Priority::Critical => "🔴",
-                    Priority::High => "⬆️",
-                    Priority::Medium => "▶️",
-                    Priority::Low => "⬇️",
-                };
-                
+            let cell = if row == max_tasks_to_show - 1 && tasks.len() > max_tasks_to_show {
+                let remaining = tasks.len() - max_tasks_to_show + 1;
+                format!("... {} more", remaining)
+            } else if let Some(task) = tasks.get(row) {
+                let status_icon = theme().task_status_symbol(&task.status);
+                let priority_icon = get_priority_indicator(&task.priority);
                 if compact {
-                    print!("  {} {} #{}", status_icon, priority_icon, task.id);
+                    format!("{} {} #{}", status_icon, priority_icon, task.id)
                 } else {
-                    let desc = if task.description.len() > 12 {
-                        format!("{}...", &task.description[..9])
-                    } else {
-                        task.description.clone()
-                    };
-                    print!("  {} {} #{} {}", status_icon, priority_icon, task.id, desc);
+                    format!("{} {} #{} {}", status_icon, priority_icon, task.id, task.description)
                 }
-            } else if row == max_tasks_to_show - 1 && tasks.len() > max_tasks_to_show {
-                let remaining = tasks.len() - max_tasks_to_show + 1;
-                print!("  ... {} more", remaining);
             } else {
-                print!("  {}", " ".repeat(if compact { 8 } else { 20 }));
-            }
-            
-            if i < phases_to_show.len() - 1 {
-                print!("  │  ");
-            }
+                String::new()
+            };
+            cells.push(cell);
+        }
+        if cells.iter().any(|cell| !cell.is_empty()) {
+            table.add_row(cells);
+        }
+    }
+    
+    let lines = table.render(output_width());
+    for (i, line) in lines.iter().enumerate() {
+        println!("{}", line);
+        if i < 2 {
+            println!();
         }
-        println!();
     }
     
     // Dependencies flow - show actual phases
     if phases_to_show.len() > 1 {
-        print!("\n  🔗 Dependencies: ");
+        print!("\n  {}Dependencies: ", theme().dependency_marker());
         for (i, phase) in phases_to_show.iter().enumerate() {
             let color = match i % 4 {
                 0 => phase.name.bright_cyan(),
@@ -359,7 +398,7 @@ pub fn display_project_timeline(roadmap: &Roadmap, _detailed: bool, active_only:
                 2 => phase.name.bright_green(),
                 _ => phase.name.bright_magenta(),
             };
-            print!("{}", color);
+            print!("{}", theme().phase_text(&phase.name, color));
             if i < phases_to_show.len() - 1 {
                 print!(" → ");
             }
@@ -368,18 +407,19 @@ pub fn display_project_timeline(roadmap: &Roadmap, _detailed: bool, active_only:
     }
     
     // Ready tasks summary
+    let completed_ids = roadmap.get_completed_task_ids();
     let ready_tasks = roadmap.tasks.iter()
-        .filter(|t| t.status == TaskStatus::Pending && t.can_be_started(&roadmap.get_completed_task_ids()))
+        .filter(|t| t.status == TaskStatus::Pending && t.can_be_started(&completed_ids))
         .count();
     
-    println!("  🚀 Ready to start: {} tasks", ready_tasks.to_string().bright_green().bold());
+    println!("  {}Ready to start: {} tasks", theme().icon("🚀"), ready_tasks.to_string().bright_green().bold());
     
-    println!("\n  💡 {} Use 'rask show --group-by-phase' for detailed phase view", "Tip:".bright_green().bold());
+    println!("\n  {}{} Use 'rask show --group-by-phase' for detailed phase view", theme().icon("💡"), "Tip:".bright_green().bold());
     println!("     Use 'rask show --phase <name>' to focus on specific phase");
     
     // Show navigation tips for pagination
     if total_pages > 1 {
-        println!("  📖 {} Navigation:", "Pages:".bright_blue().bold());
+        println!("  {}{} Navigation:", theme().icon("📖"), "Pages:".bright_blue().bold());
         if current_page > 1 {
             println!("     Previous: rask timeline --page {}", current_page - 1);
         }
@@ -402,9 +442,9 @@ fn display_phase_section(phase_name: &str, emoji: &str, tasks: &[&crate::model::
     let is_completed = percentage == 100;
     let should_collapse = collapse_completed && is_completed;
     
-    println!("\n  {} {} Phase - {} ({} tasks, {}% complete)", 
+    println!("\n  {}{} Phase - {} ({} tasks, {}% complete)", 
         emoji, 
-        phase_name.bright_yellow().bold(),
+        theme().phase_text(phase_name, phase_name.bright_yellow().bold()),
         if is_completed { "Complete".bright_green() } else { "In Progress".bright_cyan() },
         total_tasks,
         percentage
@@ -418,14 +458,12 @@ fn display_phase_section(phase_name: &str, emoji: &str, tasks: &[&crate::model::
     }
     println!();
     
-    println!("  {}", "─".repeat(60).bright_black());
+    println!("  {}", theme().rule("─", 60).bright_black());
     
     // Show tasks (limit to first few if not detailed)
     let tasks_to_show = if detailed { tasks.len() } else { std::cmp::min(tasks.len(), 5) };
     
-    for (_i, task) in tasks.iter().take(tasks_to_show).enumerate() {
-        display_task_line(task, detailed);
-    }
+    display_task_lines(&tasks[..tasks_to_show], detailed);
     
     // Show "and X more" if there are more tasks
     if tasks.len() > tasks_to_show {
@@ -442,21 +480,11 @@ fn display_phase_section(phase_name: &str, emoji: &str, tasks: &[&crate::model::
         .count();
     
     if !is_completed {
-        println!("  📊 {} ready to start", ready_tasks.to_string().bright_green());
+        println!("  {}{} ready to start", theme().icon("📊"), ready_tasks.to_string().bright_green());
     }
 }
 
 /// Helper function to create a progress bar string
 fn create_progress_bar(completed: usize, total: usize, width: usize) -> String {
-    if total == 0 {
-        return "░".repeat(width).dimmed().to_string();
-    }
-    
-    let filled = (completed * width) / total;
-    let empty = width - filled;
-    
-    format!("{}{}", 
-        "█".repeat(filled).bright_green(),
-        "░".repeat(empty).dimmed()
-    )
+    theme().progress_bar(completed, total, width)
 }
\ No newline at end of file
@@ -1,29 +1,26 @@
-use crate::model::{Priority, Task, TaskStatus};
-use crate::ui::helpers::{get_priority_indicator, get_priority_color};
+use crate::model::{NoteKind, Priority, Task, TaskStatus};
+use crate::ui::helpers::{get_priority_indicator, output_width, priority_text, stale_after_days, Table};
+use crate::ui::theme::theme;
 use colored::*;
+use std::io::Write;
 
-/// Display a single task line with enhanced formatting
-pub fn display_task_line(task: &Task, detailed: bool) {
-    let status_icon = if task.status == TaskStatus::Completed { "✓" } else { "□" };
-    let status_color = if task.status == TaskStatus::Completed { 
-        status_icon.green() 
-    } else { 
-        status_icon.bright_black() 
-    };
+/// Columns of a task's main line: status, AI marker, priority (list view only),
+/// id, and the description followed by tags, owners and contexts
+fn task_line_cells(task: &Task, detailed: bool) -> Vec<String> {
+    let status_color = theme().task_status_symbol(&task.status);
     
     // AI task indicator - show special icon for AI-generated tasks
-    let ai_indicator = if task.is_ai_generated() {
-        "🤖".bright_cyan()
-    } else {
-        "  ".normal()
+    let ai_indicator = match (task.is_ai_generated(), theme().emoji) {
+        (true, true) => "🤖".bright_cyan(),
+        (true, false) => "AI".bright_cyan(),
+        (false, _) => "  ".normal(),
     };
     
     // Apply priority-based coloring to task description
-    let priority_color_fn = get_priority_color(&task.priority);
     let mut description = if task.status == TaskStatus::Completed {
-        priority_color_fn(&task.description).strikethrough().dimmed()
+        priority_text(&task.priority, &task.description).strikethrough().dimmed()
     } else {
-        priority_color_fn(&task.description)
+        priority_text(&task.priority, &task.description)
     };
     
     // Special coloring for AI-generated tasks (cyan tint when not completed)
@@ -31,101 +28,150 @@ pub fn display_task_line(task: &Task, detailed: bool) {
         description = description.bright_cyan();
     }
     
-    // Format the main task line with consistent spacing
-    // In detailed mode, we don't show priority icon here since it's shown in details below
-    // In non-detailed mode, we show the priority icon for quick reference
-    if detailed {
-        // Detailed view: no priority icon in main line (shown in details below)
-        print!("  {} {} #{:2} {}", 
-            status_color,       // Status checkbox (✓ or □)
-            ai_indicator,       // AI indicator (🤖 or spaces)
-            task.id,           // Task ID with consistent 2-digit padding
-            description        // Task description with priority/AI coloring
-        );
-    } else {
-        // List view: show priority icon for quick scanning
-        let priority_indicator = get_priority_indicator(&task.priority);
-        print!("  {} {} {} #{:2} {}", 
-            status_color,           // Status checkbox (✓ or □)
-            ai_indicator,           // AI indicator (🤖 or spaces)
-            priority_indicator,     // Priority emoji (🔥, ⬆️, ▶️, ⬇️)
-            task.id,               // Task ID with consistent 2-digit padding
-            description            // Task description with priority/AI coloring
-        );
+    let mut cells = vec![status_color.to_string(), ai_indicator.to_string()];
+    // In detailed mode the priority is shown in the details below the line;
+    // in the list view the priority icon is kept for quick scanning
+    if !detailed {
+        cells.push(get_priority_indicator(&task.priority).to_string());
     }
+    cells.push(format!("#{:2}", task.id));
     
+    let mut text = description.to_string();
     // Add tags if present, with consistent spacing
-    if !task.tags.is_empty() {
-        let tags_str = task.tags.iter()
-            .map(|tag| format!("#{}", tag).bright_magenta().to_string())
-            .collect::<Vec<_>>()
-            .join(" ");
-        print!(" {}", tags_str);
+    for tag in &task.tags {
+        text.push_str(&format!(" {}", theme().tag_label(tag)));
     }
     
-    println!();
+    // Add owners as @mentions
+    for owner in &task.owners {
+        text.push_str(&format!(" {}", format!("@{}", owner.replace(' ', "_")).bright_blue()));
+    }
     
-    // Show detailed info if requested
-    if detailed {
-        // Always show priority in detailed view since we removed it from the main line
-        println!("       {} Priority: {}", 
-            get_priority_indicator(&task.priority),
-            format!("{}", task.priority).bright_white()
-        );
-        
-        // Show AI information if available
-        if task.is_ai_generated() {
-            if let Some(operation) = task.get_ai_operation() {
-                println!("       🤖 AI Generated: {} operation", operation.bright_cyan());
-            }
-            if let Some(reasoning) = task.get_ai_reasoning() {
-                println!("       💡 AI Suggestion: {}", reasoning.bright_blue().italic());
+    // Add contexts, which already carry their @ prefix
+    for context in &task.contexts {
+        text.push_str(&format!(" {}", context.bright_green()));
+    }
+    
+    // Mark pending tasks nobody has touched for a while
+    if task.status == TaskStatus::Pending {
+        let idle = task.idle_days(chrono::Utc::now());
+        if let (Some(days), Some(limit)) = (idle, stale_after_days()) {
+            if days >= limit {
+                text.push_str(&format!(" {}", format!("stale {}d", days).dimmed()));
             }
         }
-        
-        if let Some(ref notes) = task.notes {
-            println!("       💭 {}", notes.italic().bright_black());
+    }
+    cells.push(text);
+    cells
+}
+
+/// Display tasks as aligned lines that fit the output width, with details
+/// under each line in detailed mode
+pub fn display_task_lines(tasks: &[&Task], detailed: bool) {
+    let mut table = Table::new(" ").indent("  ");
+    for task in tasks {
+        table.add_row(task_line_cells(task, detailed));
+    }
+    for (task, line) in tasks.iter().zip(table.render(output_width())) {
+        println!("{}", line);
+        if detailed {
+            display_task_details(task);
         }
-        
-        if !task.dependencies.is_empty() {
-            let deps_str = task.dependencies.iter()
-                .map(|id| id.to_string())
-                .collect::<Vec<_>>()
-                .join(", ");
-            println!("       🔗 Depends on: {}", deps_str.bright_yellow());
+    }
+}
+
+fn display_task_details(task: &Task) {
+    // Always show priority in detailed view since we removed it from the main line
+    println!("       {} Priority: {}", 
+        get_priority_indicator(&task.priority),
+        format!("{}", task.priority).bright_white()
+    );
+    
+    // Show AI information if available
+    let icon = |emoji: &str| theme().icon(emoji);
+    if task.is_ai_generated() {
+        if let Some(operation) = task.get_ai_operation() {
+            println!("       {}AI Generated: {} operation", icon("🤖"), operation.bright_cyan());
         }
-        
-        // Show creation/completion info if available
-        if let Some(ref created_at) = task.created_at {
-            use chrono::DateTime;
-            if let Ok(datetime) = DateTime::parse_from_rfc3339(created_at) {
-                println!("       📅 Created: {}", datetime.format("%Y-%m-%d %H:%M").to_string().bright_black());
-            }
+        if let Some(reasoning) = task.get_ai_reasoning() {
+            println!("       {}AI Suggestion: {}", icon("💡"), reasoning.bright_blue().italic());
+        }
+    }
+    
+    if let Some(ref notes) = task.notes {
+        println!("       {}{}", icon("💭"), notes.italic().bright_black());
+    }
+    
+    if let Some((first, rest)) = task.dependencies.split_first() {
+        print!("       {}Depends on: {}", theme().dependency_marker(), first.to_string().bright_yellow());
+        for id in rest {
+            print!("{}{}", ", ".bright_yellow(), id.to_string().bright_yellow());
+        }
+        println!();
+    }
+    
+    if let Some(due) = &task.due_date {
+        println!("       {}Due: {}", icon("⏰"), due.bright_yellow());
+    }
+    
+    // Show creation/completion info if available
+    if let Some(datetime) = task.created_datetime() {
+        println!("       {}Created: {}", icon("📅"), datetime.format("%Y-%m-%d %H:%M").to_string().bright_black());
+    }
+}
+
+fn display_history_entries(history: &[crate::model::TaskHistoryEntry]) {
+    for entry in history {
+        let when = chrono::DateTime::parse_from_rfc3339(&entry.timestamp)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|_| entry.timestamp.clone());
+        match &entry.details {
+            Some(details) => println!("      {} {} — {}", when.bright_black(), entry.action.bright_white(), details),
+            None => println!("      {} {}", when.bright_black(), entry.action.bright_white()),
         }
     }
 }
 
+/// Display the full activity log of a task, oldest first
+pub fn display_task_history_log(task: &Task) {
+    let icon = |emoji: &str| theme().icon(emoji);
+    println!("\n  {}{} #{} {}", icon("📜"), "History of".bold().bright_blue(), task.id, task.description.bold());
+    println!("  {}", theme().rule("─", 50).bright_black());
+    if task.history.is_empty() {
+        println!("      No activity recorded yet.");
+    } else {
+        display_history_entries(&task.history);
+    }
+    println!();
+}
+
 /// Display filtered tasks with optional detailed view
 pub fn display_filtered_tasks(roadmap: &crate::model::Roadmap, filtered_tasks: &[&Task], detailed: bool) {
+    let icon = |emoji: &str| theme().icon(emoji);
+    if super::is_porcelain() {
+        print_porcelain_lines(filtered_tasks.iter().map(|task| porcelain_line(task)));
+        return;
+    }
+    
     let total_tasks = roadmap.tasks.len();
     let filtered_count = filtered_tasks.len();
     
     // Print header
-    println!("\n{}", "═".repeat(60).bright_blue());
+    println!("\n{}", theme().rule("═", 60).bright_blue());
     println!("  {} (Showing {} of {} tasks)", 
         roadmap.title.bold().bright_cyan(), 
         filtered_count.to_string().bright_white(),
         total_tasks.to_string().bright_white()
     );
-    println!("{}", "═".repeat(60).bright_blue());
+    println!("{}", theme().rule("═", 60).bright_blue());
     
     if filtered_tasks.is_empty() {
-        println!("\n  🔍 No tasks match your filter criteria.");
+        println!("\n  {}No tasks match your filter criteria.", icon("🔍"));
         println!("      Try adjusting your search terms or filters.");
         
         // Provide helpful suggestions
         if total_tasks > 0 {
-            println!("\n  💡 Suggestions:");
+            println!("\n  {}Suggestions:", icon("💡"));
             println!("      • Use 'rask list' to see all tasks");
             println!("      • Use 'rask list --status all' to include completed tasks");
             println!("      • Try broader search terms with 'rask list --search <keyword>'");
@@ -150,19 +196,17 @@ pub fn display_filtered_tasks(roadmap: &crate::model::Roadmap, filtered_tasks: &
     }
     
     // Print task list header
-    println!("\n  📋 {}:", "Filtered Tasks".bold());
-    println!("  {}", "─".repeat(50).bright_black());
+    println!("\n  {}{}:", icon("📋"), "Filtered Tasks".bold());
+    println!("  {}", theme().rule("─", 50).bright_black());
     
     // Print each filtered task
-    for task in filtered_tasks {
-        display_task_line(task, detailed);
-    }
+    display_task_lines(filtered_tasks, detailed);
     
-    println!("  {}", "─".repeat(50).bright_black());
+    println!("  {}", theme().rule("─", 50).bright_black());
     
     // Print filter summary
     if filtered_count < total_tasks {
-        println!("  📊 Showing {} of {} total tasks", 
+        println!("  {}Showing {} of {} total tasks", icon("📊"), 
             filtered_count.to_string().bright_white(),
             total_tasks.to_string().bright_white()
         );
@@ -171,15 +215,95 @@ pub fn display_filtered_tasks(roadmap: &crate::model::Roadmap, filtered_tasks: &
     println!();
 }
 
+/// Display tasks grouped by tag namespace, for `list --group-tags`
+pub fn display_tag_groups(roadmap: &crate::model::Roadmap, groups: &[(String, Vec<&Task>)], filtered_count: usize, detailed: bool) {
+    let icon = |emoji: &str| theme().icon(emoji);
+    println!("\n{}", theme().rule("═", 60).bright_blue());
+    println!("  {} (Showing {} of {} tasks in {} groups)",
+        roadmap.title.bold().bright_cyan(),
+        filtered_count.to_string().bright_white(),
+        roadmap.tasks.len().to_string().bright_white(),
+        groups.len().to_string().bright_white()
+    );
+    println!("{}", theme().rule("═", 60).bright_blue());
+
+    if groups.is_empty() {
+        println!("\n  {}No tasks match your filter criteria.\n", icon("🔍"));
+        return;
+    }
+    for (name, tasks) in groups {
+        let label = if name.starts_with('(') { name.bright_black().to_string() } else { theme().tag_label(name).bold().to_string() };
+        let done = tasks.iter().filter(|task| task.status == TaskStatus::Completed).count();
+        println!("\n  {}{} {}", icon("🏷️ "), label, format!("({} tasks, {} done)", tasks.len(), done).bright_black());
+        println!("  {}", theme().rule("─", 50).bright_black());
+        display_task_lines(tasks, detailed);
+    }
+    println!();
+}
+
+/// Format a task as `id, status, priority, phase, tags, description`, tab-separated.
+/// Backslashes, tabs and line breaks inside fields are escaped as `\\`, `\t`, `\n` and `\r`.
+pub fn porcelain_line(task: &Task) -> String {
+    let status = if task.status == TaskStatus::Completed { "completed" } else { "pending" };
+    let mut tags: Vec<&str> = task.tags.iter().map(String::as_str).collect();
+    tags.sort_unstable();
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{}",
+        task.id,
+        status,
+        task.priority.to_string().to_lowercase(),
+        porcelain_field(&task.phase.name),
+        porcelain_field(&tags.join(",")),
+        porcelain_field(&task.description)
+    )
+}
+
+/// Escape a porcelain field so it cannot break the one-row-per-line, tab-separated format
+fn porcelain_field(text: &str) -> std::borrow::Cow<'_, str> {
+    if !text.contains(['\\', '\t', '\n', '\r']) {
+        return text.into();
+    }
+    text.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+        .into()
+}
+
+/// Write porcelain lines to stdout. A closed pipe (`rask --porcelain list | head -1`)
+/// ends the process quietly instead of panicking.
+pub fn print_porcelain_lines(lines: impl IntoIterator<Item = String>) {
+    let mut out = std::io::stdout().lock();
+    for line in lines {
+        if let Err(e) = writeln!(out, "{}", line) {
+            if e.kind() == std::io::ErrorKind::BrokenPipe {
+                std::process::exit(0);
+            }
+            return;
+        }
+    }
+    if out.flush().is_err_and(|e| e.kind() == std::io::ErrorKind::BrokenPipe) {
+        std::process::exit(0);
+    }
+}
+
 /// Display enhanced add success message
-pub fn display_add_success_enhanced(task: &Task) {
-    println!("\n➕ {}: Task #{} added successfully!", 
+pub fn display_add_success_enhanced(task: &Task, markdown_synced: bool) {
+    if super::is_porcelain() {
+        print_porcelain_lines([task.id.to_string()]);
+        return;
+    }
+    if super::is_quiet() {
+        return;
+    }
+    let icon = |emoji: &str| theme().icon(emoji);
+    println!("\n{}{}: Task #{} added successfully!", icon("➕"), 
         "Success".green().bold(), 
         task.id.to_string().bright_white()
     );
     
-    println!("    📝 Task: {}", task.description.bright_white());
-    println!("    🆔 Assigned ID: {}", task.id.to_string().bright_cyan());
+    println!("    {}Task: {}", icon("📝"), task.description.bright_white());
+    println!("    {}Assigned ID: {}", icon("🆔"), task.id.to_string().bright_cyan());
     
     // Show priority if not default
     if task.priority != Priority::Medium {
@@ -192,15 +316,28 @@ pub fn display_add_success_enhanced(task: &Task) {
     // Show tags if present
     if !task.tags.is_empty() {
         let tags_str = task.tags.iter()
-            .map(|tag| format!("#{}", tag).bright_magenta().to_string())
+            .map(|tag| theme().tag_label(tag))
             .collect::<Vec<_>>()
             .join(" ");
-        println!("    🏷️  Tags: {}", tags_str);
+        println!("    {}Tags: {}", icon("🏷️"), tags_str);
+    }
+    
+    // Show owners if present
+    if !task.owners.is_empty() {
+        println!("    {}Owners: {}", icon("👥"), task.owners.join(", ").bright_blue());
+    }
+    
+    // Show energy and contexts if present
+    if let Some(energy) = task.energy {
+        println!("    {}Energy: {}", icon("⚡"), energy.to_string().bright_white());
+    }
+    if !task.contexts.is_empty() {
+        println!("    {}Contexts: {}", icon("📍"), task.contexts.join(", ").bright_green());
     }
     
     // Show notes if present
     if let Some(ref notes) = task.notes {
-        println!("    💭 Notes: {}", notes.italic().bright_black());
+        println!("    {}Notes: {}", icon("💭"), notes.italic().bright_black());
     }
     
     // Show dependencies if present
@@ -209,10 +346,14 @@ pub fn display_add_success_enhanced(task: &Task) {
             .map(|id| id.to_string())
             .collect::<Vec<_>>()
             .join(", ");
-        println!("    🔗 Dependencies: {}", deps_str.bright_yellow());
+        println!("    {}Dependencies: {}", icon("🔗"), deps_str.bright_yellow());
     }
     
-    println!("    💡 Task added to both state and markdown file!\n");
+    if markdown_synced {
+        println!("    {}Task added to both state and markdown file!\n", icon("💡"));
+    } else {
+        println!("    {}Task saved; the markdown file was not changed\n", icon("💡"));
+    }
 }
 
 /// Display enhanced completion success with dependency unlocking notifications
@@ -222,25 +363,29 @@ pub fn display_completion_success_enhanced(
     newly_unblocked: &[usize],
     roadmap: &crate::model::Roadmap
 ) {
-    println!("\n✨ {}: Task #{} completed!", 
+    if super::is_quiet() {
+        return;
+    }
+    let icon = |emoji: &str| theme().icon(emoji);
+    println!("\n{}{}: Task #{} completed!", icon("✨"), 
         "Success".green().bold(), 
         task_id.to_string().bright_white()
     );
     
-    println!("   📝 Task: {}", task_description.bright_white());
-    println!("   🎊 Well done! Keep up the great work!");
+    println!("   {}Task: {}", icon("📝"), task_description.bright_white());
+    println!("   {}Well done! Keep up the great work!", icon("🎊"));
     
     // Show dependency unlocking notifications
     if !newly_unblocked.is_empty() {
-        println!("\n🔓 {} unblocked by completing this task:", 
+        println!("\n{}{} unblocked by completing this task:", icon("🔓"), 
             if newly_unblocked.len() == 1 { "Task" } else { "Tasks" }.bright_green().bold()
         );
         
         for &unblocked_id in newly_unblocked {
             if let Some(unblocked_task) = roadmap.find_task_by_id(unblocked_id) {
                 let priority_indicator = get_priority_indicator(&unblocked_task.priority);
-                println!("   {} {} #{} {}", 
-                    "▶️".bright_green(),
+                println!("   {}{} #{} {}", 
+                    icon("▶️").bright_green(),
                     priority_indicator,
                     unblocked_id.to_string().bright_cyan(),
                     unblocked_task.description.bright_white()
@@ -248,7 +393,7 @@ pub fn display_completion_success_enhanced(
             }
         }
         
-        println!("   💡 {} ready to start!", 
+        println!("   {}{} ready to start!", icon("💡"), 
             if newly_unblocked.len() == 1 { "This task is now" } else { "These tasks are now" }.bright_yellow()
         );
     }
@@ -259,20 +404,30 @@ pub fn display_completion_success_enhanced(
 /// Display comprehensive detailed view of a specific task
 /// Shows all metadata, dependencies, reverse dependencies, and contextual information
 pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::model::Roadmap) {
-    println!("\n{}", "═".repeat(70).bright_blue());
-    println!("  {} #{}", "Detailed Task View".bold().bright_cyan(), task.id.to_string().bright_white());
-    println!("{}", "═".repeat(70).bright_blue());
-    
+    let icon = |emoji: &str| theme().icon(emoji);
+    println!("\n{}", theme().rule("═", 70).bright_blue());
+    println!("  {} #{} {}", "Detailed Task View".bold().bright_cyan(), task.id.to_string().bright_white(), task.uuid.bright_black());
+    println!("{}", theme().rule("═", 70).bright_blue());
+
+    // Summary of the notes, from `rask ai summarize`
+    if let Some(summary) = &task.summary {
+        let date = summary.generated_at.get(..10).unwrap_or(&summary.generated_at);
+        println!("\n  {}{} {}", icon("🧾"), "Summary".bold().bright_cyan(), format!("({})", date).bright_black());
+        for line in summary.text.lines().filter(|line| !line.trim().is_empty()) {
+            println!("    {}", line.trim());
+        }
+        if task.summary_is_stale() {
+            println!("    {}", format!("Notes were added since; run 'rask ai summarize {}' to refresh", task.id).bright_yellow());
+        }
+    }
+
     // Task status and basic info
-    let status_icon = match task.status {
-        crate::model::TaskStatus::Completed => "✅".to_string(),
-        crate::model::TaskStatus::Pending => "⏳".to_string(),
-    };
+    let status_icon = theme().status_badge(&task.status);
     
     let priority_icon = get_priority_indicator(&task.priority);
     
-    println!("\n  📝 {}: {}", "Description".bold(), task.description.bright_white());
-    println!("  📊 {}: {} {}", "Status".bold(), status_icon, 
+    println!("\n  {}{}: {}", icon("📝"), "Description".bold(), task.description.bright_white());
+    println!("  {}{}: {} {}", icon("📊"), "Status".bold(), status_icon, 
         match task.status {
             crate::model::TaskStatus::Completed => "Completed".bright_green(),
             crate::model::TaskStatus::Pending => "Pending".bright_yellow(),
@@ -290,27 +445,42 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
     
     // Tags
     if !task.tags.is_empty() {
-        println!("  🏷️  {}: {}", "Tags".bold(), 
+        println!("  {}{}: {}", icon("🏷️"), "Tags".bold(), 
             task.tags.iter()
-                .map(|tag| format!("#{}", tag))
+                .map(|tag| theme().tag_label(tag))
                 .collect::<Vec<_>>()
                 .join(" ")
-                .bright_cyan()
         );
     }
     
+    if !task.owners.is_empty() {
+        println!("  {}{}: {}", icon("👥"), "Owners".bold(), task.owners.join(", ").bright_blue());
+    }
+    
+    if let Some(energy) = task.energy {
+        println!("  {}{}: {}", icon("⚡"), "Energy".bold(), energy.to_string().bright_white());
+    }
+    
+    if !task.contexts.is_empty() {
+        println!("  {}{}: {}", icon("📍"), "Contexts".bold(), task.contexts.join(", ").bright_green());
+    }
+    
+    if !task.sync {
+        println!("  {}{}: {}", icon("🔒"), "Markdown".bold(), "Local only (not written to the roadmap file)".bright_black());
+    }
+    
     // AI Information - prominently displayed for AI-generated tasks
     if task.is_ai_generated() {
-        println!("\n{}", "─".repeat(40).bright_cyan());
-        println!("  🤖 {} {}", "AI Generated Task".bold().bright_cyan(), "🤖".bright_cyan());
-        println!("{}", "─".repeat(40).bright_cyan());
+        println!("\n{}", theme().rule("─", 40).bright_cyan());
+        println!("  {}{} {}", icon("🤖"), "AI Generated Task".bold().bright_cyan(), icon("🤖").trim_end().bright_cyan());
+        println!("{}", theme().rule("─", 40).bright_cyan());
         
         if let Some(operation) = task.get_ai_operation() {
-            println!("  🔧 {}: {} operation", "AI Source".bold(), operation.bright_cyan());
+            println!("  {}{}: {} operation", icon("🔧"), "AI Source".bold(), operation.bright_cyan());
         }
         
         if let Some(reasoning) = task.get_ai_reasoning() {
-            println!("  💡 {}:", "AI Analysis & Suggestions".bold().bright_blue());
+            println!("  {}{}:", icon("💡"), "AI Analysis & Suggestions".bold().bright_blue());
             // Handle multi-line AI reasoning with proper indentation
             for line in reasoning.lines() {
                 if line.trim().is_empty() {
@@ -324,22 +494,22 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
         if let Some(ai_timestamp) = &task.ai_info.ai_timestamp {
             use chrono::DateTime;
             if let Ok(datetime) = DateTime::parse_from_rfc3339(ai_timestamp) {
-                println!("  🕒 {}: {}", "AI Generated".bold(), 
+                println!("  {}{}: {}", icon("🕒"), "AI Generated".bold(), 
                     datetime.format("%Y-%m-%d at %H:%M").to_string().bright_black()
                 );
             }
         }
         
         if let Some(model) = &task.ai_info.ai_model {
-            println!("  🧠 {}: {}", "AI Model".bold(), model.bright_magenta());
+            println!("  {}{}: {}", icon("🧠"), "AI Model".bold(), model.bright_magenta());
         }
         
-        println!("{}", "─".repeat(40).bright_cyan());
+        println!("{}", theme().rule("─", 40).bright_cyan());
     }
     
         // Notes
     if let Some(ref notes) = task.notes {
-        println!("  💭 {}:", "Notes".bold());
+        println!("  {}{}:", icon("💭"), "Notes".bold());
         // Handle multi-line notes with proper indentation
         for line in notes.lines() {
             println!("      {}", line.italic().bright_black());
@@ -348,15 +518,22 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
 
     // Implementation Notes
     if !task.implementation_notes.is_empty() {
-        println!("  🔧 {} ({}):", "Implementation Notes".bold().bright_blue(), task.implementation_notes.len());
+        println!("  {}{} ({}):", icon("🔧"), "Implementation Notes".bold().bright_blue(), task.implementation_notes.len());
         for (index, note) in task.implementation_notes.iter().enumerate() {
-            println!("      {} {}:", format!("#{}", index).bright_white().bold(), "Note".bright_blue());
+            println!("      {}{} {}:", icon(note.kind.icon()), format!("#{}", index).bright_white().bold(), note.kind.to_string().bright_blue());
             // Handle multi-line implementation notes with proper indentation
-            for line in note.lines() {
+            for line in note.text.lines() {
                 if line.trim().is_empty() {
                     println!();
                 } else {
-                    println!("        {}", line.bright_cyan());
+                    let line = match note.kind {
+                        NoteKind::Decision => line.bright_magenta().bold(),
+                        NoteKind::Blocker => line.bright_red(),
+                        NoteKind::Code => line.bright_green(),
+                        NoteKind::Link => line.bright_blue().underline(),
+                        NoteKind::General => line.bright_cyan(),
+                    };
+                    println!("        {}", line);
                 }
             }
             if index < task.implementation_notes.len() - 1 {
@@ -365,21 +542,49 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
         }
     }
 
-    // Creation date
-    if let Some(ref created_at) = task.created_at {
-        use chrono::DateTime;
-        if let Ok(datetime) = DateTime::parse_from_rfc3339(created_at) {
-            println!("  📅 {}: {}", "Created".bold(), 
-                datetime.format("%Y-%m-%d at %H:%M").to_string().bright_black()
-            );
+    // Due date
+    if let Some(due) = task.due_date_parsed() {
+        let days_left = (due - chrono::Local::now().date_naive()).num_days();
+        let countdown = match days_left {
+            _ if task.status == crate::model::TaskStatus::Completed => "".normal(),
+            d if d < 0 => format!("(overdue by {} days)", -d).bright_red(),
+            0 => "(due today)".bright_yellow(),
+            d => format!("(in {} days)", d).bright_black(),
+        };
+        println!("  {}{}: {} {}", icon("⏰"), "Due".bold(), due.format("%Y-%m-%d").to_string().bright_white(), countdown);
+    }
+
+    if task.is_snoozed(chrono::Local::now().date_naive()) {
+        if let Some(until) = &task.snoozed_until {
+            println!("  {}{}: until {}", icon("💤"), "Snoozed".bold(), until.bright_white());
+        }
+    }
+
+    // Acceptance criteria
+    if !task.acceptance_criteria.is_empty() {
+        println!("  {}{} ({}):", icon("🎯"), "Acceptance Criteria".bold().bright_magenta(), task.acceptance_criteria.len());
+        let checkbox = match (theme().emoji, &task.status) {
+            (true, crate::model::TaskStatus::Completed) => "☑".normal(),
+            (true, crate::model::TaskStatus::Pending) => "☐".normal(),
+            (false, status) => theme().task_status_symbol(status),
+        };
+        for criterion in &task.acceptance_criteria {
+            println!("      {} {}", checkbox, criterion);
         }
     }
+
+    // Creation date
+    if let Some(datetime) = task.created_datetime() {
+        println!("  {}{}: {}", icon("📅"), "Created".bold(), 
+            datetime.format("%Y-%m-%d at %H:%M").to_string().bright_black()
+        );
+    }
     
-    println!("\n{}", "─".repeat(70).bright_black());
+    println!("\n{}", theme().rule("─", 70).bright_black());
     
     // Dependencies analysis
     if !task.dependencies.is_empty() {
-        println!("  🔗 {} ({}):", "Dependencies".bold().bright_yellow(), task.dependencies.len());
+        println!("  {}{} ({}):", icon("🔗"), "Dependencies".bold().bright_yellow(), task.dependencies.len());
         
         let completed_ids = roadmap.get_completed_task_ids();
         let mut completed_deps = Vec::new();
@@ -397,25 +602,31 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
         
         // Show completed dependencies
         if !completed_deps.is_empty() {
-            println!("      ✅ {} completed:", "Dependencies".bright_green());
+            println!("      {}{} completed:", icon("✅"), "Dependencies".bright_green());
             for (dep_id, dep_task) in completed_deps {
                 println!("         #{} {}", dep_id.to_string().bright_green(), dep_task.description.dimmed());
+                if let Some(note) = task.dependency_notes.get(&dep_id) {
+                    println!("            {}{}", icon("💬"), note.italic().bright_black());
+                }
             }
         }
         
         // Show pending dependencies
         if !pending_deps.is_empty() {
-            println!("      ⏳ {} pending:", "Dependencies".bright_red());
+            println!("      {}{} pending:", icon("⏳"), "Dependencies".bright_red());
             for (dep_id, dep_task) in pending_deps {
                 let dep_priority_icon = get_priority_indicator(&dep_task.priority);
                 println!("         {} #{} {}", dep_priority_icon, dep_id.to_string().bright_red(), dep_task.description);
+                if let Some(note) = task.dependency_notes.get(&dep_id) {
+                    println!("            {}{}", icon("💬"), note.italic());
+                }
             }
         }
         
         // Show dependency chain
         let chain = roadmap.get_dependency_chain(task.id);
         if chain.len() > task.dependencies.len() {
-            println!("      🔄 {}: {}", "Full dependency chain".bright_black(), 
+            println!("      {}{}: {}", icon("🔄"), "Full dependency chain".bright_black(), 
                 chain.iter()
                     .map(|id| format!("#{}", id))
                     .collect::<Vec<_>>()
@@ -424,34 +635,31 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
             );
         }
     } else {
-        println!("  🔗 {}: None", "Dependencies".bold().bright_green());
+        println!("  {}{}: None", icon("🔗"), "Dependencies".bold().bright_green());
     }
     
     // Reverse dependencies (tasks that depend on this one)
     let dependents = roadmap.get_dependents(task.id);
     if !dependents.is_empty() {
-        println!("  🔄 {} ({}):", "Tasks depending on this".bold().bright_cyan(), dependents.len());
+        println!("  {}{} ({}):", icon("🔄"), "Tasks depending on this".bold().bright_cyan(), dependents.len());
         for &dep_id in &dependents {
             if let Some(dep_task) = roadmap.find_task_by_id(dep_id) {
-                let status_icon = match dep_task.status {
-                    crate::model::TaskStatus::Completed => "✅",
-                    crate::model::TaskStatus::Pending => "⏳",
-                };
+                let status_icon = theme().status_badge(&dep_task.status);
                 let priority_icon = get_priority_indicator(&dep_task.priority);
                 println!("      {} {} #{} {}", status_icon, priority_icon, dep_id.to_string().bright_cyan(), dep_task.description);
             }
         }
     } else {
-        println!("  🔄 {}: None", "Tasks depending on this".bold().bright_green());
+        println!("  {}{}: None", icon("🔄"), "Tasks depending on this".bold().bright_green());
     }
     
-    println!("\n{}", "─".repeat(70).bright_black());
+    println!("\n{}", theme().rule("─", 70).bright_black());
     
     // Task readiness analysis
     let completed_ids = roadmap.get_completed_task_ids();
     if task.status == crate::model::TaskStatus::Pending {
         if task.can_be_started(&completed_ids) {
-            println!("  🚀 {}: This task is ready to be started!", "Status".bold().bright_green());
+            println!("  {}{}: This task is ready to be started!", icon("🚀"), "Status".bold().bright_green());
             if !task.dependencies.is_empty() {
                 println!("      All dependencies have been completed.");
             }
@@ -460,7 +668,7 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
                 .filter(|&&dep_id| !completed_ids.contains(&dep_id))
                 .copied()
                 .collect();
-            println!("  🔒 {}: This task is blocked by {} incomplete dependencies", 
+            println!("  {}{}: This task is blocked by {} incomplete dependencies", icon("🔒"), 
                 "Status".bold().bright_red(), incomplete_deps.len());
             println!("      Complete tasks {} first", 
                 incomplete_deps.iter()
@@ -471,7 +679,7 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
             );
         }
     } else {
-        println!("  ✅ {}: This task has been completed!", "Status".bold().bright_green());
+        println!("  {}{}: This task has been completed!", icon("✅"), "Status".bold().bright_green());
         
         // Show what this completion unlocked
         let unlocked_tasks: Vec<usize> = roadmap.tasks.iter()
@@ -484,7 +692,7 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
             .collect();
         
         if !unlocked_tasks.is_empty() {
-            println!("      🔓 Completing this task unlocked: {}", 
+            println!("      {}Completing this task unlocked: {}", icon("🔓"), 
                 unlocked_tasks.iter()
                     .map(|id| format!("#{}", id))
                     .collect::<Vec<_>>()
@@ -494,18 +702,56 @@ pub fn display_detailed_task_view(task: &crate::model::Task, roadmap: &crate::mo
         }
     }
     
+    // Task history
+    if !task.history.is_empty() {
+        println!("\n  {}{} ({}):", icon("📜"), "History".bold().bright_blue(), task.history.len());
+        display_history_entries(&task.history);
+    }
+
+    // Discussion thread
+    if !task.comments.is_empty() {
+        println!("\n  {}{} ({}):", icon("💬"), "Comments".bold().bright_cyan(), task.comments.len());
+        display_task_comments(&task.comments);
+    }
+
+    // Linked git commits
+    if !task.linked_commits.is_empty() {
+        println!("\n  {}{} ({}):", icon("🔀"), "Linked Commits".bold().bright_green(), task.linked_commits.len());
+        for commit in &task.linked_commits {
+            let short_hash = commit.hash.get(..7).unwrap_or(&commit.hash);
+            let when = commit.committed_at.as_deref()
+                .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok())
+                .map(|dt| format!(" ({})", dt.format("%Y-%m-%d")))
+                .unwrap_or_default();
+            println!("      {} {}{}", short_hash.bright_yellow(), commit.summary, when.bright_black());
+        }
+    }
+
     // Validation check
     if let Err(errors) = roadmap.validate_task_dependencies(task.id) {
-        println!("\n  ⚠️  {}: Found {} issue(s)", "Validation".bold().bright_red(), errors.len());
+        println!("\n  {}{}: Found {} issue(s)", icon("⚠️"), "Validation".bold().bright_red(), errors.len());
         for error in &errors {
             println!("      • {}", error.to_string().bright_red());
         }
     }
     
-    println!("\n{}", "═".repeat(70).bright_blue());
-    println!("  💡 Use {} to see the dependency tree", format!("rask dependencies --task-id {}", task.id).bright_cyan());
+    println!("\n{}", theme().rule("═", 70).bright_blue());
+    println!("  {}Use {} to see the dependency tree", icon("💡"), format!("rask dependencies --task-id {}", task.id).bright_cyan());
     if task.status == crate::model::TaskStatus::Pending && task.can_be_started(&completed_ids) {
-        println!("  💡 Use {} to complete this task", format!("rask complete {}", task.id).bright_cyan());
+        println!("  {}Use {} to complete this task", icon("💡"), format!("rask complete {}", task.id).bright_cyan());
     }
     println!();
-}
\ No newline at end of file
+}
+
+/// Print a task's comments, oldest first, with author and date
+pub fn display_task_comments(comments: &[crate::model::TaskComment]) {
+    for comment in comments {
+        let when = chrono::DateTime::parse_from_rfc3339(&comment.created_at)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|_| comment.created_at.clone());
+        println!("      {} {}", comment.author.bright_white().bold(), when.bright_black());
+        for line in comment.text.lines() {
+            println!("        {}", line);
+        }
+    }
+}
@@ -0,0 +1,403 @@
+//! Output theme: colors and symbols for the terminal views
+//!
+//! `theme.name` in the config picks one of [`BUILTIN_THEMES`]. Colors and
+//! symbols set in `[theme]` are laid over it, except values that are still the
+//! config defaults, which every config file written so far contains.
+//!
+//! Accessible mode (`ui.accessible` or `--accessible`) goes further than any
+//! theme: no color, emoji or box drawing, and bracketed text labels such as
+//! `[DONE]` and `[HIGH PRIORITY]` that read well in a screen reader.
+//!
+//! `theme.tag_colors` and `theme.phase_colors` give single tags and phases a
+//! color, an emoji or both, e.g. `backend = "blue ⚙️"`. A nested tag such as
+//! `area/backend/auth` takes the style of its closest configured ancestor.
+
+use crate::config::{RaskConfig, SymbolConfig, ThemeConfig};
+use crate::model::{Phase, Priority, TaskStatus};
+use colored::*;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Names accepted by `rask config set theme.name`
+pub const BUILTIN_THEMES: &[&str] = &["default", "dark", "light", "no-emoji"];
+
+/// Color and emoji configured for a tag or phase
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LabelStyle {
+    pub color: Option<Color>,
+    pub emoji: Option<String>,
+}
+
+impl LabelStyle {
+    /// Parse a `tag_colors`/`phase_colors` value: a color name or `#rrggbb`,
+    /// an emoji, or a color followed by an emoji
+    pub fn parse(value: &str) -> Result<LabelStyle, String> {
+        let (words, emoji): (Vec<&str>, Vec<&str>) = value.split_whitespace().partition(|word| word.is_ascii());
+        let name = words.join(" ");
+        let color = color(&name);
+        if color.is_none() && !matches!(name.to_lowercase().as_str(), "" | "none" | "normal" | "default") {
+            return Err(format!("Unknown color '{}'. Use a terminal color such as 'blue' or 'bright red', or #rrggbb", name));
+        }
+        Ok(LabelStyle { color, emoji: if emoji.is_empty() { None } else { Some(emoji.join(" ")) } })
+    }
+
+    /// The color as CSS, for the HTML exports
+    pub fn css(&self) -> Option<String> {
+        self.color.map(css_color)
+    }
+}
+
+/// Resolved theme used by all `ui` output
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Whether decorative emoji are printed before headings and messages
+    pub emoji: bool,
+    /// Plain text output for screen readers
+    pub accessible: bool,
+    priority_colors: HashMap<String, Option<Color>>,
+    status_colors: HashMap<String, Option<Color>>,
+    priority_symbols: HashMap<String, String>,
+    status_symbols: HashMap<String, String>,
+    dependency: String,
+    /// Filled part of progress bars
+    pub progress_filled: String,
+    /// Empty part of progress bars
+    pub progress_empty: String,
+    progress_color: Option<Color>,
+    tag_styles: HashMap<String, LabelStyle>,
+    phase_styles: HashMap<String, LabelStyle>,
+}
+
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+/// The theme for this run, resolved from the configuration on first use
+pub fn theme() -> &'static Theme {
+    THEME.get_or_init(|| resolve(false))
+}
+
+/// Resolve the theme up front, with `--accessible` forcing accessible mode
+pub fn init(accessible: bool) {
+    let theme = THEME.get_or_init(|| resolve(accessible));
+    if theme.accessible {
+        colored::control::set_override(false);
+    }
+}
+
+fn resolve(accessible: bool) -> Theme {
+    let config = RaskConfig::load().unwrap_or_default();
+    let theme = Theme::from_config(&config.theme);
+    if accessible || config.ui.accessible { theme.into_accessible() } else { theme }
+}
+
+fn color(name: &str) -> Option<Color> {
+    match name.trim().to_lowercase().as_str() {
+        "" | "none" | "normal" | "default" => None,
+        hex if hex.starts_with('#') && hex.len() == 7 => {
+            let channel = |range| u8::from_str_radix(hex.get(range)?, 16).ok();
+            Some(Color::TrueColor { r: channel(1..3)?, g: channel(3..5)?, b: channel(5..7)? })
+        }
+        name => Color::from_str(&name.replace(['_', '-'], " ")).ok(),
+    }
+}
+
+/// Whether a character is an emoji or other pictograph, including the
+/// joiners and variation selectors that combine them
+fn is_pictograph(c: char) -> bool {
+    matches!(c,
+        '\u{1F000}'..='\u{1FAFF}' | '\u{2600}'..='\u{27BF}' | '\u{2300}'..='\u{23FF}'
+        | '\u{2B00}'..='\u{2BFF}' | '\u{FE00}'..='\u{FE0F}' | '\u{200D}' | '\u{20E3}'
+        | '\u{2139}' | '\u{2122}' | '\u{3030}' | '\u{303D}'
+    )
+}
+
+/// CSS color for a terminal color, for the HTML exports
+pub fn css_color(color: Color) -> String {
+    let hex = match color {
+        Color::TrueColor { r, g, b } => return format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Color::Black => "#2c3e50",
+        Color::Red => "#c0392b",
+        Color::Green => "#27ae60",
+        Color::Yellow => "#d4ac0d",
+        Color::Blue => "#2980b9",
+        Color::Magenta => "#8e44ad",
+        Color::Cyan => "#16a085",
+        Color::White => "#bdc3c7",
+        Color::BrightBlack => "#7f8c8d",
+        Color::BrightRed => "#e74c3c",
+        Color::BrightGreen => "#2ecc71",
+        Color::BrightYellow => "#f1c40f",
+        Color::BrightBlue => "#3498db",
+        Color::BrightMagenta => "#9b59b6",
+        Color::BrightCyan => "#1abc9c",
+        Color::BrightWhite => "#ecf0f1",
+    };
+    hex.to_string()
+}
+
+/// Parsed label styles keyed by lowercase name; invalid values are left out
+/// here and rejected by `rask config set`
+fn label_styles(config: &HashMap<String, String>) -> HashMap<String, LabelStyle> {
+    config.iter()
+        .filter_map(|(name, value)| Some((name.to_lowercase(), LabelStyle::parse(value).ok()?)))
+        .collect()
+}
+
+fn table(entries: &[(&str, &str)]) -> HashMap<String, String> {
+    entries.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+impl Theme {
+    /// A built-in theme by name
+    pub fn builtin(name: &str) -> Option<Theme> {
+        let emoji_priorities = [("critical", "🔥"), ("high", "⬆️"), ("medium", "▶️"), ("low", "⬇️")];
+        let checkboxes = [("pending", "□"), ("completed", "✓"), ("blocked", "□")];
+        let (priority_colors, status_colors, progress_color) = match name {
+            "default" | "no-emoji" => (
+                [("critical", "bright red"), ("high", "red"), ("medium", "normal"), ("low", "bright black")],
+                [("pending", "bright black"), ("completed", "green"), ("blocked", "red")],
+                "bright green",
+            ),
+            "dark" => (
+                [("critical", "bright red"), ("high", "bright yellow"), ("medium", "bright white"), ("low", "bright black")],
+                [("pending", "white"), ("completed", "bright green"), ("blocked", "bright red")],
+                "bright green",
+            ),
+            "light" => (
+                [("critical", "red"), ("high", "magenta"), ("medium", "black"), ("low", "blue")],
+                [("pending", "black"), ("completed", "green"), ("blocked", "red")],
+                "green",
+            ),
+            _ => return None,
+        };
+        let plain = name == "no-emoji";
+        let theme = Theme {
+            emoji: !plain,
+            accessible: false,
+            priority_colors: priority_colors.iter().map(|(k, v)| (k.to_string(), color(v))).collect(),
+            status_colors: status_colors.iter().map(|(k, v)| (k.to_string(), color(v))).collect(),
+            priority_symbols: if plain {
+                table(&[("critical", "!!!"), ("high", "!! "), ("medium", "!  "), ("low", "-  ")])
+            } else {
+                table(&emoji_priorities)
+            },
+            status_symbols: if plain {
+                table(&[("pending", "[ ]"), ("completed", "[x]"), ("blocked", "[!]")])
+            } else {
+                table(&checkboxes)
+            },
+            dependency: if plain { "->" } else { "🔗" }.to_string(),
+            progress_filled: if plain { "#" } else { "█" }.to_string(),
+            progress_empty: if plain { "." } else { "░" }.to_string(),
+            progress_color: color(progress_color),
+            tag_styles: HashMap::new(),
+            phase_styles: HashMap::new(),
+        };
+        Some(theme)
+    }
+
+    /// Resolve the configured theme; unknown names fall back to the default theme
+    pub fn from_config(config: &ThemeConfig) -> Theme {
+        let mut theme = Theme::builtin(&config.name).unwrap_or_else(|| Theme::builtin("default").expect("default theme"));
+        let defaults = ThemeConfig::default();
+        let changed = |map: &HashMap<String, String>, default: &HashMap<String, String>| -> Vec<(String, String)> {
+            map.iter()
+                .filter(|(k, v)| default.get(*k) != Some(*v))
+                .map(|(k, v)| (k.to_lowercase(), v.clone()))
+                .collect()
+        };
+        for (key, value) in changed(&config.priority_colors, &defaults.priority_colors) {
+            theme.priority_colors.insert(key, color(&value));
+        }
+        for (key, value) in changed(&config.status_colors, &defaults.status_colors) {
+            theme.status_colors.insert(key, color(&value));
+        }
+        for (key, value) in &config.priority_symbols {
+            theme.priority_symbols.insert(key.to_lowercase(), value.clone());
+        }
+
+        let SymbolConfig { pending, completed, blocked, dependency, progress_filled, progress_empty, .. } = &config.symbols;
+        let default_symbols = &defaults.symbols;
+        for (key, value, default) in [
+            ("pending", pending, &default_symbols.pending),
+            ("completed", completed, &default_symbols.completed),
+            ("blocked", blocked, &default_symbols.blocked),
+        ] {
+            if value != default {
+                theme.status_symbols.insert(key.to_string(), value.clone());
+            }
+        }
+        if dependency != &default_symbols.dependency {
+            theme.dependency = dependency.clone();
+        }
+        if let Some(filled) = progress_filled {
+            theme.progress_filled = filled.clone();
+        }
+        if let Some(empty) = progress_empty {
+            theme.progress_empty = empty.clone();
+        }
+        theme.tag_styles = label_styles(&config.tag_colors);
+        theme.phase_styles = label_styles(&config.phase_colors);
+        theme
+    }
+
+    /// Accessible variant: text labels and no decoration
+    pub fn into_accessible(self) -> Theme {
+        Theme {
+            emoji: false,
+            accessible: true,
+            priority_colors: HashMap::new(),
+            status_colors: HashMap::new(),
+            priority_symbols: table(&[
+                ("critical", "[CRITICAL PRIORITY]"),
+                ("high", "[HIGH PRIORITY]"),
+                ("medium", "[MEDIUM PRIORITY]"),
+                ("low", "[LOW PRIORITY]"),
+            ]),
+            status_symbols: table(&[("pending", "[TODO]"), ("completed", "[DONE]"), ("blocked", "[BLOCKED]")]),
+            dependency: String::new(),
+            progress_color: None,
+            tag_styles: HashMap::new(),
+            phase_styles: HashMap::new(),
+            ..self
+        }
+    }
+
+    /// Symbol shown before dependency lists, followed by a space
+    pub fn dependency_marker(&self) -> String {
+        if self.dependency.is_empty() { String::new() } else { format!("{} ", self.dependency) }
+    }
+
+    /// A horizontal rule of `width` characters; empty in accessible mode
+    pub fn rule(&self, ch: &str, width: usize) -> String {
+        if self.accessible { String::new() } else { ch.repeat(width) }
+    }
+
+    /// Status badge for detailed views: emoji when the theme has them, else the status symbol
+    pub fn status_badge(&self, status: &TaskStatus) -> ColoredString {
+        match (self.emoji, status) {
+            (true, TaskStatus::Completed) => "✅".normal(),
+            (true, TaskStatus::Pending) => "⏳".normal(),
+            (false, status) => self.task_status_symbol(status),
+        }
+    }
+
+    /// Decorative emoji followed by a space, or nothing when the theme has emoji turned off
+    pub fn icon(&self, emoji: &str) -> String {
+        if self.emoji { format!("{} ", emoji) } else { String::new() }
+    }
+
+    /// Message text as it should be printed: with emoji turned off (the
+    /// `no-emoji` theme or accessible mode) pictographs are dropped, so a
+    /// screen reader is not left spelling out "🎯 Ready Tasks"
+    pub fn plain_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.emoji || !text.chars().any(is_pictograph) {
+            return Cow::Borrowed(text);
+        }
+        let mut plain = String::with_capacity(text.len());
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if !is_pictograph(c) {
+                plain.push(c);
+                continue;
+            }
+            // Drop the space that separated the pictograph from the text
+            if (plain.is_empty() || plain.ends_with(char::is_whitespace)) && chars.peek() == Some(&' ') {
+                chars.next();
+            }
+        }
+        Cow::Owned(plain.trim_end().to_string())
+    }
+
+    fn paint(text: &str, color: Option<Color>) -> ColoredString {
+        match color {
+            Some(color) => text.color(color),
+            None => text.normal(),
+        }
+    }
+
+    /// Text in the color for a priority level
+    pub fn priority_text(&self, priority: &Priority, text: &str) -> ColoredString {
+        let key = priority.to_string().to_lowercase();
+        let colored = Self::paint(text, self.priority_colors.get(&key).copied().flatten());
+        if *priority == Priority::Critical { colored.bold() } else { colored }
+    }
+
+    /// Colored indicator for a priority level
+    pub fn priority_symbol(&self, priority: &Priority) -> ColoredString {
+        let key = priority.to_string().to_lowercase();
+        let symbol = self.priority_symbols.get(&key).map(String::as_str).unwrap_or("");
+        Self::paint(symbol, self.priority_colors.get(&key).copied().flatten())
+    }
+
+    /// Colored symbol for a status: "pending", "completed" or "blocked"
+    pub fn status_symbol(&self, status: &str) -> ColoredString {
+        let symbol = self.status_symbols.get(status).map(String::as_str).unwrap_or("");
+        Self::paint(symbol, self.status_colors.get(status).copied().flatten())
+    }
+
+    /// Colored checkbox for a task status
+    pub fn task_status_symbol(&self, status: &TaskStatus) -> ColoredString {
+        match status {
+            TaskStatus::Completed => self.status_symbol("completed"),
+            TaskStatus::Pending => self.status_symbol("pending"),
+        }
+    }
+
+    /// Style of a tag, or of its closest configured ancestor for nested tags
+    pub fn tag_style(&self, tag: &str) -> Option<&LabelStyle> {
+        let mut tag = tag.to_lowercase();
+        loop {
+            if let Some(style) = self.tag_styles.get(&tag) {
+                return Some(style);
+            }
+            tag.truncate(tag.rfind('/')?);
+        }
+    }
+
+    /// Style configured for a phase
+    pub fn phase_style(&self, phase: &str) -> Option<&LabelStyle> {
+        self.phase_styles.get(&phase.to_lowercase())
+    }
+
+    /// A tag as `#tag`, in its configured color (magenta otherwise) and after its emoji
+    pub fn tag_label(&self, tag: &str) -> String {
+        let style = self.tag_style(tag);
+        let emoji = style.and_then(|style| style.emoji.as_deref()).map(|emoji| self.icon(emoji)).unwrap_or_default();
+        let text = format!("#{}", tag);
+        let text = match style.and_then(|style| style.color) {
+            Some(color) => text.color(color),
+            None => text.bright_magenta(),
+        };
+        format!("{}{}", emoji, text)
+    }
+
+    /// Phase text recolored when the phase has a configured color
+    pub fn phase_text(&self, phase: &str, text: ColoredString) -> ColoredString {
+        match self.phase_style(phase).and_then(|style| style.color) {
+            Some(color) => text.color(color),
+            None => text,
+        }
+    }
+
+    /// The configured emoji for a phase, or the phase's own
+    pub fn phase_emoji(&self, phase: &Phase) -> String {
+        self.phase_style(&phase.name)
+            .and_then(|style| style.emoji.clone())
+            .unwrap_or_else(|| phase.emoji())
+    }
+
+    /// A bar `width` characters wide, filled in proportion to completed/total.
+    /// Accessible mode says the numbers instead.
+    pub fn progress_bar(&self, completed: usize, total: usize, width: usize) -> String {
+        if self.accessible {
+            return format!("{} of {} done", completed, total);
+        }
+        let filled = (completed.min(total) * width).checked_div(total).unwrap_or(0);
+        format!("{}{}",
+            Self::paint(&self.progress_filled.repeat(filled), self.progress_color),
+            self.progress_empty.repeat(width - filled).bright_black()
+        )
+    }
+}
@@ -0,0 +1,83 @@
+//! `[[Note Name]]` links in task notes
+//!
+//! Task notes and implementation notes can point at pages of an Obsidian
+//! vault, or any directory of markdown notes, configured as
+//! `advanced.notes_dir`. A link names a page by its file name without `.md`;
+//! `[[Page#Heading]]` and `[[Page|label]]` work as they do in Obsidian.
+
+use regex::Regex;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// `[[Page]]`, `[[Page#Heading]]` or `[[Page|label]]`
+static WIKI_LINK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[\[([^\[\]|#]+)(?:#([^\[\]|]*))?(?:\|([^\[\]]+))?\]\]").expect("valid wiki link regex")
+});
+
+/// A `[[...]]` link found in a note
+#[derive(Debug, Clone)]
+pub struct WikiLink {
+    /// Page name, possibly with folders: "Design/API"
+    pub target: String,
+    /// Text to show: the `|label` if given, otherwise the page name
+    pub label: String,
+    /// Where the link sits in the note text
+    pub span: Range<usize>,
+}
+
+/// All wiki links in a note, in order
+pub fn find_links(text: &str) -> Vec<WikiLink> {
+    WIKI_LINK.captures_iter(text)
+        .filter_map(|caps| {
+            let whole = caps.get(0)?;
+            let target = caps[1].trim().to_string();
+            if target.is_empty() {
+                return None;
+            }
+            let label = caps.get(3).map(|l| l.as_str().trim().to_string()).unwrap_or_else(|| target.clone());
+            Some(WikiLink { target, label, span: whole.range() })
+        })
+        .collect()
+}
+
+/// The configured notes directory, with `~/` expanded. Relative paths are
+/// taken from the project directory.
+pub fn notes_dir() -> Option<PathBuf> {
+    let dir = crate::config::RaskConfig::load().ok()?.advanced.notes_dir?;
+    match dir.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+        None => Some(PathBuf::from(dir)),
+    }
+}
+
+/// The file a link points at. Like Obsidian, a bare page name matches a note
+/// with that name anywhere in the directory; a link to a page that does not
+/// exist yet resolves to a new file at the given path.
+pub fn resolve(notes_dir: &Path, target: &str) -> PathBuf {
+    let file_name = if target.to_lowercase().ends_with(".md") { target.to_string() } else { format!("{}.md", target) };
+    let direct = notes_dir.join(&file_name);
+    if direct.exists() || target.contains('/') {
+        return direct;
+    }
+    find_note(notes_dir, &file_name.to_lowercase()).unwrap_or(direct)
+}
+
+/// Search a directory tree for a file name, ignoring case and hidden folders like `.obsidian`
+fn find_note(dir: &Path, file_name: &str) -> Option<PathBuf> {
+    let mut subdirs = Vec::new();
+    for entry in fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_lowercase();
+        if path.is_dir() {
+            if !name.starts_with('.') {
+                subdirs.push(path);
+            }
+        } else if name == file_name {
+            return Some(path);
+        }
+    }
+    subdirs.sort();
+    subdirs.iter().find_map(|sub| find_note(sub, file_name))
+}
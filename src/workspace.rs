@@ -0,0 +1,96 @@
+//! Workspaces: one parent project linking several child projects
+//!
+//! A workspace is a project whose `.rask/workspace.json` lists child
+//! projects, typically sub-directories of the same repository that each have
+//! their own `.rask`. Commands that work across the workspace
+//! (`rask workspace status`, `rask list --all-projects`) read every member's
+//! state in turn, from the parent or from inside any child.
+
+use crate::state;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::{Path, PathBuf};
+
+const WORKSPACE_FILE: &str = "workspace.json";
+
+/// The child projects linked from a parent project
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct Workspace {
+    pub projects: Vec<LinkedProject>,
+}
+
+/// A child project, stored relative to the workspace root when it lives inside it
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LinkedProject {
+    pub name: String,
+    pub path: String,
+}
+
+/// A project taking part in a workspace, with its directory resolved
+#[derive(Debug, Clone)]
+pub struct Member {
+    pub name: String,
+    pub dir: PathBuf,
+}
+
+impl Workspace {
+    /// Find the workspace containing the current directory: the closest
+    /// ancestor (or the current directory itself) with a `.rask/workspace.json`
+    pub fn find() -> Result<Option<(PathBuf, Workspace)>, Error> {
+        let cwd = std::env::current_dir()?;
+        for dir in cwd.ancestors() {
+            let file = dir.join(".rask").join(WORKSPACE_FILE);
+            if file.is_file() {
+                let workspace = serde_json::from_str(&fs::read_to_string(&file)?)
+                    .map_err(|e| Error::new(ErrorKind::InvalidData, format!("Invalid {}: {}", file.display(), e)))?;
+                return Ok(Some((dir.to_path_buf(), workspace)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The workspace rooted at the current directory, or an empty one
+    pub fn load_here() -> Result<Workspace, Error> {
+        let file = Path::new(".rask").join(WORKSPACE_FILE);
+        if !file.exists() {
+            return Ok(Workspace::default());
+        }
+        serde_json::from_str(&fs::read_to_string(&file)?)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("Invalid {}: {}", file.display(), e)))
+    }
+
+    /// Save the workspace in the current directory's `.rask`
+    pub fn save_here(&self) -> Result<(), Error> {
+        fs::create_dir_all(".rask")?;
+        let json_data = serde_json::to_string_pretty(self).map_err(Error::other)?;
+        fs::write(Path::new(".rask").join(WORKSPACE_FILE), json_data)
+    }
+
+    /// Every project in the workspace: the parent first (when it has tasks of
+    /// its own), then the linked projects in the order they were added
+    pub fn members(&self, root: &Path) -> Vec<Member> {
+        let mut members = Vec::new();
+        if in_project(root, state::load_state_summary).is_ok_and(|r| r.is_ok()) {
+            let name = root.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| ".".to_string());
+            members.push(Member { name, dir: root.to_path_buf() });
+        }
+        members.extend(self.projects.iter().map(|project| Member {
+            name: project.name.clone(),
+            dir: root.join(&project.path),
+        }));
+        members
+    }
+}
+
+/// Run `f` with `dir` as the current directory, restoring it afterwards
+pub fn in_project<T>(dir: &Path, f: impl FnOnce() -> T) -> Result<T, Error> {
+    let previous = std::env::current_dir()?;
+    std::env::set_current_dir(dir)
+        .map_err(|e| Error::new(e.kind(), format!("Could not enter '{}': {}", dir.display(), e)))?;
+    let result = f();
+    std::env::set_current_dir(previous)?;
+    Ok(result)
+}